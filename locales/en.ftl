@@ -0,0 +1,471 @@
+## Connecting
+
+connecting-title = Connecting...
+connecting-login = Logging in
+connecting-channel = Connecting to channel
+connecting-game = Connecting to game
+creating-character-title = Creating character...
+creating-character = Creating character
+login-profile = Profile:
+game-reconnect-title = Connection Lost
+game-reconnect-lost = The connection to the game server was lost.
+game-reconnect-countdown = Reconnecting in { $seconds } seconds
+game-reconnect-connecting = Reconnecting...
+game-reconnect-failed = Could not reconnect to the game server.
+game-reconnect-attempt = Attempt { $attempt } of { $max }
+game-reconnect-now = Reconnect Now
+game-reconnect-cancel = Return to Login
+motd-title = News
+motd-loading = Loading news...
+motd-fetch-failed = Could not load the news: { $error }
+motd-patch-notes = Patch Notes
+
+## Logout
+
+exit-game-title = Exit Game
+exit-game-character-select = Character Select
+exit-game-exit = Exit Game
+exit-game-cancel = Cancel
+logout-title = Logging Out
+logout-countdown-character-select = Returning to character select in { $seconds } seconds
+logout-countdown-exit = Exiting the game in { $seconds } seconds
+logout-move-to-cancel = Moving will cancel the logout.
+logout-cancel = Cancel
+logout-waiting = Logging out...
+logout-cancelled = Logout cancelled.
+logout-cancelled-moved = Logout cancelled because you moved.
+logout-failed = You cannot log out yet, please wait { $seconds } seconds.
+
+## Character select
+
+character-delete-title = Delete Character
+character-delete-warning = { $name } will be deleted once the deletion countdown ends.
+character-delete-type-name = Type the character name to confirm:
+character-delete-confirm = Delete
+character-delete-cancel = Cancel
+character-delete-undo = Cancel Deletion
+character-delete-countdown = Deleted in { $countdown }
+
+## Items
+
+item-drop-money = { $money } Zuly
+item-tooltip-grade-bonus = [Grade { $grade }:{ $bonuses }]
+item-tooltip-empty-socket = [Empty socket]
+item-tooltip-sell-price = Sell Price: { $money } Zuly
+
+## Inventory
+
+inventory-weight = Weight
+inventory-weight-over-encumbered = Over encumbered, you are slowed to walking
+
+## NPC store
+
+npc-store-sell-locked-item = You cannot sell a locked item.
+npc-store-not-enough-money = You do not have enough Zuly for this transaction.
+
+## Player info
+
+player-info-out-of-ammo = Out of ammo!
+player-info-low-ammo = Low ammo: { $quantity }
+player-info-ammo = ammo
+player-info-equip-ammo = Equip { $name } ({ $quantity })
+
+## Session statistics
+
+session-statistics-title = Session Statistics
+session-statistics-time = Session Time:
+session-statistics-duration = { $hours }h { $minutes }m
+session-statistics-xp = Experience:
+session-statistics-money = Zuly:
+session-statistics-kills = Kills:
+session-statistics-per-hour = { $value } / hour
+session-statistics-kills-by-monster = Kills by monster
+session-statistics-items-looted = Items looted
+session-statistics-reset = Reset
+session-statistics-export = Export CSV
+session-statistics-export-succeeded = Exported to { $path }
+session-statistics-export-failed = Failed to export: { $error }
+
+## Frame profiler
+
+frame-profiler-export-succeeded = Exported to { $path }
+frame-profiler-export-failed = Export failed: { $error }
+
+## Money input
+
+money-input-drop-title = Drop Zuly
+money-input-hint = Enter an amount, e.g. 1,500 or 1.5k or 2m
+money-input-amount = { $money } Zuly
+money-input-balance = Balance: { $money } Zuly
+money-input-empty = Enter an amount
+money-input-invalid = Invalid amount
+money-input-overflow = Amount is too large
+money-input-insufficient = You do not have enough Zuly
+money-input-ok = OK
+money-input-max = Max
+money-input-cancel = Cancel
+
+## Chat system messages
+
+chat-waiting = Waiting...
+chat-invalid-target = Invalid target
+chat-drop-locked-item = You cannot drop a locked item.
+chat-assist-no-target = You must select a target to assist.
+chat-assist-target-has-no-target = Your target does not have a target.
+chat-defend-started = You are now defending { $name }.
+chat-defend-no-target = You must select a target to defend.
+chat-defend-stopped = You are no longer defending anyone.
+chat-afk-set = You are now away: { $message }
+chat-afk-cleared = You are no longer away.
+chat-afk-auto = You have been marked as away due to inactivity.
+chat-ammo-empty = You have run out of { $ammo }.
+chat-ammo-low = You are running low on { $ammo }.
+chat-encumbrance-warning = You are nearing your maximum carry weight.
+chat-encumbrance-over = You are carrying too much and can no longer run.
+chat-achievement-unlocked = Achievement unlocked: { $name }! You can now use the title "{ $title }".
+chat-character-exported = Character exported to { $path }
+chat-character-export-failed = Failed to export character: { $error }
+chat-level-up = Congratulations! You are now level { $level }!
+chat-hunted-monster = You have succeeded in hunting { $name }
+chat-ability-increased = Ability { $ability } has increased by { $value }.
+chat-ability-decreased = Ability { $ability } has decreased by { $value }.
+chat-ability-changed = Ability { $ability } has been changed to { $value }.
+chat-earned-xp = You have earned { $xp } experience points.
+chat-earned-item = You have earned { $item }.
+chat-earned-money = You have earned { $money } Zuly.
+chat-pickup-inventory-full = Cannot pickup item, inventory full.
+chat-pickup-no-permission = Cannot pickup item, it does not belong to you.
+chat-learn-skill-already-learnt = Failed to learn skill, you already know it.
+chat-learn-skill-job-requirement = Failed to learn skill, you do not satisfy the job requirement.
+chat-learn-skill-skill-requirement = Failed to learn skill, you do not satisfy the skill requirement.
+chat-learn-skill-ability-requirement = Failed to learn skill, you do not satisfy the ability requirement.
+chat-learn-skill-full = Failed to learn skill, you have too many skills.
+chat-learn-skill-invalid-skill = Failed to learn skill, invalid skill.
+chat-learn-skill-skill-points = Failed to learn skill, not enough skill points.
+chat-level-up-skill-failed = Failed to level up skill.
+chat-level-up-skill-job-requirement = Failed to level up skill, you do not satisfy the job requirement.
+chat-level-up-skill-skill-requirement = Failed to level up skill, you do not satisfy the skill requirement.
+chat-level-up-skill-ability-requirement = Failed to level up skill, you do not satisfy the ability requirement.
+chat-level-up-skill-money = Failed to level up skill, not enough money.
+chat-level-up-skill-skill-points = Failed to level up skill, not enough skill points.
+chat-npc-store-transaction-failed = Store transaction failed with error { $error }
+chat-personal-store-transaction-cancelled = Transaction failed.
+chat-personal-store-transaction-sold-out = Transaction failed. Item has sold out.
+chat-personal-store-transaction-no-more-need = Transaction failed. Item is no longer wanted.
+chat-personal-store-purchased = { $quantity ->
+    [1] You have purchased { $item } for { $money } Zuly.
+   *[other] You have purchased { $quantity }x { $item } for { $money } Zuly.
+}
+chat-personal-store-sold = { $quantity ->
+    [1] You have sold { $item } for { $money } Zuly.
+   *[other] You have sold { $quantity }x { $item } for { $money } Zuly.
+}
+chat-clan-level-up = Your clan has reached level { $level }!
+chat-clan-points-increased = Clan points: { $points } (+{ $change })
+chat-clan-points-decreased = Clan points: { $points } (-{ $change })
+
+## Party
+
+chat-party-invite-accepted = { $name } accepted your party invite.
+chat-party-invite-rejected = { $name } rejected your party invite.
+chat-party-joined = { $name } has joined the party.
+chat-party-left = You have left the party.
+chat-party-member-left = { $name } has left the party.
+chat-party-member-disconnected = { $name } has disconnected.
+chat-party-member-kicked = { $name } has been kicked from the party.
+chat-party-member-earned-item = { $name } has earned { $item }.
+chat-party-new-leader = { $name } is now leader of the party.
+chat-party-rules-changed = Party rules have changed.
+chat-party-xp-sharing = Experience points sharing: { $sharing }.
+chat-party-item-sharing = Item sharing: { $sharing }.
+party-xp-sharing-equal-share = Equal Share
+party-xp-sharing-distributed-by-level = Distributed by Level
+party-item-sharing-equal-loot-distribution = Equal Loot Distribution
+party-item-sharing-acquisition-order = Acquisition Order
+
+## Settings
+
+settings-title = Settings
+settings-page-sound = Sound
+settings-page-gameplay = Gameplay
+settings-page-graphics = Graphics
+settings-page-combat-text = Combat Text
+settings-page-accessibility = Accessibility
+settings-page-controls = Controls
+settings-enabled = Enabled
+settings-off = Off
+
+settings-sound = Sound:
+settings-output-device = Output Device:
+settings-output-device-default = System Default
+settings-output-device-refresh = Refresh
+settings-global-volume = Global Volume:
+settings-background-music = Background Music:
+settings-player-footsteps = Player Footsteps:
+settings-other-footsteps = Other Footsteps:
+settings-player-combat = Player Combat:
+settings-other-combat = Other Combat:
+settings-npc-sounds = NPC Sounds:
+
+settings-auto-loot = Auto Loot:
+settings-loot-radius = Loot Radius:
+settings-loot-min-item-quality = Minimum Item Quality:
+settings-loot-pickup = Pickup:
+settings-loot-money = Zuly
+settings-loot-equipment = Equipment
+settings-loot-consumables = Consumables
+settings-loot-materials = Materials
+settings-loot-hint = Press Z to loot all nearby items.
+settings-auto-away = Auto Away:
+settings-auto-away-timeout = Away After (minutes):
+settings-while-away = While Away:
+settings-auto-reply = Reply to whispers
+settings-while-unfocused = While Unfocused:
+settings-low-power-unfocused = Reduce frame rate
+settings-mute-unfocused = Mute sound
+settings-afk-hint = Type /afk [message] to toggle away status.
+settings-level-up = Level Up:
+settings-level-up-effect = Effect
+settings-level-up-fanfare = Fanfare
+settings-level-up-screen-flash = Screen flash
+settings-level-up-banner = Banner
+settings-cooldown-alerts = Cooldown Alerts:
+settings-cooldown-alerts-hotbar-pulse = Pulse hotbar slot
+settings-cooldown-alerts-toast = Show message
+settings-cooldown-alerts-sound = Play sound
+settings-cooldown-alerts-sound-id = Sound ID
+settings-cooldown-alerts-test = Test
+settings-cooldown-alerts-warning = Buff Expiry Warning (seconds):
+settings-cooldown-alerts-hint = Right click a hotbar skill or buff icon to choose what alerts.
+settings-hud-layout = HUD Layout:
+settings-hud-edit = Edit HUD
+
+settings-window-mode = Window Mode:
+settings-window-mode-windowed = Windowed
+settings-window-mode-borderless = Borderless Fullscreen
+settings-window-mode-exclusive = Exclusive Fullscreen
+settings-monitor = Monitor:
+settings-monitor-automatic = Automatic
+settings-monitor-index = Monitor { $index }
+settings-resolution = Resolution:
+settings-resolution-native = Native
+settings-minimize-on-focus-loss = Minimize On Alt-Tab:
+settings-vsync = Vertical Sync:
+settings-minimize-never = Never
+settings-minimize-any-fullscreen = Any Fullscreen
+settings-quality-preset = Quality Preset:
+settings-quality-low = Low
+settings-quality-medium = Medium
+settings-quality-high = High
+settings-quality-ultra = Ultra
+settings-quality-custom = Custom
+settings-shadows = Shadows:
+settings-draw-distance = Draw Distance:
+settings-particle-density = Particle Density:
+settings-texture-quality = Texture Quality:
+settings-applies-after-restart = (applies after restart)
+settings-anti-aliasing = Anti-aliasing:
+settings-msaa-2x = MSAA 2x
+settings-msaa-4x = MSAA 4x
+settings-benchmark-presets = Benchmark Presets
+settings-benchmark-measuring = Measuring { $preset } preset...
+settings-benchmark-preset = Preset
+settings-benchmark-frame-time = Frame Time
+settings-benchmark-fps = FPS
+settings-item-grade-effects = Item Grade Effects:
+settings-item-grade-effects-hint = Set to 0 to disable the glow of high grade equipment.
+settings-item-drop-effects = Item Drop Beams:
+settings-name-tags-character = Character Name Tags:
+settings-name-tags-npc = NPC Name Tags:
+settings-name-tags-monster = Monster Name Tags:
+settings-name-tags-always-show = Always Show
+settings-name-tags-font-size = Font Size
+settings-name-tags-combat-healthbars = Combat Health Bars:
+settings-name-tags-chat-bubbles = Chat Bubbles:
+
+settings-combat-text-dealt = Damage Dealt:
+settings-combat-text-critical = Critical Hit:
+settings-combat-text-taken = Damage Taken:
+settings-combat-text-miss = Miss:
+settings-combat-text-heal = Heal:
+settings-combat-text-scale = Scale
+settings-combat-text-merge = Merge Small Hits:
+settings-combat-text-merge-max-damage = Maximum Damage:
+settings-combat-text-merge-window = Merge Window (ms):
+settings-combat-text-status-effects = Status Effects:
+settings-combat-text-buff = Buff:
+settings-combat-text-debuff = Debuff:
+settings-combat-text-resisted = Resisted:
+
+settings-effect-intensity = Effect Intensity:
+settings-bloom-intensity = Bloom Intensity:
+settings-reduce-flashing = Reduce Flashing:
+settings-effect-intensity-hint = Effect intensity applies to skill and event effects spawned after it is changed.
+settings-text-to-speech = Text to Speech:
+settings-text-to-speech-read = Read Aloud:
+settings-tts-say = Chat
+settings-tts-shout = Shouts
+settings-tts-whisper = Whispers
+settings-tts-announce = Announcements
+settings-tts-system = System Messages
+settings-tts-quest = Quest Messages
+settings-tts-dialog = NPC Dialogs
+settings-tts-message-box = Message Boxes
+settings-controls-press-key = Press a key...
+settings-controls-binding-tooltip = Click to change, right click to unbind. Escape cancels.
+settings-controls-conflict = Also bound to { $actions }
+settings-controls-resolve = Unbind others
+settings-controls-resolve-tooltip = Remove this key from the other actions
+settings-controls-reset = Reset to Defaults
+settings-controls-export-cheat-sheet = Export Cheat Sheet
+settings-controls-cheat-sheet-exported = Cheat sheet exported to { $path }
+settings-controls-cheat-sheet-failed = Failed to export cheat sheet: { $error }
+
+## Key bindings
+
+input-cheat-sheet-title = Key Bindings
+input-context-general = General
+input-context-combat = Combat
+input-context-vehicle = Vehicle
+input-context-free-camera = Free Camera
+input-context-ui = Interface
+input-context-debug = Debug
+input-action-loot-all = Loot All
+input-action-hotbar = Hotbar Slot { $slot }
+input-action-vehicle-get-off = Get Off Vehicle
+input-action-free-camera-forward = Move Forward
+input-action-free-camera-backward = Move Backward
+input-action-free-camera-left = Move Left
+input-action-free-camera-right = Move Right
+input-action-free-camera-down = Move Down
+input-action-free-camera-up = Move Up
+input-action-free-camera-boost = Move Faster
+input-action-toggle-character-info = Character Info
+input-action-toggle-inventory = Inventory
+input-action-toggle-clan = Clan
+input-action-toggle-friends = Friends
+input-action-toggle-calendar = Event Calendar
+input-action-toggle-skill-list = Skills
+input-action-toggle-quest-list = Quests
+input-action-toggle-settings = Settings
+input-action-toggle-session-statistics = Session Statistics
+input-action-toggle-waypoints = Waypoints
+input-action-toggle-personal-store-scanner = Personal Store Scanner
+input-action-toggle-debug-window = Debug Window
+input-action-debug-inspector-pick = Inspect Entity Under Cursor
+input-action-debug-physics-spawn-balls = Spawn Physics Balls
+
+## HUD layout
+
+hud-edit-title = Edit HUD
+hud-edit-hint = Drag elements to move them. The layout is saved for this character.
+hud-edit-hide = Hide
+hud-edit-show = Show
+hud-edit-reset = Reset to Default
+hud-edit-done = Done
+hud-element-chatbox = Chat
+hud-element-minimap = Minimap
+hud-element-hotbar = Hotbar
+hud-element-party = Party
+
+## Calendar
+
+calendar-title = Event Calendar
+calendar-no-schedule = This server does not have an event schedule.
+calendar-no-events = There are no upcoming events.
+calendar-refresh = Refresh
+calendar-fetch-failed = Failed to load the event schedule: { $error }
+calendar-event-starts-in = Starts in { $countdown }
+calendar-event-active = Active now, ends in { $countdown }
+calendar-remind-me = Remind me
+calendar-reminder = { $name } is about to start!
+
+## Clock
+
+clock-real-time = Local time: { $time }
+clock-world-time = Day { $day }, { $time }
+clock-day-in = { $state }, day in { $countdown }
+clock-night-in = { $state }, night in { $countdown }
+clock-open-calendar = Click to open the event calendar
+zone-time-morning = Morning
+zone-time-day = Day
+zone-time-evening = Evening
+zone-time-night = Night
+
+## Crafting
+
+craft-insert-gem-menu = Insert Gem
+craft-insert-gem-title = Insert Gem
+craft-insert-gem-drop-gem = Drop a gem from your inventory here.
+craft-insert-gem-select-item = Select an equipped item with a socket:
+craft-insert-gem-no-items = None of your equipped items have a socket.
+craft-insert-gem-button = Insert
+craft-insert-gem-success = The gem was inserted successfully.
+craft-insert-gem-no-socket = The item does not have a socket.
+craft-insert-gem-socket-full = The socket already has a gem.
+
+## Repair
+
+repair-title = Repair
+repair-using-item = Select an item to repair with the repair tool.
+repair-no-items = None of your equipment needs repairing.
+repair-item-life = Life: { $life }%
+repair-item-price = About { $money } Zuly
+repair-button = Repair
+repair-success = The item was repaired.
+
+## Friends
+
+friends-title = Friends
+friends-name-hint = Character name
+friends-add = Add
+friends-empty = Your friend list is empty.
+friends-nearby = Nearby
+friends-not-nearby = Not nearby
+friends-whisper = Whisper
+friends-remove = Remove
+
+## Inspect character
+
+character-menu-inspect = Inspect
+character-menu-whisper = Whisper
+character-menu-add-friend = Add Friend
+inspect-character-title = { $name }'s Equipment
+inspect-character-level-job = Level { $level } { $job }
+
+## Respawn
+
+respawn-map-title = Revive Points
+respawn-nearest-revive-point = Revive at the nearest revive point
+respawn-other-revive-point = You can only revive at the nearest revive point
+respawn-saved-location = Revive at your saved location
+respawn-set-save-zone = Save this zone as my revive location
+
+## Level up
+
+level-up-banner = Level Up!
+level-up-banner-level = Level { $level }
+
+## Status effects
+
+status-effect-applied = { $name }!
+status-effect-resisted = Resisted
+status-effect-warn-expiry = Warn before expiring
+status-effect-time-remaining = Time remaining: { $time }
+
+## Cast bar
+
+cast-bar-cancel-hint = Press Escape to cancel
+
+## Cooldown alerts
+
+cooldown-alert-ready = { $name } is ready
+cooldown-alert-expiring = { $name } expires in { $seconds }s
+hotbar-alert-when-ready = Alert when ready
+
+## Text to speech
+
+tts-say = { $name } says: { $text }
+tts-shout = { $name } shouts: { $text }
+tts-whisper = { $name } whispers: { $text }
+tts-announce = { $name } announces: { $text }
@@ -0,0 +1,471 @@
+## Connecting
+
+connecting-title = 接続中...
+connecting-login = ログイン中
+connecting-channel = チャンネルに接続中
+connecting-game = ゲームに接続中
+creating-character-title = キャラクター作成中...
+creating-character = キャラクター作成中
+login-profile = プロフィール:
+game-reconnect-title = 接続切断
+game-reconnect-lost = ゲームサーバーとの接続が切断されました。
+game-reconnect-countdown = { $seconds }秒後に再接続します
+game-reconnect-connecting = 再接続中...
+game-reconnect-failed = ゲームサーバーに再接続できませんでした。
+game-reconnect-attempt = { $attempt } / { $max } 回目の試行
+game-reconnect-now = 今すぐ再接続
+game-reconnect-cancel = ログイン画面に戻る
+motd-title = お知らせ
+motd-loading = お知らせを読み込み中...
+motd-fetch-failed = お知らせを読み込めませんでした: { $error }
+motd-patch-notes = パッチノート
+
+## Logout
+
+exit-game-title = ゲーム終了
+exit-game-character-select = キャラクター選択
+exit-game-exit = ゲーム終了
+exit-game-cancel = キャンセル
+logout-title = ログアウト中
+logout-countdown-character-select = { $seconds }秒後にキャラクター選択に戻ります
+logout-countdown-exit = { $seconds }秒後にゲームを終了します
+logout-move-to-cancel = 移動するとログアウトがキャンセルされます。
+logout-cancel = キャンセル
+logout-waiting = ログアウト中...
+logout-cancelled = ログアウトがキャンセルされました。
+logout-cancelled-moved = 移動したためログアウトがキャンセルされました。
+logout-failed = まだログアウトできません。{ $seconds }秒お待ちください。
+
+## Character select
+
+character-delete-title = キャラクター削除
+character-delete-warning = 削除の猶予期間が終わると{ $name }は削除されます。
+character-delete-type-name = 確認のためキャラクター名を入力してください:
+character-delete-confirm = 削除
+character-delete-cancel = キャンセル
+character-delete-undo = 削除を取り消す
+character-delete-countdown = { $countdown }後に削除
+
+## Items
+
+item-drop-money = { $money } Zuly
+item-tooltip-grade-bonus = [グレード{ $grade }:{ $bonuses }]
+item-tooltip-empty-socket = [空きソケット]
+item-tooltip-sell-price = 売却価格: { $money } Zuly
+
+## Inventory
+
+inventory-weight = 重量
+inventory-weight-over-encumbered = 重量オーバーのため歩いてしか移動できません
+
+## NPC store
+
+npc-store-sell-locked-item = ロックされたアイテムは売却できません。
+npc-store-not-enough-money = この取引に必要なZulyが足りません。
+
+## Player info
+
+player-info-out-of-ammo = 弾薬がありません!
+player-info-low-ammo = 弾薬残りわずか: { $quantity }
+player-info-ammo = 弾薬
+player-info-equip-ammo = { $name }を装備 ({ $quantity })
+
+## Session statistics
+
+session-statistics-title = セッション統計
+session-statistics-time = プレイ時間:
+session-statistics-duration = { $hours }時間{ $minutes }分
+session-statistics-xp = 経験値:
+session-statistics-money = Zuly:
+session-statistics-kills = 討伐数:
+session-statistics-per-hour = { $value } / 時間
+session-statistics-kills-by-monster = モンスター別討伐数
+session-statistics-items-looted = 獲得アイテム
+session-statistics-reset = リセット
+session-statistics-export = CSVに書き出す
+session-statistics-export-succeeded = { $path }に書き出しました
+session-statistics-export-failed = 書き出しに失敗しました: { $error }
+
+## Frame profiler
+
+frame-profiler-export-succeeded = { $path }に書き出しました
+frame-profiler-export-failed = 書き出しに失敗しました: { $error }
+
+## Money input
+
+money-input-drop-title = Zulyを捨てる
+money-input-hint = 金額を入力してください。例: 1,500、1.5k、2m
+money-input-amount = { $money } Zuly
+money-input-balance = 所持金: { $money } Zuly
+money-input-empty = 金額を入力してください
+money-input-invalid = 無効な金額です
+money-input-overflow = 金額が大きすぎます
+money-input-insufficient = Zulyが足りません
+money-input-ok = OK
+money-input-max = 最大
+money-input-cancel = キャンセル
+
+## Chat system messages
+
+chat-waiting = お待ちください...
+chat-invalid-target = 無効なターゲットです
+chat-drop-locked-item = ロックされたアイテムは捨てられません。
+chat-assist-no-target = アシストするターゲットを選択してください。
+chat-assist-target-has-no-target = ターゲットは何も選択していません。
+chat-defend-started = { $name }を守っています。
+chat-defend-no-target = 守るターゲットを選択してください。
+chat-defend-stopped = 誰も守っていません。
+chat-afk-set = 離席中: { $message }
+chat-afk-cleared = 離席状態を解除しました。
+chat-afk-auto = 操作がないため離席状態になりました。
+chat-ammo-empty = { $ammo }を使い切りました。
+chat-ammo-low = { $ammo }が残りわずかです。
+chat-encumbrance-warning = 所持重量の上限に近づいています。
+chat-encumbrance-over = 荷物が重すぎて走れません。
+chat-achievement-unlocked = 実績解除: { $name }! 称号「{ $title }」が使えるようになりました。
+chat-character-exported = キャラクターを{ $path }に書き出しました
+chat-character-export-failed = キャラクターの書き出しに失敗しました: { $error }
+chat-level-up = おめでとうございます!レベル{ $level }になりました!
+chat-hunted-monster = { $name }の討伐に成功しました
+chat-ability-increased = 能力値{ $ability }が{ $value }上がりました。
+chat-ability-decreased = 能力値{ $ability }が{ $value }下がりました。
+chat-ability-changed = 能力値{ $ability }が{ $value }に変わりました。
+chat-earned-xp = 経験値を{ $xp }獲得しました。
+chat-earned-item = { $item }を獲得しました。
+chat-earned-money = { $money } Zulyを獲得しました。
+chat-pickup-inventory-full = インベントリがいっぱいのため拾えません。
+chat-pickup-no-permission = 自分のアイテムではないため拾えません。
+chat-learn-skill-already-learnt = スキル習得失敗、すでに習得しています。
+chat-learn-skill-job-requirement = スキル習得失敗、職業の条件を満たしていません。
+chat-learn-skill-skill-requirement = スキル習得失敗、スキルの条件を満たしていません。
+chat-learn-skill-ability-requirement = スキル習得失敗、能力値の条件を満たしていません。
+chat-learn-skill-full = スキル習得失敗、スキルが多すぎます。
+chat-learn-skill-invalid-skill = スキル習得失敗、無効なスキルです。
+chat-learn-skill-skill-points = スキル習得失敗、スキルポイントが足りません。
+chat-level-up-skill-failed = スキルのレベルアップに失敗しました。
+chat-level-up-skill-job-requirement = スキルのレベルアップ失敗、職業の条件を満たしていません。
+chat-level-up-skill-skill-requirement = スキルのレベルアップ失敗、スキルの条件を満たしていません。
+chat-level-up-skill-ability-requirement = スキルのレベルアップ失敗、能力値の条件を満たしていません。
+chat-level-up-skill-money = スキルのレベルアップ失敗、Zulyが足りません。
+chat-level-up-skill-skill-points = スキルのレベルアップ失敗、スキルポイントが足りません。
+chat-npc-store-transaction-failed = 商店の取引に失敗しました。エラー { $error }
+chat-personal-store-transaction-cancelled = 取引に失敗しました。
+chat-personal-store-transaction-sold-out = 取引に失敗しました。アイテムは売り切れました。
+chat-personal-store-transaction-no-more-need = 取引に失敗しました。このアイテムはもう買い取っていません。
+chat-personal-store-purchased = { $quantity ->
+    [1] { $item }を{ $money } Zulyで購入しました。
+   *[other] { $item }を{ $quantity }個、{ $money } Zulyで購入しました。
+}
+chat-personal-store-sold = { $quantity ->
+    [1] { $item }を{ $money } Zulyで売却しました。
+   *[other] { $item }を{ $quantity }個、{ $money } Zulyで売却しました。
+}
+chat-clan-level-up = クランがレベル{ $level }になりました!
+chat-clan-points-increased = クランポイント: { $points } (+{ $change })
+chat-clan-points-decreased = クランポイント: { $points } (-{ $change })
+
+## Party
+
+chat-party-invite-accepted = { $name }がパーティーの招待を受けました。
+chat-party-invite-rejected = { $name }がパーティーの招待を断りました。
+chat-party-joined = { $name }がパーティーに参加しました。
+chat-party-left = パーティーから脱退しました。
+chat-party-member-left = { $name }がパーティーから脱退しました。
+chat-party-member-disconnected = { $name }の接続が切れました。
+chat-party-member-kicked = { $name }がパーティーから追放されました。
+chat-party-member-earned-item = { $name }が{ $item }を獲得しました。
+chat-party-new-leader = { $name }がパーティーリーダーになりました。
+chat-party-rules-changed = パーティーのルールが変更されました。
+chat-party-xp-sharing = 経験値の分配: { $sharing }。
+chat-party-item-sharing = アイテムの分配: { $sharing }。
+party-xp-sharing-equal-share = 均等分配
+party-xp-sharing-distributed-by-level = レベル別分配
+party-item-sharing-equal-loot-distribution = 均等分配
+party-item-sharing-acquisition-order = 取得順
+
+## Settings
+
+settings-title = 設定
+settings-page-sound = サウンド
+settings-page-gameplay = ゲームプレイ
+settings-page-graphics = グラフィック
+settings-page-combat-text = 戦闘テキスト
+settings-page-accessibility = アクセシビリティ
+settings-page-controls = 操作
+settings-enabled = 有効
+settings-off = オフ
+
+settings-sound = サウンド:
+settings-output-device = 出力デバイス:
+settings-output-device-default = システムの既定
+settings-output-device-refresh = 更新
+settings-global-volume = 全体の音量:
+settings-background-music = BGM:
+settings-player-footsteps = プレイヤーの足音:
+settings-other-footsteps = 他の足音:
+settings-player-combat = プレイヤーの戦闘音:
+settings-other-combat = 他の戦闘音:
+settings-npc-sounds = NPCの音:
+
+settings-auto-loot = 自動拾得:
+settings-loot-radius = 拾得範囲:
+settings-loot-min-item-quality = 最低アイテム品質:
+settings-loot-pickup = 拾うもの:
+settings-loot-money = Zuly
+settings-loot-equipment = 装備
+settings-loot-consumables = 消耗品
+settings-loot-materials = 材料
+settings-loot-hint = Zキーで周囲のアイテムをすべて拾います。
+settings-auto-away = 自動離席:
+settings-auto-away-timeout = 離席までの時間 (分):
+settings-while-away = 離席中:
+settings-auto-reply = ささやきに自動返信
+settings-while-unfocused = 非アクティブ時:
+settings-low-power-unfocused = フレームレートを下げる
+settings-mute-unfocused = 消音
+settings-afk-hint = /afk [メッセージ] で離席状態を切り替えます。
+settings-level-up = レベルアップ:
+settings-level-up-effect = エフェクト
+settings-level-up-fanfare = ファンファーレ
+settings-level-up-screen-flash = 画面フラッシュ
+settings-level-up-banner = バナー
+settings-cooldown-alerts = クールダウン通知:
+settings-cooldown-alerts-hotbar-pulse = ホットバーのスロットを点滅
+settings-cooldown-alerts-toast = メッセージを表示
+settings-cooldown-alerts-sound = サウンドを再生
+settings-cooldown-alerts-sound-id = サウンドID
+settings-cooldown-alerts-test = テスト
+settings-cooldown-alerts-warning = バフ終了の警告 (秒):
+settings-cooldown-alerts-hint = ホットバーのスキルやバフのアイコンを右クリックして通知を選択します。
+settings-hud-layout = HUDレイアウト:
+settings-hud-edit = HUDを編集
+
+settings-window-mode = ウィンドウモード:
+settings-window-mode-windowed = ウィンドウ
+settings-window-mode-borderless = ボーダーレスフルスクリーン
+settings-window-mode-exclusive = 排他的フルスクリーン
+settings-monitor = モニター:
+settings-monitor-automatic = 自動
+settings-monitor-index = モニター { $index }
+settings-resolution = 解像度:
+settings-resolution-native = ネイティブ
+settings-minimize-on-focus-loss = Alt-Tabで最小化:
+settings-vsync = 垂直同期:
+settings-minimize-never = しない
+settings-minimize-any-fullscreen = すべてのフルスクリーン
+settings-quality-preset = 画質プリセット:
+settings-quality-low = 低
+settings-quality-medium = 中
+settings-quality-high = 高
+settings-quality-ultra = 最高
+settings-quality-custom = カスタム
+settings-shadows = 影:
+settings-draw-distance = 描画距離:
+settings-particle-density = パーティクル密度:
+settings-texture-quality = テクスチャ品質:
+settings-applies-after-restart = (再起動後に適用)
+settings-anti-aliasing = アンチエイリアス:
+settings-msaa-2x = MSAA 2x
+settings-msaa-4x = MSAA 4x
+settings-benchmark-presets = プリセットのベンチマーク
+settings-benchmark-measuring = { $preset }プリセットを測定中...
+settings-benchmark-preset = プリセット
+settings-benchmark-frame-time = フレーム時間
+settings-benchmark-fps = FPS
+settings-item-grade-effects = アイテムグレードのエフェクト:
+settings-item-grade-effects-hint = 0にすると高グレード装備の光を無効にします。
+settings-item-drop-effects = ドロップアイテムの光の柱:
+settings-name-tags-character = キャラクターの名前:
+settings-name-tags-npc = NPCの名前:
+settings-name-tags-monster = モンスターの名前:
+settings-name-tags-always-show = 常に表示
+settings-name-tags-font-size = フォントサイズ
+settings-name-tags-combat-healthbars = 戦闘中のHPバー:
+settings-name-tags-chat-bubbles = 吹き出し:
+
+settings-combat-text-dealt = 与ダメージ:
+settings-combat-text-critical = クリティカル:
+settings-combat-text-taken = 被ダメージ:
+settings-combat-text-miss = ミス:
+settings-combat-text-heal = 回復:
+settings-combat-text-scale = サイズ
+settings-combat-text-merge = 小さなダメージをまとめる:
+settings-combat-text-merge-max-damage = 最大ダメージ:
+settings-combat-text-merge-window = まとめる間隔 (ms):
+settings-combat-text-status-effects = 状態効果:
+settings-combat-text-buff = バフ:
+settings-combat-text-debuff = デバフ:
+settings-combat-text-resisted = 抵抗:
+
+settings-effect-intensity = エフェクトの強さ:
+settings-bloom-intensity = ブルームの強さ:
+settings-reduce-flashing = 点滅を抑える:
+settings-effect-intensity-hint = エフェクトの強さは変更後に発生したスキルとイベントのエフェクトに適用されます。
+settings-text-to-speech = 読み上げ:
+settings-text-to-speech-read = 読み上げる内容:
+settings-tts-say = チャット
+settings-tts-shout = シャウト
+settings-tts-whisper = ささやき
+settings-tts-announce = お知らせ
+settings-tts-system = システムメッセージ
+settings-tts-quest = クエストメッセージ
+settings-tts-dialog = NPCの会話
+settings-tts-message-box = メッセージボックス
+settings-controls-press-key = キーを押してください...
+settings-controls-binding-tooltip = クリックで変更、右クリックで解除します。Escでキャンセルします。
+settings-controls-conflict = { $actions }にも割り当てられています
+settings-controls-resolve = 他の割り当てを解除
+settings-controls-resolve-tooltip = 他の操作からこのキーを外します
+settings-controls-reset = 初期設定に戻す
+settings-controls-export-cheat-sheet = キー一覧を書き出す
+settings-controls-cheat-sheet-exported = キー一覧を{ $path }に書き出しました
+settings-controls-cheat-sheet-failed = キー一覧の書き出しに失敗しました: { $error }
+
+## Key bindings
+
+input-cheat-sheet-title = キー割り当て
+input-context-general = 一般
+input-context-combat = 戦闘
+input-context-vehicle = 乗り物
+input-context-free-camera = フリーカメラ
+input-context-ui = インターフェース
+input-context-debug = デバッグ
+input-action-loot-all = すべて拾う
+input-action-hotbar = ホットバースロット { $slot }
+input-action-vehicle-get-off = 乗り物から降りる
+input-action-free-camera-forward = 前へ移動
+input-action-free-camera-backward = 後ろへ移動
+input-action-free-camera-left = 左へ移動
+input-action-free-camera-right = 右へ移動
+input-action-free-camera-down = 下へ移動
+input-action-free-camera-up = 上へ移動
+input-action-free-camera-boost = 高速移動
+input-action-toggle-character-info = キャラクター情報
+input-action-toggle-inventory = インベントリ
+input-action-toggle-clan = クラン
+input-action-toggle-friends = フレンド
+input-action-toggle-calendar = イベントカレンダー
+input-action-toggle-skill-list = スキル
+input-action-toggle-quest-list = クエスト
+input-action-toggle-settings = 設定
+input-action-toggle-session-statistics = セッション統計
+input-action-toggle-waypoints = ウェイポイント
+input-action-toggle-personal-store-scanner = 個人商店検索
+input-action-toggle-debug-window = デバッグウィンドウ
+input-action-debug-inspector-pick = カーソル下のエンティティを調べる
+input-action-debug-physics-spawn-balls = 物理ボールを生成
+
+## HUD layout
+
+hud-edit-title = HUDを編集
+hud-edit-hint = 要素をドラッグして移動します。レイアウトはこのキャラクターに保存されます。
+hud-edit-hide = 非表示
+hud-edit-show = 表示
+hud-edit-reset = 初期設定に戻す
+hud-edit-done = 完了
+hud-element-chatbox = チャット
+hud-element-minimap = ミニマップ
+hud-element-hotbar = ホットバー
+hud-element-party = パーティー
+
+## Calendar
+
+calendar-title = イベントカレンダー
+calendar-no-schedule = このサーバーにはイベントの予定がありません。
+calendar-no-events = 予定されているイベントはありません。
+calendar-refresh = 更新
+calendar-fetch-failed = イベントの予定を読み込めませんでした: { $error }
+calendar-event-starts-in = { $countdown }後に開始
+calendar-event-active = 開催中、{ $countdown }後に終了
+calendar-remind-me = 通知する
+calendar-reminder = { $name }がまもなく始まります!
+
+## Clock
+
+clock-real-time = 現地時刻: { $time }
+clock-world-time = { $day }日目、{ $time }
+clock-day-in = { $state }、{ $countdown }後に昼
+clock-night-in = { $state }、{ $countdown }後に夜
+clock-open-calendar = クリックでイベントカレンダーを開きます
+zone-time-morning = 朝
+zone-time-day = 昼
+zone-time-evening = 夕方
+zone-time-night = 夜
+
+## Crafting
+
+craft-insert-gem-menu = 宝石をはめる
+craft-insert-gem-title = 宝石をはめる
+craft-insert-gem-drop-gem = インベントリから宝石をここに置いてください。
+craft-insert-gem-select-item = ソケットのある装備中のアイテムを選択してください:
+craft-insert-gem-no-items = 装備中のアイテムにソケットのあるものがありません。
+craft-insert-gem-button = はめる
+craft-insert-gem-success = 宝石をはめました。
+craft-insert-gem-no-socket = このアイテムにはソケットがありません。
+craft-insert-gem-socket-full = ソケットにはすでに宝石がはまっています。
+
+## Repair
+
+repair-title = 修理
+repair-using-item = 修理道具で修理するアイテムを選択してください。
+repair-no-items = 修理が必要な装備はありません。
+repair-item-life = 寿命: { $life }%
+repair-item-price = 約{ $money } Zuly
+repair-button = 修理
+repair-success = アイテムを修理しました。
+
+## Friends
+
+friends-title = フレンド
+friends-name-hint = キャラクター名
+friends-add = 追加
+friends-empty = フレンドリストは空です。
+friends-nearby = 近く
+friends-not-nearby = 近くにいない
+friends-whisper = ささやき
+friends-remove = 削除
+
+## Inspect character
+
+character-menu-inspect = 調べる
+character-menu-whisper = ささやき
+character-menu-add-friend = フレンドに追加
+inspect-character-title = { $name }の装備
+inspect-character-level-job = レベル{ $level } { $job }
+
+## Respawn
+
+respawn-map-title = 復活地点
+respawn-nearest-revive-point = 最寄りの復活地点で復活
+respawn-other-revive-point = 最寄りの復活地点でのみ復活できます
+respawn-saved-location = 保存した場所で復活
+respawn-set-save-zone = このゾーンを復活場所として保存
+
+## Level up
+
+level-up-banner = レベルアップ!
+level-up-banner-level = レベル{ $level }
+
+## Status effects
+
+status-effect-applied = { $name }!
+status-effect-resisted = 抵抗
+status-effect-warn-expiry = 終了前に警告
+status-effect-time-remaining = 残り時間: { $time }
+
+## Cast bar
+
+cast-bar-cancel-hint = Escでキャンセル
+
+## Cooldown alerts
+
+cooldown-alert-ready = { $name }が使用可能です
+cooldown-alert-expiring = { $name }は{ $seconds }秒後に終了します
+hotbar-alert-when-ready = 使用可能になったら通知
+
+## Text to speech
+
+tts-say = { $name }: { $text }
+tts-shout = { $name }のシャウト: { $text }
+tts-whisper = { $name }のささやき: { $text }
+tts-announce = { $name }のお知らせ: { $text }
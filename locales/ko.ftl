@@ -0,0 +1,471 @@
+## Connecting
+
+connecting-title = 접속 중...
+connecting-login = 로그인 중
+connecting-channel = 채널에 접속 중
+connecting-game = 게임에 접속 중
+creating-character-title = 캐릭터 생성 중...
+creating-character = 캐릭터 생성 중
+login-profile = 프로필:
+game-reconnect-title = 연결 끊김
+game-reconnect-lost = 게임 서버와의 연결이 끊어졌습니다.
+game-reconnect-countdown = { $seconds }초 후에 다시 접속합니다
+game-reconnect-connecting = 다시 접속 중...
+game-reconnect-failed = 게임 서버에 다시 접속할 수 없습니다.
+game-reconnect-attempt = { $max }번 중 { $attempt }번째 시도
+game-reconnect-now = 지금 다시 접속
+game-reconnect-cancel = 로그인 화면으로
+motd-title = 소식
+motd-loading = 소식을 불러오는 중...
+motd-fetch-failed = 소식을 불러올 수 없습니다: { $error }
+motd-patch-notes = 패치 노트
+
+## Logout
+
+exit-game-title = 게임 종료
+exit-game-character-select = 캐릭터 선택
+exit-game-exit = 게임 종료
+exit-game-cancel = 취소
+logout-title = 로그아웃 중
+logout-countdown-character-select = { $seconds }초 후에 캐릭터 선택 화면으로 돌아갑니다
+logout-countdown-exit = { $seconds }초 후에 게임을 종료합니다
+logout-move-to-cancel = 이동하면 로그아웃이 취소됩니다.
+logout-cancel = 취소
+logout-waiting = 로그아웃 중...
+logout-cancelled = 로그아웃이 취소되었습니다.
+logout-cancelled-moved = 이동하여 로그아웃이 취소되었습니다.
+logout-failed = 아직 로그아웃할 수 없습니다. { $seconds }초 기다려 주십시오.
+
+## Character select
+
+character-delete-title = 캐릭터 삭제
+character-delete-warning = 삭제 대기 시간이 끝나면 { $name } 캐릭터가 삭제됩니다.
+character-delete-type-name = 확인을 위해 캐릭터 이름을 입력하십시오:
+character-delete-confirm = 삭제
+character-delete-cancel = 취소
+character-delete-undo = 삭제 취소
+character-delete-countdown = { $countdown } 후 삭제
+
+## Items
+
+item-drop-money = { $money } 줄리
+item-tooltip-grade-bonus = [{ $grade }등급:{ $bonuses }]
+item-tooltip-empty-socket = [빈 소켓]
+item-tooltip-sell-price = 판매 가격: { $money } 줄리
+
+## Inventory
+
+inventory-weight = 무게
+inventory-weight-over-encumbered = 무게 초과로 걸어서만 이동할 수 있습니다
+
+## NPC store
+
+npc-store-sell-locked-item = 잠긴 아이템은 판매할 수 없습니다.
+npc-store-not-enough-money = 거래에 필요한 줄리가 부족합니다.
+
+## Player info
+
+player-info-out-of-ammo = 소모탄이 없습니다!
+player-info-low-ammo = 소모탄 부족: { $quantity }
+player-info-ammo = 소모탄
+player-info-equip-ammo = { $name } 장착 ({ $quantity })
+
+## Session statistics
+
+session-statistics-title = 세션 통계
+session-statistics-time = 플레이 시간:
+session-statistics-duration = { $hours }시간 { $minutes }분
+session-statistics-xp = 경험치:
+session-statistics-money = 줄리:
+session-statistics-kills = 처치:
+session-statistics-per-hour = 시간당 { $value }
+session-statistics-kills-by-monster = 몬스터별 처치
+session-statistics-items-looted = 획득한 아이템
+session-statistics-reset = 초기화
+session-statistics-export = CSV 내보내기
+session-statistics-export-succeeded = { $path }(으)로 내보냈습니다
+session-statistics-export-failed = 내보내기 실패: { $error }
+
+## Frame profiler
+
+frame-profiler-export-succeeded = { $path }(으)로 내보냈습니다
+frame-profiler-export-failed = 내보내기 실패: { $error }
+
+## Money input
+
+money-input-drop-title = 줄리 버리기
+money-input-hint = 금액을 입력하십시오. 예: 1,500 또는 1.5k 또는 2m
+money-input-amount = { $money } 줄리
+money-input-balance = 잔액: { $money } 줄리
+money-input-empty = 금액을 입력하십시오
+money-input-invalid = 잘못된 금액입니다
+money-input-overflow = 금액이 너무 큽니다
+money-input-insufficient = 줄리가 부족합니다
+money-input-ok = 확인
+money-input-max = 최대
+money-input-cancel = 취소
+
+## Chat system messages
+
+chat-waiting = 기다리는 중...
+chat-invalid-target = 잘못된 대상입니다
+chat-drop-locked-item = 잠긴 아이템은 버릴 수 없습니다.
+chat-assist-no-target = 지원할 대상을 선택해야 합니다.
+chat-assist-target-has-no-target = 대상이 선택한 대상이 없습니다.
+chat-defend-started = 이제 { $name }님을 방어합니다.
+chat-defend-no-target = 방어할 대상을 선택해야 합니다.
+chat-defend-stopped = 더 이상 아무도 방어하지 않습니다.
+chat-afk-set = 자리 비움 상태입니다: { $message }
+chat-afk-cleared = 자리 비움 상태가 해제되었습니다.
+chat-afk-auto = 활동이 없어 자리 비움 상태가 되었습니다.
+chat-ammo-empty = { $ammo }을(를) 모두 사용했습니다.
+chat-ammo-low = { $ammo }이(가) 얼마 남지 않았습니다.
+chat-encumbrance-warning = 최대 소지 무게에 가까워지고 있습니다.
+chat-encumbrance-over = 소지품이 너무 무거워 더 이상 달릴 수 없습니다.
+chat-achievement-unlocked = 업적 달성: { $name }! 이제 "{ $title }" 칭호를 사용할 수 있습니다.
+chat-character-exported = 캐릭터를 { $path }(으)로 내보냈습니다
+chat-character-export-failed = 캐릭터를 내보내지 못했습니다: { $error }
+chat-level-up = 축하합니다! 레벨 { $level }이(가) 되었습니다!
+chat-hunted-monster = { $name } 사냥에 성공했습니다
+chat-ability-increased = { $ability } 능력치가 { $value } 증가했습니다.
+chat-ability-decreased = { $ability } 능력치가 { $value } 감소했습니다.
+chat-ability-changed = { $ability } 능력치가 { $value }(으)로 변경되었습니다.
+chat-earned-xp = 경험치 { $xp }을(를) 획득했습니다.
+chat-earned-item = { $item }을(를) 획득했습니다.
+chat-earned-money = { $money } 줄리를 획득했습니다.
+chat-pickup-inventory-full = 인벤토리가 가득 차서 아이템을 주울 수 없습니다.
+chat-pickup-no-permission = 자신의 아이템이 아니어서 주울 수 없습니다.
+chat-learn-skill-already-learnt = 스킬 습득 실패, 이미 알고 있는 스킬입니다.
+chat-learn-skill-job-requirement = 스킬 습득 실패, 직업 조건을 만족하지 않습니다.
+chat-learn-skill-skill-requirement = 스킬 습득 실패, 선행 스킬 조건을 만족하지 않습니다.
+chat-learn-skill-ability-requirement = 스킬 습득 실패, 능력치 조건을 만족하지 않습니다.
+chat-learn-skill-full = 스킬 습득 실패, 스킬이 너무 많습니다.
+chat-learn-skill-invalid-skill = 스킬 습득 실패, 잘못된 스킬입니다.
+chat-learn-skill-skill-points = 스킬 습득 실패, 스킬 포인트가 부족합니다.
+chat-level-up-skill-failed = 스킬 레벨업에 실패했습니다.
+chat-level-up-skill-job-requirement = 스킬 레벨업 실패, 직업 조건을 만족하지 않습니다.
+chat-level-up-skill-skill-requirement = 스킬 레벨업 실패, 선행 스킬 조건을 만족하지 않습니다.
+chat-level-up-skill-ability-requirement = 스킬 레벨업 실패, 능력치 조건을 만족하지 않습니다.
+chat-level-up-skill-money = 스킬 레벨업 실패, 줄리가 부족합니다.
+chat-level-up-skill-skill-points = 스킬 레벨업 실패, 스킬 포인트가 부족합니다.
+chat-npc-store-transaction-failed = 상점 거래에 실패했습니다. 오류 { $error }
+chat-personal-store-transaction-cancelled = 거래에 실패했습니다.
+chat-personal-store-transaction-sold-out = 거래에 실패했습니다. 아이템이 모두 팔렸습니다.
+chat-personal-store-transaction-no-more-need = 거래에 실패했습니다. 더 이상 구매하지 않는 아이템입니다.
+chat-personal-store-purchased = { $quantity ->
+    [1] { $item }을(를) { $money } 줄리에 구매했습니다.
+   *[other] { $item } { $quantity }개를 { $money } 줄리에 구매했습니다.
+}
+chat-personal-store-sold = { $quantity ->
+    [1] { $item }을(를) { $money } 줄리에 판매했습니다.
+   *[other] { $item } { $quantity }개를 { $money } 줄리에 판매했습니다.
+}
+chat-clan-level-up = 클랜이 레벨 { $level }에 도달했습니다!
+chat-clan-points-increased = 클랜 포인트: { $points } (+{ $change })
+chat-clan-points-decreased = 클랜 포인트: { $points } (-{ $change })
+
+## Party
+
+chat-party-invite-accepted = { $name }님이 파티 초대를 수락했습니다.
+chat-party-invite-rejected = { $name }님이 파티 초대를 거절했습니다.
+chat-party-joined = { $name }님이 파티에 참가했습니다.
+chat-party-left = 파티에서 탈퇴했습니다.
+chat-party-member-left = { $name }님이 파티에서 탈퇴했습니다.
+chat-party-member-disconnected = { $name }님의 접속이 끊어졌습니다.
+chat-party-member-kicked = { $name }님이 파티에서 추방되었습니다.
+chat-party-member-earned-item = { $name }님이 { $item }을(를) 획득했습니다.
+chat-party-new-leader = { $name }님이 파티장이 되었습니다.
+chat-party-rules-changed = 파티 규칙이 변경되었습니다.
+chat-party-xp-sharing = 경험치 분배: { $sharing }.
+chat-party-item-sharing = 아이템 분배: { $sharing }.
+party-xp-sharing-equal-share = 균등 분배
+party-xp-sharing-distributed-by-level = 레벨별 분배
+party-item-sharing-equal-loot-distribution = 균등 분배
+party-item-sharing-acquisition-order = 획득 순서
+
+## Settings
+
+settings-title = 설정
+settings-page-sound = 사운드
+settings-page-gameplay = 게임플레이
+settings-page-graphics = 그래픽
+settings-page-combat-text = 전투 텍스트
+settings-page-accessibility = 접근성
+settings-page-controls = 조작
+settings-enabled = 사용
+settings-off = 끄기
+
+settings-sound = 사운드:
+settings-output-device = 출력 장치:
+settings-output-device-default = 시스템 기본값
+settings-output-device-refresh = 새로 고침
+settings-global-volume = 전체 볼륨:
+settings-background-music = 배경 음악:
+settings-player-footsteps = 플레이어 발소리:
+settings-other-footsteps = 다른 발소리:
+settings-player-combat = 플레이어 전투:
+settings-other-combat = 다른 전투:
+settings-npc-sounds = NPC 소리:
+
+settings-auto-loot = 자동 줍기:
+settings-loot-radius = 줍기 범위:
+settings-loot-min-item-quality = 최소 아이템 품질:
+settings-loot-pickup = 줍기:
+settings-loot-money = 줄리
+settings-loot-equipment = 장비
+settings-loot-consumables = 소모품
+settings-loot-materials = 재료
+settings-loot-hint = Z 키를 눌러 주변의 모든 아이템을 줍습니다.
+settings-auto-away = 자동 자리 비움:
+settings-auto-away-timeout = 자리 비움 전환 시간 (분):
+settings-while-away = 자리 비움 중:
+settings-auto-reply = 귓속말에 자동 응답
+settings-while-unfocused = 비활성 창일 때:
+settings-low-power-unfocused = 프레임 속도 낮추기
+settings-mute-unfocused = 소리 끄기
+settings-afk-hint = /afk [메시지]를 입력하여 자리 비움 상태를 전환합니다.
+settings-level-up = 레벨업:
+settings-level-up-effect = 이펙트
+settings-level-up-fanfare = 팡파르
+settings-level-up-screen-flash = 화면 번쩍임
+settings-level-up-banner = 배너
+settings-cooldown-alerts = 재사용 알림:
+settings-cooldown-alerts-hotbar-pulse = 단축키 슬롯 깜빡임
+settings-cooldown-alerts-toast = 메시지 표시
+settings-cooldown-alerts-sound = 소리 재생
+settings-cooldown-alerts-sound-id = 사운드 ID
+settings-cooldown-alerts-test = 테스트
+settings-cooldown-alerts-warning = 버프 만료 경고 (초):
+settings-cooldown-alerts-hint = 단축키 스킬이나 버프 아이콘을 오른쪽 클릭하여 알림을 선택합니다.
+settings-hud-layout = HUD 배치:
+settings-hud-edit = HUD 편집
+
+settings-window-mode = 창 모드:
+settings-window-mode-windowed = 창 모드
+settings-window-mode-borderless = 테두리 없는 전체 화면
+settings-window-mode-exclusive = 전용 전체 화면
+settings-monitor = 모니터:
+settings-monitor-automatic = 자동
+settings-monitor-index = 모니터 { $index }
+settings-resolution = 해상도:
+settings-resolution-native = 기본 해상도
+settings-minimize-on-focus-loss = Alt-Tab 시 최소화:
+settings-vsync = 수직 동기화:
+settings-minimize-never = 안 함
+settings-minimize-any-fullscreen = 모든 전체 화면
+settings-quality-preset = 품질 사전 설정:
+settings-quality-low = 낮음
+settings-quality-medium = 중간
+settings-quality-high = 높음
+settings-quality-ultra = 최고
+settings-quality-custom = 사용자 지정
+settings-shadows = 그림자:
+settings-draw-distance = 시야 거리:
+settings-particle-density = 파티클 밀도:
+settings-texture-quality = 텍스처 품질:
+settings-applies-after-restart = (재시작 후 적용)
+settings-anti-aliasing = 안티 앨리어싱:
+settings-msaa-2x = MSAA 2x
+settings-msaa-4x = MSAA 4x
+settings-benchmark-presets = 사전 설정 벤치마크
+settings-benchmark-measuring = { $preset } 사전 설정 측정 중...
+settings-benchmark-preset = 사전 설정
+settings-benchmark-frame-time = 프레임 시간
+settings-benchmark-fps = FPS
+settings-item-grade-effects = 아이템 등급 이펙트:
+settings-item-grade-effects-hint = 0으로 설정하면 고등급 장비의 빛을 끕니다.
+settings-item-drop-effects = 아이템 드롭 빛기둥:
+settings-name-tags-character = 캐릭터 이름표:
+settings-name-tags-npc = NPC 이름표:
+settings-name-tags-monster = 몬스터 이름표:
+settings-name-tags-always-show = 항상 표시
+settings-name-tags-font-size = 글꼴 크기
+settings-name-tags-combat-healthbars = 전투 체력 바:
+settings-name-tags-chat-bubbles = 말풍선:
+
+settings-combat-text-dealt = 준 피해:
+settings-combat-text-critical = 치명타:
+settings-combat-text-taken = 받은 피해:
+settings-combat-text-miss = 빗나감:
+settings-combat-text-heal = 회복:
+settings-combat-text-scale = 크기
+settings-combat-text-merge = 작은 피해 합치기:
+settings-combat-text-merge-max-damage = 최대 피해:
+settings-combat-text-merge-window = 합치기 간격 (ms):
+settings-combat-text-status-effects = 상태 효과:
+settings-combat-text-buff = 버프:
+settings-combat-text-debuff = 디버프:
+settings-combat-text-resisted = 저항:
+
+settings-effect-intensity = 이펙트 강도:
+settings-bloom-intensity = 블룸 강도:
+settings-reduce-flashing = 번쩍임 줄이기:
+settings-effect-intensity-hint = 이펙트 강도는 변경 후 생성되는 스킬과 이벤트 이펙트에 적용됩니다.
+settings-text-to-speech = 텍스트 음성 변환:
+settings-text-to-speech-read = 소리 내어 읽기:
+settings-tts-say = 대화
+settings-tts-shout = 외치기
+settings-tts-whisper = 귓속말
+settings-tts-announce = 공지
+settings-tts-system = 시스템 메시지
+settings-tts-quest = 퀘스트 메시지
+settings-tts-dialog = NPC 대화
+settings-tts-message-box = 메시지 상자
+settings-controls-press-key = 키를 누르십시오...
+settings-controls-binding-tooltip = 클릭하여 변경하고 오른쪽 클릭하여 해제합니다. Esc 키로 취소합니다.
+settings-controls-conflict = { $actions }에도 지정되어 있습니다
+settings-controls-resolve = 다른 지정 해제
+settings-controls-resolve-tooltip = 다른 동작에서 이 키를 제거합니다
+settings-controls-reset = 기본값으로 초기화
+settings-controls-export-cheat-sheet = 단축키 목록 내보내기
+settings-controls-cheat-sheet-exported = 단축키 목록을 { $path }(으)로 내보냈습니다
+settings-controls-cheat-sheet-failed = 단축키 목록을 내보내지 못했습니다: { $error }
+
+## Key bindings
+
+input-cheat-sheet-title = 단축키
+input-context-general = 일반
+input-context-combat = 전투
+input-context-vehicle = 탈것
+input-context-free-camera = 자유 카메라
+input-context-ui = 인터페이스
+input-context-debug = 디버그
+input-action-loot-all = 모두 줍기
+input-action-hotbar = 단축키 슬롯 { $slot }
+input-action-vehicle-get-off = 탈것에서 내리기
+input-action-free-camera-forward = 앞으로 이동
+input-action-free-camera-backward = 뒤로 이동
+input-action-free-camera-left = 왼쪽으로 이동
+input-action-free-camera-right = 오른쪽으로 이동
+input-action-free-camera-down = 아래로 이동
+input-action-free-camera-up = 위로 이동
+input-action-free-camera-boost = 빠르게 이동
+input-action-toggle-character-info = 캐릭터 정보
+input-action-toggle-inventory = 인벤토리
+input-action-toggle-clan = 클랜
+input-action-toggle-friends = 친구
+input-action-toggle-calendar = 이벤트 일정
+input-action-toggle-skill-list = 스킬
+input-action-toggle-quest-list = 퀘스트
+input-action-toggle-settings = 설정
+input-action-toggle-session-statistics = 세션 통계
+input-action-toggle-waypoints = 웨이포인트
+input-action-toggle-personal-store-scanner = 개인 상점 검색
+input-action-toggle-debug-window = 디버그 창
+input-action-debug-inspector-pick = 커서 아래 엔티티 검사
+input-action-debug-physics-spawn-balls = 물리 공 생성
+
+## HUD layout
+
+hud-edit-title = HUD 편집
+hud-edit-hint = 요소를 끌어서 이동합니다. 배치는 이 캐릭터에 저장됩니다.
+hud-edit-hide = 숨기기
+hud-edit-show = 표시
+hud-edit-reset = 기본값으로 초기화
+hud-edit-done = 완료
+hud-element-chatbox = 채팅
+hud-element-minimap = 미니맵
+hud-element-hotbar = 단축키
+hud-element-party = 파티
+
+## Calendar
+
+calendar-title = 이벤트 일정
+calendar-no-schedule = 이 서버에는 이벤트 일정이 없습니다.
+calendar-no-events = 예정된 이벤트가 없습니다.
+calendar-refresh = 새로 고침
+calendar-fetch-failed = 이벤트 일정을 불러오지 못했습니다: { $error }
+calendar-event-starts-in = { $countdown } 후 시작
+calendar-event-active = 진행 중, { $countdown } 후 종료
+calendar-remind-me = 알림 받기
+calendar-reminder = { $name }이(가) 곧 시작됩니다!
+
+## Clock
+
+clock-real-time = 현지 시간: { $time }
+clock-world-time = { $day }일째, { $time }
+clock-day-in = { $state }, { $countdown } 후 낮
+clock-night-in = { $state }, { $countdown } 후 밤
+clock-open-calendar = 클릭하여 이벤트 일정을 엽니다
+zone-time-morning = 아침
+zone-time-day = 낮
+zone-time-evening = 저녁
+zone-time-night = 밤
+
+## Crafting
+
+craft-insert-gem-menu = 보석 박기
+craft-insert-gem-title = 보석 박기
+craft-insert-gem-drop-gem = 인벤토리에서 보석을 여기에 놓으십시오.
+craft-insert-gem-select-item = 소켓이 있는 장착 아이템을 선택하십시오:
+craft-insert-gem-no-items = 장착한 아이템 중 소켓이 있는 아이템이 없습니다.
+craft-insert-gem-button = 박기
+craft-insert-gem-success = 보석을 박았습니다.
+craft-insert-gem-no-socket = 소켓이 없는 아이템입니다.
+craft-insert-gem-socket-full = 이미 보석이 박혀 있습니다.
+
+## Repair
+
+repair-title = 수리
+repair-using-item = 수리 도구로 수리할 아이템을 선택하십시오.
+repair-no-items = 수리가 필요한 장비가 없습니다.
+repair-item-life = 수명: { $life }%
+repair-item-price = 약 { $money } 줄리
+repair-button = 수리
+repair-success = 아이템을 수리했습니다.
+
+## Friends
+
+friends-title = 친구
+friends-name-hint = 캐릭터 이름
+friends-add = 추가
+friends-empty = 친구 목록이 비어 있습니다.
+friends-nearby = 근처
+friends-not-nearby = 근처에 없음
+friends-whisper = 귓속말
+friends-remove = 삭제
+
+## Inspect character
+
+character-menu-inspect = 살펴보기
+character-menu-whisper = 귓속말
+character-menu-add-friend = 친구 추가
+inspect-character-title = { $name }의 장비
+inspect-character-level-job = 레벨 { $level } { $job }
+
+## Respawn
+
+respawn-map-title = 부활 지점
+respawn-nearest-revive-point = 가장 가까운 부활 지점에서 부활
+respawn-other-revive-point = 가장 가까운 부활 지점에서만 부활할 수 있습니다
+respawn-saved-location = 저장한 위치에서 부활
+respawn-set-save-zone = 이 지역을 부활 위치로 저장
+
+## Level up
+
+level-up-banner = 레벨 업!
+level-up-banner-level = 레벨 { $level }
+
+## Status effects
+
+status-effect-applied = { $name }!
+status-effect-resisted = 저항
+status-effect-warn-expiry = 만료 전에 경고
+status-effect-time-remaining = 남은 시간: { $time }
+
+## Cast bar
+
+cast-bar-cancel-hint = Esc 키를 눌러 취소
+
+## Cooldown alerts
+
+cooldown-alert-ready = { $name } 사용 가능
+cooldown-alert-expiring = { $name } { $seconds }초 후 만료
+hotbar-alert-when-ready = 사용 가능할 때 알림
+
+## Text to speech
+
+tts-say = { $name }: { $text }
+tts-shout = { $name }의 외침: { $text }
+tts-whisper = { $name }의 귓속말: { $text }
+tts-announce = { $name }의 공지: { $text }
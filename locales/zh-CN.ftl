@@ -0,0 +1,471 @@
+## Connecting
+
+connecting-title = 正在连接...
+connecting-login = 正在登录
+connecting-channel = 正在连接频道
+connecting-game = 正在连接游戏
+creating-character-title = 正在创建角色...
+creating-character = 正在创建角色
+login-profile = 配置:
+game-reconnect-title = 连接中断
+game-reconnect-lost = 与游戏服务器的连接已中断。
+game-reconnect-countdown = { $seconds } 秒后重新连接
+game-reconnect-connecting = 正在重新连接...
+game-reconnect-failed = 无法重新连接到游戏服务器。
+game-reconnect-attempt = 第 { $attempt } 次尝试,共 { $max } 次
+game-reconnect-now = 立即重新连接
+game-reconnect-cancel = 返回登录
+motd-title = 新闻
+motd-loading = 正在加载新闻...
+motd-fetch-failed = 无法加载新闻:{ $error }
+motd-patch-notes = 更新说明
+
+## Logout
+
+exit-game-title = 退出游戏
+exit-game-character-select = 选择角色
+exit-game-exit = 退出游戏
+exit-game-cancel = 取消
+logout-title = 正在登出
+logout-countdown-character-select = { $seconds } 秒后返回角色选择
+logout-countdown-exit = { $seconds } 秒后退出游戏
+logout-move-to-cancel = 移动将取消登出。
+logout-cancel = 取消
+logout-waiting = 正在登出...
+logout-cancelled = 已取消登出。
+logout-cancelled-moved = 由于你移动了,登出已取消。
+logout-failed = 暂时无法登出,请等待 { $seconds } 秒。
+
+## Character select
+
+character-delete-title = 删除角色
+character-delete-warning = 删除倒计时结束后将删除 { $name }。
+character-delete-type-name = 请输入角色名称以确认:
+character-delete-confirm = 删除
+character-delete-cancel = 取消
+character-delete-undo = 取消删除
+character-delete-countdown = { $countdown } 后删除
+
+## Items
+
+item-drop-money = { $money } Zuly
+item-tooltip-grade-bonus = [等级 { $grade }:{ $bonuses }]
+item-tooltip-empty-socket = [空插槽]
+item-tooltip-sell-price = 出售价格:{ $money } Zuly
+
+## Inventory
+
+inventory-weight = 重量
+inventory-weight-over-encumbered = 负重过大,只能步行
+
+## NPC store
+
+npc-store-sell-locked-item = 无法出售已锁定的物品。
+npc-store-not-enough-money = 你的 Zuly 不足以完成此交易。
+
+## Player info
+
+player-info-out-of-ammo = 弹药已用完!
+player-info-low-ammo = 弹药不足:{ $quantity }
+player-info-ammo = 弹药
+player-info-equip-ammo = 装备 { $name } ({ $quantity })
+
+## Session statistics
+
+session-statistics-title = 本次统计
+session-statistics-time = 游戏时间:
+session-statistics-duration = { $hours } 小时 { $minutes } 分
+session-statistics-xp = 经验值:
+session-statistics-money = Zuly:
+session-statistics-kills = 击杀:
+session-statistics-per-hour = { $value } / 小时
+session-statistics-kills-by-monster = 按怪物统计击杀
+session-statistics-items-looted = 获得的物品
+session-statistics-reset = 重置
+session-statistics-export = 导出 CSV
+session-statistics-export-succeeded = 已导出到 { $path }
+session-statistics-export-failed = 导出失败:{ $error }
+
+## Frame profiler
+
+frame-profiler-export-succeeded = 已导出到 { $path }
+frame-profiler-export-failed = 导出失败:{ $error }
+
+## Money input
+
+money-input-drop-title = 丢弃 Zuly
+money-input-hint = 输入金额,例如 1,500、1.5k 或 2m
+money-input-amount = { $money } Zuly
+money-input-balance = 余额:{ $money } Zuly
+money-input-empty = 请输入金额
+money-input-invalid = 金额无效
+money-input-overflow = 金额过大
+money-input-insufficient = 你的 Zuly 不足
+money-input-ok = 确定
+money-input-max = 最大
+money-input-cancel = 取消
+
+## Chat system messages
+
+chat-waiting = 请稍候...
+chat-invalid-target = 目标无效
+chat-drop-locked-item = 无法丢弃已锁定的物品。
+chat-assist-no-target = 你必须选择一个要协助的目标。
+chat-assist-target-has-no-target = 你的目标没有选择目标。
+chat-defend-started = 你正在保护 { $name }。
+chat-defend-no-target = 你必须选择一个要保护的目标。
+chat-defend-stopped = 你不再保护任何人。
+chat-afk-set = 你现在处于离开状态:{ $message }
+chat-afk-cleared = 你已不再处于离开状态。
+chat-afk-auto = 由于长时间未操作,你已被标记为离开。
+chat-ammo-empty = 你的{ $ammo }已用完。
+chat-ammo-low = 你的{ $ammo }快用完了。
+chat-encumbrance-warning = 你的负重已接近上限。
+chat-encumbrance-over = 你携带的物品过重,无法再奔跑。
+chat-achievement-unlocked = 解锁成就:{ $name }!现在可以使用称号“{ $title }”。
+chat-character-exported = 角色已导出到 { $path }
+chat-character-export-failed = 导出角色失败:{ $error }
+chat-level-up = 恭喜!你已升到 { $level } 级!
+chat-hunted-monster = 你成功狩猎了 { $name }
+chat-ability-increased = 能力 { $ability } 提高了 { $value }。
+chat-ability-decreased = 能力 { $ability } 降低了 { $value }。
+chat-ability-changed = 能力 { $ability } 已变为 { $value }。
+chat-earned-xp = 你获得了 { $xp } 点经验值。
+chat-earned-item = 你获得了 { $item }。
+chat-earned-money = 你获得了 { $money } Zuly。
+chat-pickup-inventory-full = 背包已满,无法拾取物品。
+chat-pickup-no-permission = 该物品不属于你,无法拾取。
+chat-learn-skill-already-learnt = 学习技能失败,你已经学会了该技能。
+chat-learn-skill-job-requirement = 学习技能失败,不满足职业要求。
+chat-learn-skill-skill-requirement = 学习技能失败,不满足技能要求。
+chat-learn-skill-ability-requirement = 学习技能失败,不满足能力要求。
+chat-learn-skill-full = 学习技能失败,技能数量已满。
+chat-learn-skill-invalid-skill = 学习技能失败,技能无效。
+chat-learn-skill-skill-points = 学习技能失败,技能点不足。
+chat-level-up-skill-failed = 技能升级失败。
+chat-level-up-skill-job-requirement = 技能升级失败,不满足职业要求。
+chat-level-up-skill-skill-requirement = 技能升级失败,不满足技能要求。
+chat-level-up-skill-ability-requirement = 技能升级失败,不满足能力要求。
+chat-level-up-skill-money = 技能升级失败,Zuly 不足。
+chat-level-up-skill-skill-points = 技能升级失败,技能点不足。
+chat-npc-store-transaction-failed = 商店交易失败,错误 { $error }
+chat-personal-store-transaction-cancelled = 交易失败。
+chat-personal-store-transaction-sold-out = 交易失败。物品已售罄。
+chat-personal-store-transaction-no-more-need = 交易失败。已不再收购该物品。
+chat-personal-store-purchased = { $quantity ->
+    [1] 你以 { $money } Zuly 购买了 { $item }。
+   *[other] 你以 { $money } Zuly 购买了 { $quantity } 个 { $item }。
+}
+chat-personal-store-sold = { $quantity ->
+    [1] 你以 { $money } Zuly 出售了 { $item }。
+   *[other] 你以 { $money } Zuly 出售了 { $quantity } 个 { $item }。
+}
+chat-clan-level-up = 你的公会已达到 { $level } 级!
+chat-clan-points-increased = 公会积分:{ $points } (+{ $change })
+chat-clan-points-decreased = 公会积分:{ $points } (-{ $change })
+
+## Party
+
+chat-party-invite-accepted = { $name } 接受了你的组队邀请。
+chat-party-invite-rejected = { $name } 拒绝了你的组队邀请。
+chat-party-joined = { $name } 加入了队伍。
+chat-party-left = 你离开了队伍。
+chat-party-member-left = { $name } 离开了队伍。
+chat-party-member-disconnected = { $name } 已断开连接。
+chat-party-member-kicked = { $name } 被踢出了队伍。
+chat-party-member-earned-item = { $name } 获得了 { $item }。
+chat-party-new-leader = { $name } 现在是队长。
+chat-party-rules-changed = 队伍规则已更改。
+chat-party-xp-sharing = 经验值分配:{ $sharing }。
+chat-party-item-sharing = 物品分配:{ $sharing }。
+party-xp-sharing-equal-share = 平均分配
+party-xp-sharing-distributed-by-level = 按等级分配
+party-item-sharing-equal-loot-distribution = 平均分配
+party-item-sharing-acquisition-order = 按拾取顺序
+
+## Settings
+
+settings-title = 设置
+settings-page-sound = 声音
+settings-page-gameplay = 游戏
+settings-page-graphics = 图形
+settings-page-combat-text = 战斗文字
+settings-page-accessibility = 辅助功能
+settings-page-controls = 操作
+settings-enabled = 启用
+settings-off = 关闭
+
+settings-sound = 声音:
+settings-output-device = 输出设备:
+settings-output-device-default = 系统默认
+settings-output-device-refresh = 刷新
+settings-global-volume = 总音量:
+settings-background-music = 背景音乐:
+settings-player-footsteps = 玩家脚步声:
+settings-other-footsteps = 其他脚步声:
+settings-player-combat = 玩家战斗:
+settings-other-combat = 其他战斗:
+settings-npc-sounds = NPC 声音:
+
+settings-auto-loot = 自动拾取:
+settings-loot-radius = 拾取范围:
+settings-loot-min-item-quality = 最低物品品质:
+settings-loot-pickup = 拾取:
+settings-loot-money = Zuly
+settings-loot-equipment = 装备
+settings-loot-consumables = 消耗品
+settings-loot-materials = 材料
+settings-loot-hint = 按 Z 拾取附近的所有物品。
+settings-auto-away = 自动离开:
+settings-auto-away-timeout = 离开前等待(分钟):
+settings-while-away = 离开时:
+settings-auto-reply = 自动回复私聊
+settings-while-unfocused = 窗口不在前台时:
+settings-low-power-unfocused = 降低帧率
+settings-mute-unfocused = 静音
+settings-afk-hint = 输入 /afk [消息] 切换离开状态。
+settings-level-up = 升级:
+settings-level-up-effect = 特效
+settings-level-up-fanfare = 音效
+settings-level-up-screen-flash = 屏幕闪光
+settings-level-up-banner = 横幅
+settings-cooldown-alerts = 冷却提醒:
+settings-cooldown-alerts-hotbar-pulse = 快捷栏格子闪烁
+settings-cooldown-alerts-toast = 显示消息
+settings-cooldown-alerts-sound = 播放声音
+settings-cooldown-alerts-sound-id = 声音 ID
+settings-cooldown-alerts-test = 测试
+settings-cooldown-alerts-warning = 增益到期提醒(秒):
+settings-cooldown-alerts-hint = 右键点击快捷栏技能或增益图标以选择提醒内容。
+settings-hud-layout = 界面布局:
+settings-hud-edit = 编辑界面
+
+settings-window-mode = 窗口模式:
+settings-window-mode-windowed = 窗口化
+settings-window-mode-borderless = 无边框全屏
+settings-window-mode-exclusive = 独占全屏
+settings-monitor = 显示器:
+settings-monitor-automatic = 自动
+settings-monitor-index = 显示器 { $index }
+settings-resolution = 分辨率:
+settings-resolution-native = 原生
+settings-minimize-on-focus-loss = Alt-Tab 时最小化:
+settings-vsync = 垂直同步:
+settings-minimize-never = 从不
+settings-minimize-any-fullscreen = 任何全屏
+settings-quality-preset = 画质预设:
+settings-quality-low = 低
+settings-quality-medium = 中
+settings-quality-high = 高
+settings-quality-ultra = 极高
+settings-quality-custom = 自定义
+settings-shadows = 阴影:
+settings-draw-distance = 视距:
+settings-particle-density = 粒子密度:
+settings-texture-quality = 纹理质量:
+settings-applies-after-restart = (重启后生效)
+settings-anti-aliasing = 抗锯齿:
+settings-msaa-2x = MSAA 2x
+settings-msaa-4x = MSAA 4x
+settings-benchmark-presets = 测试预设性能
+settings-benchmark-measuring = 正在测试{ $preset }预设...
+settings-benchmark-preset = 预设
+settings-benchmark-frame-time = 帧时间
+settings-benchmark-fps = FPS
+settings-item-grade-effects = 物品等级特效:
+settings-item-grade-effects-hint = 设为 0 可关闭高等级装备的光芒。
+settings-item-drop-effects = 掉落物品光柱:
+settings-name-tags-character = 角色名称:
+settings-name-tags-npc = NPC 名称:
+settings-name-tags-monster = 怪物名称:
+settings-name-tags-always-show = 始终显示
+settings-name-tags-font-size = 字体大小
+settings-name-tags-combat-healthbars = 战斗血条:
+settings-name-tags-chat-bubbles = 聊天气泡:
+
+settings-combat-text-dealt = 造成伤害:
+settings-combat-text-critical = 暴击:
+settings-combat-text-taken = 受到伤害:
+settings-combat-text-miss = 未命中:
+settings-combat-text-heal = 治疗:
+settings-combat-text-scale = 大小
+settings-combat-text-merge = 合并小伤害:
+settings-combat-text-merge-max-damage = 最大伤害:
+settings-combat-text-merge-window = 合并间隔(毫秒):
+settings-combat-text-status-effects = 状态效果:
+settings-combat-text-buff = 增益:
+settings-combat-text-debuff = 减益:
+settings-combat-text-resisted = 抵抗:
+
+settings-effect-intensity = 特效强度:
+settings-bloom-intensity = 泛光强度:
+settings-reduce-flashing = 减少闪烁:
+settings-effect-intensity-hint = 特效强度适用于更改后产生的技能和事件特效。
+settings-text-to-speech = 文字转语音:
+settings-text-to-speech-read = 朗读:
+settings-tts-say = 聊天
+settings-tts-shout = 喊话
+settings-tts-whisper = 私聊
+settings-tts-announce = 公告
+settings-tts-system = 系统消息
+settings-tts-quest = 任务消息
+settings-tts-dialog = NPC 对话
+settings-tts-message-box = 消息框
+settings-controls-press-key = 请按一个键...
+settings-controls-binding-tooltip = 点击更改,右键点击解除绑定。按 Esc 取消。
+settings-controls-conflict = 也绑定到了 { $actions }
+settings-controls-resolve = 解除其他绑定
+settings-controls-resolve-tooltip = 从其他操作中移除此键
+settings-controls-reset = 恢复默认
+settings-controls-export-cheat-sheet = 导出按键表
+settings-controls-cheat-sheet-exported = 按键表已导出到 { $path }
+settings-controls-cheat-sheet-failed = 导出按键表失败:{ $error }
+
+## Key bindings
+
+input-cheat-sheet-title = 按键绑定
+input-context-general = 通用
+input-context-combat = 战斗
+input-context-vehicle = 载具
+input-context-free-camera = 自由镜头
+input-context-ui = 界面
+input-context-debug = 调试
+input-action-loot-all = 全部拾取
+input-action-hotbar = 快捷栏格子 { $slot }
+input-action-vehicle-get-off = 离开载具
+input-action-free-camera-forward = 向前移动
+input-action-free-camera-backward = 向后移动
+input-action-free-camera-left = 向左移动
+input-action-free-camera-right = 向右移动
+input-action-free-camera-down = 向下移动
+input-action-free-camera-up = 向上移动
+input-action-free-camera-boost = 加速移动
+input-action-toggle-character-info = 角色信息
+input-action-toggle-inventory = 背包
+input-action-toggle-clan = 公会
+input-action-toggle-friends = 好友
+input-action-toggle-calendar = 活动日历
+input-action-toggle-skill-list = 技能
+input-action-toggle-quest-list = 任务
+input-action-toggle-settings = 设置
+input-action-toggle-session-statistics = 本次统计
+input-action-toggle-waypoints = 路标
+input-action-toggle-personal-store-scanner = 个人商店搜索
+input-action-toggle-debug-window = 调试窗口
+input-action-debug-inspector-pick = 检查光标下的实体
+input-action-debug-physics-spawn-balls = 生成物理球
+
+## HUD layout
+
+hud-edit-title = 编辑界面
+hud-edit-hint = 拖动元素以移动它们。布局会为此角色保存。
+hud-edit-hide = 隐藏
+hud-edit-show = 显示
+hud-edit-reset = 恢复默认
+hud-edit-done = 完成
+hud-element-chatbox = 聊天
+hud-element-minimap = 小地图
+hud-element-hotbar = 快捷栏
+hud-element-party = 队伍
+
+## Calendar
+
+calendar-title = 活动日历
+calendar-no-schedule = 此服务器没有活动日程。
+calendar-no-events = 没有即将开始的活动。
+calendar-refresh = 刷新
+calendar-fetch-failed = 加载活动日程失败:{ $error }
+calendar-event-starts-in = { $countdown } 后开始
+calendar-event-active = 进行中,{ $countdown } 后结束
+calendar-remind-me = 提醒我
+calendar-reminder = { $name } 即将开始!
+
+## Clock
+
+clock-real-time = 本地时间:{ $time }
+clock-world-time = 第 { $day } 天,{ $time }
+clock-day-in = { $state },{ $countdown } 后天亮
+clock-night-in = { $state },{ $countdown } 后入夜
+clock-open-calendar = 点击打开活动日历
+zone-time-morning = 早晨
+zone-time-day = 白天
+zone-time-evening = 傍晚
+zone-time-night = 夜晚
+
+## Crafting
+
+craft-insert-gem-menu = 镶嵌宝石
+craft-insert-gem-title = 镶嵌宝石
+craft-insert-gem-drop-gem = 将背包中的宝石放到这里。
+craft-insert-gem-select-item = 选择一件带插槽的已装备物品:
+craft-insert-gem-no-items = 你的已装备物品都没有插槽。
+craft-insert-gem-button = 镶嵌
+craft-insert-gem-success = 宝石镶嵌成功。
+craft-insert-gem-no-socket = 该物品没有插槽。
+craft-insert-gem-socket-full = 插槽中已有宝石。
+
+## Repair
+
+repair-title = 修理
+repair-using-item = 选择要用修理工具修理的物品。
+repair-no-items = 你的装备都不需要修理。
+repair-item-life = 寿命:{ $life }%
+repair-item-price = 约 { $money } Zuly
+repair-button = 修理
+repair-success = 物品已修理。
+
+## Friends
+
+friends-title = 好友
+friends-name-hint = 角色名称
+friends-add = 添加
+friends-empty = 你的好友列表是空的。
+friends-nearby = 附近
+friends-not-nearby = 不在附近
+friends-whisper = 私聊
+friends-remove = 删除
+
+## Inspect character
+
+character-menu-inspect = 查看
+character-menu-whisper = 私聊
+character-menu-add-friend = 添加好友
+inspect-character-title = { $name } 的装备
+inspect-character-level-job = { $level } 级 { $job }
+
+## Respawn
+
+respawn-map-title = 复活点
+respawn-nearest-revive-point = 在最近的复活点复活
+respawn-other-revive-point = 只能在最近的复活点复活
+respawn-saved-location = 在保存的位置复活
+respawn-set-save-zone = 将此区域保存为复活位置
+
+## Level up
+
+level-up-banner = 升级!
+level-up-banner-level = { $level } 级
+
+## Status effects
+
+status-effect-applied = { $name }!
+status-effect-resisted = 抵抗
+status-effect-warn-expiry = 到期前提醒
+status-effect-time-remaining = 剩余时间:{ $time }
+
+## Cast bar
+
+cast-bar-cancel-hint = 按 Esc 取消
+
+## Cooldown alerts
+
+cooldown-alert-ready = { $name } 已就绪
+cooldown-alert-expiring = { $name } 将在 { $seconds } 秒后到期
+hotbar-alert-when-ready = 就绪时提醒
+
+## Text to speech
+
+tts-say = { $name } 说:{ $text }
+tts-shout = { $name } 喊道:{ $text }
+tts-whisper = { $name } 私聊:{ $text }
+tts-announce = { $name } 公告:{ $text }
@@ -0,0 +1,471 @@
+## Connecting
+
+connecting-title = 正在連線...
+connecting-login = 正在登入
+connecting-channel = 正在連線頻道
+connecting-game = 正在連線遊戲
+creating-character-title = 正在建立角色...
+creating-character = 正在建立角色
+login-profile = 設定檔:
+game-reconnect-title = 連線中斷
+game-reconnect-lost = 與遊戲伺服器的連線已中斷。
+game-reconnect-countdown = { $seconds } 秒後重新連線
+game-reconnect-connecting = 正在重新連線...
+game-reconnect-failed = 無法重新連線到遊戲伺服器。
+game-reconnect-attempt = 第 { $attempt } 次嘗試,共 { $max } 次
+game-reconnect-now = 立即重新連線
+game-reconnect-cancel = 返回登入
+motd-title = 最新消息
+motd-loading = 正在載入最新消息...
+motd-fetch-failed = 無法載入最新消息:{ $error }
+motd-patch-notes = 更新說明
+
+## Logout
+
+exit-game-title = 離開遊戲
+exit-game-character-select = 選擇角色
+exit-game-exit = 離開遊戲
+exit-game-cancel = 取消
+logout-title = 正在登出
+logout-countdown-character-select = { $seconds } 秒後返回角色選擇
+logout-countdown-exit = { $seconds } 秒後離開遊戲
+logout-move-to-cancel = 移動將取消登出。
+logout-cancel = 取消
+logout-waiting = 正在登出...
+logout-cancelled = 已取消登出。
+logout-cancelled-moved = 由於你移動了,登出已取消。
+logout-failed = 暫時無法登出,請等待 { $seconds } 秒。
+
+## Character select
+
+character-delete-title = 刪除角色
+character-delete-warning = 刪除倒數計時結束後將刪除 { $name }。
+character-delete-type-name = 請輸入角色名稱以確認:
+character-delete-confirm = 刪除
+character-delete-cancel = 取消
+character-delete-undo = 取消刪除
+character-delete-countdown = { $countdown } 後刪除
+
+## Items
+
+item-drop-money = { $money } Zuly
+item-tooltip-grade-bonus = [等級 { $grade }:{ $bonuses }]
+item-tooltip-empty-socket = [空插槽]
+item-tooltip-sell-price = 出售價格:{ $money } Zuly
+
+## Inventory
+
+inventory-weight = 重量
+inventory-weight-over-encumbered = 負重過大,只能步行
+
+## NPC store
+
+npc-store-sell-locked-item = 無法出售已鎖定的物品。
+npc-store-not-enough-money = 你的 Zuly 不足以完成此交易。
+
+## Player info
+
+player-info-out-of-ammo = 彈藥已用完!
+player-info-low-ammo = 彈藥不足:{ $quantity }
+player-info-ammo = 彈藥
+player-info-equip-ammo = 裝備 { $name } ({ $quantity })
+
+## Session statistics
+
+session-statistics-title = 本次統計
+session-statistics-time = 遊戲時間:
+session-statistics-duration = { $hours } 小時 { $minutes } 分
+session-statistics-xp = 經驗值:
+session-statistics-money = Zuly:
+session-statistics-kills = 擊殺:
+session-statistics-per-hour = { $value } / 小時
+session-statistics-kills-by-monster = 按怪物統計擊殺
+session-statistics-items-looted = 獲得的物品
+session-statistics-reset = 重設
+session-statistics-export = 匯出 CSV
+session-statistics-export-succeeded = 已匯出到 { $path }
+session-statistics-export-failed = 匯出失敗:{ $error }
+
+## Frame profiler
+
+frame-profiler-export-succeeded = 已匯出到 { $path }
+frame-profiler-export-failed = 匯出失敗:{ $error }
+
+## Money input
+
+money-input-drop-title = 丟棄 Zuly
+money-input-hint = 輸入金額,例如 1,500、1.5k 或 2m
+money-input-amount = { $money } Zuly
+money-input-balance = 餘額:{ $money } Zuly
+money-input-empty = 請輸入金額
+money-input-invalid = 金額無效
+money-input-overflow = 金額過大
+money-input-insufficient = 你的 Zuly 不足
+money-input-ok = 確定
+money-input-max = 最大
+money-input-cancel = 取消
+
+## Chat system messages
+
+chat-waiting = 請稍候...
+chat-invalid-target = 目標無效
+chat-drop-locked-item = 無法丟棄已鎖定的物品。
+chat-assist-no-target = 你必須選擇一個要協助的目標。
+chat-assist-target-has-no-target = 你的目標沒有選擇目標。
+chat-defend-started = 你正在保護 { $name }。
+chat-defend-no-target = 你必須選擇一個要保護的目標。
+chat-defend-stopped = 你不再保護任何人。
+chat-afk-set = 你現在處於離開狀態:{ $message }
+chat-afk-cleared = 你已不再處於離開狀態。
+chat-afk-auto = 由於長時間未操作,你已被標記為離開。
+chat-ammo-empty = 你的{ $ammo }已用完。
+chat-ammo-low = 你的{ $ammo }快用完了。
+chat-encumbrance-warning = 你的負重已接近上限。
+chat-encumbrance-over = 你攜帶的物品過重,無法再奔跑。
+chat-achievement-unlocked = 解鎖成就:{ $name }!現在可以使用稱號“{ $title }”。
+chat-character-exported = 角色已匯出到 { $path }
+chat-character-export-failed = 匯出角色失敗:{ $error }
+chat-level-up = 恭喜!你已升到 { $level } 級!
+chat-hunted-monster = 你成功狩獵了 { $name }
+chat-ability-increased = 能力 { $ability } 提高了 { $value }。
+chat-ability-decreased = 能力 { $ability } 降低了 { $value }。
+chat-ability-changed = 能力 { $ability } 已變為 { $value }。
+chat-earned-xp = 你獲得了 { $xp } 點經驗值。
+chat-earned-item = 你獲得了 { $item }。
+chat-earned-money = 你獲得了 { $money } Zuly。
+chat-pickup-inventory-full = 背包已滿,無法拾取物品。
+chat-pickup-no-permission = 該物品不屬於你,無法拾取。
+chat-learn-skill-already-learnt = 學習技能失敗,你已經學會了該技能。
+chat-learn-skill-job-requirement = 學習技能失敗,不滿足職業要求。
+chat-learn-skill-skill-requirement = 學習技能失敗,不滿足技能要求。
+chat-learn-skill-ability-requirement = 學習技能失敗,不滿足能力要求。
+chat-learn-skill-full = 學習技能失敗,技能數量已滿。
+chat-learn-skill-invalid-skill = 學習技能失敗,技能無效。
+chat-learn-skill-skill-points = 學習技能失敗,技能點不足。
+chat-level-up-skill-failed = 技能升級失敗。
+chat-level-up-skill-job-requirement = 技能升級失敗,不滿足職業要求。
+chat-level-up-skill-skill-requirement = 技能升級失敗,不滿足技能要求。
+chat-level-up-skill-ability-requirement = 技能升級失敗,不滿足能力要求。
+chat-level-up-skill-money = 技能升級失敗,Zuly 不足。
+chat-level-up-skill-skill-points = 技能升級失敗,技能點不足。
+chat-npc-store-transaction-failed = 商店交易失敗,錯誤 { $error }
+chat-personal-store-transaction-cancelled = 交易失敗。
+chat-personal-store-transaction-sold-out = 交易失敗。物品已售罄。
+chat-personal-store-transaction-no-more-need = 交易失敗。已不再收購該物品。
+chat-personal-store-purchased = { $quantity ->
+    [1] 你以 { $money } Zuly 購買了 { $item }。
+   *[other] 你以 { $money } Zuly 購買了 { $quantity } 個 { $item }。
+}
+chat-personal-store-sold = { $quantity ->
+    [1] 你以 { $money } Zuly 出售了 { $item }。
+   *[other] 你以 { $money } Zuly 出售了 { $quantity } 個 { $item }。
+}
+chat-clan-level-up = 你的公會已達到 { $level } 級!
+chat-clan-points-increased = 公會積分:{ $points } (+{ $change })
+chat-clan-points-decreased = 公會積分:{ $points } (-{ $change })
+
+## Party
+
+chat-party-invite-accepted = { $name } 接受了你的組隊邀請。
+chat-party-invite-rejected = { $name } 拒絕了你的組隊邀請。
+chat-party-joined = { $name } 加入了隊伍。
+chat-party-left = 你離開了隊伍。
+chat-party-member-left = { $name } 離開了隊伍。
+chat-party-member-disconnected = { $name } 已斷開連線。
+chat-party-member-kicked = { $name } 被踢出了隊伍。
+chat-party-member-earned-item = { $name } 獲得了 { $item }。
+chat-party-new-leader = { $name } 現在是隊長。
+chat-party-rules-changed = 隊伍規則已更改。
+chat-party-xp-sharing = 經驗值分配:{ $sharing }。
+chat-party-item-sharing = 物品分配:{ $sharing }。
+party-xp-sharing-equal-share = 平均分配
+party-xp-sharing-distributed-by-level = 按等級分配
+party-item-sharing-equal-loot-distribution = 平均分配
+party-item-sharing-acquisition-order = 按拾取順序
+
+## Settings
+
+settings-title = 設定
+settings-page-sound = 聲音
+settings-page-gameplay = 遊戲
+settings-page-graphics = 圖形
+settings-page-combat-text = 戰鬥文字
+settings-page-accessibility = 輔助功能
+settings-page-controls = 操作
+settings-enabled = 啟用
+settings-off = 關閉
+
+settings-sound = 聲音:
+settings-output-device = 輸出裝置:
+settings-output-device-default = 系統預設
+settings-output-device-refresh = 重新整理
+settings-global-volume = 總音量:
+settings-background-music = 背景音樂:
+settings-player-footsteps = 玩家腳步聲:
+settings-other-footsteps = 其他腳步聲:
+settings-player-combat = 玩家戰鬥:
+settings-other-combat = 其他戰鬥:
+settings-npc-sounds = NPC 聲音:
+
+settings-auto-loot = 自動拾取:
+settings-loot-radius = 拾取範圍:
+settings-loot-min-item-quality = 最低物品品質:
+settings-loot-pickup = 拾取:
+settings-loot-money = Zuly
+settings-loot-equipment = 裝備
+settings-loot-consumables = 消耗品
+settings-loot-materials = 材料
+settings-loot-hint = 按 Z 拾取附近的所有物品。
+settings-auto-away = 自動離開:
+settings-auto-away-timeout = 離開前等待(分鐘):
+settings-while-away = 離開時:
+settings-auto-reply = 自動回覆密語
+settings-while-unfocused = 視窗不在前台時:
+settings-low-power-unfocused = 降低幀率
+settings-mute-unfocused = 靜音
+settings-afk-hint = 輸入 /afk [訊息] 切換離開狀態。
+settings-level-up = 升級:
+settings-level-up-effect = 特效
+settings-level-up-fanfare = 音效
+settings-level-up-screen-flash = 屏幕閃光
+settings-level-up-banner = 橫幅
+settings-cooldown-alerts = 冷卻提醒:
+settings-cooldown-alerts-hotbar-pulse = 快捷列格子閃爍
+settings-cooldown-alerts-toast = 顯示訊息
+settings-cooldown-alerts-sound = 播放聲音
+settings-cooldown-alerts-sound-id = 聲音 ID
+settings-cooldown-alerts-test = 測試
+settings-cooldown-alerts-warning = 增益到期提醒(秒):
+settings-cooldown-alerts-hint = 右鍵點擊快捷列技能或增益圖標以選擇提醒內容。
+settings-hud-layout = 介面布局:
+settings-hud-edit = 編輯介面
+
+settings-window-mode = 視窗模式:
+settings-window-mode-windowed = 視窗化
+settings-window-mode-borderless = 無邊框全螢幕
+settings-window-mode-exclusive = 獨佔全螢幕
+settings-monitor = 螢幕:
+settings-monitor-automatic = 自動
+settings-monitor-index = 螢幕 { $index }
+settings-resolution = 解析度:
+settings-resolution-native = 原生
+settings-minimize-on-focus-loss = Alt-Tab 時最小化:
+settings-vsync = 垂直同步:
+settings-minimize-never = 從不
+settings-minimize-any-fullscreen = 任何全螢幕
+settings-quality-preset = 畫質預設:
+settings-quality-low = 低
+settings-quality-medium = 中
+settings-quality-high = 高
+settings-quality-ultra = 極高
+settings-quality-custom = 自訂
+settings-shadows = 陰影:
+settings-draw-distance = 視距:
+settings-particle-density = 粒子密度:
+settings-texture-quality = 材質品質:
+settings-applies-after-restart = (重啟後生效)
+settings-anti-aliasing = 反鋸齒:
+settings-msaa-2x = MSAA 2x
+settings-msaa-4x = MSAA 4x
+settings-benchmark-presets = 測試預設性能
+settings-benchmark-measuring = 正在測試{ $preset }預設...
+settings-benchmark-preset = 預設
+settings-benchmark-frame-time = 幀時間
+settings-benchmark-fps = FPS
+settings-item-grade-effects = 物品等級特效:
+settings-item-grade-effects-hint = 設為 0 可關閉高等級裝備的光芒。
+settings-item-drop-effects = 掉落物品光柱:
+settings-name-tags-character = 角色名稱:
+settings-name-tags-npc = NPC 名稱:
+settings-name-tags-monster = 怪物名稱:
+settings-name-tags-always-show = 始終顯示
+settings-name-tags-font-size = 字體大小
+settings-name-tags-combat-healthbars = 戰鬥血條:
+settings-name-tags-chat-bubbles = 聊天氣泡:
+
+settings-combat-text-dealt = 造成傷害:
+settings-combat-text-critical = 爆擊:
+settings-combat-text-taken = 受到傷害:
+settings-combat-text-miss = 未命中:
+settings-combat-text-heal = 治療:
+settings-combat-text-scale = 大小
+settings-combat-text-merge = 合並小傷害:
+settings-combat-text-merge-max-damage = 最大傷害:
+settings-combat-text-merge-window = 合並間隔(毫秒):
+settings-combat-text-status-effects = 狀態效果:
+settings-combat-text-buff = 增益:
+settings-combat-text-debuff = 減益:
+settings-combat-text-resisted = 抵抗:
+
+settings-effect-intensity = 特效強度:
+settings-bloom-intensity = 光暈強度:
+settings-reduce-flashing = 減少閃爍:
+settings-effect-intensity-hint = 特效強度適用於更改後產生的技能和事件特效。
+settings-text-to-speech = 文字轉語音:
+settings-text-to-speech-read = 朗讀:
+settings-tts-say = 聊天
+settings-tts-shout = 喊話
+settings-tts-whisper = 密語
+settings-tts-announce = 公告
+settings-tts-system = 系統訊息
+settings-tts-quest = 任務訊息
+settings-tts-dialog = NPC 對話
+settings-tts-message-box = 訊息框
+settings-controls-press-key = 請按一個鍵...
+settings-controls-binding-tooltip = 點擊更改,右鍵點擊解除綁定。按 Esc 取消。
+settings-controls-conflict = 也綁定到了 { $actions }
+settings-controls-resolve = 解除其他綁定
+settings-controls-resolve-tooltip = 從其他操作中移除此鍵
+settings-controls-reset = 恢復預設
+settings-controls-export-cheat-sheet = 匯出按鍵表
+settings-controls-cheat-sheet-exported = 按鍵表已匯出到 { $path }
+settings-controls-cheat-sheet-failed = 匯出按鍵表失敗:{ $error }
+
+## Key bindings
+
+input-cheat-sheet-title = 按鍵綁定
+input-context-general = 通用
+input-context-combat = 戰鬥
+input-context-vehicle = 載具
+input-context-free-camera = 自由鏡頭
+input-context-ui = 介面
+input-context-debug = 偵錯
+input-action-loot-all = 全部拾取
+input-action-hotbar = 快捷列格子 { $slot }
+input-action-vehicle-get-off = 離開載具
+input-action-free-camera-forward = 向前移動
+input-action-free-camera-backward = 向後移動
+input-action-free-camera-left = 向左移動
+input-action-free-camera-right = 向右移動
+input-action-free-camera-down = 向下移動
+input-action-free-camera-up = 向上移動
+input-action-free-camera-boost = 加速移動
+input-action-toggle-character-info = 角色資訊
+input-action-toggle-inventory = 背包
+input-action-toggle-clan = 公會
+input-action-toggle-friends = 好友
+input-action-toggle-calendar = 活動日曆
+input-action-toggle-skill-list = 技能
+input-action-toggle-quest-list = 任務
+input-action-toggle-settings = 設定
+input-action-toggle-session-statistics = 本次統計
+input-action-toggle-waypoints = 路標
+input-action-toggle-personal-store-scanner = 個人商店搜尋
+input-action-toggle-debug-window = 偵錯視窗
+input-action-debug-inspector-pick = 檢查游標下的實體
+input-action-debug-physics-spawn-balls = 生成物理球
+
+## HUD layout
+
+hud-edit-title = 編輯介面
+hud-edit-hint = 拖動元素以移動它們。布局會為此角色儲存。
+hud-edit-hide = 隱藏
+hud-edit-show = 顯示
+hud-edit-reset = 恢復預設
+hud-edit-done = 完成
+hud-element-chatbox = 聊天
+hud-element-minimap = 小地圖
+hud-element-hotbar = 快捷列
+hud-element-party = 隊伍
+
+## Calendar
+
+calendar-title = 活動日曆
+calendar-no-schedule = 此伺服器沒有活動日程。
+calendar-no-events = 沒有即將開始的活動。
+calendar-refresh = 重新整理
+calendar-fetch-failed = 載入活動日程失敗:{ $error }
+calendar-event-starts-in = { $countdown } 後開始
+calendar-event-active = 進行中,{ $countdown } 後結束
+calendar-remind-me = 提醒我
+calendar-reminder = { $name } 即將開始!
+
+## Clock
+
+clock-real-time = 本地時間:{ $time }
+clock-world-time = 第 { $day } 天,{ $time }
+clock-day-in = { $state },{ $countdown } 後天亮
+clock-night-in = { $state },{ $countdown } 後入夜
+clock-open-calendar = 點擊開啟活動日曆
+zone-time-morning = 早晨
+zone-time-day = 白天
+zone-time-evening = 傍晚
+zone-time-night = 夜晚
+
+## Crafting
+
+craft-insert-gem-menu = 鑲嵌寶石
+craft-insert-gem-title = 鑲嵌寶石
+craft-insert-gem-drop-gem = 將背包中的寶石放到這裡。
+craft-insert-gem-select-item = 選擇一件帶插槽的已裝備物品:
+craft-insert-gem-no-items = 你的已裝備物品都沒有插槽。
+craft-insert-gem-button = 鑲嵌
+craft-insert-gem-success = 寶石鑲嵌成功。
+craft-insert-gem-no-socket = 該物品沒有插槽。
+craft-insert-gem-socket-full = 插槽中已有寶石。
+
+## Repair
+
+repair-title = 修理
+repair-using-item = 選擇要用修理工具修理的物品。
+repair-no-items = 你的裝備都不需要修理。
+repair-item-life = 壽命:{ $life }%
+repair-item-price = 約 { $money } Zuly
+repair-button = 修理
+repair-success = 物品已修理。
+
+## Friends
+
+friends-title = 好友
+friends-name-hint = 角色名稱
+friends-add = 添加
+friends-empty = 你的好友列表是空的。
+friends-nearby = 附近
+friends-not-nearby = 不在附近
+friends-whisper = 密語
+friends-remove = 刪除
+
+## Inspect character
+
+character-menu-inspect = 查看
+character-menu-whisper = 密語
+character-menu-add-friend = 添加好友
+inspect-character-title = { $name } 的裝備
+inspect-character-level-job = { $level } 級 { $job }
+
+## Respawn
+
+respawn-map-title = 復活點
+respawn-nearest-revive-point = 在最近的復活點復活
+respawn-other-revive-point = 只能在最近的復活點復活
+respawn-saved-location = 在儲存的位置復活
+respawn-set-save-zone = 將此區域儲存為復活位置
+
+## Level up
+
+level-up-banner = 升級!
+level-up-banner-level = { $level } 級
+
+## Status effects
+
+status-effect-applied = { $name }!
+status-effect-resisted = 抵抗
+status-effect-warn-expiry = 到期前提醒
+status-effect-time-remaining = 剩餘時間:{ $time }
+
+## Cast bar
+
+cast-bar-cancel-hint = 按 Esc 取消
+
+## Cooldown alerts
+
+cooldown-alert-ready = { $name } 已就緒
+cooldown-alert-expiring = { $name } 將在 { $seconds } 秒後到期
+hotbar-alert-when-ready = 就緒時提醒
+
+## Text to speech
+
+tts-say = { $name } 說:{ $text }
+tts-shout = { $name } 喊道:{ $text }
+tts-whisper = { $name } 密語:{ $text }
+tts-announce = { $name } 公告:{ $text }
@@ -8,6 +8,7 @@ use bevy::{
 mod animation_state;
 mod camera_animation;
 mod mesh_animation;
+mod morph_animation;
 mod skeletal_animation;
 mod transform_animation;
 mod zmo_asset_loader;
@@ -15,15 +16,18 @@ mod zmo_asset_loader;
 pub use animation_state::AnimationFrameEvent;
 pub use camera_animation::CameraAnimation;
 pub use mesh_animation::MeshAnimation;
+pub use morph_animation::MorphAnimation;
 pub use skeletal_animation::SkeletalAnimation;
 pub use transform_animation::TransformAnimation;
 pub use zmo_asset_loader::{
-    ZmoAsset, ZmoAssetAnimationTexture, ZmoAssetBone, ZmoAssetLoader, ZmoTextureAssetLoader,
+    is_vertex_animation, ZmoAsset, ZmoAssetAnimationTexture, ZmoAssetBone, ZmoAssetLoader,
+    ZmoAssetVertex, ZmoTextureAssetLoader,
 };
 
 use animation_state::AnimationState;
 use camera_animation::camera_animation_system;
 use mesh_animation::mesh_animation_system;
+use morph_animation::morph_animation_system;
 use skeletal_animation::skeletal_animation_system;
 use transform_animation::transform_animation_system;
 
@@ -39,6 +43,7 @@ impl Plugin for RoseAnimationPlugin {
             .register_asset_reflect::<ZmoAsset>()
             .register_type::<ZmoAssetAnimationTexture>()
             .register_type::<ZmoAssetBone>()
+            .register_type::<ZmoAssetVertex>()
             .init_asset_loader::<ZmoAssetLoader>()
             .init_asset_loader::<ZmoTextureAssetLoader>();
 
@@ -47,6 +52,7 @@ impl Plugin for RoseAnimationPlugin {
         app.register_type::<AnimationState>()
             .register_type::<CameraAnimation>()
             .register_type::<MeshAnimation>()
+            .register_type::<MorphAnimation>()
             .register_type::<SkeletalAnimation>()
             .register_type::<TransformAnimation>();
 
@@ -59,6 +65,7 @@ impl Plugin for RoseAnimationPlugin {
             (
                 camera_animation_system,
                 mesh_animation_system,
+                morph_animation_system,
                 skeletal_animation_system,
                 transform_animation_system,
             )
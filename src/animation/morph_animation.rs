@@ -0,0 +1,131 @@
+use bevy::{
+    asset::LoadState,
+    prelude::{AssetServer, Assets, Component, Deref, DerefMut, Handle, Mesh, Query, Res, ResMut},
+    reflect::Reflect,
+    render::mesh::VertexAttributeValues,
+    time::Time,
+};
+
+use crate::animation::{AnimationState, ZmoAsset};
+
+/// Animates the vertices of a mesh on the CPU, this is used for zone object parts
+/// whose material does not support the animation texture used by effect meshes.
+#[derive(Component, Reflect, Deref, DerefMut)]
+pub struct MorphAnimation {
+    #[deref]
+    animation: AnimationState,
+
+    /// Whether the entity has been given its own copy of the mesh to animate,
+    /// as the original mesh may be shared with other entities.
+    has_unique_mesh: bool,
+}
+
+impl MorphAnimation {
+    pub fn repeat(motion: Handle<ZmoAsset>, limit: Option<usize>) -> Self {
+        Self {
+            animation: AnimationState::repeat(motion, limit),
+            has_unique_mesh: false,
+        }
+    }
+
+    pub fn once(motion: Handle<ZmoAsset>) -> Self {
+        Self {
+            animation: AnimationState::once(motion),
+            has_unique_mesh: false,
+        }
+    }
+}
+
+pub fn morph_animation_system(
+    mut query_animations: Query<(&mut MorphAnimation, &mut Handle<Mesh>)>,
+    motion_assets: Res<Assets<ZmoAsset>>,
+    asset_server: Res<AssetServer>,
+    time: Res<Time>,
+    mut mesh_assets: ResMut<Assets<Mesh>>,
+) {
+    for (mut morph_animation, mut mesh_handle) in query_animations.iter_mut() {
+        if morph_animation.completed() {
+            continue;
+        }
+
+        let zmo_handle = morph_animation.motion();
+        let Some(zmo_asset) = motion_assets.get(zmo_handle) else {
+            if matches!(
+                asset_server.get_load_state(zmo_handle),
+                LoadState::Failed | LoadState::Unloaded
+            ) {
+                // If the asset has failed to load, mark the animation as completed
+                morph_animation.set_completed();
+            }
+
+            continue;
+        };
+
+        if zmo_asset.vertices.is_empty() {
+            continue;
+        }
+
+        if !morph_animation.has_unique_mesh {
+            let Some(mesh) = mesh_assets.get(&mesh_handle).cloned() else {
+                continue;
+            };
+
+            *mesh_handle = mesh_assets.add(mesh);
+            morph_animation.has_unique_mesh = true;
+        }
+
+        let animation = &mut morph_animation.animation;
+        animation.advance(zmo_asset, &time);
+
+        let Some(mesh) = mesh_assets.get_mut(&mesh_handle) else {
+            continue;
+        };
+        let current_frame_fract = animation.current_frame_fract();
+        let current_frame_index = animation.current_frame_index();
+        let next_frame_index = animation.next_frame_index();
+
+        if let Some(VertexAttributeValues::Float32x3(positions)) =
+            mesh.attribute_mut(Mesh::ATTRIBUTE_POSITION)
+        {
+            for (position, vertex) in positions.iter_mut().zip(zmo_asset.vertices.iter()) {
+                if let (Some(current), Some(next)) = (
+                    vertex.position.get(current_frame_index),
+                    vertex.position.get(next_frame_index),
+                ) {
+                    *position = current.lerp(*next, current_frame_fract).to_array();
+                }
+            }
+        }
+
+        if let Some(VertexAttributeValues::Float32x3(normals)) =
+            mesh.attribute_mut(Mesh::ATTRIBUTE_NORMAL)
+        {
+            for (normal, vertex) in normals.iter_mut().zip(zmo_asset.vertices.iter()) {
+                if let (Some(current), Some(next)) = (
+                    vertex.normal.get(current_frame_index),
+                    vertex.normal.get(next_frame_index),
+                ) {
+                    *normal = current
+                        .lerp(*next, current_frame_fract)
+                        .normalize_or_zero()
+                        .to_array();
+                }
+            }
+        }
+
+        if let Some(VertexAttributeValues::Float32x2(uvs)) =
+            mesh.attribute_mut(Mesh::ATTRIBUTE_UV_0)
+        {
+            for (uv, vertex) in uvs.iter_mut().zip(zmo_asset.vertices.iter()) {
+                if let (Some(current), Some(next)) = (
+                    vertex.uv.get(current_frame_index),
+                    vertex.uv.get(next_frame_index),
+                ) {
+                    *uv = current.lerp(*next, current_frame_fract).to_array();
+                }
+            }
+        }
+
+        // TODO: Alpha channel is not supported as the object materials are shared between parts
+    }
+}
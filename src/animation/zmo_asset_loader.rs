@@ -2,7 +2,7 @@ use std::{ffi::OsString, num::NonZeroU16, path::PathBuf};
 
 use bevy::{
     asset::{AssetLoader, BoxedFuture, LoadContext, LoadedAsset},
-    math::{Quat, Vec3},
+    math::{Quat, Vec2, Vec3},
     prelude::{Handle, Image},
     reflect::{Reflect, TypeUuid},
     render::render_resource::{Extent3d, TextureDimension, TextureFormat},
@@ -22,6 +22,13 @@ pub struct ZmoAssetBone {
     pub scale: Vec<f32>,
 }
 
+#[derive(Reflect, Clone, Default)]
+pub struct ZmoAssetVertex {
+    pub position: Vec<Vec3>,
+    pub normal: Vec<Vec3>,
+    pub uv: Vec<Vec2>,
+}
+
 #[derive(Reflect, Clone, Default)]
 pub struct ZmoAssetAnimationTexture {
     pub texture: Handle<Image>,
@@ -40,6 +47,7 @@ pub struct ZmoAsset {
     pub frame_events: Vec<u16>,
     pub interpolation_interval: f32,
     pub bones: Vec<ZmoAssetBone>,
+    pub vertices: Vec<ZmoAssetVertex>,
     pub animation_texture: Option<ZmoAssetAnimationTexture>,
 }
 
@@ -120,6 +128,57 @@ impl ZmoAsset {
     }
 }
 
+/// Vertex animations have channels per vertex instead of per bone, so they either
+/// contain vertex only channels or position channels for more than one vertex.
+pub fn is_vertex_animation(zmo: &ZmoFile) -> bool {
+    let has_vertex_channel = zmo.channels.iter().any(|(_, channel)| {
+        matches!(
+            channel,
+            ZmoChannel::Normal(_) | ZmoChannel::Alpha(_) | ZmoChannel::UV1(_)
+        )
+    });
+    let has_only_position_channels = zmo
+        .channels
+        .iter()
+        .all(|(_, channel)| matches!(channel, ZmoChannel::Position(_)));
+    let has_multiple_vertices = zmo.channels.iter().any(|(vertex_id, _)| *vertex_id > 0);
+
+    has_vertex_channel || (has_only_position_channels && has_multiple_vertices)
+}
+
+fn load_vertices(zmo: &ZmoFile) -> Vec<ZmoAssetVertex> {
+    let mut vertices = Vec::new();
+
+    for (vertex_id, channel) in zmo.channels.iter() {
+        let vertex_id = *vertex_id as usize;
+        if vertices.len() <= vertex_id {
+            vertices.resize(vertex_id + 1, ZmoAssetVertex::default());
+        }
+
+        let vertex = &mut vertices[vertex_id];
+        match channel {
+            ZmoChannel::Position(positions) => {
+                vertex.position = positions
+                    .iter()
+                    .map(|position| Vec3::new(position.x, position.z, -position.y) / 100.0)
+                    .collect();
+            }
+            ZmoChannel::Normal(normals) => {
+                vertex.normal = normals
+                    .iter()
+                    .map(|normal| Vec3::new(normal.x, normal.z, -normal.y))
+                    .collect();
+            }
+            ZmoChannel::UV1(uvs) => {
+                vertex.uv = uvs.iter().map(|uv| Vec2::new(uv.x, uv.y)).collect();
+            }
+            _ => {}
+        }
+    }
+
+    vertices
+}
+
 impl AssetLoader for ZmoAssetLoader {
     fn load<'a>(
         &'a self,
@@ -128,6 +187,21 @@ impl AssetLoader for ZmoAssetLoader {
     ) -> BoxedFuture<'a, Result<(), anyhow::Error>> {
         Box::pin(async move {
             match <ZmoFile as RoseFile>::read(bytes.into(), &Default::default()) {
+                Ok(zmo) if is_vertex_animation(&zmo) => {
+                    load_context.set_default_asset(LoadedAsset::new(ZmoAsset {
+                        num_frames: zmo.num_frames,
+                        fps: zmo.fps,
+                        bones: Vec::new(),
+                        vertices: load_vertices(&zmo),
+                        frame_events: zmo.frame_events,
+                        interpolation_interval: (zmo.interpolation_interval_ms.unwrap_or(500)
+                            as f32
+                            / 1000.0)
+                            .max(0.0001),
+                        animation_texture: None,
+                    }));
+                    Ok(())
+                }
                 Ok(zmo) => {
                     // First count how many transform channels there are
                     let mut max_bone_id = 0;
@@ -181,6 +255,7 @@ impl AssetLoader for ZmoAssetLoader {
                         num_frames: zmo.num_frames,
                         fps: zmo.fps,
                         bones,
+                        vertices: Vec::new(),
                         frame_events: zmo.frame_events,
                         interpolation_interval: (zmo.interpolation_interval_ms.unwrap_or(500)
                             as f32
@@ -328,6 +403,7 @@ impl AssetLoader for ZmoTextureAssetLoader {
                             / 1000.0)
                             .max(0.0001),
                         bones: Vec::new(),
+                        vertices: Vec::new(),
                         animation_texture: Some(ZmoAssetAnimationTexture {
                             texture: texture_handle,
                             alphas,
@@ -1,8 +1,19 @@
-use bevy::prelude::{AddAsset, App, Component, IntoSystemConfigs, Last, Plugin, Resource};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use bevy::prelude::{
+    AddAsset, App, Component, IntoSystemConfigs, Last, NonSendMut, Plugin, Res, ResMut, Resource,
+};
 
 mod audio_source;
 mod global_sound;
 mod ogg;
+mod sound_attenuation;
 mod spatial_sound;
 mod streaming_sound;
 mod wav;
@@ -45,64 +56,259 @@ use wav::WavLoader;
 
 pub use audio_source::{AudioSource, StreamingAudioSource};
 pub use global_sound::GlobalSound;
+pub use sound_attenuation::{AttenuationCurve, SoundAttenuation};
 pub use spatial_sound::SpatialSound;
 
 use self::{
     global_sound::global_sound_gain_changed_system,
     spatial_sound::spatial_sound_gain_changed_system,
 };
+use crate::resources::SoundSettings;
 
-pub struct OddioPlugin;
+const FALLBACK_SAMPLE_RATE: u32 = 44100;
 
-impl Plugin for OddioPlugin {
-    fn build(&self, app: &mut App) {
-        let host = cpal::default_host();
+// How often to check if the default output device has changed
+const OUTPUT_DEVICE_CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Names of the audio output devices which can be selected in the settings.
+#[derive(Resource, Default)]
+pub struct AvailableAudioDevices {
+    pub output_devices: Vec<String>,
+
+    /// Set to enumerate the output devices again on the next update
+    pub refresh: bool,
+}
+
+type CreateOutputStreamFn =
+    Box<dyn Fn(Option<&str>) -> Result<(cpal::Stream, u32, String), anyhow::Error>>;
+
+/// The current output stream, which is recreated when the selected device changes
+/// or the device stops being available, e.g. when headphones are unplugged.
+struct AudioOutputStream {
+    // Only held to keep the stream playing
+    #[allow(dead_code)]
+    stream: Option<cpal::Stream>,
+    device_name: Option<String>,
+    selected_device: Option<String>,
+    stream_error: Arc<AtomicBool>,
+    last_device_check: Instant,
+
+    // Keeps the type of the root mixer signal out of the resource
+    create_stream: CreateOutputStreamFn,
+}
+
+fn get_output_device_names() -> Vec<String> {
+    match cpal::default_host().output_devices() {
+        Ok(devices) => devices.filter_map(|device| device.name().ok()).collect(),
+        Err(error) => {
+            log::warn!(
+                "Failed to enumerate audio output devices with error: {}",
+                error
+            );
+            Vec::new()
+        }
+    }
+}
+
+/// Copies the stereo output of the mixer to a device with a different number of
+/// channels, mono devices get a downmix and extra channels are left silent.
+fn write_output_channels(stereo_frames: &[[f32; 2]], output: &mut [f32], channels: usize) {
+    for (output_frame, [left, right]) in output.chunks_mut(channels).zip(stereo_frames.iter()) {
+        if channels == 1 {
+            output_frame[0] = (left + right) * 0.5;
+        } else {
+            output_frame[0] = *left;
+            output_frame[1] = *right;
+            output_frame[2..].fill(0.0);
+        }
+    }
+}
+
+fn create_output_stream<S>(
+    root_mixer: &Arc<Mutex<S>>,
+    device_name: Option<&str>,
+    stream_error: Arc<AtomicBool>,
+) -> Result<(cpal::Stream, u32, String), anyhow::Error>
+where
+    S: oddio::Signal<Frame = [f32; 2]> + Send + 'static,
+{
+    let host = cpal::default_host();
+    let selected_device = device_name.and_then(|device_name| {
         let device = host
+            .output_devices()
+            .ok()?
+            .find(|device| device.name().map_or(false, |name| name == device_name));
+        if device.is_none() {
+            log::warn!(
+                "Audio output device {} not found, using the default device",
+                device_name
+            );
+        }
+        device
+    });
+    let device = selected_device
+        .or_else(|| host.default_output_device())
+        .ok_or_else(|| anyhow::anyhow!("no output device available"))?;
+    let device_name = device.name().unwrap_or_default();
+    let default_config = device.default_output_config()?;
+    let sample_rate = default_config.sample_rate();
+    let channels = default_config.channels().max(1);
+    let config = cpal::StreamConfig {
+        channels,
+        sample_rate,
+        buffer_size: cpal::BufferSize::Default,
+    };
+
+    if channels != 2 {
+        log::info!(
+            "Audio output device has {} channels, converting from stereo",
+            channels
+        );
+    }
+
+    let channels = channels as usize;
+    let mut stereo_frames: Vec<[f32; 2]> = Vec::new();
+    let root_mixer = root_mixer.clone();
+    let stream = device.build_output_stream(
+        &config,
+        move |data: &mut [f32], _: &cpal::OutputCallbackInfo| {
+            let Ok(root_mixer) = root_mixer.lock() else {
+                data.fill(0.0);
+                return;
+            };
+
+            if channels == 2 {
+                let frames = oddio::frame_stereo(data);
+                oddio::run(&*root_mixer, sample_rate.0, frames);
+            } else {
+                stereo_frames.resize(data.len() / channels, [0.0, 0.0]);
+                oddio::run(&*root_mixer, sample_rate.0, &mut stereo_frames);
+                write_output_channels(&stereo_frames, data, channels);
+            }
+        },
+        move |err| {
+            eprintln!("{}", err);
+
+            if matches!(err, cpal::StreamError::DeviceNotAvailable) {
+                stream_error.store(true, Ordering::Relaxed);
+            }
+        },
+        None,
+    )?;
+    stream.play()?;
+
+    Ok((stream, sample_rate.0, device_name))
+}
+
+fn audio_output_device_system(
+    mut audio_output: NonSendMut<AudioOutputStream>,
+    mut oddio_context: ResMut<OddioContext>,
+    mut available_audio_devices: ResMut<AvailableAudioDevices>,
+    sound_settings: Res<SoundSettings>,
+) {
+    if available_audio_devices.refresh {
+        available_audio_devices.output_devices = get_output_device_names();
+        available_audio_devices.refresh = false;
+    }
+
+    let mut recreate_stream = audio_output.stream_error.swap(false, Ordering::Relaxed)
+        || audio_output.selected_device != sound_settings.output_device;
+
+    if !recreate_stream
+        && sound_settings.output_device.is_none()
+        && audio_output.last_device_check.elapsed() > OUTPUT_DEVICE_CHECK_INTERVAL
+    {
+        audio_output.last_device_check = Instant::now();
+
+        let default_device_name = cpal::default_host()
             .default_output_device()
-            .expect("no output device available");
-        let sample_rate = device.default_output_config().unwrap().sample_rate();
-        let config = cpal::StreamConfig {
-            channels: 2,
-            sample_rate,
-            buffer_size: cpal::BufferSize::Default,
-        };
+            .and_then(|device| device.name().ok());
+        if default_device_name.is_some() && default_device_name != audio_output.device_name {
+            recreate_stream = true;
+        }
+    }
+
+    if !recreate_stream {
+        return;
+    }
 
+    // Release the previous device before opening the new one
+    audio_output.stream = None;
+    audio_output.selected_device = sound_settings.output_device.clone();
+
+    match (audio_output.create_stream)(audio_output.selected_device.as_deref()) {
+        Ok((stream, sample_rate, device_name)) => {
+            log::info!("Using audio output device {}", device_name);
+            audio_output.stream = Some(stream);
+            audio_output.device_name = Some(device_name);
+            oddio_context.sample_rate = sample_rate;
+        }
+        Err(error) => {
+            log::warn!("Failed to create audio output stream with error: {}", error);
+
+            // Do not retry until the default device changes
+            audio_output.device_name = cpal::default_host()
+                .default_output_device()
+                .and_then(|device| device.name().ok());
+        }
+    }
+}
+
+pub struct OddioPlugin;
+
+impl Plugin for OddioPlugin {
+    fn build(&self, app: &mut App) {
         let (mut root_mixer_handle, root_mixer) = oddio::split(oddio::Mixer::new());
         let (scene_handle, scene) = oddio::split(oddio::SpatialScene::new());
         root_mixer_handle.control().play(scene);
 
-        let stream = device
-            .build_output_stream(
-                &config,
-                move |data: &mut [f32], _: &cpal::OutputCallbackInfo| {
-                    let frames = oddio::frame_stereo(data);
-                    oddio::run(&root_mixer, sample_rate.0, frames);
-                },
-                move |err| {
-                    eprintln!("{}", err);
-                },
-                None,
-            )
-            .unwrap();
-        stream.play().unwrap();
-
-        app.insert_non_send_resource(stream)
-            .insert_resource(OddioContext {
-                mixer: root_mixer_handle,
-                spatial: scene_handle,
-                sample_rate: sample_rate.0,
+        let root_mixer = Arc::new(Mutex::new(root_mixer));
+        let stream_error = Arc::new(AtomicBool::new(false));
+        let create_stream: CreateOutputStreamFn = {
+            let stream_error = stream_error.clone();
+            Box::new(move |device_name: Option<&str>| {
+                create_output_stream(&root_mixer, device_name, stream_error.clone())
             })
-            .add_asset::<AudioSource>()
-            .init_asset_loader::<OggLoader>()
-            .init_asset_loader::<WavLoader>()
-            .add_systems(
-                Last,
-                (
-                    spatial_sound_gain_changed_system.before(spatial_sound_system),
-                    spatial_sound_system,
-                    global_sound_gain_changed_system.before(global_sound_system),
-                    global_sound_system,
-                ),
-            );
+        };
+
+        // Continue without sound output when there is no audio device, e.g. when running headless
+        let (stream, device_name, sample_rate) = match create_stream(None) {
+            Ok((stream, sample_rate, device_name)) => {
+                (Some(stream), Some(device_name), sample_rate)
+            }
+            Err(error) => {
+                log::warn!("Failed to create audio output stream with error: {}", error);
+                (None, None, FALLBACK_SAMPLE_RATE)
+            }
+        };
+
+        app.insert_non_send_resource(AudioOutputStream {
+            stream,
+            device_name,
+            selected_device: None,
+            stream_error,
+            last_device_check: Instant::now(),
+            create_stream,
+        });
+
+        app.insert_resource(OddioContext {
+            mixer: root_mixer_handle,
+            spatial: scene_handle,
+            sample_rate,
+        })
+        .init_resource::<AvailableAudioDevices>()
+        .add_asset::<AudioSource>()
+        .init_asset_loader::<OggLoader>()
+        .init_asset_loader::<WavLoader>()
+        .add_systems(
+            Last,
+            (
+                spatial_sound_gain_changed_system.before(spatial_sound_system),
+                spatial_sound_system,
+                global_sound_gain_changed_system.before(global_sound_system),
+                global_sound_system,
+                audio_output_device_system,
+            ),
+        );
     }
 }
@@ -0,0 +1,82 @@
+use bevy::prelude::Component;
+use serde::Deserialize;
+
+/// The shape of the volume falloff between the minimum and maximum distance of a sound.
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AttenuationCurve {
+    Linear,
+    /// Falls off quickly near the sound and slowly far away, so distant sounds stay audible.
+    Logarithmic,
+    /// Pairs of (fraction of the way from minimum to maximum distance, gain), sorted by
+    /// distance. The gain is interpolated linearly between points.
+    Custom {
+        points: Vec<(f32, f32)>,
+    },
+}
+
+/// Overrides the default distance falloff of a spatial sound, the sound is at full volume
+/// within min_distance and silent beyond max_distance.
+#[derive(Component, Clone, Debug, PartialEq, Deserialize)]
+pub struct SoundAttenuation {
+    pub curve: AttenuationCurve,
+    pub min_distance: f32,
+    pub max_distance: f32,
+}
+
+impl SoundAttenuation {
+    pub fn linear(min_distance: f32, max_distance: f32) -> Self {
+        Self {
+            curve: AttenuationCurve::Linear,
+            min_distance,
+            max_distance,
+        }
+    }
+
+    pub fn logarithmic(min_distance: f32, max_distance: f32) -> Self {
+        Self {
+            curve: AttenuationCurve::Logarithmic,
+            min_distance,
+            max_distance,
+        }
+    }
+
+    /// Returns the amplitude ratio to apply to a sound at the given distance from the listener.
+    pub fn gain(&self, distance: f32) -> f32 {
+        let min_distance = self.min_distance.max(0.01);
+        if distance <= min_distance {
+            return 1.0;
+        }
+
+        if distance >= self.max_distance {
+            return 0.0;
+        }
+
+        let gain = match &self.curve {
+            AttenuationCurve::Linear => {
+                1.0 - (distance - min_distance) / (self.max_distance - min_distance)
+            }
+            AttenuationCurve::Logarithmic => {
+                1.0 - (distance / min_distance).ln() / (self.max_distance / min_distance).ln()
+            }
+            AttenuationCurve::Custom { points } => {
+                let fraction = (distance - min_distance) / (self.max_distance - min_distance);
+                match points.iter().position(|&(x, _)| x >= fraction) {
+                    Some(0) => points[0].1,
+                    Some(index) => {
+                        let (x0, y0) = points[index - 1];
+                        let (x1, y1) = points[index];
+                        if x1 > x0 {
+                            y0 + (y1 - y0) * (fraction - x0) / (x1 - x0)
+                        } else {
+                            y1
+                        }
+                    }
+                    None => points.last().map_or(0.0, |&(_, y)| y),
+                }
+            }
+        };
+
+        gain.clamp(0.0, 1.0)
+    }
+}
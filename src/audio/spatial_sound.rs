@@ -2,15 +2,16 @@ use bevy::{
     asset::LoadState,
     math::Vec3,
     prelude::{
-        AssetServer, Assets, Camera3d, Changed, Commands, Component, Entity, GlobalTransform,
-        Handle, Local, Query, Res, ResMut, With,
+        AssetServer, Assets, Changed, Commands, Component, Entity, GlobalTransform, Handle, Local,
+        Query, Res, ResMut, With,
     },
     time::Time,
 };
 
 use crate::{
-    audio::{AudioSource, OddioContext, SoundGain, SoundRadius, StreamingSound},
-    components::PlayerCharacter,
+    audio::{AudioSource, OddioContext, SoundAttenuation, SoundGain, SoundRadius, StreamingSound},
+    components::{MainCamera, PlayerCharacter, SoundCategory},
+    resources::{SoundCache, SoundSettings},
 };
 
 struct SpatialControlHandle(
@@ -43,6 +44,16 @@ pub struct SpatialSound {
     control_handle: Option<SpatialControlHandle>,
     streaming_sound: Option<StreamingSound>,
     last_position: Option<Vec3>,
+    /// The falloff curve chosen when the sound started playing, if any.
+    attenuation: Option<SoundAttenuation>,
+}
+
+fn sound_gain_ratio(sound_gain: Option<&SoundGain>) -> f32 {
+    match sound_gain {
+        Some(&SoundGain::Decibel(db)) => 10.0f32.powf(db / 20.0),
+        Some(&SoundGain::Ratio(factor)) => factor,
+        None => 1.0,
+    }
 }
 
 #[allow(dead_code)]
@@ -54,6 +65,7 @@ impl SpatialSound {
             control_handle: None,
             streaming_sound: None,
             last_position: None,
+            attenuation: None,
         }
     }
 
@@ -64,6 +76,7 @@ impl SpatialSound {
             control_handle: None,
             streaming_sound: None,
             last_position: None,
+            attenuation: None,
         }
     }
 }
@@ -72,6 +85,11 @@ pub fn spatial_sound_gain_changed_system(
     mut query: Query<(&mut SpatialSound, &SoundGain), Changed<SoundGain>>,
 ) {
     for (mut spatial_sound, gain) in query.iter_mut() {
+        if spatial_sound.attenuation.is_some() {
+            // The gain is updated along with the distance in spatial_sound_system
+            continue;
+        }
+
         if let Some(handle) = spatial_sound.control_handle.as_mut() {
             match *gain {
                 SoundGain::Decibel(db) => handle.gain_control().set_gain(db),
@@ -86,17 +104,21 @@ pub fn spatial_sound_system(
     mut context: ResMut<OddioContext>,
     audio: Res<Assets<AudioSource>>,
     asset_server: Res<AssetServer>,
-    camera: Query<&GlobalTransform, With<Camera3d>>,
+    camera: Query<&GlobalTransform, With<MainCamera>>,
     mut query_spatial_sounds: Query<(
         Entity,
         &mut SpatialSound,
         &GlobalTransform,
         Option<&SoundRadius>,
         Option<&SoundGain>,
+        Option<&SoundAttenuation>,
+        Option<&SoundCategory>,
     )>,
     mut last_listener_position: Local<Option<Vec3>>,
     query_player: Query<&GlobalTransform, With<PlayerCharacter>>,
     time: Res<Time>,
+    sound_settings: Res<SoundSettings>,
+    sound_cache: Option<Res<SoundCache>>,
 ) {
     let player = &mut context.spatial;
     let (_, camera_rotation, camera_position) = camera.single().to_scale_rotation_translation();
@@ -120,14 +142,22 @@ pub fn spatial_sound_system(
         .control()
         .set_listener_rotation(camera_rotation.to_array().into());
 
-    for (entity, mut spatial_sound, global_transform, sound_radius, sound_gain) in
-        query_spatial_sounds.iter_mut()
+    for (
+        entity,
+        mut spatial_sound,
+        global_transform,
+        sound_radius,
+        sound_gain,
+        sound_attenuation,
+        sound_category,
+    ) in query_spatial_sounds.iter_mut()
     {
         let repeating = spatial_sound.repeating;
         let SpatialSound {
             control_handle,
             streaming_sound,
             last_position,
+            attenuation,
             ..
         } = &mut *spatial_sound;
 
@@ -150,8 +180,9 @@ pub fn spatial_sound_system(
         };
 
         // Adjust spatial position to be in direction of camera, but distance from player
-        let spatial_position = (sound_global_translation - camera_position).normalize()
-            * (sound_global_translation - listener_position).length();
+        let listener_distance = (sound_global_translation - listener_position).length();
+        let spatial_position =
+            (sound_global_translation - camera_position).normalize() * listener_distance;
 
         if let Some(handle) = control_handle.as_mut() {
             let has_more_audio = if let Some(streaming_sound) = streaming_sound.as_mut() {
@@ -166,6 +197,12 @@ pub fn spatial_sound_system(
                 false,
             );
 
+            if let Some(attenuation) = attenuation.as_ref() {
+                handle.gain_control().set_amplitude_ratio(
+                    sound_gain_ratio(sound_gain) * attenuation.gain(listener_distance),
+                );
+            }
+
             if !has_more_audio {
                 spatial_sound.control_handle = None;
                 spatial_sound.asset_handle = Handle::default();
@@ -175,13 +212,37 @@ pub fn spatial_sound_system(
             let mut streaming_sound = StreamingSound::new(audio_source);
             let sample_rate = streaming_sound.sample_rate();
 
+            // An explicit falloff on the entity overrides the configured falloff of the sound
+            let attenuation = sound_attenuation.cloned().or_else(|| {
+                let sound_id = sound_cache
+                    .as_ref()
+                    .and_then(|sound_cache| sound_cache.get_sound_id(&spatial_sound.asset_handle));
+                sound_settings
+                    .attenuation(sound_category.copied(), sound_id)
+                    .cloned()
+            });
+
             let stream_signal = oddio::Stream::new(sample_rate, sample_rate as usize / 8);
-            let gain_signal = match sound_gain {
-                Some(&SoundGain::Decibel(db)) => oddio::Gain::with_gain(stream_signal, db),
-                Some(&SoundGain::Ratio(factor)) => {
-                    oddio::Gain::with_amplitude_ratio(stream_signal, factor)
+            let gain_signal = if let Some(attenuation) = attenuation.as_ref() {
+                oddio::Gain::with_amplitude_ratio(
+                    stream_signal,
+                    sound_gain_ratio(sound_gain) * attenuation.gain(listener_distance),
+                )
+            } else {
+                match sound_gain {
+                    Some(&SoundGain::Decibel(db)) => oddio::Gain::with_gain(stream_signal, db),
+                    Some(&SoundGain::Ratio(factor)) => {
+                        oddio::Gain::with_amplitude_ratio(stream_signal, factor)
+                    }
+                    None => oddio::Gain::new(stream_signal),
                 }
-                None => oddio::Gain::new(stream_signal),
+            };
+
+            // Our falloff replaces oddio's, so only let oddio attenuate beyond the max distance
+            let radius = if let Some(attenuation) = attenuation.as_ref() {
+                attenuation.max_distance
+            } else {
+                sound_radius.map(|x| x.0).unwrap_or(4.0)
             };
 
             let mut handle = SpatialControlHandle(player.control().play_buffered(
@@ -189,7 +250,7 @@ pub fn spatial_sound_system(
                 oddio::SpatialOptions {
                     position: spatial_position.to_array().into(),
                     velocity: spatial_velocity.to_array().into(),
-                    radius: sound_radius.map(|x| x.0).unwrap_or(4.0),
+                    radius,
                 },
                 500.0,
                 sample_rate,
@@ -207,6 +268,7 @@ pub fn spatial_sound_system(
 
             spatial_sound.control_handle = Some(handle);
             spatial_sound.streaming_sound = Some(streaming_sound);
+            spatial_sound.attenuation = attenuation;
         } else if matches!(
             asset_server.get_load_state(&spatial_sound.asset_handle),
             LoadState::Failed | LoadState::Unloaded
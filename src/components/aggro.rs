@@ -0,0 +1,14 @@
+use std::time::Duration;
+
+use bevy::{
+    prelude::{Component, Entity},
+    reflect::Reflect,
+};
+
+/// Added to monsters which are attacking the player or one of their party members.
+#[derive(Component, Reflect)]
+pub struct Aggro {
+    pub target: Entity,
+    pub is_targeting_player: bool,
+    pub last_attack_time: Duration,
+}
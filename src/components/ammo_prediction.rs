@@ -0,0 +1,36 @@
+use bevy::prelude::Component;
+use enum_map::EnumMap;
+
+use rose_data::AmmoIndex;
+
+#[derive(Copy, Clone, Default)]
+pub struct AmmoPredictionSlot {
+    /// The quantity last reported by the server.
+    pub server_quantity: u32,
+    /// Shots fired since the server last reported the quantity.
+    pub used: u32,
+}
+
+/// Client side prediction of the remaining ammo, the server only updates the
+/// equipped ammo when it runs out so the shots fired are counted here to warn
+/// when running low. The Equipment component keeps the server reported quantity.
+#[derive(Component, Default)]
+pub struct AmmoPrediction {
+    pub slots: EnumMap<AmmoIndex, AmmoPredictionSlot>,
+}
+
+impl AmmoPrediction {
+    /// Counts a shot and returns the predicted remaining quantity.
+    pub fn use_ammo(&mut self, ammo_index: AmmoIndex, server_quantity: u32) -> u32 {
+        let slot = &mut self.slots[ammo_index];
+        if slot.server_quantity != server_quantity {
+            *slot = AmmoPredictionSlot {
+                server_quantity,
+                used: 0,
+            };
+        }
+
+        slot.used = (slot.used + 1).min(server_quantity);
+        server_quantity - slot.used
+    }
+}
@@ -2,7 +2,24 @@ use bevy::ecs::prelude::Component;
 
 use rose_data::Item;
 
+pub const BANK_SLOTS_PER_PAGE: usize = 40;
+
+/// The number of pages in the standard character bank, the last of which is premium storage.
+pub const BANK_CHARACTER_PAGES: usize = 4;
+
 #[derive(Component)]
 pub struct Bank {
     pub slots: Vec<Option<Item>>,
 }
+
+impl Bank {
+    /// Servers which support account shared storage send slots beyond the
+    /// standard character bank pages, these are displayed as shared pages.
+    pub fn get_shared_page_count(&self) -> usize {
+        let shared_slots = self
+            .slots
+            .len()
+            .saturating_sub(BANK_CHARACTER_PAGES * BANK_SLOTS_PER_PAGE);
+        (shared_slots + BANK_SLOTS_PER_PAGE - 1) / BANK_SLOTS_PER_PAGE
+    }
+}
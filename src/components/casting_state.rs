@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use bevy::prelude::{Assets, Component, Entity, Handle};
+
+use rose_data::SkillId;
+
+use crate::animation::ZmoAsset;
+
+/// Present while an entity is casting a skill, until the skill's action starts or the cast
+/// is cancelled.
+#[derive(Component)]
+pub struct CastingState {
+    pub skill_id: SkillId,
+    pub target_entity: Option<Entity>,
+    /// The casting motion, the cast is expected to last as long as it takes to play.
+    pub motion: Option<Handle<ZmoAsset>>,
+    pub motion_speed: f32,
+    pub start_time: Duration,
+}
+
+impl CastingState {
+    pub fn new(
+        skill_id: SkillId,
+        target_entity: Option<Entity>,
+        motion: Option<Handle<ZmoAsset>>,
+        motion_speed: f32,
+        start_time: Duration,
+    ) -> Self {
+        Self {
+            skill_id,
+            target_entity,
+            motion,
+            motion_speed,
+            start_time,
+        }
+    }
+
+    /// Returns how far through the cast we are, from 0 to 1. Once the casting motion has
+    /// finished we are waiting for the server, so the cast stays at 1.
+    pub fn get_progress(&self, now: Duration, zmo_assets: &Assets<ZmoAsset>) -> Option<f32> {
+        let zmo_asset = zmo_assets.get(self.motion.as_ref()?)?;
+        if zmo_asset.fps == 0 || self.motion_speed <= 0.0 {
+            return None;
+        }
+
+        let duration = zmo_asset.num_frames as f32 / zmo_asset.fps as f32 / self.motion_speed;
+        let elapsed = now.saturating_sub(self.start_time).as_secs_f32();
+        Some((elapsed / duration).min(1.0))
+    }
+}
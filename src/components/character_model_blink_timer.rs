@@ -3,6 +3,8 @@ use std::ops::Range;
 use bevy::{prelude::Component, reflect::Reflect};
 use rand::Rng;
 
+use crate::resources::ReplayRng;
+
 #[derive(Component, Reflect)]
 pub struct CharacterBlinkTimer {
     pub timer: f32,
@@ -19,8 +21,8 @@ impl CharacterBlinkTimer {
         Self {
             timer: 0.0,
             is_open: false,
-            closed_duration: rand::thread_rng().gen_range(Self::BLINK_CLOSED_DURATION),
-            open_duration: rand::thread_rng().gen_range(Self::BLINK_OPEN_DURATION),
+            closed_duration: ReplayRng.gen_range(Self::BLINK_CLOSED_DURATION),
+            open_duration: ReplayRng.gen_range(Self::BLINK_OPEN_DURATION),
         }
     }
 }
@@ -0,0 +1,7 @@
+use bevy::{prelude::Component, reflect::Reflect};
+
+/// Title displayed under a character's name tag.
+#[derive(Component, Reflect)]
+pub struct CharacterTitle {
+    pub title: String,
+}
@@ -0,0 +1,31 @@
+use std::time::Duration;
+
+use bevy::{
+    prelude::Component,
+    time::{Timer, TimerMode},
+};
+
+const CHAT_BUBBLE_MIN_DURATION: Duration = Duration::from_secs(3);
+const CHAT_BUBBLE_MAX_DURATION: Duration = Duration::from_secs(10);
+const CHAT_BUBBLE_DURATION_PER_CHARACTER: Duration = Duration::from_millis(80);
+
+/// Local chat message displayed above the character who said it.
+#[derive(Component)]
+pub struct ChatBubble {
+    pub text: String,
+    pub timer: Timer,
+}
+
+impl ChatBubble {
+    /// Longer messages stay visible for longer so there is time to read them.
+    pub fn new(text: String) -> Self {
+        let duration = (CHAT_BUBBLE_MIN_DURATION
+            + CHAT_BUBBLE_DURATION_PER_CHARACTER * text.chars().count() as u32)
+            .min(CHAT_BUBBLE_MAX_DURATION);
+
+        Self {
+            text,
+            timer: Timer::new(duration, TimerMode::Once),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use std::time::Duration;
+
+use bevy::{prelude::Component, reflect::Reflect};
+
+/// Added to monsters which the player or one of their party members is fighting,
+/// so their name tag health bar is visible without selecting them.
+#[derive(Component, Reflect)]
+pub struct CombatHealthbar {
+    pub last_combat_time: Duration,
+}
@@ -0,0 +1,6 @@
+use bevy::prelude::Component;
+
+/// The camera which renders the conversation dialog portrait, this should be
+/// excluded from queries which expect the main game camera.
+#[derive(Component)]
+pub struct ConversationDialogPortraitCamera;
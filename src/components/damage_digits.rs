@@ -1,6 +1,14 @@
-use bevy::prelude::Component;
+use bevy::{
+    math::{Vec3, Vec4},
+    prelude::Component,
+};
 
 #[derive(Component)]
 pub struct DamageDigits {
     pub damage: u32,
+    pub color: Vec4,
+    pub scale: f32,
+
+    /// The animation translation is relative to this position
+    pub position: Vec3,
 }
@@ -6,6 +6,9 @@ pub struct DummyBoneOffset {
 }
 
 impl DummyBoneOffset {
+    /// The dummy bone at the top of a character's head, which hats are attached to.
+    pub const CHARACTER_HEAD: usize = 6;
+
     pub fn new(index: usize) -> Self {
         Self { index }
     }
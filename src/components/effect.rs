@@ -11,6 +11,12 @@ impl Effect {
     }
 }
 
+/// An effect root entity which is returned to the effect pool instead of despawned.
+/// Only used for free standing effects which are despawned by effect_system, so
+/// the entity id is never held anywhere else.
+#[derive(Component, Default, Reflect)]
+pub struct PooledEffect {}
+
 #[derive(Component, Default, Reflect)]
 pub struct EffectMesh {}
 
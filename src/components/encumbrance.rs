@@ -0,0 +1,24 @@
+use bevy::{prelude::Component, reflect::Reflect};
+
+/// Weight ratio at which the player is warned they are close to being over encumbered.
+pub const ENCUMBRANCE_WARNING_RATIO: f32 = 0.9;
+
+#[derive(Component, Default, Reflect)]
+pub struct Encumbrance {
+    pub weight: u32,
+    pub max_weight: u32,
+}
+
+impl Encumbrance {
+    pub fn get_ratio(&self) -> f32 {
+        if self.max_weight == 0 {
+            0.0
+        } else {
+            self.weight as f32 / self.max_weight as f32
+        }
+    }
+
+    pub fn is_over_encumbered(&self) -> bool {
+        self.weight > self.max_weight
+    }
+}
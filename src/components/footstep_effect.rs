@@ -0,0 +1,15 @@
+use bevy::prelude::Component;
+
+use crate::resources::FootstepEffectKind;
+
+/// A pooled dust puff or footprint, hidden once it has faded out so it can be reused.
+#[derive(Component)]
+pub struct FootstepEffect {
+    pub kind: FootstepEffectKind,
+    /// The terrain tile texture the effect was coloured for.
+    pub tile_index: Option<usize>,
+    pub scale: f32,
+    pub age: f32,
+    pub lifetime: f32,
+    pub fade_level: usize,
+}
@@ -7,3 +7,7 @@ pub struct ItemDropModel {
     pub root_bone: Entity,
     pub model_parts: Vec<Entity>,
 }
+
+/// The beam and glow shown over a valuable item drop.
+#[derive(Component)]
+pub struct ItemDropEffectEntity(pub Entity);
@@ -0,0 +1,8 @@
+use bevy::{prelude::Component, reflect::Reflect};
+
+use rose_game_common::messages::ClientEntityId;
+
+#[derive(Component, Reflect)]
+pub struct ItemDropOwner {
+    pub owner_entity_id: Option<ClientEntityId>,
+}
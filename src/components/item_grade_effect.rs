@@ -0,0 +1,23 @@
+use bevy::{math::Vec3, prelude::Component, reflect::Reflect};
+
+use crate::render::ObjectMaterialGlow;
+
+/// Added to character model parts which glow due to the grade of their equipped item.
+#[derive(Component, Reflect)]
+pub struct ItemGradeGlow {
+    pub colour: Vec3,
+}
+
+impl ItemGradeGlow {
+    pub fn get_material_glow(&self, intensity: f32) -> Option<ObjectMaterialGlow> {
+        if intensity > 0.0 {
+            Some(ObjectMaterialGlow::Light(self.colour * intensity))
+        } else {
+            None
+        }
+    }
+}
+
+/// Added to the particle effect attached to a character model part due to the grade of its equipped item.
+#[derive(Component, Reflect)]
+pub struct ItemGradeParticleEffect;
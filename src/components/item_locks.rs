@@ -0,0 +1,215 @@
+use bevy::prelude::Component;
+use serde::{Deserialize, Serialize};
+
+use rose_data::{EquipmentItem, Item, ItemReference, StackableItem};
+use rose_game_common::components::{Equipment, Inventory, InventoryPageType, ItemSlot};
+
+use crate::resources::CharacterStoreKey;
+
+/// What an item is, used to check a locked slot still holds the item which was
+/// locked. Durability and life are not part of it as they change while the
+/// item is used.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedItemIdentity {
+    pub item: ItemReference,
+    pub gem: u16,
+    pub grade: u8,
+    pub has_socket: bool,
+    pub is_crafted: bool,
+}
+
+impl LockedItemIdentity {
+    pub fn new(item: &Item) -> Self {
+        match item {
+            Item::Equipment(equipment_item) => Self::from_equipment(equipment_item),
+            Item::Stackable(stackable_item) => Self::from_stackable(stackable_item),
+        }
+    }
+
+    fn from_equipment(equipment_item: &EquipmentItem) -> Self {
+        Self {
+            item: equipment_item.item,
+            gem: equipment_item.gem,
+            grade: equipment_item.grade,
+            has_socket: equipment_item.has_socket,
+            is_crafted: equipment_item.is_crafted,
+        }
+    }
+
+    fn from_stackable(stackable_item: &StackableItem) -> Self {
+        Self {
+            item: stackable_item.item,
+            gem: 0,
+            grade: 0,
+            has_socket: false,
+            is_crafted: false,
+        }
+    }
+}
+
+/// A lock is kept on a slot, so other stacks of the same item or identical
+/// equipment are not locked with it.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LockedItem {
+    pub slot: ItemSlot,
+    pub identity: LockedItemIdentity,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+struct ItemLocksStore {
+    items: Vec<LockedItem>,
+}
+
+fn get_player_items(
+    equipment: &Equipment,
+    inventory: &Inventory,
+) -> Vec<(ItemSlot, LockedItemIdentity)> {
+    let mut items = Vec::new();
+
+    for (page_type, page) in [
+        (InventoryPageType::Equipment, &inventory.equipment),
+        (InventoryPageType::Consumables, &inventory.consumables),
+        (InventoryPageType::Materials, &inventory.materials),
+        (InventoryPageType::Vehicles, &inventory.vehicles),
+    ] {
+        for (index, item) in page.slots.iter().enumerate() {
+            if let Some(item) = item {
+                items.push((
+                    ItemSlot::Inventory(page_type, index),
+                    LockedItemIdentity::new(item),
+                ));
+            }
+        }
+    }
+
+    for (equipment_index, item) in equipment.equipped_items.iter() {
+        if let Some(item) = item {
+            items.push((
+                ItemSlot::Equipment(equipment_index),
+                LockedItemIdentity::from_equipment(item),
+            ));
+        }
+    }
+
+    for (vehicle_part_index, item) in equipment.equipped_vehicle.iter() {
+        if let Some(item) = item {
+            items.push((
+                ItemSlot::Vehicle(vehicle_part_index),
+                LockedItemIdentity::from_equipment(item),
+            ));
+        }
+    }
+
+    for (ammo_index, item) in equipment.equipped_ammo.iter() {
+        if let Some(item) = item {
+            items.push((
+                ItemSlot::Ammo(ammo_index),
+                LockedItemIdentity::from_stackable(item),
+            ));
+        }
+    }
+
+    items
+}
+
+/// Client side item locks for the player character, a locked item cannot be
+/// dropped, sold to a NPC store or traded. Stored in the user's data directory
+/// so the locks are kept between sessions.
+#[derive(Default, Component)]
+pub struct ItemLocks {
+    pub store_key: Option<CharacterStoreKey>,
+    pub locked_items: Vec<LockedItem>,
+}
+
+impl ItemLocks {
+    pub fn load(store_key: CharacterStoreKey) -> Self {
+        let store: ItemLocksStore = store_key.load("item_locks");
+
+        Self {
+            store_key: Some(store_key),
+            locked_items: store.items,
+        }
+    }
+
+    fn save(&self) {
+        if let Some(store_key) = self.store_key.as_ref() {
+            store_key.save(
+                "item_locks",
+                &ItemLocksStore {
+                    items: self.locked_items.clone(),
+                },
+            );
+        }
+    }
+
+    pub fn is_locked(&self, slot: ItemSlot, item: &Item) -> bool {
+        let identity = LockedItemIdentity::new(item);
+        self.locked_items
+            .iter()
+            .any(|locked_item| locked_item.slot == slot && locked_item.identity == identity)
+    }
+
+    pub fn lock(&mut self, slot: ItemSlot, item: &Item) {
+        if !self.is_locked(slot, item) {
+            self.locked_items
+                .retain(|locked_item| locked_item.slot != slot);
+            self.locked_items.push(LockedItem {
+                slot,
+                identity: LockedItemIdentity::new(item),
+            });
+            self.save();
+        }
+    }
+
+    pub fn unlock(&mut self, slot: ItemSlot) {
+        let len = self.locked_items.len();
+        self.locked_items
+            .retain(|locked_item| locked_item.slot != slot);
+        if self.locked_items.len() != len {
+            self.save();
+        }
+    }
+
+    /// Moves each lock to follow its item when the item has moved to another
+    /// slot, and removes the locks of items which are no longer carried.
+    pub fn update_slots(&mut self, equipment: &Equipment, inventory: &Inventory) {
+        let items = get_player_items(equipment, inventory);
+        let is_in_slot = |locked_item: &LockedItem| {
+            items.iter().any(|(slot, identity)| {
+                *slot == locked_item.slot && *identity == locked_item.identity
+            })
+        };
+
+        let mut locked_slots: Vec<ItemSlot> = self
+            .locked_items
+            .iter()
+            .filter(|locked_item| is_in_slot(locked_item))
+            .map(|locked_item| locked_item.slot)
+            .collect();
+        let mut changed = false;
+
+        self.locked_items.retain_mut(|locked_item| {
+            if is_in_slot(locked_item) {
+                return true;
+            }
+            changed = true;
+
+            // Identical items cannot be told apart, so the lock moves to the
+            // first one which is not already locked
+            let Some(&(slot, _)) = items.iter().find(|(slot, identity)| {
+                *identity == locked_item.identity && !locked_slots.contains(slot)
+            }) else {
+                return false;
+            };
+
+            locked_item.slot = slot;
+            locked_slots.push(slot);
+            true
+        });
+
+        if changed {
+            self.save();
+        }
+    }
+}
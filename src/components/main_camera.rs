@@ -0,0 +1,7 @@
+use bevy::prelude::Component;
+
+/// The camera which renders the game world to the window, queries for the game
+/// camera should use this as other cameras such as the water reflection camera
+/// also have Camera3d.
+#[derive(Component)]
+pub struct MainCamera;
@@ -1,24 +1,39 @@
+mod aggro;
+mod ammo_prediction;
 mod bank;
+mod casting_state;
 mod character_model;
 mod character_model_blink_timer;
+mod character_title;
+mod chat_bubble;
 mod clan;
 mod clan_membership;
 mod client_entity;
 mod client_entity_name;
 mod collision;
+mod combat_healthbar;
 mod command;
+mod conversation_dialog_portrait_camera;
 mod cooldowns;
 mod damage_digits;
 mod dead;
 mod dummy_bone_offset;
 mod effect;
+mod encumbrance;
 mod event_object;
 mod facing_direction;
+mod footstep_effect;
 mod item_drop_model;
+mod item_drop_owner;
+mod item_grade_effect;
+mod item_locks;
+mod main_camera;
 mod model_height;
 mod name_tag_entity;
+mod networked_position_history;
 mod night_time_effect;
 mod npc_model;
+mod npc_quest_marker;
 mod particle_sequence;
 mod party_info;
 mod passive_recovery_time;
@@ -29,17 +44,24 @@ mod player_character;
 mod position;
 mod projectile;
 mod sound_category;
+mod status_effect_sources;
 mod vehicle;
 mod vehicle_model;
 mod vehicle_sound;
 mod visible_status_effects;
 mod warp_object;
+mod water_reflection_camera;
 mod zone;
 mod zone_object;
 
-pub use bank::Bank;
+pub use aggro::Aggro;
+pub use ammo_prediction::{AmmoPrediction, AmmoPredictionSlot};
+pub use bank::{Bank, BANK_CHARACTER_PAGES, BANK_SLOTS_PER_PAGE};
+pub use casting_state::CastingState;
 pub use character_model::{CharacterModel, CharacterModelPart, CharacterModelPartIndex};
 pub use character_model_blink_timer::CharacterBlinkTimer;
+pub use character_title::CharacterTitle;
+pub use chat_bubble::ChatBubble;
 pub use clan::{Clan, ClanMember};
 pub use clan_membership::ClanMembership;
 pub use client_entity::{ClientEntity, ClientEntityId, ClientEntityType};
@@ -52,25 +74,35 @@ pub use collision::{
     COLLISION_GROUP_ZONE_EVENT_OBJECT, COLLISION_GROUP_ZONE_OBJECT, COLLISION_GROUP_ZONE_TERRAIN,
     COLLISION_GROUP_ZONE_WARP_OBJECT, COLLISION_GROUP_ZONE_WATER,
 };
+pub use combat_healthbar::CombatHealthbar;
 pub use command::{
     Command, CommandAttack, CommandCastSkill, CommandCastSkillState, CommandCastSkillTarget,
     CommandEmote, CommandMove, CommandSit, NextCommand,
 };
+pub use conversation_dialog_portrait_camera::ConversationDialogPortraitCamera;
 pub use cooldowns::{ConsumableCooldownGroup, Cooldowns};
 pub use damage_digits::DamageDigits;
 pub use dead::Dead;
 pub use dummy_bone_offset::DummyBoneOffset;
-pub use effect::{Effect, EffectMesh, EffectParticle};
+pub use effect::{Effect, EffectMesh, EffectParticle, PooledEffect};
+pub use encumbrance::{Encumbrance, ENCUMBRANCE_WARNING_RATIO};
 pub use event_object::EventObject;
 pub use facing_direction::FacingDirection;
-pub use item_drop_model::ItemDropModel;
+pub use footstep_effect::FootstepEffect;
+pub use item_drop_model::{ItemDropEffectEntity, ItemDropModel};
+pub use item_drop_owner::ItemDropOwner;
+pub use item_grade_effect::{ItemGradeGlow, ItemGradeParticleEffect};
+pub use item_locks::{ItemLocks, LockedItem, LockedItemIdentity};
+pub use main_camera::MainCamera;
 pub use model_height::ModelHeight;
 pub use name_tag_entity::{
     NameTag, NameTagEntity, NameTagHealthbarBackground, NameTagHealthbarForeground, NameTagName,
     NameTagTargetMark, NameTagType, RemoveNameTagCommand,
 };
+pub use networked_position_history::NetworkedPositionHistory;
 pub use night_time_effect::NightTimeEffect;
 pub use npc_model::NpcModel;
+pub use npc_quest_marker::{NpcQuestMarker, NpcQuestMarkerType};
 pub use particle_sequence::{ActiveParticle, ParticleSequence};
 pub use party_info::{PartyInfo, PartyOwner};
 pub use passive_recovery_time::PassiveRecoveryTime;
@@ -78,16 +110,18 @@ pub use pending_damage_list::{PendingDamage, PendingDamageList};
 pub use pending_skill_effect_list::{
     PendingSkillEffect, PendingSkillEffectList, PendingSkillTarget, PendingSkillTargetList,
 };
-pub use personal_store::{PersonalStore, PersonalStoreModel};
+pub use personal_store::{PersonalStore, PersonalStoreModel, PersonalStoreSignboard};
 pub use player_character::PlayerCharacter;
 pub use position::Position;
 pub use projectile::{Projectile, ProjectileParabola, ProjectileTarget};
 pub use sound_category::SoundCategory;
+pub use status_effect_sources::{StatusEffectSource, StatusEffectSources};
 pub use vehicle::Vehicle;
 pub use vehicle_model::VehicleModel;
 pub use vehicle_sound::{VehicleSound, VehicleSoundState};
 pub use visible_status_effects::{VisibleStatusEffect, VisibleStatusEffects};
 pub use warp_object::WarpObject;
+pub use water_reflection_camera::WaterReflectionCamera;
 pub use zone::Zone;
 pub use zone_object::{
     ZoneObject, ZoneObjectAnimatedObject, ZoneObjectId, ZoneObjectPart,
@@ -0,0 +1,102 @@
+use std::{collections::VecDeque, time::Duration};
+
+use bevy::{
+    math::{Vec2, Vec3Swizzles},
+    prelude::{Component, Vec3},
+};
+
+// How long a correction from the server is blended over, so remote entities
+// do not snap to the corrected position
+const NETWORKED_POSITION_CORRECTION_DURATION: Duration = Duration::from_millis(150);
+
+const NETWORKED_POSITION_HISTORY_MAX_LENGTH: usize = 16;
+
+// Larger jumps than this are treated as a teleport and are not interpolated
+const NETWORKED_POSITION_SNAP_DISTANCE: f32 = 1000.0;
+
+/// Positions of a remote entity reported by the server, and the time the
+/// entity is at each of them.
+///
+/// Samples are only added when a position or move message arrives from the
+/// server. The entity is drawn interpolated between them, and once it has
+/// passed the last one it is drawn at its simulated `Position` again.
+#[derive(Component, Default)]
+pub struct NetworkedPositionHistory {
+    samples: VecDeque<(f64, Vec2)>,
+}
+
+impl NetworkedPositionHistory {
+    /// Adds a server position which the entity will be at by `time`.
+    ///
+    /// Any later positions are from a path the server has since replaced, so
+    /// they are removed.
+    pub fn push(&mut self, time: f64, position: Vec3) {
+        let position = position.xy();
+
+        while self
+            .samples
+            .back()
+            .map_or(false, |&(last_time, _)| last_time >= time)
+        {
+            self.samples.pop_back();
+        }
+
+        if let Some(&(_, last_position)) = self.samples.back() {
+            if last_position.distance(position) > NETWORKED_POSITION_SNAP_DISTANCE {
+                self.samples.clear();
+            }
+        }
+
+        if self.samples.len() == NETWORKED_POSITION_HISTORY_MAX_LENGTH {
+            self.samples.pop_front();
+        }
+        self.samples.push_back((time, position));
+    }
+
+    /// Returns the interpolated position at the given time, or `None` once
+    /// the last server position has been reached.
+    pub fn sample(&mut self, time: f64) -> Option<Vec2> {
+        while self.samples.len() > 1 && self.samples[1].0 <= time {
+            self.samples.pop_front();
+        }
+
+        let &(start_time, start_position) = self.samples.front()?;
+        let Some(&(end_time, end_position)) = self.samples.get(1) else {
+            self.samples.clear();
+            return None;
+        };
+
+        if time <= start_time {
+            return Some(start_position);
+        }
+
+        let t = ((time - start_time) / (end_time - start_time)) as f32;
+        Some(start_position.lerp(end_position, t.clamp(0.0, 1.0)))
+    }
+
+    /// Records a server move from the currently drawn position to
+    /// `destination`, arriving at the same time as the simulated movement.
+    pub fn push_move(&mut self, time: f64, from: Vec3, destination: Vec3, move_speed: f32) {
+        let from = self.sample(time).map_or(from, |drawn| drawn.extend(from.z));
+        let duration = if move_speed > 0.0 {
+            (from.xy().distance(destination.xy()) / move_speed) as f64
+        } else {
+            0.0
+        };
+
+        self.push(time, from);
+        self.push(time + duration, destination);
+    }
+
+    /// Records a server position which the entity is corrected to, blending
+    /// from the currently drawn position.
+    pub fn push_correction(&mut self, time: f64, from: Vec3, position: Vec3) {
+        let from = self.sample(time).map_or(from, |drawn| drawn.extend(from.z));
+
+        self.push(time, from);
+        self.push(
+            time + NETWORKED_POSITION_CORRECTION_DURATION.as_secs_f64(),
+            position,
+        );
+    }
+}
@@ -0,0 +1,17 @@
+use bevy::{
+    prelude::{Component, Entity},
+    reflect::Reflect,
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Reflect)]
+pub enum NpcQuestMarkerType {
+    Available,
+    Complete,
+}
+
+/// Added to NPCs which currently have a quest available or ready to complete for the player.
+#[derive(Component, Reflect)]
+pub struct NpcQuestMarker {
+    pub marker_type: NpcQuestMarkerType,
+    pub rect_entity: Entity,
+}
@@ -8,6 +8,8 @@ use rand::Rng;
 
 use rose_file_readers::{PtlKeyframeData, PtlSequence, PtlUpdateCoords};
 
+use crate::resources::ReplayRng;
+
 pub struct ActiveParticle {
     pub age: f32,
     pub keyframe_timer: f32,
@@ -26,6 +28,8 @@ pub struct ActiveParticle {
 
     pub color: Vec4,
     pub color_step: Vec4,
+    /// The colour which is rendered, this can lag behind color when reducing flashing.
+    pub render_color: Vec4,
 
     pub velocity: Vec3,
     pub velocity_step: Vec3,
@@ -53,6 +57,7 @@ impl ActiveParticle {
             rotation: 0.0,
             size: Vec2::new(10.0, 10.0),
             color: Vec4::new(1.0, 1.0, 1.0, 1.0),
+            render_color: Vec4::new(1.0, 1.0, 1.0, 1.0),
             velocity: Vec3::new(0.0, 0.0, 0.0),
             texture_atlas_index: 0.0,
 
@@ -91,6 +96,7 @@ pub struct ParticleSequence {
     pub num_particles: i32,
 
     pub start_delay: f32,
+    pub intensity: f32,
     pub emit_counter: f32,
     pub num_emitted: usize,
     pub particles: Vec<ActiveParticle>,
@@ -100,7 +106,7 @@ pub struct ParticleSequence {
 
 impl ParticleSequence {
     pub fn from(sequence: PtlSequence) -> Self {
-        let mut rng = rand::thread_rng();
+        let mut rng = ReplayRng;
 
         // Select key frame start times
         let mut keyframes: Vec<ParticleSequenceKeyframe> = sequence
@@ -150,6 +156,7 @@ impl ParticleSequence {
             num_loops: sequence.num_loops,
             num_particles: sequence.num_particles,
             start_delay: 0.0,
+            intensity: 1.0,
         }
     }
 
@@ -157,4 +164,9 @@ impl ParticleSequence {
         self.start_delay = start_delay;
         self
     }
+
+    pub fn with_intensity(mut self, intensity: f32) -> Self {
+        self.intensity = intensity;
+        self
+    }
 }
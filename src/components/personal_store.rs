@@ -1,5 +1,5 @@
 use bevy::{
-    prelude::{Component, Entity},
+    prelude::{Component, Entity, Handle, Image},
     reflect::Reflect,
 };
 
@@ -21,3 +21,11 @@ pub struct PersonalStoreModel {
     pub model: Entity,
     pub model_parts: Vec<Entity>,
 }
+
+/// The signboard showing the store title above a personal store.
+#[derive(Component)]
+pub struct PersonalStoreSignboard {
+    pub title: String,
+    pub entity: Entity,
+    pub image: Handle<Image>,
+}
@@ -11,11 +11,11 @@ pub enum ProjectileTarget {
     Position { position: Vec3 },
 }
 
+/// A ballistic arc from the launch position to the target, the end of the arc
+/// follows the target so moving targets are still hit.
 pub struct ProjectileParabola {
-    pub start_y: f32,
-    pub end_y: f32,
-    pub velocity_y: f32,
-    pub move_vec: Vec3,
+    pub start: Vec3,
+    pub arc_height: f32,
     pub current_time: f32,
     pub total_time: f32,
 }
@@ -31,6 +31,9 @@ pub struct Projectile {
     pub move_type: EffectBulletMoveType,
     pub move_speed: f32,
     pub parabola: Option<ProjectileParabola>,
+    /// Tumble speed in radians per second, used for thrown weapons
+    pub spin_speed: f32,
+    pub spin_angle: f32,
 
     pub apply_damage: bool,
 }
@@ -0,0 +1,32 @@
+use std::time::Duration;
+
+use bevy::prelude::Component;
+
+use enum_map::EnumMap;
+use rose_data::{SkillId, StatusEffectId, StatusEffectType};
+
+pub struct StatusEffectSource {
+    pub status_effect_id: StatusEffectId,
+    pub skill_id: SkillId,
+    pub duration: Duration,
+}
+
+/// The skill which applied each of an entity's status effects, only known for effects we
+/// saw being applied. Used to show the full duration and description of a status effect.
+#[derive(Component, Default)]
+pub struct StatusEffectSources {
+    pub sources: EnumMap<StatusEffectType, Option<StatusEffectSource>>,
+}
+
+impl StatusEffectSources {
+    /// Returns the source of an active status effect, if it was not replaced since.
+    pub fn get(
+        &self,
+        status_effect_type: StatusEffectType,
+        status_effect_id: StatusEffectId,
+    ) -> Option<&StatusEffectSource> {
+        self.sources[status_effect_type]
+            .as_ref()
+            .filter(|source| source.status_effect_id == status_effect_id)
+    }
+}
@@ -0,0 +1,5 @@
+use bevy::prelude::Component;
+
+/// The camera mirrored below the water plane which renders the water reflection.
+#[derive(Component)]
+pub struct WaterReflectionCamera;
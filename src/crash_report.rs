@@ -0,0 +1,294 @@
+use std::{
+    collections::VecDeque,
+    fmt::{Display, Write},
+    path::PathBuf,
+    sync::Mutex,
+};
+
+use bevy::{
+    math::Vec3,
+    prelude::{App, Last, Plugin, Query, Res, Resource, State, With},
+    render::renderer::RenderAdapterInfo,
+    utils::tracing::{
+        field::{Field, Visit},
+        Event, Subscriber,
+    },
+};
+use lazy_static::lazy_static;
+use tracing_subscriber::{layer::Context, Layer};
+
+use rose_data::ZoneId;
+use rose_game_common::components::Position;
+
+use crate::{
+    components::PlayerCharacter,
+    resources::{get_data_dir, AppState, CurrentZone, GameConnection, GameData, WorldConnection},
+};
+
+const CRASH_REPORT_MAX_LOG_LINES: usize = 500;
+const CRASH_REPORT_PENDING_FILE: &str = "pending";
+
+#[derive(Default)]
+struct CrashReportState {
+    app_state: Option<AppState>,
+    zone: Option<(ZoneId, String)>,
+    player_position: Option<Vec3>,
+    world_server_message_backlog: Option<usize>,
+    game_server_message_backlog: Option<usize>,
+    adapter: Option<String>,
+}
+
+lazy_static! {
+    static ref CRASH_REPORT_LOG_LINES: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+    static ref CRASH_REPORT_STATE: Mutex<CrashReportState> =
+        Mutex::new(CrashReportState::default());
+}
+
+/// Set when the previous run of the client wrote a crash report.
+#[derive(Resource)]
+pub struct PendingCrashReport {
+    pub path: PathBuf,
+}
+
+#[derive(Default)]
+struct LogMessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl Visit for LogMessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{:?}", value);
+        } else if !field.name().starts_with("log.") {
+            write!(self.fields, " {}={:?}", field.name(), value).ok();
+        }
+    }
+}
+
+/// Formats a log event as a single line with the time, level and target.
+pub(crate) fn format_log_event(event: &Event<'_>) -> String {
+    let mut visitor = LogMessageVisitor::default();
+    event.record(&mut visitor);
+
+    let metadata = event.metadata();
+    format!(
+        "{} {:5} {}: {}{}",
+        chrono::Local::now().format("%H:%M:%S%.3f"),
+        metadata.level(),
+        metadata.target(),
+        visitor.message,
+        visitor.fields
+    )
+}
+
+/// Keeps the most recent log lines in memory so they can be written to a crash report.
+pub struct CrashReportLogLayer;
+
+impl<S> Layer<S> for CrashReportLogLayer
+where
+    S: Subscriber,
+{
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let line = format_log_event(event);
+
+        // Use try_lock so logging from within the panic hook cannot deadlock
+        if let Ok(mut log_lines) = CRASH_REPORT_LOG_LINES.try_lock() {
+            if log_lines.len() >= CRASH_REPORT_MAX_LOG_LINES {
+                log_lines.pop_front();
+            }
+            log_lines.push_back(line);
+        }
+    }
+}
+
+fn get_crash_report_dir() -> anyhow::Result<PathBuf> {
+    Ok(get_data_dir()?.join("crash_reports"))
+}
+
+fn write_crash_report(panic_info: &impl Display) -> anyhow::Result<PathBuf> {
+    let mut report = String::new();
+    writeln!(report, "rose-offline-client crash report")?;
+    writeln!(report, "Version: {}", env!("CARGO_PKG_VERSION"))?;
+    writeln!(report, "Time: {}", chrono::Local::now().to_rfc3339())?;
+
+    writeln!(report, "\n== Panic ==")?;
+    writeln!(
+        report,
+        "Thread: {}",
+        std::thread::current().name().unwrap_or("<unnamed>")
+    )?;
+    writeln!(report, "{}", panic_info)?;
+    writeln!(report, "{}", std::backtrace::Backtrace::force_capture())?;
+
+    writeln!(report, "\n== System ==")?;
+    writeln!(
+        report,
+        "OS: {} {}",
+        std::env::consts::OS,
+        std::env::consts::ARCH
+    )?;
+    writeln!(
+        report,
+        "CPUs: {}",
+        std::thread::available_parallelism().map_or(0, |cpus| cpus.get())
+    )?;
+
+    // Use try_lock so a panic while the state is being updated cannot deadlock
+    let state = CRASH_REPORT_STATE.try_lock().ok();
+    writeln!(
+        report,
+        "GPU: {}",
+        state
+            .as_ref()
+            .and_then(|state| state.adapter.as_deref())
+            .unwrap_or("Unknown")
+    )?;
+
+    writeln!(report, "\n== State ==")?;
+    if let Some(state) = state.as_ref() {
+        writeln!(report, "App State: {:?}", state.app_state)?;
+        match state.zone.as_ref() {
+            Some((zone_id, zone_name)) => {
+                writeln!(report, "Zone: {} {}", zone_id.get(), zone_name)?
+            }
+            None => writeln!(report, "Zone: None")?,
+        }
+        writeln!(report, "Player Position: {:?}", state.player_position)?;
+        writeln!(
+            report,
+            "World Server Message Backlog: {:?}",
+            state.world_server_message_backlog
+        )?;
+        writeln!(
+            report,
+            "Game Server Message Backlog: {:?}",
+            state.game_server_message_backlog
+        )?;
+    } else {
+        writeln!(report, "Unavailable")?;
+    }
+
+    writeln!(report, "\n== Recent Log ==")?;
+    if let Ok(log_lines) = CRASH_REPORT_LOG_LINES.try_lock() {
+        for line in log_lines.iter() {
+            writeln!(report, "{}", line)?;
+        }
+    } else {
+        writeln!(report, "Unavailable")?;
+    }
+
+    let crash_report_dir = get_crash_report_dir()?;
+    let path = crash_report_dir.join(format!(
+        "crash-{}.txt",
+        chrono::Local::now().format("%Y%m%d-%H%M%S")
+    ));
+    std::fs::create_dir_all(&crash_report_dir)?;
+    std::fs::write(&path, report)?;
+
+    // Remember the report so we can tell the user about it on the next launch
+    std::fs::write(
+        crash_report_dir.join(CRASH_REPORT_PENDING_FILE),
+        path.to_string_lossy().as_bytes(),
+    )?;
+
+    Ok(path)
+}
+
+fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        default_hook(panic_info);
+
+        match write_crash_report(panic_info) {
+            Ok(path) => eprintln!("Crash report written to {}", path.to_string_lossy()),
+            Err(error) => eprintln!("Failed to write crash report with error: {}", error),
+        }
+    }));
+}
+
+fn take_pending_crash_report() -> Option<PathBuf> {
+    let pending_path = get_crash_report_dir().ok()?.join(CRASH_REPORT_PENDING_FILE);
+    let path = std::fs::read_to_string(&pending_path).ok()?;
+    std::fs::remove_file(&pending_path).ok();
+    Some(PathBuf::from(path))
+}
+
+pub fn open_crash_report_dir() -> anyhow::Result<()> {
+    let crash_report_dir = get_crash_report_dir()?;
+    let program = if cfg!(target_os = "windows") {
+        "explorer"
+    } else if cfg!(target_os = "macos") {
+        "open"
+    } else {
+        "xdg-open"
+    };
+
+    std::process::Command::new(program)
+        .arg(crash_report_dir)
+        .spawn()?;
+    Ok(())
+}
+
+fn crash_report_state_system(
+    app_state: Res<State<AppState>>,
+    current_zone: Option<Res<CurrentZone>>,
+    game_data: Option<Res<GameData>>,
+    game_connection: Option<Res<GameConnection>>,
+    world_connection: Option<Res<WorldConnection>>,
+    render_adapter_info: Option<Res<RenderAdapterInfo>>,
+    query_player: Query<&Position, With<PlayerCharacter>>,
+) {
+    let Ok(mut state) = CRASH_REPORT_STATE.lock() else {
+        return;
+    };
+
+    state.app_state = Some(*app_state.get());
+    state.player_position = query_player
+        .get_single()
+        .ok()
+        .map(|position| position.position);
+    state.world_server_message_backlog = world_connection
+        .as_ref()
+        .map(|world_connection| world_connection.server_message_rx.len());
+    state.game_server_message_backlog = game_connection
+        .as_ref()
+        .map(|game_connection| game_connection.server_message_rx.len());
+
+    let zone_id = current_zone.map(|current_zone| current_zone.id);
+    if state.zone.as_ref().map(|(id, _)| *id) != zone_id {
+        state.zone = zone_id.map(|zone_id| {
+            let zone_name = game_data
+                .as_ref()
+                .and_then(|game_data| game_data.zone_list.get_zone(zone_id))
+                .map_or_else(String::new, |zone_data| zone_data.name.to_string());
+            (zone_id, zone_name)
+        });
+    }
+
+    if state.adapter.is_none() {
+        state.adapter = render_adapter_info.map(|adapter_info| {
+            format!(
+                "{} ({:?}, {} {})",
+                adapter_info.name,
+                adapter_info.backend,
+                adapter_info.driver,
+                adapter_info.driver_info
+            )
+        });
+    }
+}
+
+pub struct CrashReportPlugin;
+
+impl Plugin for CrashReportPlugin {
+    fn build(&self, app: &mut App) {
+        install_panic_hook();
+
+        if let Some(path) = take_pending_crash_report() {
+            app.insert_resource(PendingCrashReport { path });
+        }
+
+        app.add_systems(Last, crash_report_state_system);
+    }
+}
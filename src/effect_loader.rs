@@ -33,6 +33,7 @@ pub fn spawn_effect(
     effect_path: VfsPath,
     manual_despawn: bool,
     effect_entity: Option<Entity>,
+    intensity: f32,
 ) -> Option<Entity> {
     // TODO: We need caching to avoid loading from file every time
     let eft_file = vfs.read_file::<EftFile, _>(effect_path).ok()?;
@@ -45,15 +46,20 @@ pub fn spawn_effect(
             asset_server,
             particle_materials,
             &eft_particle,
+            intensity,
         ) {
             child_entities.push(particle_entity);
         }
     }
 
     for eft_particle in eft_file.meshes {
-        if let Some(mesh_entity) =
-            spawn_mesh(commands, asset_server, effect_mesh_materials, &eft_particle)
-        {
+        if let Some(mesh_entity) = spawn_mesh(
+            commands,
+            asset_server,
+            effect_mesh_materials,
+            &eft_particle,
+            intensity,
+        ) {
             child_entities.push(mesh_entity);
         }
     }
@@ -117,6 +123,7 @@ fn spawn_mesh(
     asset_server: &AssetServer,
     effect_mesh_materials: &mut Assets<EffectMeshMaterial>,
     eft_mesh: &EftMesh,
+    intensity: f32,
 ) -> Option<Entity> {
     Some(
         commands
@@ -158,6 +165,7 @@ fn spawn_mesh(
                                 path.path().to_str().unwrap(),
                             ))
                         }),
+                        intensity,
                     }),
                     Visibility::default(),
                     ComputedVisibility::default(),
@@ -208,6 +216,7 @@ fn spawn_particle(
     asset_server: &AssetServer,
     particle_materials: &mut Assets<ParticleMaterial>,
     eft_particle: &EftParticle,
+    intensity: f32,
 ) -> Option<Entity> {
     let ptl_file = vfs
         .read_file::<PtlFile, _>(&eft_particle.particle_file)
@@ -254,7 +263,8 @@ fn spawn_particle(
                             texture: asset_server.load(sequence.texture_path.path()),
                         }),
                         ParticleSequence::from(sequence)
-                            .with_start_delay(eft_particle.start_delay as f32 / 1000.0),
+                            .with_start_delay(eft_particle.start_delay as f32 / 1000.0)
+                            .with_intensity(intensity),
                         Transform::default(),
                         GlobalTransform::default(),
                         Aabb::default(),
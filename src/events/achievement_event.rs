@@ -0,0 +1,7 @@
+use bevy::prelude::Event;
+
+#[derive(Event)]
+pub enum AchievementEvent {
+    MonsterKilled,
+    QuestCompleted,
+}
@@ -5,5 +5,6 @@ pub enum CharacterSelectEvent {
     SelectCharacter(usize),
     PlaySelected,
     DeleteSelected,
+    CancelDeleteSelected,
     Disconnect,
 }
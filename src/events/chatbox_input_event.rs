@@ -0,0 +1,8 @@
+use bevy::prelude::Event;
+
+/// Changes the text being typed in the chatbox.
+#[derive(Event)]
+pub enum ChatboxInputEvent {
+    /// Start typing a whisper to the named character.
+    Whisper(String),
+}
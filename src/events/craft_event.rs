@@ -0,0 +1,10 @@
+use bevy::prelude::Event;
+
+use rose_game_common::{components::ItemSlot, messages::server::CraftInsertGemError};
+
+#[derive(Event)]
+pub enum CraftEvent {
+    OpenInsertGem { gem_item_slot: ItemSlot },
+    InsertGemSuccess,
+    InsertGemError { error: CraftInsertGemError },
+}
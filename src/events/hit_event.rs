@@ -1,7 +1,17 @@
-use bevy::prelude::{Entity, Event};
+use bevy::{
+    math::Vec3,
+    prelude::{Entity, Event},
+};
 
 use rose_data::{EffectId, SkillId};
 
+/// Where a projectile struck the defender, used to orient the hit effect.
+#[derive(Copy, Clone)]
+pub struct HitImpact {
+    pub position: Vec3,
+    pub normal: Vec3,
+}
+
 #[derive(Event)]
 pub struct HitEvent {
     pub attacker: Entity,
@@ -10,6 +20,7 @@ pub struct HitEvent {
     pub skill_id: Option<SkillId>,
     pub apply_damage: bool,
     pub ignore_miss: bool,
+    pub impact: Option<HitImpact>,
 }
 
 impl HitEvent {
@@ -21,6 +32,7 @@ impl HitEvent {
             skill_id: None,
             apply_damage: true,
             ignore_miss: false,
+            impact: None,
         }
     }
 
@@ -32,6 +44,7 @@ impl HitEvent {
             skill_id: Some(skill_id),
             apply_damage: true,
             ignore_miss: false,
+            impact: None,
         }
     }
 
@@ -43,6 +56,7 @@ impl HitEvent {
             skill_id: Some(skill_id),
             apply_damage: true,
             ignore_miss: true,
+            impact: None,
         }
     }
 
@@ -50,4 +64,9 @@ impl HitEvent {
         self.apply_damage = apply_damage;
         self
     }
+
+    pub fn impact(mut self, position: Vec3, normal: Vec3) -> Self {
+        self.impact = Some(HitImpact { position, normal });
+        self
+    }
 }
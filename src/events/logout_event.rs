@@ -0,0 +1,13 @@
+use std::time::Duration;
+
+use bevy::prelude::Event;
+
+use crate::resources::LogoutTarget;
+
+#[derive(Event)]
+pub enum LogoutEvent {
+    Start(LogoutTarget),
+    Success,
+    Failed { wait_duration: Duration },
+    ReturnToCharacterSelect,
+}
@@ -1,13 +1,18 @@
+mod achievement_event;
 mod bank_event;
 mod character_select_event;
 mod chatbox_event;
+mod chatbox_input_event;
 mod clan_dialog_event;
 mod client_entity_event;
 mod conversation_dialog_event;
+mod craft_event;
 mod game_connection_event;
 mod hit_event;
 mod login_event;
+mod logout_event;
 mod message_box_event;
+mod money_input_dialog_event;
 mod move_destination_effect_event;
 mod network_event;
 mod npc_store_event;
@@ -16,6 +21,8 @@ mod party_event;
 mod personal_store_event;
 mod player_command_event;
 mod quest_trigger_event;
+mod repair_event;
+mod screen_transition_event;
 mod spawn_effect_event;
 mod spawn_projectile_event;
 mod system_func_event;
@@ -23,27 +30,34 @@ mod use_item_event;
 mod world_connection_event;
 mod zone_event;
 
+pub use achievement_event::AchievementEvent;
 pub use bank_event::BankEvent;
 pub use character_select_event::CharacterSelectEvent;
 pub use chatbox_event::ChatboxEvent;
+pub use chatbox_input_event::ChatboxInputEvent;
 pub use clan_dialog_event::ClanDialogEvent;
 pub use client_entity_event::ClientEntityEvent;
 pub use conversation_dialog_event::ConversationDialogEvent;
+pub use craft_event::CraftEvent;
 pub use game_connection_event::GameConnectionEvent;
 pub use hit_event::HitEvent;
 pub use login_event::LoginEvent;
+pub use logout_event::LogoutEvent;
 pub use message_box_event::MessageBoxEvent;
+pub use money_input_dialog_event::MoneyInputDialogEvent;
 pub use move_destination_effect_event::MoveDestinationEffectEvent;
 pub use network_event::NetworkEvent;
 pub use npc_store_event::NpcStoreEvent;
 pub use number_input_dialog_event::NumberInputDialogEvent;
 pub use party_event::PartyEvent;
 pub use personal_store_event::PersonalStoreEvent;
-pub use player_command_event::PlayerCommandEvent;
+pub use player_command_event::{CharacterExportFormat, PlayerCommandEvent};
 pub use quest_trigger_event::QuestTriggerEvent;
+pub use repair_event::RepairEvent;
+pub use screen_transition_event::ScreenTransitionEvent;
 pub use spawn_effect_event::{SpawnEffect, SpawnEffectData, SpawnEffectEvent};
 pub use spawn_projectile_event::SpawnProjectileEvent;
 pub use system_func_event::SystemFuncEvent;
 pub use use_item_event::UseItemEvent;
 pub use world_connection_event::WorldConnectionEvent;
-pub use zone_event::{LoadZoneEvent, ZoneEvent};
+pub use zone_event::{LoadZoneEvent, ValidateZoneEvent, ZoneEvent};
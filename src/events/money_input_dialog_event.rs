@@ -0,0 +1,14 @@
+use bevy::prelude::{Commands, Event};
+
+use rose_game_common::components::Money;
+
+#[derive(Event)]
+pub enum MoneyInputDialogEvent {
+    Show {
+        title: String,
+        balance: Money,
+        modal: bool,
+        ok: Option<Box<dyn FnOnce(&mut Commands, Money) + Send + Sync>>,
+        cancel: Option<Box<dyn FnOnce(&mut Commands) + Send + Sync>>,
+    },
+}
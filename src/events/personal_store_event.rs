@@ -7,6 +7,7 @@ use rose_game_common::components::Money;
 pub enum PersonalStoreEvent {
     OpenEntityStore(Entity),
     SetItemList {
+        entity: Entity,
         sell_items: Vec<(u8, Item, Money)>,
         buy_items: Vec<(u8, Item, Money)>,
     },
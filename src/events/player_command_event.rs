@@ -5,15 +5,29 @@ use rose_game_common::components::{HotbarSlot, ItemSlot, SkillSlot};
 
 use crate::components::Position;
 
+#[derive(Copy, Clone, Debug)]
+pub enum CharacterExportFormat {
+    Json,
+    Html,
+}
+
 #[derive(Event, Clone)]
 pub enum PlayerCommandEvent {
     UseSkill(SkillSlot),
     DropItem(ItemSlot),
+    SetItemLocked(ItemSlot, bool),
     UseItem(ItemSlot),
+    UseItemConfirmed(ItemSlot),
     UseHotbar(usize, usize),
     SetHotbar(usize, usize, Option<HotbarSlot>),
     Attack(Entity),
+    Assist(Option<Entity>),
+    Defend(Option<Entity>),
+    StopDefend,
+    ToggleAfk(Option<String>),
+    ExportCharacter(CharacterExportFormat),
     Move(Position, Option<Entity>),
+    CancelCast,
     UnequipAmmo(AmmoIndex),
     UnequipEquipment(EquipmentIndex),
     UnequipVehicle(VehiclePartIndex),
@@ -0,0 +1,10 @@
+use bevy::prelude::Event;
+
+use rose_game_common::{components::ItemSlot, messages::ClientEntityId};
+
+#[derive(Event)]
+pub enum RepairEvent {
+    OpenRepairFromClientEntity { client_entity_id: ClientEntityId },
+    OpenRepairUsingItem { use_item_slot: ItemSlot },
+    Repaired { item_slot: ItemSlot },
+}
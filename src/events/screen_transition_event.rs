@@ -0,0 +1,12 @@
+use bevy::prelude::Event;
+
+/// Zone loads start a transition automatically, these are for anything else
+/// which should hide the screen while it changes.
+#[derive(Event)]
+pub enum ScreenTransitionEvent {
+    /// Fade to black and stay there until FadeIn, a zone load, or a timeout.
+    FadeOut,
+    FadeIn,
+    /// Cut straight to black and fade in, e.g. when a cutscene starts.
+    CutIn,
+}
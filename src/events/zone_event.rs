@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use bevy::prelude::Event;
 
 use rose_data::ZoneId;
@@ -21,3 +23,9 @@ impl LoadZoneEvent {
 pub enum ZoneEvent {
     Loaded(ZoneId),
 }
+
+#[derive(Event)]
+pub struct ValidateZoneEvent {
+    pub id: ZoneId,
+    pub output_path: Option<PathBuf>,
+}
@@ -0,0 +1,318 @@
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use bevy::{
+    log::Level,
+    prelude::{App, First, Plugin, ResMut, Resource},
+    utils::tracing::{
+        field::{Field, Visit},
+        span::{Attributes, Id},
+        Subscriber,
+    },
+};
+use lazy_static::lazy_static;
+use serde::Serialize;
+use tracing_appender::rolling::RollingFileAppender;
+use tracing_subscriber::{layer::Context, prelude::*, registry::LookupSpan, EnvFilter, Layer};
+
+use crate::{
+    crash_report::CrashReportLogLayer, log_viewer::LogViewerLayer, resources::get_data_dir,
+};
+
+const AVERAGE_SMOOTHING: f64 = 0.05;
+
+lazy_static! {
+    static ref PROFILER_EPOCH: Instant = Instant::now();
+    static ref PROFILER_SPANS: Mutex<Vec<FrameProfilerSpan>> = Mutex::new(Vec::new());
+}
+
+static NEXT_THREAD_INDEX: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static THREAD_INDEX: usize = NEXT_THREAD_INDEX.fetch_add(1, Ordering::Relaxed);
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FrameProfilerSpanKind {
+    Schedule,
+    System,
+}
+
+#[derive(Clone)]
+pub struct FrameProfilerSpan {
+    pub kind: FrameProfilerSpanKind,
+    pub name: String,
+    pub thread_index: usize,
+    pub start: Duration,
+    pub duration: Duration,
+}
+
+pub struct FrameProfilerTiming {
+    pub kind: FrameProfilerSpanKind,
+    pub name: String,
+    pub calls: usize,
+    pub total: Duration,
+    pub average_ms: f64,
+}
+
+#[derive(Default, Resource)]
+pub struct FrameProfiler {
+    pub frozen: bool,
+    pub frame_spans: Vec<FrameProfilerSpan>,
+    pub frame_start: Duration,
+    pub frame_end: Duration,
+    pub timings: Vec<FrameProfilerTiming>,
+    average_ms: HashMap<(FrameProfilerSpanKind, String), f64>,
+}
+
+impl FrameProfiler {
+    pub fn is_enabled() -> bool {
+        cfg!(feature = "profiler")
+    }
+
+    fn update(&mut self, spans: Vec<FrameProfilerSpan>) {
+        if spans.is_empty() {
+            return;
+        }
+
+        let mut timings: HashMap<(FrameProfilerSpanKind, &str), (usize, Duration)> = HashMap::new();
+        for span in spans.iter() {
+            let timing = timings
+                .entry((span.kind, span.name.as_str()))
+                .or_insert((0, Duration::ZERO));
+            timing.0 += 1;
+            timing.1 += span.duration;
+        }
+
+        self.timings = timings
+            .into_iter()
+            .map(|((kind, name), (calls, total))| {
+                let average_ms = self
+                    .average_ms
+                    .entry((kind, name.to_string()))
+                    .and_modify(|average_ms| {
+                        *average_ms +=
+                            (total.as_secs_f64() * 1000.0 - *average_ms) * AVERAGE_SMOOTHING;
+                    })
+                    .or_insert(total.as_secs_f64() * 1000.0);
+
+                FrameProfilerTiming {
+                    kind,
+                    name: name.to_string(),
+                    calls,
+                    total,
+                    average_ms: *average_ms,
+                }
+            })
+            .collect();
+
+        self.frame_start = spans
+            .iter()
+            .map(|span| span.start)
+            .min()
+            .unwrap_or_default();
+        self.frame_end = spans
+            .iter()
+            .map(|span| span.start + span.duration)
+            .max()
+            .unwrap_or_default();
+        self.frame_spans = spans;
+    }
+
+    /// Exports the current frame in the Chrome trace event format, which can be
+    /// opened in chrome://tracing or Perfetto.
+    pub fn export_chrome_trace(&self) -> anyhow::Result<PathBuf> {
+        #[derive(Serialize)]
+        struct TraceEvent<'a> {
+            name: &'a str,
+            cat: &'static str,
+            ph: &'static str,
+            ts: f64,
+            dur: f64,
+            pid: u32,
+            tid: usize,
+        }
+
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Trace<'a> {
+            trace_events: Vec<TraceEvent<'a>>,
+        }
+
+        let trace = Trace {
+            trace_events: self
+                .frame_spans
+                .iter()
+                .map(|span| TraceEvent {
+                    name: &span.name,
+                    cat: match span.kind {
+                        FrameProfilerSpanKind::Schedule => "schedule",
+                        FrameProfilerSpanKind::System => "system",
+                    },
+                    ph: "X",
+                    ts: span.start.as_secs_f64() * 1_000_000.0,
+                    dur: span.duration.as_secs_f64() * 1_000_000.0,
+                    pid: std::process::id(),
+                    tid: span.thread_index,
+                })
+                .collect(),
+        };
+
+        let path = get_data_dir()?.join("profiles").join(format!(
+            "frame-{}.json",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ));
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, serde_json::to_string(&trace)?)?;
+        Ok(path)
+    }
+}
+
+struct ProfiledSpan {
+    kind: FrameProfilerSpanKind,
+    name: String,
+    entered: Option<Instant>,
+}
+
+#[derive(Default)]
+struct SpanNameVisitor {
+    name: Option<String>,
+}
+
+impl Visit for SpanNameVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "name" {
+            self.name = Some(value.to_string());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "name" {
+            self.name = Some(format!("{:?}", value));
+        }
+    }
+}
+
+/// Records the timings of the bevy schedule and system spans, these spans are
+/// only created when bevy is built with the trace feature.
+pub struct FrameProfilerLayer;
+
+impl<S> Layer<S> for FrameProfilerLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let kind = match attrs.metadata().name() {
+            "schedule" => FrameProfilerSpanKind::Schedule,
+            "system" => FrameProfilerSpanKind::System,
+            _ => return,
+        };
+
+        let mut visitor = SpanNameVisitor::default();
+        attrs.record(&mut visitor);
+
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(ProfiledSpan {
+                kind,
+                name: visitor
+                    .name
+                    .unwrap_or_else(|| attrs.metadata().name().to_string()),
+                entered: None,
+            });
+        }
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(profiled_span) = span.extensions_mut().get_mut::<ProfiledSpan>() {
+                profiled_span.entered = Some(Instant::now());
+            }
+        }
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else {
+            return;
+        };
+        let mut extensions = span.extensions_mut();
+        let Some(profiled_span) = extensions.get_mut::<ProfiledSpan>() else {
+            return;
+        };
+        let Some(entered) = profiled_span.entered.take() else {
+            return;
+        };
+
+        let profiler_span = FrameProfilerSpan {
+            kind: profiled_span.kind,
+            name: profiled_span.name.clone(),
+            thread_index: THREAD_INDEX.with(|thread_index| *thread_index),
+            start: entered.saturating_duration_since(*PROFILER_EPOCH),
+            duration: entered.elapsed(),
+        };
+
+        if let Ok(mut spans) = PROFILER_SPANS.lock() {
+            spans.push(profiler_span);
+        }
+    }
+}
+
+/// Replaces the bevy LogPlugin so the crash report, log viewer and frame profiler
+/// layers can be added to the subscriber, optionally also writing to a log file.
+pub fn init_tracing_subscriber(level: Level, filter: &str, log_file: Option<RollingFileAppender>) {
+    lazy_static::initialize(&PROFILER_EPOCH);
+
+    let default_filter = format!("{},{}", level, filter);
+    let filter_layer = EnvFilter::try_from_default_env()
+        .or_else(|_| EnvFilter::try_new(&default_filter))
+        .unwrap();
+
+    if tracing_log::LogTracer::init().is_err() {
+        log::warn!("Could not set global logger as it is already set");
+    }
+
+    let subscriber = tracing_subscriber::registry()
+        .with(filter_layer)
+        .with(tracing_subscriber::fmt::layer())
+        .with(log_file.map(|log_file| {
+            tracing_subscriber::fmt::layer()
+                .with_ansi(false)
+                .with_writer(log_file)
+        }))
+        .with(CrashReportLogLayer)
+        .with(LogViewerLayer)
+        .with(FrameProfiler::is_enabled().then_some(FrameProfilerLayer));
+    if bevy::utils::tracing::subscriber::set_global_default(subscriber).is_err() {
+        log::warn!("Could not set global tracing subscriber as it is already set");
+    }
+}
+
+fn frame_profiler_system(mut frame_profiler: ResMut<FrameProfiler>) {
+    let spans = PROFILER_SPANS
+        .lock()
+        .map(|mut spans| std::mem::take(&mut *spans))
+        .unwrap_or_default();
+
+    if !frame_profiler.frozen {
+        frame_profiler.update(spans);
+    }
+}
+
+#[derive(Default)]
+pub struct FrameProfilerPlugin;
+
+impl Plugin for FrameProfilerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<FrameProfiler>()
+            .add_systems(First, frame_profiler_system);
+    }
+}
@@ -5,26 +5,37 @@ use animation::RoseAnimationPlugin;
 use bevy::{
     core_pipeline::{bloom::BloomSettings, clear_color::ClearColor},
     ecs::event::Events,
+    input::InputSystem,
     log::Level,
     prelude::{
         apply_deferred, in_state, AddAsset, App, AssetServer, Assets, Camera, Camera3dBundle,
-        Color, Commands, IntoSystemConfigs, IntoSystemSetConfigs, Msaa, OnEnter, OnExit,
-        PluginGroup, PostStartup, PostUpdate, PreUpdate, Quat, Res, ResMut, Startup, State,
-        SystemSet, Transform, Update, Vec3,
+        Color, Commands, Condition, First, IntoSystemConfigs, IntoSystemSetConfigs, Last, Msaa,
+        OnEnter, OnExit, PluginGroup, PostStartup, PostUpdate, PreUpdate, Quat, Res, ResMut,
+        Startup, State, SystemSet, Transform, Update, Vec2, Vec3,
     },
-    render::{render_resource::WgpuFeatures, settings::WgpuSettings},
+    render::{render_resource::WgpuFeatures, settings::WgpuSettings, view::RenderLayers},
+    time::TimeSystem,
     transform::TransformSystem,
-    window::{Window, WindowMode},
+    window::Window,
+    winit::WinitSettings,
 };
 use bevy_egui::{egui, EguiContexts, EguiSet};
 use bevy_rapier3d::plugin::PhysicsSet;
 use enum_map::enum_map;
 use exe_resource_loader::{ExeResourceCursor, ExeResourceLoader};
-use serde::Deserialize;
+use frame_profiler::FrameProfilerPlugin;
+use log_viewer::LogViewerPlugin;
+use serde::{Deserialize, Serialize};
 use std::{
+    collections::{BTreeMap, HashMap},
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
 };
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
 
 use rose_data::{CharacterMotionDatabaseOptions, NpcDatabaseOptions, ZoneId};
 use rose_file_readers::{
@@ -36,105 +47,156 @@ pub mod animation;
 pub mod audio;
 pub mod bundles;
 pub mod components;
+pub mod crash_report;
 pub mod effect_loader;
 pub mod events;
 pub mod exe_resource_loader;
+pub mod frame_profiler;
+pub mod localization;
+pub mod log_viewer;
 pub mod model_loader;
 pub mod protocol;
 pub mod render;
 pub mod resources;
 pub mod scripting;
 pub mod systems;
+pub mod text_to_speech;
 pub mod ui;
 pub mod vfs_asset_io;
 pub mod zms_asset_loader;
 pub mod zone_loader;
 
-use audio::OddioPlugin;
+use audio::{OddioPlugin, SoundAttenuation};
+use crash_report::CrashReportPlugin;
 use events::{
-    BankEvent, CharacterSelectEvent, ChatboxEvent, ClanDialogEvent, ClientEntityEvent,
-    ConversationDialogEvent, GameConnectionEvent, HitEvent, LoadZoneEvent, LoginEvent,
-    MessageBoxEvent, MoveDestinationEffectEvent, NetworkEvent, NpcStoreEvent,
-    NumberInputDialogEvent, PartyEvent, PersonalStoreEvent, PlayerCommandEvent, QuestTriggerEvent,
-    SpawnEffectEvent, SpawnProjectileEvent, SystemFuncEvent, UseItemEvent, WorldConnectionEvent,
-    ZoneEvent,
+    AchievementEvent, BankEvent, CharacterSelectEvent, ChatboxEvent, ChatboxInputEvent,
+    ClanDialogEvent, ClientEntityEvent, ConversationDialogEvent, CraftEvent, GameConnectionEvent,
+    HitEvent, LoadZoneEvent, LoginEvent, LogoutEvent, MessageBoxEvent, MoneyInputDialogEvent,
+    MoveDestinationEffectEvent, NetworkEvent, NpcStoreEvent, NumberInputDialogEvent, PartyEvent,
+    PersonalStoreEvent, PlayerCommandEvent, QuestTriggerEvent, RepairEvent, ScreenTransitionEvent,
+    SpawnEffectEvent, SpawnProjectileEvent, SystemFuncEvent, UseItemEvent, ValidateZoneEvent,
+    WorldConnectionEvent, ZoneEvent,
 };
 use model_loader::ModelLoader;
-use render::{DamageDigitMaterial, RoseRenderPlugin};
+use render::{DamageDigitMaterial, RoseRenderPlugin, WATER_RENDER_LAYER};
 use resources::{
     load_ui_resources, run_network_thread, ui_requested_cursor_apply_system, update_ui_resources,
-    AppState, ClientEntityList, DamageDigitsSpawner, DebugRenderConfig, GameData, NameTagSettings,
-    NetworkThread, NetworkThreadMessage, RenderConfiguration, SelectedTarget, ServerConfiguration,
-    SoundCache, SoundSettings, SpecularTexture, VfsResource, WorldTime, ZoneTime,
+    AccessibilitySettings, Achievements, AfkSettings, AfkState, AppState, AutoLootSettings,
+    AvailableMonitors, ClientEntityList, ConfigPersistence, ConversationDialogPortrait,
+    CooldownAlertSettings, CooldownAlerts, CooldownStore, DamageDigitsSpawner, DebugRenderConfig,
+    DebugTimeControl, DefendTarget, EntityHistory, EntityPool, EventSchedule, FootstepEffects,
+    FriendList, GameData, GameVersionRegistry, GraphicsBenchmark, GraphicsQuality,
+    HeadlessSmokeTest, HudLayout, InputBindings, ItemDropEffects, ItemGradeEffectSettings,
+    LevelUpPresentation, LevelUpSettings, LoginProfiles, MessageOfTheDay, MinimizeOnFocusLoss,
+    MissingAssets, NameTagSettings, NetworkThread, NetworkThreadMessage, PacketInspector,
+    PersonalStoreListRequests, ProtocolRegistry, RenderConfiguration, ReplayPlayback,
+    ReplayRecorder, SelectedTarget, ServerConfiguration, SessionStatistics, SkillQueue,
+    SkillRangeIndicator, SoundCache, SoundSettings, SpecularTexture, TextureQuality,
+    UiScreenshotHarness, UserSettingsStore, VfsResource, WaterReflection, Waypoints,
+    WindowSettings, WorldTime, ZoneBenchmark, ZoneMusicPlaylists, ZoneTime, ZoneValidationResult,
 };
 use scripting::RoseScriptingPlugin;
 use systems::{
-    ability_values_system, animation_effect_system, animation_sound_system, auto_login_system,
-    background_music_system, character_model_add_collider_system, character_model_blink_system,
-    character_model_update_system, character_select_enter_system, character_select_event_system,
-    character_select_exit_system, character_select_input_system, character_select_models_system,
-    character_select_system, clan_system, client_entity_event_system, collision_height_only_system,
-    collision_player_system, collision_player_system_join_zoin, command_system,
-    conversation_dialog_system, cooldown_system, damage_digit_render_system,
+    ability_values_system, accessibility_bloom_system, achievement_save_system, achievement_system,
+    afk_auto_reply_system, afk_system, aggro_system, ammo_system, animation_effect_system,
+    animation_sound_system, auto_login_system, auto_loot_system, background_music_system,
+    character_export_system, character_model_add_collider_system, character_model_blink_system,
+    character_model_update_system, character_select_camera_system, character_select_enter_system,
+    character_select_event_system, character_select_exit_system, character_select_input_system,
+    character_select_models_system, character_select_system, clan_skill_system, clan_system,
+    client_entity_event_system, collision_height_only_system, collision_player_system,
+    collision_player_system_join_zoin, command_system, conversation_dialog_portrait_system,
+    conversation_dialog_system, cooldown_store_system, cooldown_system, damage_digit_render_system,
     debug_render_collider_system, debug_render_directional_light_system,
-    debug_render_skeleton_system, directional_light_system, effect_system, facing_direction_system,
-    free_camera_system, game_connection_system, game_mouse_input_system, game_state_enter_system,
-    game_zone_change_system, hit_event_system, item_drop_model_add_collider_system,
-    item_drop_model_system, login_connection_system, login_event_system, login_state_enter_system,
-    login_state_exit_system, login_system, model_viewer_enter_system, model_viewer_exit_system,
-    model_viewer_system, move_destination_effect_system, name_tag_system,
-    name_tag_update_color_system, name_tag_update_healthbar_system, name_tag_visibility_system,
-    network_thread_system, npc_idle_sound_system, npc_model_add_collider_system,
-    npc_model_update_system, orbit_camera_system, particle_sequence_system,
-    passive_recovery_system, pending_damage_system, pending_skill_effect_system,
-    personal_store_model_add_collider_system, personal_store_model_system, player_command_system,
-    projectile_system, quest_trigger_system, spawn_effect_system, spawn_projectile_system,
-    status_effect_system, system_func_event_system, update_position_system, use_item_event_system,
-    vehicle_model_system, vehicle_sound_system, visible_status_effects_system,
-    world_connection_system, world_time_system, zone_time_system, zone_viewer_enter_system,
+    debug_render_skeleton_system, debug_render_walkability_system, debug_time_control_system,
+    defend_target_system, directional_light_system, effect_system, encumbrance_system,
+    entity_history_system, event_schedule_system, facing_direction_system, footstep_effect_system,
+    footstep_effect_update_system, free_camera_system, game_connection_system,
+    game_mouse_input_system, game_reconnect_start_system, game_reconnect_system,
+    game_state_enter_system, game_state_exit_system, game_zone_change_system,
+    graphics_benchmark_system, graphics_quality_detect_system, graphics_quality_system,
+    headless_smoke_test_system, hit_event_system, input_context_system, item_drop_effect_system,
+    item_drop_model_add_collider_system, item_drop_model_system, item_locks_system,
+    login_connection_system, login_event_system, login_state_enter_system, login_state_exit_system,
+    login_system, logout_system, model_viewer_enter_system, model_viewer_exit_system,
+    model_viewer_system, move_destination_effect_system, name_tag_combat_healthbar_system,
+    name_tag_system, name_tag_update_color_system, name_tag_update_healthbar_system,
+    name_tag_visibility_system, network_thread_system, npc_idle_sound_system,
+    npc_model_add_collider_system, npc_model_update_system, npc_quest_marker_system,
+    orbit_camera_system, particle_sequence_system, passive_recovery_system, pending_damage_system,
+    pending_skill_effect_system, personal_store_model_add_collider_system,
+    personal_store_model_system, personal_store_signboard_system, player_command_system,
+    projectile_system, quest_trigger_system, replay_playback_system, replay_record_input_system,
+    skill_range_indicator_system, spawn_effect_system, spawn_projectile_system,
+    status_effect_system, system_func_event_system, update_networked_position_system,
+    update_position_system, use_item_event_system, user_settings_load_system,
+    user_settings_save_system, vehicle_input_system, vehicle_model_system, vehicle_sound_system,
+    visible_status_effects_system, water_reflection_system, window_focus_system,
+    window_monitors_system, window_settings_system, world_connection_system, world_time_system,
+    zone_benchmark_system, zone_time_system, zone_validation_system, zone_viewer_enter_system,
     DebugInspectorPlugin,
 };
+use text_to_speech::TextToSpeechPlugin;
 use ui::{
-    load_dialog_sprites_system, ui_bank_system, ui_character_create_system,
-    ui_character_info_system, ui_character_select_name_tag_system, ui_character_select_system,
-    ui_chatbox_system, ui_clan_system, ui_create_clan_system, ui_debug_camera_info_system,
+    load_dialog_sprites_system, ui_achievements_system, ui_announcement_system, ui_bank_system,
+    ui_calendar_system, ui_cast_bar_system, ui_character_create_system, ui_character_info_system,
+    ui_character_select_name_tag_system, ui_character_select_system, ui_chat_bubble_system,
+    ui_chatbox_system, ui_clan_system, ui_clock_system, ui_cooldown_alert_system, ui_craft_system,
+    ui_crash_report_system, ui_create_clan_system, ui_debug_camera_info_system,
     ui_debug_client_entity_list_system, ui_debug_command_viewer_system,
-    ui_debug_diagnostics_system, ui_debug_dialog_list_system, ui_debug_effect_list_system,
-    ui_debug_entity_inspector_system, ui_debug_item_list_system, ui_debug_menu_system,
-    ui_debug_npc_list_system, ui_debug_physics_system, ui_debug_render_system,
-    ui_debug_skill_list_system, ui_debug_zone_lighting_system, ui_debug_zone_list_system,
-    ui_debug_zone_time_system, ui_drag_and_drop_system, ui_game_menu_system, ui_hotbar_system,
-    ui_inventory_system, ui_item_drop_name_system, ui_login_system, ui_message_box_system,
-    ui_minimap_system, ui_npc_store_system, ui_number_input_dialog_system, ui_party_option_system,
-    ui_party_system, ui_personal_store_system, ui_player_info_system, ui_quest_list_system,
-    ui_respawn_system, ui_selected_target_system, ui_server_select_system, ui_settings_system,
-    ui_skill_list_system, ui_skill_tree_system, ui_sound_event_system, ui_status_effects_system,
+    ui_debug_detached_windows_system, ui_debug_diagnostics_system, ui_debug_dialog_list_system,
+    ui_debug_effect_list_system, ui_debug_entity_inspector_system, ui_debug_frame_profiler_system,
+    ui_debug_item_list_system, ui_debug_log_viewer_system, ui_debug_lua_vm_system,
+    ui_debug_memory_usage_system, ui_debug_menu_system, ui_debug_npc_list_system,
+    ui_debug_packet_inspector_system, ui_debug_physics_system, ui_debug_render_statistics_system,
+    ui_debug_render_system, ui_debug_skill_list_system, ui_debug_spawn_palette_system,
+    ui_debug_time_control_system, ui_debug_zone_lighting_system, ui_debug_zone_list_system,
+    ui_debug_zone_time_system, ui_drag_and_drop_system, ui_friends_system, ui_game_menu_system,
+    ui_hotbar_system, ui_hud_layout_system, ui_inspect_character_system, ui_inventory_system,
+    ui_item_drop_name_system, ui_level_up_system, ui_login_system, ui_message_box_system,
+    ui_minimap_system, ui_money_input_dialog_system, ui_npc_store_system,
+    ui_number_input_dialog_system, ui_party_option_system, ui_party_system,
+    ui_personal_store_scanner_system, ui_personal_store_system, ui_player_info_system,
+    ui_quest_list_system, ui_repair_system, ui_respawn_system, ui_screen_transition_system,
+    ui_screenshot_harness_system, ui_selected_target_system, ui_server_select_system,
+    ui_session_statistics_system, ui_settings_system, ui_setup_wizard_system, ui_skill_list_system,
+    ui_skill_tree_system, ui_sound_event_system, ui_status_effect_text_system,
+    ui_status_effects_system, ui_waypoints_system, ui_window_manager_system,
     ui_window_sound_system, widgets::Dialog, DialogLoader, UiSoundEvent, UiStateDebugWindows,
-    UiStateDragAndDrop, UiStateWindows,
+    UiStateDragAndDrop, UiStateSettings, UiStateWindows,
 };
 use vfs_asset_io::VfsAssetIo;
 use zms_asset_loader::{ZmsAssetLoader, ZmsMaterialNumFaces, ZmsNoSkinAssetLoader};
 use zone_loader::{zone_loader_system, ZoneLoader, ZoneLoaderAsset};
 
-use crate::components::SoundCategory;
+use crate::components::{MainCamera, PooledEffect, Projectile, SoundCategory};
 
-#[derive(Default, Deserialize)]
+const LOG_FILTER: &str = "wgpu=error,packets=debug,quest=trace,lua=debug,con=trace,animation=info";
+
+#[derive(Clone, Default, Deserialize)]
 #[serde(default)]
-pub struct AccountConfig {
+pub struct ProfileConfig {
+    pub server: ServerConfig,
     pub username: String,
+    /// Stored in plain text, prefer remember_password to use the OS keyring.
     pub password: String,
+    pub remember_password: bool,
+    pub auto_login: AutoLoginConfig,
 }
 
-#[derive(Default, Deserialize)]
+#[derive(Clone, Default, Deserialize)]
 #[serde(default)]
 pub struct AutoLoginConfig {
     pub enabled: bool,
     pub channel_id: Option<usize>,
     pub server_id: Option<usize>,
     pub character_name: Option<String>,
+    pub start_zone: Option<u16>,
+    pub start_position: Option<[f32; 2]>,
+    pub skip_intro: bool,
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize, Serialize)]
 #[serde(tag = "type", content = "path")]
 pub enum FilesystemDeviceConfig {
     #[serde(rename = "vfs")]
@@ -149,7 +211,7 @@ pub enum FilesystemDeviceConfig {
     IrosePh(String),
 }
 
-#[derive(Default, Deserialize)]
+#[derive(Clone, Default, Deserialize, Serialize)]
 #[serde(default)]
 pub struct FilesystemConfig {
     pub devices: Vec<FilesystemDeviceConfig>,
@@ -157,6 +219,13 @@ pub struct FilesystemConfig {
 
 impl FilesystemConfig {
     pub fn create_virtual_filesystem(&self) -> Option<Arc<VirtualFilesystem>> {
+        self.try_create_virtual_filesystem()
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    pub fn try_create_virtual_filesystem(
+        &self,
+    ) -> Result<Option<Arc<VirtualFilesystem>>, anyhow::Error> {
         let mut vfs_devices: Vec<Box<dyn VirtualFilesystemDevice + Send + Sync>> = Vec::new();
         for device_config in self.devices.iter() {
             match device_config {
@@ -173,7 +242,7 @@ impl FilesystemConfig {
                     log::info!("Loading game data from AruaVfs {}", path);
                     vfs_devices.push(Box::new(
                         AruaVfsIndex::load(Path::new(path), &index_root_path.join("data.rose"))
-                            .unwrap_or_else(|_| panic!("Failed to load AruaVfs at {}", path)),
+                            .map_err(|_| anyhow::anyhow!("Failed to load AruaVfs at {}", path))?,
                     ));
 
                     log::info!(
@@ -191,7 +260,7 @@ impl FilesystemConfig {
                     log::info!("Loading game data from TitanVfs {}", path);
                     vfs_devices.push(Box::new(
                         TitanVfsIndex::load(Path::new(path), &index_root_path.join("data.trf"))
-                            .unwrap_or_else(|_| panic!("Failed to load TitanVfs at {}", path)),
+                            .map_err(|_| anyhow::anyhow!("Failed to load TitanVfs at {}", path))?,
                     ));
 
                     log::info!("Loading game data from TitanVfs root path {}", path);
@@ -199,10 +268,10 @@ impl FilesystemConfig {
                 }
                 FilesystemDeviceConfig::Vfs(path) => {
                     log::info!("Loading game data from Vfs {}", path);
-                    vfs_devices.push(Box::new(
-                        VfsIndex::load(Path::new(path))
-                            .unwrap_or_else(|_| panic!("Failed to load Vfs at {}", path)),
-                    ));
+                    vfs_devices
+                        .push(Box::new(VfsIndex::load(Path::new(path)).map_err(|_| {
+                            anyhow::anyhow!("Failed to load Vfs at {}", path)
+                        })?));
 
                     let index_root_path = Path::new(path)
                         .parent()
@@ -218,10 +287,9 @@ impl FilesystemConfig {
                         .unwrap_or_else(PathBuf::new);
 
                     log::info!("Loading game data from iRosePH {}", path);
-                    vfs_devices.push(Box::new(
-                        IrosePhVfsIndex::load(Path::new(path))
-                            .unwrap_or_else(|_| panic!("Failed to load iRosePH VFS at {}", path)),
-                    ));
+                    vfs_devices.push(Box::new(IrosePhVfsIndex::load(Path::new(path)).map_err(
+                        |_| anyhow::anyhow!("Failed to load iRosePH VFS at {}", path),
+                    )?));
 
                     log::info!(
                         "Loading game data from iRosePH root path {}",
@@ -233,18 +301,29 @@ impl FilesystemConfig {
         }
 
         if vfs_devices.is_empty() {
-            None
+            Ok(None)
         } else {
-            Some(Arc::new(VirtualFilesystem::new(vfs_devices)))
+            Ok(Some(Arc::new(VirtualFilesystem::new(vfs_devices))))
         }
     }
 }
 
-#[derive(Deserialize)]
+#[derive(Clone, Deserialize)]
 #[serde(default)]
 pub struct ServerConfig {
     pub ip: String,
     pub port: u16,
+    /// URL of a JSON schedule of server events shown in the calendar, empty to disable.
+    pub event_schedule_url: String,
+    /// URL of a JSON message of the day and patch notes shown beside the server list, empty
+    /// to disable.
+    pub motd_url: String,
+    /// Zone shown behind the login screen, defaults to the title zone.
+    pub login_zone: Option<u16>,
+    /// Camera ZMO played behind the login screen, empty for the title intro camera.
+    pub login_camera: String,
+    /// Music played on the login screen instead of the music of the login zone.
+    pub login_music: String,
 }
 
 impl Default for ServerConfig {
@@ -252,6 +331,11 @@ impl Default for ServerConfig {
         Self {
             ip: "127.0.0.1".into(),
             port: 29000,
+            event_schedule_url: String::new(),
+            motd_url: String::new(),
+            login_zone: None,
+            login_camera: String::new(),
+            login_music: String::new(),
         }
     }
 }
@@ -262,6 +346,8 @@ pub struct GameConfig {
     pub data_version: String,
     pub network_version: String,
     pub ui_version: String,
+    /// The language used for game data and client strings, e.g. "en".
+    pub language: String,
 }
 
 impl Default for GameConfig {
@@ -270,6 +356,7 @@ impl Default for GameConfig {
             data_version: "irose".into(),
             network_version: "irose".into(),
             ui_version: "irose".into(),
+            language: localization::DEFAULT_LANGUAGE.into(),
         }
     }
 }
@@ -279,17 +366,27 @@ impl Default for GameConfig {
 pub enum GraphicsModeConfig {
     #[serde(rename = "window")]
     Window { width: f32, height: f32 },
-    #[serde(rename = "fullscreen")]
-    Fullscreen,
+    #[serde(rename = "borderless", alias = "fullscreen")]
+    Borderless,
+    #[serde(rename = "exclusive")]
+    Exclusive {
+        width: u32,
+        height: u32,
+        refresh_rate: Option<u32>,
+    },
 }
 
 #[derive(Deserialize)]
 #[serde(default)]
 pub struct GraphicsConfig {
     pub mode: GraphicsModeConfig,
+    pub monitor: Option<usize>,
+    pub minimize_on_focus_loss: MinimizeOnFocusLoss,
     pub passthrough_terrain_textures: bool,
     pub trail_effect_duration_multiplier: f32,
     pub disable_vsync: bool,
+    pub gpu_timestamps: bool,
+    pub water_reflections: bool,
 }
 
 impl Default for GraphicsConfig {
@@ -299,9 +396,13 @@ impl Default for GraphicsConfig {
                 width: 1920.0,
                 height: 1080.0,
             },
+            monitor: None,
+            minimize_on_focus_loss: MinimizeOnFocusLoss::ExclusiveFullscreen,
             passthrough_terrain_textures: false,
             trail_effect_duration_multiplier: 1.0,
             disable_vsync: false,
+            gpu_timestamps: false,
+            water_reflections: true,
         }
     }
 }
@@ -334,11 +435,41 @@ impl Default for SoundVolumeConfig {
     }
 }
 
+/// Distance falloff of spatial sounds, a category without a falloff uses each sound's radius.
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct SoundAttenuationConfig {
+    pub player_footstep: Option<SoundAttenuation>,
+    pub player_combat: Option<SoundAttenuation>,
+    pub other_footstep: Option<SoundAttenuation>,
+    pub other_combat: Option<SoundAttenuation>,
+    pub npc_sounds: Option<SoundAttenuation>,
+
+    /// Falloff for individual sounds from the sound database, by sound id
+    pub sounds: HashMap<String, SoundAttenuation>,
+}
+
+impl Default for SoundAttenuationConfig {
+    fn default() -> Self {
+        Self {
+            player_footstep: Some(SoundAttenuation::linear(2.0, 20.0)),
+            player_combat: Some(SoundAttenuation::logarithmic(4.0, 80.0)),
+            other_footstep: Some(SoundAttenuation::linear(2.0, 20.0)),
+            other_combat: Some(SoundAttenuation::logarithmic(4.0, 60.0)),
+            npc_sounds: None,
+            sounds: HashMap::new(),
+        }
+    }
+}
+
 #[derive(Deserialize)]
 #[serde(default)]
 pub struct SoundConfig {
     pub enabled: bool,
     pub volume: SoundVolumeConfig,
+    pub attenuation: SoundAttenuationConfig,
+    /// Path of a TOML file of background music playlists which replace the music of zones.
+    pub zone_music_path: Option<String>,
 }
 
 impl Default for SoundConfig {
@@ -346,20 +477,151 @@ impl Default for SoundConfig {
         Self {
             enabled: true,
             volume: SoundVolumeConfig::default(),
+            attenuation: SoundAttenuationConfig::default(),
+            zone_music_path: None,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LogRotationConfig {
+    Never,
+    Hourly,
+    Daily,
+}
+
+#[derive(Deserialize)]
+#[serde(default)]
+pub struct LogConfig {
+    /// The default log level: error, warn, info, debug or trace.
+    pub level: String,
+    /// Per target log levels in the same format as RUST_LOG, which overrides the config.
+    pub filter: String,
+    pub file_enabled: bool,
+    /// Defaults to the logs folder in the user data directory.
+    pub file_directory: Option<String>,
+    pub file_rotation: LogRotationConfig,
+    /// The number of rotated log files to keep, 0 keeps all files.
+    pub max_files: usize,
+}
+
+impl Default for LogConfig {
+    fn default() -> Self {
+        Self {
+            level: "info".into(),
+            filter: LOG_FILTER.into(),
+            file_enabled: false,
+            file_directory: None,
+            file_rotation: LogRotationConfig::Daily,
+            max_files: 7,
+        }
+    }
+}
+
+impl LogConfig {
+    fn level(&self) -> Level {
+        self.level.parse().unwrap_or_else(|_| {
+            println!("Invalid log level {}, using info", self.level);
+            Level::INFO
+        })
+    }
+
+    fn create_file_appender(&self) -> Option<RollingFileAppender> {
+        if !self.file_enabled {
+            return None;
+        }
+
+        let directory = self
+            .file_directory
+            .as_ref()
+            .map(PathBuf::from)
+            .or_else(|| {
+                resources::get_data_dir()
+                    .ok()
+                    .map(|data_dir| data_dir.join("logs"))
+            })?;
+
+        let mut builder = RollingFileAppender::builder()
+            .rotation(match self.file_rotation {
+                LogRotationConfig::Never => Rotation::NEVER,
+                LogRotationConfig::Hourly => Rotation::HOURLY,
+                LogRotationConfig::Daily => Rotation::DAILY,
+            })
+            .filename_prefix("rose-offline-client")
+            .filename_suffix("log");
+        if self.max_files > 0 {
+            builder = builder.max_log_files(self.max_files);
+        }
+
+        match builder.build(&directory) {
+            Ok(file_appender) => Some(file_appender),
+            Err(error) => {
+                println!(
+                    "Failed to create log file in {} with error: {}",
+                    directory.to_string_lossy(),
+                    error
+                );
+                None
+            }
         }
     }
 }
 
+#[derive(Default, Deserialize)]
+#[serde(default)]
+pub struct ReplayConfig {
+    pub record: bool,
+    pub playback_path: Option<String>,
+    pub headless: bool,
+}
+
 #[derive(Default, Deserialize)]
 #[serde(default)]
 pub struct Config {
-    pub account: AccountConfig,
-    pub auto_login: AutoLoginConfig,
+    pub profile: Option<String>,
+    pub profiles: BTreeMap<String, ProfileConfig>,
     pub filesystem: FilesystemConfig,
     pub game: GameConfig,
     pub graphics: GraphicsConfig,
-    pub server: ServerConfig,
+    pub log: LogConfig,
+    pub replay: ReplayConfig,
     pub sound: SoundConfig,
+    /// The file the config was read from, which settings changed in game are
+    /// saved back to.
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+}
+
+impl Config {
+    pub const DEFAULT_PROFILE_NAME: &'static str = "default";
+
+    /// The name of the profile given by --profile, or the first profile.
+    pub fn selected_profile_name(&self) -> String {
+        self.profile
+            .clone()
+            .or_else(|| self.profiles.keys().next().cloned())
+            .unwrap_or_else(|| Self::DEFAULT_PROFILE_NAME.to_string())
+    }
+
+    pub fn selected_profile(&self) -> ProfileConfig {
+        self.profiles
+            .get(&self.selected_profile_name())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    pub fn selected_profile_mut(&mut self) -> &mut ProfileConfig {
+        let name = self.selected_profile_name();
+        self.profiles.entry(name).or_default()
+    }
+}
+
+/// The config in the user's config directory, used when --config is not given.
+pub fn get_default_config_path() -> Option<PathBuf> {
+    resources::get_config_dir()
+        .ok()
+        .map(|config_dir| config_dir.join("config.toml"))
 }
 
 pub fn load_config(path: &Path) -> Config {
@@ -375,9 +637,10 @@ pub fn load_config(path: &Path) -> Config {
         }
     };
 
-    match toml::from_str(&toml_str) {
-        Ok(config) => {
+    match toml::from_str::<Config>(&toml_str) {
+        Ok(mut config) => {
             println!("Read configuration from {}", path.to_string_lossy());
+            config.path = Some(path.to_path_buf());
             config
         }
         Err(error) => {
@@ -394,6 +657,7 @@ pub fn load_config(path: &Path) -> Config {
 #[derive(Default)]
 pub struct SystemsConfig {
     pub disable_player_command_system: bool,
+    pub headless: bool,
     pub add_custom_systems: Option<Box<dyn FnOnce(&mut App)>>,
 }
 
@@ -422,6 +686,144 @@ pub fn run_zone_viewer(config: &Config, zone_id: Option<ZoneId>) {
     );
 }
 
+/// Runs without a window or GPU, loads the zone and exits after num_frames,
+/// returns a non-zero exit code on panic or missing assets.
+pub fn run_headless_smoke_test(config: &Config, zone_id: Option<ZoneId>, num_frames: u32) -> i32 {
+    let smoke_test = HeadlessSmokeTest::new(num_frames);
+    smoke_test.install_panic_hook();
+
+    let smoke_test_resource = smoke_test.clone();
+    run_client(
+        config,
+        AppState::ZoneViewer,
+        SystemsConfig {
+            headless: true,
+            add_custom_systems: Some(Box::new(move |app| {
+                app.world
+                    .resource_mut::<Events<LoadZoneEvent>>()
+                    .send(LoadZoneEvent::new(
+                        zone_id.unwrap_or_else(|| ZoneId::new(1).unwrap()),
+                    ));
+                app.insert_resource(smoke_test_resource);
+                app.add_systems(Update, headless_smoke_test_system);
+            })),
+            ..Default::default()
+        },
+    );
+
+    smoke_test.exit_code()
+}
+
+/// Loads the zone without a window, writes a report of any broken zone data
+/// and returns a non-zero exit code if any issues were found.
+pub fn run_zone_validation(
+    config: &Config,
+    zone_id: Option<ZoneId>,
+    output_path: Option<PathBuf>,
+) -> i32 {
+    let zone_validation_result = ZoneValidationResult::default();
+    let zone_validation_result_resource = zone_validation_result.clone();
+    run_client(
+        config,
+        AppState::ZoneViewer,
+        SystemsConfig {
+            headless: true,
+            add_custom_systems: Some(Box::new(move |app| {
+                app.world
+                    .resource_mut::<Events<ValidateZoneEvent>>()
+                    .send(ValidateZoneEvent {
+                        id: zone_id.unwrap_or_else(|| ZoneId::new(1).unwrap()),
+                        output_path,
+                    });
+                app.insert_resource(zone_validation_result_resource);
+            })),
+            ..Default::default()
+        },
+    );
+
+    zone_validation_result.exit_code()
+}
+
+/// Loads the zone, plays camera_motion or orbits the zone for duration and writes a
+/// report of the frame rate, GPU pass times and memory usage to output_path.
+pub fn run_zone_benchmark(
+    config: &Config,
+    zone_id: Option<ZoneId>,
+    camera_motion: Option<String>,
+    duration: Duration,
+    output_path: Option<PathBuf>,
+) -> i32 {
+    let zone_benchmark = ZoneBenchmark::new(camera_motion, duration, output_path);
+    let zone_benchmark_resource = zone_benchmark.clone();
+    run_client(
+        config,
+        AppState::ZoneViewer,
+        SystemsConfig {
+            add_custom_systems: Some(Box::new(move |app| {
+                app.world
+                    .resource_mut::<Events<LoadZoneEvent>>()
+                    .send(LoadZoneEvent::new(
+                        zone_id.unwrap_or_else(|| ZoneId::new(1).unwrap()),
+                    ));
+                app.insert_resource(zone_benchmark_resource);
+                app.add_systems(Update, zone_benchmark_system);
+            })),
+            ..Default::default()
+        },
+    );
+
+    zone_benchmark.exit_code()
+}
+
+/// Draws every dialog in turn with sample data bound, saving a labeled
+/// screenshot of each to output_dir so layout changes can be compared.
+pub fn run_ui_screenshots(config: &Config, output_dir: PathBuf) {
+    run_client(
+        config,
+        AppState::ZoneViewer,
+        SystemsConfig {
+            add_custom_systems: Some(Box::new(move |app| {
+                app.insert_resource(UiScreenshotHarness::new(output_dir));
+                app.add_systems(
+                    Update,
+                    ui_screenshot_harness_system.in_set(UiSystemSets::UiDebug),
+                );
+            })),
+            ..Default::default()
+        },
+    );
+}
+
+/// Shows a window to choose the game data location, which is written to the
+/// filesystem section of the config at config_path. Returns true if the user
+/// completed setup, the client must then be restarted as the window event loop
+/// can only be created once per process.
+pub fn run_setup_wizard(config_path: PathBuf) -> bool {
+    let completed = Arc::new(AtomicBool::new(false));
+
+    let mut app = App::new();
+    app.insert_resource(WinitSettings {
+        return_from_run: true,
+        ..WinitSettings::desktop_app()
+    })
+    .add_plugins(
+        bevy::prelude::DefaultPlugins.set(bevy::window::WindowPlugin {
+            primary_window: Some(Window {
+                title: "rose-offline-client setup".to_string(),
+                resolution: bevy::window::WindowResolution::new(640.0, 320.0),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }),
+    )
+    .add_plugins(bevy_egui::EguiPlugin)
+    .insert_resource(SetupWizard::new(config_path, completed.clone()))
+    .add_systems(Update, ui_setup_wizard_system);
+    app.run();
+
+    completed.load(Ordering::Relaxed)
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash, SystemSet)]
 enum GameStages {
     ZoneChange,
@@ -455,20 +857,106 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
             return;
         };
 
-    let (window_width, window_height) =
-        if let GraphicsModeConfig::Window { width, height } = config.graphics.mode {
-            (width, height)
-        } else {
-            (1920.0, 1080.0)
-        };
+    let window_settings = WindowSettings::from_config(&config.graphics);
+    let (window_width, window_height) = window_settings.window_resolution();
+
+    // Read user settings early as texture quality must be known when creating the app
+    let user_settings_store = UserSettingsStore::read();
+    let texture_quality = user_settings_store
+        .get_loaded::<TextureQuality>("graphics", "texture_quality")
+        .unwrap_or(TextureQuality::High);
+
+    // The replay must be loaded or created before the app as it seeds ReplayRng, which is
+    // used by resources initialised below
+    let replay_playback = config.replay.playback_path.as_ref().map(|playback_path| {
+        (
+            playback_path,
+            ReplayPlayback::load(Path::new(playback_path), config.replay.headless),
+        )
+    });
+    let replay_recorder = if replay_playback.is_none() && config.replay.record {
+        Some(ReplayRecorder::new())
+    } else {
+        None
+    };
+
+    // Headless replay playback runs without a window or renderer
+    if matches!(&replay_playback, Some((_, Ok(replay_playback))) if replay_playback.headless) {
+        systems_config.headless = true;
+    }
 
     let mut app = App::new();
 
     // Must Initialise asset server before asset plugin
+    let missing_assets = MissingAssets::default();
     app.insert_resource(VfsResource {
         vfs: virtual_filesystem.clone(),
     })
-    .insert_resource(AssetServer::new(VfsAssetIo::new(virtual_filesystem)));
+    .insert_resource(missing_assets.clone())
+    .insert_resource(AssetServer::new(VfsAssetIo::new(
+        virtual_filesystem,
+        missing_assets,
+    )));
+
+    let default_plugins = bevy::prelude::DefaultPlugins
+        .set(bevy::render::RenderPlugin {
+            wgpu_settings: WgpuSettings {
+                features: if config.graphics.gpu_timestamps {
+                    WgpuFeatures::TEXTURE_COMPRESSION_BC
+                        | WgpuFeatures::TIMESTAMP_QUERY
+                        | WgpuFeatures::WRITE_TIMESTAMP_INSIDE_PASSES
+                } else {
+                    WgpuFeatures::TEXTURE_COMPRESSION_BC
+                },
+                // backends: Some(Backends::DX12),
+                backends: if systems_config.headless {
+                    None
+                } else {
+                    WgpuSettings::default().backends
+                },
+                ..Default::default()
+            },
+        })
+        .set(bevy::window::WindowPlugin {
+            primary_window: Some(Window {
+                title: "rose-offline-client".to_string(),
+                visible: !systems_config.headless,
+                present_mode: window_settings.present_mode(),
+                resolution: bevy::window::WindowResolution::new(window_width, window_height),
+                mode: window_settings.window_mode(),
+                position: window_settings.window_position(),
+                ..Default::default()
+            }),
+            // Detached debug tool windows should not keep the game running
+            exit_condition: bevy::window::ExitCondition::OnPrimaryClosed,
+            ..Default::default()
+        })
+        .set(bevy::pbr::PbrPlugin {
+            prepass_enabled: false,
+        })
+        .set(bevy::render::texture::ImagePlugin {
+            default_sampler: texture_quality.sampler_descriptor(),
+        });
+
+    // Without winit no window or surface is created, but the primary window entity
+    // still exists for the egui systems
+    let default_plugins = if systems_config.headless {
+        default_plugins.disable::<bevy::winit::WinitPlugin>().add(
+            bevy::app::ScheduleRunnerPlugin::run_loop(Duration::from_secs_f64(1.0 / 60.0)),
+        )
+    } else {
+        default_plugins
+    };
+
+    // Replace the bevy log plugin so recent log lines can be kept for crash reports
+    // and the log viewer, and the frame profiler can record system spans
+    frame_profiler::init_tracing_subscriber(
+        config.log.level(),
+        &config.log.filter,
+        config.log.create_file_appender(),
+    );
+    localization::set_language(localization::get_language(&config.game.language));
+    let default_plugins = default_plugins.disable::<bevy::log::LogPlugin>();
 
     // Initialise bevy engine
     app.insert_resource(Msaa::Off)
@@ -478,44 +966,7 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
             ..Default::default()
         })
         .add_plugins((
-            bevy::prelude::DefaultPlugins
-                .set(bevy::render::RenderPlugin {
-                    wgpu_settings: WgpuSettings {
-                        features: WgpuFeatures::TEXTURE_COMPRESSION_BC,
-                        // backends: Some(Backends::DX12),
-                        ..Default::default()
-                    },
-                })
-                .set(bevy::window::WindowPlugin {
-                    primary_window: Some(Window {
-                        title: "rose-offline-client".to_string(),
-                        present_mode: if config.graphics.disable_vsync {
-                            bevy::window::PresentMode::Immediate
-                        } else {
-                            bevy::window::PresentMode::Fifo
-                        },
-                        resolution: bevy::window::WindowResolution::new(
-                            window_width,
-                            window_height,
-                        ),
-                        mode: if matches!(config.graphics.mode, GraphicsModeConfig::Fullscreen) {
-                            WindowMode::BorderlessFullscreen
-                        } else {
-                            WindowMode::Windowed
-                        },
-                        ..Default::default()
-                    }),
-                    ..Default::default()
-                })
-                .set(bevy::log::LogPlugin {
-                    level: Level::INFO,
-                    filter:
-                        "wgpu=error,packets=debug,quest=trace,lua=debug,con=trace,animation=info"
-                            .to_string(),
-                })
-                .set(bevy::pbr::PbrPlugin {
-                    prepass_enabled: false,
-                }),
+            default_plugins,
             bevy::diagnostic::EntityCountDiagnosticsPlugin,
             bevy::diagnostic::FrameTimeDiagnosticsPlugin,
         ));
@@ -538,6 +989,20 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
     ));
 
     // Initialise rose stuff
+    let login_profiles = LoginProfiles::from_config(config);
+    let zone_music_playlists = if let Some(zone_music_path) = config.sound.zone_music_path.as_ref()
+    {
+        ZoneMusicPlaylists::load(Path::new(zone_music_path)).unwrap_or_else(|error| {
+            log::error!(
+                "Failed to load zone music playlists {} with error: {}",
+                zone_music_path,
+                error
+            );
+            ZoneMusicPlaylists::default()
+        })
+    } else {
+        ZoneMusicPlaylists::default()
+    };
     app.init_asset_loader::<ZmsAssetLoader>()
         .init_asset_loader::<ZmsNoSkinAssetLoader>()
         .add_asset::<ZmsMaterialNumFaces>()
@@ -549,17 +1014,10 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
         .insert_resource(RenderConfiguration {
             passthrough_terrain_textures: config.graphics.passthrough_terrain_textures,
             trail_effect_duration_multiplier: config.graphics.trail_effect_duration_multiplier,
+            water_reflections: config.graphics.water_reflections,
         })
-        .insert_resource(ServerConfiguration {
-            ip: config.server.ip.clone(),
-            port: format!("{}", config.server.port),
-            preset_username: Some(config.account.username.clone()),
-            preset_password: Some(config.account.password.clone()),
-            preset_server_id: config.auto_login.server_id,
-            preset_channel_id: config.auto_login.channel_id,
-            preset_character_name: config.auto_login.character_name.clone(),
-            auto_login: config.auto_login.enabled,
-        })
+        .insert_resource(ServerConfiguration::from_profile(login_profiles.selected()))
+        .insert_resource(login_profiles)
         .insert_resource(SoundSettings {
             enabled: config.sound.enabled,
             global_gain: config.sound.volume.global,
@@ -572,41 +1030,77 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
                 SoundCategory::NpcSounds => config.sound.volume.npc_sounds,
                 SoundCategory::Ui => config.sound.volume.ui_sounds,
             },
+            category_attenuation: enum_map! {
+                SoundCategory::BackgroundMusic | SoundCategory::Ui => None,
+                SoundCategory::PlayerFootstep => config.sound.attenuation.player_footstep.clone(),
+                SoundCategory::PlayerCombat => config.sound.attenuation.player_combat.clone(),
+                SoundCategory::OtherFootstep => config.sound.attenuation.other_footstep.clone(),
+                SoundCategory::OtherCombat => config.sound.attenuation.other_combat.clone(),
+                SoundCategory::NpcSounds => config.sound.attenuation.npc_sounds.clone(),
+            },
+            sound_attenuation: config
+                .sound
+                .attenuation
+                .sounds
+                .iter()
+                .filter_map(|(sound_id, attenuation)| {
+                    let Ok(sound_id) = sound_id.parse::<u16>() else {
+                        log::warn!("Invalid sound id {} in sound attenuation config", sound_id);
+                        return None;
+                    };
+                    Some((sound_id, attenuation.clone()))
+                })
+                .collect(),
+            output_device: None,
+            muted: false,
         })
+        .insert_resource(zone_music_playlists)
         .add_plugins((
             RoseAnimationPlugin,
             RoseRenderPlugin,
             RoseScriptingPlugin,
             DebugInspectorPlugin,
+            FrameProfilerPlugin,
+            CrashReportPlugin,
+            LogViewerPlugin,
+            TextToSpeechPlugin,
         ));
 
     // Setup state
     app.add_state::<AppState>()
         .insert_resource(State::new(app_state));
 
-    app.add_event::<BankEvent>()
+    app.add_event::<AchievementEvent>()
+        .add_event::<BankEvent>()
         .add_event::<ChatboxEvent>()
+        .add_event::<ChatboxInputEvent>()
         .add_event::<CharacterSelectEvent>()
         .add_event::<ClanDialogEvent>()
         .add_event::<ClientEntityEvent>()
         .add_event::<ConversationDialogEvent>()
+        .add_event::<CraftEvent>()
         .add_event::<GameConnectionEvent>()
         .add_event::<HitEvent>()
         .add_event::<LoginEvent>()
+        .add_event::<LogoutEvent>()
         .add_event::<LoadZoneEvent>()
         .add_event::<MessageBoxEvent>()
         .add_event::<MoveDestinationEffectEvent>()
         .add_event::<NetworkEvent>()
         .add_event::<NumberInputDialogEvent>()
+        .add_event::<MoneyInputDialogEvent>()
         .add_event::<NpcStoreEvent>()
         .add_event::<PartyEvent>()
         .add_event::<PersonalStoreEvent>()
         .add_event::<PlayerCommandEvent>()
         .add_event::<QuestTriggerEvent>()
+        .add_event::<RepairEvent>()
+        .add_event::<ScreenTransitionEvent>()
         .add_event::<SystemFuncEvent>()
         .add_event::<SpawnEffectEvent>()
         .add_event::<SpawnProjectileEvent>()
         .add_event::<UseItemEvent>()
+        .add_event::<ValidateZoneEvent>()
         .add_event::<WorldConnectionEvent>()
         .add_event::<ZoneEvent>()
         .add_event::<UiSoundEvent>();
@@ -624,6 +1118,12 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
         Update,
         (free_camera_system, orbit_camera_system).in_set(GameSystemSets::UpdateCamera),
     );
+    app.add_systems(
+        Update,
+        input_context_system
+            .before(GameSystemSets::UpdateCamera)
+            .before(GameSystemSets::Ui),
+    );
     app.add_systems(
         Update,
         (
@@ -638,6 +1138,7 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
                 npc_model_add_collider_system.after(npc_model_update_system),
                 item_drop_model_system,
                 item_drop_model_add_collider_system.after(item_drop_model_system),
+                item_drop_effect_system,
                 particle_sequence_system,
                 effect_system,
                 animation_effect_system.before(spawn_effect_system),
@@ -675,6 +1176,7 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
                 move_destination_effect_system.after(game_mouse_input_system),
                 npc_idle_sound_system,
                 name_tag_system,
+                personal_store_signboard_system,
                 name_tag_visibility_system.after(game_mouse_input_system),
                 name_tag_update_color_system,
                 world_time_system,
@@ -682,6 +1184,8 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
                 load_dialog_sprites_system,
                 zone_time_system.after(world_time_system),
                 directional_light_system,
+                footstep_effect_system,
+                footstep_effect_update_system.after(footstep_effect_system),
             ),
         ),
     );
@@ -693,12 +1197,24 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
 
     app.add_systems(
         Update,
-        ui_item_drop_name_system.in_set(UiSystemSets::UiFirst),
+        (
+            ui_screen_transition_system,
+            ui_item_drop_name_system,
+            ui_status_effect_text_system,
+            ui_level_up_system,
+        )
+            .in_set(UiSystemSets::UiFirst),
     );
 
     app.add_systems(
         Update,
-        (ui_message_box_system, ui_number_input_dialog_system).in_set(UiSystemSets::UiLast),
+        (
+            ui_crash_report_system,
+            ui_message_box_system,
+            ui_number_input_dialog_system,
+            ui_money_input_dialog_system,
+        )
+            .in_set(UiSystemSets::UiLast),
     );
     app.add_systems(
         Update,
@@ -710,7 +1226,11 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
     );
     app.add_systems(
         Update,
-        (ui_debug_menu_system,).in_set(UiSystemSets::UiDebugMenu),
+        (
+            ui_debug_menu_system,
+            ui_debug_detached_windows_system.after(ui_debug_menu_system),
+        )
+            .in_set(UiSystemSets::UiDebugMenu),
     );
 
     app.add_systems(
@@ -731,14 +1251,26 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
             ui_debug_zone_list_system,
             ui_debug_zone_time_system,
             ui_debug_diagnostics_system,
+            ui_debug_frame_profiler_system,
+            ui_debug_render_statistics_system,
+            ui_debug_memory_usage_system,
+            ui_debug_packet_inspector_system,
+            ui_debug_time_control_system,
         )
             .in_set(UiSystemSets::UiDebug),
     );
+    app.add_systems(
+        Update,
+        (ui_debug_log_viewer_system, ui_debug_lua_vm_system).in_set(UiSystemSets::UiDebug),
+    );
 
     // character_model_blink_system in PostUpdate to avoid any conflicts with model destruction
     // e.g. through the character select exit system.
     app.add_systems(PostUpdate, character_model_blink_system);
 
+    // entity_history_system in PostUpdate so it sees the commands set during Update
+    app.add_systems(PostUpdate, entity_history_system);
+
     // vehicle_model_system in after ::Update but before ::PostUpdate to avoid any conflicts,
     // with model destruction but to also be before global transform is calculated.
     app.add_systems(
@@ -748,6 +1280,13 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
             .in_set(GameStages::AfterUpdate),
     );
 
+    // water_reflection_system in PostUpdate after the cameras have moved, but before the
+    // reflection camera global transform is calculated.
+    app.add_systems(
+        PostUpdate,
+        water_reflection_system.before(TransformSystem::TransformPropagate),
+    );
+
     // Run zone change system just before physics sync which is after Update
     app.add_systems(
         Update,
@@ -758,6 +1297,11 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
             .in_set(GameStages::ZoneChange),
     );
 
+    app.add_systems(Update, zone_validation_system);
+
+    // Debug time scale must be applied before the time is updated for this frame
+    app.add_systems(First, debug_time_control_system.before(TimeSystem));
+
     // Run debug render stage last after physics update so it has accurate data
     app.add_systems(
         Update,
@@ -765,12 +1309,19 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
             debug_render_collider_system,
             debug_render_skeleton_system,
             debug_render_directional_light_system,
+            debug_render_walkability_system,
         )
             .in_set(GameStages::DebugRender),
     );
 
     // Zone Viewer
     app.add_systems(OnEnter(AppState::ZoneViewer), zone_viewer_enter_system);
+    app.add_systems(
+        Update,
+        ui_debug_spawn_palette_system
+            .in_set(UiSystemSets::UiDebug)
+            .run_if(in_state(AppState::ZoneViewer).or_else(in_state(AppState::ModelViewer))),
+    );
 
     // Model Viewer, we avoid deleting any entities during CoreStage::Update by using a custom
     // stage which runs after Update. We cannot run before Update because the on_enter system
@@ -791,7 +1342,23 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
 
     app.add_systems(
         Update,
-        (login_system, login_event_system).run_if(in_state(AppState::GameLogin)),
+        (login_system, login_event_system, message_of_the_day_system)
+            .run_if(in_state(AppState::GameLogin)),
+    );
+
+    // Reconnecting goes through login and character select, so is not limited to a state
+    app.add_systems(
+        Update,
+        (
+            game_reconnect_start_system.run_if(in_state(AppState::Game)),
+            game_reconnect_system.before(login_event_system),
+        ),
+    );
+
+    app.add_systems(Update, logout_system.run_if(in_state(AppState::Game)));
+    app.add_systems(
+        Update,
+        vehicle_input_system.run_if(in_state(AppState::Game)),
     );
 
     app.add_systems(
@@ -819,6 +1386,7 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
             character_select_system,
             character_select_input_system,
             character_select_models_system,
+            character_select_camera_system,
             character_select_event_system,
         )
             .run_if(in_state(AppState::GameCharacterSelect)),
@@ -841,15 +1409,105 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
     // Game
     app.init_resource::<UiStateDragAndDrop>()
         .init_resource::<UiStateWindows>()
+        .init_resource::<UiStateSettings>()
         .init_resource::<UiStateDebugWindows>()
         .init_resource::<ClientEntityList>()
         .init_resource::<DebugRenderConfig>()
+        .init_resource::<DebugTimeControl>()
+        .init_resource::<EntityHistory>()
         .init_resource::<WorldTime>()
         .init_resource::<ZoneTime>()
         .init_resource::<SelectedTarget>()
-        .init_resource::<NameTagSettings>();
+        .init_resource::<NameTagSettings>()
+        .init_resource::<Achievements>()
+        .init_resource::<HudLayout>()
+        .init_resource::<AutoLootSettings>()
+        .init_resource::<SkillQueue>()
+        .init_resource::<DefendTarget>()
+        .init_resource::<Waypoints>()
+        .init_resource::<AfkSettings>()
+        .init_resource::<AfkState>()
+        .init_resource::<SessionStatistics>()
+        .init_resource::<EventSchedule>()
+        .init_resource::<MessageOfTheDay>()
+        .init_resource::<FriendList>()
+        .init_resource::<InputBindings>()
+        .init_resource::<CooldownStore>()
+        .init_resource::<PersonalStoreListRequests>()
+        .init_resource::<ItemGradeEffectSettings>()
+        .init_resource::<LevelUpSettings>()
+        .init_resource::<CooldownAlertSettings>()
+        .init_resource::<CooldownAlerts>()
+        .init_resource::<LevelUpPresentation>()
+        .init_resource::<AccessibilitySettings>()
+        .init_resource::<ConversationDialogPortrait>()
+        .init_resource::<WaterReflection>()
+        .init_resource::<SkillRangeIndicator>()
+        .init_resource::<ItemDropEffects>()
+        .init_resource::<FootstepEffects>()
+        .init_resource::<EntityPool<Projectile>>()
+        .init_resource::<EntityPool<PooledEffect>>()
+        .init_resource::<GraphicsQuality>()
+        .init_resource::<GraphicsBenchmark>()
+        .init_resource::<AvailableMonitors>()
+        .insert_resource(window_settings)
+        .insert_resource(user_settings_store)
+        .insert_resource(ConfigPersistence::new(config.path.clone()));
+
+    app.add_systems(OnEnter(AppState::Game), game_state_enter_system)
+        .add_systems(OnExit(AppState::Game), game_state_exit_system);
+
+    app.add_systems(
+        Update,
+        (
+            afk_system,
+            afk_auto_reply_system.run_if(in_state(AppState::Game)),
+        ),
+    );
+
+    app.add_systems(
+        Update,
+        clan_skill_system
+            .before(ability_values_system)
+            .run_if(in_state(AppState::Game)),
+    );
 
-    app.add_systems(OnEnter(AppState::Game), game_state_enter_system);
+    app.add_systems(
+        Update,
+        cooldown_store_system
+            .after(cooldown_system)
+            .run_if(in_state(AppState::Game)),
+    );
+
+    app.add_systems(
+        Update,
+        character_export_system
+            .after(player_command_system)
+            .run_if(in_state(AppState::Game)),
+    );
+
+    app.add_systems(
+        Update,
+        npc_quest_marker_system
+            .after(npc_model_add_collider_system)
+            .run_if(in_state(AppState::Game)),
+    );
+
+    app.add_systems(
+        Update,
+        aggro_system
+            .after(hit_event_system)
+            .before(name_tag_update_color_system)
+            .run_if(in_state(AppState::Game)),
+    );
+
+    app.add_systems(
+        Update,
+        name_tag_combat_healthbar_system
+            .after(hit_event_system)
+            .after(name_tag_visibility_system)
+            .run_if(in_state(AppState::Game)),
+    );
 
     app.add_systems(
         Update,
@@ -874,6 +1532,21 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
             passive_recovery_system,
             quest_trigger_system,
             game_mouse_input_system.after(GameSystemSets::Ui),
+            auto_loot_system.after(GameSystemSets::Ui),
+            achievement_system,
+            item_locks_system,
+            encumbrance_system,
+            ammo_system,
+            defend_target_system.after(hit_event_system),
+        )
+            .run_if(in_state(AppState::Game)),
+    );
+
+    app.add_systems(
+        Update,
+        (
+            update_networked_position_system.after(collision_height_only_system),
+            event_schedule_system,
         )
             .run_if(in_state(AppState::Game)),
     );
@@ -887,8 +1560,12 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
                 ui_character_info_system,
                 ui_clan_system,
                 ui_create_clan_system,
+                ui_inspect_character_system,
                 ui_inventory_system,
                 ui_game_menu_system.after(ui_character_info_system),
+                ui_window_manager_system
+                    .before(ui_game_menu_system)
+                    .before(ui_settings_system),
                 ui_hotbar_system,
                 ui_minimap_system,
                 ui_npc_store_system,
@@ -905,12 +1582,38 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
                 ui_skill_tree_system,
                 ui_settings_system,
                 ui_status_effects_system,
+                ui_achievements_system,
+                ui_waypoints_system,
+                ui_session_statistics_system,
+                ui_announcement_system,
+                ui_personal_store_scanner_system,
+                ui_chat_bubble_system,
+                ui_calendar_system,
                 conversation_dialog_system,
             ),
+            (
+                ui_cast_bar_system,
+                ui_clock_system.after(ui_minimap_system),
+                ui_cooldown_alert_system,
+                ui_craft_system,
+                ui_friends_system,
+                ui_repair_system,
+            ),
         )
             .run_if(in_state(AppState::Game))
             .in_set(UiSystemSets::Ui),
     );
+    app.add_systems(
+        Update,
+        (skill_range_indicator_system, ui_hud_layout_system)
+            .after(UiSystemSets::Ui)
+            .run_if(in_state(AppState::Game)),
+    );
+    // Not limited to the game state so the portrait camera is removed after leaving the game
+    app.add_systems(
+        Update,
+        conversation_dialog_portrait_system.after(conversation_dialog_system),
+    );
 
     if !systems_config.disable_player_command_system {
         app.add_systems(
@@ -928,7 +1631,18 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
     let (network_thread_tx, network_thread_rx) =
         tokio::sync::mpsc::unbounded_channel::<NetworkThreadMessage>();
     let network_thread = std::thread::spawn(move || run_network_thread(network_thread_rx));
-    app.insert_resource(NetworkThread::new(network_thread_tx.clone()));
+    app.insert_resource(NetworkThread::new(network_thread_tx.clone()))
+        .init_resource::<PacketInspector>()
+        .init_resource::<ProtocolRegistry>();
+
+    let mut game_version_registry = GameVersionRegistry::default();
+    game_version_registry.register_data_loader("irose", |app| {
+        app.add_systems(Startup, load_game_data_irose);
+    });
+    game_version_registry.register_ui_loader("irose", |app| {
+        app.add_systems(Startup, load_ui_resources);
+    });
+    app.insert_resource(game_version_registry);
 
     // Run network systems before Update, so we can add/remove entities
     app.add_systems(
@@ -940,35 +1654,121 @@ fn run_client(config: &Config, app_state: AppState, mut systems_config: SystemsC
         ),
     );
 
-    app.add_systems(PostStartup, load_common_game_data);
+    // Setup replay recording or playback
+    if let Some((playback_path, replay_playback)) = replay_playback {
+        match replay_playback {
+            Ok(replay_playback) => {
+                log::info!(
+                    "Playing back replay {} with {} records",
+                    playback_path,
+                    replay_playback.records.len()
+                );
+                app.insert_resource(replay_playback);
+                app.add_systems(
+                    PreUpdate,
+                    replay_playback_system
+                        .before(InputSystem)
+                        .before(EguiSet::ProcessInput),
+                );
+            }
+            Err(error) => {
+                log::error!(
+                    "Failed to load replay {} with error: {}",
+                    playback_path,
+                    error
+                );
+                return;
+            }
+        }
+    } else if let Some(replay_recorder) = replay_recorder {
+        match replay_recorder {
+            Ok((replay_recorder, path)) => {
+                log::info!("Recording replay to {}", path.to_string_lossy());
+                app.insert_resource(replay_recorder);
+                app.add_systems(PreUpdate, replay_record_input_system.after(InputSystem));
+            }
+            Err(error) => {
+                log::error!("Failed to start replay recording with error: {}", error);
+            }
+        }
+    }
+
+    // User settings are applied after load_common_game_data has created the resources they modify,
+    // graphics quality is only detected when there were no saved graphics settings
+    app.add_systems(
+        PostStartup,
+        (
+            load_common_game_data,
+            apply_deferred,
+            user_settings_load_system,
+            graphics_quality_detect_system,
+        )
+            .chain(),
+    );
+    app.add_systems(
+        Update,
+        (
+            (graphics_benchmark_system, graphics_quality_system).chain(),
+            accessibility_bloom_system,
+        ),
+    );
+    app.add_systems(
+        Update,
+        (
+            window_settings_system,
+            window_monitors_system,
+            window_focus_system,
+        ),
+    );
+    app.add_systems(Last, (user_settings_save_system, achievement_save_system));
 
     if let Some(app_builder) = systems_config.add_custom_systems.take() {
         app_builder(&mut app);
     }
 
+    // Custom systems may have registered additional protocols in ProtocolRegistry and
+    // additional loaders in GameVersionRegistry
     match config.game.network_version.as_str() {
-        "irose" => {
-            app.add_systems(PostUpdate, network_thread_system);
-        }
         "custom" => {}
-        unknown => panic!("Unknown game network version {}", unknown),
-    };
+        network_version => {
+            if !app
+                .world
+                .resource_mut::<ProtocolRegistry>()
+                .select(network_version)
+            {
+                log::error!("Unknown game network version {}", network_version);
+                return;
+            }
 
-    match config.game.ui_version.as_str() {
-        "irose" => {
-            app.add_systems(Startup, load_ui_resources);
+            app.add_systems(PostUpdate, network_thread_system);
         }
-        "custom" => {}
-        unknown => panic!("Unknown game ui version {}", unknown),
     };
 
-    match config.game.data_version.as_str() {
-        "irose" => {
-            app.add_systems(Startup, load_game_data_irose);
-        }
-        "custom" => {}
-        unknown => panic!("Unknown game data version {}", unknown),
+    let game_version_registry = app.world.resource::<GameVersionRegistry>();
+    let Some(ui_loader) = game_version_registry.ui_loader(&config.game.ui_version) else {
+        log::error!(
+            "Unknown game ui version {}, available versions are: {}",
+            config.game.ui_version,
+            game_version_registry
+                .ui_versions()
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        return;
     };
+    let Some(data_loader) = game_version_registry.data_loader(&config.game.data_version) else {
+        log::error!(
+            "Unknown game data version {}, available versions are: {}",
+            config.game.data_version,
+            game_version_registry
+                .data_versions()
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        return;
+    };
+    ui_loader(&mut app);
+    data_loader(&mut app);
 
     app.configure_sets(
         PostUpdate,
@@ -1022,8 +1822,11 @@ fn load_game_data_irose(
     vfs_resource: Res<VfsResource>,
     asset_server: Res<AssetServer>,
 ) {
-    let string_database = rose_data_irose::get_string_database(&vfs_resource.vfs, 1)
-        .expect("Failed to load string database");
+    let string_database = rose_data_irose::get_string_database(
+        &vfs_resource.vfs,
+        localization::get_current_language().string_database_index,
+    )
+    .expect("Failed to load string database");
 
     let items = Arc::new(
         rose_data_irose::get_item_database(&vfs_resource.vfs, string_database.clone())
@@ -1160,6 +1963,7 @@ fn load_common_game_data(
     );
 
     commands.spawn((
+        MainCamera,
         Camera3dBundle {
             camera: Camera {
                 hdr: false,
@@ -1168,6 +1972,7 @@ fn load_common_game_data(
             ..Default::default()
         },
         BloomSettings::NATURAL,
+        RenderLayers::default().with(WATER_RENDER_LAYER),
     ));
 
     commands.insert_resource(DamageDigitsSpawner::load(
@@ -1175,6 +1980,12 @@ fn load_common_game_data(
         &mut damage_digit_materials,
     ));
 
+    egui_context
+        .ctx_mut()
+        .set_fonts(create_ui_font_definitions());
+}
+
+pub(crate) fn create_ui_font_definitions() -> egui::FontDefinitions {
     let mut fonts = egui::FontDefinitions::default();
     fonts.font_data.insert(
         "Ubuntu-M".to_owned(),
@@ -1186,6 +1997,5 @@ fn load_common_game_data(
         .entry(egui::FontFamily::Name("Ubuntu-M".into()))
         .or_default()
         .insert(0, "Ubuntu-M".to_owned());
-
-    egui_context.ctx_mut().set_fonts(fonts);
+    fonts
 }
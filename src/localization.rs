@@ -0,0 +1,180 @@
+use std::{
+    collections::HashSet,
+    sync::{Mutex, RwLock},
+};
+
+use fluent_bundle::{concurrent::FluentBundle, FluentResource};
+use lazy_static::lazy_static;
+use unic_langid::LanguageIdentifier;
+
+pub use fluent_bundle::FluentArgs;
+
+pub struct Language {
+    /// The language code used in the config, e.g. "en".
+    pub code: &'static str,
+    pub name: &'static str,
+    /// Index of the language column in the game data string tables.
+    pub string_database_index: usize,
+    translation: Option<&'static str>,
+}
+
+pub const DEFAULT_LANGUAGE: &str = "en";
+
+pub static LANGUAGES: [Language; 5] = [
+    Language {
+        code: "ko",
+        name: "한국어",
+        string_database_index: 0,
+        translation: Some(include_str!("../locales/ko.ftl")),
+    },
+    Language {
+        code: "en",
+        name: "English",
+        string_database_index: 1,
+        translation: Some(include_str!("../locales/en.ftl")),
+    },
+    Language {
+        code: "ja",
+        name: "日本語",
+        string_database_index: 2,
+        translation: Some(include_str!("../locales/ja.ftl")),
+    },
+    Language {
+        code: "zh-TW",
+        name: "繁體中文",
+        string_database_index: 3,
+        translation: Some(include_str!("../locales/zh-TW.ftl")),
+    },
+    Language {
+        code: "zh-CN",
+        name: "简体中文",
+        string_database_index: 4,
+        translation: Some(include_str!("../locales/zh-CN.ftl")),
+    },
+];
+
+lazy_static! {
+    static ref LOCALIZATION: RwLock<Localization> =
+        RwLock::new(Localization::new(get_language(DEFAULT_LANGUAGE)));
+}
+
+pub fn get_language(code: &str) -> &'static Language {
+    LANGUAGES
+        .iter()
+        .find(|language| language.code.eq_ignore_ascii_case(code))
+        .unwrap_or_else(|| {
+            log::warn!("Unknown language {}, using {}", code, DEFAULT_LANGUAGE);
+            get_language(DEFAULT_LANGUAGE)
+        })
+}
+
+fn create_bundle(code: &str, source: &str) -> Option<FluentBundle<FluentResource>> {
+    let language_id = code.parse::<LanguageIdentifier>().ok()?;
+    let resource = FluentResource::try_new(source.to_string())
+        .map_err(|(_, errors)| {
+            log::error!("Failed to parse {} translation: {:?}", code, errors);
+        })
+        .ok()?;
+
+    let mut bundle = FluentBundle::new_concurrent(vec![language_id]);
+    // Unicode isolation marks are not supported by the UI font
+    bundle.set_use_isolating(false);
+    bundle.add_resource(resource).ok()?;
+    Some(bundle)
+}
+
+/// The translations of client authored strings, any strings missing from the
+/// selected language use the default language.
+pub struct Localization {
+    language: &'static Language,
+    bundles: Vec<FluentBundle<FluentResource>>,
+    /// Ids of the missing strings which have already been warned about, as most
+    /// strings are formatted every frame.
+    warned_missing: Mutex<HashSet<String>>,
+}
+
+impl Localization {
+    fn new(language: &'static Language) -> Self {
+        let mut bundles = Vec::new();
+        if let Some(bundle) = language
+            .translation
+            .and_then(|translation| create_bundle(language.code, translation))
+        {
+            bundles.push(bundle);
+        }
+
+        if language.code != DEFAULT_LANGUAGE {
+            let default_language = get_language(DEFAULT_LANGUAGE);
+            if let Some(bundle) = default_language
+                .translation
+                .and_then(|translation| create_bundle(default_language.code, translation))
+            {
+                bundles.push(bundle);
+            }
+        }
+
+        Self {
+            language,
+            bundles,
+            warned_missing: Default::default(),
+        }
+    }
+
+    fn format(&self, id: &str, args: Option<&FluentArgs>) -> String {
+        for bundle in self.bundles.iter() {
+            let Some(pattern) = bundle.get_message(id).and_then(|message| message.value()) else {
+                continue;
+            };
+
+            let mut errors = Vec::new();
+            let text = bundle.format_pattern(pattern, args, &mut errors);
+            if !errors.is_empty() {
+                log::warn!("Failed to format string {} with errors: {:?}", id, errors);
+            }
+            return text.into_owned();
+        }
+
+        if let Ok(mut warned_missing) = self.warned_missing.lock() {
+            if !warned_missing.contains(id) {
+                log::warn!("Missing localized string {}", id);
+                warned_missing.insert(id.to_string());
+            }
+        }
+        id.to_string()
+    }
+}
+
+/// Selects the language used for client authored strings.
+pub fn set_language(language: &'static Language) {
+    if let Ok(mut localization) = LOCALIZATION.write() {
+        *localization = Localization::new(language);
+    }
+}
+
+pub fn get_current_language() -> &'static Language {
+    LOCALIZATION.read().map_or_else(
+        |_| get_language(DEFAULT_LANGUAGE),
+        |localization| localization.language,
+    )
+}
+
+pub fn localize(id: &str, args: Option<&FluentArgs>) -> String {
+    LOCALIZATION.read().map_or_else(
+        |_| id.to_string(),
+        |localization| localization.format(id, args),
+    )
+}
+
+/// Returns the localized string with the given id, with optional named arguments:
+/// `localize!("chat-earned-money", money = money.0)`
+#[macro_export]
+macro_rules! localize {
+    ($id:expr) => {
+        $crate::localization::localize($id, None)
+    };
+    ($id:expr, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = $crate::localization::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)+
+        $crate::localization::localize($id, Some(&args))
+    }};
+}
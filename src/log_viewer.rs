@@ -0,0 +1,75 @@
+use std::{collections::VecDeque, sync::Mutex};
+
+use bevy::{
+    log::Level,
+    prelude::{App, First, Plugin, ResMut, Resource},
+    utils::tracing::{Event, Subscriber},
+};
+use lazy_static::lazy_static;
+use tracing_subscriber::{layer::Context, Layer};
+
+use crate::crash_report::format_log_event;
+
+const LOG_VIEWER_MAX_LINES: usize = 5000;
+
+lazy_static! {
+    static ref LOG_VIEWER_PENDING_LINES: Mutex<Vec<LogViewerLine>> = Mutex::new(Vec::new());
+}
+
+#[derive(Clone)]
+pub struct LogViewerLine {
+    pub level: Level,
+    pub text: String,
+}
+
+/// The most recent log lines, shown in the in-game log viewer.
+#[derive(Default, Resource)]
+pub struct LogViewer {
+    pub lines: VecDeque<LogViewerLine>,
+}
+
+/// Collects log lines to be moved into the LogViewer resource at the start of each frame.
+pub struct LogViewerLayer;
+
+impl<S> Layer<S> for LogViewerLayer
+where
+    S: Subscriber,
+{
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let line = LogViewerLine {
+            level: *event.metadata().level(),
+            text: format_log_event(event),
+        };
+
+        // Use try_lock so logging from within the panic hook cannot deadlock
+        if let Ok(mut pending_lines) = LOG_VIEWER_PENDING_LINES.try_lock() {
+            if pending_lines.len() < LOG_VIEWER_MAX_LINES {
+                pending_lines.push(line);
+            }
+        }
+    }
+}
+
+fn log_viewer_system(mut log_viewer: ResMut<LogViewer>) {
+    let pending_lines = LOG_VIEWER_PENDING_LINES
+        .lock()
+        .map(|mut pending_lines| std::mem::take(&mut *pending_lines))
+        .unwrap_or_default();
+    if pending_lines.is_empty() {
+        return;
+    }
+
+    log_viewer.lines.extend(pending_lines);
+    let num_excess_lines = log_viewer.lines.len().saturating_sub(LOG_VIEWER_MAX_LINES);
+    log_viewer.lines.drain(..num_excess_lines);
+}
+
+#[derive(Default)]
+pub struct LogViewerPlugin;
+
+impl Plugin for LogViewerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<LogViewer>()
+            .add_systems(First, log_viewer_system);
+    }
+}
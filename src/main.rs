@@ -1,9 +1,13 @@
-use std::path::Path;
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
 use rose_data::ZoneId;
 use rose_offline_client::{
-    load_config, run_game, run_model_viewer, run_zone_viewer, Config, FilesystemDeviceConfig,
-    SystemsConfig,
+    get_default_config_path, load_config, run_game, run_headless_smoke_test, run_model_viewer,
+    run_setup_wizard, run_ui_screenshots, run_zone_benchmark, run_zone_validation, run_zone_viewer,
+    Config, FilesystemDeviceConfig, SystemsConfig,
 };
 
 fn main() {
@@ -60,11 +64,85 @@ fn main() {
                 .long("model-viewer")
                 .help("Run model viewer"),
         )
+        .arg(
+            clap::Arg::new("headless")
+                .long("headless")
+                .help("Run without a window or GPU, loading --zone and exiting with a non-zero exit code on panic or missing assets"),
+        )
+        .arg(
+            clap::Arg::new("headless-frames")
+                .long("headless-frames")
+                .help("Number of frames to run after the zone has loaded in headless mode")
+                .takes_value(true)
+                .default_value("300"),
+        )
+        .arg(
+            clap::Arg::new("ui-screenshots")
+                .long("ui-screenshots")
+                .help("Save a screenshot of every UI dialog to the given directory and exit")
+                .takes_value(true),
+        )
+        .arg(
+            clap::Arg::new("validate-zone")
+                .long("validate-zone")
+                .help("Load --zone without a window, write a report of broken zone data and exit with a non-zero exit code if any issues were found"),
+        )
+        .arg(
+            clap::Arg::new("validate-zone-output")
+                .long("validate-zone-output")
+                .help("Path to write the --validate-zone report to")
+                .takes_value(true),
+        )
+        .arg(
+            clap::Arg::new("benchmark")
+                .long("benchmark")
+                .help("Load --zone, play a scripted camera path and write a report of the frame rate, GPU pass times and memory usage"),
+        )
+        .arg(
+            clap::Arg::new("benchmark-camera")
+                .long("benchmark-camera")
+                .help("Path of a camera ZMO for --benchmark to play, the camera orbits the zone when not set")
+                .takes_value(true),
+        )
+        .arg(
+            clap::Arg::new("benchmark-duration")
+                .long("benchmark-duration")
+                .help("Number of seconds the --benchmark camera orbits the zone for")
+                .takes_value(true)
+                .default_value("30"),
+        )
+        .arg(
+            clap::Arg::new("benchmark-output")
+                .long("benchmark-output")
+                .help("Path to write the --benchmark JSON report to")
+                .takes_value(true),
+        )
         .arg(
             clap::Arg::new("disable-vsync")
                 .long("disable-vsync")
                 .help("Disable v-sync to see accurate frame times"),
         )
+        .arg(
+            clap::Arg::new("gpu-timestamps")
+                .long("gpu-timestamps")
+                .help("Enable GPU timestamp queries to show render pass timings in the render statistics debug window"),
+        )
+        .arg(
+            clap::Arg::new("record-replay")
+                .long("record-replay")
+                .help("Record local input and server packets to a replay file"),
+        )
+        .arg(
+            clap::Arg::new("replay")
+                .long("replay")
+                .help("Play back a previously recorded replay file")
+                .takes_value(true),
+        )
+        .arg(
+            clap::Arg::new("replay-headless")
+                .long("replay-headless")
+                .help("Play back the replay without a window or GPU and exit when it finishes"),
+        )
         .arg(
             clap::Arg::new("ip")
                 .long("ip")
@@ -78,6 +156,12 @@ fn main() {
                 .takes_value(true)
                 .default_value("29000"),
         )
+        .arg(
+            clap::Arg::new("profile")
+                .long("profile")
+                .help("Name of the login profile to use from the config")
+                .takes_value(true),
+        )
         .arg(
             clap::Arg::new("username")
                 .long("username")
@@ -113,6 +197,23 @@ fn main() {
                 .long("auto-login")
                 .help("Automatically login to server"),
         )
+        .arg(
+            clap::Arg::new("start-zone")
+                .long("start-zone")
+                .help("If --auto-login is set, warp to the given zone after joining the game")
+                .takes_value(true),
+        )
+        .arg(
+            clap::Arg::new("start-position")
+                .long("start-position")
+                .help("If --auto-login is set, warp to the given x,y position after joining the game")
+                .takes_value(true),
+        )
+        .arg(
+            clap::Arg::new("skip-intro")
+                .long("skip-intro")
+                .help("If --auto-login is set, skip the title zone and camera animations"),
+        )
         .arg(
             clap::Arg::new("passthrough-terrain-textures")
                 .long("passthrough-terrain-textures")
@@ -146,51 +247,105 @@ fn main() {
         );
     let matches = command.get_matches();
 
-    let mut config = matches
+    let config_path = matches
         .value_of("config")
-        .map(Path::new)
-        .map_or_else(Config::default, load_config);
+        .map(PathBuf::from)
+        .or_else(get_default_config_path);
+    let mut config = match config_path.as_ref() {
+        Some(path) if matches.is_present("config") || path.exists() => load_config(path),
+        _ => Config::default(),
+    };
 
-    if let Some(ip) = matches.value_of("ip") {
-        config.server.ip = ip.into();
+    if let Some(profile) = matches.value_of("profile") {
+        if !config.profiles.contains_key(profile) {
+            println!("Unknown profile {}, using default settings", profile);
+        }
+        config.profile = Some(profile.into());
     }
 
-    if let Some(port) = matches.value_of("port").and_then(|s| s.parse::<u16>().ok()) {
-        config.server.port = port;
-    }
+    {
+        // Command line arguments override the selected profile
+        let profile = config.selected_profile_mut();
 
-    if let Some(username) = matches.value_of("username") {
-        config.account.username = username.into();
-    }
+        if let Some(ip) = matches.value_of("ip") {
+            profile.server.ip = ip.into();
+        }
+
+        if let Some(port) = matches.value_of("port").and_then(|s| s.parse::<u16>().ok()) {
+            profile.server.port = port;
+        }
+
+        if let Some(username) = matches.value_of("username") {
+            profile.username = username.into();
+        }
+
+        if let Some(password) = matches.value_of("password") {
+            profile.password = password.into();
+        }
+
+        if matches.is_present("auto-login") {
+            profile.auto_login.enabled = true;
+        }
+
+        if let Some(id) = matches
+            .value_of("server-id")
+            .and_then(|s| s.parse::<usize>().ok())
+        {
+            profile.auto_login.server_id = Some(id);
+        }
+
+        if let Some(id) = matches
+            .value_of("channel-id")
+            .and_then(|s| s.parse::<usize>().ok())
+        {
+            profile.auto_login.channel_id = Some(id);
+        }
 
-    if let Some(password) = matches.value_of("password") {
-        config.account.password = password.into();
+        if let Some(character_name) = matches.value_of("character-name") {
+            profile.auto_login.character_name = Some(character_name.into());
+        }
+
+        if let Some(zone_id) = matches
+            .value_of("start-zone")
+            .and_then(|s| s.parse::<u16>().ok())
+        {
+            profile.auto_login.start_zone = Some(zone_id);
+        }
+
+        if let Some(start_position) = matches.value_of("start-position") {
+            if let Some((Ok(x), Ok(y))) = start_position
+                .split_once(',')
+                .map(|(x, y)| (x.trim().parse::<f32>(), y.trim().parse::<f32>()))
+            {
+                profile.auto_login.start_position = Some([x, y]);
+            } else {
+                println!("Invalid --start-position {}, expected x,y", start_position);
+            }
+        }
+
+        if matches.is_present("skip-intro") {
+            profile.auto_login.skip_intro = true;
+        }
     }
 
-    if matches.is_present("auto-login") {
-        config.auto_login.enabled = true;
+    if matches.is_present("disable-vsync") || matches.is_present("benchmark") {
+        config.graphics.disable_vsync = true;
     }
 
-    if let Some(id) = matches
-        .value_of("server-id")
-        .and_then(|s| s.parse::<usize>().ok())
-    {
-        config.auto_login.server_id = Some(id);
+    if matches.is_present("gpu-timestamps") || matches.is_present("benchmark") {
+        config.graphics.gpu_timestamps = true;
     }
 
-    if let Some(id) = matches
-        .value_of("channel-id")
-        .and_then(|s| s.parse::<usize>().ok())
-    {
-        config.auto_login.channel_id = Some(id);
+    if matches.is_present("record-replay") {
+        config.replay.record = true;
     }
 
-    if let Some(character_name) = matches.value_of("character-name") {
-        config.auto_login.character_name = Some(character_name.into());
+    if let Some(replay_path) = matches.value_of("replay") {
+        config.replay.playback_path = Some(replay_path.into());
     }
 
-    if matches.is_present("disable-vsync") {
-        config.graphics.disable_vsync = true;
+    if matches.is_present("replay-headless") {
+        config.replay.headless = true;
     }
 
     if matches.is_present("passthrough-terrain-textures") {
@@ -255,16 +410,67 @@ fn main() {
             .push(FilesystemDeviceConfig::Vfs("data.idx".into()));
     }
 
-    if matches.is_present("model-viewer") {
+    if config.filesystem.devices.is_empty()
+        && !matches.is_present("headless")
+        && !matches.is_present("validate-zone")
+    {
+        let Some(config_path) = config_path else {
+            println!(
+                "No game data found, use --data-idx or --data-path to set the game data location"
+            );
+            return;
+        };
+
+        if !run_setup_wizard(config_path) {
+            return;
+        }
+
+        // The window event loop can only be created once, so restart with the new config
+        let exit_code = std::env::current_exe()
+            .and_then(|exe| {
+                std::process::Command::new(exe)
+                    .args(std::env::args_os().skip(1))
+                    .status()
+            })
+            .map_or(1, |status| status.code().unwrap_or(1));
+        std::process::exit(exit_code);
+    }
+
+    let zone_id = matches
+        .value_of("zone")
+        .and_then(|str| str.parse::<u16>().ok())
+        .and_then(ZoneId::new);
+
+    if matches.is_present("headless") {
+        let num_frames = matches
+            .value_of("headless-frames")
+            .and_then(|str| str.parse::<u32>().ok())
+            .unwrap_or(300);
+        std::process::exit(run_headless_smoke_test(&config, zone_id, num_frames));
+    } else if matches.is_present("validate-zone") {
+        std::process::exit(run_zone_validation(
+            &config,
+            zone_id,
+            matches.value_of("validate-zone-output").map(PathBuf::from),
+        ));
+    } else if matches.is_present("benchmark") {
+        let duration = matches
+            .value_of("benchmark-duration")
+            .and_then(|str| str.parse::<f32>().ok())
+            .unwrap_or(30.0);
+        std::process::exit(run_zone_benchmark(
+            &config,
+            zone_id,
+            matches.value_of("benchmark-camera").map(String::from),
+            Duration::from_secs_f32(duration.max(1.0)),
+            matches.value_of("benchmark-output").map(PathBuf::from),
+        ));
+    } else if let Some(output_dir) = matches.value_of("ui-screenshots") {
+        run_ui_screenshots(&config, output_dir.into());
+    } else if matches.is_present("model-viewer") {
         run_model_viewer(&config);
     } else if matches.is_present("zone-viewer") {
-        run_zone_viewer(
-            &config,
-            matches
-                .value_of("zone")
-                .and_then(|str| str.parse::<u16>().ok())
-                .and_then(ZoneId::new),
-        );
+        run_zone_viewer(&config, zone_id);
     } else {
         run_game(&config, SystemsConfig::default());
     }
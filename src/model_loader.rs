@@ -14,10 +14,10 @@ use enum_map::{enum_map, EnumMap};
 
 use rose_data::{
     CharacterMotionAction, CharacterMotionDatabase, EffectDatabase, ItemClass, ItemDatabase, NpcId,
-    VehicleMotionAction, VehiclePartIndex, VehicleType,
+    NpcMotionAction, VehicleMotionAction, VehiclePartIndex, VehicleType,
 };
 use rose_data::{EquipmentIndex, ItemType, NpcDatabase};
-use rose_file_readers::{ChrFile, VirtualFilesystem, ZmdFile, ZscFile};
+use rose_file_readers::{ChrFile, VfsPathBuf, VirtualFilesystem, ZmdFile, ZscFile};
 use rose_game_common::components::{
     CharacterGender, CharacterInfo, DroppedItem, Equipment, EquipmentItemDatabase,
 };
@@ -26,10 +26,13 @@ use crate::{
     animation::ZmoAsset,
     components::{
         CharacterModel, CharacterModelPart, CharacterModelPartIndex, DummyBoneOffset,
-        ItemDropModel, NpcModel, PersonalStoreModel, VehicleModel,
+        ItemDropModel, ItemGradeGlow, ItemGradeParticleEffect, NpcModel, PersonalStoreModel,
+        VehicleModel,
     },
     effect_loader::spawn_effect,
-    render::{EffectMeshMaterial, ObjectMaterial, ParticleMaterial, TrailEffect},
+    render::{
+        EffectMeshMaterial, ObjectMaterial, ObjectMaterialGlow, ParticleMaterial, TrailEffect,
+    },
     zms_asset_loader::ZmsMaterialNumFaces,
 };
 
@@ -45,6 +48,14 @@ const TRAIL_COLOURS: [Color; 9] = [
     Color::rgba(1.0, 0.5, 0.0, 1.0),
 ];
 
+const ITEM_GRADE_GLOW_MIN_GRADE: usize = 4;
+const ITEM_GRADE_PARTICLE_MIN_GRADE: usize = 9;
+const ITEM_GRADE_MAX_GRADE: usize = 9;
+const ITEM_GRADE_PARTICLE_EFFECT_PATH: &str = "3DDATA/EFFECT/_GRADE_GLOW_01.EFT";
+
+// The personal store booth models in LIST_FIELDITEM.ZSC start from this index
+const PERSONAL_STORE_MODEL_ID: usize = 260;
+
 #[derive(Resource)]
 pub struct ModelLoader {
     vfs: Arc<VirtualFilesystem>,
@@ -196,6 +207,54 @@ impl ModelLoader {
         }
     }
 
+    /// Returns a description of every model, skeleton or motion referenced by
+    /// the NPC which does not exist, used by zone validation.
+    pub fn get_npc_model_issues(&self, npc_id: NpcId) -> Vec<String> {
+        let mut issues = Vec::new();
+        let Some(npc_model_data) = self.npc_chr.npcs.get(&npc_id.get()) else {
+            issues.push("no model in LIST_NPC.CHR".to_string());
+            return issues;
+        };
+
+        match self
+            .npc_chr
+            .skeleton_files
+            .get(npc_model_data.skeleton_index as usize)
+        {
+            Some(path) => {
+                if self.vfs.open_file(path.as_str()).is_err() {
+                    issues.push(format!("missing skeleton {}", path));
+                }
+            }
+            None => issues.push(format!(
+                "skeleton index {} out of range",
+                npc_model_data.skeleton_index
+            )),
+        }
+
+        for model_id in npc_model_data.model_ids.iter() {
+            if *model_id as usize >= self.npc_zsc.objects.len() {
+                issues.push(format!("model {} out of range in PART_NPC.ZSC", model_id));
+            }
+        }
+
+        let action_motions: EnumMap<NpcMotionAction, Option<String>> = enum_map! {
+            action => self
+                .npc_database
+                .get_npc_action_motion(npc_id, action)
+                .map(|motion_data| motion_data.path.path().to_string_lossy().into_owned())
+        };
+        for (action, path) in action_motions.iter() {
+            if let Some(path) = path {
+                if self.vfs.open_file(path.as_str()).is_err() {
+                    issues.push(format!("missing {:?} motion {}", action, path));
+                }
+            }
+        }
+
+        issues
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn spawn_npc_model(
         &self,
@@ -251,6 +310,7 @@ impl ModelLoader {
                 dummy_bone_offset,
                 false,
                 &self.specular_image,
+                None,
             );
             model_parts.append(&mut parts);
         }
@@ -270,6 +330,7 @@ impl ModelLoader {
                         effect_path.into(),
                         false,
                         None,
+                        1.0,
                     ) {
                         commands.entity(*dummy_bone_entity).add_child(effect_entity);
                         model_parts.push(effect_entity);
@@ -292,6 +353,7 @@ impl ModelLoader {
                     dummy_bone_offset,
                     false,
                     &self.specular_image,
+                    None,
                 );
                 model_parts.append(&mut parts);
             }
@@ -309,6 +371,7 @@ impl ModelLoader {
                     dummy_bone_offset,
                     false,
                     &self.specular_image,
+                    None,
                 );
                 model_parts.append(&mut parts);
             }
@@ -354,18 +417,26 @@ impl ModelLoader {
             .id();
         commands.entity(model_entity).add_child(root_bone);
 
+        // Each store skin has its own booth model, use the default booth for unknown skins
+        let mut model_id = PERSONAL_STORE_MODEL_ID + skin;
+        if model_id >= self.field_item.objects.len() {
+            log::warn!("Unknown personal store skin {}, using default booth", skin);
+            model_id = PERSONAL_STORE_MODEL_ID;
+        }
+
         let model_parts = spawn_model(
             commands,
             asset_server,
             object_materials,
             root_bone,
             &self.field_item,
-            260 + skin,
+            model_id,
             None,
             None,
             0,
             false,
             &self.specular_image,
+            None,
         );
 
         PersonalStoreModel {
@@ -419,6 +490,7 @@ impl ModelLoader {
                     0,
                     false,
                     &self.specular_image,
+                    None,
                 ),
             },
             asset_server.load(&self.field_item_motion_path),
@@ -549,6 +621,7 @@ impl ModelLoader {
             effect_file.into(),
             false,
             None,
+            1.0,
         )?;
 
         commands
@@ -574,6 +647,43 @@ impl ModelLoader {
         Some(effect_entity)
     }
 
+    fn spawn_character_grade_effect(
+        &self,
+        commands: &mut Commands,
+        asset_server: &AssetServer,
+        particle_materials: &mut Assets<ParticleMaterial>,
+        effect_mesh_materials: &mut Assets<EffectMeshMaterial>,
+        model_parts: &[Entity],
+        grade_effect_intensity: f32,
+    ) -> Option<Entity> {
+        let parent_part_entity = model_parts.first()?;
+        let effect_entity = spawn_effect(
+            &self.vfs,
+            commands,
+            asset_server,
+            particle_materials,
+            effect_mesh_materials,
+            (&VfsPathBuf::new(ITEM_GRADE_PARTICLE_EFFECT_PATH)).into(),
+            false,
+            None,
+            1.0,
+        )?;
+
+        commands
+            .entity(*parent_part_entity)
+            .add_child(effect_entity);
+
+        // The effect is hidden rather than not spawned, so it can be shown again
+        // when the intensity setting is changed
+        let mut effect_commands = commands.entity(effect_entity);
+        effect_commands.insert(ItemGradeParticleEffect);
+        if grade_effect_intensity <= 0.0 {
+            effect_commands.insert(Visibility::Hidden);
+        }
+
+        Some(effect_entity)
+    }
+
     pub fn spawn_character_weapon_trail(
         &self,
         commands: &mut Commands,
@@ -669,6 +779,7 @@ impl ModelLoader {
         model_entity: Entity,
         character_info: &CharacterInfo,
         equipment: &Equipment,
+        grade_effect_intensity: f32,
     ) -> (CharacterModel, SkinnedMesh, DummyBoneOffset) {
         let skeleton = self.get_skeleton(character_info.gender);
         let dummy_bone_offset = skeleton.bones.len();
@@ -704,12 +815,13 @@ impl ModelLoader {
                         asset_server,
                         object_materials,
                         model_entity,
-                        model_id.id,
+                        model_id,
                         &skinned_mesh,
                         dummy_bone_offset,
                         equipment,
                         particle_materials,
                         effect_mesh_materials,
+                        grade_effect_intensity,
                     ),
                 );
             }
@@ -738,14 +850,17 @@ impl ModelLoader {
         asset_server: &AssetServer,
         object_materials: &mut Assets<ObjectMaterial>,
         model_entity: Entity,
-        model_id: usize,
+        model_part_index: CharacterModelPartIndex,
         skinned_mesh: &SkinnedMesh,
         dummy_bone_offset: usize,
         equipment: &Equipment,
         particle_materials: &mut Assets<ParticleMaterial>,
         effect_mesh_materials: &mut Assets<EffectMeshMaterial>,
+        grade_effect_intensity: f32,
     ) -> Vec<Entity> {
         let model_list = self.get_model_list(character_info.gender, model_part);
+        let model_id = model_part_index.id;
+        let grade_glow = get_item_grade_glow(model_part_index.grade);
 
         let mut model_parts = spawn_model(
             commands,
@@ -759,8 +874,37 @@ impl ModelLoader {
             dummy_bone_offset,
             matches!(model_part, CharacterModelPart::CharacterFace),
             &self.specular_image,
+            grade_glow
+                .as_ref()
+                .and_then(|grade_glow| grade_glow.get_material_glow(grade_effect_intensity)),
         );
 
+        if let Some(grade_glow) = grade_glow {
+            for &part_entity in model_parts.iter() {
+                commands.entity(part_entity).insert(ItemGradeGlow {
+                    colour: grade_glow.colour,
+                });
+            }
+
+            if model_part_index.grade >= ITEM_GRADE_PARTICLE_MIN_GRADE
+                && matches!(
+                    model_part,
+                    CharacterModelPart::Weapon | CharacterModelPart::SubWeapon
+                )
+            {
+                if let Some(grade_effect_entity) = self.spawn_character_grade_effect(
+                    commands,
+                    asset_server,
+                    particle_materials,
+                    effect_mesh_materials,
+                    &model_parts,
+                    grade_effect_intensity,
+                ) {
+                    model_parts.push(grade_effect_entity);
+                }
+            }
+        }
+
         if matches!(model_part, CharacterModelPart::Weapon) {
             let weapon_trail_entities = self.spawn_character_weapon_trail(
                 commands,
@@ -837,6 +981,7 @@ impl ModelLoader {
         character_model: &mut CharacterModel,
         dummy_bone_offset: &DummyBoneOffset,
         skinned_mesh: &SkinnedMesh,
+        grade_effect_intensity: f32,
     ) {
         let weapon_model_index = get_model_part_index(
             &self.item_database,
@@ -896,12 +1041,13 @@ impl ModelLoader {
                             asset_server,
                             object_materials,
                             model_entity,
-                            model_id.id,
+                            model_id,
                             skinned_mesh,
                             dummy_bone_offset.index,
                             equipment,
                             particle_materials,
                             effect_mesh_materials,
+                            grade_effect_intensity,
                         ),
                     );
                 } else {
@@ -970,6 +1116,7 @@ impl ModelLoader {
                         dummy_bone_offset,
                         false,
                         &self.specular_image,
+                        None,
                     ),
                 );
 
@@ -992,6 +1139,7 @@ impl ModelLoader {
                                     effect_path.into(),
                                     false,
                                     None,
+                                    1.0,
                                 ) {
                                     commands.entity(*dummy_bone_entity).add_child(effect_entity);
                                     model_parts[vehicle_part_index].1.push(effect_entity);
@@ -1070,7 +1218,7 @@ impl DefaultBoneId for CharacterModelPart {
         match *self {
             CharacterModelPart::CharacterFace => Some(4),
             CharacterModelPart::CharacterHair => Some(4),
-            CharacterModelPart::Head => Some(dummy_bone_offset + 6),
+            CharacterModelPart::Head => Some(dummy_bone_offset + DummyBoneOffset::CHARACTER_HEAD),
             CharacterModelPart::FaceItem => Some(dummy_bone_offset + 4),
             CharacterModelPart::Back => Some(dummy_bone_offset + 3),
             _ => None,
@@ -1190,6 +1338,7 @@ fn spawn_model(
     dummy_bone_offset: usize,
     load_clip_faces: bool,
     specular_image: &Handle<Image>,
+    glow: Option<ObjectMaterialGlow>,
 ) -> Vec<Entity> {
     let mut parts = Vec::new();
     let object = if let Some(object) = model_list.objects.get(model_id) {
@@ -1222,6 +1371,7 @@ fn spawn_model(
                 None
             },
             skinned: zsc_material.is_skin,
+            glow,
             ..Default::default()
         });
 
@@ -1277,6 +1427,21 @@ fn spawn_model(
     parts
 }
 
+fn get_item_grade_glow(grade: usize) -> Option<ItemGradeGlow> {
+    if grade < ITEM_GRADE_GLOW_MIN_GRADE {
+        return None;
+    }
+
+    // Glow shifts from a faint blue to a bright gold as the grade increases
+    let strength = (grade.min(ITEM_GRADE_MAX_GRADE) + 1 - ITEM_GRADE_GLOW_MIN_GRADE) as f32
+        / (ITEM_GRADE_MAX_GRADE + 1 - ITEM_GRADE_GLOW_MIN_GRADE) as f32;
+    let colour = Vec3::new(0.2, 0.4, 1.0).lerp(Vec3::new(1.0, 0.75, 0.25), strength);
+
+    Some(ItemGradeGlow {
+        colour: colour * strength * 0.5,
+    })
+}
+
 fn get_model_part_index(
     item_database: &ItemDatabase,
     character_info: &CharacterInfo,
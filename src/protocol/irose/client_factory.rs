@@ -0,0 +1,57 @@
+use crate::protocol::{
+    irose::{GameClient, LoginClient, WorldClient},
+    ProtocolClient, ProtocolClientArgs, ProtocolClientFactory,
+};
+
+pub struct IroseClientFactory;
+
+impl ProtocolClientFactory for IroseClientFactory {
+    fn create_login_client(
+        &self,
+        args: ProtocolClientArgs,
+    ) -> Box<dyn ProtocolClient + Send + Sync> {
+        Box::new(
+            LoginClient::new(
+                args.server_address,
+                args.client_message_rx,
+                args.server_message_tx,
+            )
+            .with_replay(args.replay_recorder, args.replay_playback_rx)
+            .with_packet_inspector(args.packet_inspector),
+        )
+    }
+
+    fn create_world_client(
+        &self,
+        args: ProtocolClientArgs,
+        packet_codec_seed: u32,
+    ) -> Box<dyn ProtocolClient + Send + Sync> {
+        Box::new(
+            WorldClient::new(
+                args.server_address,
+                packet_codec_seed,
+                args.client_message_rx,
+                args.server_message_tx,
+            )
+            .with_replay(args.replay_recorder, args.replay_playback_rx)
+            .with_packet_inspector(args.packet_inspector),
+        )
+    }
+
+    fn create_game_client(
+        &self,
+        args: ProtocolClientArgs,
+        packet_codec_seed: u32,
+    ) -> Box<dyn ProtocolClient + Send + Sync> {
+        Box::new(
+            GameClient::new(
+                args.server_address,
+                packet_codec_seed,
+                args.client_message_rx,
+                args.server_message_tx,
+            )
+            .with_replay(args.replay_recorder, args.replay_playback_rx)
+            .with_packet_inspector(args.packet_inspector),
+        )
+    }
+}
@@ -23,14 +23,14 @@ use rose_network_irose::{
         PacketClientChangeVehiclePart, PacketClientChat, PacketClientClanCommand,
         PacketClientConnectRequest, PacketClientCraftItem, PacketClientDropItemFromInventory,
         PacketClientEmote, PacketClientIncreaseBasicStat, PacketClientJoinZone,
-        PacketClientLevelUpSkill, PacketClientMove, PacketClientMoveCollision,
-        PacketClientMoveToggle, PacketClientMoveToggleType, PacketClientNpcStoreTransaction,
-        PacketClientPartyReply, PacketClientPartyRequest, PacketClientPartyUpdateRules,
-        PacketClientPersonalStoreBuyItem, PacketClientPersonalStoreListItems,
-        PacketClientPickupItemDrop, PacketClientQuestRequest, PacketClientQuestRequestType,
-        PacketClientRepairItemUsingItem, PacketClientRepairItemUsingNpc, PacketClientReviveRequest,
-        PacketClientSetHotbarSlot, PacketClientSetReviveZone, PacketClientUseItem,
-        PacketClientWarpGateRequest,
+        PacketClientLevelUpSkill, PacketClientLogoutRequest, PacketClientMove,
+        PacketClientMoveCollision, PacketClientMoveToggle, PacketClientMoveToggleType,
+        PacketClientNpcStoreTransaction, PacketClientPartyReply, PacketClientPartyRequest,
+        PacketClientPartyUpdateRules, PacketClientPersonalStoreBuyItem,
+        PacketClientPersonalStoreListItems, PacketClientPickupItemDrop, PacketClientQuestRequest,
+        PacketClientQuestRequestType, PacketClientRepairItemUsingItem,
+        PacketClientRepairItemUsingNpc, PacketClientReviveRequest, PacketClientSetHotbarSlot,
+        PacketClientSetReviveZone, PacketClientUseItem, PacketClientWarpGateRequest,
     },
     game_server_packets::{
         ConnectResult, PacketConnectionReply, PacketServerAdjustPosition, PacketServerAnnounceChat,
@@ -65,13 +65,21 @@ use rose_network_irose::{
     ClientPacketCodec, IROSE_112_TABLE,
 };
 
-use crate::protocol::{ProtocolClient, ProtocolClientError};
+use crate::{
+    protocol::{
+        ProtocolClient, ProtocolClientError, ProtocolConnection, ProtocolPacketInspector,
+        ProtocolReplay, ServerMessageSender,
+    },
+    resources::{PacketInspector, ReplayConnection, ReplayRecorder},
+};
 
 pub struct GameClient {
     server_address: SocketAddr,
     client_message_rx: tokio::sync::mpsc::UnboundedReceiver<ClientMessage>,
-    server_message_tx: crossbeam_channel::Sender<ServerMessage>,
+    server_message_tx: ServerMessageSender,
     packet_codec: Box<dyn PacketCodec + Send + Sync>,
+    replay: ProtocolReplay,
+    packet_inspector: ProtocolPacketInspector,
 }
 
 impl GameClient {
@@ -81,14 +89,36 @@ impl GameClient {
         client_message_rx: tokio::sync::mpsc::UnboundedReceiver<ClientMessage>,
         server_message_tx: crossbeam_channel::Sender<ServerMessage>,
     ) -> Self {
+        let packet_inspector = ProtocolPacketInspector::new(ReplayConnection::Game);
+
         Self {
             server_address,
             client_message_rx,
-            server_message_tx,
+            server_message_tx: ServerMessageSender::new(
+                server_message_tx,
+                packet_inspector.clone(),
+            ),
             packet_codec: Box::new(ClientPacketCodec::init(&IROSE_112_TABLE, packet_codec_seed)),
+            replay: ProtocolReplay::new(ReplayConnection::Game),
+            packet_inspector,
         }
     }
 
+    pub fn with_replay(
+        mut self,
+        recorder: Option<ReplayRecorder>,
+        playback_rx: Option<tokio::sync::mpsc::UnboundedReceiver<Packet>>,
+    ) -> Self {
+        self.replay.recorder = recorder;
+        self.replay.playback_rx = playback_rx;
+        self
+    }
+
+    pub fn with_packet_inspector(self, packet_inspector: PacketInspector) -> Self {
+        self.packet_inspector.set_packet_inspector(packet_inspector);
+        self
+    }
+
     async fn handle_packet(&self, packet: &Packet) -> Result<(), anyhow::Error> {
         match FromPrimitive::from_u16(packet.command) {
             Some(ServerPackets::ConnectReply) => {
@@ -1117,7 +1147,7 @@ impl GameClient {
 
     async fn handle_client_message(
         &self,
-        connection: &mut Connection<'_>,
+        connection: &mut ProtocolConnection<'_, '_>,
         message: ClientMessage,
     ) -> Result<(), anyhow::Error> {
         match message {
@@ -1481,6 +1511,11 @@ impl GameClient {
                     }))
                     .await?
             }
+            ClientMessage::LogoutRequest(_) => {
+                connection
+                    .write_packet(Packet::from(&PacketClientLogoutRequest {}))
+                    .await?
+            }
             ClientMessage::SetReviveSaveZone => {
                 connection
                     .write_packet(Packet::from(&PacketClientSetReviveZone))
@@ -22,13 +22,21 @@ use rose_network_irose::{
     ClientPacketCodec, IROSE_112_TABLE,
 };
 
-use crate::protocol::{ProtocolClient, ProtocolClientError};
+use crate::{
+    protocol::{
+        ProtocolClient, ProtocolClientError, ProtocolConnection, ProtocolPacketInspector,
+        ProtocolReplay, ServerMessageSender,
+    },
+    resources::{PacketInspector, ReplayConnection, ReplayRecorder},
+};
 
 pub struct LoginClient {
     server_address: SocketAddr,
     client_message_rx: tokio::sync::mpsc::UnboundedReceiver<ClientMessage>,
-    server_message_tx: crossbeam_channel::Sender<ServerMessage>,
+    server_message_tx: ServerMessageSender,
     packet_codec: Box<dyn PacketCodec + Send + Sync>,
+    replay: ProtocolReplay,
+    packet_inspector: ProtocolPacketInspector,
 }
 
 impl LoginClient {
@@ -37,14 +45,36 @@ impl LoginClient {
         client_message_rx: tokio::sync::mpsc::UnboundedReceiver<ClientMessage>,
         server_message_tx: crossbeam_channel::Sender<ServerMessage>,
     ) -> Self {
+        let packet_inspector = ProtocolPacketInspector::new(ReplayConnection::Login);
+
         Self {
             server_address,
             client_message_rx,
-            server_message_tx,
+            server_message_tx: ServerMessageSender::new(
+                server_message_tx,
+                packet_inspector.clone(),
+            ),
             packet_codec: Box::new(ClientPacketCodec::default(&IROSE_112_TABLE)),
+            replay: ProtocolReplay::new(ReplayConnection::Login),
+            packet_inspector,
         }
     }
 
+    pub fn with_replay(
+        mut self,
+        recorder: Option<ReplayRecorder>,
+        playback_rx: Option<tokio::sync::mpsc::UnboundedReceiver<Packet>>,
+    ) -> Self {
+        self.replay.recorder = recorder;
+        self.replay.playback_rx = playback_rx;
+        self
+    }
+
+    pub fn with_packet_inspector(self, packet_inspector: PacketInspector) -> Self {
+        self.packet_inspector.set_packet_inspector(packet_inspector);
+        self
+    }
+
     async fn handle_packet(&self, packet: &Packet) -> Result<(), anyhow::Error> {
         match FromPrimitive::from_u16(packet.command) {
             Some(ServerPackets::NetworkStatus) => {
@@ -129,7 +159,7 @@ impl LoginClient {
 
     async fn handle_client_message(
         &self,
-        connection: &mut Connection<'_>,
+        connection: &mut ProtocolConnection<'_, '_>,
         message: ClientMessage,
     ) -> Result<(), anyhow::Error> {
         match message {
@@ -1,7 +1,9 @@
+mod client_factory;
 mod game_client;
 mod login_client;
 mod world_client;
 
+pub use client_factory::IroseClientFactory;
 pub use game_client::GameClient;
 pub use login_client::LoginClient;
 pub use world_client::WorldClient;
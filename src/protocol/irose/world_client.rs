@@ -24,13 +24,21 @@ use rose_network_irose::{
     ClientPacketCodec, IROSE_112_TABLE,
 };
 
-use crate::protocol::{ProtocolClient, ProtocolClientError};
+use crate::{
+    protocol::{
+        ProtocolClient, ProtocolClientError, ProtocolConnection, ProtocolPacketInspector,
+        ProtocolReplay, ServerMessageSender,
+    },
+    resources::{PacketInspector, ReplayConnection, ReplayRecorder},
+};
 
 pub struct WorldClient {
     server_address: SocketAddr,
     client_message_rx: tokio::sync::mpsc::UnboundedReceiver<ClientMessage>,
-    server_message_tx: crossbeam_channel::Sender<ServerMessage>,
+    server_message_tx: ServerMessageSender,
     packet_codec: Box<dyn PacketCodec + Send + Sync>,
+    replay: ProtocolReplay,
+    packet_inspector: ProtocolPacketInspector,
 }
 
 impl WorldClient {
@@ -40,14 +48,36 @@ impl WorldClient {
         client_message_rx: tokio::sync::mpsc::UnboundedReceiver<ClientMessage>,
         server_message_tx: crossbeam_channel::Sender<ServerMessage>,
     ) -> Self {
+        let packet_inspector = ProtocolPacketInspector::new(ReplayConnection::World);
+
         Self {
             server_address,
             client_message_rx,
-            server_message_tx,
+            server_message_tx: ServerMessageSender::new(
+                server_message_tx,
+                packet_inspector.clone(),
+            ),
             packet_codec: Box::new(ClientPacketCodec::init(&IROSE_112_TABLE, packet_codec_seed)),
+            replay: ProtocolReplay::new(ReplayConnection::World),
+            packet_inspector,
         }
     }
 
+    pub fn with_replay(
+        mut self,
+        recorder: Option<ReplayRecorder>,
+        playback_rx: Option<tokio::sync::mpsc::UnboundedReceiver<Packet>>,
+    ) -> Self {
+        self.replay.recorder = recorder;
+        self.replay.playback_rx = playback_rx;
+        self
+    }
+
+    pub fn with_packet_inspector(self, packet_inspector: PacketInspector) -> Self {
+        self.packet_inspector.set_packet_inspector(packet_inspector);
+        self
+    }
+
     async fn handle_packet(&self, packet: &Packet) -> Result<(), anyhow::Error> {
         match FromPrimitive::from_u16(packet.command) {
             Some(ServerPackets::ConnectReply) => {
@@ -129,7 +159,7 @@ impl WorldClient {
 
     async fn handle_client_message(
         &self,
-        connection: &mut Connection<'_>,
+        connection: &mut ProtocolConnection<'_, '_>,
         message: ClientMessage,
     ) -> Result<(), anyhow::Error> {
         match message {
@@ -1,23 +1,251 @@
+use std::{
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+
 use async_trait::async_trait;
+use rose_game_common::messages::{client::ClientMessage, server::ServerMessage};
+use rose_network_common::{Connection, Packet};
 use thiserror::Error;
 
+use crate::resources::{
+    PacketInspector, PacketInspectorDirection, PacketInspectorEntry, ReplayConnection,
+    ReplayRecorder,
+};
+
 #[derive(Debug, Error)]
 pub enum ProtocolClientError {
     #[error("client initiated disconnect")]
     ClientInitiatedDisconnect,
 }
 
+/// Records the packets received by a protocol client, or replaces the server
+/// connection with the packets from a replay.
+pub struct ProtocolReplay {
+    pub connection: ReplayConnection,
+    pub recorder: Option<ReplayRecorder>,
+    pub playback_rx: Option<tokio::sync::mpsc::UnboundedReceiver<Packet>>,
+}
+
+impl ProtocolReplay {
+    pub fn new(connection: ReplayConnection) -> Self {
+        Self {
+            connection,
+            recorder: None,
+            playback_rx: None,
+        }
+    }
+
+    pub fn record_packet(&self, packet: &Packet) {
+        if let Some(recorder) = self.recorder.as_ref() {
+            recorder.record_packet(self.connection, packet);
+        }
+    }
+}
+
+#[derive(Default)]
+struct ProtocolPacketInspectorState {
+    packet_inspector: Option<PacketInspector>,
+    current_entry: Option<PacketInspectorEntry>,
+}
+
+/// Collects the packets and decoded messages of a protocol client into entries
+/// for the packet inspector window.
+#[derive(Clone)]
+pub struct ProtocolPacketInspector {
+    connection: ReplayConnection,
+    state: Arc<Mutex<ProtocolPacketInspectorState>>,
+}
+
+fn get_message_name(fields: &str) -> String {
+    fields
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+impl ProtocolPacketInspector {
+    pub fn new(connection: ReplayConnection) -> Self {
+        Self {
+            connection,
+            state: Default::default(),
+        }
+    }
+
+    pub fn set_packet_inspector(&self, packet_inspector: PacketInspector) {
+        self.state.lock().unwrap().packet_inspector = Some(packet_inspector);
+    }
+
+    fn begin_entry(&self, direction: PacketInspectorDirection) {
+        let mut state = self.state.lock().unwrap();
+        state.current_entry = state
+            .packet_inspector
+            .as_ref()
+            .filter(|packet_inspector| packet_inspector.is_enabled())
+            .map(|packet_inspector| packet_inspector.create_entry(self.connection, direction));
+    }
+
+    pub fn begin_incoming(&self, packet: &Packet) {
+        self.begin_entry(PacketInspectorDirection::Incoming);
+        self.record_packet(packet);
+    }
+
+    pub fn begin_outgoing(&self, message: &ClientMessage) {
+        self.begin_entry(PacketInspectorDirection::Outgoing);
+
+        // Avoid showing passwords in the packet inspector
+        let fields = format!("{:#?}", message);
+        match message {
+            ClientMessage::LoginRequest { .. } | ClientMessage::ConnectionRequest { .. } => {
+                self.record_message(format!("{} {{ .. }}", get_message_name(&fields)))
+            }
+            _ => self.record_message(fields),
+        }
+    }
+
+    pub fn finish(&self) {
+        let mut state = self.state.lock().unwrap();
+        if let Some(entry) = state.current_entry.take() {
+            if let Some(packet_inspector) = state.packet_inspector.as_ref() {
+                packet_inspector.push_entry(entry);
+            }
+        }
+    }
+
+    fn record_packet(&self, packet: &Packet) {
+        if let Some(entry) = self.state.lock().unwrap().current_entry.as_mut() {
+            entry.commands.push(packet.command);
+            entry.size += packet.data.len();
+        }
+    }
+
+    fn record_message(&self, fields: String) {
+        if let Some(entry) = self.state.lock().unwrap().current_entry.as_mut() {
+            entry.names.push(get_message_name(&fields));
+            entry.fields.push(fields);
+        }
+    }
+}
+
+/// The connection of a protocol client while it handles a client message, every packet
+/// written is recorded in the packet inspector.
+pub struct ProtocolConnection<'a, 'c> {
+    connection: &'a mut Connection<'c>,
+    packet_inspector: &'a ProtocolPacketInspector,
+}
+
+impl<'a, 'c> ProtocolConnection<'a, 'c> {
+    pub fn new(
+        connection: &'a mut Connection<'c>,
+        packet_inspector: &'a ProtocolPacketInspector,
+    ) -> Self {
+        Self {
+            connection,
+            packet_inspector,
+        }
+    }
+
+    pub async fn write_packet(&mut self, packet: Packet) -> Result<(), anyhow::Error> {
+        self.packet_inspector.record_packet(&packet);
+        self.connection.write_packet(packet).await?;
+        Ok(())
+    }
+}
+
+/// Sends decoded server messages to the game, recording them in the packet inspector.
+pub struct ServerMessageSender {
+    server_message_tx: crossbeam_channel::Sender<ServerMessage>,
+    packet_inspector: ProtocolPacketInspector,
+}
+
+impl ServerMessageSender {
+    pub fn new(
+        server_message_tx: crossbeam_channel::Sender<ServerMessage>,
+        packet_inspector: ProtocolPacketInspector,
+    ) -> Self {
+        Self {
+            server_message_tx,
+            packet_inspector,
+        }
+    }
+
+    pub fn send(
+        &self,
+        message: ServerMessage,
+    ) -> Result<(), crossbeam_channel::SendError<ServerMessage>> {
+        self.packet_inspector
+            .record_message(format!("{:#?}", message));
+        self.server_message_tx.send(message)
+    }
+}
+
 #[async_trait]
 pub trait ProtocolClient {
     async fn run_connection(&mut self) -> Result<(), anyhow::Error>;
 }
 
+/// The connection a protocol client is created for, shared by all protocols.
+pub struct ProtocolClientArgs {
+    pub server_address: SocketAddr,
+    pub client_message_rx: tokio::sync::mpsc::UnboundedReceiver<ClientMessage>,
+    pub server_message_tx: crossbeam_channel::Sender<ServerMessage>,
+    pub replay_recorder: Option<ReplayRecorder>,
+    pub replay_playback_rx: Option<tokio::sync::mpsc::UnboundedReceiver<Packet>>,
+    pub packet_inspector: PacketInspector,
+}
+
+/// Creates the protocol clients for a network version, registered with
+/// [`ProtocolRegistry`](crate::resources::ProtocolRegistry).
+pub trait ProtocolClientFactory {
+    fn create_login_client(
+        &self,
+        args: ProtocolClientArgs,
+    ) -> Box<dyn ProtocolClient + Send + Sync>;
+
+    fn create_world_client(
+        &self,
+        args: ProtocolClientArgs,
+        packet_codec_seed: u32,
+    ) -> Box<dyn ProtocolClient + Send + Sync>;
+
+    fn create_game_client(
+        &self,
+        args: ProtocolClientArgs,
+        packet_codec_seed: u32,
+    ) -> Box<dyn ProtocolClient + Send + Sync>;
+}
+
 #[macro_export]
 macro_rules! implement_protocol_client {
     ( $x:ident ) => {
         #[async_trait]
         impl ProtocolClient for $x {
             async fn run_connection(&mut self) -> Result<(), anyhow::Error> {
+                if let Some(mut playback_rx) = self.replay.playback_rx.take() {
+                    loop {
+                        tokio::select! {
+                            packet = playback_rx.recv() => {
+                                match packet {
+                                    Some(packet) => {
+                                        self.packet_inspector.begin_incoming(&packet);
+                                        let result = self.handle_packet(&packet).await;
+                                        self.packet_inspector.finish();
+                                        result?;
+                                    }
+                                    None => return Ok(()),
+                                }
+                            },
+                            client_message = self.client_message_rx.recv() => {
+                                // Client messages are not sent anywhere during replay playback
+                                if client_message.is_none() {
+                                    return Err(ProtocolClientError::ClientInitiatedDisconnect.into());
+                                }
+                            }
+                        };
+                    }
+                }
+
                 let socket = TcpStream::connect(&self.server_address).await?;
                 let mut connection = Connection::new(socket, self.packet_codec.as_ref());
 
@@ -26,7 +254,11 @@ macro_rules! implement_protocol_client {
                         packet = connection.read_packet() => {
                             match packet {
                                 Ok(packet) => {
-                                    match self.handle_packet(&packet).await {
+                                    self.replay.record_packet(&packet);
+                                    self.packet_inspector.begin_incoming(&packet);
+                                    let result = self.handle_packet(&packet).await;
+                                    self.packet_inspector.finish();
+                                    match result {
                                         Ok(_) => {},
                                         Err(error) => {
                                             log::warn!("Error {} handling packet [{:03X}] {:02x?}", error, packet.command, &packet.data[..]);
@@ -41,7 +273,11 @@ macro_rules! implement_protocol_client {
                         },
                         server_message = self.client_message_rx.recv() => {
                             if let Some(message) = server_message {
-                                self.handle_client_message(&mut connection, message).await?;
+                                self.packet_inspector.begin_outgoing(&message);
+                                let mut connection = ProtocolConnection::new(&mut connection, &self.packet_inspector);
+                                let result = self.handle_client_message(&mut connection, message).await;
+                                self.packet_inspector.finish();
+                                result?;
                             } else {
                                 return Err(ProtocolClientError::ClientInitiatedDisconnect.into());
                             }
@@ -29,7 +29,10 @@ use bevy::{
 use bytemuck::Pod;
 use std::{collections::HashMap, num::NonZeroU64, ops::Range};
 
-use crate::render::{DamageDigitMaterial, DamageDigitRenderData};
+use crate::render::{
+    render_statistics::{RenderStatisticsCounters, RenderStatisticsPass, WriteGpuTimestamp},
+    DamageDigitMaterial, DamageDigitRenderData,
+};
 
 pub const DAMAGE_DIGIT_SHADER_HANDLE: HandleUntyped =
     HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 39699708885);
@@ -45,16 +48,17 @@ impl Plugin for DamageDigitRenderPlugin {
             Shader::from_wgsl
         );
 
-        let render_app = app.sub_app_mut(RenderApp);
-        render_app
-            .add_systems(ExtractSchedule, extract_damage_digits)
-            .add_systems(Render, prepare_damage_digits.in_set(RenderSet::Prepare))
-            .add_systems(Render, queue_damage_digits.in_set(RenderSet::Queue))
-            .init_resource::<DamageDigitMeta>()
-            .init_resource::<ExtractedDamageDigits>()
-            .init_resource::<MaterialBindGroups>()
-            .init_resource::<SpecializedRenderPipelines<DamageDigitPipeline>>()
-            .add_render_command::<Transparent3d, DrawDamageDigit>();
+        if let Ok(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app
+                .add_systems(ExtractSchedule, extract_damage_digits)
+                .add_systems(Render, prepare_damage_digits.in_set(RenderSet::Prepare))
+                .add_systems(Render, queue_damage_digits.in_set(RenderSet::Queue))
+                .init_resource::<DamageDigitMeta>()
+                .init_resource::<ExtractedDamageDigits>()
+                .init_resource::<MaterialBindGroups>()
+                .init_resource::<SpecializedRenderPipelines<DamageDigitPipeline>>()
+                .add_render_command::<Transparent3d, DrawDamageDigit>();
+        }
     }
 
     fn finish(&self, app: &mut App) {
@@ -128,6 +132,17 @@ impl FromWorld for DamageDigitPipeline {
                     },
                     count: None,
                 },
+                // Colors
+                BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: ShaderStages::VERTEX,
+                    ty: BindingType::Buffer {
+                        ty: BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: Some(Vec4::min_size()),
+                    },
+                    count: None,
+                },
             ],
         });
 
@@ -265,6 +280,7 @@ struct ExtractedDamageDigitRenderData {
     positions: Vec<Vec4>,
     sizes: Vec<Vec2>,
     uvs: Vec<Vec4>,
+    colors: Vec<Vec4>,
 }
 
 #[derive(Default, Component, Resource)]
@@ -304,6 +320,7 @@ fn extract_damage_digits(
                     positions: particles.positions.clone(),
                     sizes: particles.sizes.clone(),
                     uvs: particles.uvs.clone(),
+                    colors: particles.colors.clone(),
                 });
         }
     }
@@ -319,6 +336,7 @@ struct DamageDigitMeta {
     positions: BufferVec<Vec4>,
     sizes: BufferVec<Vec2>,
     uvs: BufferVec<Vec4>,
+    colors: BufferVec<Vec4>,
 }
 
 impl Default for DamageDigitMeta {
@@ -332,6 +350,7 @@ impl Default for DamageDigitMeta {
             positions: BufferVec::new(BufferUsages::STORAGE),
             sizes: BufferVec::new(BufferUsages::STORAGE),
             uvs: BufferVec::new(BufferUsages::STORAGE),
+            colors: BufferVec::new(BufferUsages::STORAGE),
         }
     }
 }
@@ -346,6 +365,7 @@ fn prepare_damage_digits(
     particle_meta.positions.clear();
     particle_meta.sizes.clear();
     particle_meta.uvs.clear();
+    particle_meta.colors.clear();
 
     let mut total_count = 0;
     for particle in extracted_damage_digits.particles.iter() {
@@ -361,6 +381,7 @@ fn prepare_damage_digits(
     particle_meta.positions.reserve(total_count, &render_device);
     particle_meta.sizes.reserve(total_count, &render_device);
     particle_meta.uvs.reserve(total_count, &render_device);
+    particle_meta.colors.reserve(total_count, &render_device);
 
     extracted_damage_digits
         .particles
@@ -389,6 +410,7 @@ fn prepare_damage_digits(
         batch_copy(&particle.positions, &mut particle_meta.positions);
         batch_copy(&particle.sizes, &mut particle_meta.sizes);
         batch_copy(&particle.uvs, &mut particle_meta.uvs);
+        batch_copy(&particle.colors, &mut particle_meta.colors);
         end += particle.positions.len() as u32;
     }
 
@@ -410,6 +432,9 @@ fn prepare_damage_digits(
     particle_meta
         .uvs
         .write_buffer(&render_device, &render_queue);
+    particle_meta
+        .colors
+        .write_buffer(&render_device, &render_queue);
 }
 
 fn batch_copy<T: Pod>(src: &[T], dst: &mut BufferVec<T>) {
@@ -488,6 +513,10 @@ fn queue_damage_digits(
                     binding: 2,
                     resource: bind_buffer(&damage_digit_meta.uvs, damage_digit_meta.total_count),
                 },
+                BindGroupEntry {
+                    binding: 3,
+                    resource: bind_buffer(&damage_digit_meta.colors, damage_digit_meta.total_count),
+                },
             ],
             label: Some("damage_digit_bind_group"),
             layout: &damage_digit_pipeline.particle_layout,
@@ -541,6 +570,7 @@ type DrawDamageDigit = (
     SetDamageDigitViewBindGroup<0>,
     SetDamageDigitBindGroup<1>,
     SetDamageDigitMaterialBindGroup<2>,
+    WriteGpuTimestamp<{ RenderStatisticsPass::DamageDigits as usize }>,
     DrawDamageDigitBatch,
 );
 
@@ -624,7 +654,7 @@ impl<P: PhaseItem, const I: usize> RenderCommand<P> for SetDamageDigitMaterialBi
 
 struct DrawDamageDigitBatch;
 impl<P: PhaseItem> RenderCommand<P> for DrawDamageDigitBatch {
-    type Param = ();
+    type Param = SRes<RenderStatisticsCounters>;
     type ViewWorldQuery = ();
     type ItemWorldQuery = Read<DamageDigitBatch>;
 
@@ -633,10 +663,15 @@ impl<P: PhaseItem> RenderCommand<P> for DrawDamageDigitBatch {
         _: &P,
         _: ROQueryItem<'w, Self::ViewWorldQuery>,
         batch: ROQueryItem<'w, Self::ItemWorldQuery>,
-        _: SystemParamItem<'w, '_, Self::Param>,
+        render_statistics_counters: SystemParamItem<'w, '_, Self::Param>,
         pass: &mut TrackedRenderPass<'w>,
     ) -> RenderCommandResult {
         let vertex_range = (batch.range.start * 6)..(batch.range.end * 6);
+        render_statistics_counters.record_draw_vertices(
+            RenderStatisticsPass::DamageDigits,
+            PrimitiveTopology::TriangleList,
+            vertex_range.len() as u32,
+        );
         pass.draw(vertex_range, 0..1);
         RenderCommandResult::Success
     }
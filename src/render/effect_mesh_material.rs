@@ -32,7 +32,10 @@ use bevy::{
     },
 };
 
-use crate::render::zone_lighting::{SetZoneLightingBindGroup, ZoneLightingUniformMeta};
+use crate::render::{
+    render_statistics::{CountMeshDrawCall, RenderStatisticsPass, WriteGpuTimestamp},
+    zone_lighting::{SetZoneLightingBindGroup, ZoneLightingUniformMeta},
+};
 
 pub const EFFECT_MESH_MATERIAL_SHADER_HANDLE: HandleUntyped =
     HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 0x90d5233c3001d33e);
@@ -74,6 +77,7 @@ bitflags::bitflags! {
     pub struct EffectMeshMaterialFlags: u32 {
         const ALPHA_MODE_OPAQUE         = (1 << 0);
         const ALPHA_MODE_MASK           = (1 << 1);
+        const BLEND_ADDITIVE            = (1 << 2);
         const NONE                      = 0;
     }
 }
@@ -112,6 +116,7 @@ impl ExtractComponent for EffectMeshAnimationRenderState {
 pub struct EffectMeshMaterialUniformData {
     pub flags: u32,
     pub alpha_cutoff: f32,
+    pub intensity: f32,
 }
 
 #[derive(AsBindGroup, Debug, Clone, TypeUuid, TypePath)]
@@ -135,6 +140,9 @@ pub struct EffectMeshMaterial {
     pub blend_op: BlendOperation,
     pub src_blend_factor: BlendFactor,
     pub dst_blend_factor: BlendFactor,
+
+    /// Scales the brightness of the effect, used to tone down flashing effects.
+    pub intensity: f32,
 }
 
 impl AsBindGroupShaderType<EffectMeshMaterialUniformData> for EffectMeshMaterial {
@@ -149,9 +157,14 @@ impl AsBindGroupShaderType<EffectMeshMaterialUniformData> for EffectMeshMaterial
             flags |= EffectMeshMaterialFlags::ALPHA_MODE_OPAQUE;
         }
 
+        if self.dst_blend_factor == BlendFactor::One {
+            flags |= EffectMeshMaterialFlags::BLEND_ADDITIVE;
+        }
+
         EffectMeshMaterialUniformData {
             flags: flags.bits(),
             alpha_cutoff: 0.5,
+            intensity: self.intensity,
         }
     }
 }
@@ -373,5 +386,7 @@ type DrawEffectMeshMaterial = (
     SetMaterialBindGroup<EffectMeshMaterial, 1>,
     SetMeshBindGroup<2>,
     SetZoneLightingBindGroup<3>,
+    WriteGpuTimestamp<{ RenderStatisticsPass::EffectMeshes as usize }>,
+    CountMeshDrawCall<{ RenderStatisticsPass::EffectMeshes as usize }>,
     DrawEffectMesh,
 );
@@ -11,9 +11,11 @@ mod object_material;
 mod particle_material;
 mod particle_pipeline;
 mod particle_render_data;
+mod render_statistics;
 mod sky_material;
 mod terrain_material;
 mod trail_effect;
+mod ui_screenshot;
 mod water_material;
 mod world_ui;
 mod zone_lighting;
@@ -37,13 +39,20 @@ pub use object_material::{
 };
 pub use particle_material::ParticleMaterial;
 pub use particle_render_data::{ParticleRenderBillboardType, ParticleRenderData};
+pub use render_statistics::{
+    GpuTimingCategory, RenderPassStatistics, RenderStatistics, RenderStatisticsFrame,
+    RenderStatisticsPass,
+};
 pub use sky_material::SkyMaterial;
 pub use terrain_material::{
     TerrainMaterial, TERRAIN_MATERIAL_MAX_TEXTURES, TERRAIN_MESH_ATTRIBUTE_TILE_INFO,
 };
 pub use trail_effect::TrailEffect;
-pub use water_material::WaterMaterial;
-pub use world_ui::WorldUiRect;
+pub use ui_screenshot::{
+    UiScreenshotCapture, UiScreenshotMesh, UiScreenshotRequest, UI_SCREENSHOT_GRAPH,
+};
+pub use water_material::{WaterMaterial, WaterReflectionProjection, WATER_RENDER_LAYER};
+pub use world_ui::{WorldUiGroup, WorldUiRect};
 pub use zone_lighting::ZoneLighting;
 
 use damage_digit_material::DamageDigitMaterialPlugin;
@@ -52,9 +61,11 @@ use effect_mesh_material::EffectMeshMaterialPlugin;
 use object_material::ObjectMaterialPlugin;
 use particle_material::ParticleMaterialPlugin;
 use particle_pipeline::ParticleRenderPlugin;
+use render_statistics::RenderStatisticsPlugin;
 use sky_material::SkyMaterialPlugin;
 use terrain_material::TerrainMaterialPlugin;
 use trail_effect::TrailEffectRenderPlugin;
+use ui_screenshot::UiScreenshotPlugin;
 use water_material::WaterMaterialPlugin;
 use world_ui::WorldUiRenderPlugin;
 use zone_lighting::ZoneLightingPlugin;
@@ -68,6 +79,7 @@ impl Plugin for RoseRenderPlugin {
 
         app.add_plugins((
             ZoneLightingPlugin,
+            RenderStatisticsPlugin,
             TerrainMaterialPlugin { prepass_enabled },
             EffectMeshMaterialPlugin { prepass_enabled },
             ObjectMaterialPlugin { prepass_enabled },
@@ -79,6 +91,7 @@ impl Plugin for RoseRenderPlugin {
             SkyMaterialPlugin { prepass_enabled },
             TrailEffectRenderPlugin,
             WorldUiRenderPlugin,
+            UiScreenshotPlugin,
         ));
     }
 }
@@ -37,6 +37,7 @@ use bevy::{
 use rose_file_readers::{ZscMaterialBlend, ZscMaterialGlow};
 
 use crate::render::{
+    render_statistics::{CountMeshDrawCall, RenderStatisticsPass, WriteGpuTimestamp},
     zone_lighting::{SetZoneLightingBindGroup, ZoneLightingUniformMeta},
     MESH_ATTRIBUTE_UV_1,
 };
@@ -147,6 +148,8 @@ type DrawObjectMaterial = (
     SetMaterialBindGroup<ObjectMaterial, 1>,
     SetMeshBindGroup<2>,
     SetZoneLightingBindGroup<3>,
+    WriteGpuTimestamp<{ RenderStatisticsPass::Objects as usize }>,
+    CountMeshDrawCall<{ RenderStatisticsPass::Objects as usize }>,
     DrawObjectMesh,
 );
 
@@ -160,6 +163,8 @@ bitflags::bitflags! {
         const ALPHA_MODE_BLEND           = (1 << 2);
         const HAS_ALPHA_VALUE            = (1 << 3);
         const SPECULAR                   = (1 << 4);
+        const GLOW                       = (1 << 5);
+        const GLOW_TEXTURE               = (1 << 6);
         const NONE                       = 0;
     }
 }
@@ -171,6 +176,7 @@ pub struct ObjectMaterialUniformData {
     pub alpha_value: f32,
     pub lightmap_uv_offset: Vec2,
     pub lightmap_uv_scale: f32,
+    pub glow_colour: Vec3,
 }
 
 impl From<&ObjectMaterial> for ObjectMaterialUniformData {
@@ -204,12 +210,26 @@ impl From<&ObjectMaterial> for ObjectMaterialUniformData {
             }
         }
 
+        let glow_colour = match material.glow {
+            Some(ObjectMaterialGlow::Simple(colour)) | Some(ObjectMaterialGlow::Light(colour)) => {
+                flags |= ObjectMaterialFlags::GLOW;
+                colour
+            }
+            Some(ObjectMaterialGlow::Texture(colour))
+            | Some(ObjectMaterialGlow::TextureLight(colour)) => {
+                flags |= ObjectMaterialFlags::GLOW | ObjectMaterialFlags::GLOW_TEXTURE;
+                colour
+            }
+            Some(ObjectMaterialGlow::Alpha(_)) | None => Vec3::ZERO,
+        };
+
         ObjectMaterialUniformData {
             flags: flags.bits(),
             alpha_cutoff,
             alpha_value,
             lightmap_uv_offset: material.lightmap_uv_offset,
             lightmap_uv_scale: material.lightmap_uv_scale,
+            glow_colour,
         }
     }
 }
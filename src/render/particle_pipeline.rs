@@ -32,7 +32,9 @@ use num_traits::FromPrimitive;
 use std::{collections::HashMap, num::NonZeroU64, ops::Range};
 
 use crate::render::{
-    particle_render_data::ParticleRenderBillboardType, particle_render_data::ParticleRenderData,
+    particle_render_data::ParticleRenderBillboardType,
+    particle_render_data::ParticleRenderData,
+    render_statistics::{RenderStatisticsCounters, RenderStatisticsPass, WriteGpuTimestamp},
     ParticleMaterial,
 };
 
@@ -55,16 +57,17 @@ impl Plugin for ParticleRenderPlugin {
             compute_particles_aabb.in_set(VisibilitySystems::CalculateBounds),
         );
 
-        let render_app = app.sub_app_mut(RenderApp);
-        render_app
-            .add_systems(ExtractSchedule, extract_particles)
-            .add_systems(Render, prepare_particles.in_set(RenderSet::Prepare))
-            .add_systems(Render, queue_particles.in_set(RenderSet::Queue))
-            .init_resource::<ParticleMeta>()
-            .init_resource::<ExtractedParticles>()
-            .init_resource::<MaterialBindGroups>()
-            .init_resource::<SpecializedRenderPipelines<ParticlePipeline>>()
-            .add_render_command::<Transparent3d, DrawParticle>();
+        if let Ok(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app
+                .add_systems(ExtractSchedule, extract_particles)
+                .add_systems(Render, prepare_particles.in_set(RenderSet::Prepare))
+                .add_systems(Render, queue_particles.in_set(RenderSet::Queue))
+                .init_resource::<ParticleMeta>()
+                .init_resource::<ExtractedParticles>()
+                .init_resource::<MaterialBindGroups>()
+                .init_resource::<SpecializedRenderPipelines<ParticlePipeline>>()
+                .add_render_command::<Transparent3d, DrawParticle>();
+        }
     }
 
     fn finish(&self, app: &mut App) {
@@ -707,6 +710,7 @@ type DrawParticle = (
     SetParticleViewBindGroup<0>,
     SetParticleBindGroup<1>,
     SetParticleMaterialBindGroup<2>,
+    WriteGpuTimestamp<{ RenderStatisticsPass::Particles as usize }>,
     DrawParticleBatch,
 );
 
@@ -786,7 +790,7 @@ impl<P: PhaseItem, const I: usize> RenderCommand<P> for SetParticleMaterialBindG
 
 struct DrawParticleBatch;
 impl<P: PhaseItem> RenderCommand<P> for DrawParticleBatch {
-    type Param = ();
+    type Param = SRes<RenderStatisticsCounters>;
     type ViewWorldQuery = ();
     type ItemWorldQuery = Read<ParticleBatch>;
 
@@ -795,10 +799,15 @@ impl<P: PhaseItem> RenderCommand<P> for DrawParticleBatch {
         _: &P,
         _: ROQueryItem<'w, Self::ViewWorldQuery>,
         batch: ROQueryItem<'w, Self::ItemWorldQuery>,
-        _: SystemParamItem<'w, '_, Self::Param>,
+        render_statistics_counters: SystemParamItem<'w, '_, Self::Param>,
         pass: &mut TrackedRenderPass<'w>,
     ) -> RenderCommandResult {
         let vertex_range = (batch.range.start * 6)..(batch.range.end * 6);
+        render_statistics_counters.record_draw_vertices(
+            RenderStatisticsPass::Particles,
+            PrimitiveTopology::TriangleList,
+            vertex_range.len() as u32,
+        );
         pass.draw(vertex_range, 0..1);
         RenderCommandResult::Success
     }
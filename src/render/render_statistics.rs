@@ -0,0 +1,639 @@
+use std::sync::{
+    atomic::{AtomicU32, AtomicU64, AtomicU8, Ordering},
+    Arc, Mutex,
+};
+
+use bevy::{
+    core_pipeline::core_3d::{self, Opaque3d},
+    ecs::{
+        query::ROQueryItem,
+        system::{
+            lifetimeless::{Read, SRes},
+            SystemParamItem,
+        },
+    },
+    prelude::{
+        App, Entity, FromWorld, Handle, IntoSystemConfigs, Local, Mesh, Plugin, Query, Res, ResMut,
+        Resource, With, World,
+    },
+    render::{
+        main_graph,
+        mesh::{GpuBufferInfo, GpuMesh},
+        render_asset::RenderAssets,
+        render_graph::{
+            Node, NodeId, NodeRunError, RenderGraph, RenderGraphApp, RenderGraphContext,
+        },
+        render_phase::{
+            PhaseItem, RenderCommand, RenderCommandResult, RenderPhase, TrackedRenderPass,
+        },
+        render_resource::{
+            Buffer, BufferDescriptor, BufferUsages, CommandEncoder, MapMode, PrimitiveTopology,
+            QuerySet, QuerySetDescriptor, QueryType, WgpuFeatures,
+        },
+        renderer::{RenderContext, RenderDevice, RenderQueue},
+        Render, RenderApp, RenderSet,
+    },
+    utils::{HashMap, HashSet},
+};
+use enum_map::{Enum, EnumMap};
+
+const GPU_TIMESTAMP_COUNT: u32 = 256;
+const GPU_TIMESTAMP_BUFFER_SIZE: u64 = GPU_TIMESTAMP_COUNT as u64 * 8;
+
+const GPU_TIMESTAMP_NODE_VIEW_BEGIN: &str = "rose_gpu_timestamp_view_begin";
+const GPU_TIMESTAMP_NODE_VIEW_MAIN_PASS_END: &str = "rose_gpu_timestamp_view_main_pass_end";
+const GPU_TIMESTAMP_NODE_VIEW_END: &str = "rose_gpu_timestamp_view_end";
+const GPU_TIMESTAMP_NODE_EGUI_BEGIN: &str = "rose_gpu_timestamp_egui_begin";
+const GPU_TIMESTAMP_NODE_EGUI_END: &str = "rose_gpu_timestamp_egui_end";
+
+const TIMESTAMP_BEGIN: u8 = 0;
+const TIMESTAMP_MARK: u8 = 1;
+const TIMESTAMP_END: u8 = 2;
+
+const READBACK_IDLE: u8 = 0;
+const READBACK_RECORDING: u8 = 1;
+const READBACK_COPY_QUEUED: u8 = 2;
+const READBACK_MAPPING: u8 = 3;
+const READBACK_MAPPED: u8 = 4;
+
+/// The draw calls issued by RoseRenderPlugin, grouped by what is being drawn.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Enum)]
+pub enum RenderStatisticsPass {
+    Terrain,
+    Objects,
+    Water,
+    Sky,
+    EffectMeshes,
+    Particles,
+    TrailEffects,
+    DamageDigits,
+    WorldUi,
+}
+
+impl RenderStatisticsPass {
+    pub fn gpu_timing_category(self) -> GpuTimingCategory {
+        match self {
+            RenderStatisticsPass::Terrain => GpuTimingCategory::Terrain,
+            RenderStatisticsPass::Objects
+            | RenderStatisticsPass::Water
+            | RenderStatisticsPass::Sky => GpuTimingCategory::Objects,
+            RenderStatisticsPass::EffectMeshes
+            | RenderStatisticsPass::Particles
+            | RenderStatisticsPass::TrailEffects => GpuTimingCategory::Particles,
+            RenderStatisticsPass::DamageDigits | RenderStatisticsPass::WorldUi => {
+                GpuTimingCategory::Ui
+            }
+        }
+    }
+}
+
+/// A GPU timestamp is written whenever a draw of a different category starts, the time
+/// until the next timestamp is attributed to that category. Ui includes the egui pass.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Enum)]
+pub enum GpuTimingCategory {
+    Terrain,
+    Objects,
+    Particles,
+    Ui,
+    /// Draws by bevy's own pipelines and post processing
+    Other,
+}
+
+#[derive(Copy, Clone, Default)]
+pub struct RenderPassStatistics {
+    pub draw_calls: u32,
+    pub triangles: u64,
+}
+
+#[derive(Clone, Default)]
+pub struct RenderStatisticsFrame {
+    pub passes: EnumMap<RenderStatisticsPass, RenderPassStatistics>,
+    pub gpu_timestamps_supported: bool,
+    /// The most recent GPU timings of each camera
+    pub gpu_camera_timings_ms: HashMap<Entity, EnumMap<GpuTimingCategory, f64>>,
+    /// The most recent GPU timing of the egui pass of every window
+    pub gpu_egui_timing_ms: Option<f64>,
+}
+
+impl RenderStatisticsFrame {
+    /// The GPU timings of all cameras and the egui pass combined.
+    pub fn gpu_timings_ms(&self) -> Option<EnumMap<GpuTimingCategory, f64>> {
+        if self.gpu_camera_timings_ms.is_empty() && self.gpu_egui_timing_ms.is_none() {
+            return None;
+        }
+
+        let mut gpu_timings_ms = EnumMap::default();
+        for camera_timings_ms in self.gpu_camera_timings_ms.values() {
+            for (category, time_ms) in camera_timings_ms.iter() {
+                gpu_timings_ms[category] += time_ms;
+            }
+        }
+        gpu_timings_ms[GpuTimingCategory::Ui] += self.gpu_egui_timing_ms.unwrap_or(0.0);
+        Some(gpu_timings_ms)
+    }
+}
+
+/// Shared between the main world and the render world, the render world publishes the
+/// statistics of each frame it renders.
+#[derive(Clone, Default, Resource)]
+pub struct RenderStatistics {
+    frame: Arc<Mutex<RenderStatisticsFrame>>,
+}
+
+impl RenderStatistics {
+    pub fn get(&self) -> RenderStatisticsFrame {
+        self.frame
+            .lock()
+            .map(|frame| frame.clone())
+            .unwrap_or_default()
+    }
+}
+
+#[derive(Default, Resource)]
+pub struct RenderStatisticsCounters {
+    draw_calls: EnumMap<RenderStatisticsPass, AtomicU32>,
+    triangles: EnumMap<RenderStatisticsPass, AtomicU64>,
+}
+
+impl RenderStatisticsCounters {
+    pub fn record_draw(&self, pass: RenderStatisticsPass, triangles: u64) {
+        self.draw_calls[pass].fetch_add(1, Ordering::Relaxed);
+        self.triangles[pass].fetch_add(triangles, Ordering::Relaxed);
+    }
+
+    pub fn record_draw_vertices(
+        &self,
+        pass: RenderStatisticsPass,
+        topology: PrimitiveTopology,
+        num_vertices: u32,
+    ) {
+        let triangles = match topology {
+            PrimitiveTopology::TriangleList => num_vertices / 3,
+            PrimitiveTopology::TriangleStrip => num_vertices.saturating_sub(2),
+            _ => 0,
+        };
+        self.record_draw(pass, triangles as u64);
+    }
+
+    fn take(&self) -> EnumMap<RenderStatisticsPass, RenderPassStatistics> {
+        EnumMap::from_fn(|pass| RenderPassStatistics {
+            draw_calls: self.draw_calls[pass].swap(0, Ordering::Relaxed),
+            triangles: self.triangles[pass].swap(0, Ordering::Relaxed),
+        })
+    }
+}
+
+fn get_mesh_num_vertices(gpu_mesh: &GpuMesh) -> u32 {
+    match &gpu_mesh.buffer_info {
+        GpuBufferInfo::Indexed { count, .. } => *count,
+        GpuBufferInfo::NonIndexed => gpu_mesh.vertex_count,
+    }
+}
+
+/// Counts the draw call and triangles of the item mesh, must be placed before the
+/// command which draws the mesh.
+pub struct CountMeshDrawCall<const PASS: usize>;
+impl<P: PhaseItem, const PASS: usize> RenderCommand<P> for CountMeshDrawCall<PASS> {
+    type Param = (SRes<RenderStatisticsCounters>, SRes<RenderAssets<Mesh>>);
+    type ViewWorldQuery = ();
+    type ItemWorldQuery = Read<Handle<Mesh>>;
+
+    #[inline]
+    fn render<'w>(
+        _: &P,
+        _: ROQueryItem<'w, Self::ViewWorldQuery>,
+        mesh_handle: ROQueryItem<'w, Self::ItemWorldQuery>,
+        (counters, meshes): SystemParamItem<'w, '_, Self::Param>,
+        _: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        if let Some(gpu_mesh) = meshes.into_inner().get(mesh_handle) {
+            counters.record_draw_vertices(
+                RenderStatisticsPass::from_usize(PASS),
+                gpu_mesh.primitive_topology,
+                get_mesh_num_vertices(gpu_mesh),
+            );
+        }
+        RenderCommandResult::Success
+    }
+}
+
+/// The timestamps written during one frame with the category of the time from each
+/// timestamp to the next. Nothing is recorded until the previous timestamps are read back.
+struct GpuTimestamps {
+    query_set: QuerySet,
+    resolve_buffer: Buffer,
+    readback_buffer: Buffer,
+    readback_state: Arc<AtomicU8>,
+    categories: Mutex<Vec<GpuTimingCategory>>,
+}
+
+impl GpuTimestamps {
+    fn new(render_device: &RenderDevice) -> Self {
+        Self {
+            query_set: render_device
+                .wgpu_device()
+                .create_query_set(&QuerySetDescriptor {
+                    label: Some("gpu_timestamp_query_set"),
+                    ty: QueryType::Timestamp,
+                    count: GPU_TIMESTAMP_COUNT,
+                }),
+            resolve_buffer: render_device.create_buffer(&BufferDescriptor {
+                label: Some("gpu_timestamp_resolve_buffer"),
+                size: GPU_TIMESTAMP_BUFFER_SIZE,
+                usage: BufferUsages::QUERY_RESOLVE | BufferUsages::COPY_SRC,
+                mapped_at_creation: false,
+            }),
+            readback_buffer: render_device.create_buffer(&BufferDescriptor {
+                label: Some("gpu_timestamp_readback_buffer"),
+                size: GPU_TIMESTAMP_BUFFER_SIZE,
+                usage: BufferUsages::MAP_READ | BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            }),
+            readback_state: Arc::new(AtomicU8::new(READBACK_IDLE)),
+            categories: Mutex::new(Vec::with_capacity(GPU_TIMESTAMP_COUNT as usize)),
+        }
+    }
+
+    /// Starts recording, returns the query index for the first timestamp.
+    fn begin(&self, category: GpuTimingCategory) -> Option<u32> {
+        self.readback_state
+            .compare_exchange(
+                READBACK_IDLE,
+                READBACK_RECORDING,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            )
+            .ok()?;
+
+        let mut categories = self.categories.lock().ok()?;
+        categories.clear();
+        categories.push(category);
+        Some(0)
+    }
+
+    /// Returns the query index for the next timestamp when the category has changed, the
+    /// last query is kept for the end timestamp so later draws count towards the last category.
+    fn next(&self, category: GpuTimingCategory) -> Option<u32> {
+        if self.readback_state.load(Ordering::Acquire) != READBACK_RECORDING {
+            return None;
+        }
+
+        let mut categories = self.categories.lock().ok()?;
+        if categories.last() == Some(&category)
+            || categories.len() as u32 + 1 >= GPU_TIMESTAMP_COUNT
+        {
+            return None;
+        }
+
+        categories.push(category);
+        Some(categories.len() as u32 - 1)
+    }
+
+    /// Writes the end timestamp and copies the timestamps to the readback buffer.
+    fn end(&self, command_encoder: &mut CommandEncoder) {
+        if self.readback_state.load(Ordering::Acquire) != READBACK_RECORDING {
+            return;
+        }
+
+        let Ok(categories) = self.categories.lock() else {
+            return;
+        };
+        let num_timestamps = categories.len() as u32 + 1;
+
+        command_encoder.write_timestamp(&self.query_set, num_timestamps - 1);
+        command_encoder.resolve_query_set(
+            &self.query_set,
+            0..num_timestamps,
+            &self.resolve_buffer,
+            0,
+        );
+        command_encoder.copy_buffer_to_buffer(
+            &self.resolve_buffer,
+            0,
+            &self.readback_buffer,
+            0,
+            num_timestamps as u64 * 8,
+        );
+        self.readback_state
+            .store(READBACK_COPY_QUEUED, Ordering::Release);
+    }
+
+    fn write(
+        &self,
+        kind: u8,
+        begin_category: GpuTimingCategory,
+        command_encoder: &mut CommandEncoder,
+    ) {
+        match kind {
+            TIMESTAMP_BEGIN => {
+                if let Some(index) = self.begin(begin_category) {
+                    command_encoder.write_timestamp(&self.query_set, index);
+                }
+            }
+            TIMESTAMP_MARK => {
+                if let Some(index) = self.next(GpuTimingCategory::Other) {
+                    command_encoder.write_timestamp(&self.query_set, index);
+                }
+            }
+            _ => self.end(command_encoder),
+        }
+    }
+
+    fn read(&self, period_ms: f64) -> Option<EnumMap<GpuTimingCategory, f64>> {
+        match self.readback_state.load(Ordering::Acquire) {
+            READBACK_COPY_QUEUED => {
+                let readback_state = self.readback_state.clone();
+                readback_state.store(READBACK_MAPPING, Ordering::Release);
+                self.readback_buffer
+                    .slice(..)
+                    .map_async(MapMode::Read, move |result| {
+                        readback_state.store(
+                            if result.is_ok() {
+                                READBACK_MAPPED
+                            } else {
+                                READBACK_IDLE
+                            },
+                            Ordering::Release,
+                        );
+                    });
+                None
+            }
+            READBACK_MAPPED => {
+                let timestamps: Vec<u64> = {
+                    let data = self.readback_buffer.slice(..).get_mapped_range();
+                    bytemuck::cast_slice(&data).to_vec()
+                };
+                self.readback_buffer.unmap();
+
+                let mut timings_ms = EnumMap::default();
+                if let Ok(categories) = self.categories.lock() {
+                    for (index, category) in categories.iter().enumerate() {
+                        timings_ms[*category] +=
+                            timestamps[index + 1].saturating_sub(timestamps[index]) as f64
+                                * period_ms;
+                    }
+                }
+
+                self.readback_state.store(READBACK_IDLE, Ordering::Release);
+                Some(timings_ms)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Timestamps are recorded separately for each camera, and for the egui pass of all windows.
+#[derive(Resource)]
+pub struct GpuTimestampQueries {
+    supported: bool,
+    timestamp_period: f32,
+    views: HashMap<Entity, GpuTimestamps>,
+    egui: Option<GpuTimestamps>,
+}
+
+impl FromWorld for GpuTimestampQueries {
+    fn from_world(world: &mut World) -> Self {
+        let render_device = world.resource::<RenderDevice>();
+        let supported = render_device
+            .features()
+            .contains(WgpuFeatures::TIMESTAMP_QUERY | WgpuFeatures::WRITE_TIMESTAMP_INSIDE_PASSES);
+
+        Self {
+            supported,
+            timestamp_period: world.resource::<RenderQueue>().get_timestamp_period(),
+            views: HashMap::default(),
+            egui: supported.then(|| GpuTimestamps::new(render_device)),
+        }
+    }
+}
+
+/// Writes a GPU timestamp when the category of the draw differs from the previous draw of
+/// the view, must be placed before the command which draws.
+pub struct WriteGpuTimestamp<const PASS: usize>;
+impl<P: PhaseItem, const PASS: usize> RenderCommand<P> for WriteGpuTimestamp<PASS> {
+    type Param = SRes<GpuTimestampQueries>;
+    type ViewWorldQuery = Entity;
+    type ItemWorldQuery = ();
+
+    #[inline]
+    fn render<'w>(
+        _: &P,
+        view_entity: ROQueryItem<'w, Self::ViewWorldQuery>,
+        _: ROQueryItem<'w, Self::ItemWorldQuery>,
+        gpu_timestamps: SystemParamItem<'w, '_, Self::Param>,
+        pass: &mut TrackedRenderPass<'w>,
+    ) -> RenderCommandResult {
+        let Some(timestamps) = gpu_timestamps.into_inner().views.get(&view_entity) else {
+            return RenderCommandResult::Success;
+        };
+
+        let category = RenderStatisticsPass::from_usize(PASS).gpu_timing_category();
+        if let Some(index) = timestamps.next(category) {
+            pass.write_timestamp(&timestamps.query_set, index);
+        }
+        RenderCommandResult::Success
+    }
+}
+
+/// Writes the timestamps around the core 3d graph of a view, the time after the main pass
+/// is attributed to post processing as Other.
+#[derive(Default)]
+struct GpuTimestampViewNode<const KIND: u8>;
+
+impl<const KIND: u8> Node for GpuTimestampViewNode<KIND> {
+    fn run(
+        &self,
+        graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        let gpu_timestamps = world.resource::<GpuTimestampQueries>();
+        if let Some(timestamps) = gpu_timestamps.views.get(&graph.view_entity()) {
+            timestamps.write(
+                KIND,
+                GpuTimingCategory::Other,
+                render_context.command_encoder(),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes the timestamps before and after the egui pass of every window.
+#[derive(Default)]
+struct GpuTimestampEguiNode<const KIND: u8>;
+
+impl<const KIND: u8> Node for GpuTimestampEguiNode<KIND> {
+    fn run(
+        &self,
+        _graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        let gpu_timestamps = world.resource::<GpuTimestampQueries>();
+        if let Some(timestamps) = gpu_timestamps.egui.as_ref() {
+            timestamps.write(
+                KIND,
+                GpuTimingCategory::Ui,
+                render_context.command_encoder(),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+fn prepare_gpu_timestamps_system(
+    mut gpu_timestamps: ResMut<GpuTimestampQueries>,
+    render_device: Res<RenderDevice>,
+    query_views: Query<Entity, With<RenderPhase<Opaque3d>>>,
+) {
+    if !gpu_timestamps.supported {
+        return;
+    }
+
+    gpu_timestamps
+        .views
+        .retain(|entity, _| query_views.contains(*entity));
+
+    for entity in query_views.iter() {
+        gpu_timestamps
+            .views
+            .entry(entity)
+            .or_insert_with(|| GpuTimestamps::new(&render_device));
+    }
+}
+
+/// bevy_egui adds a render graph node for each window when it is created, the egui timestamp
+/// nodes are ordered around every one of them.
+fn egui_timestamp_edges_system(
+    mut render_graph: ResMut<RenderGraph>,
+    mut egui_nodes: Local<HashSet<NodeId>>,
+) {
+    let new_egui_nodes: Vec<NodeId> = render_graph
+        .iter_nodes()
+        .filter(|node| node.type_name.contains("EguiNode") && !egui_nodes.contains(&node.id))
+        .map(|node| node.id)
+        .collect();
+
+    for node_id in new_egui_nodes {
+        render_graph.add_node_edge(GPU_TIMESTAMP_NODE_EGUI_BEGIN, node_id);
+        render_graph.add_node_edge(node_id, GPU_TIMESTAMP_NODE_EGUI_END);
+        egui_nodes.insert(node_id);
+    }
+}
+
+fn publish_render_statistics_system(
+    counters: Res<RenderStatisticsCounters>,
+    gpu_timestamps: Res<GpuTimestampQueries>,
+    render_statistics: Res<RenderStatistics>,
+) {
+    let passes = counters.take();
+    let period_ms = gpu_timestamps.timestamp_period as f64 / 1_000_000.0;
+
+    let Ok(mut frame) = render_statistics.frame.lock() else {
+        return;
+    };
+
+    frame.passes = passes;
+    frame.gpu_timestamps_supported = gpu_timestamps.supported;
+    frame
+        .gpu_camera_timings_ms
+        .retain(|entity, _| gpu_timestamps.views.contains_key(entity));
+
+    for (entity, timestamps) in gpu_timestamps.views.iter() {
+        if let Some(timings_ms) = timestamps.read(period_ms) {
+            frame.gpu_camera_timings_ms.insert(*entity, timings_ms);
+        }
+    }
+
+    if let Some(timings_ms) = gpu_timestamps
+        .egui
+        .as_ref()
+        .and_then(|timestamps| timestamps.read(period_ms))
+    {
+        frame.gpu_egui_timing_ms = Some(timings_ms.values().sum());
+    }
+}
+
+#[derive(Default)]
+pub struct RenderStatisticsPlugin;
+
+impl Plugin for RenderStatisticsPlugin {
+    fn build(&self, app: &mut App) {
+        let render_statistics = RenderStatistics::default();
+        app.insert_resource(render_statistics.clone());
+
+        let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app
+            .insert_resource(render_statistics)
+            .init_resource::<RenderStatisticsCounters>()
+            .add_systems(
+                Render,
+                (
+                    (prepare_gpu_timestamps_system, egui_timestamp_edges_system)
+                        .in_set(RenderSet::Prepare),
+                    publish_render_statistics_system.in_set(RenderSet::Cleanup),
+                ),
+            )
+            .add_render_graph_node::<GpuTimestampViewNode<TIMESTAMP_BEGIN>>(
+                core_3d::graph::NAME,
+                GPU_TIMESTAMP_NODE_VIEW_BEGIN,
+            )
+            .add_render_graph_node::<GpuTimestampViewNode<TIMESTAMP_MARK>>(
+                core_3d::graph::NAME,
+                GPU_TIMESTAMP_NODE_VIEW_MAIN_PASS_END,
+            )
+            .add_render_graph_node::<GpuTimestampViewNode<TIMESTAMP_END>>(
+                core_3d::graph::NAME,
+                GPU_TIMESTAMP_NODE_VIEW_END,
+            )
+            .add_render_graph_edges(
+                core_3d::graph::NAME,
+                &[
+                    core_3d::graph::node::START_MAIN_PASS,
+                    GPU_TIMESTAMP_NODE_VIEW_BEGIN,
+                    core_3d::graph::node::MAIN_OPAQUE_PASS,
+                ],
+            )
+            .add_render_graph_edges(
+                core_3d::graph::NAME,
+                &[
+                    core_3d::graph::node::MAIN_TRANSPARENT_PASS,
+                    GPU_TIMESTAMP_NODE_VIEW_MAIN_PASS_END,
+                    core_3d::graph::node::END_MAIN_PASS,
+                ],
+            )
+            .add_render_graph_edges(
+                core_3d::graph::NAME,
+                &[core_3d::graph::node::UPSCALING, GPU_TIMESTAMP_NODE_VIEW_END],
+            );
+
+        let mut render_graph = render_app.world.resource_mut::<RenderGraph>();
+        render_graph.add_node(
+            GPU_TIMESTAMP_NODE_EGUI_BEGIN,
+            GpuTimestampEguiNode::<TIMESTAMP_BEGIN>,
+        );
+        render_graph.add_node(
+            GPU_TIMESTAMP_NODE_EGUI_END,
+            GpuTimestampEguiNode::<TIMESTAMP_END>,
+        );
+        render_graph.add_node_edge(
+            main_graph::node::CAMERA_DRIVER,
+            GPU_TIMESTAMP_NODE_EGUI_BEGIN,
+        );
+        render_graph.add_node_edge(GPU_TIMESTAMP_NODE_EGUI_BEGIN, GPU_TIMESTAMP_NODE_EGUI_END);
+    }
+
+    fn finish(&self, app: &mut App) {
+        let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app.init_resource::<GpuTimestampQueries>();
+    }
+}
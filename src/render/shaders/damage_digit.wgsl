@@ -6,6 +6,7 @@ var<uniform> view: View;
 struct PositionBuffer { data: array<vec4<f32>>, };
 struct SizeBuffer { data: array<vec2<f32>>, };
 struct UvBuffer { data: array<vec4<f32>>, };
+struct ColorBuffer { data: array<vec4<f32>>, };
 
 @group(1) @binding(0)
 var<storage, read> positions: PositionBuffer;
@@ -13,6 +14,8 @@ var<storage, read> positions: PositionBuffer;
 var<storage, read> sizes: SizeBuffer;
 @group(1) @binding(2)
 var<storage, read> uvs: UvBuffer;
+@group(1) @binding(3)
+var<storage, read> colors: ColorBuffer;
 @group(2) @binding(0)
 var base_color_texture: texture_2d<f32>;
 @group(2) @binding(1)
@@ -25,6 +28,7 @@ struct VertexInput {
 struct VertexOutput {
   @builtin(position) position: vec4<f32>,
   @location(0) uv: vec2<f32>,
+  @location(1) color: vec4<f32>,
 };
 
 @vertex
@@ -59,6 +63,7 @@ fn vs_main(model: VertexInput) -> VertexOutput {
 
   var out: VertexOutput;
   out.position = view.view_proj * vec4<f32>(world_space, 1.0);
+  out.color = colors.data[digit_idx];
 
   let texture = uvs.data[digit_idx];
   if (vertex_positions[vert_idx].x < 0.0) {
@@ -78,5 +83,5 @@ fn vs_main(model: VertexInput) -> VertexOutput {
 
 @fragment
 fn fs_main(in: VertexOutput) -> @location(0) vec4<f32> {
-  return textureSample(base_color_texture, base_color_sampler, in.uv);
+  return textureSample(base_color_texture, base_color_sampler, in.uv) * in.color;
 }
@@ -6,10 +6,12 @@
 struct EffectMeshMaterialData {
     flags: u32,
     alpha_cutoff: f32,
+    intensity: f32,
 };
 
 const EFFECT_MESH_MATERIAL_FLAGS_ALPHA_MODE_OPAQUE: u32 = 0x1u;
 const EFFECT_MESH_MATERIAL_FLAGS_ALPHA_MODE_MASK: u32   = 0x2u;
+const EFFECT_MESH_MATERIAL_FLAGS_BLEND_ADDITIVE: u32    = 0x4u;
 
 const EFECT_MESH_ANIMATION_STATE_FLAGS_POSITION: u32   = 0x1u;
 const EFECT_MESH_ANIMATION_STATE_FLAGS_NORMAL: u32     = 0x2u;
@@ -145,5 +147,12 @@ fn fragment(in: FragmentInput) -> @location(0) vec4<f32> {
         }
     }
 
+    if ((material.flags & EFFECT_MESH_MATERIAL_FLAGS_BLEND_ADDITIVE) != 0u) {
+        // NOTE: Additive blending brightens the scene, so reduce the colour which is added
+        output_color = vec4<f32>(output_color.rgb * material.intensity, output_color.a);
+    } else if ((material.flags & EFFECT_MESH_MATERIAL_FLAGS_ALPHA_MODE_OPAQUE) == 0u) {
+        output_color.a = output_color.a * material.intensity;
+    }
+
     return output_color;
 }
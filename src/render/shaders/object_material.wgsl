@@ -102,6 +102,7 @@ struct StaticMeshMaterialData {
     alpha_value: f32,
     lightmap_uv_offset: vec2<f32>,
     lightmap_uv_scale: f32,
+    glow_colour: vec3<f32>,
 };
 
 const OBJECT_MATERIAL_FLAGS_ALPHA_MODE_OPAQUE: u32              = 1u;
@@ -109,6 +110,8 @@ const OBJECT_MATERIAL_FLAGS_ALPHA_MODE_MASK: u32                = 2u;
 const OBJECT_MATERIAL_FLAGS_ALPHA_MODE_BLEND: u32               = 4u;
 const OBJECT_MATERIAL_FLAGS_HAS_ALPHA_VALUE: u32                = 8u;
 const OBJECT_MATERIAL_FLAGS_SPECULAR: u32                       = 16u;
+const OBJECT_MATERIAL_FLAGS_GLOW: u32                           = 32u;
+const OBJECT_MATERIAL_FLAGS_GLOW_TEXTURE: u32                   = 64u;
 
 struct FragmentInput {
     @builtin(position) frag_coord: vec4<f32>,
@@ -163,6 +166,12 @@ fn fragment(in: FragmentInput) -> @location(0) vec4<f32> {
         output_color = vec4<f32>(output_color.rgb + output_color.a * textureSample(specular_texture, specular_sampler, R.xy * 0.5 + vec2<f32>(0.5, 0.5)).rgb, output_color.a);
     }
 
+    if ((material.flags & OBJECT_MATERIAL_FLAGS_GLOW_TEXTURE) != 0u) {
+        output_color = vec4<f32>(output_color.rgb + output_color.rgb * material.glow_colour, output_color.a);
+    } else if ((material.flags & OBJECT_MATERIAL_FLAGS_GLOW) != 0u) {
+        output_color = vec4<f32>(output_color.rgb + material.glow_colour, output_color.a);
+    }
+
     if ((material.flags & OBJECT_MATERIAL_FLAGS_HAS_ALPHA_VALUE) != 0u) {
         output_color.a = material.alpha_value;
     } else if ((material.flags & OBJECT_MATERIAL_FLAGS_ALPHA_MODE_OPAQUE) != 0u) {
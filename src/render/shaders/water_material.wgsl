@@ -31,13 +31,22 @@ fn vertex(vertex: Vertex) -> VertexOutput {
 var water_array_texture: binding_array<texture_2d<f32>>;
 @group(1) @binding(1)
 var water_array_sampler: sampler;
+@group(1) @binding(2)
+var water_reflection_texture: texture_2d<f32>;
+@group(1) @binding(3)
+var water_reflection_sampler: sampler;
 
-struct WaterTextureIndex {
+struct WaterPushConstants {
+    reflection_view_proj: mat4x4<f32>,
     current_index: i32,
     next_index: i32,
     next_weight: f32,
+    reflection_strength: f32,
 };
-var<push_constant> water_texture_index: WaterTextureIndex;
+var<push_constant> water_push_constants: WaterPushConstants;
+
+// Offset the reflection by the water texture to make it ripple
+const WATER_REFLECTION_DISTORTION: f32 = 0.02;
 
 struct FragmentInput {
     @builtin(position) frag_coord: vec4<f32>,
@@ -55,8 +64,24 @@ fn fragment(in: FragmentInput) -> @location(0) vec4<f32> {
         view.inverse_view[3].z
     ), in.world_position);
 
-    let color1 = textureSample(water_array_texture[water_texture_index.current_index], water_array_sampler, in.uv0);
-    let color2 = textureSample(water_array_texture[water_texture_index.next_index], water_array_sampler, in.uv0);
-    let water_color = mix(color1, color2, water_texture_index.next_weight);
-    return apply_zone_lighting(in.world_position, in.world_normal, water_color, view_z);
+    let color1 = textureSample(water_array_texture[water_push_constants.current_index], water_array_sampler, in.uv0);
+    let color2 = textureSample(water_array_texture[water_push_constants.next_index], water_array_sampler, in.uv0);
+    let water_color = mix(color1, color2, water_push_constants.next_weight);
+    let lit_color = apply_zone_lighting(in.world_position, in.world_normal, water_color, view_z);
+
+    // Project the water position with the mirrored reflection camera to find what it reflects
+    let reflection_clip = water_push_constants.reflection_view_proj * in.world_position;
+    let reflection_ndc = reflection_clip.xy / reflection_clip.w;
+    let reflection_uv = reflection_ndc * vec2<f32>(0.5, -0.5) + vec2<f32>(0.5, 0.5)
+        + (water_color.rg - vec2<f32>(0.5, 0.5)) * WATER_REFLECTION_DISTORTION;
+    let reflection_color =
+        textureSample(water_reflection_texture, water_reflection_sampler, reflection_uv);
+
+    // Water reflects more when viewed at a glancing angle
+    let view_direction = normalize(view.world_position.xyz - in.world_position.xyz);
+    let cos_view_angle = max(dot(view_direction, normalize(in.world_normal)), 0.0);
+    let fresnel = mix(0.25, 1.0, pow(1.0 - cos_view_angle, 3.0));
+    let reflection_weight = water_push_constants.reflection_strength * fresnel;
+
+    return vec4<f32>(lit_color.rgb + reflection_color.rgb * reflection_weight, lit_color.a);
 }
@@ -24,7 +24,10 @@ use bevy::{
     },
 };
 
-use crate::resources::{ZoneTime, ZoneTimeState};
+use crate::{
+    render::render_statistics::{CountMeshDrawCall, RenderStatisticsPass, WriteGpuTimestamp},
+    resources::{ZoneTime, ZoneTimeState},
+};
 
 pub const SKY_MATERIAL_SHADER_HANDLE: HandleUntyped =
     HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 0xadc5cbbc7a53fe);
@@ -149,5 +152,7 @@ type DrawSkyMaterial = (
     SetMaterialBindGroup<SkyMaterial, 1>,
     SetMeshBindGroup<2>,
     SetZoneTimePushConstant<0>,
+    WriteGpuTimestamp<{ RenderStatisticsPass::Sky as usize }>,
+    CountMeshDrawCall<{ RenderStatisticsPass::Sky as usize }>,
     DrawMesh,
 );
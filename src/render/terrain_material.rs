@@ -29,6 +29,7 @@ use bevy::{
 };
 
 use crate::render::{
+    render_statistics::{CountMeshDrawCall, RenderStatisticsPass, WriteGpuTimestamp},
     zone_lighting::{SetZoneLightingBindGroup, ZoneLightingUniformMeta},
     MESH_ATTRIBUTE_UV_1,
 };
@@ -236,5 +237,7 @@ type DrawTerrainMaterial = (
     SetMaterialBindGroup<TerrainMaterial, 1>,
     SetMeshBindGroup<2>,
     SetZoneLightingBindGroup<3>,
+    WriteGpuTimestamp<{ RenderStatisticsPass::Terrain as usize }>,
+    CountMeshDrawCall<{ RenderStatisticsPass::Terrain as usize }>,
     DrawMesh,
 );
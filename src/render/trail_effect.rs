@@ -33,7 +33,12 @@ use bevy::{
 use bytemuck::{Pod, Zeroable};
 use std::{collections::HashMap, ops::Range};
 
-use crate::resources::RenderConfiguration;
+use crate::{
+    render::render_statistics::{
+        RenderStatisticsCounters, RenderStatisticsPass, WriteGpuTimestamp,
+    },
+    resources::RenderConfiguration,
+};
 
 pub const TRAIL_EFFECT_SHADER_HANDLE: HandleUntyped =
     HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 3042057527543835453);
@@ -52,16 +57,17 @@ impl Plugin for TrailEffectRenderPlugin {
         app.add_systems(Update, (initialise_trail_effects,));
         app.add_systems(PostUpdate, (update_trail_effects,));
 
-        let render_app = app.sub_app_mut(RenderApp);
-        render_app
-            .add_systems(ExtractSchedule, extract_trail_effects)
-            .add_systems(Render, prepare_trail_effects.in_set(RenderSet::Prepare))
-            .add_systems(Render, queue_trail_effects.in_set(RenderSet::Queue))
-            .init_resource::<TrailEffectMeta>()
-            .init_resource::<ExtractedTrailEffects>()
-            .init_resource::<MaterialBindGroups>()
-            .init_resource::<SpecializedRenderPipelines<TrailEffectPipeline>>()
-            .add_render_command::<Transparent3d, DrawTrailEffect>();
+        if let Ok(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app
+                .add_systems(ExtractSchedule, extract_trail_effects)
+                .add_systems(Render, prepare_trail_effects.in_set(RenderSet::Prepare))
+                .add_systems(Render, queue_trail_effects.in_set(RenderSet::Queue))
+                .init_resource::<TrailEffectMeta>()
+                .init_resource::<ExtractedTrailEffects>()
+                .init_resource::<MaterialBindGroups>()
+                .init_resource::<SpecializedRenderPipelines<TrailEffectPipeline>>()
+                .add_render_command::<Transparent3d, DrawTrailEffect>();
+        }
     }
 
     fn finish(&self, app: &mut App) {
@@ -740,6 +746,7 @@ type DrawTrailEffect = (
     SetItemPipeline,
     SetTrailEffectViewBindGroup<0>,
     SetTrailEffectMaterialBindGroup<1>,
+    WriteGpuTimestamp<{ RenderStatisticsPass::TrailEffects as usize }>,
     DrawTrailEffectBatch,
 );
 
@@ -797,7 +804,7 @@ impl<P: PhaseItem, const I: usize> RenderCommand<P> for SetTrailEffectMaterialBi
 
 struct DrawTrailEffectBatch;
 impl<P: PhaseItem> RenderCommand<P> for DrawTrailEffectBatch {
-    type Param = SRes<TrailEffectMeta>;
+    type Param = (SRes<TrailEffectMeta>, SRes<RenderStatisticsCounters>);
     type ViewWorldQuery = ();
     type ItemWorldQuery = Read<TrailEffectBatch>;
 
@@ -806,9 +813,14 @@ impl<P: PhaseItem> RenderCommand<P> for DrawTrailEffectBatch {
         _: &P,
         _: ROQueryItem<'w, Self::ViewWorldQuery>,
         batch: ROQueryItem<'w, Self::ItemWorldQuery>,
-        trail_effect_meta: SystemParamItem<'w, '_, Self::Param>,
+        (trail_effect_meta, render_statistics_counters): SystemParamItem<'w, '_, Self::Param>,
         pass: &mut TrackedRenderPass<'w>,
     ) -> RenderCommandResult {
+        render_statistics_counters.record_draw_vertices(
+            RenderStatisticsPass::TrailEffects,
+            PrimitiveTopology::TriangleStrip,
+            batch.vertex_range.len() as u32,
+        );
         pass.set_vertex_buffer(
             0,
             trail_effect_meta
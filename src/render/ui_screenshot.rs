@@ -0,0 +1,547 @@
+use std::sync::{
+    atomic::{AtomicU8, Ordering},
+    Arc, Mutex,
+};
+
+use bevy::{
+    asset::{load_internal_asset, Handle},
+    prelude::{
+        App, Color, Entity, FromWorld, HandleUntyped, Image, IntoSystemConfigs, Plugin, Res,
+        ResMut, Resource, UVec2, World,
+    },
+    reflect::TypeUuid,
+    render::{
+        prelude::Shader,
+        render_asset::RenderAssets,
+        render_graph::{Node, NodeRunError, RenderGraphApp, RenderGraphContext},
+        render_resource::{
+            BindGroup, BindGroupDescriptor, BindGroupEntry, BindGroupLayout,
+            BindGroupLayoutDescriptor, BindGroupLayoutEntry, BindingResource, BindingType,
+            BlendComponent, BlendFactor, BlendOperation, BlendState, Buffer, BufferDescriptor,
+            BufferInitDescriptor, BufferUsages, CachedRenderPipelineId, ColorTargetState,
+            ColorWrites, Extent3d, FragmentState, FrontFace, ImageCopyBuffer, ImageDataLayout,
+            IndexFormat, LoadOp, MapMode, MultisampleState, Operations, PipelineCache, PolygonMode,
+            PrimitiveState, PrimitiveTopology, RenderPassColorAttachment, RenderPassDescriptor,
+            RenderPipelineDescriptor, SamplerBindingType, ShaderStages, TextureFormat,
+            TextureSampleType, TextureViewDimension, VertexAttribute, VertexBufferLayout,
+            VertexFormat, VertexState, VertexStepMode,
+        },
+        renderer::{RenderContext, RenderDevice},
+        texture::BevyDefault,
+        view::ViewTarget,
+        Render, RenderApp, RenderSet,
+    },
+    utils::HashMap,
+};
+use bevy_egui::egui;
+use bytemuck::{Pod, Zeroable};
+
+pub const UI_SCREENSHOT_SHADER_HANDLE: HandleUntyped =
+    HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 0x4c1b7e2a9f03d865);
+
+/// The render graph of a camera which draws a UiScreenshotRequest into its image target.
+pub const UI_SCREENSHOT_GRAPH: &str = "rose_ui_screenshot";
+const UI_SCREENSHOT_NODE: &str = "rose_ui_screenshot_draw";
+
+// wgpu requires each row of a texture copy to a buffer to be aligned to this
+const COPY_BYTES_PER_ROW_ALIGNMENT: u32 = 256;
+
+const READBACK_PENDING: u8 = 0;
+const READBACK_COPY_QUEUED: u8 = 1;
+const READBACK_MAPPING: u8 = 2;
+const READBACK_MAPPED: u8 = 3;
+
+/// An egui mesh to draw with the image of its texture, positions are in pixels.
+pub struct UiScreenshotMesh {
+    pub image: Handle<Image>,
+    pub clip_rect: egui::Rect,
+    pub mesh: egui::epaint::Mesh,
+}
+
+/// Draws the meshes into target, which must be the RenderTarget::Image of camera and use
+/// the UI_SCREENSHOT_GRAPH render graph.
+pub struct UiScreenshotRequest {
+    pub camera: Entity,
+    pub target: Handle<Image>,
+    pub size: UVec2,
+    pub meshes: Vec<UiScreenshotMesh>,
+}
+
+#[derive(Default)]
+struct UiScreenshotState {
+    request: Option<UiScreenshotRequest>,
+    pixels: Option<Vec<u8>>,
+}
+
+/// Shared by the main and render world, a request is drawn offscreen and the pixels of the
+/// target image are read back so they can be saved.
+#[derive(Clone, Default, Resource)]
+pub struct UiScreenshotCapture {
+    state: Arc<Mutex<UiScreenshotState>>,
+}
+
+impl UiScreenshotCapture {
+    pub fn request(&self, request: UiScreenshotRequest) {
+        if let Ok(mut state) = self.state.lock() {
+            state.request = Some(request);
+            state.pixels = None;
+        }
+    }
+
+    /// Returns the RGBA8 pixels of the last request once they have been read back.
+    pub fn take_pixels(&self) -> Option<Vec<u8>> {
+        self.state.lock().ok()?.pixels.take()
+    }
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Pod, Zeroable)]
+struct UiScreenshotVertex {
+    position: [f32; 2],
+    uv: [f32; 2],
+    color: [f32; 4],
+}
+
+struct UiScreenshotDraw {
+    bind_group: BindGroup,
+    scissor: [u32; 4],
+    num_indices: u32,
+    first_index: u32,
+    base_vertex: i32,
+}
+
+struct PreparedUiScreenshot {
+    camera: Entity,
+    target: Handle<Image>,
+    size: UVec2,
+    vertex_buffer: Buffer,
+    index_buffer: Buffer,
+    draws: Vec<UiScreenshotDraw>,
+    padded_bytes_per_row: u32,
+    readback_buffer: Buffer,
+    readback_state: Arc<AtomicU8>,
+}
+
+#[derive(Default, Resource)]
+struct UiScreenshotMeta {
+    prepared: Option<PreparedUiScreenshot>,
+}
+
+#[derive(Resource)]
+struct UiScreenshotPipeline {
+    texture_layout: BindGroupLayout,
+    pipeline_id: CachedRenderPipelineId,
+}
+
+impl FromWorld for UiScreenshotPipeline {
+    fn from_world(world: &mut World) -> Self {
+        let render_device = world.resource::<RenderDevice>();
+        let texture_layout = render_device.create_bind_group_layout(&BindGroupLayoutDescriptor {
+            entries: &[
+                // Base Texture
+                BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Texture {
+                        multisampled: false,
+                        sample_type: TextureSampleType::Float { filterable: true },
+                        view_dimension: TextureViewDimension::D2,
+                    },
+                    count: None,
+                },
+                // Base Texture Sampler
+                BindGroupLayoutEntry {
+                    binding: 1,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                    count: None,
+                },
+            ],
+            label: Some("ui_screenshot_texture_layout"),
+        });
+
+        // egui colours are premultiplied by alpha
+        let blend_component = BlendComponent {
+            src_factor: BlendFactor::One,
+            dst_factor: BlendFactor::OneMinusSrcAlpha,
+            operation: BlendOperation::Add,
+        };
+
+        let descriptor = RenderPipelineDescriptor {
+            vertex: VertexState {
+                shader: UI_SCREENSHOT_SHADER_HANDLE.typed(),
+                entry_point: "vertex".into(),
+                buffers: vec![VertexBufferLayout {
+                    array_stride: 2 * 4 + 2 * 4 + 4 * 4,
+                    step_mode: VertexStepMode::Vertex,
+                    attributes: vec![
+                        // Position
+                        VertexAttribute {
+                            format: VertexFormat::Float32x2,
+                            offset: 0,
+                            shader_location: 0,
+                        },
+                        // UV
+                        VertexAttribute {
+                            format: VertexFormat::Float32x2,
+                            offset: 2 * 4,
+                            shader_location: 1,
+                        },
+                        // Color
+                        VertexAttribute {
+                            format: VertexFormat::Float32x4,
+                            offset: 2 * 4 + 2 * 4,
+                            shader_location: 2,
+                        },
+                    ],
+                }],
+                shader_defs: Vec::default(),
+            },
+            fragment: Some(FragmentState {
+                shader: UI_SCREENSHOT_SHADER_HANDLE.typed(),
+                shader_defs: Vec::default(),
+                entry_point: "fragment".into(),
+                targets: vec![Some(ColorTargetState {
+                    format: TextureFormat::bevy_default(),
+                    blend: Some(BlendState {
+                        color: blend_component,
+                        alpha: blend_component,
+                    }),
+                    write_mask: ColorWrites::ALL,
+                })],
+            }),
+            layout: vec![texture_layout.clone()],
+            primitive: PrimitiveState {
+                front_face: FrontFace::Ccw,
+                cull_mode: None,
+                unclipped_depth: false,
+                polygon_mode: PolygonMode::Fill,
+                conservative: false,
+                topology: PrimitiveTopology::TriangleList,
+                strip_index_format: None,
+            },
+            depth_stencil: None,
+            multisample: MultisampleState::default(),
+            label: Some("ui_screenshot_pipeline".into()),
+            push_constant_ranges: Vec::default(),
+        };
+        let pipeline_id = world
+            .resource_mut::<PipelineCache>()
+            .queue_render_pipeline(descriptor);
+
+        Self {
+            texture_layout,
+            pipeline_id,
+        }
+    }
+}
+
+fn get_scissor(clip_rect: egui::Rect, size: UVec2) -> Option<[u32; 4]> {
+    let min_x = (clip_rect.min.x.round().max(0.0) as u32).min(size.x);
+    let min_y = (clip_rect.min.y.round().max(0.0) as u32).min(size.y);
+    let max_x = (clip_rect.max.x.round().max(0.0) as u32).min(size.x);
+    let max_y = (clip_rect.max.y.round().max(0.0) as u32).min(size.y);
+
+    if max_x > min_x && max_y > min_y {
+        Some([min_x, min_y, max_x - min_x, max_y - min_y])
+    } else {
+        None
+    }
+}
+
+fn queue_ui_screenshot_system(
+    capture: Res<UiScreenshotCapture>,
+    mut meta: ResMut<UiScreenshotMeta>,
+    pipeline: Res<UiScreenshotPipeline>,
+    gpu_images: Res<RenderAssets<Image>>,
+    render_device: Res<RenderDevice>,
+) {
+    if meta.prepared.is_some() {
+        // Wait until the previous screenshot has been read back
+        return;
+    }
+
+    let Ok(mut state) = capture.state.lock() else {
+        return;
+    };
+
+    // Wait until the target and every texture has been uploaded to the GPU
+    let Some(request) = state.request.as_ref() else {
+        return;
+    };
+    if !gpu_images.contains_key(&request.target)
+        || request
+            .meshes
+            .iter()
+            .any(|mesh| !gpu_images.contains_key(&mesh.image))
+    {
+        return;
+    }
+
+    let Some(request) = state.request.take() else {
+        return;
+    };
+
+    let size = request.size.as_vec2();
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+    let mut draws = Vec::new();
+    let mut bind_groups: HashMap<Handle<Image>, BindGroup> = HashMap::default();
+
+    for mesh in request.meshes.iter() {
+        let Some(scissor) = get_scissor(mesh.clip_rect, request.size) else {
+            continue;
+        };
+        let Some(gpu_image) = gpu_images.get(&mesh.image) else {
+            continue;
+        };
+
+        let bind_group = bind_groups
+            .entry(mesh.image.clone_weak())
+            .or_insert_with(|| {
+                render_device.create_bind_group(&BindGroupDescriptor {
+                    entries: &[
+                        BindGroupEntry {
+                            binding: 0,
+                            resource: BindingResource::TextureView(&gpu_image.texture_view),
+                        },
+                        BindGroupEntry {
+                            binding: 1,
+                            resource: BindingResource::Sampler(&gpu_image.sampler),
+                        },
+                    ],
+                    label: Some("ui_screenshot_bind_group"),
+                    layout: &pipeline.texture_layout,
+                })
+            })
+            .clone();
+
+        draws.push(UiScreenshotDraw {
+            bind_group,
+            scissor,
+            num_indices: mesh.mesh.indices.len() as u32,
+            first_index: indices.len() as u32,
+            base_vertex: vertices.len() as i32,
+        });
+
+        indices.extend_from_slice(&mesh.mesh.indices);
+        vertices.extend(mesh.mesh.vertices.iter().map(|vertex| UiScreenshotVertex {
+            position: [
+                vertex.pos.x / size.x * 2.0 - 1.0,
+                1.0 - vertex.pos.y / size.y * 2.0,
+            ],
+            uv: [vertex.uv.x, vertex.uv.y],
+            color: egui::Rgba::from(vertex.color).to_array(),
+        }));
+    }
+
+    let padded_bytes_per_row = (request.size.x * 4 + COPY_BYTES_PER_ROW_ALIGNMENT - 1)
+        / COPY_BYTES_PER_ROW_ALIGNMENT
+        * COPY_BYTES_PER_ROW_ALIGNMENT;
+
+    meta.prepared = Some(PreparedUiScreenshot {
+        camera: request.camera,
+        target: request.target,
+        size: request.size,
+        vertex_buffer: render_device.create_buffer_with_data(&BufferInitDescriptor {
+            label: Some("ui_screenshot_vertex_buffer"),
+            contents: bytemuck::cast_slice(&vertices),
+            usage: BufferUsages::VERTEX,
+        }),
+        index_buffer: render_device.create_buffer_with_data(&BufferInitDescriptor {
+            label: Some("ui_screenshot_index_buffer"),
+            contents: bytemuck::cast_slice(&indices),
+            usage: BufferUsages::INDEX,
+        }),
+        draws,
+        padded_bytes_per_row,
+        readback_buffer: render_device.create_buffer(&BufferDescriptor {
+            label: Some("ui_screenshot_readback_buffer"),
+            size: padded_bytes_per_row as u64 * request.size.y as u64,
+            usage: BufferUsages::MAP_READ | BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        }),
+        readback_state: Arc::new(AtomicU8::new(READBACK_PENDING)),
+    });
+}
+
+/// Draws the prepared screenshot into the image target of its camera, then copies the image
+/// to the readback buffer.
+#[derive(Default)]
+struct UiScreenshotNode;
+
+impl Node for UiScreenshotNode {
+    fn run(
+        &self,
+        graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext,
+        world: &World,
+    ) -> Result<(), NodeRunError> {
+        let view_entity = graph.view_entity();
+        let Some(prepared) = world
+            .resource::<UiScreenshotMeta>()
+            .prepared
+            .as_ref()
+            .filter(|prepared| prepared.camera == view_entity)
+        else {
+            return Ok(());
+        };
+        if prepared.readback_state.load(Ordering::Acquire) != READBACK_PENDING {
+            return Ok(());
+        }
+
+        let pipeline_id = world.resource::<UiScreenshotPipeline>().pipeline_id;
+        let (Some(view_target), Some(pipeline), Some(gpu_image)) = (
+            world.get::<ViewTarget>(view_entity),
+            world
+                .resource::<PipelineCache>()
+                .get_render_pipeline(pipeline_id),
+            world
+                .resource::<RenderAssets<Image>>()
+                .get(&prepared.target),
+        ) else {
+            // Try again next frame
+            return Ok(());
+        };
+
+        {
+            let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+                label: Some("ui_screenshot_pass"),
+                color_attachments: &[Some(RenderPassColorAttachment {
+                    view: view_target.out_texture(),
+                    resolve_target: None,
+                    ops: Operations {
+                        load: LoadOp::Clear(Color::NONE.into()),
+                        store: true,
+                    },
+                })],
+                depth_stencil_attachment: None,
+            });
+
+            render_pass.set_render_pipeline(pipeline);
+            render_pass.set_vertex_buffer(0, prepared.vertex_buffer.slice(..));
+            render_pass.set_index_buffer(prepared.index_buffer.slice(..), 0, IndexFormat::Uint32);
+
+            for draw in prepared.draws.iter() {
+                let [x, y, width, height] = draw.scissor;
+                render_pass.set_scissor_rect(x, y, width, height);
+                render_pass.set_bind_group(0, &draw.bind_group, &[]);
+                render_pass.draw_indexed(
+                    draw.first_index..draw.first_index + draw.num_indices,
+                    draw.base_vertex,
+                    0..1,
+                );
+            }
+        }
+
+        render_context.command_encoder().copy_texture_to_buffer(
+            gpu_image.texture.as_image_copy(),
+            ImageCopyBuffer {
+                buffer: &prepared.readback_buffer,
+                layout: ImageDataLayout {
+                    offset: 0,
+                    bytes_per_row: Some(prepared.padded_bytes_per_row),
+                    rows_per_image: None,
+                },
+            },
+            Extent3d {
+                width: prepared.size.x,
+                height: prepared.size.y,
+                depth_or_array_layers: 1,
+            },
+        );
+        prepared
+            .readback_state
+            .store(READBACK_COPY_QUEUED, Ordering::Release);
+
+        Ok(())
+    }
+}
+
+fn readback_ui_screenshot_system(
+    capture: Res<UiScreenshotCapture>,
+    mut meta: ResMut<UiScreenshotMeta>,
+) {
+    let Some(prepared) = meta.prepared.as_ref() else {
+        return;
+    };
+
+    match prepared.readback_state.load(Ordering::Acquire) {
+        READBACK_COPY_QUEUED => {
+            let readback_state = prepared.readback_state.clone();
+            readback_state.store(READBACK_MAPPING, Ordering::Release);
+            prepared
+                .readback_buffer
+                .slice(..)
+                .map_async(MapMode::Read, move |result| {
+                    readback_state.store(
+                        if result.is_ok() {
+                            READBACK_MAPPED
+                        } else {
+                            READBACK_PENDING
+                        },
+                        Ordering::Release,
+                    );
+                });
+        }
+        READBACK_MAPPED => {
+            let bytes_per_row = prepared.size.x as usize * 4;
+            let pixels: Vec<u8> = {
+                let data = prepared.readback_buffer.slice(..).get_mapped_range();
+                data.chunks(prepared.padded_bytes_per_row as usize)
+                    .flat_map(|row| &row[..bytes_per_row])
+                    .copied()
+                    .collect()
+            };
+            prepared.readback_buffer.unmap();
+
+            if let Ok(mut state) = capture.state.lock() {
+                state.pixels = Some(pixels);
+            }
+            meta.prepared = None;
+        }
+        _ => {}
+    }
+}
+
+#[derive(Default)]
+pub struct UiScreenshotPlugin;
+
+impl Plugin for UiScreenshotPlugin {
+    fn build(&self, app: &mut App) {
+        load_internal_asset!(
+            app,
+            UI_SCREENSHOT_SHADER_HANDLE,
+            "shaders/ui_screenshot.wgsl",
+            Shader::from_wgsl
+        );
+
+        let capture = UiScreenshotCapture::default();
+        app.insert_resource(capture.clone());
+
+        let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app
+            .insert_resource(capture)
+            .init_resource::<UiScreenshotMeta>()
+            .add_systems(
+                Render,
+                (
+                    queue_ui_screenshot_system.in_set(RenderSet::Queue),
+                    readback_ui_screenshot_system.in_set(RenderSet::Cleanup),
+                ),
+            )
+            .add_render_sub_graph(UI_SCREENSHOT_GRAPH)
+            .add_render_graph_node::<UiScreenshotNode>(UI_SCREENSHOT_GRAPH, UI_SCREENSHOT_NODE);
+    }
+
+    fn finish(&self, app: &mut App) {
+        let Ok(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+
+        render_app.init_resource::<UiScreenshotPipeline>();
+    }
+}
@@ -6,16 +6,19 @@ use bevy::{
         query::ROQueryItem,
         system::{lifetimeless::SRes, SystemParamItem},
     },
+    math::{Mat4, Vec4},
     pbr::{
         DrawMesh, DrawPrepass, MeshPipelineKey, SetMaterialBindGroup, SetMeshBindGroup,
         SetMeshViewBindGroup,
     },
     prelude::{
-        AlphaMode, App, Commands, FromWorld, HandleUntyped, Image, Material, MaterialPlugin, Mesh,
-        Plugin, Res, Resource, Time, World,
+        AlphaMode, App, Commands, Component, FromWorld, HandleUntyped, Image, Material,
+        MaterialPlugin, Mesh, PerspectiveProjection, Plugin, ReflectComponent, ReflectDefault, Res,
+        Resource, Time, World,
     },
-    reflect::{TypePath, TypeUuid},
+    reflect::{Reflect, TypePath, TypeUuid},
     render::{
+        camera::{CameraProjection, CameraProjectionPlugin},
         mesh::MeshVertexBufferLayout,
         prelude::Shader,
         render_asset::RenderAssets,
@@ -36,13 +39,66 @@ use bevy::{
     },
 };
 
-use crate::render::zone_lighting::{SetZoneLightingBindGroup, ZoneLightingUniformMeta};
+use crate::{
+    render::{
+        render_statistics::{CountMeshDrawCall, RenderStatisticsPass, WriteGpuTimestamp},
+        zone_lighting::{SetZoneLightingBindGroup, ZoneLightingUniformMeta},
+    },
+    resources::WaterReflection,
+};
 
 pub const WATER_MESH_MATERIAL_SHADER_HANDLE: HandleUntyped =
     HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 0x333959e64b35d5d9);
 
 pub const WATER_MATERIAL_NUM_TEXTURES: usize = 25;
 
+/// Water is only rendered by cameras with this layer, so the water reflection camera
+/// does not render the water which samples the reflection image.
+pub const WATER_RENDER_LAYER: u8 = 1;
+
+/// The projection of the water reflection camera, a perspective projection with
+/// the near plane replaced by the water plane so nothing below the water is
+/// reflected. See Lengyel, "Oblique View Frustum Depth Projection and Clipping".
+#[derive(Component, Clone, Default, Reflect)]
+#[reflect(Component, Default)]
+pub struct WaterReflectionProjection {
+    pub perspective: PerspectiveProjection,
+    /// The clip plane in view space, with the normal pointing to the visible side.
+    pub clip_plane: Vec4,
+}
+
+impl CameraProjection for WaterReflectionProjection {
+    fn get_projection_matrix(&self) -> Mat4 {
+        let mut matrix = self.perspective.get_projection_matrix();
+        let normal = self.clip_plane.truncate();
+        if normal.length_squared() == 0.0 {
+            return matrix;
+        }
+
+        // With the reverse z projection the near plane is where z = w, replace the z
+        // row with w - scale * plane so the clip plane becomes the near plane. The
+        // scale keeps depth above zero for every direction inside the frustum.
+        let tan_y = (self.perspective.fov * 0.5).tan();
+        let tan_x = tan_y * self.perspective.aspect_ratio;
+        let max_depth_slope = normal.x.abs() * tan_x + normal.y.abs() * tan_y - normal.z;
+        let scale = 1.0 / max_depth_slope.max(f32::EPSILON);
+
+        matrix.x_axis.z = matrix.x_axis.w - scale * self.clip_plane.x;
+        matrix.y_axis.z = matrix.y_axis.w - scale * self.clip_plane.y;
+        matrix.z_axis.z = matrix.z_axis.w - scale * self.clip_plane.z;
+        matrix.w_axis.z = matrix.w_axis.w - scale * self.clip_plane.w;
+        matrix
+    }
+
+    fn update(&mut self, width: f32, height: f32) {
+        self.perspective.update(width, height);
+    }
+
+    fn far(&self) -> f32 {
+        self.perspective.far
+    }
+}
+
 #[derive(Default)]
 pub struct WaterMaterialPlugin {
     pub prepass_enabled: bool,
@@ -57,14 +113,13 @@ impl Plugin for WaterMaterialPlugin {
             Shader::from_wgsl
         );
 
-        app.add_plugins(MaterialPlugin::<
-            WaterMaterial,
-            DrawWaterMaterial,
-            DrawPrepass<WaterMaterial>,
-        > {
-            prepass_enabled: self.prepass_enabled,
-            ..Default::default()
-        });
+        app.add_plugins((
+            MaterialPlugin::<WaterMaterial, DrawWaterMaterial, DrawPrepass<WaterMaterial>> {
+                prepass_enabled: self.prepass_enabled,
+                ..Default::default()
+            },
+            CameraProjectionPlugin::<WaterReflectionProjection>::default(),
+        ));
 
         if let Ok(render_app) = app.get_sub_app_mut(RenderApp) {
             render_app.add_systems(ExtractSchedule, extract_water_push_constant_data);
@@ -74,21 +129,38 @@ impl Plugin for WaterMaterialPlugin {
 
 #[derive(Clone, ShaderType, Resource)]
 pub struct WaterPushConstantData {
+    pub reflection_view_proj: Mat4,
     pub current_index: i32,
     pub next_index: i32,
     pub next_weight: f32,
+    pub reflection_strength: f32,
 }
 
-fn extract_water_push_constant_data(mut commands: Commands, time: Extract<Res<Time>>) {
+fn extract_water_push_constant_data(
+    mut commands: Commands,
+    time: Extract<Res<Time>>,
+    water_reflection: Extract<Option<Res<WaterReflection>>>,
+) {
     let time = time.elapsed_seconds_wrapped() * 10.0;
     let current_index = (time as i32) % WATER_MATERIAL_NUM_TEXTURES as i32;
     let next_index = (current_index + 1) % WATER_MATERIAL_NUM_TEXTURES as i32;
     let next_weight = time.fract();
 
+    let (reflection_view_proj, reflection_strength) = water_reflection
+        .as_ref()
+        .and_then(|water_reflection| {
+            water_reflection
+                .view_proj
+                .map(|view_proj| (view_proj, water_reflection.strength))
+        })
+        .unwrap_or((Mat4::IDENTITY, 0.0));
+
     commands.insert_resource(WaterPushConstantData {
+        reflection_view_proj,
         current_index,
         next_index,
         next_weight,
+        reflection_strength,
     });
 }
 
@@ -112,6 +184,8 @@ impl FromWorld for WaterMaterialPipelineData {
 #[uuid = "e9e46dcc-94db-4b31-819f-d5ecffc732f0"]
 pub struct WaterMaterial {
     pub textures: Vec<Handle<Image>>,
+    /// Set by water_reflection_system when water reflections are enabled.
+    pub reflection_image: Option<Handle<Image>>,
 }
 
 impl Material for WaterMaterial {
@@ -213,6 +287,14 @@ impl AsBindGroup for WaterMaterial {
             textures[id] = &*image.texture_view;
         }
 
+        let reflection_texture = match self.reflection_image.as_ref() {
+            Some(handle) => match image_assets.get(handle) {
+                Some(image) => &image.texture_view,
+                None => return Err(AsBindGroupError::RetryNextUpdate),
+            },
+            None => &fallback_image.d2.texture_view,
+        };
+
         let sampler = render_device.create_sampler(&SamplerDescriptor {
             address_mode_u: AddressMode::Repeat,
             address_mode_v: AddressMode::Repeat,
@@ -221,6 +303,14 @@ impl AsBindGroup for WaterMaterial {
             ..Default::default()
         });
 
+        let reflection_sampler = render_device.create_sampler(&SamplerDescriptor {
+            address_mode_u: AddressMode::ClampToEdge,
+            address_mode_v: AddressMode::ClampToEdge,
+            mag_filter: FilterMode::Linear,
+            min_filter: FilterMode::Linear,
+            ..Default::default()
+        });
+
         let bind_group = render_device.create_bind_group(&BindGroupDescriptor {
             label: "water_material_bind_group".into(),
             layout,
@@ -233,6 +323,14 @@ impl AsBindGroup for WaterMaterial {
                     binding: 1,
                     resource: BindingResource::Sampler(&sampler),
                 },
+                BindGroupEntry {
+                    binding: 2,
+                    resource: BindingResource::TextureView(reflection_texture),
+                },
+                BindGroupEntry {
+                    binding: 3,
+                    resource: BindingResource::Sampler(&reflection_sampler),
+                },
             ],
         });
 
@@ -266,6 +364,22 @@ impl AsBindGroup for WaterMaterial {
                     ty: BindingType::Sampler(SamplerBindingType::Filtering),
                     count: None,
                 },
+                BindGroupLayoutEntry {
+                    binding: 2,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Texture {
+                        sample_type: TextureSampleType::Float { filterable: true },
+                        view_dimension: TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                },
+                BindGroupLayoutEntry {
+                    binding: 3,
+                    visibility: ShaderStages::FRAGMENT,
+                    ty: BindingType::Sampler(SamplerBindingType::Filtering),
+                    count: None,
+                },
             ],
         })
     }
@@ -299,5 +413,7 @@ type DrawWaterMaterial = (
     SetMeshBindGroup<2>,
     SetZoneLightingBindGroup<3>,
     SetWaterMaterialPushConstants<0>,
+    WriteGpuTimestamp<{ RenderStatisticsPass::Water as usize }>,
+    CountMeshDrawCall<{ RenderStatisticsPass::Water as usize }>,
     DrawMesh,
 );
@@ -13,8 +13,8 @@ use bevy::{
     pbr::MeshPipelineKey,
     prelude::{
         App, Assets, Color, Commands, Component, ComputedVisibility, FromWorld, GlobalTransform,
-        HandleUntyped, IntoSystemConfigs, Msaa, Plugin, Query, Res, ResMut, Resource, Vec2, Vec3,
-        World,
+        HandleUntyped, IntoSystemConfigs, Msaa, Parent, Plugin, Query, Res, ResMut, Resource, Vec2,
+        Vec3, World,
     },
     reflect::TypeUuid,
     render::{
@@ -45,7 +45,10 @@ use bevy::{
 };
 use bytemuck::{Pod, Zeroable};
 
-use crate::render::zone_lighting::{SetZoneLightingBindGroup, ZoneLightingUniformMeta};
+use crate::render::{
+    render_statistics::{RenderStatisticsCounters, RenderStatisticsPass, WriteGpuTimestamp},
+    zone_lighting::{SetZoneLightingBindGroup, ZoneLightingUniformMeta},
+};
 
 pub const WORLD_UI_SHADER_HANDLE: HandleUntyped =
     HandleUntyped::weak_from_u64(Shader::TYPE_UUID, 0xd5cdda11c713e3a7);
@@ -95,6 +98,22 @@ pub struct WorldUiRect {
     pub order: u8,
 }
 
+/// Scales and fades every WorldUiRect which is a direct child of this entity.
+#[derive(Component, Copy, Clone)]
+pub struct WorldUiGroup {
+    pub scale: f32,
+    pub alpha: f32,
+}
+
+impl Default for WorldUiGroup {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            alpha: 1.0,
+        }
+    }
+}
+
 pub struct ExtractedRect {
     pub world_position: Vec3,
     pub screen_offset: Vec2,
@@ -122,10 +141,18 @@ impl Default for ExtractedWorldUi {
 fn extract_world_ui_rects(
     mut extracted_world_ui: ResMut<ExtractedWorldUi>,
     images: Extract<Res<Assets<Image>>>,
-    query: Extract<Query<(&ComputedVisibility, &GlobalTransform, &WorldUiRect)>>,
+    query: Extract<
+        Query<(
+            &ComputedVisibility,
+            &GlobalTransform,
+            &WorldUiRect,
+            Option<&Parent>,
+        )>,
+    >,
+    query_group: Extract<Query<&WorldUiGroup>>,
 ) {
     extracted_world_ui.rects.clear();
-    for (visible, global_transform, rect) in query.iter() {
+    for (visible, global_transform, rect, parent) in query.iter() {
         if !visible.is_visible_in_hierarchy() {
             continue;
         }
@@ -134,14 +161,25 @@ fn extract_world_ui_rects(
             continue;
         }
 
+        let group = parent
+            .and_then(|parent| query_group.get(parent.get()).ok())
+            .copied()
+            .unwrap_or_default();
+        if group.alpha <= 0.0 {
+            continue;
+        }
+
+        let mut color = rect.color;
+        color.set_a(color.a() * group.alpha);
+
         extracted_world_ui.rects.push(ExtractedRect {
             world_position: global_transform.translation(),
-            screen_offset: rect.screen_offset,
-            screen_size: rect.screen_size,
+            screen_offset: rect.screen_offset * group.scale,
+            screen_size: rect.screen_size * group.scale,
             image_handle_id: rect.image.id(),
             uv_min: rect.uv_min,
             uv_max: rect.uv_max,
-            color: rect.color,
+            color,
             order: rect.order,
         });
     }
@@ -371,12 +409,13 @@ type DrawWorldUi = (
     SetWorldUiViewBindGroup<0>,
     SetWorldUiMaterialBindGroup<1>,
     SetZoneLightingBindGroup<2>,
+    WriteGpuTimestamp<{ RenderStatisticsPass::WorldUi as usize }>,
     DrawWorldUiBatch,
 );
 
 struct DrawWorldUiBatch;
 impl<P: PhaseItem> RenderCommand<P> for DrawWorldUiBatch {
-    type Param = SRes<WorldUiMeta>;
+    type Param = (SRes<WorldUiMeta>, SRes<RenderStatisticsCounters>);
     type ViewWorldQuery = ();
     type ItemWorldQuery = Read<WorldUiBatch>;
 
@@ -385,10 +424,15 @@ impl<P: PhaseItem> RenderCommand<P> for DrawWorldUiBatch {
         _: &P,
         _: ROQueryItem<'w, Self::ViewWorldQuery>,
         batch: ROQueryItem<'w, Self::ItemWorldQuery>,
-        sprite_meta: SystemParamItem<'w, '_, Self::Param>,
+        (sprite_meta, render_statistics_counters): SystemParamItem<'w, '_, Self::Param>,
         pass: &mut TrackedRenderPass<'w>,
     ) -> RenderCommandResult {
         let sprite_meta = sprite_meta.into_inner();
+        render_statistics_counters.record_draw_vertices(
+            RenderStatisticsPass::WorldUi,
+            PrimitiveTopology::TriangleList,
+            batch.vertex_range.len() as u32,
+        );
         pass.set_vertex_buffer(0, sprite_meta.vertices.buffer().unwrap().slice(..));
         pass.draw(batch.vertex_range.clone(), 0..1);
         RenderCommandResult::Success
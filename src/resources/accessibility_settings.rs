@@ -0,0 +1,29 @@
+use bevy::prelude::Resource;
+use enum_map::{enum_map, EnumMap};
+
+use crate::text_to_speech::TextToSpeechChannel;
+
+#[derive(Resource)]
+pub struct AccessibilitySettings {
+    /// Scales the brightness of effects spawned by skills and other events.
+    pub effect_intensity: f32,
+    /// Scales the intensity of the camera bloom.
+    pub bloom_intensity: f32,
+    /// Limits how quickly particle colours can change to prevent strobing.
+    pub reduce_flashing: bool,
+
+    pub text_to_speech_enabled: bool,
+    pub text_to_speech_channels: EnumMap<TextToSpeechChannel, bool>,
+}
+
+impl Default for AccessibilitySettings {
+    fn default() -> Self {
+        Self {
+            effect_intensity: 1.0,
+            bloom_intensity: 1.0,
+            reduce_flashing: false,
+            text_to_speech_enabled: false,
+            text_to_speech_channels: enum_map! { _ => true },
+        }
+    }
+}
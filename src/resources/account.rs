@@ -4,4 +4,11 @@ use bevy::prelude::Resource;
 pub struct Account {
     pub username: String,
     pub password: String,
+
+    /// Address of the login server, used with server_id to key the character stores.
+    pub login_server: String,
+
+    /// The server and channel last joined with this account.
+    pub server_id: Option<usize>,
+    pub channel_id: Option<usize>,
 }
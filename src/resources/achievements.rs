@@ -0,0 +1,155 @@
+use std::time::{Duration, Instant};
+
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::CharacterStoreKey;
+
+/// How long changed progress is kept before it is saved.
+const ACHIEVEMENTS_SAVE_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub enum AchievementKind {
+    Kills,
+    QuestsCompleted,
+    ZonesVisited,
+}
+
+pub struct AchievementDefinition {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub kind: AchievementKind,
+    pub required: u32,
+    pub title: &'static str,
+}
+
+pub const ACHIEVEMENTS: &[AchievementDefinition] = &[
+    AchievementDefinition {
+        name: "First Blood",
+        description: "Defeat your first monster.",
+        kind: AchievementKind::Kills,
+        required: 1,
+        title: "Novice Hunter",
+    },
+    AchievementDefinition {
+        name: "Hunter",
+        description: "Defeat 1,000 monsters.",
+        kind: AchievementKind::Kills,
+        required: 1000,
+        title: "Hunter",
+    },
+    AchievementDefinition {
+        name: "Slayer",
+        description: "Defeat 10,000 monsters.",
+        kind: AchievementKind::Kills,
+        required: 10000,
+        title: "Slayer",
+    },
+    AchievementDefinition {
+        name: "Helping Hand",
+        description: "Complete 10 quests.",
+        kind: AchievementKind::QuestsCompleted,
+        required: 10,
+        title: "Helpful",
+    },
+    AchievementDefinition {
+        name: "Hero of Junon",
+        description: "Complete 100 quests.",
+        kind: AchievementKind::QuestsCompleted,
+        required: 100,
+        title: "Hero",
+    },
+    AchievementDefinition {
+        name: "Sightseer",
+        description: "Visit 10 different zones.",
+        kind: AchievementKind::ZonesVisited,
+        required: 10,
+        title: "Traveller",
+    },
+    AchievementDefinition {
+        name: "Explorer",
+        description: "Visit 30 different zones.",
+        kind: AchievementKind::ZonesVisited,
+        required: 30,
+        title: "Explorer",
+    },
+];
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AchievementProgress {
+    pub kills: u32,
+    pub quests_completed: u32,
+    pub zones_visited: Vec<u16>,
+    pub selected_title: Option<String>,
+}
+
+impl AchievementProgress {
+    pub fn get(&self, kind: AchievementKind) -> u32 {
+        match kind {
+            AchievementKind::Kills => self.kills,
+            AchievementKind::QuestsCompleted => self.quests_completed,
+            AchievementKind::ZonesVisited => self.zones_visited.len() as u32,
+        }
+    }
+}
+
+/// Client side achievement progress for the current character, stored in the
+/// user's data directory as there is no server side support for achievements.
+#[derive(Default, Resource)]
+pub struct Achievements {
+    pub store_key: Option<CharacterStoreKey>,
+    pub progress: AchievementProgress,
+    pub changed: bool,
+    /// When the progress was first changed since it was last saved.
+    unsaved_since: Option<Instant>,
+}
+
+impl Achievements {
+    pub fn load(store_key: CharacterStoreKey) -> Self {
+        Self {
+            progress: store_key.load("achievements"),
+            store_key: Some(store_key),
+            changed: false,
+            unsaved_since: None,
+        }
+    }
+
+    /// Queues the progress to be saved, it is written by save_pending so frequent
+    /// changes such as kills do not write the file every time.
+    pub fn mark_unsaved(&mut self) {
+        self.unsaved_since.get_or_insert_with(Instant::now);
+    }
+
+    pub fn save_pending(&mut self, force: bool) {
+        let Some(unsaved_since) = self.unsaved_since else {
+            return;
+        };
+
+        if !force && unsaved_since.elapsed() < ACHIEVEMENTS_SAVE_INTERVAL {
+            return;
+        }
+
+        if let Some(store_key) = self.store_key.as_ref() {
+            store_key.save("achievements", &self.progress);
+        }
+        self.unsaved_since = None;
+    }
+
+    pub fn is_unlocked(&self, achievement: &AchievementDefinition) -> bool {
+        self.progress.get(achievement.kind) >= achievement.required
+    }
+
+    pub fn unlocked_titles(&self) -> impl Iterator<Item = &'static str> + '_ {
+        ACHIEVEMENTS
+            .iter()
+            .filter(|achievement| self.is_unlocked(achievement))
+            .map(|achievement| achievement.title)
+    }
+
+    pub fn get_selected_title(&self) -> Option<&str> {
+        let selected_title = self.progress.selected_title.as_deref()?;
+        self.unlocked_titles()
+            .find(|title| *title == selected_title)
+    }
+}
@@ -0,0 +1,24 @@
+use std::time::Duration;
+
+use bevy::prelude::Resource;
+
+#[derive(Resource)]
+pub struct AfkSettings {
+    pub auto_away_enabled: bool,
+    pub auto_away_timeout: Duration,
+    pub auto_reply_enabled: bool,
+    pub low_power_when_unfocused: bool,
+    pub mute_when_unfocused: bool,
+}
+
+impl Default for AfkSettings {
+    fn default() -> Self {
+        Self {
+            auto_away_enabled: true,
+            auto_away_timeout: Duration::from_secs(5 * 60),
+            auto_reply_enabled: true,
+            low_power_when_unfocused: false,
+            mute_when_unfocused: false,
+        }
+    }
+}
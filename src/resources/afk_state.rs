@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use bevy::prelude::Resource;
+
+pub const DEFAULT_AFK_MESSAGE: &str = "I am away from my keyboard.";
+
+#[derive(Default, Resource)]
+pub struct AfkState {
+    /// Whether the player is currently away, either manually or from inactivity
+    pub is_afk: bool,
+
+    /// Set when the player used /afk, these are only cleared by using /afk again
+    pub is_manual: bool,
+
+    pub message: Option<String>,
+    pub last_input_time: Duration,
+
+    /// Players who have already received an auto reply while we have been away
+    pub replied_to: Vec<String>,
+}
+
+impl AfkState {
+    pub fn get_message(&self) -> &str {
+        self.message.as_deref().unwrap_or(DEFAULT_AFK_MESSAGE)
+    }
+
+    pub fn set_afk(&mut self, is_manual: bool, message: Option<String>) {
+        self.is_afk = true;
+        self.is_manual = is_manual;
+        self.message = message;
+        self.replied_to.clear();
+    }
+
+    pub fn clear_afk(&mut self) {
+        self.is_afk = false;
+        self.is_manual = false;
+        self.message = None;
+        self.replied_to.clear();
+    }
+}
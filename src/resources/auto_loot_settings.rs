@@ -0,0 +1,26 @@
+use bevy::prelude::Resource;
+
+#[derive(Resource)]
+pub struct AutoLootSettings {
+    pub enabled: bool,
+    pub radius: f32,
+    pub min_item_quality: u32,
+    pub pickup_money: bool,
+    pub pickup_equipment: bool,
+    pub pickup_consumables: bool,
+    pub pickup_materials: bool,
+}
+
+impl Default for AutoLootSettings {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            radius: 800.0,
+            min_item_quality: 0,
+            pickup_money: true,
+            pickup_equipment: true,
+            pickup_consumables: true,
+            pickup_materials: true,
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use std::path::PathBuf;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::resources::Account;
+
+fn get_project_dirs() -> Option<directories::ProjectDirs> {
+    directories::ProjectDirs::from("", "", "rose-offline-client")
+}
+
+/// The user's data directory, used for per-character stores, logs and exported files.
+pub fn get_data_dir() -> anyhow::Result<PathBuf> {
+    get_project_dirs()
+        .map(|project_dirs| project_dirs.data_dir().to_path_buf())
+        .ok_or_else(|| anyhow::anyhow!("Could not find data directory"))
+}
+
+/// The user's config directory.
+pub fn get_config_dir() -> anyhow::Result<PathBuf> {
+    get_project_dirs()
+        .map(|project_dirs| project_dirs.config_dir().to_path_buf())
+        .ok_or_else(|| anyhow::anyhow!("Could not find config directory"))
+}
+
+fn to_path_component(name: &str) -> String {
+    name.chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' || c == '_' || c == '.' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Identifies the character a client side store belongs to, characters on
+/// different servers can have the same name so the server is part of the key.
+///
+/// Each store is saved in the user's data directory as
+/// `<store>/<server>/<character>.toml`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CharacterStoreKey {
+    pub server: String,
+    pub character_name: String,
+}
+
+impl CharacterStoreKey {
+    /// The server is the login server and the world server selected on it, without
+    /// an account (e.g. replay playback) the stores are shared as a local server.
+    pub fn new(account: Option<&Account>, character_name: &str) -> Self {
+        let server = account.map_or_else(
+            || "local".to_string(),
+            |account| {
+                format!(
+                    "{}-{}",
+                    account.login_server,
+                    account.server_id.unwrap_or_default()
+                )
+            },
+        );
+
+        Self {
+            server,
+            character_name: character_name.to_string(),
+        }
+    }
+
+    fn get_path(&self, store: &str) -> anyhow::Result<PathBuf> {
+        Ok(get_data_dir()?
+            .join(store)
+            .join(to_path_component(&self.server))
+            .join(format!("{}.toml", to_path_component(&self.character_name))))
+    }
+
+    /// Returns the default value when the store does not exist or fails to load.
+    pub fn load<T: DeserializeOwned + Default>(&self, store: &str) -> T {
+        self.get_path(store)
+            .ok()
+            .and_then(|path| std::fs::read_to_string(path).ok())
+            .and_then(|toml_str| toml::from_str(&toml_str).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save<T: Serialize>(&self, store: &str, value: &T) {
+        let result = self.get_path(store).and_then(|path| {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(&path, toml::to_string(value)?)?;
+            Ok(())
+        });
+
+        if let Err(error) = result {
+            log::warn!(
+                "Failed to save {} for character {} on server {} with error: {}",
+                store,
+                self.character_name,
+                self.server,
+                error
+            );
+        }
+    }
+}
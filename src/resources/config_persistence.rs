@@ -0,0 +1,178 @@
+use std::{
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use bevy::prelude::Resource;
+
+use crate::{
+    resources::{UserSettings, WindowModeSetting},
+    GraphicsConfig, GraphicsModeConfig,
+};
+
+// Wait for the user to stop changing settings before writing the file
+const CONFIG_SAVE_DELAY: Duration = Duration::from_secs(1);
+
+/// Writes settings changed in game back to the launch config.toml they were
+/// read from.
+///
+/// Only the keys which can be changed in game are replaced, everything else
+/// in the file is written back as it was read so values given on the command
+/// line are never saved. Comments in the file are not kept.
+#[derive(Default, Resource)]
+pub struct ConfigPersistence {
+    path: Option<PathBuf>,
+    current: Option<UserSettings>,
+    pending: Option<Instant>,
+}
+
+impl ConfigPersistence {
+    pub fn new(path: Option<PathBuf>) -> Self {
+        Self {
+            path,
+            ..Default::default()
+        }
+    }
+
+    /// Queues the config to be written once the settings have stopped
+    /// changing. The first settings given are the ones already in use.
+    pub fn update(&mut self, settings: &UserSettings) {
+        if self.path.is_none() {
+            return;
+        }
+
+        match self.current.as_ref() {
+            Some(current) if current == settings => {}
+            Some(_) => {
+                self.current = Some(settings.clone());
+                self.pending = Some(Instant::now());
+            }
+            None => {
+                self.current = Some(settings.clone());
+            }
+        }
+    }
+
+    pub fn save_pending(&mut self, force: bool) {
+        let Some(changed_time) = self.pending else {
+            return;
+        };
+
+        if !force && changed_time.elapsed() < CONFIG_SAVE_DELAY {
+            return;
+        }
+        self.pending = None;
+
+        let (Some(path), Some(settings)) = (self.path.as_ref(), self.current.as_ref()) else {
+            return;
+        };
+
+        match write_config(path, settings) {
+            Ok(()) => log::info!("Saved configuration to {}", path.to_string_lossy()),
+            Err(error) => log::warn!(
+                "Failed to save configuration to {} with error: {}",
+                path.to_string_lossy(),
+                error
+            ),
+        }
+    }
+}
+
+fn get_table<'a>(table: &'a mut toml::Table, key: &str) -> &'a mut toml::Table {
+    if !table.get(key).map_or(false, |value| value.is_table()) {
+        table.insert(key.into(), toml::Value::Table(toml::Table::new()));
+    }
+
+    table
+        .get_mut(key)
+        .and_then(|value| value.as_table_mut())
+        .unwrap()
+}
+
+// Go through the decimal string so 0.6 is not written as 0.6000000238418579
+fn float_value(value: f32) -> toml::Value {
+    toml::Value::Float(value.to_string().parse().unwrap_or(value as f64))
+}
+
+fn write_config(path: &Path, settings: &UserSettings) -> Result<(), anyhow::Error> {
+    // Read the file again so any edits made while the game is running are kept
+    let mut config: toml::Table = toml::from_str(&std::fs::read_to_string(path)?)?;
+
+    let sound = get_table(&mut config, "sound");
+    sound.insert("enabled".into(), settings.sound.enabled.into());
+
+    let volume = get_table(sound, "volume");
+    for (key, value) in [
+        ("global", settings.sound.global),
+        ("background_music", settings.sound.background_music),
+        ("player_footstep", settings.sound.player_footstep),
+        ("player_combat", settings.sound.player_combat),
+        ("other_footstep", settings.sound.other_footstep),
+        ("other_combat", settings.sound.other_combat),
+        ("npc_sounds", settings.sound.npc_sounds),
+        ("ui_sounds", settings.sound.ui_sounds),
+    ] {
+        volume.insert(key.into(), float_value(value));
+    }
+
+    let graphics = get_table(&mut config, "graphics");
+    let current_mode_type = graphics
+        .get("mode")
+        .and_then(|mode| mode.get("type"))
+        .and_then(|mode_type| mode_type.as_str());
+    let mut mode = toml::Table::new();
+    match settings.window.mode {
+        // The window size is not changed in game, so keep the size from the file
+        WindowModeSetting::Windowed if current_mode_type == Some("window") => {}
+        WindowModeSetting::Windowed => {
+            let (width, height) = match GraphicsConfig::default().mode {
+                GraphicsModeConfig::Window { width, height } => (width, height),
+                _ => (1920.0, 1080.0),
+            };
+            mode.insert("type".into(), "window".into());
+            mode.insert("width".into(), float_value(width));
+            mode.insert("height".into(), float_value(height));
+        }
+        WindowModeSetting::Borderless => {
+            mode.insert("type".into(), "borderless".into());
+        }
+        // The highest resolution exclusive mode cannot be set in config.toml,
+        // so the mode from the file is kept
+        WindowModeSetting::Exclusive if settings.window.exclusive_width == 0 => {}
+        WindowModeSetting::Exclusive => {
+            mode.insert("type".into(), "exclusive".into());
+            mode.insert(
+                "width".into(),
+                i64::from(settings.window.exclusive_width).into(),
+            );
+            mode.insert(
+                "height".into(),
+                i64::from(settings.window.exclusive_height).into(),
+            );
+            if settings.window.exclusive_refresh_rate != 0 {
+                mode.insert(
+                    "refresh_rate".into(),
+                    i64::from(settings.window.exclusive_refresh_rate).into(),
+                );
+            }
+        }
+    }
+
+    if !mode.is_empty() {
+        graphics.insert("mode".into(), toml::Value::Table(mode));
+    }
+
+    if settings.window.monitor >= 0 {
+        graphics.insert("monitor".into(), i64::from(settings.window.monitor).into());
+    } else {
+        graphics.remove("monitor");
+    }
+    graphics.insert(
+        "minimize_on_focus_loss".into(),
+        toml::Value::try_from(settings.window.minimize_on_focus_loss)?,
+    );
+    graphics.insert("disable_vsync".into(), (!settings.window.vsync).into());
+
+    std::fs::write(path, toml::to_string_pretty(&config)?)?;
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use bevy::{
+    prelude::{Assets, Entity, FromWorld, Handle, Image, Resource, World},
+    render::render_resource::{
+        Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
+    },
+};
+
+pub const CONVERSATION_DIALOG_PORTRAIT_SIZE: u32 = 128;
+
+/// The NPC shown in the conversation dialog portrait, which is rendered to image
+/// by a camera placed in front of their face.
+#[derive(Resource)]
+pub struct ConversationDialogPortrait {
+    pub npc_entity: Option<Entity>,
+    pub image: Handle<Image>,
+}
+
+impl FromWorld for ConversationDialogPortrait {
+    fn from_world(world: &mut World) -> Self {
+        let size = Extent3d {
+            width: CONVERSATION_DIALOG_PORTRAIT_SIZE,
+            height: CONVERSATION_DIALOG_PORTRAIT_SIZE,
+            depth_or_array_layers: 1,
+        };
+        let mut image = Image {
+            texture_descriptor: TextureDescriptor {
+                label: Some("conversation_dialog_portrait"),
+                size,
+                dimension: TextureDimension::D2,
+                format: TextureFormat::Bgra8UnormSrgb,
+                mip_level_count: 1,
+                sample_count: 1,
+                usage: TextureUsages::TEXTURE_BINDING
+                    | TextureUsages::COPY_DST
+                    | TextureUsages::RENDER_ATTACHMENT,
+                view_formats: &[],
+            },
+            ..Default::default()
+        };
+        image.resize(size);
+
+        Self {
+            npc_entity: None,
+            image: world.resource_mut::<Assets<Image>>().add(image),
+        }
+    }
+}
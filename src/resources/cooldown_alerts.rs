@@ -0,0 +1,103 @@
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::CharacterStoreKey;
+
+/// How the player is alerted, the skills and status effects which alert are
+/// chosen per character in CooldownAlerts.
+#[derive(Resource)]
+pub struct CooldownAlertSettings {
+    pub hotbar_pulse: bool,
+    pub toast: bool,
+    pub sound: bool,
+    pub sound_id: u16,
+    /// How long before a status effect expires to warn about it.
+    pub status_effect_warning: Duration,
+}
+
+impl Default for CooldownAlertSettings {
+    fn default() -> Self {
+        Self {
+            hotbar_pulse: true,
+            toast: true,
+            sound: true,
+            sound_id: 16,
+            status_effect_warning: Duration::from_secs(10),
+        }
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+struct CooldownAlertsStore {
+    skills: Vec<u16>,
+    status_effects: Vec<u16>,
+}
+
+/// The skills which alert when their cooldown finishes and the status effects
+/// which warn before they expire for the current character, stored in the
+/// user's data directory.
+#[derive(Default, Resource)]
+pub struct CooldownAlerts {
+    pub store_key: Option<CharacterStoreKey>,
+    pub skills: HashSet<u16>,
+    pub status_effects: HashSet<u16>,
+    /// Remaining time of the hotbar pulse for each skill which just became ready.
+    pub ready_pulses: HashMap<u16, f32>,
+}
+
+impl CooldownAlerts {
+    pub fn load(store_key: CharacterStoreKey) -> Self {
+        let store: CooldownAlertsStore = store_key.load("cooldown_alerts");
+
+        Self {
+            store_key: Some(store_key),
+            skills: store.skills.into_iter().collect(),
+            status_effects: store.status_effects.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    fn save(&self) {
+        let Some(store_key) = self.store_key.as_ref() else {
+            return;
+        };
+
+        let mut store = CooldownAlertsStore {
+            skills: self.skills.iter().copied().collect(),
+            status_effects: self.status_effects.iter().copied().collect(),
+        };
+        store.skills.sort_unstable();
+        store.status_effects.sort_unstable();
+        store_key.save("cooldown_alerts", &store);
+    }
+
+    pub fn set_skill(&mut self, skill_id: u16, enabled: bool) {
+        let changed = if enabled {
+            self.skills.insert(skill_id)
+        } else {
+            self.skills.remove(&skill_id)
+        };
+
+        if changed {
+            self.save();
+        }
+    }
+
+    pub fn set_status_effect(&mut self, status_effect_id: u16, enabled: bool) {
+        let changed = if enabled {
+            self.status_effects.insert(status_effect_id)
+        } else {
+            self.status_effects.remove(&status_effect_id)
+        };
+
+        if changed {
+            self.save();
+        }
+    }
+}
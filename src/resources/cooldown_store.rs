@@ -0,0 +1,101 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use bevy::prelude::Resource;
+use enum_map::EnumMap;
+
+use crate::{
+    components::{ConsumableCooldownGroup, Cooldowns},
+    resources::CharacterStoreKey,
+};
+
+/// A cooldown stored as the time it expires, so the remaining duration stays
+/// accurate while the Cooldowns component does not exist or is not updated.
+#[derive(Copy, Clone)]
+struct StoredCooldown {
+    expire_time: Instant,
+    total: Duration,
+}
+
+impl StoredCooldown {
+    fn new(cooldown: Option<&(Duration, Duration)>, now: Instant) -> Option<Self> {
+        cooldown.map(|&(current, total)| Self {
+            expire_time: now + current,
+            total,
+        })
+    }
+
+    fn restore(&self, now: Instant) -> Option<(Duration, Duration)> {
+        let remaining = self.expire_time.checked_duration_since(now)?;
+        if remaining.is_zero() {
+            None
+        } else {
+            Some((remaining, self.total))
+        }
+    }
+}
+
+#[derive(Default)]
+struct StoredCooldowns {
+    global: Option<StoredCooldown>,
+    skills: HashMap<u16, StoredCooldown>,
+    skill_groups: HashMap<usize, StoredCooldown>,
+    consumable_items: EnumMap<ConsumableCooldownGroup, Option<StoredCooldown>>,
+}
+
+/// Stores the player cooldowns by character so they survive zone changes
+/// and returning to character select, matching the cooldowns the server enforces.
+#[derive(Default, Resource)]
+pub struct CooldownStore {
+    characters: HashMap<CharacterStoreKey, StoredCooldowns>,
+}
+
+impl CooldownStore {
+    pub fn store(&mut self, store_key: &CharacterStoreKey, cooldowns: &Cooldowns, now: Instant) {
+        let stored = StoredCooldowns {
+            global: StoredCooldown::new(cooldowns.global.as_ref(), now),
+            skills: cooldowns
+                .skills
+                .iter()
+                .filter_map(|(skill_id, cooldown)| {
+                    Some((*skill_id, StoredCooldown::new(cooldown.as_ref(), now)?))
+                })
+                .collect(),
+            skill_groups: cooldowns
+                .skill_groups
+                .iter()
+                .filter_map(|(skill_group, cooldown)| {
+                    Some((*skill_group, StoredCooldown::new(cooldown.as_ref(), now)?))
+                })
+                .collect(),
+            consumable_items: EnumMap::from_fn(|group| {
+                StoredCooldown::new(cooldowns.consumable_items[group].as_ref(), now)
+            }),
+        };
+
+        self.characters.insert(store_key.clone(), stored);
+    }
+
+    pub fn restore(&self, store_key: &CharacterStoreKey, now: Instant) -> Option<Cooldowns> {
+        let stored = self.characters.get(store_key)?;
+
+        Some(Cooldowns {
+            global: stored.global.and_then(|cooldown| cooldown.restore(now)),
+            skills: stored
+                .skills
+                .iter()
+                .map(|(skill_id, cooldown)| (*skill_id, cooldown.restore(now)))
+                .collect(),
+            skill_groups: stored
+                .skill_groups
+                .iter()
+                .map(|(skill_group, cooldown)| (*skill_group, cooldown.restore(now)))
+                .collect(),
+            consumable_items: EnumMap::from_fn(|group| {
+                stored.consumable_items[group].and_then(|cooldown| cooldown.restore(now))
+            }),
+        })
+    }
+}
@@ -1,23 +1,92 @@
+use std::time::Duration;
+
 use bevy::{
+    math::Vec4,
     prelude::{
-        AssetServer, Assets, BuildChildren, Commands, ComputedVisibility, GlobalTransform, Handle,
-        Resource, Transform, Vec3, Visibility,
+        AssetServer, Assets, Commands, ComputedVisibility, Entity, GlobalTransform, Handle,
+        Resource, Transform, Vec3, Visibility, World,
     },
     render::primitives::Aabb,
 };
+use enum_map::{enum_map, Enum, EnumMap};
 
 use crate::{
     animation::{TransformAnimation, ZmoAsset},
-    components::DamageDigits,
+    components::{DamageDigits, ModelHeight},
     render::{DamageDigitMaterial, DamageDigitRenderData},
+    resources::EntityPool,
 };
 
+/// Used for models which have no collider to measure their height from.
+const DEFAULT_MODEL_HEIGHT: f32 = 1.8;
+
+/// The gap left above the head dummy bone, as a fraction of the model height.
+const HEAD_ANCHOR_GAP: f32 = 0.1;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Enum)]
+pub enum DamageDigitType {
+    Dealt,
+    Critical,
+    Taken,
+    Miss,
+    Heal,
+}
+
+#[derive(Copy, Clone)]
+pub struct DamageDigitStyle {
+    pub color: [f32; 3],
+    pub scale: f32,
+}
+
+impl DamageDigitStyle {
+    pub fn new(color: [f32; 3], scale: f32) -> Self {
+        Self { color, scale }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Enum)]
+pub enum StatusEffectTextType {
+    Buff,
+    Debuff,
+    Resisted,
+}
+
+pub struct StatusEffectText {
+    pub text: String,
+    pub text_type: StatusEffectTextType,
+    pub position: Vec3,
+    pub spawn_time: Duration,
+}
+
+struct MergedDamageDigits {
+    target_entity: Entity,
+    digits_entity: Entity,
+    digit_type: DamageDigitType,
+    spawn_time: Duration,
+}
+
 #[derive(Resource)]
 pub struct DamageDigitsSpawner {
     pub texture_damage: Handle<DamageDigitMaterial>,
     pub texture_damage_player: Handle<DamageDigitMaterial>,
     pub texture_miss: Handle<DamageDigitMaterial>,
     pub motion: Handle<ZmoAsset>,
+
+    pub styles: EnumMap<DamageDigitType, DamageDigitStyle>,
+
+    /// When enabled, hits up to merge_max_damage which land on the same target
+    /// within merge_window are added to the previous digits instead of spawning new ones.
+    pub merge_small_hits: bool,
+    pub merge_max_damage: u32,
+    pub merge_window: Duration,
+    merged_digits: Vec<MergedDamageDigits>,
+
+    pool: EntityPool<DamageDigits>,
+
+    /// When enabled, text is shown above the target when a status effect is applied or resisted.
+    pub status_effect_text_enabled: bool,
+    pub status_effect_text_colors: EnumMap<StatusEffectTextType, [f32; 3]>,
+    pub status_effect_texts: Vec<StatusEffectText>,
 }
 
 impl DamageDigitsSpawner {
@@ -36,39 +105,95 @@ impl DamageDigitsSpawner {
                 texture: asset_server.load("3DDATA/EFFECT/SPECIAL/DIGITNUMBERMISS.DDS"),
             }),
             motion: asset_server.load("3DDATA/EFFECT/SPECIAL/HIT_FIGURE_01.ZMO"),
+            styles: enum_map! {
+                DamageDigitType::Dealt => DamageDigitStyle::new([1.0, 1.0, 1.0], 1.0),
+                DamageDigitType::Critical => DamageDigitStyle::new([1.0, 0.85, 0.3], 1.4),
+                DamageDigitType::Taken => DamageDigitStyle::new([1.0, 1.0, 1.0], 1.0),
+                DamageDigitType::Miss => DamageDigitStyle::new([1.0, 1.0, 1.0], 1.0),
+                DamageDigitType::Heal => DamageDigitStyle::new([0.4, 1.0, 0.4], 1.0),
+            },
+            merge_small_hits: false,
+            merge_max_damage: 50,
+            merge_window: Duration::from_millis(500),
+            merged_digits: Vec::new(),
+            pool: EntityPool::default(),
+            status_effect_text_enabled: true,
+            status_effect_text_colors: enum_map! {
+                StatusEffectTextType::Buff => [0.5, 0.8, 1.0],
+                StatusEffectTextType::Debuff => [1.0, 0.4, 0.3],
+                StatusEffectTextType::Resisted => [0.8, 0.8, 0.8],
+            },
+            status_effect_texts: Vec::new(),
+        }
+    }
+
+    pub fn get_spawn_position(global_transform: &GlobalTransform, model_height: f32) -> Vec3 {
+        let (scale, _, translation) = global_transform.to_scale_rotation_translation();
+        translation + Vec3::new(0.0, model_height * scale.y, 0.0)
+    }
+
+    /// Spawns just above the head dummy bone when there is one, so the digits follow the
+    /// model when it is crouched or knocked down, otherwise at the top of the model.
+    pub fn get_anchored_spawn_position(
+        global_transform: &GlobalTransform,
+        model_height: Option<&ModelHeight>,
+        head_transform: Option<&GlobalTransform>,
+    ) -> Vec3 {
+        let model_height =
+            model_height.map_or(DEFAULT_MODEL_HEIGHT, |model_height| model_height.height);
+
+        if let Some(head_transform) = head_transform {
+            let (scale, _, _) = global_transform.to_scale_rotation_translation();
+            head_transform.translation()
+                + Vec3::new(0.0, model_height * HEAD_ANCHOR_GAP * scale.y, 0.0)
+        } else {
+            Self::get_spawn_position(global_transform, model_height)
         }
     }
 
     pub fn spawn(
-        &self,
+        &mut self,
         commands: &mut Commands,
-        global_transform: &GlobalTransform,
-        model_height: f32,
+        now: Duration,
+        target_entity: Entity,
+        position: Vec3,
         damage: u32,
-        is_damage_player: bool,
+        digit_type: DamageDigitType,
     ) {
-        let (scale, _, translation) = global_transform.to_scale_rotation_translation();
+        self.merged_digits
+            .retain(|merged| now.saturating_sub(merged.spawn_time) < self.merge_window);
 
-        // We need to spawn inside a parent entity for positioning because the ActiveMotion will set the translation absolutely
-        commands
-            .spawn((
-                Transform::from_translation(
-                    translation + Vec3::new(0.0, model_height * scale.y, 0.0),
-                ),
-                GlobalTransform::default(),
-                Visibility::default(),
-                ComputedVisibility::default(),
-            ))
-            .with_children(|child_builder| {
-                child_builder.spawn((
-                    DamageDigits { damage },
+        if self.merge_small_hits && damage > 0 && damage <= self.merge_max_damage {
+            if let Some(merged) = self.merged_digits.iter().find(|merged| {
+                merged.target_entity == target_entity && merged.digit_type == digit_type
+            }) {
+                let digits_entity = merged.digits_entity;
+                commands.add(move |world: &mut World| {
+                    if let Some(mut damage_digits) = world.get_mut::<DamageDigits>(digits_entity) {
+                        damage_digits.damage += damage;
+                    }
+                });
+                return;
+            }
+        }
+
+        let style = &self.styles[digit_type];
+        let digits_entity = self
+            .pool
+            .spawn(
+                commands,
+                (
+                    DamageDigits {
+                        damage,
+                        color: Vec4::new(style.color[0], style.color[1], style.color[2], 1.0),
+                        scale: style.scale,
+                        position,
+                    },
                     DamageDigitRenderData::new(4),
-                    if damage == 0 {
-                        self.texture_miss.clone_weak()
-                    } else if is_damage_player {
-                        self.texture_damage_player.clone_weak()
-                    } else {
-                        self.texture_damage.clone_weak()
+                    match digit_type {
+                        DamageDigitType::Miss => self.texture_miss.clone_weak(),
+                        DamageDigitType::Taken => self.texture_damage_player.clone_weak(),
+                        _ => self.texture_damage.clone_weak(),
                     },
                     TransformAnimation::once(self.motion.clone_weak()),
                     Transform::default(),
@@ -76,7 +201,43 @@ impl DamageDigitsSpawner {
                     Aabb::default(),
                     Visibility::default(),
                     ComputedVisibility::default(),
-                ));
+                ),
+            )
+            .id();
+
+        if self.merge_small_hits && damage > 0 && damage <= self.merge_max_damage {
+            self.merged_digits.push(MergedDamageDigits {
+                target_entity,
+                digits_entity,
+                digit_type,
+                spawn_time: now,
             });
+        }
+    }
+
+    /// Returns the digits entity to the pool once its animation has completed.
+    pub fn despawn(&mut self, commands: &mut Commands, digits_entity: Entity) {
+        self.merged_digits
+            .retain(|merged| merged.digits_entity != digits_entity);
+        self.pool.release(commands, digits_entity);
+    }
+
+    pub fn spawn_status_effect_text(
+        &mut self,
+        now: Duration,
+        position: Vec3,
+        text: String,
+        text_type: StatusEffectTextType,
+    ) {
+        if !self.status_effect_text_enabled {
+            return;
+        }
+
+        self.status_effect_texts.push(StatusEffectText {
+            text,
+            text_type,
+            position,
+            spawn_time: now,
+        });
     }
 }
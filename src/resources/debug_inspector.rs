@@ -1,7 +1,19 @@
-use bevy::prelude::{Entity, Resource};
+use bevy::{
+    ecs::component::ComponentId,
+    prelude::{Entity, Resource},
+    reflect::Reflect,
+};
+
+/// The reflected component values of an entity, used to revert any edits made
+/// in the entity inspector.
+pub struct DebugInspectorSnapshot {
+    pub entity: Entity,
+    pub components: Vec<(ComponentId, Box<dyn Reflect>)>,
+}
 
 #[derive(Resource, Default)]
 pub struct DebugInspector {
     pub enable_picking: bool,
     pub entity: Option<Entity>,
+    pub snapshot: Option<DebugInspectorSnapshot>,
 }
@@ -11,6 +11,26 @@ const DEBUG_RENDER_COLOR_LIST: [Color; 8] = [
     Color::BLACK,
 ];
 
+pub struct DebugRenderWalkability {
+    pub enabled: bool,
+    pub show_grid: bool,
+    pub radius: f32,
+    pub spacing: f32,
+    pub max_slope_degrees: f32,
+}
+
+impl Default for DebugRenderWalkability {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            show_grid: false,
+            radius: 30.0,
+            spacing: 1.0,
+            max_slope_degrees: 45.0,
+        }
+    }
+}
+
 #[derive(Default, Resource)]
 pub struct DebugRenderConfig {
     pub colliders: bool,
@@ -18,6 +38,7 @@ pub struct DebugRenderConfig {
     pub bone_up: bool,
     pub directional_light_frustum: bool,
     pub directional_light_frustum_freeze: bool,
+    pub walkability: DebugRenderWalkability,
 }
 
 impl DebugRenderConfig {
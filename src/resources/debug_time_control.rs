@@ -0,0 +1,34 @@
+use bevy::prelude::Resource;
+
+pub const DEBUG_TIME_SCALE_MIN: f32 = 0.1;
+pub const DEBUG_TIME_SCALE_MAX: f32 = 5.0;
+
+/// Applied to the bevy virtual time by debug_time_control_system, so every
+/// system using Res<Time> is scaled, paused and stepped together.
+#[derive(Resource)]
+pub struct DebugTimeControl {
+    pub time_scale: f32,
+    pub paused: bool,
+    pub step_frames: u32,
+}
+
+impl Default for DebugTimeControl {
+    fn default() -> Self {
+        Self {
+            time_scale: 1.0,
+            paused: false,
+            step_frames: 0,
+        }
+    }
+}
+
+impl DebugTimeControl {
+    pub fn step(&mut self, num_frames: u32) {
+        self.paused = true;
+        self.step_frames += num_frames;
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
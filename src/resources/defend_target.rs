@@ -0,0 +1,7 @@
+use bevy::prelude::{Entity, Resource};
+
+/// When set, the player will automatically target whoever last attacked this entity.
+#[derive(Default, Resource)]
+pub struct DefendTarget {
+    pub entity: Option<Entity>,
+}
@@ -0,0 +1,175 @@
+use std::{collections::VecDeque, time::Instant};
+
+use bevy::{
+    math::Vec3,
+    prelude::{Entity, Resource},
+};
+
+use rose_game_common::messages::{server::ServerMessage, ClientEntityId};
+
+use crate::{components::Command, resources::ClientEntityList};
+
+pub const ENTITY_HISTORY_MIN_DURATION: f32 = 1.0;
+pub const ENTITY_HISTORY_MAX_DURATION: f32 = 60.0;
+
+pub enum EntityHistoryEvent {
+    Command(Command),
+    NextCommand(Option<Command>),
+    ServerMessage(String),
+}
+
+pub struct EntityHistoryPosition {
+    pub time: f32,
+    pub position: Vec3,
+    pub speed: f32,
+}
+
+/// Timeline of a single entity, recorded by entity_history_system and
+/// game_connection_system while the command viewer window is open.
+#[derive(Resource)]
+pub struct EntityHistory {
+    pub entity: Option<Entity>,
+    pub duration: f32,
+    pub events: VecDeque<(f32, EntityHistoryEvent)>,
+    pub positions: VecDeque<EntityHistoryPosition>,
+    start_time: Instant,
+}
+
+impl Default for EntityHistory {
+    fn default() -> Self {
+        Self {
+            entity: None,
+            duration: 10.0,
+            events: VecDeque::new(),
+            positions: VecDeque::new(),
+            start_time: Instant::now(),
+        }
+    }
+}
+
+fn get_server_message_entity_ids(message: &ServerMessage) -> Vec<ClientEntityId> {
+    match message {
+        ServerMessage::MoveEntity {
+            entity_id,
+            target_entity_id,
+            ..
+        } => std::iter::once(*entity_id)
+            .chain(*target_entity_id)
+            .collect(),
+        ServerMessage::AttackEntity {
+            entity_id,
+            target_entity_id,
+            ..
+        }
+        | ServerMessage::CastSkillTargetEntity {
+            entity_id,
+            target_entity_id,
+            ..
+        } => vec![*entity_id, *target_entity_id],
+        ServerMessage::DamageEntity {
+            attacker_entity_id,
+            defender_entity_id,
+            ..
+        } => vec![*attacker_entity_id, *defender_entity_id],
+        ServerMessage::ApplySkillEffect {
+            entity_id,
+            caster_entity_id,
+            ..
+        } => vec![*entity_id, *caster_entity_id],
+        ServerMessage::RemoveEntities { entity_ids } => entity_ids.clone(),
+        ServerMessage::AdjustPosition { entity_id, .. }
+        | ServerMessage::StopMoveEntity { entity_id, .. }
+        | ServerMessage::Teleport { entity_id, .. }
+        | ServerMessage::UpdateSpeed { entity_id, .. }
+        | ServerMessage::UpdateStatusEffects { entity_id, .. }
+        | ServerMessage::UseEmote { entity_id, .. }
+        | ServerMessage::SitToggle { entity_id }
+        | ServerMessage::UseItem { entity_id, .. }
+        | ServerMessage::CastSkillSelf { entity_id, .. }
+        | ServerMessage::CastSkillTargetPosition { entity_id, .. }
+        | ServerMessage::CancelCastingSkill { entity_id, .. }
+        | ServerMessage::StartCastingSkill { entity_id }
+        | ServerMessage::FinishCastingSkill { entity_id, .. }
+        | ServerMessage::LevelUpEntity { entity_id }
+        | ServerMessage::UpdateLevel { entity_id, .. } => vec![*entity_id],
+        _ => Vec::new(),
+    }
+}
+
+impl EntityHistory {
+    pub fn elapsed(&self) -> f32 {
+        self.start_time.elapsed().as_secs_f32()
+    }
+
+    pub fn set_entity(&mut self, entity: Option<Entity>) {
+        if self.entity != entity {
+            self.entity = entity;
+            self.clear();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.events.clear();
+        self.positions.clear();
+    }
+
+    pub fn push_event(&mut self, event: EntityHistoryEvent) {
+        let time = self.elapsed();
+        self.events.push_back((time, event));
+    }
+
+    pub fn push_position(&mut self, position: Vec3) {
+        let time = self.elapsed();
+        let speed = self.positions.back().map_or(0.0, |last| {
+            let delta_time = time - last.time;
+            if delta_time > 0.0 {
+                position.distance(last.position) / delta_time
+            } else {
+                0.0
+            }
+        });
+
+        self.positions.push_back(EntityHistoryPosition {
+            time,
+            position,
+            speed,
+        });
+    }
+
+    pub fn record_server_message(
+        &mut self,
+        client_entity_list: &ClientEntityList,
+        message: &ServerMessage,
+    ) {
+        let Some(entity) = self.entity else {
+            return;
+        };
+
+        if get_server_message_entity_ids(message)
+            .into_iter()
+            .any(|entity_id| client_entity_list.get(entity_id) == Some(entity))
+        {
+            self.push_event(EntityHistoryEvent::ServerMessage(format!("{:?}", message)));
+        }
+    }
+
+    pub fn remove_expired(&mut self) {
+        let expire_time = self.elapsed() - self.duration;
+
+        while self
+            .events
+            .front()
+            .map_or(false, |(time, _)| *time < expire_time)
+        {
+            self.events.pop_front();
+        }
+
+        while self
+            .positions
+            .front()
+            .map_or(false, |position| position.time < expire_time)
+        {
+            self.positions.pop_front();
+        }
+    }
+}
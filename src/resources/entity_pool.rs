@@ -0,0 +1,75 @@
+use std::marker::PhantomData;
+
+use bevy::{
+    ecs::system::EntityCommands,
+    hierarchy::{BuildChildren, DespawnRecursiveExt},
+    prelude::{Bundle, Commands, Component, Entity, Resource, Visibility},
+};
+
+/// Maximum number of released entities kept for reuse by a pool.
+pub const ENTITY_POOL_SIZE: usize = 64;
+
+/// Released entities which are reused instead of spawning new ones, for objects such as
+/// projectiles and effects which are spawned and despawned constantly.
+///
+/// The component T marks an entity as in use, it is removed on release so a released entity
+/// is ignored by the systems which query for it. Reusing an entity inserts the whole bundle
+/// again to reset it, so the bundle must contain every component the entity was spawned with.
+#[derive(Resource)]
+pub struct EntityPool<T: Component> {
+    free: Vec<Entity>,
+    max_free: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Component> Default for EntityPool<T> {
+    fn default() -> Self {
+        Self::new(ENTITY_POOL_SIZE)
+    }
+}
+
+impl<T: Component> EntityPool<T> {
+    pub fn new(max_free: usize) -> Self {
+        Self {
+            free: Vec::with_capacity(max_free),
+            max_free,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Spawns an entity with the bundle, reusing a released entity when there is one.
+    pub fn spawn<'w, 's, 'a>(
+        &mut self,
+        commands: &'a mut Commands<'w, 's>,
+        bundle: impl Bundle,
+    ) -> EntityCommands<'w, 's, 'a> {
+        while let Some(entity) = self.free.pop() {
+            // A released entity may have been despawned since by another system, get_entity
+            // checks the entity still exists so stale ids are dropped instead of reused
+            if commands.get_entity(entity).is_some() {
+                let mut entity_commands = commands.entity(entity);
+                entity_commands.insert(bundle);
+                return entity_commands;
+            }
+        }
+
+        commands.spawn(bundle)
+    }
+
+    /// Hides the entity and keeps it for reuse, detaching it from its parent and despawning
+    /// its children. The entity is despawned instead once the pool is full.
+    pub fn release(&mut self, commands: &mut Commands, entity: Entity) {
+        if self.free.len() >= self.max_free {
+            commands.entity(entity).despawn_recursive();
+            return;
+        }
+
+        commands
+            .entity(entity)
+            .despawn_descendants()
+            .remove_parent()
+            .remove::<T>()
+            .insert(Visibility::Hidden);
+        self.free.push(entity);
+    }
+}
@@ -0,0 +1,180 @@
+use std::time::{Duration, Instant};
+
+use bevy::prelude::Resource;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+const EVENT_SCHEDULE_REFRESH_INTERVAL: Duration = Duration::from_secs(15 * 60);
+const EVENT_SCHEDULE_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+const EVENT_REMINDER_MINUTES: i64 = 5;
+
+#[derive(Deserialize)]
+struct EventScheduleFile {
+    events: Vec<EventScheduleFileEvent>,
+}
+
+#[derive(Deserialize)]
+struct EventScheduleFileEvent {
+    name: String,
+    #[serde(default)]
+    description: String,
+    start: String,
+    #[serde(default)]
+    end: Option<String>,
+}
+
+pub struct ScheduledEvent {
+    pub name: String,
+    pub description: String,
+    pub start: DateTime<Utc>,
+    pub end: Option<DateTime<Utc>>,
+
+    /// Whether the user asked to be reminded shortly before the event starts.
+    pub remind: bool,
+    pub reminded: bool,
+}
+
+impl ScheduledEvent {
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.start <= now && self.end.map_or(false, |end| now < end)
+    }
+
+    /// Events without an end time are finished once they have started.
+    pub fn has_ended(&self, now: DateTime<Utc>) -> bool {
+        self.end.map_or(self.start <= now, |end| end <= now)
+    }
+}
+
+fn parse_event_time(time: &str) -> anyhow::Result<DateTime<Utc>> {
+    Ok(DateTime::parse_from_rfc3339(time)?.with_timezone(&Utc))
+}
+
+fn fetch_event_schedule(url: &str) -> anyhow::Result<Vec<ScheduledEvent>> {
+    let json = ureq::get(url)
+        .timeout(EVENT_SCHEDULE_REQUEST_TIMEOUT)
+        .call()?
+        .into_string()?;
+    let file: EventScheduleFile = serde_json::from_str(&json)?;
+
+    let mut events = Vec::with_capacity(file.events.len());
+    for event in file.events {
+        events.push(ScheduledEvent {
+            start: parse_event_time(&event.start)?,
+            end: event.end.as_deref().map(parse_event_time).transpose()?,
+            name: event.name,
+            description: event.description,
+            remind: false,
+            reminded: false,
+        });
+    }
+    events.sort_by_key(|event| event.start);
+    Ok(events)
+}
+
+/// Server events shown in the calendar, fetched from the JSON schedule at the
+/// `event_schedule_url` of the selected server.
+#[derive(Default, Resource)]
+pub struct EventSchedule {
+    pub url: String,
+    pub events: Vec<ScheduledEvent>,
+    pub error: Option<String>,
+
+    /// Names of events which are about to start, to be shown as reminders.
+    pub pending_reminders: Vec<String>,
+
+    last_fetch: Option<Instant>,
+    fetch_rx: Option<crossbeam_channel::Receiver<Result<Vec<ScheduledEvent>, String>>>,
+}
+
+impl EventSchedule {
+    pub fn is_fetching(&self) -> bool {
+        self.fetch_rx.is_some()
+    }
+
+    pub fn needs_refresh(&self) -> bool {
+        !self.url.is_empty()
+            && !self.is_fetching()
+            && self.last_fetch.map_or(true, |last_fetch| {
+                last_fetch.elapsed() >= EVENT_SCHEDULE_REFRESH_INTERVAL
+            })
+    }
+
+    /// Fetches the schedule on a background thread, the result is applied by
+    /// `receive` once it has arrived.
+    pub fn fetch(&mut self) {
+        if self.url.is_empty() || self.is_fetching() {
+            return;
+        }
+
+        let (fetch_tx, fetch_rx) = crossbeam_channel::bounded(1);
+        let url = self.url.clone();
+        std::thread::spawn(move || {
+            fetch_tx
+                .send(fetch_event_schedule(&url).map_err(|error| error.to_string()))
+                .ok();
+        });
+
+        self.fetch_rx = Some(fetch_rx);
+        self.last_fetch = Some(Instant::now());
+    }
+
+    pub fn receive(&mut self) {
+        let Some(result) = self
+            .fetch_rx
+            .as_ref()
+            .and_then(|fetch_rx| fetch_rx.try_recv().ok())
+        else {
+            return;
+        };
+        self.fetch_rx = None;
+
+        match result {
+            Ok(mut events) => {
+                // Keep the reminders of events which are still in the schedule
+                for event in events.iter_mut() {
+                    if let Some(previous) = self.events.iter().find(|previous| {
+                        previous.name == event.name && previous.start == event.start
+                    }) {
+                        event.remind = previous.remind;
+                        event.reminded = previous.reminded;
+                    }
+                }
+
+                self.events = events;
+                self.error = None;
+            }
+            Err(error) => {
+                log::warn!(
+                    "Failed to fetch event schedule from {}: {}",
+                    self.url,
+                    error
+                );
+                self.error = Some(error);
+            }
+        }
+    }
+
+    pub fn update_reminders(&mut self, now: DateTime<Utc>) {
+        let reminder_time = chrono::Duration::minutes(EVENT_REMINDER_MINUTES);
+
+        for event in self.events.iter_mut() {
+            if event.remind && !event.reminded && event.start - now <= reminder_time {
+                event.reminded = true;
+
+                if !event.has_ended(now) {
+                    self.pending_reminders.push(event.name.clone());
+                }
+            }
+        }
+    }
+
+    /// Use the schedule from a different url, e.g. after selecting another server.
+    pub fn set_url(&mut self, url: &str) {
+        if self.url != url {
+            *self = Self {
+                url: url.to_string(),
+                ..Default::default()
+            };
+        }
+    }
+}
@@ -0,0 +1,232 @@
+use std::collections::HashMap;
+
+use bevy::{
+    prelude::{Assets, Entity, FromWorld, Handle, Image, Mesh, Mut, Resource, World},
+    render::render_resource::{BlendFactor, BlendOperation, TextureFormat},
+};
+use enum_map::{enum_map, Enum, EnumMap};
+
+use rose_data::ZoneId;
+
+use super::item_drop_effects::{create_gradient_image, create_quad_mesh};
+use crate::render::EffectMeshMaterial;
+
+/// Number of materials each effect fades through, from faintest to strongest.
+pub const FOOTSTEP_EFFECT_FADE_LEVELS: usize = 6;
+
+/// Maximum number of footstep effects alive at once, scaled by the particle density setting.
+pub const FOOTSTEP_EFFECT_POOL_SIZE: usize = 96;
+
+// Used when the terrain colour is not known, such as when standing on an object
+const DEFAULT_SURFACE_COLOUR: [u8; 3] = [150, 135, 110];
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Enum)]
+pub enum FootstepEffectKind {
+    Dust,
+    Decal,
+}
+
+pub type FootstepFadeMaterials = [Handle<EffectMeshMaterial>; FOOTSTEP_EFFECT_FADE_LEVELS];
+
+/// The faded materials for each kind of footstep effect on one surface.
+pub struct FootstepSurfaceMaterials {
+    pub materials: EnumMap<FootstepEffectKind, FootstepFadeMaterials>,
+}
+
+/// The meshes and materials for the dust puffs and footprints left by running characters and
+/// large monsters, and the pool of entities used to show them.
+#[derive(Resource)]
+pub struct FootstepEffects {
+    pub meshes: EnumMap<FootstepEffectKind, Handle<Mesh>>,
+    pub default_materials: FootstepSurfaceMaterials,
+
+    /// Materials coloured to match each terrain tile texture of the current zone.
+    pub zone_id: Option<ZoneId>,
+    pub tile_materials: HashMap<usize, FootstepSurfaceMaterials>,
+
+    pub pool: Vec<Entity>,
+    pub next_pool_index: usize,
+}
+
+impl FootstepEffects {
+    pub fn get_materials(
+        &self,
+        tile_index: Option<usize>,
+        kind: FootstepEffectKind,
+    ) -> &FootstepFadeMaterials {
+        let surface_materials = tile_index
+            .and_then(|tile_index| self.tile_materials.get(&tile_index))
+            .unwrap_or(&self.default_materials);
+        &surface_materials.materials[kind]
+    }
+}
+
+fn create_alpha_material(texture: Handle<Image>, intensity: f32) -> EffectMeshMaterial {
+    EffectMeshMaterial {
+        base_texture: Some(texture),
+        animation_texture: None,
+        alpha_enabled: true,
+        alpha_test: false,
+        two_sided: true,
+        z_test_enabled: true,
+        z_write_enabled: false,
+        blend_op: BlendOperation::Add,
+        src_blend_factor: BlendFactor::SrcAlpha,
+        dst_blend_factor: BlendFactor::OneMinusSrcAlpha,
+        intensity,
+    }
+}
+
+pub fn create_footstep_surface_materials(
+    images: &mut Assets<Image>,
+    materials: &mut Assets<EffectMeshMaterial>,
+    colour: [u8; 3],
+) -> FootstepSurfaceMaterials {
+    // Dust is a lighter soft puff, footprints are a darker print pressed into the ground
+    let dust_colour = colour.map(|x| (x as f32 * 0.7 + 255.0 * 0.3) as u8);
+    let decal_colour = colour.map(|x| (x as f32 * 0.45) as u8);
+
+    let dust_image = images.add(create_gradient_image(dust_colour, |u, v| {
+        let distance = ((u * 2.0 - 1.0).powi(2) + (v * 2.0 - 1.0).powi(2)).sqrt();
+        (1.0 - distance).powf(1.5) * 0.7
+    }));
+    let decal_image = images.add(create_gradient_image(decal_colour, |u, v| {
+        // An oval print, narrower towards the heel
+        let width = 0.7 + 0.3 * v;
+        let distance = (((u * 2.0 - 1.0) / width).powi(2) + (v * 2.0 - 1.0).powi(2)).sqrt();
+        ((1.0 - distance) * 4.0).min(1.0) * 0.6
+    }));
+
+    let mut create_faded_materials = |image: &Handle<Image>| {
+        std::array::from_fn(|level| {
+            materials.add(create_alpha_material(
+                image.clone(),
+                (level + 1) as f32 / FOOTSTEP_EFFECT_FADE_LEVELS as f32,
+            ))
+        })
+    };
+
+    FootstepSurfaceMaterials {
+        materials: enum_map! {
+            FootstepEffectKind::Dust => create_faded_materials(&dust_image),
+            FootstepEffectKind::Decal => create_faded_materials(&decal_image),
+        },
+    }
+}
+
+fn rgb565_to_rgb(colour: u16) -> [f32; 3] {
+    [
+        ((colour >> 11) & 0x1f) as f32 * 255.0 / 31.0,
+        ((colour >> 5) & 0x3f) as f32 * 255.0 / 63.0,
+        (colour & 0x1f) as f32 * 255.0 / 31.0,
+    ]
+}
+
+/// Returns the average colour of the top mip level of an image, supporting the uncompressed
+/// and block compressed formats used by terrain tile textures.
+pub fn get_image_average_colour(image: &Image) -> Option<[u8; 3]> {
+    let size = image.texture_descriptor.size;
+    let num_pixels = (size.width * size.height) as usize;
+    let mut sum = [0.0f32; 3];
+    let mut count = 0;
+
+    match image.texture_descriptor.format {
+        TextureFormat::Rgba8Unorm
+        | TextureFormat::Rgba8UnormSrgb
+        | TextureFormat::Bgra8Unorm
+        | TextureFormat::Bgra8UnormSrgb => {
+            let is_bgra = matches!(
+                image.texture_descriptor.format,
+                TextureFormat::Bgra8Unorm | TextureFormat::Bgra8UnormSrgb
+            );
+
+            for pixel in image.data.chunks_exact(4).take(num_pixels) {
+                let rgb = if is_bgra {
+                    [pixel[2], pixel[1], pixel[0]]
+                } else {
+                    [pixel[0], pixel[1], pixel[2]]
+                };
+                for (sum, value) in sum.iter_mut().zip(rgb) {
+                    *sum += value as f32;
+                }
+                count += 1;
+            }
+        }
+        format @ (TextureFormat::Bc1RgbaUnorm
+        | TextureFormat::Bc1RgbaUnormSrgb
+        | TextureFormat::Bc2RgbaUnorm
+        | TextureFormat::Bc2RgbaUnormSrgb
+        | TextureFormat::Bc3RgbaUnorm
+        | TextureFormat::Bc3RgbaUnormSrgb) => {
+            // Average the two endpoint colours of each block, which is close enough for a tint
+            let (block_size, colour_offset) = if matches!(
+                format,
+                TextureFormat::Bc1RgbaUnorm | TextureFormat::Bc1RgbaUnormSrgb
+            ) {
+                (8, 0)
+            } else {
+                (16, 8)
+            };
+            let num_blocks = (((size.width + 3) / 4) * ((size.height + 3) / 4)) as usize;
+
+            for block in image.data.chunks_exact(block_size).take(num_blocks) {
+                let colour0 = u16::from_le_bytes([block[colour_offset], block[colour_offset + 1]]);
+                let colour1 =
+                    u16::from_le_bytes([block[colour_offset + 2], block[colour_offset + 3]]);
+                for colour in [colour0, colour1] {
+                    for (sum, value) in sum.iter_mut().zip(rgb565_to_rgb(colour)) {
+                        *sum += value;
+                    }
+                    count += 1;
+                }
+            }
+        }
+        _ => return None,
+    }
+
+    if count == 0 {
+        return None;
+    }
+
+    Some(sum.map(|sum| (sum / count as f32) as u8))
+}
+
+impl FromWorld for FootstepEffects {
+    fn from_world(world: &mut World) -> Self {
+        // Three crossed vertical quads so the puff looks round from every direction
+        let dust_quads: Vec<[[f32; 3]; 4]> = (0..3)
+            .map(|i| {
+                let angle = i as f32 * std::f32::consts::PI / 3.0;
+                let (x, z) = (angle.cos() * 0.5, angle.sin() * 0.5);
+                [[-x, 0.0, -z], [x, 0.0, z], [x, 1.0, z], [-x, 1.0, -z]]
+            })
+            .collect();
+        let decal_mesh = create_quad_mesh(&[[
+            [-0.5, 0.02, -0.5],
+            [0.5, 0.02, -0.5],
+            [0.5, 0.02, 0.5],
+            [-0.5, 0.02, 0.5],
+        ]]);
+
+        let mut meshes = world.resource_mut::<Assets<Mesh>>();
+        let dust_mesh = meshes.add(create_quad_mesh(&dust_quads));
+        let decal_mesh = meshes.add(decal_mesh);
+
+        let default_materials = world.resource_scope(|world, mut images: Mut<Assets<Image>>| {
+            let mut materials = world.resource_mut::<Assets<EffectMeshMaterial>>();
+            create_footstep_surface_materials(&mut images, &mut materials, DEFAULT_SURFACE_COLOUR)
+        });
+
+        Self {
+            meshes: enum_map! {
+                FootstepEffectKind::Dust => dust_mesh.clone(),
+                FootstepEffectKind::Decal => decal_mesh.clone(),
+            },
+            default_materials,
+            zone_id: None,
+            tile_materials: HashMap::new(),
+            pool: Vec::with_capacity(FOOTSTEP_EFFECT_POOL_SIZE),
+            next_pool_index: 0,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use bevy::prelude::Resource;
+use serde::{Deserialize, Serialize};
+
+use crate::resources::CharacterStoreKey;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Friend {
+    pub name: String,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+struct FriendListStore {
+    friends: Vec<Friend>,
+}
+
+/// Friends of the current character, stored in the user's data directory
+/// alongside the waypoints.
+#[derive(Default, Resource)]
+pub struct FriendList {
+    pub store_key: Option<CharacterStoreKey>,
+    pub friends: Vec<Friend>,
+}
+
+impl FriendList {
+    pub fn load(store_key: CharacterStoreKey) -> Self {
+        let store: FriendListStore = store_key.load("friends");
+
+        Self {
+            store_key: Some(store_key),
+            friends: store.friends,
+        }
+    }
+
+    pub fn save(&self) {
+        if let Some(store_key) = self.store_key.as_ref() {
+            store_key.save(
+                "friends",
+                &FriendListStore {
+                    friends: self.friends.clone(),
+                },
+            );
+        }
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.friends
+            .iter()
+            .any(|friend| friend.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Returns false if the name is empty or already a friend.
+    pub fn add(&mut self, name: &str) -> bool {
+        let name = name.trim();
+        if name.is_empty() || self.contains(name) {
+            return false;
+        }
+
+        self.friends.push(Friend {
+            name: name.to_string(),
+        });
+        self.save();
+        true
+    }
+
+    pub fn remove(&mut self, name: &str) {
+        self.friends
+            .retain(|friend| !friend.name.eq_ignore_ascii_case(name));
+        self.save();
+    }
+}
@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+use bevy::{
+    prelude::Resource,
+    time::{Timer, TimerMode},
+};
+
+pub const GAME_RECONNECT_MAX_ATTEMPTS: u32 = 5;
+
+const GAME_RECONNECT_DELAY: Duration = Duration::from_secs(5);
+const GAME_RECONNECT_MAX_DELAY: Duration = Duration::from_secs(60);
+const GAME_RECONNECT_ATTEMPT_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub enum GameReconnectState {
+    /// Waiting before starting the next attempt.
+    Countdown(Timer),
+    Login,
+    WaitServerList,
+    WaitCharacterList,
+    JoiningGame,
+    /// No more attempts will be made, the user can only return to login.
+    Failed,
+}
+
+/// Logs back in with the same account, server and character after the game
+/// server connection is lost.
+#[derive(Resource)]
+pub struct GameReconnect {
+    pub username: String,
+    pub password: String,
+    pub server_id: usize,
+    pub channel_id: usize,
+    pub character_name: String,
+    pub attempt: u32,
+    pub attempt_timeout: Timer,
+    pub state: GameReconnectState,
+}
+
+impl GameReconnect {
+    pub fn new(
+        username: String,
+        password: String,
+        server_id: usize,
+        channel_id: usize,
+        character_name: String,
+    ) -> Self {
+        Self {
+            username,
+            password,
+            server_id,
+            channel_id,
+            character_name,
+            attempt: 0,
+            attempt_timeout: Timer::new(GAME_RECONNECT_ATTEMPT_TIMEOUT, TimerMode::Once),
+            state: GameReconnectState::Countdown(Timer::new(GAME_RECONNECT_DELAY, TimerMode::Once)),
+        }
+    }
+
+    pub fn is_attempting(&self) -> bool {
+        !matches!(
+            self.state,
+            GameReconnectState::Countdown(_) | GameReconnectState::Failed
+        )
+    }
+
+    pub fn skip_countdown(&mut self) {
+        if let GameReconnectState::Countdown(timer) = &mut self.state {
+            timer.set_elapsed(timer.duration());
+        }
+    }
+
+    pub fn start_attempt(&mut self) {
+        self.attempt += 1;
+        self.attempt_timeout.reset();
+        self.state = GameReconnectState::Login;
+    }
+
+    /// Waits before trying again, each failed attempt doubles the delay.
+    pub fn fail_attempt(&mut self) {
+        if self.attempt >= GAME_RECONNECT_MAX_ATTEMPTS {
+            self.state = GameReconnectState::Failed;
+            return;
+        }
+
+        let delay = (GAME_RECONNECT_DELAY * 2u32.pow(self.attempt)).min(GAME_RECONNECT_MAX_DELAY);
+        self.state = GameReconnectState::Countdown(Timer::new(delay, TimerMode::Once));
+    }
+}
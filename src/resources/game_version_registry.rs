@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+
+use bevy::prelude::{App, Resource};
+
+/// Adds the systems which load the game data or UI resources for a version.
+pub type GameVersionLoader = fn(&mut App);
+
+/// The loaders available for `game.data_version` and `game.ui_version`.
+///
+/// Custom systems can register their own loaders by name from
+/// `SystemsConfig::add_custom_systems`, before the configured versions are selected.
+/// The "custom" version of each does nothing, for custom systems which always
+/// load their own data.
+#[derive(Resource)]
+pub struct GameVersionRegistry {
+    data_loaders: HashMap<String, GameVersionLoader>,
+    ui_loaders: HashMap<String, GameVersionLoader>,
+}
+
+impl Default for GameVersionRegistry {
+    fn default() -> Self {
+        let mut registry = Self {
+            data_loaders: HashMap::new(),
+            ui_loaders: HashMap::new(),
+        };
+        registry.register_data_loader("custom", |_| {});
+        registry.register_ui_loader("custom", |_| {});
+        registry
+    }
+}
+
+impl GameVersionRegistry {
+    /// Registers a game data loader, replacing any existing loader with the same name.
+    pub fn register_data_loader(&mut self, name: impl Into<String>, loader: GameVersionLoader) {
+        self.data_loaders.insert(name.into(), loader);
+    }
+
+    /// Registers a UI resources loader, replacing any existing loader with the same name.
+    pub fn register_ui_loader(&mut self, name: impl Into<String>, loader: GameVersionLoader) {
+        self.ui_loaders.insert(name.into(), loader);
+    }
+
+    pub fn data_loader(&self, name: &str) -> Option<GameVersionLoader> {
+        self.data_loaders.get(name).copied()
+    }
+
+    pub fn ui_loader(&self, name: &str) -> Option<GameVersionLoader> {
+        self.ui_loaders.get(name).copied()
+    }
+
+    pub fn data_versions(&self) -> impl Iterator<Item = &str> {
+        self.data_loaders.keys().map(String::as_str)
+    }
+
+    pub fn ui_versions(&self) -> impl Iterator<Item = &str> {
+        self.ui_loaders.keys().map(String::as_str)
+    }
+}
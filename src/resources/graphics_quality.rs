@@ -0,0 +1,297 @@
+use std::time::Duration;
+
+use bevy::{
+    prelude::{Msaa, Resource},
+    render::{render_resource::SamplerDescriptor, texture::ImageSampler},
+};
+use serde::{Deserialize, Serialize};
+
+pub const GRAPHICS_BENCHMARK_WARMUP_DURATION: Duration = Duration::from_secs(2);
+pub const GRAPHICS_BENCHMARK_MEASURE_DURATION: Duration = Duration::from_secs(5);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GraphicsQualityPreset {
+    Low,
+    Medium,
+    High,
+    Ultra,
+    Custom,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ShadowQuality {
+    Off,
+    Low,
+    Medium,
+    High,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TextureQuality {
+    Low,
+    Medium,
+    High,
+    Ultra,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AntiAliasing {
+    Off,
+    Msaa2,
+    Msaa4,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GraphicsQualitySettings {
+    pub shadow_quality: ShadowQuality,
+    pub draw_distance: f32,
+    pub particle_density: f32,
+    pub texture_quality: TextureQuality,
+    pub anti_aliasing: AntiAliasing,
+}
+
+impl GraphicsQualityPreset {
+    pub const PRESETS: [GraphicsQualityPreset; 4] = [
+        GraphicsQualityPreset::Low,
+        GraphicsQualityPreset::Medium,
+        GraphicsQualityPreset::High,
+        GraphicsQualityPreset::Ultra,
+    ];
+
+    pub fn settings(self) -> Option<GraphicsQualitySettings> {
+        match self {
+            GraphicsQualityPreset::Low => Some(GraphicsQualitySettings {
+                shadow_quality: ShadowQuality::Off,
+                draw_distance: 250.0,
+                particle_density: 0.25,
+                texture_quality: TextureQuality::Low,
+                anti_aliasing: AntiAliasing::Off,
+            }),
+            GraphicsQualityPreset::Medium => Some(GraphicsQualitySettings {
+                shadow_quality: ShadowQuality::Low,
+                draw_distance: 500.0,
+                particle_density: 0.5,
+                texture_quality: TextureQuality::Medium,
+                anti_aliasing: AntiAliasing::Off,
+            }),
+            GraphicsQualityPreset::High => Some(GraphicsQualitySettings {
+                shadow_quality: ShadowQuality::Medium,
+                draw_distance: 1000.0,
+                particle_density: 1.0,
+                texture_quality: TextureQuality::High,
+                anti_aliasing: AntiAliasing::Msaa2,
+            }),
+            GraphicsQualityPreset::Ultra => Some(GraphicsQualitySettings {
+                shadow_quality: ShadowQuality::High,
+                draw_distance: 2000.0,
+                particle_density: 1.0,
+                texture_quality: TextureQuality::Ultra,
+                anti_aliasing: AntiAliasing::Msaa4,
+            }),
+            GraphicsQualityPreset::Custom => None,
+        }
+    }
+
+    /// Returns the preset which matches settings, or Custom if none do.
+    pub fn from_settings(settings: &GraphicsQualitySettings) -> Self {
+        Self::PRESETS
+            .into_iter()
+            .find(|preset| preset.settings().as_ref() == Some(settings))
+            .unwrap_or(GraphicsQualityPreset::Custom)
+    }
+
+    /// Guess a suitable preset from the name of the GPU adapter.
+    pub fn detect(adapter_name: &str) -> Self {
+        let name = adapter_name.to_lowercase();
+        let contains_any =
+            |patterns: &[&str]| patterns.iter().any(|pattern| name.contains(pattern));
+
+        if contains_any(&["llvmpipe", "swiftshader", "software", "microsoft basic"]) {
+            GraphicsQualityPreset::Low
+        } else if name.contains("intel") && !name.contains("arc") {
+            if contains_any(&["hd graphics", "uhd graphics"]) {
+                GraphicsQualityPreset::Low
+            } else {
+                GraphicsQualityPreset::Medium
+            }
+        } else if contains_any(&["radeon(tm) graphics", "radeon graphics", "vega", "apple m1"]) {
+            GraphicsQualityPreset::Medium
+        } else if contains_any(&["rtx 30", "rtx 40", "rx 6", "rx 7"]) {
+            GraphicsQualityPreset::Ultra
+        } else {
+            GraphicsQualityPreset::High
+        }
+    }
+}
+
+impl ShadowQuality {
+    /// Returns the size of the directional light shadow map, or None if
+    /// shadows are disabled.
+    pub fn shadow_map_size(self) -> Option<usize> {
+        match self {
+            ShadowQuality::Off => None,
+            ShadowQuality::Low => Some(1024),
+            ShadowQuality::Medium => Some(2048),
+            ShadowQuality::High => Some(4096),
+        }
+    }
+}
+
+impl TextureQuality {
+    /// The default sampler can only be set when the app is created, so changes
+    /// to texture quality are applied after restarting.
+    pub fn sampler_descriptor(self) -> SamplerDescriptor<'static> {
+        let (lod_min_clamp, anisotropy_clamp) = match self {
+            // Never sample the highest resolution mip level
+            TextureQuality::Low => (1.0, 1),
+            TextureQuality::Medium => (0.0, 1),
+            TextureQuality::High => (0.0, 4),
+            TextureQuality::Ultra => (0.0, 16),
+        };
+
+        SamplerDescriptor {
+            lod_min_clamp,
+            anisotropy_clamp,
+            ..ImageSampler::linear_descriptor()
+        }
+    }
+}
+
+impl From<AntiAliasing> for Msaa {
+    fn from(anti_aliasing: AntiAliasing) -> Self {
+        match anti_aliasing {
+            AntiAliasing::Off => Msaa::Off,
+            AntiAliasing::Msaa2 => Msaa::Sample2,
+            AntiAliasing::Msaa4 => Msaa::Sample4,
+        }
+    }
+}
+
+#[derive(Resource)]
+pub struct GraphicsQuality {
+    pub preset: GraphicsQualityPreset,
+    pub settings: GraphicsQualitySettings,
+
+    /// Set while a zone is only shown as the background of a menu, such as the
+    /// login screen.
+    pub reduced: bool,
+}
+
+impl Default for GraphicsQuality {
+    fn default() -> Self {
+        Self::from_preset(GraphicsQualityPreset::High)
+    }
+}
+
+impl GraphicsQuality {
+    pub fn from_preset(preset: GraphicsQualityPreset) -> Self {
+        Self {
+            preset,
+            settings: preset
+                .settings()
+                .unwrap_or_else(|| GraphicsQualityPreset::High.settings().unwrap()),
+            reduced: false,
+        }
+    }
+
+    /// The settings to render with, when reduced shadows and particles are
+    /// limited to the low preset.
+    pub fn effective_settings(&self) -> GraphicsQualitySettings {
+        let mut settings = self.settings.clone();
+
+        if self.reduced {
+            if let Some(low_settings) = GraphicsQualityPreset::Low.settings() {
+                settings.shadow_quality = low_settings.shadow_quality;
+                settings.particle_density =
+                    settings.particle_density.min(low_settings.particle_density);
+            }
+        }
+
+        settings
+    }
+
+    pub fn set_preset(&mut self, preset: GraphicsQualityPreset) {
+        if let Some(settings) = preset.settings() {
+            self.preset = preset;
+            self.settings = settings;
+        }
+    }
+}
+
+pub struct GraphicsBenchmarkRun {
+    pub preset_index: usize,
+    pub elapsed: Duration,
+    pub measured_frame_time: Duration,
+    pub measured_frames: u32,
+    restore_settings: GraphicsQualitySettings,
+}
+
+/// Runs the game with each preset in turn, measuring the average frame time.
+#[derive(Default, Resource)]
+pub struct GraphicsBenchmark {
+    pub running: Option<GraphicsBenchmarkRun>,
+    pub results: Vec<(GraphicsQualityPreset, Duration)>,
+}
+
+impl GraphicsBenchmark {
+    pub fn start(&mut self, graphics_quality: &mut GraphicsQuality) {
+        self.results.clear();
+        self.running = Some(GraphicsBenchmarkRun {
+            preset_index: 0,
+            elapsed: Duration::ZERO,
+            measured_frame_time: Duration::ZERO,
+            measured_frames: 0,
+            restore_settings: graphics_quality.settings.clone(),
+        });
+        graphics_quality.set_preset(GraphicsQualityPreset::PRESETS[0]);
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.running.is_some()
+    }
+
+    pub fn current_preset(&self) -> Option<GraphicsQualityPreset> {
+        self.running
+            .as_ref()
+            .and_then(|run| GraphicsQualityPreset::PRESETS.get(run.preset_index))
+            .copied()
+    }
+
+    /// Records a frame, moving on to the next preset once enough frames have
+    /// been measured. Restores the previous settings when complete.
+    pub fn update(&mut self, graphics_quality: &mut GraphicsQuality, frame_time: Duration) {
+        let Some(run) = self.running.as_mut() else {
+            return;
+        };
+
+        run.elapsed += frame_time;
+        if run.elapsed <= GRAPHICS_BENCHMARK_WARMUP_DURATION {
+            return;
+        }
+
+        run.measured_frame_time += frame_time;
+        run.measured_frames += 1;
+        if run.elapsed < GRAPHICS_BENCHMARK_WARMUP_DURATION + GRAPHICS_BENCHMARK_MEASURE_DURATION {
+            return;
+        }
+
+        self.results.push((
+            GraphicsQualityPreset::PRESETS[run.preset_index],
+            run.measured_frame_time / run.measured_frames,
+        ));
+
+        run.preset_index += 1;
+        run.elapsed = Duration::ZERO;
+        run.measured_frame_time = Duration::ZERO;
+        run.measured_frames = 0;
+
+        if let Some(preset) = GraphicsQualityPreset::PRESETS.get(run.preset_index) {
+            graphics_quality.set_preset(*preset);
+        } else {
+            graphics_quality.settings = run.restore_settings.clone();
+            graphics_quality.preset =
+                GraphicsQualityPreset::from_settings(&graphics_quality.settings);
+            self.running = None;
+        }
+    }
+}
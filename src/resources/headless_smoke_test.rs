@@ -0,0 +1,47 @@
+use std::sync::{
+    atomic::{AtomicBool, AtomicI32, Ordering},
+    Arc,
+};
+
+use bevy::prelude::Resource;
+
+#[derive(Clone, Resource)]
+pub struct HeadlessSmokeTest {
+    pub num_frames: u32,
+    panicked: Arc<AtomicBool>,
+    exit_code: Arc<AtomicI32>,
+}
+
+impl HeadlessSmokeTest {
+    pub fn new(num_frames: u32) -> Self {
+        Self {
+            num_frames,
+            panicked: Arc::new(AtomicBool::new(false)),
+            // Assume failure until the smoke test has completed successfully
+            exit_code: Arc::new(AtomicI32::new(1)),
+        }
+    }
+
+    /// Records any panic, including those on task pool threads which do not
+    /// stop the app, so the smoke test can fail.
+    pub fn install_panic_hook(&self) {
+        let panicked = self.panicked.clone();
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |panic_info| {
+            panicked.store(true, Ordering::Relaxed);
+            default_hook(panic_info);
+        }));
+    }
+
+    pub fn has_panicked(&self) -> bool {
+        self.panicked.load(Ordering::Relaxed)
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        self.exit_code.load(Ordering::Relaxed)
+    }
+
+    pub fn set_exit_code(&self, exit_code: i32) {
+        self.exit_code.store(exit_code, Ordering::Relaxed);
+    }
+}
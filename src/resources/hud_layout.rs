@@ -0,0 +1,66 @@
+use bevy::prelude::Resource;
+use bevy_egui::egui;
+use enum_map::{Enum, EnumMap};
+use serde::{Deserialize, Serialize};
+
+use crate::resources::CharacterStoreKey;
+
+#[derive(Enum, Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HudElement {
+    Chatbox,
+    Minimap,
+    Hotbar,
+    Party,
+}
+
+#[derive(Copy, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HudElementLayout {
+    /// Offset from the default position of the element.
+    pub offset: [f32; 2],
+    pub hidden: bool,
+}
+
+/// Position of the HUD elements for the current character, stored in the user's
+/// data directory so each character can have a different layout.
+#[derive(Default, Resource)]
+pub struct HudLayout {
+    pub store_key: Option<CharacterStoreKey>,
+    pub elements: EnumMap<HudElement, HudElementLayout>,
+    /// Screen rect of each element the last time it was drawn, used to place the
+    /// drag handles in edit mode.
+    pub rects: EnumMap<HudElement, Option<egui::Rect>>,
+    pub edit_mode: bool,
+    pub changed: bool,
+}
+
+impl HudLayout {
+    pub fn load(store_key: CharacterStoreKey) -> Self {
+        Self {
+            elements: store_key.load("hud_layouts"),
+            store_key: Some(store_key),
+            ..Default::default()
+        }
+    }
+
+    pub fn save(&self) {
+        if let Some(store_key) = self.store_key.as_ref() {
+            store_key.save("hud_layouts", &self.elements);
+        }
+    }
+
+    pub fn get_offset(&self, element: HudElement) -> egui::Vec2 {
+        let [x, y] = self.elements[element].offset;
+        egui::vec2(x, y)
+    }
+
+    /// Hidden elements are still shown in edit mode so they can be moved and unhidden.
+    pub fn is_visible(&self, element: HudElement) -> bool {
+        self.edit_mode || !self.elements[element].hidden
+    }
+
+    pub fn reset(&mut self) {
+        self.elements = Default::default();
+        self.changed = true;
+    }
+}
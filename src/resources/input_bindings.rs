@@ -0,0 +1,579 @@
+use std::{fmt::Write, path::PathBuf};
+
+use bevy::{
+    input::Input,
+    prelude::{KeyCode, Resource},
+};
+use enum_map::{enum_map, Enum, EnumMap};
+
+use crate::{localize, resources::get_data_dir};
+
+/// Bindings are grouped by the situation they are used in, a key can be bound
+/// to one action in each group of contexts which are never active together.
+#[derive(Enum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InputContext {
+    General,
+    Combat,
+    Vehicle,
+    FreeCamera,
+    Ui,
+    Debug,
+}
+
+impl InputContext {
+    /// Returns true if both contexts can be active at the same time.
+    pub fn overlaps(self, other: InputContext) -> bool {
+        match (self, other) {
+            (InputContext::General | InputContext::Debug, _)
+            | (_, InputContext::General | InputContext::Debug) => true,
+            (InputContext::FreeCamera, other) | (other, InputContext::FreeCamera) => {
+                other == InputContext::FreeCamera
+            }
+            _ => true,
+        }
+    }
+
+    pub fn name(self) -> String {
+        match self {
+            InputContext::General => localize!("input-context-general"),
+            InputContext::Combat => localize!("input-context-combat"),
+            InputContext::Vehicle => localize!("input-context-vehicle"),
+            InputContext::FreeCamera => localize!("input-context-free-camera"),
+            InputContext::Ui => localize!("input-context-ui"),
+            InputContext::Debug => localize!("input-context-debug"),
+        }
+    }
+}
+
+#[derive(Enum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum InputAction {
+    LootAll,
+    Hotbar1,
+    Hotbar2,
+    Hotbar3,
+    Hotbar4,
+    Hotbar5,
+    Hotbar6,
+    Hotbar7,
+    Hotbar8,
+    VehicleGetOff,
+    FreeCameraForward,
+    FreeCameraBackward,
+    FreeCameraLeft,
+    FreeCameraRight,
+    FreeCameraDown,
+    FreeCameraUp,
+    FreeCameraBoost,
+    ToggleCharacterInfo,
+    ToggleInventory,
+    ToggleClan,
+    ToggleFriends,
+    ToggleCalendar,
+    ToggleSkillList,
+    ToggleQuestList,
+    ToggleSettings,
+    ToggleSessionStatistics,
+    ToggleWaypoints,
+    TogglePersonalStoreScanner,
+    ToggleDebugWindow,
+    DebugInspectorPick,
+    DebugPhysicsSpawnBalls,
+}
+
+impl InputAction {
+    pub const HOTBAR: [InputAction; 8] = [
+        InputAction::Hotbar1,
+        InputAction::Hotbar2,
+        InputAction::Hotbar3,
+        InputAction::Hotbar4,
+        InputAction::Hotbar5,
+        InputAction::Hotbar6,
+        InputAction::Hotbar7,
+        InputAction::Hotbar8,
+    ];
+
+    pub fn context(self) -> InputContext {
+        match self {
+            InputAction::LootAll => InputContext::General,
+            InputAction::Hotbar1
+            | InputAction::Hotbar2
+            | InputAction::Hotbar3
+            | InputAction::Hotbar4
+            | InputAction::Hotbar5
+            | InputAction::Hotbar6
+            | InputAction::Hotbar7
+            | InputAction::Hotbar8 => InputContext::Combat,
+            InputAction::VehicleGetOff => InputContext::Vehicle,
+            InputAction::FreeCameraForward
+            | InputAction::FreeCameraBackward
+            | InputAction::FreeCameraLeft
+            | InputAction::FreeCameraRight
+            | InputAction::FreeCameraDown
+            | InputAction::FreeCameraUp
+            | InputAction::FreeCameraBoost => InputContext::FreeCamera,
+            InputAction::ToggleCharacterInfo
+            | InputAction::ToggleInventory
+            | InputAction::ToggleClan
+            | InputAction::ToggleFriends
+            | InputAction::ToggleCalendar
+            | InputAction::ToggleSkillList
+            | InputAction::ToggleQuestList
+            | InputAction::ToggleSettings
+            | InputAction::ToggleSessionStatistics
+            | InputAction::ToggleWaypoints
+            | InputAction::TogglePersonalStoreScanner => InputContext::Ui,
+            InputAction::ToggleDebugWindow
+            | InputAction::DebugInspectorPick
+            | InputAction::DebugPhysicsSpawnBalls => InputContext::Debug,
+        }
+    }
+
+    pub fn name(self) -> String {
+        match self {
+            InputAction::LootAll => localize!("input-action-loot-all"),
+            InputAction::Hotbar1 => localize!("input-action-hotbar", slot = 1),
+            InputAction::Hotbar2 => localize!("input-action-hotbar", slot = 2),
+            InputAction::Hotbar3 => localize!("input-action-hotbar", slot = 3),
+            InputAction::Hotbar4 => localize!("input-action-hotbar", slot = 4),
+            InputAction::Hotbar5 => localize!("input-action-hotbar", slot = 5),
+            InputAction::Hotbar6 => localize!("input-action-hotbar", slot = 6),
+            InputAction::Hotbar7 => localize!("input-action-hotbar", slot = 7),
+            InputAction::Hotbar8 => localize!("input-action-hotbar", slot = 8),
+            InputAction::VehicleGetOff => localize!("input-action-vehicle-get-off"),
+            InputAction::FreeCameraForward => localize!("input-action-free-camera-forward"),
+            InputAction::FreeCameraBackward => localize!("input-action-free-camera-backward"),
+            InputAction::FreeCameraLeft => localize!("input-action-free-camera-left"),
+            InputAction::FreeCameraRight => localize!("input-action-free-camera-right"),
+            InputAction::FreeCameraDown => localize!("input-action-free-camera-down"),
+            InputAction::FreeCameraUp => localize!("input-action-free-camera-up"),
+            InputAction::FreeCameraBoost => localize!("input-action-free-camera-boost"),
+            InputAction::ToggleCharacterInfo => localize!("input-action-toggle-character-info"),
+            InputAction::ToggleInventory => localize!("input-action-toggle-inventory"),
+            InputAction::ToggleClan => localize!("input-action-toggle-clan"),
+            InputAction::ToggleFriends => localize!("input-action-toggle-friends"),
+            InputAction::ToggleCalendar => localize!("input-action-toggle-calendar"),
+            InputAction::ToggleSkillList => localize!("input-action-toggle-skill-list"),
+            InputAction::ToggleQuestList => localize!("input-action-toggle-quest-list"),
+            InputAction::ToggleSettings => localize!("input-action-toggle-settings"),
+            InputAction::ToggleSessionStatistics => {
+                localize!("input-action-toggle-session-statistics")
+            }
+            InputAction::ToggleWaypoints => localize!("input-action-toggle-waypoints"),
+            InputAction::TogglePersonalStoreScanner => {
+                localize!("input-action-toggle-personal-store-scanner")
+            }
+            InputAction::ToggleDebugWindow => localize!("input-action-toggle-debug-window"),
+            InputAction::DebugInspectorPick => localize!("input-action-debug-inspector-pick"),
+            InputAction::DebugPhysicsSpawnBalls => {
+                localize!("input-action-debug-physics-spawn-balls")
+            }
+        }
+    }
+}
+
+/// The keys which can be bound, in the order they are listed in settings.
+const BINDABLE_KEYS: &[KeyCode] = &[
+    KeyCode::A,
+    KeyCode::B,
+    KeyCode::C,
+    KeyCode::D,
+    KeyCode::E,
+    KeyCode::F,
+    KeyCode::G,
+    KeyCode::H,
+    KeyCode::I,
+    KeyCode::J,
+    KeyCode::K,
+    KeyCode::L,
+    KeyCode::M,
+    KeyCode::N,
+    KeyCode::O,
+    KeyCode::P,
+    KeyCode::Q,
+    KeyCode::R,
+    KeyCode::S,
+    KeyCode::T,
+    KeyCode::U,
+    KeyCode::V,
+    KeyCode::W,
+    KeyCode::X,
+    KeyCode::Y,
+    KeyCode::Z,
+    KeyCode::Key1,
+    KeyCode::Key2,
+    KeyCode::Key3,
+    KeyCode::Key4,
+    KeyCode::Key5,
+    KeyCode::Key6,
+    KeyCode::Key7,
+    KeyCode::Key8,
+    KeyCode::Key9,
+    KeyCode::Key0,
+    KeyCode::F1,
+    KeyCode::F2,
+    KeyCode::F3,
+    KeyCode::F4,
+    KeyCode::F5,
+    KeyCode::F6,
+    KeyCode::F7,
+    KeyCode::F8,
+    KeyCode::F9,
+    KeyCode::F10,
+    KeyCode::F11,
+    KeyCode::F12,
+    KeyCode::Numpad0,
+    KeyCode::Numpad1,
+    KeyCode::Numpad2,
+    KeyCode::Numpad3,
+    KeyCode::Numpad4,
+    KeyCode::Numpad5,
+    KeyCode::Numpad6,
+    KeyCode::Numpad7,
+    KeyCode::Numpad8,
+    KeyCode::Numpad9,
+    KeyCode::Space,
+    KeyCode::Tab,
+    KeyCode::Grave,
+    KeyCode::Minus,
+    KeyCode::Equals,
+    KeyCode::BracketLeft,
+    KeyCode::BracketRight,
+    KeyCode::Backslash,
+    KeyCode::Semicolon,
+    KeyCode::Apostrophe,
+    KeyCode::Comma,
+    KeyCode::Period,
+    KeyCode::Slash,
+    KeyCode::Insert,
+    KeyCode::Delete,
+    KeyCode::Home,
+    KeyCode::End,
+    KeyCode::PageUp,
+    KeyCode::PageDown,
+    KeyCode::Up,
+    KeyCode::Down,
+    KeyCode::Left,
+    KeyCode::Right,
+    KeyCode::ShiftLeft,
+    KeyCode::ShiftRight,
+    KeyCode::ControlLeft,
+    KeyCode::ControlRight,
+    KeyCode::AltLeft,
+    KeyCode::AltRight,
+];
+
+fn is_modifier_key(key: KeyCode) -> bool {
+    matches!(
+        key,
+        KeyCode::ShiftLeft
+            | KeyCode::ShiftRight
+            | KeyCode::ControlLeft
+            | KeyCode::ControlRight
+            | KeyCode::AltLeft
+            | KeyCode::AltRight
+    )
+}
+
+fn key_name(key: KeyCode) -> String {
+    let name = format!("{:?}", key);
+    name.strip_prefix("Key").unwrap_or(&name).to_string()
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct KeyBinding {
+    pub key: KeyCode,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+}
+
+impl KeyBinding {
+    pub const fn new(key: KeyCode) -> Self {
+        Self {
+            key,
+            ctrl: false,
+            alt: false,
+            shift: false,
+        }
+    }
+
+    pub const fn ctrl(key: KeyCode) -> Self {
+        Self {
+            key,
+            ctrl: true,
+            alt: false,
+            shift: false,
+        }
+    }
+
+    pub const fn alt(key: KeyCode) -> Self {
+        Self {
+            key,
+            ctrl: false,
+            alt: true,
+            shift: false,
+        }
+    }
+
+    fn modifiers_held(keyboard: &Input<KeyCode>) -> (bool, bool, bool) {
+        (
+            keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]),
+            keyboard.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]),
+            keyboard.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+        )
+    }
+
+    /// Returns the binding for a key which was just pressed with the held
+    /// modifiers, modifier keys are only bound on their own when released.
+    pub fn from_input(keyboard: &Input<KeyCode>) -> Option<Self> {
+        let (ctrl, alt, shift) = Self::modifiers_held(keyboard);
+        if let Some(&key) = BINDABLE_KEYS
+            .iter()
+            .find(|&&key| !is_modifier_key(key) && keyboard.just_pressed(key))
+        {
+            return Some(Self {
+                key,
+                ctrl,
+                alt,
+                shift,
+            });
+        }
+
+        if ctrl || alt || shift {
+            return None;
+        }
+
+        BINDABLE_KEYS
+            .iter()
+            .find(|&&key| is_modifier_key(key) && keyboard.just_released(key))
+            .map(|&key| Self::new(key))
+    }
+
+    /// A binding is triggered when its key is pressed with exactly its modifiers.
+    pub fn just_pressed(&self, keyboard: &Input<KeyCode>) -> bool {
+        keyboard.just_pressed(self.key)
+            && (is_modifier_key(self.key)
+                || Self::modifiers_held(keyboard) == (self.ctrl, self.alt, self.shift))
+    }
+
+    /// A binding is held while its key and at least its modifiers are held.
+    pub fn pressed(&self, keyboard: &Input<KeyCode>) -> bool {
+        let (ctrl, alt, shift) = Self::modifiers_held(keyboard);
+        keyboard.pressed(self.key)
+            && (ctrl || !self.ctrl)
+            && (alt || !self.alt)
+            && (shift || !self.shift)
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        let mut binding = Self::new(KeyCode::Escape);
+        let mut parts = value.split('+').peekable();
+        while let Some(part) = parts.next() {
+            if parts.peek().is_none() {
+                binding.key = *BINDABLE_KEYS.iter().find(|&&key| key_name(key) == part)?;
+                return Some(binding);
+            }
+
+            match part {
+                "Ctrl" => binding.ctrl = true,
+                "Alt" => binding.alt = true,
+                "Shift" => binding.shift = true,
+                _ => return None,
+            }
+        }
+        None
+    }
+}
+
+impl std::fmt::Display for KeyBinding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.ctrl {
+            write!(f, "Ctrl+")?;
+        }
+        if self.alt {
+            write!(f, "Alt+")?;
+        }
+        if self.shift {
+            write!(f, "Shift+")?;
+        }
+        write!(f, "{}", key_name(self.key))
+    }
+}
+
+pub const INPUT_BINDINGS_PER_ACTION: usize = 2;
+
+pub type ActionBindings = [Option<KeyBinding>; INPUT_BINDINGS_PER_ACTION];
+
+#[derive(Resource)]
+pub struct InputBindings {
+    pub bindings: EnumMap<InputAction, ActionBindings>,
+    /// Updated each frame by input_context_system.
+    pub active_contexts: EnumMap<InputContext, bool>,
+}
+
+impl Default for InputBindings {
+    fn default() -> Self {
+        Self {
+            bindings: Self::default_bindings(),
+            active_contexts: enum_map! {
+                InputContext::FreeCamera => false,
+                _ => true,
+            },
+        }
+    }
+}
+
+impl InputBindings {
+    pub fn default_bindings() -> EnumMap<InputAction, ActionBindings> {
+        let key = |key| [Some(KeyBinding::new(key)), None];
+        let ctrl = |key| [Some(KeyBinding::ctrl(key)), None];
+        let alt = |key| [Some(KeyBinding::alt(key)), None];
+
+        enum_map! {
+            InputAction::LootAll => key(KeyCode::Z),
+            InputAction::Hotbar1 => key(KeyCode::F1),
+            InputAction::Hotbar2 => key(KeyCode::F2),
+            InputAction::Hotbar3 => key(KeyCode::F3),
+            InputAction::Hotbar4 => key(KeyCode::F4),
+            InputAction::Hotbar5 => key(KeyCode::F5),
+            InputAction::Hotbar6 => key(KeyCode::F6),
+            InputAction::Hotbar7 => key(KeyCode::F7),
+            InputAction::Hotbar8 => key(KeyCode::F8),
+            InputAction::VehicleGetOff => key(KeyCode::X),
+            InputAction::FreeCameraForward => key(KeyCode::W),
+            InputAction::FreeCameraBackward => key(KeyCode::S),
+            InputAction::FreeCameraLeft => key(KeyCode::A),
+            InputAction::FreeCameraRight => key(KeyCode::D),
+            InputAction::FreeCameraDown => key(KeyCode::Q),
+            InputAction::FreeCameraUp => key(KeyCode::E),
+            InputAction::FreeCameraBoost => key(KeyCode::ShiftLeft),
+            InputAction::ToggleCharacterInfo => alt(KeyCode::A),
+            InputAction::ToggleInventory => {
+                [Some(KeyBinding::alt(KeyCode::I)), Some(KeyBinding::alt(KeyCode::V))]
+            }
+            InputAction::ToggleClan => alt(KeyCode::N),
+            InputAction::ToggleFriends => alt(KeyCode::C),
+            InputAction::ToggleCalendar => alt(KeyCode::E),
+            InputAction::ToggleSkillList => alt(KeyCode::S),
+            InputAction::ToggleQuestList => alt(KeyCode::Q),
+            InputAction::ToggleSettings => alt(KeyCode::O),
+            InputAction::ToggleSessionStatistics => alt(KeyCode::T),
+            InputAction::ToggleWaypoints => alt(KeyCode::W),
+            InputAction::TogglePersonalStoreScanner => alt(KeyCode::M),
+            InputAction::ToggleDebugWindow => ctrl(KeyCode::D),
+            InputAction::DebugInspectorPick => key(KeyCode::P),
+            InputAction::DebugPhysicsSpawnBalls => key(KeyCode::B),
+        }
+    }
+
+    pub fn just_pressed(&self, action: InputAction, keyboard: &Input<KeyCode>) -> bool {
+        self.active_contexts[action.context()]
+            && self.bindings[action]
+                .iter()
+                .flatten()
+                .any(|binding| binding.just_pressed(keyboard))
+    }
+
+    pub fn pressed(&self, action: InputAction, keyboard: &Input<KeyCode>) -> bool {
+        self.active_contexts[action.context()]
+            && self.bindings[action]
+                .iter()
+                .flatten()
+                .any(|binding| binding.pressed(keyboard))
+    }
+
+    /// Returns the other actions which would be triggered by the same binding.
+    pub fn find_conflicts(
+        &self,
+        action: InputAction,
+        binding: &KeyBinding,
+    ) -> impl Iterator<Item = InputAction> + '_ {
+        let context = action.context();
+        let binding = *binding;
+        self.bindings
+            .iter()
+            .filter(move |(other_action, other_bindings)| {
+                *other_action != action
+                    && context.overlaps(other_action.context())
+                    && other_bindings.contains(&Some(binding))
+            })
+            .map(|(other_action, _)| other_action)
+    }
+
+    /// Removes the binding from every other action it conflicts with.
+    pub fn unbind_conflicts(&mut self, action: InputAction, binding: &KeyBinding) {
+        let conflicts: Vec<InputAction> = self.find_conflicts(action, binding).collect();
+        for other_action in conflicts {
+            for other_binding in self.bindings[other_action].iter_mut() {
+                if other_binding.as_ref() == Some(binding) {
+                    *other_binding = None;
+                }
+            }
+        }
+    }
+
+    pub fn write_cheat_sheet(&self) -> Result<String, std::fmt::Error> {
+        let mut html = String::new();
+        writeln!(html, "<!DOCTYPE html>")?;
+        writeln!(html, "<html>")?;
+        writeln!(html, "<head>")?;
+        writeln!(html, "<meta charset=\"utf-8\">")?;
+        writeln!(
+            html,
+            "<title>{}</title>",
+            localize!("input-cheat-sheet-title")
+        )?;
+        writeln!(
+            html,
+            "<style>body {{ font-family: sans-serif; }} table {{ border-collapse: collapse; margin-bottom: 1em; }} th, td {{ border: 1px solid #888; padding: 2px 8px; text-align: left; }}</style>"
+        )?;
+        writeln!(html, "</head>")?;
+        writeln!(html, "<body>")?;
+        writeln!(html, "<h1>{}</h1>", localize!("input-cheat-sheet-title"))?;
+
+        for (context, _) in self.active_contexts.iter() {
+            writeln!(html, "<h2>{}</h2>", context.name())?;
+            writeln!(html, "<table>")?;
+            for (action, bindings) in self
+                .bindings
+                .iter()
+                .filter(|(action, _)| action.context() == context)
+            {
+                let keys: Vec<String> = bindings
+                    .iter()
+                    .flatten()
+                    .map(|binding| binding.to_string())
+                    .collect();
+                writeln!(
+                    html,
+                    "<tr><th>{}</th><td>{}</td></tr>",
+                    action.name(),
+                    if keys.is_empty() {
+                        "-".to_string()
+                    } else {
+                        keys.join(", ")
+                    }
+                )?;
+            }
+            writeln!(html, "</table>")?;
+        }
+
+        writeln!(html, "</body>")?;
+        writeln!(html, "</html>")?;
+        Ok(html)
+    }
+
+    /// Writes a printable list of the current bindings to the exports directory.
+    pub fn export_cheat_sheet(&self) -> anyhow::Result<PathBuf> {
+        let path = get_data_dir()?.join("exports").join(format!(
+            "keybindings-{}.html",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ));
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&path, self.write_cheat_sheet()?)?;
+        Ok(path)
+    }
+}
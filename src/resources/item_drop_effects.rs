@@ -0,0 +1,172 @@
+use bevy::{
+    prelude::{Assets, FromWorld, Handle, Image, Mesh, Resource, World},
+    render::{
+        mesh::{Indices, PrimitiveTopology},
+        render_resource::{BlendFactor, BlendOperation, Extent3d, TextureDimension, TextureFormat},
+    },
+};
+use enum_map::{enum_map, Enum, EnumMap};
+
+use crate::render::EffectMeshMaterial;
+
+const ITEM_DROP_EFFECT_TEXTURE_SIZE: u32 = 32;
+const ITEM_DROP_BEAM_WIDTH: f32 = 0.3;
+const ITEM_DROP_GLOW_SIZE: f32 = 1.2;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Enum)]
+pub enum ItemDropRarity {
+    Money,
+    Equipment,
+    Rare,
+    Unique,
+}
+
+pub struct ItemDropRarityEffect {
+    /// Height of the light beam in metres, or 0 for no beam.
+    pub beam_height: f32,
+    pub beam_material: Handle<EffectMeshMaterial>,
+    pub glow_material: Handle<EffectMeshMaterial>,
+}
+
+/// The meshes and materials for the beams and glows shown over item drops,
+/// coloured to match the item name colours.
+#[derive(Resource)]
+pub struct ItemDropEffects {
+    pub beam_mesh: Handle<Mesh>,
+    pub glow_mesh: Handle<Mesh>,
+    pub rarity_effects: EnumMap<ItemDropRarity, ItemDropRarityEffect>,
+}
+
+pub(crate) fn create_quad_mesh(quads: &[[[f32; 3]; 4]]) -> Mesh {
+    let mut positions = Vec::with_capacity(quads.len() * 4);
+    let mut uvs = Vec::with_capacity(quads.len() * 4);
+    let mut indices = Vec::with_capacity(quads.len() * 6);
+
+    for quad in quads {
+        let base_index = positions.len() as u16;
+        positions.extend_from_slice(quad);
+        uvs.extend_from_slice(&[[0.0, 1.0], [1.0, 1.0], [1.0, 0.0], [0.0, 0.0]]);
+        indices.extend_from_slice(&[
+            base_index,
+            base_index + 1,
+            base_index + 2,
+            base_index,
+            base_index + 2,
+            base_index + 3,
+        ]);
+    }
+
+    let mut mesh = Mesh::new(PrimitiveTopology::TriangleList);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.set_indices(Some(Indices::U16(indices)));
+    mesh
+}
+
+/// Creates a texture of colour where the alpha is given by alpha_fn(u, v).
+pub(crate) fn create_gradient_image(colour: [u8; 3], alpha_fn: impl Fn(f32, f32) -> f32) -> Image {
+    let size = ITEM_DROP_EFFECT_TEXTURE_SIZE;
+    let mut data = Vec::with_capacity((size * size * 4) as usize);
+    for y in 0..size {
+        for x in 0..size {
+            let u = (x as f32 + 0.5) / size as f32;
+            let v = (y as f32 + 0.5) / size as f32;
+            let alpha = alpha_fn(u, v).clamp(0.0, 1.0);
+            data.extend_from_slice(&[colour[0], colour[1], colour[2], (alpha * 255.0) as u8]);
+        }
+    }
+
+    Image::new(
+        Extent3d {
+            width: size,
+            height: size,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+    )
+}
+
+fn create_additive_material(texture: Handle<Image>) -> EffectMeshMaterial {
+    EffectMeshMaterial {
+        base_texture: Some(texture),
+        animation_texture: None,
+        alpha_enabled: true,
+        alpha_test: false,
+        two_sided: true,
+        z_test_enabled: true,
+        z_write_enabled: false,
+        blend_op: BlendOperation::Add,
+        src_blend_factor: BlendFactor::SrcAlpha,
+        dst_blend_factor: BlendFactor::One,
+        intensity: 1.0,
+    }
+}
+
+impl FromWorld for ItemDropEffects {
+    fn from_world(world: &mut World) -> Self {
+        let half_width = ITEM_DROP_BEAM_WIDTH / 2.0;
+        let half_glow = ITEM_DROP_GLOW_SIZE / 2.0;
+
+        // Two crossed vertical quads so the beam is visible from every direction
+        let beam_mesh = create_quad_mesh(&[
+            [
+                [-half_width, 0.0, 0.0],
+                [half_width, 0.0, 0.0],
+                [half_width, 1.0, 0.0],
+                [-half_width, 1.0, 0.0],
+            ],
+            [
+                [0.0, 0.0, -half_width],
+                [0.0, 0.0, half_width],
+                [0.0, 1.0, half_width],
+                [0.0, 1.0, -half_width],
+            ],
+        ]);
+        let glow_mesh = create_quad_mesh(&[[
+            [-half_glow, 0.05, -half_glow],
+            [half_glow, 0.05, -half_glow],
+            [half_glow, 0.05, half_glow],
+            [-half_glow, 0.05, half_glow],
+        ]]);
+
+        let mut meshes = world.resource_mut::<Assets<Mesh>>();
+        let beam_mesh = meshes.add(beam_mesh);
+        let glow_mesh = meshes.add(glow_mesh);
+
+        let create_rarity_effect = |world: &mut World, colour: [u8; 3], beam_height: f32| {
+            // The beam fades out towards the top and sides, the glow fades out from the centre
+            let beam_image =
+                create_gradient_image(colour, |u, v| (1.0 - (u * 2.0 - 1.0).abs()) * v * 0.8);
+            let glow_image = create_gradient_image(colour, |u, v| {
+                let distance = ((u * 2.0 - 1.0).powi(2) + (v * 2.0 - 1.0).powi(2)).sqrt();
+                (1.0 - distance) * 0.8
+            });
+
+            let mut images = world.resource_mut::<Assets<Image>>();
+            let beam_image = images.add(beam_image);
+            let glow_image = images.add(glow_image);
+
+            let mut materials = world.resource_mut::<Assets<EffectMeshMaterial>>();
+            ItemDropRarityEffect {
+                beam_height,
+                beam_material: materials.add(create_additive_material(beam_image)),
+                glow_material: materials.add(create_additive_material(glow_image)),
+            }
+        };
+
+        let rarity_effects = enum_map! {
+            ItemDropRarity::Money => create_rarity_effect(world, [255, 255, 0], 0.0),
+            ItemDropRarity::Equipment => create_rarity_effect(world, [255, 255, 255], 0.0),
+            ItemDropRarity::Rare => create_rarity_effect(world, [0, 255, 255], 3.0),
+            ItemDropRarity::Unique => create_rarity_effect(world, [255, 128, 255], 5.0),
+        };
+
+        Self {
+            beam_mesh,
+            glow_mesh,
+            rarity_effects,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use bevy::prelude::Resource;
+
+#[derive(Resource)]
+pub struct ItemGradeEffectSettings {
+    pub intensity: f32,
+    /// Show beams and glows over valuable item drops.
+    pub item_drop_effects: bool,
+}
+
+impl Default for ItemGradeEffectSettings {
+    fn default() -> Self {
+        Self {
+            intensity: 1.0,
+            item_drop_effects: true,
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use bevy::prelude::{Entity, Resource};
+
+#[derive(Resource)]
+pub struct LevelUpSettings {
+    /// Attach the level up effect to the player.
+    pub effect: bool,
+    pub fanfare: bool,
+    pub screen_flash: bool,
+    /// Show an animated "Level Up!" banner above the player.
+    pub banner: bool,
+}
+
+impl Default for LevelUpSettings {
+    fn default() -> Self {
+        Self {
+            effect: true,
+            fanfare: true,
+            screen_flash: true,
+            banner: true,
+        }
+    }
+}
+
+/// The most recent level up of the player, shown by ui_level_up_system.
+#[derive(Default, Resource)]
+pub struct LevelUpPresentation {
+    pub entity: Option<Entity>,
+    pub level: Option<u32>,
+    pub start_time: Duration,
+}
+
+impl LevelUpPresentation {
+    pub fn start(&mut self, now: Duration, entity: Entity, level: Option<u32>) {
+        self.entity = Some(entity);
+        self.level = level;
+        self.start_time = now;
+    }
+}
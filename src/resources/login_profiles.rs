@@ -0,0 +1,115 @@
+use bevy::prelude::Resource;
+
+use crate::{Config, ProfileConfig};
+
+const KEYRING_SERVICE: &str = "rose-offline-client";
+
+pub struct LoginProfile {
+    pub name: String,
+    pub config: ProfileConfig,
+}
+
+impl LoginProfile {
+    fn keyring_entry(&self) -> Result<keyring::Entry, keyring::Error> {
+        keyring::Entry::new(
+            KEYRING_SERVICE,
+            &format!(
+                "{}:{}:{}",
+                self.config.server.ip, self.config.server.port, self.config.username
+            ),
+        )
+    }
+
+    /// Returns the password from the config, or from the OS keyring when the
+    /// password is remembered.
+    pub fn password(&self) -> Option<String> {
+        if !self.config.password.is_empty() {
+            return Some(self.config.password.clone());
+        }
+
+        if !self.config.remember_password || self.config.username.is_empty() {
+            return None;
+        }
+
+        match self.keyring_entry().and_then(|entry| entry.get_password()) {
+            Ok(password) => Some(password),
+            Err(keyring::Error::NoEntry) => None,
+            Err(error) => {
+                log::warn!(
+                    "Failed to read password for profile {} from keyring with error: {}",
+                    self.name,
+                    error
+                );
+                None
+            }
+        }
+    }
+
+    /// Stores the password in the OS keyring, or removes it when remember is false.
+    pub fn remember_password(&mut self, username: &str, password: &str, remember: bool) {
+        self.config.username = username.to_string();
+        self.config.remember_password = remember;
+
+        let result = self.keyring_entry().and_then(|entry| {
+            if remember {
+                entry.set_password(password)
+            } else {
+                match entry.delete_password() {
+                    Err(keyring::Error::NoEntry) => Ok(()),
+                    result => result,
+                }
+            }
+        });
+
+        if let Err(error) = result {
+            log::warn!(
+                "Failed to update password for profile {} in keyring with error: {}",
+                self.name,
+                error
+            );
+        }
+    }
+}
+
+/// The login profiles from the config, selectable on the login screen.
+#[derive(Resource)]
+pub struct LoginProfiles {
+    pub profiles: Vec<LoginProfile>,
+    pub selected: usize,
+}
+
+impl LoginProfiles {
+    pub fn from_config(config: &Config) -> Self {
+        let selected_name = config.selected_profile_name();
+        let mut profiles: Vec<LoginProfile> = config
+            .profiles
+            .iter()
+            .map(|(name, config)| LoginProfile {
+                name: name.clone(),
+                config: config.clone(),
+            })
+            .collect();
+
+        if !profiles.iter().any(|profile| profile.name == selected_name) {
+            profiles.push(LoginProfile {
+                name: selected_name.clone(),
+                config: config.selected_profile(),
+            });
+        }
+
+        let selected = profiles
+            .iter()
+            .position(|profile| profile.name == selected_name)
+            .unwrap_or(0);
+
+        Self { profiles, selected }
+    }
+
+    pub fn selected(&self) -> &LoginProfile {
+        &self.profiles[self.selected]
+    }
+
+    pub fn selected_mut(&mut self) -> &mut LoginProfile {
+        &mut self.profiles[self.selected]
+    }
+}
@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+use bevy::{
+    math::Vec3,
+    prelude::Resource,
+    time::{Timer, TimerMode},
+};
+
+const LOGOUT_COUNTDOWN: Duration = Duration::from_secs(10);
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LogoutTarget {
+    CharacterSelect,
+    Exit,
+}
+
+pub enum LogoutState {
+    /// Waiting before sending the logout request, moving away from the start
+    /// position cancels the logout.
+    Countdown {
+        timer: Timer,
+        start_position: Vec3,
+    },
+    WaitServer,
+    /// The game server connection has been closed, waiting to leave the game.
+    Complete,
+}
+
+#[derive(Resource)]
+pub struct Logout {
+    pub target: LogoutTarget,
+    pub state: LogoutState,
+}
+
+impl Logout {
+    pub fn new(target: LogoutTarget, start_position: Vec3) -> Self {
+        Self {
+            target,
+            state: LogoutState::Countdown {
+                timer: Timer::new(LOGOUT_COUNTDOWN, TimerMode::Once),
+                start_position,
+            },
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use std::time::Duration;
+
+use bevy::prelude::Resource;
+use serde::Deserialize;
+
+const MESSAGE_OF_THE_DAY_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Default, Deserialize)]
+#[serde(default)]
+pub struct MessageOfTheDayContent {
+    pub message: String,
+    pub links: Vec<MessageOfTheDayLink>,
+    pub patch_notes: Vec<PatchNotes>,
+}
+
+#[derive(Deserialize)]
+pub struct MessageOfTheDayLink {
+    pub text: String,
+    pub url: String,
+}
+
+#[derive(Deserialize)]
+pub struct PatchNotes {
+    pub title: String,
+    #[serde(default)]
+    pub date: String,
+    #[serde(default)]
+    pub notes: Vec<String>,
+}
+
+fn fetch_message_of_the_day(url: &str) -> anyhow::Result<MessageOfTheDayContent> {
+    let json = ureq::get(url)
+        .timeout(MESSAGE_OF_THE_DAY_REQUEST_TIMEOUT)
+        .call()?
+        .into_string()?;
+    Ok(serde_json::from_str(&json)?)
+}
+
+/// The message of the day and patch notes shown beside the server list, fetched
+/// from the JSON at the `motd_url` of the selected server.
+#[derive(Default, Resource)]
+pub struct MessageOfTheDay {
+    pub url: String,
+    pub content: Option<MessageOfTheDayContent>,
+    pub error: Option<String>,
+
+    fetched: bool,
+    fetch_rx: Option<crossbeam_channel::Receiver<Result<MessageOfTheDayContent, String>>>,
+}
+
+impl MessageOfTheDay {
+    pub fn is_fetching(&self) -> bool {
+        self.fetch_rx.is_some()
+    }
+
+    /// The message is only fetched once for each url, after connecting to the
+    /// login server.
+    pub fn needs_fetch(&self) -> bool {
+        !self.url.is_empty() && !self.fetched
+    }
+
+    /// Fetches the message on a background thread, the result is applied by
+    /// `receive` once it has arrived.
+    pub fn fetch(&mut self) {
+        if self.url.is_empty() || self.is_fetching() {
+            return;
+        }
+
+        let (fetch_tx, fetch_rx) = crossbeam_channel::bounded(1);
+        let url = self.url.clone();
+        std::thread::spawn(move || {
+            fetch_tx
+                .send(fetch_message_of_the_day(&url).map_err(|error| error.to_string()))
+                .ok();
+        });
+
+        self.fetch_rx = Some(fetch_rx);
+        self.fetched = true;
+    }
+
+    pub fn receive(&mut self) {
+        let Some(result) = self
+            .fetch_rx
+            .as_ref()
+            .and_then(|fetch_rx| fetch_rx.try_recv().ok())
+        else {
+            return;
+        };
+        self.fetch_rx = None;
+
+        match result {
+            Ok(content) => {
+                self.content = Some(content);
+                self.error = None;
+            }
+            Err(error) => {
+                log::warn!(
+                    "Failed to fetch message of the day from {}: {}",
+                    self.url,
+                    error
+                );
+                self.error = Some(error);
+            }
+        }
+    }
+
+    /// Use the message from a different url, e.g. after selecting another profile.
+    pub fn set_url(&mut self, url: &str) {
+        if self.url != url {
+            *self = Self {
+                url: url.to_string(),
+                ..Default::default()
+            };
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+use bevy::prelude::Resource;
+
+/// Paths which the asset server failed to find in the virtual filesystem,
+/// shared with VfsAssetIo which runs on the asset loading threads.
+#[derive(Clone, Default, Resource)]
+pub struct MissingAssets {
+    paths: Arc<Mutex<BTreeSet<PathBuf>>>,
+}
+
+impl MissingAssets {
+    pub fn add(&self, path: &Path) {
+        self.paths.lock().unwrap().insert(path.to_path_buf());
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.paths.lock().unwrap().is_empty()
+    }
+
+    pub fn paths(&self) -> Vec<PathBuf> {
+        self.paths.lock().unwrap().iter().cloned().collect()
+    }
+}
@@ -1,52 +1,157 @@
+mod accessibility_settings;
 mod account;
+mod achievements;
+mod afk_settings;
+mod afk_state;
 mod app_state;
+mod auto_loot_settings;
 mod character_list;
 mod character_select_state;
+mod character_store;
 mod client_entity_list;
+mod config_persistence;
+mod conversation_dialog_portrait;
+mod cooldown_alerts;
+mod cooldown_store;
 mod current_zone;
 mod damage_digits_spawner;
 mod debug_inspector;
 mod debug_render;
+mod debug_time_control;
+mod defend_target;
+mod entity_history;
+mod entity_pool;
+mod event_schedule;
+mod footstep_effects;
+mod friend_list;
 mod game_connection;
 mod game_data;
+mod game_reconnect;
+mod game_version_registry;
+mod graphics_quality;
+mod headless_smoke_test;
+mod hud_layout;
+mod input_bindings;
+mod item_drop_effects;
+mod item_grade_effect_settings;
+mod level_up_presentation;
 mod login_connection;
+mod login_profiles;
 mod login_state;
+mod logout;
+mod message_of_the_day;
+mod missing_assets;
 mod name_tag_cache;
 mod name_tag_settings;
 mod network_thread;
+mod packet_inspector;
+mod personal_store_list_requests;
+mod protocol_registry;
 mod render_configuration;
+mod replay;
 mod selected_target;
 mod server_configuration;
 mod server_list;
+mod session_statistics;
+mod setup_wizard;
+mod skill_queue;
+mod skill_range_indicator;
 mod sound_cache;
 mod sound_settings;
 mod specular_texture;
 mod ui_resources;
+mod ui_screenshot_harness;
+mod user_settings;
 mod virtual_filesystem;
+mod water_reflection;
+mod waypoints;
+mod window_settings;
 mod world_connection;
 mod world_rates;
 mod world_time;
+mod zone_benchmark;
+mod zone_music_playlists;
 mod zone_time;
+mod zone_validation_result;
 
+pub use accessibility_settings::AccessibilitySettings;
 pub use account::Account;
+pub use achievements::{
+    AchievementDefinition, AchievementKind, AchievementProgress, Achievements, ACHIEVEMENTS,
+};
+pub use afk_settings::AfkSettings;
+pub use afk_state::{AfkState, DEFAULT_AFK_MESSAGE};
 pub use app_state::AppState;
+pub use auto_loot_settings::AutoLootSettings;
 pub use character_list::CharacterList;
 pub use character_select_state::CharacterSelectState;
+pub use character_store::{get_config_dir, get_data_dir, CharacterStoreKey};
 pub use client_entity_list::ClientEntityList;
+pub use config_persistence::ConfigPersistence;
+pub use conversation_dialog_portrait::{
+    ConversationDialogPortrait, CONVERSATION_DIALOG_PORTRAIT_SIZE,
+};
+pub use cooldown_alerts::{CooldownAlertSettings, CooldownAlerts};
+pub use cooldown_store::CooldownStore;
 pub use current_zone::CurrentZone;
-pub use damage_digits_spawner::DamageDigitsSpawner;
-pub use debug_inspector::DebugInspector;
+pub use damage_digits_spawner::{
+    DamageDigitStyle, DamageDigitType, DamageDigitsSpawner, StatusEffectText, StatusEffectTextType,
+};
+pub use debug_inspector::{DebugInspector, DebugInspectorSnapshot};
 pub use debug_render::DebugRenderConfig;
+pub use debug_time_control::{DebugTimeControl, DEBUG_TIME_SCALE_MAX, DEBUG_TIME_SCALE_MIN};
+pub use defend_target::DefendTarget;
+pub use entity_history::{
+    EntityHistory, EntityHistoryEvent, EntityHistoryPosition, ENTITY_HISTORY_MAX_DURATION,
+    ENTITY_HISTORY_MIN_DURATION,
+};
+pub use entity_pool::{EntityPool, ENTITY_POOL_SIZE};
+pub use event_schedule::{EventSchedule, ScheduledEvent};
+pub use footstep_effects::{
+    create_footstep_surface_materials, get_image_average_colour, FootstepEffectKind,
+    FootstepEffects, FootstepFadeMaterials, FootstepSurfaceMaterials, FOOTSTEP_EFFECT_FADE_LEVELS,
+    FOOTSTEP_EFFECT_POOL_SIZE,
+};
+pub use friend_list::{Friend, FriendList};
 pub use game_connection::GameConnection;
 pub use game_data::GameData;
+pub use game_reconnect::{GameReconnect, GameReconnectState, GAME_RECONNECT_MAX_ATTEMPTS};
+pub use game_version_registry::{GameVersionLoader, GameVersionRegistry};
+pub use graphics_quality::{
+    AntiAliasing, GraphicsBenchmark, GraphicsQuality, GraphicsQualityPreset,
+    GraphicsQualitySettings, ShadowQuality, TextureQuality,
+};
+pub use headless_smoke_test::HeadlessSmokeTest;
+pub use hud_layout::{HudElement, HudElementLayout, HudLayout};
+pub use input_bindings::{
+    ActionBindings, InputAction, InputBindings, InputContext, KeyBinding, INPUT_BINDINGS_PER_ACTION,
+};
+pub use item_drop_effects::{ItemDropEffects, ItemDropRarity, ItemDropRarityEffect};
+pub use item_grade_effect_settings::ItemGradeEffectSettings;
+pub use level_up_presentation::{LevelUpPresentation, LevelUpSettings};
 pub use login_connection::LoginConnection;
+pub use login_profiles::{LoginProfile, LoginProfiles};
 pub use login_state::LoginState;
+pub use logout::{Logout, LogoutState, LogoutTarget};
+pub use message_of_the_day::MessageOfTheDay;
+pub use missing_assets::MissingAssets;
 pub use name_tag_settings::NameTagSettings;
 pub use network_thread::{run_network_thread, NetworkThread, NetworkThreadMessage};
+pub use packet_inspector::{PacketInspector, PacketInspectorDirection, PacketInspectorEntry};
+pub use personal_store_list_requests::PersonalStoreListRequests;
+pub use protocol_registry::ProtocolRegistry;
 pub use render_configuration::RenderConfiguration;
+pub use replay::{
+    create_replay_packet, ReplayConnection, ReplayInputEvent, ReplayPlayback, ReplayRecord,
+    ReplayRecordData, ReplayRecorder, ReplayRng,
+};
 pub use selected_target::SelectedTarget;
-pub use server_configuration::ServerConfiguration;
+pub use server_configuration::{title_zone_id, ServerConfiguration};
 pub use server_list::{ServerList, ServerListGameServer, ServerListWorldServer};
+pub use session_statistics::SessionStatistics;
+pub use setup_wizard::{SetupDataType, SetupWizard};
+pub use skill_queue::{QueuedSkill, SkillQueue};
+pub use skill_range_indicator::SkillRangeIndicator;
 pub use sound_cache::SoundCache;
 pub use sound_settings::SoundSettings;
 pub use specular_texture::SpecularTexture;
@@ -54,8 +159,19 @@ pub use ui_resources::{
     load_ui_resources, ui_requested_cursor_apply_system, update_ui_resources, UiCursorType,
     UiRequestedCursor, UiResources, UiSprite, UiSpriteSheet, UiSpriteSheetType, UiTexture,
 };
+pub use ui_screenshot_harness::UiScreenshotHarness;
+pub use user_settings::{UserSettings, UserSettingsStore};
 pub use virtual_filesystem::VfsResource;
+pub use water_reflection::WaterReflection;
+pub use waypoints::{Waypoint, Waypoints};
+pub use window_settings::{
+    AvailableMonitors, MinimizeOnFocusLoss, MonitorInfo, MonitorVideoMode, WindowModeSetting,
+    WindowSettings,
+};
 pub use world_connection::WorldConnection;
 pub use world_rates::WorldRates;
 pub use world_time::WorldTime;
+pub use zone_benchmark::ZoneBenchmark;
+pub use zone_music_playlists::{ZoneMusicPlaylist, ZoneMusicPlaylists};
 pub use zone_time::{ZoneTime, ZoneTimeState};
+pub use zone_validation_result::ZoneValidationResult;
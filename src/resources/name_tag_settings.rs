@@ -7,6 +7,10 @@ use crate::components::NameTagType;
 pub struct NameTagSettings {
     pub show_all: EnumMap<NameTagType, bool>,
     pub font_size: EnumMap<NameTagType, f32>,
+    /// Show the health bar of monsters the player or their party is fighting.
+    pub combat_healthbars: bool,
+    /// Show local chat in a speech bubble above the character who said it.
+    pub chat_bubbles: bool,
 }
 
 impl Default for NameTagSettings {
@@ -22,6 +26,8 @@ impl Default for NameTagSettings {
                 NameTagType::Npc => 16.0,
                 NameTagType::Monster => 16.0,
             },
+            combat_healthbars: true,
+            chat_bubbles: true,
         }
     }
 }
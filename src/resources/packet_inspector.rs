@@ -0,0 +1,110 @@
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use bevy::prelude::Resource;
+
+use crate::resources::ReplayConnection;
+
+const PACKET_INSPECTOR_MAX_PENDING_ENTRIES: usize = 10000;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PacketInspectorDirection {
+    Incoming,
+    Outgoing,
+}
+
+#[derive(Clone)]
+pub struct PacketInspectorEntry {
+    pub time: Duration,
+    pub connection: ReplayConnection,
+    pub direction: PacketInspectorDirection,
+    pub commands: Vec<u16>,
+    pub size: usize,
+    pub names: Vec<String>,
+    pub fields: Vec<String>,
+}
+
+impl PacketInspectorEntry {
+    pub fn name(&self) -> String {
+        if self.names.is_empty() {
+            "Unhandled".to_string()
+        } else {
+            self.names.join(", ")
+        }
+    }
+}
+
+/// Shared with the network thread, entries are only captured while the packet
+/// inspector window is open.
+#[derive(Clone, Resource)]
+pub struct PacketInspector {
+    start_time: Instant,
+    enabled: Arc<AtomicBool>,
+    pending: Arc<Mutex<VecDeque<PacketInspectorEntry>>>,
+}
+
+impl Default for PacketInspector {
+    fn default() -> Self {
+        Self {
+            start_time: Instant::now(),
+            enabled: Arc::new(AtomicBool::new(false)),
+            pending: Arc::new(Mutex::new(VecDeque::new())),
+        }
+    }
+}
+
+impl PacketInspector {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+
+        if !enabled {
+            self.pending.lock().unwrap().clear();
+        }
+    }
+
+    pub fn create_entry(
+        &self,
+        connection: ReplayConnection,
+        direction: PacketInspectorDirection,
+    ) -> PacketInspectorEntry {
+        PacketInspectorEntry {
+            time: self.start_time.elapsed(),
+            connection,
+            direction,
+            commands: Vec::new(),
+            size: 0,
+            names: Vec::new(),
+            fields: Vec::new(),
+        }
+    }
+
+    pub fn push_entry(&self, entry: PacketInspectorEntry) {
+        if !self.is_enabled() {
+            return;
+        }
+
+        let mut pending = self.pending.lock().unwrap();
+        if pending.len() >= PACKET_INSPECTOR_MAX_PENDING_ENTRIES {
+            pending.pop_front();
+        }
+        pending.push_back(entry);
+    }
+
+    pub fn pop_entry(&self) -> Option<PacketInspectorEntry> {
+        self.pending.lock().unwrap().pop_front()
+    }
+
+    pub fn num_pending_entries(&self) -> usize {
+        self.pending.lock().unwrap().len()
+    }
+}
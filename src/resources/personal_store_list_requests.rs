@@ -0,0 +1,11 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::{Entity, Resource};
+
+/// The personal store item list reply does not include the store entity, so
+/// the stores of outstanding item list requests are kept in the order they
+/// were sent to match each reply to its store.
+#[derive(Default, Resource)]
+pub struct PersonalStoreListRequests {
+    pub pending: VecDeque<Entity>,
+}
@@ -0,0 +1,48 @@
+use std::{collections::HashMap, sync::Arc};
+
+use bevy::prelude::Resource;
+
+use crate::protocol::{irose::IroseClientFactory, ProtocolClientFactory};
+
+/// The protocol client factories available for `game.network_version`.
+///
+/// Custom systems can register their own protocol by name from
+/// `SystemsConfig::add_custom_systems`, before the network version is selected.
+#[derive(Resource)]
+pub struct ProtocolRegistry {
+    factories: HashMap<String, Arc<dyn ProtocolClientFactory + Send + Sync>>,
+    selected: Option<Arc<dyn ProtocolClientFactory + Send + Sync>>,
+}
+
+impl Default for ProtocolRegistry {
+    fn default() -> Self {
+        let mut registry = Self {
+            factories: HashMap::new(),
+            selected: None,
+        };
+        registry.register("irose", IroseClientFactory);
+        registry
+    }
+}
+
+impl ProtocolRegistry {
+    /// Registers a protocol, replacing any existing protocol with the same name.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        factory: impl ProtocolClientFactory + Send + Sync + 'static,
+    ) {
+        self.factories.insert(name.into(), Arc::new(factory));
+    }
+
+    /// Selects the protocol used for new connections, returns false if there
+    /// is no protocol registered with that name.
+    pub fn select(&mut self, name: &str) -> bool {
+        self.selected = self.factories.get(name).cloned();
+        self.selected.is_some()
+    }
+
+    pub fn selected(&self) -> Option<&(dyn ProtocolClientFactory + Send + Sync)> {
+        self.selected.as_deref()
+    }
+}
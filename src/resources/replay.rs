@@ -0,0 +1,201 @@
+use std::{
+    collections::VecDeque,
+    fs::File,
+    io::{BufRead, BufReader, BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use bevy::{
+    input::{
+        keyboard::KeyboardInput,
+        mouse::{MouseButtonInput, MouseMotion, MouseWheel},
+    },
+    prelude::Resource,
+    utils::HashMap,
+};
+use lazy_static::lazy_static;
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+use rose_network_common::{Packet, PacketWriter};
+
+use crate::resources::get_data_dir;
+
+lazy_static! {
+    static ref REPLAY_RNG: Mutex<StdRng> = Mutex::new(StdRng::from_entropy());
+}
+
+/// A random number generator which is seeded from the replay, this should be used
+/// instead of thread_rng for anything which affects the game simulation.
+#[derive(Copy, Clone, Default)]
+pub struct ReplayRng;
+
+impl ReplayRng {
+    pub fn seed(seed: u64) {
+        if let Ok(mut rng) = REPLAY_RNG.lock() {
+            *rng = StdRng::seed_from_u64(seed);
+        }
+    }
+}
+
+impl RngCore for ReplayRng {
+    fn next_u32(&mut self) -> u32 {
+        REPLAY_RNG.lock().unwrap().next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        REPLAY_RNG.lock().unwrap().next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        REPLAY_RNG.lock().unwrap().fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        REPLAY_RNG.lock().unwrap().try_fill_bytes(dest)
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ReplayConnection {
+    Login,
+    World,
+    Game,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ReplayInputEvent {
+    Keyboard(KeyboardInput),
+    MouseButton(MouseButtonInput),
+    MouseWheel(MouseWheel),
+    MouseMotion(MouseMotion),
+    CursorMoved { position: [f32; 2] },
+    ReceivedCharacter { char: char },
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ReplayRecordData {
+    Seed {
+        seed: u64,
+    },
+    Input(ReplayInputEvent),
+    ServerPacket {
+        connection: ReplayConnection,
+        command: u16,
+        data: Vec<u8>,
+    },
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ReplayRecord {
+    pub time_us: u64,
+    pub data: ReplayRecordData,
+}
+
+/// Writes the replay records as json lines, it is shared with the network thread so
+/// packets are recorded in the order they were received.
+#[derive(Clone, Resource)]
+pub struct ReplayRecorder {
+    start_time: Instant,
+    writer: Arc<Mutex<BufWriter<File>>>,
+}
+
+impl ReplayRecorder {
+    pub fn new() -> Result<(Self, PathBuf), anyhow::Error> {
+        let path = get_data_dir()?.join("replays").join(format!(
+            "replay-{}.jsonl",
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ));
+
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let recorder = Self {
+            start_time: Instant::now(),
+            writer: Arc::new(Mutex::new(BufWriter::new(File::create(&path)?))),
+        };
+
+        let seed = rand::random::<u64>();
+        ReplayRng::seed(seed);
+        recorder.record(ReplayRecordData::Seed { seed });
+
+        Ok((recorder, path))
+    }
+
+    pub fn record(&self, data: ReplayRecordData) {
+        let record = ReplayRecord {
+            time_us: self.start_time.elapsed().as_micros() as u64,
+            data,
+        };
+
+        let Ok(mut writer) = self.writer.lock() else {
+            return;
+        };
+
+        // Flush every record so the replay is complete even if the client crashes
+        if let Err(error) = serde_json::to_writer(&mut *writer, &record)
+            .map_err(anyhow::Error::from)
+            .and_then(|_| writer.write_all(b"\n").map_err(anyhow::Error::from))
+            .and_then(|_| writer.flush().map_err(anyhow::Error::from))
+        {
+            log::warn!("Failed to write replay record: {}", error);
+        }
+    }
+
+    pub fn record_packet(&self, connection: ReplayConnection, packet: &Packet) {
+        self.record(ReplayRecordData::ServerPacket {
+            connection,
+            command: packet.command,
+            data: packet.data[..].to_vec(),
+        });
+    }
+}
+
+#[derive(Resource)]
+pub struct ReplayPlayback {
+    pub headless: bool,
+    pub records: VecDeque<ReplayRecord>,
+    pub start_time: Option<Duration>,
+    pub connections: HashMap<ReplayConnection, tokio::sync::mpsc::UnboundedSender<Packet>>,
+}
+
+impl ReplayPlayback {
+    pub fn load(path: &Path, headless: bool) -> Result<Self, anyhow::Error> {
+        let mut records = VecDeque::new();
+        for line in BufReader::new(File::open(path)?).lines() {
+            let line = line?;
+            if line.is_empty() {
+                continue;
+            }
+
+            let record: ReplayRecord = serde_json::from_str(&line)?;
+            if let ReplayRecordData::Seed { seed } = record.data {
+                ReplayRng::seed(seed);
+            } else {
+                records.push_back(record);
+            }
+        }
+
+        Ok(Self {
+            headless,
+            records,
+            start_time: None,
+            connections: HashMap::default(),
+        })
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.records.is_empty()
+    }
+}
+
+pub fn create_replay_packet(command: u16, data: &[u8]) -> Packet {
+    let mut writer = PacketWriter::new(command);
+    for byte in data {
+        writer.write_u8(*byte);
+    }
+    writer.into()
+}
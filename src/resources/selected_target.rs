@@ -1,7 +1,9 @@
-use bevy::prelude::{Entity, Resource};
+use bevy::prelude::{Entity, Resource, Vec2};
 
 #[derive(Default, Resource)]
 pub struct SelectedTarget {
     pub selected: Option<Entity>,
     pub hover: Option<Entity>,
+    /// Entity which was right clicked, and the cursor position to open its context menu at.
+    pub context_menu: Option<(Entity, Vec2)>,
 }
@@ -1,13 +1,88 @@
-use bevy::prelude::Resource;
+use bevy::prelude::{Resource, Vec2};
+
+use rose_data::ZoneId;
+
+use crate::resources::LoginProfile;
+
+const TITLE_LOGIN_CAMERA: &str = "3DDATA/TITLE/CAMERA01_INTRO01.ZMO";
+
+/// The title zone contains the character select scene, it is also shown behind
+/// the login screen unless the server configures another zone.
+pub fn title_zone_id() -> ZoneId {
+    ZoneId::new(4).unwrap()
+}
 
 #[derive(Resource)]
 pub struct ServerConfiguration {
     pub ip: String,
     pub port: String,
+    pub event_schedule_url: String,
+    pub motd_url: String,
+    pub login_zone: ZoneId,
+    pub login_camera: String,
+    pub login_music: Option<String>,
     pub preset_username: Option<String>,
     pub preset_password: Option<String>,
     pub preset_server_id: Option<usize>,
     pub preset_channel_id: Option<usize>,
     pub preset_character_name: Option<String>,
+    pub preset_start_zone: Option<ZoneId>,
+    pub preset_start_position: Option<Vec2>,
     pub auto_login: bool,
+    pub skip_intro: bool,
+}
+
+impl ServerConfiguration {
+    pub fn from_profile(profile: &LoginProfile) -> Self {
+        let auto_login = &profile.config.auto_login;
+        let mut server_configuration = Self {
+            ip: String::new(),
+            port: String::new(),
+            event_schedule_url: String::new(),
+            motd_url: String::new(),
+            login_zone: title_zone_id(),
+            login_camera: TITLE_LOGIN_CAMERA.into(),
+            login_music: None,
+            preset_username: None,
+            preset_password: None,
+            preset_server_id: auto_login.server_id,
+            preset_channel_id: auto_login.channel_id,
+            preset_character_name: auto_login.character_name.clone(),
+            preset_start_zone: auto_login.start_zone.and_then(ZoneId::new),
+            preset_start_position: auto_login.start_position.map(Vec2::from),
+            auto_login: auto_login.enabled,
+            skip_intro: auto_login.skip_intro,
+        };
+        server_configuration.select_profile(profile);
+        server_configuration
+    }
+
+    /// Use the server and account of profile, the auto login settings are only
+    /// used from the profile selected at startup.
+    pub fn select_profile(&mut self, profile: &LoginProfile) {
+        self.ip = profile.config.server.ip.clone();
+        self.port = format!("{}", profile.config.server.port);
+        self.event_schedule_url = profile.config.server.event_schedule_url.clone();
+        self.motd_url = profile.config.server.motd_url.clone();
+        self.login_zone = profile
+            .config
+            .server
+            .login_zone
+            .and_then(ZoneId::new)
+            .unwrap_or_else(title_zone_id);
+        self.login_camera = Some(profile.config.server.login_camera.clone())
+            .filter(|path| !path.is_empty())
+            .unwrap_or_else(|| TITLE_LOGIN_CAMERA.into());
+        self.login_music =
+            Some(profile.config.server.login_music.clone()).filter(|path| !path.is_empty());
+        self.preset_username =
+            Some(profile.config.username.clone()).filter(|username| !username.is_empty());
+        self.preset_password = profile.password();
+    }
+
+    /// The title screen and character select camera animations are only
+    /// skipped when using auto login.
+    pub fn should_skip_intro(&self) -> bool {
+        self.auto_login && self.skip_intro
+    }
 }
@@ -0,0 +1,61 @@
+use bevy::prelude::Resource;
+use chrono::{DateTime, Local};
+
+use rose_data::{ItemReference, NpcId};
+
+/// Statistics gathered since the current character entered the game.
+#[derive(Resource)]
+pub struct SessionStatistics {
+    pub start_time: DateTime<Local>,
+    pub xp_earned: u64,
+    pub money_earned: i64,
+    pub kills: Vec<(NpcId, u32)>,
+    pub items_looted: Vec<(ItemReference, u32)>,
+}
+
+impl Default for SessionStatistics {
+    fn default() -> Self {
+        Self {
+            start_time: Local::now(),
+            xp_earned: 0,
+            money_earned: 0,
+            kills: Vec::new(),
+            items_looted: Vec::new(),
+        }
+    }
+}
+
+impl SessionStatistics {
+    pub fn add_kill(&mut self, npc_id: NpcId) {
+        if let Some((_, count)) = self.kills.iter_mut().find(|(id, _)| *id == npc_id) {
+            *count += 1;
+        } else {
+            self.kills.push((npc_id, 1));
+        }
+    }
+
+    pub fn add_item(&mut self, item: ItemReference, quantity: u32) {
+        if let Some((_, count)) = self
+            .items_looted
+            .iter_mut()
+            .find(|(looted_item, _)| *looted_item == item)
+        {
+            *count += quantity;
+        } else {
+            self.items_looted.push((item, quantity));
+        }
+    }
+
+    pub fn get_total_kills(&self) -> u32 {
+        self.kills.iter().map(|(_, count)| count).sum()
+    }
+
+    pub fn get_elapsed_hours(&self) -> f64 {
+        let elapsed = Local::now().signed_duration_since(self.start_time);
+        elapsed.num_milliseconds().max(1) as f64 / (60.0 * 60.0 * 1000.0)
+    }
+
+    pub fn get_per_hour(&self, value: f64) -> f64 {
+        value / self.get_elapsed_hours()
+    }
+}
@@ -0,0 +1,141 @@
+use std::{
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+use bevy::prelude::Resource;
+
+use crate::{FilesystemConfig, FilesystemDeviceConfig};
+
+// Every supported client contains the zone list, so use it to check the data is valid
+const SETUP_VALIDATE_PATH: &str = "3DDATA/STB/LIST_ZONE.STB";
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SetupDataType {
+    Vfs,
+    Directory,
+    AruaVfs,
+    TitanVfs,
+    IrosePh,
+}
+
+impl SetupDataType {
+    pub const ALL: [SetupDataType; 5] = [
+        SetupDataType::Vfs,
+        SetupDataType::Directory,
+        SetupDataType::AruaVfs,
+        SetupDataType::TitanVfs,
+        SetupDataType::IrosePh,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            SetupDataType::Vfs => "irose data.idx",
+            SetupDataType::Directory => "Extracted data folder",
+            SetupDataType::AruaVfs => "AruaRose data.idx",
+            SetupDataType::TitanVfs => "TitanRose data.idx",
+            SetupDataType::IrosePh => "iRosePH data index",
+        }
+    }
+
+    pub fn is_directory(self) -> bool {
+        matches!(self, SetupDataType::Directory)
+    }
+
+    fn device_config(self, path: String) -> FilesystemDeviceConfig {
+        match self {
+            SetupDataType::Vfs => FilesystemDeviceConfig::Vfs(path),
+            SetupDataType::Directory => FilesystemDeviceConfig::Directory(path),
+            SetupDataType::AruaVfs => FilesystemDeviceConfig::AruaVfs(path),
+            SetupDataType::TitanVfs => FilesystemDeviceConfig::TitanVfs(path),
+            SetupDataType::IrosePh => FilesystemDeviceConfig::IrosePh(path),
+        }
+    }
+}
+
+/// Used by the first run setup window to choose the game data location and
+/// write it to the filesystem section of the config.
+#[derive(Resource)]
+pub struct SetupWizard {
+    pub config_path: PathBuf,
+    pub data_type: SetupDataType,
+    pub path: String,
+    pub error: Option<String>,
+    completed: Arc<AtomicBool>,
+}
+
+impl SetupWizard {
+    pub fn new(config_path: PathBuf, completed: Arc<AtomicBool>) -> Self {
+        Self {
+            config_path,
+            data_type: SetupDataType::Vfs,
+            path: String::new(),
+            error: None,
+            completed,
+        }
+    }
+
+    fn validate(&self) -> Result<FilesystemConfig, anyhow::Error> {
+        if self.path.is_empty() {
+            anyhow::bail!("Select the location of your game data");
+        }
+
+        let filesystem = FilesystemConfig {
+            devices: vec![self.data_type.device_config(self.path.clone())],
+        };
+        let vfs = filesystem
+            .try_create_virtual_filesystem()?
+            .ok_or_else(|| anyhow::anyhow!("No filesystem devices"))?;
+        if vfs.open_file(SETUP_VALIDATE_PATH).is_err() {
+            anyhow::bail!(
+                "Could not find {}, is this the correct data location?",
+                SETUP_VALIDATE_PATH
+            );
+        }
+
+        Ok(filesystem)
+    }
+
+    /// Replaces the filesystem section of the config, keeping any other
+    /// sections already in the file.
+    fn write_config(&self, filesystem: &FilesystemConfig) -> Result<(), anyhow::Error> {
+        let mut config = match std::fs::read_to_string(&self.config_path) {
+            Ok(toml_str) => toml::from_str::<toml::Table>(&toml_str)?,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => toml::Table::new(),
+            Err(error) => return Err(error.into()),
+        };
+        config.insert("filesystem".into(), toml::Value::try_from(filesystem)?);
+
+        if let Some(parent) = self.config_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(&self.config_path, toml::to_string_pretty(&config)?)?;
+        Ok(())
+    }
+
+    /// Validates the selected data and writes the config, returns true on success.
+    pub fn complete(&mut self) -> bool {
+        let result = self
+            .validate()
+            .and_then(|filesystem| self.write_config(&filesystem));
+
+        match result {
+            Ok(_) => {
+                log::info!(
+                    "Wrote game data location to {}",
+                    self.config_path.to_string_lossy()
+                );
+                self.error = None;
+                self.completed.store(true, Ordering::Relaxed);
+                true
+            }
+            Err(error) => {
+                self.error = Some(error.to_string());
+                false
+            }
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use bevy::prelude::{Entity, Resource};
+
+use rose_game_common::components::SkillSlot;
+
+#[derive(Copy, Clone)]
+pub struct QueuedSkill {
+    pub skill_slot: SkillSlot,
+    pub target_entity: Option<Entity>,
+}
+
+/// A skill which is waiting to be cast once the player has finished casting,
+/// the global cooldown has expired, and the target is within range.
+#[derive(Default, Resource)]
+pub struct SkillQueue {
+    pub queued: Option<QueuedSkill>,
+}
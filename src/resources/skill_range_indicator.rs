@@ -0,0 +1,10 @@
+use bevy::prelude::Resource;
+
+use rose_data::SkillId;
+
+/// The skill whose cast range and area of effect are drawn on the terrain,
+/// set each frame by the UI while a skill slot is hovered.
+#[derive(Default, Resource)]
+pub struct SkillRangeIndicator {
+    pub hovered_skill: Option<SkillId>,
+}
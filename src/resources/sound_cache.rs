@@ -1,6 +1,9 @@
-use std::sync::RwLock;
+use std::{collections::HashMap, sync::RwLock};
 
-use bevy::prelude::{AssetServer, Handle, Resource};
+use bevy::{
+    asset::HandleId,
+    prelude::{AssetServer, Handle, Resource},
+};
 use rose_data::{SoundData, SoundId};
 
 use crate::audio::AudioSource;
@@ -8,12 +11,14 @@ use crate::audio::AudioSource;
 #[derive(Resource)]
 pub struct SoundCache {
     pub cached_sounds: RwLock<Vec<Option<Handle<AudioSource>>>>,
+    pub sound_ids: RwLock<HashMap<HandleId, SoundId>>,
 }
 
 impl SoundCache {
     pub fn new(size: usize) -> Self {
         Self {
             cached_sounds: RwLock::new(vec![None; size]),
+            sound_ids: RwLock::new(HashMap::new()),
         }
     }
 
@@ -25,6 +30,11 @@ impl SoundCache {
             .and_then(|x| x.clone())
     }
 
+    /// Returns which sound in the sound database was loaded as this audio source.
+    pub fn get_sound_id(&self, handle: &Handle<AudioSource>) -> Option<SoundId> {
+        self.sound_ids.read().unwrap().get(&handle.id()).copied()
+    }
+
     pub fn load(&self, sound_data: &SoundData, asset_server: &AssetServer) -> Handle<AudioSource> {
         if let Some(cached) = self.get(sound_data.id) {
             return cached;
@@ -42,11 +52,13 @@ impl SoundCache {
             .unwrap()
             .get_mut(id.get() as usize)
         {
+            self.sound_ids.write().unwrap().insert(handle.id(), id);
             *cache = Some(handle);
         }
     }
 
     pub fn clear(&self) {
         self.cached_sounds.write().unwrap().fill(None);
+        self.sound_ids.write().unwrap().clear();
     }
 }
@@ -1,21 +1,50 @@
+use std::collections::HashMap;
+
 use bevy::prelude::Resource;
 use enum_map::EnumMap;
+use rose_data::SoundId;
 
-use crate::{audio::SoundGain, components::SoundCategory};
+use crate::{
+    audio::{SoundAttenuation, SoundGain},
+    components::SoundCategory,
+};
 
 #[derive(Resource)]
 pub struct SoundSettings {
     pub enabled: bool,
     pub global_gain: f32,
     pub gains: EnumMap<SoundCategory, f32>,
+
+    /// Distance falloff for spatial sounds of each category, or None to use the sound's radius
+    pub category_attenuation: EnumMap<SoundCategory, Option<SoundAttenuation>>,
+
+    /// Distance falloff for individual sounds from the sound database, by sound id
+    pub sound_attenuation: HashMap<u16, SoundAttenuation>,
+
+    /// Name of the audio output device, or None to follow the system default
+    pub output_device: Option<String>,
+
+    /// Temporarily mutes all sounds without changing the user's settings
+    pub muted: bool,
 }
 
 impl SoundSettings {
     pub fn gain(&self, category: SoundCategory) -> SoundGain {
-        if self.enabled {
+        if self.enabled && !self.muted {
             SoundGain::Ratio(self.global_gain * self.gains[category])
         } else {
             SoundGain::Ratio(0.0)
         }
     }
+
+    /// Returns the falloff for a sound, a sound's own falloff overrides its category's.
+    pub fn attenuation(
+        &self,
+        category: Option<SoundCategory>,
+        sound_id: Option<SoundId>,
+    ) -> Option<&SoundAttenuation> {
+        sound_id
+            .and_then(|sound_id| self.sound_attenuation.get(&sound_id.get()))
+            .or_else(|| category.and_then(|category| self.category_attenuation[category].as_ref()))
+    }
 }
@@ -0,0 +1,63 @@
+use std::path::PathBuf;
+
+use bevy::{
+    prelude::{Entity, Handle, Image, Resource},
+    utils::HashMap,
+};
+use bevy_egui::egui;
+
+/// Used by ui_screenshot_harness_system to render every dialog in turn and
+/// save a screenshot of each to output_dir.
+///
+/// The dialogs are drawn with a separate egui context, so the window UI is not
+/// captured, and rendered offscreen to an image by camera.
+#[derive(Resource)]
+pub struct UiScreenshotHarness {
+    pub output_dir: PathBuf,
+    pub dialog_filenames: Vec<String>,
+    pub current_index: usize,
+    pub current_frame: u32,
+    pub capture_requested: bool,
+    pub num_written: usize,
+    pub failed: Vec<String>,
+    pub egui_context: egui::Context,
+    pub managed_textures: HashMap<u64, Handle<Image>>,
+    pub camera: Option<Entity>,
+}
+
+impl UiScreenshotHarness {
+    pub fn new(output_dir: PathBuf) -> Self {
+        Self {
+            output_dir,
+            dialog_filenames: Vec::new(),
+            current_index: 0,
+            current_frame: 0,
+            capture_requested: false,
+            num_written: 0,
+            failed: Vec::new(),
+            egui_context: egui::Context::default(),
+            managed_textures: HashMap::default(),
+            camera: None,
+        }
+    }
+
+    pub fn current_dialog(&self) -> Option<&str> {
+        self.dialog_filenames
+            .get(self.current_index)
+            .map(|filename| filename.as_str())
+    }
+
+    pub fn next_dialog(&mut self) {
+        self.current_index += 1;
+        self.current_frame = 0;
+        self.capture_requested = false;
+    }
+
+    pub fn screenshot_path(&self, filename: &str) -> PathBuf {
+        let name = filename
+            .rsplit_once('.')
+            .map_or(filename, |(name, _)| name)
+            .to_lowercase();
+        self.output_dir.join(format!("{}.png", name))
+    }
+}
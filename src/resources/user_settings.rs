@@ -0,0 +1,684 @@
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use bevy::prelude::Resource;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    components::{NameTagType, SoundCategory},
+    resources::{
+        get_config_dir, AccessibilitySettings, AfkSettings, AntiAliasing, AutoLootSettings,
+        CooldownAlertSettings, DamageDigitStyle, DamageDigitType, DamageDigitsSpawner,
+        GraphicsQuality, GraphicsQualityPreset, GraphicsQualitySettings, InputBindings,
+        ItemGradeEffectSettings, KeyBinding, LevelUpSettings, MinimizeOnFocusLoss,
+        MonitorVideoMode, NameTagSettings, ShadowQuality, SoundSettings, StatusEffectTextType,
+        TextureQuality, WindowModeSetting, WindowSettings, INPUT_BINDINGS_PER_ACTION,
+    },
+    text_to_speech::TextToSpeechChannel,
+};
+
+/// Increment when a setting is renamed or changes meaning, and add a migration
+/// from the previous version to USER_SETTINGS_MIGRATIONS.
+pub const USER_SETTINGS_VERSION: u32 = 1;
+
+/// Each migration upgrades a settings file from version N to N + 1, where N is
+/// the index of the migration plus one.
+const USER_SETTINGS_MIGRATIONS: &[fn(&mut toml::Table)] = &[];
+
+// Wait for the user to stop changing settings before writing the file
+const USER_SETTINGS_SAVE_DELAY: Duration = Duration::from_secs(1);
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserSoundSettings {
+    pub enabled: bool,
+    pub global: f32,
+    pub background_music: f32,
+    pub player_footstep: f32,
+    pub player_combat: f32,
+    pub other_footstep: f32,
+    pub other_combat: f32,
+    pub npc_sounds: f32,
+    pub ui_sounds: f32,
+    /// Name of the output device, empty to use the system default.
+    pub output_device: String,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserAutoLootSettings {
+    pub enabled: bool,
+    pub radius: f32,
+    pub min_item_quality: u32,
+    pub pickup_money: bool,
+    pub pickup_equipment: bool,
+    pub pickup_consumables: bool,
+    pub pickup_materials: bool,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserAfkSettings {
+    pub auto_away_enabled: bool,
+    pub auto_away_timeout_secs: u64,
+    pub auto_reply_enabled: bool,
+    pub low_power_when_unfocused: bool,
+    pub mute_when_unfocused: bool,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserLevelUpSettings {
+    pub effect: bool,
+    pub fanfare: bool,
+    pub screen_flash: bool,
+    pub banner: bool,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserCooldownAlertSettings {
+    pub hotbar_pulse: bool,
+    pub toast: bool,
+    pub sound: bool,
+    pub sound_id: u16,
+    pub status_effect_warning_secs: u64,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserGraphicsSettings {
+    pub item_grade_effect_intensity: f32,
+    pub item_drop_effects: bool,
+    pub quality_preset: GraphicsQualityPreset,
+    pub shadow_quality: ShadowQuality,
+    pub draw_distance: f32,
+    pub particle_density: f32,
+    pub texture_quality: TextureQuality,
+    pub anti_aliasing: AntiAliasing,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserWindowSettings {
+    pub mode: WindowModeSetting,
+    /// Index of the monitor to use, or -1 to let the window manager choose.
+    pub monitor: i32,
+    /// Exclusive fullscreen video mode, a width of 0 uses the highest resolution.
+    pub exclusive_width: u32,
+    pub exclusive_height: u32,
+    pub exclusive_refresh_rate: u32,
+    pub minimize_on_focus_loss: MinimizeOnFocusLoss,
+    pub vsync: bool,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserNameTagSettings {
+    pub show_all_characters: bool,
+    pub show_all_npcs: bool,
+    pub show_all_monsters: bool,
+    pub character_font_size: f32,
+    pub npc_font_size: f32,
+    pub monster_font_size: f32,
+    pub combat_healthbars: bool,
+    pub chat_bubbles: bool,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserCombatTextStyle {
+    pub color: [f32; 3],
+    pub scale: f32,
+}
+
+impl From<&DamageDigitStyle> for UserCombatTextStyle {
+    fn from(style: &DamageDigitStyle) -> Self {
+        Self {
+            color: style.color,
+            scale: style.scale,
+        }
+    }
+}
+
+impl From<&UserCombatTextStyle> for DamageDigitStyle {
+    fn from(style: &UserCombatTextStyle) -> Self {
+        DamageDigitStyle::new(style.color, style.scale)
+    }
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserCombatTextSettings {
+    pub merge_small_hits: bool,
+    pub merge_max_damage: u32,
+    pub merge_window_ms: u64,
+    pub dealt: UserCombatTextStyle,
+    pub critical: UserCombatTextStyle,
+    pub taken: UserCombatTextStyle,
+    pub miss: UserCombatTextStyle,
+    pub heal: UserCombatTextStyle,
+    pub status_effect_text: bool,
+    pub status_effect_buff_color: [f32; 3],
+    pub status_effect_debuff_color: [f32; 3],
+    pub status_effect_resisted_color: [f32; 3],
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserAccessibilitySettings {
+    pub effect_intensity: f32,
+    pub bloom_intensity: f32,
+    pub reduce_flashing: bool,
+    pub text_to_speech_enabled: bool,
+    pub text_to_speech_say: bool,
+    pub text_to_speech_shout: bool,
+    pub text_to_speech_whisper: bool,
+    pub text_to_speech_announce: bool,
+    pub text_to_speech_system: bool,
+    pub text_to_speech_quest: bool,
+    pub text_to_speech_dialog: bool,
+    pub text_to_speech_message_box: bool,
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserControlSettings {
+    /// The bindings of each action by name, an empty string is unbound.
+    pub bindings: BTreeMap<String, [String; INPUT_BINDINGS_PER_ACTION]>,
+}
+
+/// The settings which can be changed from the in game settings window, saved
+/// to settings.toml in the user's config directory. This is separate to the
+/// launch config.toml, values stored here take priority over it. Settings
+/// which config.toml also has are written back to it by ConfigPersistence.
+#[derive(Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserSettings {
+    pub version: u32,
+    pub sound: UserSoundSettings,
+    pub auto_loot: UserAutoLootSettings,
+    pub afk: UserAfkSettings,
+    pub level_up: UserLevelUpSettings,
+    pub cooldown_alerts: UserCooldownAlertSettings,
+    pub graphics: UserGraphicsSettings,
+    pub window: UserWindowSettings,
+    pub name_tags: UserNameTagSettings,
+    pub combat_text: UserCombatTextSettings,
+    pub accessibility: UserAccessibilitySettings,
+    pub controls: UserControlSettings,
+}
+
+impl UserSettings {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        sound_settings: &SoundSettings,
+        auto_loot_settings: &AutoLootSettings,
+        afk_settings: &AfkSettings,
+        level_up_settings: &LevelUpSettings,
+        cooldown_alert_settings: &CooldownAlertSettings,
+        item_grade_effect_settings: &ItemGradeEffectSettings,
+        graphics_quality: &GraphicsQuality,
+        window_settings: &WindowSettings,
+        name_tag_settings: &NameTagSettings,
+        damage_digits_spawner: &DamageDigitsSpawner,
+        accessibility_settings: &AccessibilitySettings,
+        input_bindings: &InputBindings,
+    ) -> Self {
+        let status_effect_text_colors = &damage_digits_spawner.status_effect_text_colors;
+        let text_to_speech_channels = &accessibility_settings.text_to_speech_channels;
+        Self {
+            version: USER_SETTINGS_VERSION,
+            sound: UserSoundSettings {
+                enabled: sound_settings.enabled,
+                global: sound_settings.global_gain,
+                background_music: sound_settings.gains[SoundCategory::BackgroundMusic],
+                player_footstep: sound_settings.gains[SoundCategory::PlayerFootstep],
+                player_combat: sound_settings.gains[SoundCategory::PlayerCombat],
+                other_footstep: sound_settings.gains[SoundCategory::OtherFootstep],
+                other_combat: sound_settings.gains[SoundCategory::OtherCombat],
+                npc_sounds: sound_settings.gains[SoundCategory::NpcSounds],
+                ui_sounds: sound_settings.gains[SoundCategory::Ui],
+                output_device: sound_settings.output_device.clone().unwrap_or_default(),
+            },
+            auto_loot: UserAutoLootSettings {
+                enabled: auto_loot_settings.enabled,
+                radius: auto_loot_settings.radius,
+                min_item_quality: auto_loot_settings.min_item_quality,
+                pickup_money: auto_loot_settings.pickup_money,
+                pickup_equipment: auto_loot_settings.pickup_equipment,
+                pickup_consumables: auto_loot_settings.pickup_consumables,
+                pickup_materials: auto_loot_settings.pickup_materials,
+            },
+            afk: UserAfkSettings {
+                auto_away_enabled: afk_settings.auto_away_enabled,
+                auto_away_timeout_secs: afk_settings.auto_away_timeout.as_secs(),
+                auto_reply_enabled: afk_settings.auto_reply_enabled,
+                low_power_when_unfocused: afk_settings.low_power_when_unfocused,
+                mute_when_unfocused: afk_settings.mute_when_unfocused,
+            },
+            level_up: UserLevelUpSettings {
+                effect: level_up_settings.effect,
+                fanfare: level_up_settings.fanfare,
+                screen_flash: level_up_settings.screen_flash,
+                banner: level_up_settings.banner,
+            },
+            cooldown_alerts: UserCooldownAlertSettings {
+                hotbar_pulse: cooldown_alert_settings.hotbar_pulse,
+                toast: cooldown_alert_settings.toast,
+                sound: cooldown_alert_settings.sound,
+                sound_id: cooldown_alert_settings.sound_id,
+                status_effect_warning_secs: cooldown_alert_settings.status_effect_warning.as_secs(),
+            },
+            graphics: UserGraphicsSettings {
+                item_grade_effect_intensity: item_grade_effect_settings.intensity,
+                item_drop_effects: item_grade_effect_settings.item_drop_effects,
+                quality_preset: graphics_quality.preset,
+                shadow_quality: graphics_quality.settings.shadow_quality,
+                draw_distance: graphics_quality.settings.draw_distance,
+                particle_density: graphics_quality.settings.particle_density,
+                texture_quality: graphics_quality.settings.texture_quality,
+                anti_aliasing: graphics_quality.settings.anti_aliasing,
+            },
+            window: UserWindowSettings {
+                mode: window_settings.mode,
+                monitor: window_settings.monitor.map_or(-1, |monitor| monitor as i32),
+                exclusive_width: window_settings
+                    .exclusive_video_mode
+                    .map_or(0, |video_mode| video_mode.width),
+                exclusive_height: window_settings
+                    .exclusive_video_mode
+                    .map_or(0, |video_mode| video_mode.height),
+                exclusive_refresh_rate: window_settings
+                    .exclusive_video_mode
+                    .map_or(0, |video_mode| video_mode.refresh_rate),
+                minimize_on_focus_loss: window_settings.minimize_on_focus_loss,
+                vsync: window_settings.vsync,
+            },
+            name_tags: UserNameTagSettings {
+                show_all_characters: name_tag_settings.show_all[NameTagType::Character],
+                show_all_npcs: name_tag_settings.show_all[NameTagType::Npc],
+                show_all_monsters: name_tag_settings.show_all[NameTagType::Monster],
+                character_font_size: name_tag_settings.font_size[NameTagType::Character],
+                npc_font_size: name_tag_settings.font_size[NameTagType::Npc],
+                monster_font_size: name_tag_settings.font_size[NameTagType::Monster],
+                combat_healthbars: name_tag_settings.combat_healthbars,
+                chat_bubbles: name_tag_settings.chat_bubbles,
+            },
+            combat_text: UserCombatTextSettings {
+                merge_small_hits: damage_digits_spawner.merge_small_hits,
+                merge_max_damage: damage_digits_spawner.merge_max_damage,
+                merge_window_ms: damage_digits_spawner.merge_window.as_millis() as u64,
+                dealt: (&damage_digits_spawner.styles[DamageDigitType::Dealt]).into(),
+                critical: (&damage_digits_spawner.styles[DamageDigitType::Critical]).into(),
+                taken: (&damage_digits_spawner.styles[DamageDigitType::Taken]).into(),
+                miss: (&damage_digits_spawner.styles[DamageDigitType::Miss]).into(),
+                heal: (&damage_digits_spawner.styles[DamageDigitType::Heal]).into(),
+                status_effect_text: damage_digits_spawner.status_effect_text_enabled,
+                status_effect_buff_color: status_effect_text_colors[StatusEffectTextType::Buff],
+                status_effect_debuff_color: status_effect_text_colors[StatusEffectTextType::Debuff],
+                status_effect_resisted_color: status_effect_text_colors
+                    [StatusEffectTextType::Resisted],
+            },
+            accessibility: UserAccessibilitySettings {
+                effect_intensity: accessibility_settings.effect_intensity,
+                bloom_intensity: accessibility_settings.bloom_intensity,
+                reduce_flashing: accessibility_settings.reduce_flashing,
+                text_to_speech_enabled: accessibility_settings.text_to_speech_enabled,
+                text_to_speech_say: text_to_speech_channels[TextToSpeechChannel::Say],
+                text_to_speech_shout: text_to_speech_channels[TextToSpeechChannel::Shout],
+                text_to_speech_whisper: text_to_speech_channels[TextToSpeechChannel::Whisper],
+                text_to_speech_announce: text_to_speech_channels[TextToSpeechChannel::Announce],
+                text_to_speech_system: text_to_speech_channels[TextToSpeechChannel::System],
+                text_to_speech_quest: text_to_speech_channels[TextToSpeechChannel::Quest],
+                text_to_speech_dialog: text_to_speech_channels[TextToSpeechChannel::Dialog],
+                text_to_speech_message_box: text_to_speech_channels
+                    [TextToSpeechChannel::MessageBox],
+            },
+            controls: UserControlSettings {
+                bindings: input_bindings
+                    .bindings
+                    .iter()
+                    .map(|(action, bindings)| {
+                        (
+                            format!("{:?}", action),
+                            bindings.map(|binding| {
+                                binding.map_or_else(String::new, |binding| binding.to_string())
+                            }),
+                        )
+                    })
+                    .collect(),
+            },
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn apply(
+        &self,
+        sound_settings: &mut SoundSettings,
+        auto_loot_settings: &mut AutoLootSettings,
+        afk_settings: &mut AfkSettings,
+        level_up_settings: &mut LevelUpSettings,
+        cooldown_alert_settings: &mut CooldownAlertSettings,
+        item_grade_effect_settings: &mut ItemGradeEffectSettings,
+        graphics_quality: &mut GraphicsQuality,
+        window_settings: &mut WindowSettings,
+        name_tag_settings: &mut NameTagSettings,
+        damage_digits_spawner: &mut DamageDigitsSpawner,
+        accessibility_settings: &mut AccessibilitySettings,
+        input_bindings: &mut InputBindings,
+    ) {
+        sound_settings.enabled = self.sound.enabled;
+        sound_settings.global_gain = self.sound.global;
+        sound_settings.gains[SoundCategory::BackgroundMusic] = self.sound.background_music;
+        sound_settings.gains[SoundCategory::PlayerFootstep] = self.sound.player_footstep;
+        sound_settings.gains[SoundCategory::PlayerCombat] = self.sound.player_combat;
+        sound_settings.gains[SoundCategory::OtherFootstep] = self.sound.other_footstep;
+        sound_settings.gains[SoundCategory::OtherCombat] = self.sound.other_combat;
+        sound_settings.gains[SoundCategory::NpcSounds] = self.sound.npc_sounds;
+        sound_settings.gains[SoundCategory::Ui] = self.sound.ui_sounds;
+        sound_settings.output_device =
+            (!self.sound.output_device.is_empty()).then(|| self.sound.output_device.clone());
+
+        auto_loot_settings.enabled = self.auto_loot.enabled;
+        auto_loot_settings.radius = self.auto_loot.radius;
+        auto_loot_settings.min_item_quality = self.auto_loot.min_item_quality;
+        auto_loot_settings.pickup_money = self.auto_loot.pickup_money;
+        auto_loot_settings.pickup_equipment = self.auto_loot.pickup_equipment;
+        auto_loot_settings.pickup_consumables = self.auto_loot.pickup_consumables;
+        auto_loot_settings.pickup_materials = self.auto_loot.pickup_materials;
+
+        afk_settings.auto_away_enabled = self.afk.auto_away_enabled;
+        afk_settings.auto_away_timeout = Duration::from_secs(self.afk.auto_away_timeout_secs);
+        afk_settings.auto_reply_enabled = self.afk.auto_reply_enabled;
+        afk_settings.low_power_when_unfocused = self.afk.low_power_when_unfocused;
+        afk_settings.mute_when_unfocused = self.afk.mute_when_unfocused;
+
+        level_up_settings.effect = self.level_up.effect;
+        level_up_settings.fanfare = self.level_up.fanfare;
+        level_up_settings.screen_flash = self.level_up.screen_flash;
+        level_up_settings.banner = self.level_up.banner;
+
+        cooldown_alert_settings.hotbar_pulse = self.cooldown_alerts.hotbar_pulse;
+        cooldown_alert_settings.toast = self.cooldown_alerts.toast;
+        cooldown_alert_settings.sound = self.cooldown_alerts.sound;
+        cooldown_alert_settings.sound_id = self.cooldown_alerts.sound_id;
+        cooldown_alert_settings.status_effect_warning =
+            Duration::from_secs(self.cooldown_alerts.status_effect_warning_secs);
+
+        item_grade_effect_settings.intensity = self.graphics.item_grade_effect_intensity;
+        item_grade_effect_settings.item_drop_effects = self.graphics.item_drop_effects;
+
+        graphics_quality.settings = GraphicsQualitySettings {
+            shadow_quality: self.graphics.shadow_quality,
+            draw_distance: self.graphics.draw_distance,
+            particle_density: self.graphics.particle_density,
+            texture_quality: self.graphics.texture_quality,
+            anti_aliasing: self.graphics.anti_aliasing,
+        };
+        graphics_quality.preset = GraphicsQualityPreset::from_settings(&graphics_quality.settings);
+
+        window_settings.mode = self.window.mode;
+        window_settings.monitor = usize::try_from(self.window.monitor).ok();
+        window_settings.exclusive_video_mode =
+            (self.window.exclusive_width != 0).then_some(MonitorVideoMode {
+                width: self.window.exclusive_width,
+                height: self.window.exclusive_height,
+                refresh_rate: self.window.exclusive_refresh_rate,
+            });
+        window_settings.minimize_on_focus_loss = self.window.minimize_on_focus_loss;
+        window_settings.vsync = self.window.vsync;
+
+        name_tag_settings.show_all[NameTagType::Character] = self.name_tags.show_all_characters;
+        name_tag_settings.show_all[NameTagType::Npc] = self.name_tags.show_all_npcs;
+        name_tag_settings.show_all[NameTagType::Monster] = self.name_tags.show_all_monsters;
+        name_tag_settings.font_size[NameTagType::Character] = self.name_tags.character_font_size;
+        name_tag_settings.font_size[NameTagType::Npc] = self.name_tags.npc_font_size;
+        name_tag_settings.font_size[NameTagType::Monster] = self.name_tags.monster_font_size;
+        name_tag_settings.combat_healthbars = self.name_tags.combat_healthbars;
+        name_tag_settings.chat_bubbles = self.name_tags.chat_bubbles;
+
+        damage_digits_spawner.styles[DamageDigitType::Dealt] = (&self.combat_text.dealt).into();
+        damage_digits_spawner.styles[DamageDigitType::Critical] =
+            (&self.combat_text.critical).into();
+        damage_digits_spawner.styles[DamageDigitType::Taken] = (&self.combat_text.taken).into();
+        damage_digits_spawner.styles[DamageDigitType::Miss] = (&self.combat_text.miss).into();
+        damage_digits_spawner.styles[DamageDigitType::Heal] = (&self.combat_text.heal).into();
+        damage_digits_spawner.merge_small_hits = self.combat_text.merge_small_hits;
+        damage_digits_spawner.merge_max_damage = self.combat_text.merge_max_damage;
+        damage_digits_spawner.merge_window =
+            Duration::from_millis(self.combat_text.merge_window_ms);
+        damage_digits_spawner.status_effect_text_enabled = self.combat_text.status_effect_text;
+        damage_digits_spawner.status_effect_text_colors[StatusEffectTextType::Buff] =
+            self.combat_text.status_effect_buff_color;
+        damage_digits_spawner.status_effect_text_colors[StatusEffectTextType::Debuff] =
+            self.combat_text.status_effect_debuff_color;
+        damage_digits_spawner.status_effect_text_colors[StatusEffectTextType::Resisted] =
+            self.combat_text.status_effect_resisted_color;
+
+        accessibility_settings.effect_intensity = self.accessibility.effect_intensity;
+        accessibility_settings.bloom_intensity = self.accessibility.bloom_intensity;
+        accessibility_settings.reduce_flashing = self.accessibility.reduce_flashing;
+        accessibility_settings.text_to_speech_enabled = self.accessibility.text_to_speech_enabled;
+
+        let text_to_speech_channels = &mut accessibility_settings.text_to_speech_channels;
+        text_to_speech_channels[TextToSpeechChannel::Say] = self.accessibility.text_to_speech_say;
+        text_to_speech_channels[TextToSpeechChannel::Shout] =
+            self.accessibility.text_to_speech_shout;
+        text_to_speech_channels[TextToSpeechChannel::Whisper] =
+            self.accessibility.text_to_speech_whisper;
+        text_to_speech_channels[TextToSpeechChannel::Announce] =
+            self.accessibility.text_to_speech_announce;
+        text_to_speech_channels[TextToSpeechChannel::System] =
+            self.accessibility.text_to_speech_system;
+        text_to_speech_channels[TextToSpeechChannel::Quest] =
+            self.accessibility.text_to_speech_quest;
+        text_to_speech_channels[TextToSpeechChannel::Dialog] =
+            self.accessibility.text_to_speech_dialog;
+        text_to_speech_channels[TextToSpeechChannel::MessageBox] =
+            self.accessibility.text_to_speech_message_box;
+
+        for (action, bindings) in input_bindings.bindings.iter_mut() {
+            let Some(names) = self.controls.bindings.get(&format!("{:?}", action)) else {
+                continue;
+            };
+
+            for (binding, name) in bindings.iter_mut().zip(names.iter()) {
+                *binding = if name.is_empty() {
+                    None
+                } else if let Some(parsed) = KeyBinding::parse(name) {
+                    Some(parsed)
+                } else {
+                    log::warn!("Ignoring invalid key binding {} for {:?}", name, action);
+                    continue;
+                };
+            }
+        }
+    }
+}
+
+/// Copies each value from loaded into defaults when it has the expected type,
+/// so a missing or invalid setting only resets that one setting.
+fn merge_settings_table(defaults: &mut toml::Table, loaded: &toml::Table, prefix: &str) {
+    for (key, default_value) in defaults.iter_mut() {
+        let Some(value) = loaded.get(key) else {
+            continue;
+        };
+
+        match (default_value, value) {
+            (toml::Value::Table(default_table), toml::Value::Table(table)) => {
+                merge_settings_table(default_table, table, &format!("{}{}.", prefix, key));
+            }
+            (toml::Value::Float(default_value), toml::Value::Integer(value)) => {
+                *default_value = *value as f64;
+            }
+            (toml::Value::Array(default_array), toml::Value::Array(array))
+                if default_array.len() != array.len() =>
+            {
+                log::warn!("Ignoring user setting {}{} with wrong length", prefix, key);
+            }
+            (default_value, value)
+                if std::mem::discriminant(default_value) == std::mem::discriminant(value) =>
+            {
+                *default_value = value.clone();
+            }
+            _ => {
+                log::warn!("Ignoring user setting {}{} with wrong type", prefix, key);
+            }
+        }
+    }
+}
+
+#[derive(Default, Resource)]
+pub struct UserSettingsStore {
+    loaded: toml::Table,
+    saved: Option<UserSettings>,
+    pending: Option<(Instant, UserSettings)>,
+}
+
+impl UserSettingsStore {
+    fn get_store_path() -> Option<PathBuf> {
+        get_config_dir()
+            .ok()
+            .map(|config_dir| config_dir.join("settings.toml"))
+    }
+
+    fn read_file(path: &Path) -> Result<Option<toml::Table>, anyhow::Error> {
+        let toml_str = match std::fs::read_to_string(path) {
+            Ok(toml_str) => toml_str,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(error) => return Err(error.into()),
+        };
+
+        let mut loaded: toml::Table = toml::from_str(&toml_str)?;
+        let version = loaded
+            .get("version")
+            .and_then(|version| version.as_integer())
+            .unwrap_or(1)
+            .max(1) as usize;
+        if version > USER_SETTINGS_VERSION as usize {
+            log::warn!(
+                "User settings version {} is newer than supported version {}",
+                version,
+                USER_SETTINGS_VERSION
+            );
+        }
+
+        for migration in USER_SETTINGS_MIGRATIONS.iter().skip(version - 1) {
+            migration(&mut loaded);
+        }
+
+        loaded.remove("version");
+        Ok(Some(loaded))
+    }
+
+    /// Reads the settings file, which is applied by load once the resources
+    /// it modifies have been created.
+    pub fn read() -> Self {
+        let Some(path) = Self::get_store_path() else {
+            return Self::default();
+        };
+
+        match Self::read_file(&path) {
+            Ok(Some(loaded)) => {
+                log::info!("Read user settings from {}", path.to_string_lossy());
+                Self {
+                    loaded,
+                    ..Default::default()
+                }
+            }
+            Ok(None) => Self::default(),
+            Err(error) => {
+                log::warn!(
+                    "Failed to read user settings from {} with error: {}",
+                    path.to_string_lossy(),
+                    error
+                );
+                Self::default()
+            }
+        }
+    }
+
+    /// Returns a single value from the settings file, for settings which must
+    /// be known before the app is created.
+    pub fn get_loaded<T: DeserializeOwned>(&self, section: &str, key: &str) -> Option<T> {
+        self.loaded.get(section)?.get(key)?.clone().try_into().ok()
+    }
+
+    /// Returns the loaded settings merged on top of current, which should
+    /// contain the values from the launch config.
+    pub fn load(&mut self, current: UserSettings) -> UserSettings {
+        let result = toml::to_string(&current)
+            .map_err(anyhow::Error::from)
+            .and_then(|toml_str| Ok(toml::from_str::<toml::Table>(&toml_str)?))
+            .and_then(|mut settings| {
+                merge_settings_table(&mut settings, &self.loaded, "");
+                Ok(toml::Value::Table(settings).try_into::<UserSettings>()?)
+            });
+
+        let settings = match result {
+            Ok(settings) => settings,
+            Err(error) => {
+                log::warn!("Failed to apply user settings with error: {}", error);
+                current
+            }
+        };
+
+        self.saved = Some(settings.clone());
+        settings
+    }
+
+    /// The settings most recently given to load or update.
+    pub fn latest(&self) -> Option<&UserSettings> {
+        self.pending
+            .as_ref()
+            .map(|(_, pending)| pending)
+            .or(self.saved.as_ref())
+    }
+
+    /// Queues the settings to be saved once they have stopped changing.
+    pub fn update(&mut self, settings: UserSettings) {
+        if self.saved.as_ref() == Some(&settings) {
+            self.pending = None;
+            return;
+        }
+
+        if self
+            .pending
+            .as_ref()
+            .map_or(false, |(_, pending)| pending == &settings)
+        {
+            return;
+        }
+
+        self.pending = Some((Instant::now(), settings));
+    }
+
+    pub fn save_pending(&mut self, force: bool) {
+        let Some((changed_time, _)) = self.pending.as_ref() else {
+            return;
+        };
+
+        if !force && changed_time.elapsed() < USER_SETTINGS_SAVE_DELAY {
+            return;
+        }
+
+        let Some((_, settings)) = self.pending.take() else {
+            return;
+        };
+
+        let Some(path) = Self::get_store_path() else {
+            return;
+        };
+
+        let result = toml::to_string_pretty(&settings)
+            .map_err(anyhow::Error::from)
+            .and_then(|toml_str| {
+                if let Some(parent) = path.parent() {
+                    std::fs::create_dir_all(parent)?;
+                }
+                std::fs::write(&path, toml_str)?;
+                Ok(())
+            });
+
+        if let Err(error) = result {
+            log::warn!(
+                "Failed to save user settings to {} with error: {}",
+                path.to_string_lossy(),
+                error
+            );
+        }
+
+        self.saved = Some(settings);
+    }
+}
@@ -0,0 +1,52 @@
+use bevy::{
+    math::Mat4,
+    prelude::{Assets, FromWorld, Handle, Image, Resource, World},
+    render::render_resource::{
+        Extent3d, TextureDescriptor, TextureDimension, TextureFormat, TextureUsages,
+    },
+};
+
+const WATER_REFLECTION_DEFAULT_STRENGTH: f32 = 0.5;
+
+/// The planar reflection sampled by the water material, rendered to image by a
+/// camera mirrored below the water plane nearest to the main camera.
+#[derive(Resource)]
+pub struct WaterReflection {
+    pub image: Handle<Image>,
+    /// The view projection of the reflection camera, None when nothing is reflected.
+    pub view_proj: Option<Mat4>,
+    pub strength: f32,
+}
+
+impl FromWorld for WaterReflection {
+    fn from_world(world: &mut World) -> Self {
+        // Resized to match the main camera by water_reflection_system
+        let size = Extent3d {
+            width: 1,
+            height: 1,
+            depth_or_array_layers: 1,
+        };
+        let mut image = Image {
+            texture_descriptor: TextureDescriptor {
+                label: Some("water_reflection"),
+                size,
+                dimension: TextureDimension::D2,
+                format: TextureFormat::Bgra8UnormSrgb,
+                mip_level_count: 1,
+                sample_count: 1,
+                usage: TextureUsages::TEXTURE_BINDING
+                    | TextureUsages::COPY_DST
+                    | TextureUsages::RENDER_ATTACHMENT,
+                view_formats: &[],
+            },
+            ..Default::default()
+        };
+        image.resize(size);
+
+        Self {
+            image: world.resource_mut::<Assets<Image>>().add(image),
+            view_proj: None,
+            strength: WATER_REFLECTION_DEFAULT_STRENGTH,
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use bevy::prelude::{Resource, Vec3};
+use serde::{Deserialize, Serialize};
+
+use rose_data::ZoneId;
+
+use crate::resources::CharacterStoreKey;
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Waypoint {
+    pub name: String,
+    pub zone_id: u16,
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Waypoint {
+    pub fn new(name: String, zone_id: ZoneId, position: Vec3) -> Self {
+        Self {
+            name,
+            zone_id: zone_id.get(),
+            x: position.x,
+            y: position.y,
+            z: position.z,
+        }
+    }
+
+    pub fn get_zone_id(&self) -> Option<ZoneId> {
+        ZoneId::new(self.zone_id)
+    }
+
+    pub fn get_position(&self) -> Vec3 {
+        Vec3::new(self.x, self.y, self.z)
+    }
+}
+
+#[derive(Default, Serialize, Deserialize)]
+#[serde(default)]
+struct WaypointStore {
+    waypoints: Vec<Waypoint>,
+}
+
+/// Personal waypoints for the current character, stored in the user's data
+/// directory alongside the achievement progress.
+#[derive(Default, Resource)]
+pub struct Waypoints {
+    pub store_key: Option<CharacterStoreKey>,
+    pub waypoints: Vec<Waypoint>,
+}
+
+impl Waypoints {
+    pub fn load(store_key: CharacterStoreKey) -> Self {
+        let store: WaypointStore = store_key.load("waypoints");
+
+        Self {
+            store_key: Some(store_key),
+            waypoints: store.waypoints,
+        }
+    }
+
+    pub fn save(&self) {
+        if let Some(store_key) = self.store_key.as_ref() {
+            store_key.save(
+                "waypoints",
+                &WaypointStore {
+                    waypoints: self.waypoints.clone(),
+                },
+            );
+        }
+    }
+
+    pub fn iter_zone(&self, zone_id: ZoneId) -> impl Iterator<Item = &Waypoint> + '_ {
+        self.waypoints
+            .iter()
+            .filter(move |waypoint| waypoint.zone_id == zone_id.get())
+    }
+}
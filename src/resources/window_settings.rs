@@ -0,0 +1,139 @@
+use bevy::{
+    prelude::Resource,
+    window::{MonitorSelection, PresentMode, WindowMode, WindowPosition},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{GraphicsConfig, GraphicsModeConfig};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WindowModeSetting {
+    Windowed,
+    Borderless,
+    Exclusive,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MinimizeOnFocusLoss {
+    Never,
+    ExclusiveFullscreen,
+    AnyFullscreen,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct MonitorVideoMode {
+    pub width: u32,
+    pub height: u32,
+    /// Refresh rate in Hz, 0 uses the highest available.
+    pub refresh_rate: u32,
+}
+
+pub struct MonitorInfo {
+    pub name: String,
+    pub video_modes: Vec<MonitorVideoMode>,
+}
+
+/// The monitors available to the primary window, in the same order used by
+/// MonitorSelection::Index.
+#[derive(Default, Resource)]
+pub struct AvailableMonitors {
+    pub monitors: Vec<MonitorInfo>,
+}
+
+#[derive(Clone, PartialEq, Resource)]
+pub struct WindowSettings {
+    pub mode: WindowModeSetting,
+    pub monitor: Option<usize>,
+    pub windowed_width: f32,
+    pub windowed_height: f32,
+    /// The video mode used in exclusive fullscreen, when None the monitor's
+    /// highest resolution is used.
+    pub exclusive_video_mode: Option<MonitorVideoMode>,
+    pub minimize_on_focus_loss: MinimizeOnFocusLoss,
+    pub vsync: bool,
+}
+
+impl WindowSettings {
+    pub fn from_config(config: &GraphicsConfig) -> Self {
+        let mut settings = Self {
+            mode: WindowModeSetting::Windowed,
+            monitor: config.monitor,
+            windowed_width: 1920.0,
+            windowed_height: 1080.0,
+            exclusive_video_mode: None,
+            minimize_on_focus_loss: config.minimize_on_focus_loss,
+            vsync: !config.disable_vsync,
+        };
+
+        match config.mode {
+            GraphicsModeConfig::Window { width, height } => {
+                settings.windowed_width = width;
+                settings.windowed_height = height;
+            }
+            GraphicsModeConfig::Borderless => {
+                settings.mode = WindowModeSetting::Borderless;
+            }
+            GraphicsModeConfig::Exclusive {
+                width,
+                height,
+                refresh_rate,
+            } => {
+                settings.mode = WindowModeSetting::Exclusive;
+                settings.exclusive_video_mode = Some(MonitorVideoMode {
+                    width,
+                    height,
+                    refresh_rate: refresh_rate.unwrap_or(0),
+                });
+            }
+        }
+
+        settings
+    }
+
+    pub fn window_mode(&self) -> WindowMode {
+        match self.mode {
+            WindowModeSetting::Windowed => WindowMode::Windowed,
+            WindowModeSetting::Borderless => WindowMode::BorderlessFullscreen,
+            WindowModeSetting::Exclusive if self.exclusive_video_mode.is_some() => {
+                WindowMode::SizedFullscreen
+            }
+            WindowModeSetting::Exclusive => WindowMode::Fullscreen,
+        }
+    }
+
+    pub fn present_mode(&self) -> PresentMode {
+        if self.vsync {
+            PresentMode::Fifo
+        } else {
+            PresentMode::Immediate
+        }
+    }
+
+    pub fn window_position(&self) -> WindowPosition {
+        match self.monitor {
+            Some(index) => WindowPosition::Centered(MonitorSelection::Index(index)),
+            None => WindowPosition::Automatic,
+        }
+    }
+
+    /// The window resolution, for sized fullscreen this is used to select the
+    /// video mode.
+    pub fn window_resolution(&self) -> (f32, f32) {
+        match self.exclusive_video_mode {
+            Some(video_mode) if self.mode == WindowModeSetting::Exclusive => {
+                (video_mode.width as f32, video_mode.height as f32)
+            }
+            _ => (self.windowed_width, self.windowed_height),
+        }
+    }
+
+    pub fn should_minimize_on_focus_loss(&self) -> bool {
+        match self.minimize_on_focus_loss {
+            MinimizeOnFocusLoss::Never => false,
+            MinimizeOnFocusLoss::ExclusiveFullscreen => self.mode == WindowModeSetting::Exclusive,
+            MinimizeOnFocusLoss::AnyFullscreen => self.mode != WindowModeSetting::Windowed,
+        }
+    }
+}
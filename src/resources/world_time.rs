@@ -4,6 +4,8 @@ use std::time::Duration;
 
 use rose_data::WorldTicks;
 
+use crate::resources::ReplayRng;
+
 #[derive(Resource)]
 pub struct WorldTime {
     pub ticks: WorldTicks,
@@ -12,7 +14,7 @@ pub struct WorldTime {
 
 impl Default for WorldTime {
     fn default() -> Self {
-        Self::new(WorldTicks(rand::thread_rng().gen_range(0..=9999)))
+        Self::new(WorldTicks(ReplayRng.gen_range(0..=9999)))
     }
 }
 
@@ -0,0 +1,46 @@
+use std::{
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicI32, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use bevy::prelude::Resource;
+
+/// Present when running with --benchmark, zone_benchmark_system plays the camera
+/// path once the zone has loaded, writes the report and exits the app.
+#[derive(Clone, Resource)]
+pub struct ZoneBenchmark {
+    /// Path of a camera ZMO to play, if None the camera orbits the zone center.
+    pub camera_motion: Option<String>,
+    /// Duration of the orbit when there is no camera_motion.
+    pub duration: Duration,
+    pub output_path: Option<PathBuf>,
+    exit_code: Arc<AtomicI32>,
+}
+
+impl ZoneBenchmark {
+    pub fn new(
+        camera_motion: Option<String>,
+        duration: Duration,
+        output_path: Option<PathBuf>,
+    ) -> Self {
+        Self {
+            camera_motion,
+            duration,
+            output_path,
+            // Assume failure until the report has been written
+            exit_code: Arc::new(AtomicI32::new(1)),
+        }
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        self.exit_code.load(Ordering::Relaxed)
+    }
+
+    pub fn set_exit_code(&self, exit_code: i32) {
+        self.exit_code.store(exit_code, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,80 @@
+use std::{collections::HashMap, path::Path};
+
+use bevy::prelude::Resource;
+use rose_data::ZoneId;
+use serde::Deserialize;
+
+const DEFAULT_CROSSFADE_DURATION: f32 = 2.0;
+
+#[derive(Deserialize)]
+#[serde(default)]
+struct ZoneMusicPlaylistsFile {
+    crossfade_duration: f32,
+    zones: HashMap<String, ZoneMusicPlaylist>,
+}
+
+impl Default for ZoneMusicPlaylistsFile {
+    fn default() -> Self {
+        Self {
+            crossfade_duration: DEFAULT_CROSSFADE_DURATION,
+            zones: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ZoneMusicPlaylist {
+    /// Tracks played during the morning and day.
+    pub day: Vec<String>,
+    /// Tracks played during the evening and night, the day tracks are used when empty.
+    pub night: Vec<String>,
+    /// Play the tracks in a random order rather than the order they are listed.
+    pub shuffle: bool,
+}
+
+/// Background music playlists which replace the day and night music of a zone
+/// from the zone list, read from the file at `zone_music_path` of the sound config.
+#[derive(Resource)]
+pub struct ZoneMusicPlaylists {
+    /// Seconds to crossfade between two tracks.
+    pub crossfade_duration: f32,
+    /// Playlists by zone id
+    pub zones: HashMap<u16, ZoneMusicPlaylist>,
+}
+
+impl Default for ZoneMusicPlaylists {
+    fn default() -> Self {
+        Self {
+            crossfade_duration: DEFAULT_CROSSFADE_DURATION,
+            zones: HashMap::new(),
+        }
+    }
+}
+
+impl ZoneMusicPlaylists {
+    pub fn load(path: &Path) -> Result<Self, anyhow::Error> {
+        let toml_str = std::fs::read_to_string(path)?;
+        let file: ZoneMusicPlaylistsFile = toml::from_str(&toml_str)?;
+
+        let mut zones = HashMap::with_capacity(file.zones.len());
+        for (zone_id, playlist) in file.zones {
+            let Some(zone_id) = zone_id.parse::<u16>().ok().and_then(ZoneId::new) else {
+                log::warn!("Invalid zone id {} in zone music playlists", zone_id);
+                continue;
+            };
+            zones.insert(zone_id.get(), playlist);
+        }
+
+        Ok(Self {
+            crossfade_duration: file.crossfade_duration.max(0.0),
+            zones,
+        })
+    }
+
+    pub fn get(&self, zone_id: ZoneId) -> Option<&ZoneMusicPlaylist> {
+        self.zones
+            .get(&zone_id.get())
+            .filter(|playlist| !playlist.day.is_empty() || !playlist.night.is_empty())
+    }
+}
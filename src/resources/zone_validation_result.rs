@@ -0,0 +1,32 @@
+use std::sync::{
+    atomic::{AtomicI32, Ordering},
+    Arc,
+};
+
+use bevy::prelude::Resource;
+
+/// Present when running with --validate-zone, zone_validation_system stores
+/// the exit code here and exits the app once the report has been written.
+#[derive(Clone, Resource)]
+pub struct ZoneValidationResult {
+    exit_code: Arc<AtomicI32>,
+}
+
+impl Default for ZoneValidationResult {
+    fn default() -> Self {
+        Self {
+            // Assume failure until the zone has been validated
+            exit_code: Arc::new(AtomicI32::new(1)),
+        }
+    }
+}
+
+impl ZoneValidationResult {
+    pub fn exit_code(&self) -> i32 {
+        self.exit_code.load(Ordering::Relaxed)
+    }
+
+    pub fn set_exit_code(&self, exit_code: i32) {
+        self.exit_code.store(exit_code, Ordering::Relaxed);
+    }
+}
@@ -4,7 +4,7 @@ use std::collections::HashMap;
 use rose_game_common::{components::CharacterGender, messages::ClientEntityId};
 
 use crate::{
-    events::{BankEvent, ClanDialogEvent, NpcStoreEvent},
+    events::{BankEvent, ClanDialogEvent, NpcStoreEvent, RepairEvent},
     scripting::{
         lua4::Lua4Value,
         lua_game_constants::{
@@ -38,6 +38,7 @@ impl Default for LuaGameFunctions {
         closures.insert("GF_openBank".into(), GF_openBank);
         closures.insert("GF_openStore".into(), GF_openStore);
         closures.insert("GF_organizeClan".into(), GF_organizeClan);
+        closures.insert("GF_repair".into(), GF_repair);
 
         /*
         GF_addUserMoney
@@ -79,7 +80,6 @@ impl Default for LuaGameFunctions {
         GF_putoffItem
         GF_putonItem
         GF_Random
-        GF_repair
         GF_rotateCamera
         GF_setEquipedItem
         GF_SetMotion
@@ -182,3 +182,22 @@ fn GF_organizeClan(
 
     vec![]
 }
+
+#[allow(non_snake_case)]
+fn GF_repair(
+    _resources: &ScriptFunctionResources,
+    context: &mut ScriptFunctionContext,
+    parameters: Vec<Lua4Value>,
+) -> Vec<Lua4Value> {
+    (|| -> Option<()> {
+        let client_entity_id = ClientEntityId(parameters.get(0)?.to_usize().ok()?);
+
+        context
+            .repair_events
+            .send(RepairEvent::OpenRepairFromClientEntity { client_entity_id });
+
+        Some(())
+    })();
+
+    vec![]
+}
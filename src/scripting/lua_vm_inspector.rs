@@ -0,0 +1,82 @@
+use std::collections::{HashMap, VecDeque};
+
+use bevy::prelude::Resource;
+use chrono::{DateTime, Local};
+
+use crate::scripting::lua4::Lua4Value;
+
+const LUA_VM_INSPECTOR_MAX_CALLS: usize = 200;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LuaVMInspectorCallKind {
+    Lua,
+    Rust,
+}
+
+pub struct LuaVMInspectorCall {
+    pub time: DateTime<Local>,
+    pub kind: LuaVMInspectorCallKind,
+    pub name: String,
+    pub parameters: Vec<String>,
+    pub result: Result<Vec<String>, String>,
+}
+
+/// Records the state of the most recently used Lua VM for the debug inspector window.
+#[derive(Default, Resource)]
+pub struct LuaVMInspector {
+    pub source: Option<String>,
+    pub globals: Vec<(String, String)>,
+    pub calls: VecDeque<LuaVMInspectorCall>,
+}
+
+pub fn format_lua_value(value: &Lua4Value) -> String {
+    match value {
+        Lua4Value::Nil => "nil".to_string(),
+        Lua4Value::UserData(_) => "userdata".to_string(),
+        Lua4Value::Number(value) => format!("{}", value),
+        Lua4Value::String(value) => format!("{:?}", value),
+        Lua4Value::Table => "table".to_string(),
+        Lua4Value::Closure(function, _) => {
+            format!("function({} parameters)", function.num_parameters)
+        }
+        Lua4Value::RustClosure(name) => format!("rust function {}", name),
+    }
+}
+
+impl LuaVMInspector {
+    pub fn begin(&mut self, source: String) {
+        self.source = Some(source);
+        self.globals.clear();
+    }
+
+    pub fn record_call<E: std::fmt::Display>(
+        &mut self,
+        kind: LuaVMInspectorCallKind,
+        name: &str,
+        parameters: &[Lua4Value],
+        result: &Result<Vec<Lua4Value>, E>,
+    ) {
+        if self.calls.len() >= LUA_VM_INSPECTOR_MAX_CALLS {
+            self.calls.pop_front();
+        }
+
+        self.calls.push_back(LuaVMInspectorCall {
+            time: Local::now(),
+            kind,
+            name: name.to_string(),
+            parameters: parameters.iter().map(format_lua_value).collect(),
+            result: match result {
+                Ok(values) => Ok(values.iter().map(format_lua_value).collect()),
+                Err(error) => Err(error.to_string()),
+            },
+        });
+    }
+
+    pub fn update_globals(&mut self, globals: &HashMap<String, Lua4Value>) {
+        self.globals = globals
+            .iter()
+            .map(|(name, value)| (name.clone(), format_lua_value(value)))
+            .collect();
+        self.globals.sort_by(|(a, _), (b, _)| a.cmp(b));
+    }
+}
@@ -5,6 +5,7 @@ pub mod lua4;
 mod lua_game_constants;
 mod lua_game_functions;
 mod lua_quest_functions;
+mod lua_vm_inspector;
 mod quest;
 mod quest_condition_functions;
 mod quest_function_context;
@@ -19,6 +20,9 @@ pub struct LuaUserValueEntity {
 pub use lua_game_constants::LuaGameConstants;
 pub use lua_game_functions::LuaGameFunctions;
 pub use lua_quest_functions::LuaQuestFunctions;
+pub use lua_vm_inspector::{
+    format_lua_value, LuaVMInspector, LuaVMInspectorCall, LuaVMInspectorCallKind,
+};
 pub use quest::{quest_apply_rewards, quest_check_conditions};
 pub use quest_condition_functions::quest_trigger_check_conditions;
 pub use quest_function_context::QuestFunctionContext;
@@ -34,5 +38,6 @@ impl Plugin for RoseScriptingPlugin {
         app.init_resource::<LuaGameConstants>();
         app.init_resource::<LuaGameFunctions>();
         app.init_resource::<LuaQuestFunctions>();
+        app.init_resource::<LuaVMInspector>();
     }
 }
@@ -3,7 +3,7 @@ use rose_file_readers::{QsdItem, QsdReward, QsdRewardOperator, QsdVariableType};
 use rose_game_common::components::ActiveQuest;
 
 use crate::{
-    events::{ChatboxEvent, SystemFuncEvent},
+    events::{AchievementEvent, ChatboxEvent, SystemFuncEvent},
     scripting::{
         quest::{get_quest_variable, set_quest_variable},
         QuestFunctionContext, ScriptFunctionContext, ScriptFunctionResources,
@@ -160,6 +160,10 @@ fn quest_reward_remove_selected_quest(
                     )));
             }
 
+            script_context
+                .achievement_events
+                .send(AchievementEvent::QuestCompleted);
+
             *quest_slot = None;
             return true;
         }
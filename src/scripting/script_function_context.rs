@@ -11,7 +11,10 @@ use rose_game_common::components::{
 
 use crate::{
     components::{ClanMembership, ClientEntity, PlayerCharacter},
-    events::{BankEvent, ChatboxEvent, ClanDialogEvent, NpcStoreEvent, SystemFuncEvent},
+    events::{
+        AchievementEvent, BankEvent, ChatboxEvent, ClanDialogEvent, NpcStoreEvent, RepairEvent,
+        SystemFuncEvent,
+    },
 };
 
 #[derive(WorldQuery)]
@@ -42,9 +45,11 @@ pub struct ScriptFunctionContext<'w, 's> {
     pub query_client_entity: Query<'w, 's, &'static ClientEntity>,
     pub query_player: Query<'w, 's, ScriptCharacterQuery<'static>, With<PlayerCharacter>>,
     pub query_npc: Query<'w, 's, &'static Npc>,
+    pub achievement_events: EventWriter<'w, AchievementEvent>,
     pub bank_events: EventWriter<'w, BankEvent>,
     pub chatbox_events: EventWriter<'w, ChatboxEvent>,
     pub clan_dialog_events: EventWriter<'w, ClanDialogEvent>,
     pub npc_store_events: EventWriter<'w, NpcStoreEvent>,
+    pub repair_events: EventWriter<'w, RepairEvent>,
     pub script_system_events: EventWriter<'w, SystemFuncEvent>,
 }
@@ -0,0 +1,23 @@
+use bevy::{
+    core_pipeline::bloom::BloomSettings,
+    prelude::{Camera3d, Query, Res, With},
+};
+
+use crate::resources::AccessibilitySettings;
+
+pub fn accessibility_bloom_system(
+    accessibility_settings: Res<AccessibilitySettings>,
+    mut query_cameras: Query<&mut BloomSettings, With<Camera3d>>,
+) {
+    // Only apply when the settings change, so the zone lighting debug window can still edit bloom
+    if !accessibility_settings.is_changed() {
+        return;
+    }
+
+    let intensity = BloomSettings::NATURAL.intensity * accessibility_settings.bloom_intensity;
+    for mut bloom_settings in query_cameras.iter_mut() {
+        if bloom_settings.intensity != intensity {
+            bloom_settings.intensity = intensity;
+        }
+    }
+}
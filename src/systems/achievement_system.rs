@@ -0,0 +1,99 @@
+use bevy::{
+    app::AppExit,
+    prelude::{Commands, Entity, EventReader, EventWriter, Query, Res, ResMut, With},
+};
+
+use rose_game_common::components::CharacterInfo;
+
+use crate::{
+    components::{CharacterTitle, PlayerCharacter},
+    events::{AchievementEvent, ChatboxEvent, ZoneEvent},
+    localize,
+    resources::{Account, Achievements, CharacterStoreKey, ACHIEVEMENTS},
+};
+
+pub fn achievement_system(
+    mut commands: Commands,
+    mut achievements: ResMut<Achievements>,
+    mut achievement_events: EventReader<AchievementEvent>,
+    mut zone_events: EventReader<ZoneEvent>,
+    mut chatbox_events: EventWriter<ChatboxEvent>,
+    query_player: Query<(Entity, &CharacterInfo, Option<&CharacterTitle>), With<PlayerCharacter>>,
+    account: Option<Res<Account>>,
+) {
+    let Ok((player_entity, character_info, character_title)) = query_player.get_single() else {
+        achievement_events.clear();
+        zone_events.clear();
+        return;
+    };
+
+    let store_key = CharacterStoreKey::new(account.as_deref(), &character_info.name);
+    if achievements.store_key.as_ref() != Some(&store_key) {
+        achievements.save_pending(true);
+        *achievements = Achievements::load(store_key);
+    }
+
+    let unlocked_before: Vec<bool> = ACHIEVEMENTS
+        .iter()
+        .map(|achievement| achievements.is_unlocked(achievement))
+        .collect();
+
+    for event in achievement_events.iter() {
+        match event {
+            AchievementEvent::MonsterKilled => achievements.progress.kills += 1,
+            AchievementEvent::QuestCompleted => achievements.progress.quests_completed += 1,
+        }
+        achievements.changed = true;
+    }
+
+    for event in zone_events.iter() {
+        let ZoneEvent::Loaded(zone_id) = event;
+        if !achievements.progress.zones_visited.contains(&zone_id.get()) {
+            achievements.progress.zones_visited.push(zone_id.get());
+            achievements.changed = true;
+        }
+    }
+
+    if achievements.changed {
+        for (achievement, was_unlocked) in ACHIEVEMENTS.iter().zip(unlocked_before) {
+            if !was_unlocked && achievements.is_unlocked(achievement) {
+                chatbox_events.send(ChatboxEvent::System(localize!(
+                    "chat-achievement-unlocked",
+                    name = achievement.name,
+                    title = achievement.title
+                )));
+            }
+        }
+
+        achievements.mark_unsaved();
+        achievements.changed = false;
+    }
+
+    match achievements.get_selected_title() {
+        Some(title) => {
+            if character_title.map_or(true, |character_title| character_title.title != title) {
+                commands.entity(player_entity).insert(CharacterTitle {
+                    title: title.to_string(),
+                });
+            }
+        }
+        None => {
+            if character_title.is_some() {
+                commands.entity(player_entity).remove::<CharacterTitle>();
+            }
+        }
+    }
+}
+
+/// Saves changed achievement progress periodically, and immediately when changing
+/// zone, leaving the game or exiting.
+pub fn achievement_save_system(
+    mut achievements: ResMut<Achievements>,
+    mut zone_events: EventReader<ZoneEvent>,
+    mut app_exit_events: EventReader<AppExit>,
+    query_player: Query<(), With<PlayerCharacter>>,
+) {
+    let zone_changed = zone_events.iter().count() > 0;
+    let exiting = app_exit_events.iter().count() > 0;
+    achievements.save_pending(zone_changed || exiting || query_player.is_empty());
+}
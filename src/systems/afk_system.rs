@@ -0,0 +1,138 @@
+use std::time::Duration;
+
+use bevy::{
+    input::{
+        keyboard::KeyboardInput,
+        mouse::{MouseButtonInput, MouseWheel},
+    },
+    prelude::{EventReader, EventWriter, Local, Query, Res, ResMut},
+    time::Time,
+    window::{CursorMoved, WindowFocused},
+    winit::{UpdateMode, WinitSettings},
+};
+
+use rose_game_common::messages::client::ClientMessage;
+
+use crate::{
+    audio::SoundGain,
+    components::SoundCategory,
+    events::ChatboxEvent,
+    localize,
+    resources::{AfkSettings, AfkState, GameConnection, SoundSettings},
+};
+
+const UNFOCUSED_LOW_POWER_WAIT: Duration = Duration::from_millis(100);
+
+pub struct AfkSystemState {
+    pub window_focused: bool,
+}
+
+impl Default for AfkSystemState {
+    fn default() -> Self {
+        Self {
+            window_focused: true,
+        }
+    }
+}
+
+pub fn afk_system(
+    mut system_state: Local<AfkSystemState>,
+    mut keyboard_input_events: EventReader<KeyboardInput>,
+    mut mouse_button_input_events: EventReader<MouseButtonInput>,
+    mut mouse_wheel_events: EventReader<MouseWheel>,
+    mut cursor_moved_events: EventReader<CursorMoved>,
+    mut window_focused_events: EventReader<WindowFocused>,
+    mut chatbox_events: EventWriter<ChatboxEvent>,
+    mut afk_state: ResMut<AfkState>,
+    mut sound_settings: ResMut<SoundSettings>,
+    mut winit_settings: ResMut<WinitSettings>,
+    mut query_sounds: Query<(&SoundCategory, &mut SoundGain)>,
+    afk_settings: Res<AfkSettings>,
+    time: Res<Time>,
+) {
+    let now = time.elapsed();
+
+    let has_input = !keyboard_input_events.is_empty()
+        || !mouse_button_input_events.is_empty()
+        || !mouse_wheel_events.is_empty()
+        || !cursor_moved_events.is_empty();
+    keyboard_input_events.clear();
+    mouse_button_input_events.clear();
+    mouse_wheel_events.clear();
+    cursor_moved_events.clear();
+
+    if has_input {
+        afk_state.last_input_time = now;
+
+        if afk_state.is_afk && !afk_state.is_manual {
+            afk_state.clear_afk();
+            chatbox_events.send(ChatboxEvent::System(localize!("chat-afk-cleared")));
+        }
+    } else if afk_settings.auto_away_enabled
+        && !afk_state.is_afk
+        && now.saturating_sub(afk_state.last_input_time) >= afk_settings.auto_away_timeout
+    {
+        afk_state.set_afk(false, None);
+        chatbox_events.send(ChatboxEvent::System(localize!("chat-afk-auto")));
+    }
+
+    if let Some(event) = window_focused_events.iter().last() {
+        system_state.window_focused = event.focused;
+    }
+
+    let unfocused_mode = if afk_settings.low_power_when_unfocused {
+        UpdateMode::ReactiveLowPower {
+            wait: UNFOCUSED_LOW_POWER_WAIT,
+        }
+    } else {
+        UpdateMode::Continuous
+    };
+    if winit_settings.unfocused_mode != unfocused_mode {
+        winit_settings.unfocused_mode = unfocused_mode;
+    }
+
+    let muted = afk_settings.mute_when_unfocused && !system_state.window_focused;
+    if sound_settings.muted != muted {
+        sound_settings.muted = muted;
+
+        for (category, mut gain) in query_sounds.iter_mut() {
+            let target_gain = sound_settings.gain(*category);
+
+            if target_gain != *gain {
+                *gain = target_gain;
+            }
+        }
+    }
+}
+
+pub fn afk_auto_reply_system(
+    mut chatbox_events: EventReader<ChatboxEvent>,
+    mut afk_state: ResMut<AfkState>,
+    afk_settings: Res<AfkSettings>,
+    game_connection: Option<Res<GameConnection>>,
+) {
+    for event in chatbox_events.iter() {
+        let ChatboxEvent::Whisper(name, _) = event else {
+            continue;
+        };
+
+        if !afk_state.is_afk
+            || !afk_settings.auto_reply_enabled
+            || afk_state.replied_to.contains(name)
+        {
+            continue;
+        }
+
+        // Whispers are sent as chat prefixed with @name, the same as the chatbox whisper tab
+        if let Some(game_connection) = game_connection.as_ref() {
+            game_connection
+                .client_message_tx
+                .send(ClientMessage::Chat {
+                    text: format!("@{} [AFK] {}", name, afk_state.get_message()),
+                })
+                .ok();
+        }
+
+        afk_state.replied_to.push(name.clone());
+    }
+}
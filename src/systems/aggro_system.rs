@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use bevy::prelude::{Commands, Entity, EventReader, Query, Res, Time, With, Without};
+
+use rose_game_common::components::{CharacterInfo, Npc};
+
+use crate::{
+    components::{Aggro, Command, CommandAttack, Dead, PartyInfo, PlayerCharacter},
+    events::HitEvent,
+};
+
+// How long a monster keeps aggro after it last attacked the player or their party
+const AGGRO_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub fn aggro_system(
+    mut commands: Commands,
+    mut hit_events: EventReader<HitEvent>,
+    query_player: Query<(Entity, Option<&PartyInfo>), With<PlayerCharacter>>,
+    query_characters: Query<(Entity, &CharacterInfo), Without<PlayerCharacter>>,
+    mut query_monsters: Query<(Entity, &Command, Option<&mut Aggro>, Option<&Dead>), With<Npc>>,
+    time: Res<Time>,
+) {
+    let Ok((player_entity, player_party)) = query_player.get_single() else {
+        hit_events.clear();
+        return;
+    };
+    let now = time.elapsed();
+
+    let mut protected_entities = vec![player_entity];
+    if let Some(player_party) = player_party {
+        for (entity, character_info) in query_characters.iter() {
+            if player_party
+                .members
+                .iter()
+                .any(|member| member.get_character_id() == character_info.unique_id)
+            {
+                protected_entities.push(entity);
+            }
+        }
+    }
+
+    let recent_attacks: Vec<(Entity, Entity)> = hit_events
+        .iter()
+        .filter(|event| protected_entities.contains(&event.defender))
+        .map(|event| (event.attacker, event.defender))
+        .collect();
+
+    for (entity, command, aggro, dead) in query_monsters.iter_mut() {
+        if dead.is_some() {
+            if aggro.is_some() {
+                commands.entity(entity).remove::<Aggro>();
+            }
+            continue;
+        }
+
+        let attack_target = match command {
+            Command::Attack(CommandAttack { target }) if protected_entities.contains(target) => {
+                Some(*target)
+            }
+            _ => recent_attacks
+                .iter()
+                .find(|(attacker, _)| *attacker == entity)
+                .map(|(_, defender)| *defender),
+        };
+
+        match (attack_target, aggro) {
+            (Some(target), Some(mut aggro)) if aggro.target == target => {
+                // Avoid triggering change detection, which would cause the name tag to update
+                aggro.bypass_change_detection().last_attack_time = now;
+            }
+            (Some(target), _) => {
+                commands.entity(entity).insert(Aggro {
+                    target,
+                    is_targeting_player: target == player_entity,
+                    last_attack_time: now,
+                });
+            }
+            (None, Some(aggro)) => {
+                if now.saturating_sub(aggro.last_attack_time) > AGGRO_TIMEOUT {
+                    commands.entity(entity).remove::<Aggro>();
+                }
+            }
+            (None, None) => {}
+        }
+    }
+}
@@ -0,0 +1,89 @@
+use bevy::prelude::{Commands, EventReader, EventWriter, Query, Res, With};
+
+use rose_data::{AmmoIndex, AnimationEventFlags, EquipmentIndex, ItemClass};
+use rose_game_common::components::{Equipment, MoveMode};
+
+use crate::{
+    animation::AnimationFrameEvent,
+    components::{AmmoPrediction, PlayerCharacter},
+    events::ChatboxEvent,
+    localize,
+    resources::GameData,
+};
+
+pub const LOW_AMMO_THRESHOLD: u32 = 50;
+
+pub fn get_weapon_ammo_index(equipment: &Equipment, game_data: &GameData) -> Option<AmmoIndex> {
+    let weapon = equipment.get_equipment_item(EquipmentIndex::Weapon)?;
+    let weapon_item_data = game_data.items.get_weapon_item(weapon.item.item_number)?;
+
+    match weapon_item_data.item_data.class {
+        ItemClass::Bow | ItemClass::Crossbow => Some(AmmoIndex::Arrow),
+        ItemClass::Gun | ItemClass::DualGuns => Some(AmmoIndex::Bullet),
+        ItemClass::Launcher => Some(AmmoIndex::Throw),
+        _ => None,
+    }
+}
+
+pub fn ammo_system(
+    mut commands: Commands,
+    mut animation_frame_events: EventReader<AnimationFrameEvent>,
+    mut query_player: Query<
+        (&Equipment, Option<&MoveMode>, Option<&mut AmmoPrediction>),
+        With<PlayerCharacter>,
+    >,
+    mut chatbox_events: EventWriter<ChatboxEvent>,
+    game_data: Res<GameData>,
+) {
+    for event in animation_frame_events.iter() {
+        if !event
+            .flags
+            .contains(AnimationEventFlags::EFFECT_WEAPON_FIRE_BULLET)
+        {
+            continue;
+        }
+
+        let Ok((equipment, move_mode, ammo_prediction)) = query_player.get_mut(event.entity) else {
+            continue;
+        };
+
+        if move_mode.map_or(false, |move_mode| matches!(move_mode, MoveMode::Drive)) {
+            // Vehicle weapons do not use ammo
+            continue;
+        }
+
+        let Some(ammo_index) = get_weapon_ammo_index(equipment, &game_data) else {
+            continue;
+        };
+        let Some(ammo_item) = equipment.get_ammo_item(ammo_index) else {
+            continue;
+        };
+        let ammo_name = game_data
+            .items
+            .get_base_item(ammo_item.item)
+            .map_or("ammo", |item_data| item_data.name);
+
+        // The server only tells us when the ammo has run out, so the remaining
+        // quantity is predicted from the shots fired
+        let remaining = if let Some(mut ammo_prediction) = ammo_prediction {
+            ammo_prediction.use_ammo(ammo_index, ammo_item.quantity)
+        } else {
+            let mut ammo_prediction = AmmoPrediction::default();
+            let remaining = ammo_prediction.use_ammo(ammo_index, ammo_item.quantity);
+            commands.entity(event.entity).insert(ammo_prediction);
+            remaining
+        };
+
+        if remaining == 0 {
+            chatbox_events.send(ChatboxEvent::System(localize!(
+                "chat-ammo-empty",
+                ammo = ammo_name
+            )));
+        } else if remaining == LOW_AMMO_THRESHOLD {
+            chatbox_events.send(ChatboxEvent::System(localize!(
+                "chat-ammo-low",
+                ammo = ammo_name
+            )));
+        }
+    }
+}
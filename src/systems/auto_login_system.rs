@@ -1,8 +1,13 @@
-use bevy::prelude::{EventWriter, Local, Res, State};
+use bevy::prelude::{EventWriter, Local, Query, Res, State, With};
+
+use rose_game_common::messages::client::ClientMessage;
 
 use crate::{
+    components::PlayerCharacter,
     events::{CharacterSelectEvent, LoginEvent},
-    resources::{AppState, CharacterList, ServerConfiguration, ServerList},
+    resources::{
+        AppState, CharacterList, CurrentZone, GameConnection, ServerConfiguration, ServerList,
+    },
 };
 
 #[derive(Default)]
@@ -12,14 +17,19 @@ pub enum AutoLoginState {
     WaitServerList,
     WaitCharacterList,
     SelectedCharacter,
+    Finished,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn auto_login_system(
     mut auto_login_state: Local<AutoLoginState>,
     app_state: Res<State<AppState>>,
     character_list: Option<Res<CharacterList>>,
     server_list: Option<Res<ServerList>>,
     server_configuration: Res<ServerConfiguration>,
+    current_zone: Option<Res<CurrentZone>>,
+    game_connection: Option<Res<GameConnection>>,
+    query_player: Query<(), With<PlayerCharacter>>,
     mut login_events: EventWriter<LoginEvent>,
     mut character_select_events: EventWriter<CharacterSelectEvent>,
 ) {
@@ -99,6 +109,38 @@ pub fn auto_login_system(
                 }
             }
         }
-        AutoLoginState::SelectedCharacter => {}
+        AutoLoginState::SelectedCharacter => {
+            if server_configuration.preset_start_zone.is_none()
+                && server_configuration.preset_start_position.is_none()
+            {
+                *auto_login_state = AutoLoginState::Finished;
+                return;
+            }
+
+            // Wait until the player has spawned in the zone before warping
+            if !matches!(app_state.get(), AppState::Game) || query_player.is_empty() {
+                return;
+            }
+
+            let (Some(current_zone), Some(game_connection)) = (current_zone, game_connection)
+            else {
+                return;
+            };
+
+            let zone_id = server_configuration
+                .preset_start_zone
+                .unwrap_or(current_zone.id);
+            let text = if let Some(position) = server_configuration.preset_start_position {
+                format!("/mm {} {} {}", zone_id.get(), position.x, position.y)
+            } else {
+                format!("/mm {}", zone_id.get())
+            };
+            game_connection
+                .client_message_tx
+                .send(ClientMessage::Chat { text })
+                .ok();
+            *auto_login_state = AutoLoginState::Finished;
+        }
+        AutoLoginState::Finished => {}
     }
 }
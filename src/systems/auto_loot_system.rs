@@ -0,0 +1,165 @@
+use std::time::Duration;
+
+use bevy::{
+    input::Input,
+    math::Vec3Swizzles,
+    prelude::{Entity, EventWriter, KeyCode, Local, Query, Res, Time, With, Without},
+};
+use bevy_egui::EguiContexts;
+
+use rose_data::ItemType;
+use rose_game_common::components::{DroppedItem, ItemDrop};
+
+use crate::{
+    components::{ClientEntity, Command, Dead, ItemDropOwner, PlayerCharacter, Position},
+    events::PlayerCommandEvent,
+    resources::{AutoLootSettings, GameData, InputAction, InputBindings},
+};
+
+const PICKUP_REQUEST_TIMEOUT: Duration = Duration::from_secs(2);
+
+#[derive(Default)]
+pub struct AutoLootState {
+    loot_all_active: bool,
+    pending_pickup: Option<(Entity, Duration)>,
+    attempted_pickups: Vec<Entity>,
+}
+
+fn should_pickup(
+    dropped_item: &DroppedItem,
+    auto_loot_settings: &AutoLootSettings,
+    game_data: &GameData,
+) -> bool {
+    match dropped_item {
+        DroppedItem::Money(_) => auto_loot_settings.pickup_money,
+        DroppedItem::Item(item) => {
+            let item_type = item.get_item_type();
+            let pickup_item_type = if item_type.is_equipment_item() {
+                auto_loot_settings.pickup_equipment
+            } else if item_type == ItemType::Consumable {
+                auto_loot_settings.pickup_consumables
+            } else {
+                auto_loot_settings.pickup_materials
+            };
+
+            pickup_item_type
+                && game_data
+                    .items
+                    .get_base_item(item.get_item_reference())
+                    .map_or(true, |item_data| {
+                        item_data.quality >= auto_loot_settings.min_item_quality
+                    })
+        }
+    }
+}
+
+pub fn auto_loot_system(
+    mut egui_context: EguiContexts,
+    mut auto_loot_state: Local<AutoLootState>,
+    auto_loot_settings: Res<AutoLootSettings>,
+    keyboard_input: Res<Input<KeyCode>>,
+    input_bindings: Res<InputBindings>,
+    query_player: Query<
+        (&ClientEntity, &Command, &Position),
+        (With<PlayerCharacter>, Without<Dead>),
+    >,
+    query_item_drops: Query<(Entity, &ItemDrop, &ItemDropOwner, &Position), With<ClientEntity>>,
+    mut player_command_events: EventWriter<PlayerCommandEvent>,
+    game_data: Res<GameData>,
+    time: Res<Time>,
+) {
+    let auto_loot_state = &mut *auto_loot_state;
+
+    if !egui_context.ctx_mut().wants_keyboard_input()
+        && input_bindings.just_pressed(InputAction::LootAll, &keyboard_input)
+    {
+        auto_loot_state.loot_all_active = true;
+    }
+
+    if !auto_loot_settings.enabled && !auto_loot_state.loot_all_active {
+        return;
+    }
+
+    let Ok((player_client_entity, player_command, player_position)) = query_player.get_single()
+    else {
+        return;
+    };
+
+    // Forget about any item drops which no longer exist
+    auto_loot_state
+        .attempted_pickups
+        .retain(|entity| query_item_drops.contains(*entity));
+
+    if let Some((pending_entity, pending_time)) = auto_loot_state.pending_pickup {
+        if query_item_drops.contains(pending_entity)
+            && time.elapsed() - pending_time < PICKUP_REQUEST_TIMEOUT
+        {
+            // Still waiting for the previous pickup to complete
+            return;
+        }
+
+        auto_loot_state.pending_pickup = None;
+    }
+
+    // Only loot when the player is idle, e.g. after combat has finished
+    if !player_command.is_stop() {
+        return;
+    }
+
+    let radius_squared = auto_loot_settings.radius * auto_loot_settings.radius;
+    let mut nearest_item_drop = None;
+
+    for (entity, item_drop, item_drop_owner, position) in query_item_drops.iter() {
+        if auto_loot_state.attempted_pickups.contains(&entity) {
+            continue;
+        }
+
+        if item_drop_owner
+            .owner_entity_id
+            .map_or(false, |owner_entity_id| {
+                owner_entity_id != player_client_entity.id
+            })
+        {
+            continue;
+        }
+
+        let Some(dropped_item) = item_drop.item.as_ref() else {
+            continue;
+        };
+
+        if !should_pickup(dropped_item, &auto_loot_settings, &game_data) {
+            continue;
+        }
+
+        let distance_squared = position
+            .position
+            .xy()
+            .distance_squared(player_position.position.xy());
+        if distance_squared > radius_squared {
+            continue;
+        }
+
+        if nearest_item_drop
+            .as_ref()
+            .map_or(true, |(nearest_distance, _, _)| {
+                distance_squared < *nearest_distance
+            })
+        {
+            nearest_item_drop = Some((distance_squared, entity, position));
+        }
+    }
+
+    let Some((_, entity, target_position)) = nearest_item_drop else {
+        auto_loot_state.loot_all_active = false;
+        return;
+    };
+
+    // Moving to the item drop will pick it up on arrival
+    player_command_events.send(PlayerCommandEvent::Move(
+        target_position.clone(),
+        Some(entity),
+    ));
+
+    auto_loot_state.pending_pickup = Some((entity, time.elapsed()));
+    auto_loot_state.attempted_pickups.push(entity);
+}
@@ -1,13 +1,17 @@
-use bevy::prelude::{AssetServer, Commands, Entity, Handle, Local, Res};
+use bevy::prelude::{AssetServer, Commands, Entity, Handle, Local, Query, Res, State, Time, With};
+use rand::Rng;
 use rose_data::ZoneId;
 
 use crate::{
-    audio::{AudioSource, GlobalSound},
+    audio::{AudioSource, GlobalSound, SoundGain},
     components::SoundCategory,
-    resources::{CurrentZone, GameData, SoundSettings, ZoneTime, ZoneTimeState},
+    resources::{
+        AppState, CurrentZone, GameData, ServerConfiguration, SoundSettings, ZoneMusicPlaylists,
+        ZoneTime, ZoneTimeState,
+    },
 };
 
-#[derive(Default)]
+#[derive(Default, PartialEq)]
 pub enum BackgroundMusicState {
     #[default]
     None,
@@ -18,98 +22,241 @@ pub enum BackgroundMusicState {
 #[derive(Default)]
 pub struct BackgroundMusic {
     pub zone: Option<ZoneId>,
+    pub music_override: Option<String>,
     pub entity: Option<Entity>,
-    pub day_audio_source: Option<Handle<AudioSource>>,
-    pub night_audio_source: Option<Handle<AudioSource>>,
+    pub day_tracks: Vec<Handle<AudioSource>>,
+    pub night_tracks: Vec<Handle<AudioSource>>,
+    pub shuffle: bool,
     pub state: BackgroundMusicState,
+    /// Index of the playing track in the tracks of the current state
+    pub track_index: usize,
+    /// Seconds since the playing track started fading in, None once it is at full volume
+    pub fade_in: Option<f32>,
+    /// Previous tracks which are fading out, with the seconds since they started fading
+    pub fading_out: Vec<(Entity, f32)>,
+}
+
+impl BackgroundMusic {
+    fn tracks(&self, state: &BackgroundMusicState) -> &[Handle<AudioSource>] {
+        match state {
+            BackgroundMusicState::None => &[],
+            BackgroundMusicState::PlayingDay => &self.day_tracks,
+            BackgroundMusicState::PlayingNight => &self.night_tracks,
+        }
+    }
+
+    fn fade_out(&mut self) {
+        if let Some(entity) = self.entity.take() {
+            self.fading_out.push((entity, 0.0));
+        }
+        self.fade_in = None;
+    }
+
+    fn play_track(
+        &mut self,
+        commands: &mut Commands,
+        sound_settings: &SoundSettings,
+        track_index: usize,
+        crossfade: bool,
+    ) {
+        self.fade_out();
+
+        let tracks = self.tracks(&self.state);
+        let Some(audio_source) = tracks.get(track_index).cloned() else {
+            return;
+        };
+
+        // A single track is repeated, a playlist moves on to the next track once it has finished
+        let global_sound = if tracks.len() == 1 {
+            GlobalSound::new_repeating(audio_source)
+        } else {
+            GlobalSound::new(audio_source)
+        };
+
+        let sound_gain = if crossfade {
+            self.fade_in = Some(0.0);
+            SoundGain::Ratio(0.0)
+        } else {
+            sound_settings.gain(SoundCategory::BackgroundMusic)
+        };
+
+        self.entity = Some(
+            commands
+                .spawn((SoundCategory::BackgroundMusic, global_sound, sound_gain))
+                .id(),
+        );
+        self.track_index = track_index;
+    }
+
+    fn first_track_index(&self) -> usize {
+        let num_tracks = self.tracks(&self.state).len();
+        if self.shuffle && num_tracks > 1 {
+            rand::thread_rng().gen_range(0..num_tracks)
+        } else {
+            0
+        }
+    }
+
+    fn next_track_index(&self) -> usize {
+        let num_tracks = self.tracks(&self.state).len().max(1);
+        if self.shuffle && num_tracks > 1 {
+            // Pick a random track other than the one which just finished
+            (self.track_index + rand::thread_rng().gen_range(1..num_tracks)) % num_tracks
+        } else {
+            (self.track_index + 1) % num_tracks
+        }
+    }
+}
+
+fn load_tracks(asset_server: &AssetServer, paths: &[String]) -> Vec<Handle<AudioSource>> {
+    paths
+        .iter()
+        .map(|path| asset_server.load(path.as_str()))
+        .collect()
 }
 
 pub fn background_music_system(
     mut commands: Commands,
     mut background_music: Local<BackgroundMusic>,
+    mut query_sound_gain: Query<&mut SoundGain, With<GlobalSound>>,
     asset_server: Res<AssetServer>,
     current_zone: Option<Res<CurrentZone>>,
     game_data: Res<GameData>,
+    zone_music_playlists: Res<ZoneMusicPlaylists>,
     zone_time: Res<ZoneTime>,
     sound_settings: Res<SoundSettings>,
+    app_state: Res<State<AppState>>,
+    server_configuration: Res<ServerConfiguration>,
+    time: Res<Time>,
 ) {
+    // The login screen can have its own music rather than the music of its zone
+    let music_override = if *app_state.get() == AppState::GameLogin {
+        server_configuration.login_music.as_ref()
+    } else {
+        None
+    };
+
     if let Some(current_zone) = current_zone {
-        if background_music.zone != Some(current_zone.id) {
-            if let Some(entity) = background_music.entity.take() {
-                commands.entity(entity).despawn();
-            }
+        if background_music.zone != Some(current_zone.id)
+            || background_music.music_override.as_ref() != music_override
+        {
+            background_music.fade_out();
             background_music.state = BackgroundMusicState::None;
+            background_music.shuffle = false;
 
-            if let Some(zone_data) = game_data.zone_list.get_zone(current_zone.id) {
-                background_music.day_audio_source = zone_data
+            if let Some(music_override) = music_override {
+                let audio_source = asset_server.load(music_override.as_str());
+                background_music.day_tracks = vec![audio_source.clone()];
+                background_music.night_tracks = vec![audio_source];
+            } else if let Some(playlist) = zone_music_playlists.get(current_zone.id) {
+                background_music.day_tracks = load_tracks(&asset_server, &playlist.day);
+                background_music.night_tracks = load_tracks(&asset_server, &playlist.night);
+                background_music.shuffle = playlist.shuffle;
+
+                // A playlist with only day or only night tracks plays them all day
+                if background_music.day_tracks.is_empty() {
+                    background_music.day_tracks = background_music.night_tracks.clone();
+                } else if background_music.night_tracks.is_empty() {
+                    background_music.night_tracks = background_music.day_tracks.clone();
+                }
+            } else if let Some(zone_data) = game_data.zone_list.get_zone(current_zone.id) {
+                background_music.day_tracks = zone_data
                     .background_music_day
-                    .as_ref()
-                    .map(|path| asset_server.load(path.path()));
-                background_music.night_audio_source = zone_data
+                    .iter()
+                    .map(|path| asset_server.load(path.path()))
+                    .collect();
+                background_music.night_tracks = zone_data
                     .background_music_night
-                    .as_ref()
-                    .map(|path| asset_server.load(path.path()));
+                    .iter()
+                    .map(|path| asset_server.load(path.path()))
+                    .collect();
             } else {
-                background_music.day_audio_source = None;
-                background_music.night_audio_source = None;
+                background_music.day_tracks.clear();
+                background_music.night_tracks.clear();
             }
 
             background_music.zone = Some(current_zone.id);
+            background_music.music_override = music_override.cloned();
         }
 
-        match zone_time.state {
-            ZoneTimeState::Morning | ZoneTimeState::Day => {
-                match background_music.state {
-                    BackgroundMusicState::None | BackgroundMusicState::PlayingNight => {
-                        // TODO: Should probably cross fade between the tracks
-                        if let Some(entity) = background_music.entity.take() {
-                            commands.entity(entity).despawn();
-                        }
-
-                        if let Some(audio_source) = background_music.night_audio_source.as_ref() {
-                            background_music.entity = Some(
-                                commands
-                                    .spawn((
-                                        SoundCategory::BackgroundMusic,
-                                        GlobalSound::new_repeating(audio_source.clone()),
-                                        sound_settings.gain(SoundCategory::BackgroundMusic),
-                                    ))
-                                    .id(),
-                            );
-                        }
-
-                        background_music.state = BackgroundMusicState::PlayingDay;
-                    }
-                    BackgroundMusicState::PlayingDay => {}
-                }
-            }
-            ZoneTimeState::Evening | ZoneTimeState::Night => {
-                match background_music.state {
-                    BackgroundMusicState::None | BackgroundMusicState::PlayingDay => {
-                        // TODO: Should probably cross fade between the tracks
-                        if let Some(entity) = background_music.entity.take() {
-                            commands.entity(entity).despawn();
-                        }
-
-                        if let Some(audio_source) = background_music.night_audio_source.as_ref() {
-                            background_music.entity = Some(
-                                commands
-                                    .spawn((
-                                        SoundCategory::BackgroundMusic,
-                                        GlobalSound::new_repeating(audio_source.clone()),
-                                        sound_settings.gain(SoundCategory::BackgroundMusic),
-                                    ))
-                                    .id(),
-                            );
-                        }
-
-                        background_music.state = BackgroundMusicState::PlayingNight;
-                    }
-                    BackgroundMusicState::PlayingNight => {}
-                }
+        let state = match zone_time.state {
+            ZoneTimeState::Morning | ZoneTimeState::Day => BackgroundMusicState::PlayingDay,
+            ZoneTimeState::Evening | ZoneTimeState::Night => BackgroundMusicState::PlayingNight,
+        };
+        let crossfade = zone_music_playlists.crossfade_duration > 0.0;
+
+        if background_music.state != state {
+            let keep_playing = background_music.state != BackgroundMusicState::None
+                && background_music.tracks(&background_music.state)
+                    == background_music.tracks(&state);
+            background_music.state = state;
+
+            // Keep the current track when the day and night music are the same
+            if !keep_playing {
+                let track_index = background_music.first_track_index();
+                background_music.play_track(&mut commands, &sound_settings, track_index, crossfade);
             }
+        } else if background_music
+            .entity
+            .map_or(false, |entity| !query_sound_gain.contains(entity))
+        {
+            // The previous track of the playlist has finished
+            let track_index = background_music.next_track_index();
+            background_music.play_track(&mut commands, &sound_settings, track_index, crossfade);
         }
-    } else if let Some(entity) = background_music.entity.take() {
-        commands.entity(entity).despawn();
+    } else {
+        background_music.fade_out();
+        background_music.zone = None;
+        background_music.state = BackgroundMusicState::None;
     }
+
+    // Crossfade between the previous and current tracks
+    let BackgroundMusic {
+        entity,
+        fade_in,
+        fading_out,
+        ..
+    } = &mut *background_music;
+    let crossfade_duration = zone_music_playlists.crossfade_duration;
+    let full_gain = match sound_settings.gain(SoundCategory::BackgroundMusic) {
+        SoundGain::Ratio(factor) => factor,
+        SoundGain::Decibel(db) => 10.0f32.powf(db / 20.0),
+    };
+
+    if let Some(fade_in_time) = fade_in.as_mut() {
+        *fade_in_time += time.delta_seconds();
+        let fade = (*fade_in_time / crossfade_duration).min(1.0);
+
+        if let Some(mut sound_gain) =
+            entity.and_then(|entity| query_sound_gain.get_mut(entity).ok())
+        {
+            *sound_gain = SoundGain::Ratio(full_gain * fade);
+        }
+
+        if fade >= 1.0 {
+            *fade_in = None;
+        }
+    }
+
+    fading_out.retain_mut(|(entity, fade_out_time)| {
+        *fade_out_time += time.delta_seconds();
+        let fade = if crossfade_duration > 0.0 {
+            (*fade_out_time / crossfade_duration).min(1.0)
+        } else {
+            1.0
+        };
+
+        let Ok(mut sound_gain) = query_sound_gain.get_mut(*entity) else {
+            // The track has already finished
+            return false;
+        };
+
+        if fade >= 1.0 {
+            commands.entity(*entity).despawn();
+            false
+        } else {
+            *sound_gain = SoundGain::Ratio(full_gain * (1.0 - fade));
+            true
+        }
+    });
 }
@@ -0,0 +1,382 @@
+use std::{fmt::Write, path::PathBuf};
+
+use bevy::{
+    ecs::query::WorldQuery,
+    prelude::{EventReader, EventWriter, Query, Res, With},
+};
+use serde::Serialize;
+
+use rose_game_common::components::{
+    AbilityValues, BasicStats, CharacterInfo, Equipment, ExperiencePoints, Level, QuestState,
+    SkillList,
+};
+
+use crate::{
+    components::PlayerCharacter,
+    events::{CharacterExportFormat, ChatboxEvent, PlayerCommandEvent},
+    localize,
+    resources::{get_data_dir, GameData},
+};
+
+#[derive(WorldQuery)]
+pub struct PlayerQuery<'w> {
+    ability_values: &'w AbilityValues,
+    basic_stats: &'w BasicStats,
+    character_info: &'w CharacterInfo,
+    equipment: &'w Equipment,
+    experience_points: &'w ExperiencePoints,
+    level: &'w Level,
+    quest_state: &'w QuestState,
+    skill_list: &'w SkillList,
+}
+
+#[derive(Serialize)]
+struct CharacterExportStat {
+    name: &'static str,
+    value: i32,
+}
+
+#[derive(Serialize)]
+struct CharacterExportEquipment<'a> {
+    slot: String,
+    name: &'a str,
+    grade: u8,
+    gem: Option<&'a str>,
+    durability: u8,
+    life: u16,
+}
+
+#[derive(Serialize)]
+struct CharacterExportSkill<'a> {
+    name: &'a str,
+    level: u32,
+}
+
+#[derive(Serialize)]
+struct CharacterExportQuestItem<'a> {
+    name: &'a str,
+    quantity: u32,
+}
+
+#[derive(Serialize)]
+struct CharacterExportQuest<'a> {
+    name: &'a str,
+    variables: Vec<u16>,
+    items: Vec<CharacterExportQuestItem<'a>>,
+}
+
+#[derive(Serialize)]
+struct CharacterExport<'a> {
+    name: &'a str,
+    job: &'a str,
+    level: u32,
+    experience: u64,
+    basic_stats: Vec<CharacterExportStat>,
+    ability_values: Vec<CharacterExportStat>,
+    equipment: Vec<CharacterExportEquipment<'a>>,
+    skills: Vec<CharacterExportSkill<'a>>,
+    quests: Vec<CharacterExportQuest<'a>>,
+}
+
+impl<'a> CharacterExport<'a> {
+    fn new(player: &'a PlayerQueryItem<'a>, game_data: &'a GameData) -> Self {
+        let basic_stats = player.basic_stats;
+        let ability_values = player.ability_values;
+
+        let equipment = player
+            .equipment
+            .equipped_items
+            .iter()
+            .filter_map(|(equipment_index, equipment_item)| {
+                let equipment_item = equipment_item.as_ref()?;
+                Some(CharacterExportEquipment {
+                    slot: format!("{:?}", equipment_index),
+                    name: game_data
+                        .items
+                        .get_base_item(equipment_item.item)
+                        .map_or("???", |item_data| item_data.name),
+                    grade: equipment_item.grade,
+                    gem: (equipment_item.gem > 300)
+                        .then(|| game_data.items.get_gem_item(equipment_item.gem as usize))
+                        .flatten()
+                        .map(|gem_item_data| gem_item_data.item_data.name),
+                    durability: equipment_item.durability,
+                    life: equipment_item.life,
+                })
+            })
+            .collect();
+
+        let skills = player
+            .skill_list
+            .pages
+            .iter()
+            .flat_map(|page| page.skills.iter())
+            .filter_map(|skill_id| {
+                let skill_data = game_data.skills.get_skill((*skill_id)?)?;
+                Some(CharacterExportSkill {
+                    name: skill_data.name,
+                    level: skill_data.level,
+                })
+            })
+            .collect();
+
+        let quests = player
+            .quest_state
+            .active_quests
+            .iter()
+            .filter_map(|active_quest| {
+                let active_quest = active_quest.as_ref()?;
+                Some(CharacterExportQuest {
+                    name: game_data
+                        .quests
+                        .get_quest_data(active_quest.quest_id)
+                        .map_or("???", |quest_data| quest_data.name),
+                    variables: active_quest.variables.to_vec(),
+                    items: active_quest
+                        .items
+                        .iter()
+                        .filter_map(|item| {
+                            let item = item.as_ref()?;
+                            Some(CharacterExportQuestItem {
+                                name: game_data
+                                    .items
+                                    .get_base_item(item.get_item_reference())
+                                    .map_or("???", |item_data| item_data.name),
+                                quantity: item.get_quantity(),
+                            })
+                        })
+                        .collect(),
+                })
+            })
+            .collect();
+
+        Self {
+            name: &player.character_info.name,
+            job: game_data
+                .string_database
+                .get_job_name(player.character_info.job),
+            level: player.level.level,
+            experience: player.experience_points.xp,
+            basic_stats: vec![
+                CharacterExportStat {
+                    name: "Strength",
+                    value: basic_stats.strength,
+                },
+                CharacterExportStat {
+                    name: "Dexterity",
+                    value: basic_stats.dexterity,
+                },
+                CharacterExportStat {
+                    name: "Intelligence",
+                    value: basic_stats.intelligence,
+                },
+                CharacterExportStat {
+                    name: "Concentration",
+                    value: basic_stats.concentration,
+                },
+                CharacterExportStat {
+                    name: "Charm",
+                    value: basic_stats.charm,
+                },
+                CharacterExportStat {
+                    name: "Sense",
+                    value: basic_stats.sense,
+                },
+            ],
+            ability_values: vec![
+                CharacterExportStat {
+                    name: "Max HP",
+                    value: ability_values.get_max_health(),
+                },
+                CharacterExportStat {
+                    name: "Max MP",
+                    value: ability_values.get_max_mana(),
+                },
+                CharacterExportStat {
+                    name: "Attack",
+                    value: ability_values.get_attack_power(),
+                },
+                CharacterExportStat {
+                    name: "Defence",
+                    value: ability_values.get_defence(),
+                },
+                CharacterExportStat {
+                    name: "Magic Resistance",
+                    value: ability_values.get_resistance(),
+                },
+                CharacterExportStat {
+                    name: "Hit",
+                    value: ability_values.get_hit(),
+                },
+                CharacterExportStat {
+                    name: "Critical",
+                    value: ability_values.get_critical(),
+                },
+                CharacterExportStat {
+                    name: "Dodge",
+                    value: ability_values.get_avoid(),
+                },
+                CharacterExportStat {
+                    name: "Attack Speed",
+                    value: ability_values.get_attack_speed(),
+                },
+            ],
+            equipment,
+            skills,
+            quests,
+        }
+    }
+}
+
+fn html_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn write_html(export: &CharacterExport) -> Result<String, std::fmt::Error> {
+    let mut html = String::new();
+    writeln!(html, "<!DOCTYPE html>")?;
+    writeln!(html, "<html>")?;
+    writeln!(html, "<head>")?;
+    writeln!(html, "<meta charset=\"utf-8\">")?;
+    writeln!(html, "<title>{}</title>", html_escape(export.name))?;
+    writeln!(
+        html,
+        "<style>body {{ font-family: sans-serif; background: #1e1e1e; color: #ddd; }} table {{ border-collapse: collapse; margin-bottom: 1em; }} th, td {{ border: 1px solid #555; padding: 2px 8px; text-align: left; }}</style>"
+    )?;
+    writeln!(html, "</head>")?;
+    writeln!(html, "<body>")?;
+    writeln!(html, "<h1>{}</h1>", html_escape(export.name))?;
+    writeln!(
+        html,
+        "<p>Level {} {} &mdash; {} XP</p>",
+        export.level,
+        html_escape(export.job),
+        export.experience
+    )?;
+
+    writeln!(html, "<h2>Stats</h2>")?;
+    writeln!(html, "<table>")?;
+    for stat in export
+        .basic_stats
+        .iter()
+        .chain(export.ability_values.iter())
+    {
+        writeln!(
+            html,
+            "<tr><th>{}</th><td>{}</td></tr>",
+            stat.name, stat.value
+        )?;
+    }
+    writeln!(html, "</table>")?;
+
+    writeln!(html, "<h2>Equipment</h2>")?;
+    writeln!(html, "<table>")?;
+    writeln!(
+        html,
+        "<tr><th>Slot</th><th>Item</th><th>Grade</th><th>Gem</th><th>Durability</th><th>Life</th></tr>"
+    )?;
+    for equipment in export.equipment.iter() {
+        writeln!(
+            html,
+            "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{:.1}%</td></tr>",
+            equipment.slot,
+            html_escape(equipment.name),
+            equipment.grade,
+            html_escape(equipment.gem.unwrap_or("")),
+            equipment.durability,
+            equipment.life as f32 / 10.0
+        )?;
+    }
+    writeln!(html, "</table>")?;
+
+    writeln!(html, "<h2>Skills</h2>")?;
+    writeln!(html, "<table>")?;
+    writeln!(html, "<tr><th>Skill</th><th>Level</th></tr>")?;
+    for skill in export.skills.iter() {
+        writeln!(
+            html,
+            "<tr><td>{}</td><td>{}</td></tr>",
+            html_escape(skill.name),
+            skill.level
+        )?;
+    }
+    writeln!(html, "</table>")?;
+
+    writeln!(html, "<h2>Quests</h2>")?;
+    writeln!(html, "<table>")?;
+    writeln!(html, "<tr><th>Quest</th><th>Items</th></tr>")?;
+    for quest in export.quests.iter() {
+        let items = quest
+            .items
+            .iter()
+            .map(|item| format!("{} x{}", html_escape(item.name), item.quantity))
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(
+            html,
+            "<tr><td>{}</td><td>{}</td></tr>",
+            html_escape(quest.name),
+            items
+        )?;
+    }
+    writeln!(html, "</table>")?;
+
+    writeln!(html, "</body>")?;
+    writeln!(html, "</html>")?;
+    Ok(html)
+}
+
+fn export_character(
+    export: &CharacterExport,
+    format: CharacterExportFormat,
+) -> anyhow::Result<PathBuf> {
+    let data_dir = get_data_dir()?;
+    let (contents, extension) = match format {
+        CharacterExportFormat::Json => (serde_json::to_string_pretty(export)?, "json"),
+        CharacterExportFormat::Html => (write_html(export)?, "html"),
+    };
+    let path = data_dir.join("exports").join(format!(
+        "{}-{}.{}",
+        export.name,
+        chrono::Local::now().format("%Y%m%d-%H%M%S"),
+        extension
+    ));
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, contents)?;
+    Ok(path)
+}
+
+pub fn character_export_system(
+    mut player_command_events: EventReader<PlayerCommandEvent>,
+    mut chatbox_events: EventWriter<ChatboxEvent>,
+    query_player: Query<PlayerQuery, With<PlayerCharacter>>,
+    game_data: Res<GameData>,
+) {
+    for event in player_command_events.iter() {
+        let &PlayerCommandEvent::ExportCharacter(format) = event else {
+            continue;
+        };
+
+        let Ok(player) = query_player.get_single() else {
+            continue;
+        };
+
+        let export = CharacterExport::new(&player, &game_data);
+        chatbox_events.send(ChatboxEvent::System(
+            match export_character(&export, format) {
+                Ok(path) => localize!(
+                    "chat-character-exported",
+                    path = path.to_string_lossy().to_string()
+                ),
+                Err(error) => localize!("chat-character-export-failed", error = error.to_string()),
+            },
+        ));
+    }
+}
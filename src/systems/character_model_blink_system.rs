@@ -4,6 +4,7 @@ use rand::Rng;
 use crate::{
     components::{CharacterBlinkTimer, CharacterModel, CharacterModelPart, Dead},
     render::ObjectMaterialClipFace,
+    resources::ReplayRng,
     zms_asset_loader::ZmsMaterialNumFaces,
 };
 
@@ -25,14 +26,14 @@ pub fn character_model_blink_system(
                     blink_timer.is_open = false;
                     blink_timer.timer -= blink_timer.open_duration;
                     blink_timer.closed_duration =
-                        rand::thread_rng().gen_range(CharacterBlinkTimer::BLINK_CLOSED_DURATION);
+                        ReplayRng.gen_range(CharacterBlinkTimer::BLINK_CLOSED_DURATION);
                     changed = true;
                 }
             } else if blink_timer.timer >= blink_timer.closed_duration {
                 blink_timer.is_open = true;
                 blink_timer.timer -= blink_timer.closed_duration;
                 blink_timer.open_duration =
-                    rand::thread_rng().gen_range(CharacterBlinkTimer::BLINK_OPEN_DURATION);
+                    ReplayRng.gen_range(CharacterBlinkTimer::BLINK_OPEN_DURATION);
                 changed = true;
             }
         } else {
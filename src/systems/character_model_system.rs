@@ -1,6 +1,9 @@
 use bevy::{
     hierarchy::DespawnRecursiveExt,
-    prelude::{AssetServer, Assets, Changed, Commands, Entity, Or, Query, Res, ResMut},
+    prelude::{
+        AssetServer, Assets, Changed, Commands, Entity, Handle, Or, Query, Res, ResMut, Visibility,
+        With,
+    },
     render::mesh::skinning::{SkinnedMesh, SkinnedMeshInverseBindposes},
 };
 
@@ -8,11 +11,12 @@ use rose_game_common::components::{CharacterInfo, Equipment};
 
 use crate::{
     components::{
-        CharacterBlinkTimer, CharacterModel, DummyBoneOffset, ModelHeight, PersonalStore,
-        RemoveColliderCommand,
+        CharacterBlinkTimer, CharacterModel, DummyBoneOffset, ItemGradeGlow,
+        ItemGradeParticleEffect, ModelHeight, PersonalStore, RemoveColliderCommand,
     },
     model_loader::ModelLoader,
     render::{EffectMeshMaterial, ObjectMaterial, ParticleMaterial},
+    resources::ItemGradeEffectSettings,
 };
 
 pub fn character_model_update_system(
@@ -39,7 +43,29 @@ pub fn character_model_update_system(
     mut particle_materials: ResMut<Assets<ParticleMaterial>>,
     mut effect_mesh_materials: ResMut<Assets<EffectMeshMaterial>>,
     mut skinned_mesh_inverse_bindposes_assets: ResMut<Assets<SkinnedMeshInverseBindposes>>,
+    query_grade_glow: Query<(&ItemGradeGlow, &Handle<ObjectMaterial>)>,
+    mut query_grade_particle_effect: Query<&mut Visibility, With<ItemGradeParticleEffect>>,
+    item_grade_effect_settings: Res<ItemGradeEffectSettings>,
 ) {
+    let grade_effect_intensity = item_grade_effect_settings.intensity;
+
+    if item_grade_effect_settings.is_changed() && !item_grade_effect_settings.is_added() {
+        // Update the grade effects of existing models to match the new intensity
+        for (grade_glow, material_handle) in query_grade_glow.iter() {
+            if let Some(material) = object_materials.get_mut(material_handle) {
+                material.glow = grade_glow.get_material_glow(grade_effect_intensity);
+            }
+        }
+
+        for mut visibility in query_grade_particle_effect.iter_mut() {
+            *visibility = if grade_effect_intensity > 0.0 {
+                Visibility::Inherited
+            } else {
+                Visibility::Hidden
+            };
+        }
+    }
+
     for (
         entity,
         character_info,
@@ -65,6 +91,7 @@ pub fn character_model_update_system(
                     &mut *current_character_model,
                     &current_dummy_bone_offset.unwrap(),
                     &current_skinned_mesh.unwrap(),
+                    grade_effect_intensity,
                 );
                 commands
                     .entity(entity)
@@ -115,6 +142,7 @@ pub fn character_model_update_system(
                 entity,
                 character_info,
                 equipment,
+                grade_effect_intensity,
             );
 
         let mut entity_commands = commands.entity(entity);
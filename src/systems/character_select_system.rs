@@ -3,11 +3,12 @@ use std::time::{Duration, Instant};
 use bevy::{
     input::Input,
     prelude::{
-        AssetServer, Camera, Camera3d, Commands, Component, ComputedVisibility,
-        DespawnRecursiveExt, Entity, EventReader, EventWriter, GlobalTransform, Handle, Local,
-        MouseButton, NextState, Query, Res, ResMut, Resource, Visibility, With,
+        AssetServer, Camera, Commands, Component, ComputedVisibility, DespawnRecursiveExt, Entity,
+        EventReader, EventWriter, GlobalTransform, Handle, Local, MouseButton, NextState, Query,
+        Res, ResMut, Resource, Transform, Vec3, Visibility, With,
     },
     render::mesh::skinning::SkinnedMesh,
+    time::Time,
     window::{CursorGrabMode, PrimaryWindow, Window},
 };
 use bevy_egui::{egui, EguiContexts};
@@ -19,17 +20,25 @@ use rose_game_common::messages::{client::ClientMessage, server::CreateCharacterE
 use crate::{
     animation::{CameraAnimation, SkeletalAnimation, ZmoAsset},
     components::{
-        CharacterModel, ColliderParent, COLLISION_FILTER_CLICKABLE, COLLISION_GROUP_CHARACTER,
-        COLLISION_GROUP_PLAYER,
+        CharacterModel, CharacterModelPart, ColliderParent, MainCamera, COLLISION_FILTER_CLICKABLE,
+        COLLISION_GROUP_CHARACTER, COLLISION_GROUP_PLAYER,
     },
     events::{CharacterSelectEvent, GameConnectionEvent, LoadZoneEvent, WorldConnectionEvent},
+    localize,
+    render::TrailEffect,
     resources::{
-        AppState, CharacterList, CharacterSelectState, GameData, ServerConfiguration,
-        WorldConnection,
+        title_zone_id, AppState, CharacterList, CharacterSelectState, CurrentZone, GameData,
+        ServerConfiguration, WorldConnection,
     },
     systems::{FreeCamera, OrbitCamera},
 };
 
+const CHARACTER_SELECT_STEP_FORWARD_DISTANCE: f32 = 0.6;
+const CHARACTER_SELECT_STEP_FORWARD_SPEED: f32 = 4.0;
+const CHARACTER_SELECT_CAMERA_FOCUS_HEIGHT: f32 = 1.6;
+const CHARACTER_SELECT_CAMERA_DOLLY_SCALE: f32 = 0.6;
+const CHARACTER_SELECT_CAMERA_DOLLY_SPEED: f32 = 3.0;
+
 #[derive(Component)]
 pub struct CharacterSelectCharacter {
     pub index: usize,
@@ -41,12 +50,26 @@ pub struct CharacterSelectModelList {
     select_motion: Handle<ZmoAsset>,
 }
 
+/// The camera transform once the camera animation has finished, the camera
+/// dollies from here towards the selected character.
+#[derive(Default, Resource)]
+pub struct CharacterSelectCamera {
+    home: Option<Transform>,
+}
+
+fn smooth_factor(speed: f32, time: &Time) -> f32 {
+    1.0 - (-speed * time.delta_seconds()).exp()
+}
+
 pub fn character_select_enter_system(
     mut commands: Commands,
     mut query_window: Query<&mut Window, With<PrimaryWindow>>,
-    query_cameras: Query<Entity, With<Camera3d>>,
+    query_cameras: Query<Entity, With<MainCamera>>,
     asset_server: Res<AssetServer>,
     game_data: Res<GameData>,
+    server_configuration: Res<ServerConfiguration>,
+    current_zone: Option<Res<CurrentZone>>,
+    mut load_zone_events: EventWriter<LoadZoneEvent>,
 ) {
     if let Ok(mut window) = query_window.get_single_mut() {
         window.cursor.grab_mode = CursorGrabMode::None;
@@ -55,17 +78,28 @@ pub fn character_select_enter_system(
 
     // Reset camera
     for entity in query_cameras.iter() {
-        commands
-            .entity(entity)
+        let mut entity_commands = commands.entity(entity);
+        entity_commands
             .remove::<FreeCamera>()
-            .remove::<OrbitCamera>()
-            .insert(CameraAnimation::once(
+            .remove::<OrbitCamera>();
+
+        if !server_configuration.should_skip_intro() {
+            entity_commands.insert(CameraAnimation::once(
                 asset_server.load("3DDATA/TITLE/CAMERA01_INSELECT01.ZMO"),
             ));
+        }
+    }
+
+    // The login screen may have used a different zone as its background
+    if !server_configuration.should_skip_intro()
+        && current_zone.map_or(true, |current_zone| current_zone.id != title_zone_id())
+    {
+        load_zone_events.send(LoadZoneEvent::new(title_zone_id()));
     }
 
     // Reset state
     commands.insert_resource(CharacterSelectState::Entering);
+    commands.insert_resource(CharacterSelectCamera::default());
 
     // Spawn entities to use for character list models
     let mut models = Vec::with_capacity(game_data.character_select_positions.len());
@@ -99,14 +133,23 @@ pub fn character_select_exit_system(
     commands.remove_resource::<CharacterList>();
     commands.remove_resource::<CharacterSelectState>();
     commands.remove_resource::<CharacterSelectModelList>();
+    commands.remove_resource::<CharacterSelectCamera>();
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn character_select_models_system(
     mut commands: Commands,
     mut model_list: ResMut<CharacterSelectModelList>,
     character_list: Option<Res<CharacterList>>,
     character_select_state: Res<CharacterSelectState>,
-    query_characters: Query<(Option<&SkeletalAnimation>, &CharacterModel), With<SkinnedMesh>>,
+    character_select_camera: Res<CharacterSelectCamera>,
+    game_data: Res<GameData>,
+    mut query_characters: Query<
+        (Option<&SkeletalAnimation>, &CharacterModel, &mut Transform),
+        With<SkinnedMesh>,
+    >,
+    mut query_trails: Query<&mut Visibility, With<TrailEffect>>,
+    time: Res<Time>,
 ) {
     // Ensure all character list models are up to date
     if let Some(character_list) = character_list.as_ref() {
@@ -121,7 +164,9 @@ pub fn character_select_models_system(
                 model_list.models[index].0 = Some(character.info.name.clone());
             }
 
-            if let Ok((skeletal_animation, character_model)) = query_characters.get(entity) {
+            if let Ok((skeletal_animation, character_model, mut transform)) =
+                query_characters.get_mut(entity)
+            {
                 let deleting = character.delete_time.is_some();
                 let selected = if let CharacterSelectState::CharacterSelect(Some(selected_index)) =
                     *character_select_state
@@ -131,24 +176,131 @@ pub fn character_select_models_system(
                     false
                 };
 
-                let desired_motion = if deleting {
-                    &character_model.action_motions[CharacterMotionAction::Sit]
+                // The selected character plays the select motion once, then
+                // holds the ready stance for its equipped weapon
+                let weapon_motion = &character_model.action_motions[CharacterMotionAction::Stop2];
+                let (desired_motion, repeat) = if deleting {
+                    (
+                        &character_model.action_motions[CharacterMotionAction::Sit],
+                        true,
+                    )
                 } else if selected {
-                    &model_list.select_motion
+                    let select_completed = skeletal_animation.map_or(false, |x| {
+                        x.motion().id() == model_list.select_motion.id() && x.completed()
+                    });
+                    let holding_weapon =
+                        skeletal_animation.map_or(false, |x| x.motion().id() == weapon_motion.id());
+
+                    if select_completed || holding_weapon {
+                        (weapon_motion, true)
+                    } else {
+                        (&model_list.select_motion, false)
+                    }
                 } else {
-                    &character_model.action_motions[CharacterMotionAction::Stop1]
+                    (
+                        &character_model.action_motions[CharacterMotionAction::Stop1],
+                        true,
+                    )
                 };
 
                 if skeletal_animation.map_or(true, |x| x.motion().id() != desired_motion.id()) {
-                    commands
-                        .entity(entity)
-                        .insert(SkeletalAnimation::repeat(desired_motion.clone(), None));
+                    commands.entity(entity).insert(if repeat {
+                        SkeletalAnimation::repeat(desired_motion.clone(), None)
+                    } else {
+                        SkeletalAnimation::once(desired_motion.clone())
+                    });
+                }
+
+                // Only show the weapon trails of the selected character
+                for &part_entity in character_model.model_parts[CharacterModelPart::Weapon]
+                    .1
+                    .iter()
+                {
+                    if let Ok(mut visibility) = query_trails.get_mut(part_entity) {
+                        let desired_visibility = if selected && !deleting {
+                            Visibility::Inherited
+                        } else {
+                            Visibility::Hidden
+                        };
+
+                        if *visibility != desired_visibility {
+                            *visibility = desired_visibility;
+                        }
+                    }
+                }
+
+                // The selected character steps forward out of the line towards the camera
+                if let (Some(slot_transform), Some(camera_home)) = (
+                    game_data.character_select_positions.get(index),
+                    character_select_camera.home.as_ref(),
+                ) {
+                    let forward = (camera_home.translation - slot_transform.translation)
+                        * Vec3::new(1.0, 0.0, 1.0);
+                    let desired_translation = if selected && !deleting {
+                        slot_transform.translation
+                            + forward.normalize_or_zero() * CHARACTER_SELECT_STEP_FORWARD_DISTANCE
+                    } else {
+                        slot_transform.translation
+                    };
+
+                    if transform.translation != desired_translation {
+                        transform.translation = transform.translation.lerp(
+                            desired_translation,
+                            smooth_factor(CHARACTER_SELECT_STEP_FORWARD_SPEED, &time),
+                        );
+                    }
                 }
             }
         }
     }
 }
 
+pub fn character_select_camera_system(
+    mut character_select_camera: ResMut<CharacterSelectCamera>,
+    character_select_state: Res<CharacterSelectState>,
+    game_data: Res<GameData>,
+    mut query_camera: Query<(&mut Transform, Option<&CameraAnimation>), With<MainCamera>>,
+    time: Res<Time>,
+) {
+    let Ok((mut camera_transform, camera_animation)) = query_camera.get_single_mut() else {
+        return;
+    };
+
+    if camera_animation.map_or(false, |animation| !animation.completed()) {
+        // The camera animation is in control, it decides where home is
+        character_select_camera.home = None;
+        return;
+    }
+
+    let CharacterSelectState::CharacterSelect(selected_index) = *character_select_state else {
+        return;
+    };
+
+    let home = *character_select_camera
+        .home
+        .get_or_insert(*camera_transform);
+
+    let desired_transform = if let Some(slot_transform) =
+        selected_index.and_then(|index| game_data.character_select_positions.get(index))
+    {
+        let focus = slot_transform.translation + Vec3::Y * CHARACTER_SELECT_CAMERA_FOCUS_HEIGHT;
+        let dolly_translation =
+            focus + (home.translation - focus) * CHARACTER_SELECT_CAMERA_DOLLY_SCALE;
+
+        Transform::from_translation(dolly_translation).looking_at(focus, Vec3::Y)
+    } else {
+        home
+    };
+
+    let t = smooth_factor(CHARACTER_SELECT_CAMERA_DOLLY_SPEED, &time);
+    camera_transform.translation = camera_transform
+        .translation
+        .lerp(desired_transform.translation, t);
+    camera_transform.rotation = camera_transform
+        .rotation
+        .slerp(desired_transform.rotation, t);
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn character_select_system(
     mut commands: Commands,
@@ -161,7 +313,7 @@ pub fn character_select_system(
     mut join_zone_id: Local<Option<ZoneId>>,
     query_camera: Query<
         (Entity, &Camera, &GlobalTransform, Option<&CameraAnimation>),
-        With<Camera3d>,
+        With<MainCamera>,
     >,
     world_connection: Option<Res<WorldConnection>>,
     mut character_list: Option<ResMut<CharacterList>>,
@@ -264,29 +416,31 @@ pub fn character_select_system(
         CharacterSelectState::CharacterSelect(_) => {}
         CharacterSelectState::CharacterCreate => {}
         CharacterSelectState::CharacterCreating => {
-            egui::Window::new("Creating character...")
+            egui::Window::new(localize!("creating-character-title"))
                 .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
                 .collapsible(false)
                 .show(egui_context.ctx_mut(), |ui| {
-                    ui.label("Creating character");
+                    ui.label(localize!("creating-character"));
                 });
         }
         CharacterSelectState::ConnectingGameServer => {
-            egui::Window::new("Connecting...")
+            egui::Window::new(localize!("connecting-title"))
                 .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
                 .collapsible(false)
                 .show(egui_context.ctx_mut(), |ui| {
-                    ui.label("Connecting to game");
+                    ui.label(localize!("connecting-game"));
                 });
 
             for event in game_connection_events.iter() {
                 let &GameConnectionEvent::Connected(zone_id) = event;
 
                 // Start camera animation
-                let (camera_entity, _, _, _) = query_camera.single();
-                commands.entity(camera_entity).insert(CameraAnimation::once(
-                    asset_server.load("3DDATA/TITLE/CAMERA01_INGAME01.ZMO"),
-                ));
+                if !server_configuration.should_skip_intro() {
+                    let (camera_entity, _, _, _) = query_camera.single();
+                    commands.entity(camera_entity).insert(CameraAnimation::once(
+                        asset_server.load("3DDATA/TITLE/CAMERA01_INGAME01.ZMO"),
+                    ));
+                }
 
                 *character_select_state = CharacterSelectState::Leaving;
                 *join_zone_id = Some(zone_id);
@@ -357,15 +511,41 @@ pub fn character_select_event_system(
                         if let Some(selected_character) =
                             character_list.characters.get(selected_character_index)
                         {
-                            if let Some(world_connection) = world_connection.as_ref() {
-                                world_connection
-                                    .client_message_tx
-                                    .send(ClientMessage::DeleteCharacter {
-                                        slot: selected_character_index as u8,
-                                        name: selected_character.info.name.clone(),
-                                        is_delete: selected_character.delete_time.is_none(),
-                                    })
-                                    .ok();
+                            if selected_character.delete_time.is_none() {
+                                if let Some(world_connection) = world_connection.as_ref() {
+                                    world_connection
+                                        .client_message_tx
+                                        .send(ClientMessage::DeleteCharacter {
+                                            slot: selected_character_index as u8,
+                                            name: selected_character.info.name.clone(),
+                                            is_delete: true,
+                                        })
+                                        .ok();
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            CharacterSelectEvent::CancelDeleteSelected => {
+                if let CharacterSelectState::CharacterSelect(Some(selected_character_index)) =
+                    *character_select_state
+                {
+                    if let Some(character_list) = character_list.as_ref() {
+                        if let Some(selected_character) =
+                            character_list.characters.get(selected_character_index)
+                        {
+                            if selected_character.delete_time.is_some() {
+                                if let Some(world_connection) = world_connection.as_ref() {
+                                    world_connection
+                                        .client_message_tx
+                                        .send(ClientMessage::DeleteCharacter {
+                                            slot: selected_character_index as u8,
+                                            name: selected_character.info.name.clone(),
+                                            is_delete: false,
+                                        })
+                                        .ok();
+                                }
                             }
                         }
                     }
@@ -385,7 +565,7 @@ pub fn character_select_input_system(
     mouse_button_input: Res<Input<MouseButton>>,
     rapier_context: Res<RapierContext>,
     mut last_selected_time: Local<Option<Instant>>,
-    query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    query_camera: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
     query_collider_parent: Query<&ColliderParent>,
     query_select_character: Query<&CharacterSelectCharacter>,
     query_window: Query<&Window, With<PrimaryWindow>>,
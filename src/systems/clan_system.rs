@@ -1,7 +1,10 @@
-use bevy::prelude::{Added, Changed, Entity, Or, Query, Res, With};
+use bevy::prelude::{
+    Added, Changed, DetectChanges, Entity, Or, Query, Ref, RemovedComponents, Res, With,
+};
 
+use rose_data_irose::{IroseSkillPageType, SKILL_PAGE_SIZE};
 use rose_game_common::{
-    components::{CharacterInfo, Level},
+    components::{CharacterInfo, Level, SkillList, SkillSlot},
     messages::client::ClientMessage,
 };
 
@@ -42,3 +45,35 @@ pub fn clan_system(
         }
     }
 }
+
+pub fn clan_skill_system(
+    mut query_player: Query<(&mut SkillList, Option<Ref<Clan>>), With<PlayerCharacter>>,
+    mut removed_clans: RemovedComponents<Clan>,
+) {
+    let clan_removed = removed_clans.iter().count() > 0;
+    let Ok((mut skill_list, clan)) = query_player.get_single_mut() else {
+        return;
+    };
+
+    if !clan_removed
+        && !skill_list.is_added()
+        && !clan.as_ref().map_or(false, |clan| clan.is_changed())
+    {
+        return;
+    }
+
+    // Clan skills are placed in the clan skill page so their passive bonuses
+    // are included when calculating the player's ability values
+    for index in 0..SKILL_PAGE_SIZE {
+        let skill_slot = SkillSlot(IroseSkillPageType::Clan as usize, index);
+        let skill_id = clan
+            .as_ref()
+            .and_then(|clan| clan.skills.get(index).cloned());
+
+        if skill_list.get_skill(skill_slot) != skill_id {
+            if let Some(slot) = skill_list.get_slot_mut(skill_slot) {
+                *slot = skill_id;
+            }
+        }
+    }
+}
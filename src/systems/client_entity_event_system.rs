@@ -1,5 +1,6 @@
 use bevy::prelude::{
-    AssetServer, Commands, EventReader, EventWriter, GlobalTransform, Query, Res, Transform,
+    AssetServer, Commands, Entity, EventReader, EventWriter, GlobalTransform, Query, Res, ResMut,
+    Time, Transform,
 };
 
 use rose_data::SoundId;
@@ -7,12 +8,15 @@ use rose_file_readers::VfsPathBuf;
 use rose_game_common::components::Npc;
 
 use crate::{
-    audio::SpatialSound,
+    audio::{GlobalSound, SpatialSound},
     components::{PlayerCharacter, SoundCategory},
     events::{ChatboxEvent, ClientEntityEvent, SpawnEffectData, SpawnEffectEvent},
-    resources::{GameData, SoundCache, SoundSettings},
+    localize,
+    resources::{GameData, LevelUpPresentation, LevelUpSettings, SoundCache, SoundSettings},
 };
 
+const LEVEL_UP_SOUND_ID: u16 = 16;
+
 pub fn client_entity_event_system(
     mut commands: Commands,
     mut client_entity_events: EventReader<ClientEntityEvent>,
@@ -25,6 +29,9 @@ pub fn client_entity_event_system(
     game_data: Res<GameData>,
     sound_settings: Res<SoundSettings>,
     sound_cache: Res<SoundCache>,
+    level_up_settings: Res<LevelUpSettings>,
+    mut level_up_presentation: ResMut<LevelUpPresentation>,
+    time: Res<Time>,
 ) {
     let is_player = |entity| query_player.contains(entity);
 
@@ -57,25 +64,44 @@ pub fn client_entity_event_system(
                 }
             }
             ClientEntityEvent::LevelUp(entity, level) => {
-                let sound_category = if is_player(entity) {
+                let level_up_sound_data = game_data
+                    .sounds
+                    .get_sound(SoundId::new(LEVEL_UP_SOUND_ID).unwrap());
+
+                if is_player(entity) {
                     if let Some(level) = level {
-                        chatbox_events.send(ChatboxEvent::System(format!(
-                            "Congratulations! You are now level {}!",
-                            level
+                        chatbox_events.send(ChatboxEvent::System(localize!(
+                            "chat-level-up",
+                            level = level
                         )));
                     }
 
-                    SoundCategory::PlayerCombat
-                } else {
-                    SoundCategory::OtherCombat
-                };
+                    // The player hears the level up as a fanfare rather than from their position
+                    if level_up_settings.fanfare {
+                        if let Some(sound_data) = level_up_sound_data {
+                            commands.spawn((
+                                SoundCategory::PlayerCombat,
+                                sound_settings.gain(SoundCategory::PlayerCombat),
+                                GlobalSound::new(sound_cache.load(sound_data, &asset_server)),
+                            ));
+                        }
+                    }
+
+                    if level_up_settings.effect {
+                        spawn_level_up_effect(&mut spawn_effect_events, entity);
+                    }
+
+                    if level_up_settings.screen_flash || level_up_settings.banner {
+                        level_up_presentation.start(time.elapsed(), entity, level);
+                    }
+                    continue;
+                }
 
                 if let Ok(global_transform) = query_global_transform.get(entity) {
-                    if let Some(sound_data) = game_data.sounds.get_sound(SoundId::new(16).unwrap())
-                    {
+                    if let Some(sound_data) = level_up_sound_data {
                         commands.spawn((
-                            sound_category,
-                            sound_settings.gain(sound_category),
+                            SoundCategory::OtherCombat,
+                            sound_settings.gain(SoundCategory::OtherCombat),
                             SpatialSound::new(sound_cache.load(sound_data, &asset_server)),
                             Transform::from_translation(global_transform.translation()),
                             GlobalTransform::from_translation(global_transform.translation()),
@@ -83,12 +109,16 @@ pub fn client_entity_event_system(
                     }
                 }
 
-                spawn_effect_events.send(SpawnEffectEvent::OnEntity(
-                    entity,
-                    None,
-                    SpawnEffectData::with_path(VfsPathBuf::new("3DDATA/EFFECT/LEVELUP_01.EFT")),
-                ));
+                spawn_level_up_effect(&mut spawn_effect_events, entity);
             }
         }
     }
 }
+
+fn spawn_level_up_effect(spawn_effect_events: &mut EventWriter<SpawnEffectEvent>, entity: Entity) {
+    spawn_effect_events.send(SpawnEffectEvent::OnEntity(
+        entity,
+        None,
+        SpawnEffectData::with_path(VfsPathBuf::new("3DDATA/EFFECT/LEVELUP_01.EFT")),
+    ));
+}
@@ -3,6 +3,7 @@ use bevy::{
     hierarchy::DespawnRecursiveExt,
     math::{Vec3, Vec3Swizzles},
     prelude::{AssetServer, Commands, Entity, EventWriter, Handle, Mut, Or, Query, Res, With},
+    time::Time,
 };
 use rand::prelude::SliceRandom;
 
@@ -18,13 +19,13 @@ use rose_game_common::{
 use crate::{
     animation::{SkeletalAnimation, ZmoAsset},
     components::{
-        CharacterModel, ClientEntity, ClientEntityType, Command, CommandAttack, CommandCastSkill,
-        CommandCastSkillState, CommandCastSkillTarget, CommandEmote, CommandMove, CommandSit, Dead,
-        FacingDirection, NextCommand, NpcModel, PersonalStore, PlayerCharacter, Position, Vehicle,
-        VehicleModel,
+        CastingState, CharacterModel, ClientEntity, ClientEntityType, Command, CommandAttack,
+        CommandCastSkill, CommandCastSkillState, CommandCastSkillTarget, CommandEmote, CommandMove,
+        CommandSit, Dead, Encumbrance, FacingDirection, NextCommand, NpcModel, PersonalStore,
+        PlayerCharacter, Position, Vehicle, VehicleModel,
     },
     events::{ClientEntityEvent, ConversationDialogEvent, PersonalStoreEvent},
-    resources::{GameConnection, GameData},
+    resources::{GameConnection, GameData, ReplayRng},
 };
 
 const NPC_MOVE_TO_DISTANCE: f32 = 250.0;
@@ -325,13 +326,14 @@ pub fn command_system(
             Option<&NpcModel>,
             Option<&Equipment>,
             &Position,
-            &MoveMode,
+            (&MoveMode, Option<&Encumbrance>),
             &MoveSpeed,
             Option<&Vehicle>,
             &mut Command,
             &mut NextCommand,
             &mut FacingDirection,
             Option<&Dead>,
+            Option<&CastingState>,
         ),
         Or<(With<CharacterModel>, With<NpcModel>)>,
     >,
@@ -347,8 +349,9 @@ pub fn command_system(
     mut conversation_dialog_events: EventWriter<ConversationDialogEvent>,
     mut client_entity_events: EventWriter<ClientEntityEvent>,
     mut personal_store_events: EventWriter<PersonalStoreEvent>,
+    time: Res<Time>,
 ) {
-    let mut rng = rand::thread_rng();
+    let mut rng = ReplayRng;
 
     for (
         entity,
@@ -358,13 +361,14 @@ pub fn command_system(
         npc_model,
         equipment,
         position,
-        move_mode,
+        (move_mode, encumbrance),
         move_speed,
         vehicle,
         mut command,
         mut next_command,
         mut facing_direction,
         dead,
+        casting_state,
     ) in query.iter_mut()
     {
         let (
@@ -391,6 +395,16 @@ pub fn command_system(
             .as_ref()
             .and_then(|vehicle| query_vehicle_model.get(vehicle.vehicle_model_entity).ok());
 
+        // Over encumbered characters are shown walking to make the slow down visible
+        let move_animation_mode = if encumbrance
+            .map_or(false, |encumbrance| encumbrance.is_over_encumbered())
+            && matches!(move_mode, MoveMode::Run)
+        {
+            &MoveMode::Walk
+        } else {
+            move_mode
+        };
+
         let requires_animation_complete = if command.is_emote() {
             // Emote has an animation, but can be interrupted by any other command
             !next_command.is_some()
@@ -506,6 +520,7 @@ pub fn command_system(
                     }
 
                     *cast_skill_state = CommandCastSkillState::Action;
+                    commands.entity(entity).remove::<CastingState>();
                     continue;
                 } else if !*ready_action
                     && matches!(*cast_skill_state, CommandCastSkillState::Casting)
@@ -605,6 +620,11 @@ pub fn command_system(
             }
         }
 
+        if casting_state.is_some() {
+            // Starting any new command ends the cast, casting a skill inserts a new state below
+            commands.entity(entity).remove::<CastingState>();
+        }
+
         match (*next_command).as_mut().unwrap() {
             Command::Stop => {
                 if let Some(motion) = get_stop_animation(character_model, npc_model, vehicle) {
@@ -771,7 +791,7 @@ pub fn command_system(
                     *command = Command::with_move(*destination, *target, *command_move_mode);
 
                     if let Some(motion) =
-                        get_move_animation(move_mode, character_model, npc_model, vehicle)
+                        get_move_animation(move_animation_mode, character_model, npc_model, vehicle)
                     {
                         update_active_motion(
                             &mut commands.entity(active_motion_entity),
@@ -860,7 +880,12 @@ pub fn command_system(
                     }
                 } else {
                     // Not in range, move towards target
-                    let motion = get_move_animation(move_mode, character_model, npc_model, vehicle);
+                    let motion = get_move_animation(
+                        move_animation_mode,
+                        character_model,
+                        npc_model,
+                        vehicle,
+                    );
                     if let Some(motion) = motion {
                         *command = Command::with_move(
                             target.position.position,
@@ -1050,15 +1075,24 @@ pub fn command_system(
                                     }
                                 });
 
-                        if let Some(motion_data) = motion_data {
+                        let motion = motion_data
+                            .map(|motion_data| asset_server.load(motion_data.path.path()));
+                        if let Some(motion) = motion.clone() {
                             update_active_motion(
                                 &mut commands.entity(active_motion_entity),
                                 &mut active_motion,
-                                asset_server.load(motion_data.path.path()),
+                                motion,
                                 skill_data.casting_motion_speed,
                                 false,
                             );
                         }
+                        commands.entity(entity).insert(CastingState::new(
+                            skill_id,
+                            target_entity,
+                            motion,
+                            skill_data.casting_motion_speed,
+                            time.elapsed(),
+                        ));
 
                         // Update next command
                         match skill_data.action_mode {
@@ -1096,8 +1130,12 @@ pub fn command_system(
                         let target_position = target_position.unwrap();
 
                         // Not in range, move towards target
-                        let motion =
-                            get_move_animation(move_mode, character_model, npc_model, vehicle);
+                        let motion = get_move_animation(
+                            move_animation_mode,
+                            character_model,
+                            npc_model,
+                            vehicle,
+                        );
                         if let Some(motion) = motion {
                             *command = Command::with_move(
                                 target_position,
@@ -0,0 +1,87 @@
+use bevy::{
+    core_pipeline::clear_color::ClearColorConfig,
+    prelude::{
+        Camera, Camera3d, Camera3dBundle, Color, Commands, Entity, GlobalTransform,
+        PerspectiveProjection, Query, Res, Transform, Vec3, With,
+    },
+    render::camera::RenderTarget,
+};
+
+use crate::{
+    components::{ConversationDialogPortraitCamera, ModelHeight, PlayerCharacter},
+    resources::ConversationDialogPortrait,
+};
+
+const PORTRAIT_CAMERA_DISTANCE: f32 = 1.0;
+const PORTRAIT_DEFAULT_MODEL_HEIGHT: f32 = 1.8;
+
+// Frame the top of the model, where the face usually is
+const PORTRAIT_HEAD_HEIGHT_RATIO: f32 = 0.85;
+
+pub fn conversation_dialog_portrait_system(
+    mut commands: Commands,
+    conversation_dialog_portrait: Res<ConversationDialogPortrait>,
+    query_npc: Query<(&GlobalTransform, Option<&ModelHeight>)>,
+    query_player: Query<&GlobalTransform, With<PlayerCharacter>>,
+    mut query_portrait_camera: Query<
+        (Entity, &mut Transform),
+        With<ConversationDialogPortraitCamera>,
+    >,
+) {
+    let Some((npc_transform, npc_model_height)) = conversation_dialog_portrait
+        .npc_entity
+        .and_then(|npc_entity| query_npc.get(npc_entity).ok())
+    else {
+        for (camera_entity, _) in query_portrait_camera.iter() {
+            commands.entity(camera_entity).despawn();
+        }
+        return;
+    };
+
+    let npc_position = npc_transform.translation();
+    let model_height = npc_model_height.map_or(PORTRAIT_DEFAULT_MODEL_HEIGHT, |model_height| {
+        model_height.height
+    });
+    let head_position = npc_position + Vec3::Y * model_height * PORTRAIT_HEAD_HEIGHT_RATIO;
+
+    // View the NPC from the side which is facing the player
+    let mut view_direction = query_player
+        .get_single()
+        .map_or(Vec3::Z, |player_transform| {
+            player_transform.translation() - npc_position
+        });
+    view_direction.y = 0.0;
+    let view_direction = view_direction.try_normalize().unwrap_or(Vec3::Z);
+
+    let camera_transform =
+        Transform::from_translation(head_position + view_direction * PORTRAIT_CAMERA_DISTANCE)
+            .looking_at(head_position, Vec3::Y);
+
+    if let Ok((_, mut transform)) = query_portrait_camera.get_single_mut() {
+        *transform = camera_transform;
+    } else {
+        commands.spawn((
+            ConversationDialogPortraitCamera,
+            Camera3dBundle {
+                camera: Camera {
+                    // Render before the main camera
+                    order: -1,
+                    target: RenderTarget::Image(conversation_dialog_portrait.image.clone()),
+                    ..Default::default()
+                },
+                camera_3d: Camera3d {
+                    clear_color: ClearColorConfig::Custom(Color::rgb(0.1, 0.1, 0.1)),
+                    ..Default::default()
+                },
+                projection: PerspectiveProjection {
+                    fov: 30.0f32.to_radians(),
+                    near: 0.1,
+                    ..Default::default()
+                }
+                .into(),
+                transform: camera_transform,
+                ..Default::default()
+            },
+        ));
+    }
+}
@@ -1,8 +1,9 @@
 use std::sync::Arc;
 
 use bevy::{
+    ecs::system::SystemParam,
     math::Vec3Swizzles,
-    prelude::{Assets, Entity, EventReader, Local, Query, Res, With},
+    prelude::{Assets, Entity, EventReader, Local, Query, Res, ResMut, Time, With},
 };
 use bevy_egui::{egui, EguiContexts};
 use rose_file_readers::{ConFile, ConMessageType};
@@ -10,16 +11,22 @@ use rose_file_readers::{ConFile, ConMessageType};
 use crate::{
     components::{ClientEntityName, PlayerCharacter, Position},
     events::ConversationDialogEvent,
-    resources::{GameData, UiResources, UiSprite},
+    resources::{
+        ConversationDialogPortrait, GameData, UiResources, UiSprite,
+        CONVERSATION_DIALOG_PORTRAIT_SIZE,
+    },
     scripting::{
         lua4::{Lua4Function, Lua4VM, Lua4VMError, Lua4VMRustClosures, Lua4Value},
-        LuaGameConstants, LuaGameFunctions, LuaQuestFunctions, LuaUserValueEntity,
-        ScriptFunctionContext, ScriptFunctionResources,
+        LuaGameConstants, LuaGameFunctions, LuaQuestFunctions, LuaUserValueEntity, LuaVMInspector,
+        LuaVMInspectorCallKind, ScriptFunctionContext, ScriptFunctionResources,
     },
+    text_to_speech::{self, TextToSpeechChannel},
     ui::{widgets::Dialog, DataBindings, DialogInstance},
     VfsResource,
 };
 
+const MESSAGE_REVEAL_CHARACTERS_PER_SECOND: f32 = 60.0;
+
 pub struct GeneratedDialogResponse {
     pub text: egui::text::LayoutJob,
     pub galley: Option<Arc<egui::text::Galley>>,
@@ -31,6 +38,10 @@ pub struct GeneratedDialogResponse {
 pub struct GeneratedDialog {
     pub message: egui::text::LayoutJob,
     pub responses: Vec<GeneratedDialogResponse>,
+    /// Set once the current message and responses have been sent to text to speech.
+    pub spoken: bool,
+    /// The number of characters of message which have been revealed by the typewriter effect.
+    pub message_reveal: f32,
 }
 
 pub struct ConversationDialogState {
@@ -41,12 +52,39 @@ pub struct ConversationDialogState {
     pub event_object_handle: Arc<dyn std::any::Any + Send + Sync>,
 }
 
+#[derive(SystemParam)]
+pub struct ConversationDialogLuaResources<'w, 's> {
+    pub game_constants: Res<'w, LuaGameConstants>,
+    pub game_functions: Res<'w, LuaGameFunctions>,
+    pub quest_functions: Res<'w, LuaQuestFunctions>,
+    pub inspector: ResMut<'w, LuaVMInspector>,
+
+    #[system_param(ignore)]
+    pub phantom: std::marker::PhantomData<&'s ()>,
+}
+
 pub struct LuaVMContext<'a, 'w1, 's1, 'w2, 's2> {
     pub function_context: &'a mut ScriptFunctionContext<'w1, 's1>,
     pub function_resources: &'a ScriptFunctionResources<'w2, 's2>,
     pub game_constants: &'a LuaGameConstants,
     pub game_functions: &'a LuaGameFunctions,
     pub quest_functions: &'a LuaQuestFunctions,
+    pub inspector: &'a mut LuaVMInspector,
+}
+
+impl<'a, 'w1, 's1, 'w2, 's2> LuaVMContext<'a, 'w1, 's1, 'w2, 's2> {
+    fn call_global_closure(
+        &mut self,
+        lua_vm: &mut Lua4VM,
+        name: &str,
+        parameters: &[Lua4Value],
+    ) -> Result<Vec<Lua4Value>, anyhow::Error> {
+        let result = lua_vm.call_global_closure(self, name, parameters);
+        self.inspector
+            .record_call(LuaVMInspectorCallKind::Lua, name, parameters, &result);
+        self.inspector.update_globals(&lua_vm.globals);
+        result
+    }
 }
 
 impl<'a, 'w1, 's1, 'w2, 's2> Lua4VMRustClosures for LuaVMContext<'a, 'w1, 's1, 'w2, 's2> {
@@ -55,21 +93,24 @@ impl<'a, 'w1, 's1, 'w2, 's2> Lua4VMRustClosures for LuaVMContext<'a, 'w1, 's1, '
         name: &str,
         parameters: Vec<Lua4Value>,
     ) -> Result<Vec<Lua4Value>, Lua4VMError> {
-        if let Some(closure) = self.quest_functions.closures.get(name) {
-            Ok(closure(
-                self.function_resources,
-                self.function_context,
-                parameters,
-            ))
-        } else if let Some(closure) = self.game_functions.closures.get(name) {
+        let closure = self
+            .quest_functions
+            .closures
+            .get(name)
+            .or_else(|| self.game_functions.closures.get(name));
+        let result = if let Some(closure) = closure {
             Ok(closure(
                 self.function_resources,
                 self.function_context,
-                parameters,
+                parameters.clone(),
             ))
         } else {
             Err(Lua4VMError::GlobalNotFound(name.to_string()))
-        }
+        };
+
+        self.inspector
+            .record_call(LuaVMInspectorCallKind::Rust, name, &parameters, &result);
+        result
     }
 }
 
@@ -93,9 +134,12 @@ fn create_conversation_dialog(
     }
 
     let lua_function = Lua4Function::from_bytes(&con_file.script_binary).ok()?;
-    lua_vm
-        .call_lua_function(user_context, &lua_function, &[])
-        .ok()?;
+    let result = lua_vm.call_lua_function(user_context, &lua_function, &[]);
+    user_context
+        .inspector
+        .record_call(LuaVMInspectorCallKind::Lua, "<main>", &[], &result);
+    user_context.inspector.update_globals(&lua_vm.globals);
+    result.ok()?;
 
     Some(ConversationDialogState {
         owner_entity,
@@ -106,38 +150,88 @@ fn create_conversation_dialog(
     })
 }
 
+/// Returns the value to substitute for a `<TEMPLATE>` in an LTB string, templates with
+/// arguments separate them with spaces, e.g. `<QUEST_VAR 1001 0>`.
+fn message_template_value(template: &str, user_context: &LuaVMContext) -> Option<String> {
+    let mut args = template
+        .trim_start_matches('<')
+        .trim_end_matches('>')
+        .split_whitespace();
+    let name = args.next()?;
+    let mut next_arg = || args.next().and_then(|arg| arg.parse::<usize>().ok());
+
+    let function_context = &user_context.function_context;
+    match name {
+        "NAME" => function_context
+            .query_player
+            .get_single()
+            .map(|player| player.character_info.name.clone())
+            .ok(),
+        "LEVEL" => function_context
+            .query_player
+            .get_single()
+            .map(|player| format!("{}", player.level.level))
+            .ok(),
+        "QUEST_VAR" | "QUEST_SWITCH" => {
+            let quest_id = next_arg()?;
+            let index = next_arg()?;
+            let quest_state = function_context.query_quest.get_single().ok()?;
+            let quest = quest_state.find_active_quest(quest_id)?;
+            let value = if name == "QUEST_VAR" {
+                *quest.variables.get(index)? as i32
+            } else {
+                *quest.switches.get(index)? as i32
+            };
+            Some(format!("{}", value))
+        }
+        "QUEST_ITEM" => {
+            let quest_id = next_arg()?;
+            let item_reference = user_context
+                .function_resources
+                .game_data
+                .data_decoder
+                .decode_item_base1000(next_arg()?)?;
+            let quest_state = function_context.query_quest.get_single().ok()?;
+            let quantity = quest_state
+                .find_active_quest(quest_id)?
+                .items
+                .iter()
+                .flatten()
+                .find(|item| item.get_item_reference() == item_reference)
+                .map_or(0, |item| item.get_quantity());
+            Some(format!("{}", quantity))
+        }
+        "EPISODE_VAR" | "JOB_VAR" | "PLANET_VAR" | "UNION_VAR" => {
+            let index = next_arg()?;
+            let quest_state = function_context.query_quest.get_single().ok()?;
+            let value = match name {
+                "EPISODE_VAR" => *quest_state.episode_variables.get(index)? as i32,
+                "JOB_VAR" => *quest_state.job_variables.get(index)? as i32,
+                "PLANET_VAR" => *quest_state.planet_variables.get(index)? as i32,
+                _ => *quest_state.union_variables.get(index)? as i32,
+            };
+            Some(format!("{}", value))
+        }
+        _ => None,
+    }
+}
+
 fn parse_message(message: &str, user_context: &LuaVMContext) -> String {
     let mut string = String::with_capacity(message.len());
 
     let mut remaining = message;
-    while let Some(template_start) = remaining.find(|c| c == '<') {
+    while let Some(template_start) = remaining.find('<') {
         let (before_template, template) = remaining.split_at(template_start);
-
-        let template_end = template.find(|c| c == '>');
-        if template_end.is_none() {
-            return string;
-        }
-        let template_end = template_end.unwrap();
+        let Some(template_end) = template.find('>') else {
+            break;
+        };
         let (template, after_template) = template.split_at(template_end + 1);
 
         string += before_template;
-        string += match template {
-            "<NAME>" => user_context
-                .function_context
-                .query_player
-                .get_single()
-                .map(|player| player.character_info.name.clone())
-                .ok(),
-            "<LEVEL>" => user_context
-                .function_context
-                .query_player
-                .get_single()
-                .map(|player| format!("{}", player.level.level))
-                .ok(),
-            _ => None,
+        match message_template_value(template, user_context) {
+            Some(value) => string += &value,
+            None => string += template,
         }
-        .unwrap_or_else(|| template.to_string())
-        .as_str();
         remaining = after_template;
     }
 
@@ -145,6 +239,51 @@ fn parse_message(message: &str, user_context: &LuaVMContext) -> String {
     string
 }
 
+/// Returns a copy of job with only the first num_chars characters, for the typewriter effect.
+fn reveal_layout_job(job: &egui::text::LayoutJob, num_chars: usize) -> egui::text::LayoutJob {
+    let reveal_len = job
+        .text
+        .char_indices()
+        .nth(num_chars)
+        .map_or(job.text.len(), |(index, _)| index);
+
+    let mut revealed = job.clone();
+    revealed.text.truncate(reveal_len);
+    revealed.sections.retain_mut(|section| {
+        section.byte_range.end = section.byte_range.end.min(reveal_len);
+        section.byte_range.start < reveal_len
+    });
+    revealed
+}
+
+/// The colours used by `{fc=N}` tags, from the original client's palette.
+fn message_text_color(index: i32) -> Option<egui::Color32> {
+    Some(match index {
+        0 => egui::Color32::from_rgb(0, 0, 0),
+        1 => egui::Color32::from_rgb(0x80, 0, 0),
+        2 => egui::Color32::from_rgb(0, 0x80, 0),
+        3 => egui::Color32::from_rgb(0, 0, 0x80),
+        4 => egui::Color32::from_rgb(0x80, 0x80, 0),
+        5 => egui::Color32::from_rgb(0x80, 0, 0x80),
+        6 => egui::Color32::from_rgb(0, 0x80, 0x80),
+        7 => egui::Color32::from_rgb(0x80, 0x80, 0x80),
+        8 => egui::Color32::from_rgb(0xC0, 0xC0, 0xC0),
+        9 => egui::Color32::from_rgb(0xC0, 0xDC, 0xC0),
+        10 => egui::Color32::from_rgb(0xC0, 0xC0, 0xDC),
+        11 => egui::Color32::from_rgb(0xA6, 0xCA, 0xF0),
+        12 => egui::Color32::from_rgb(0xFF, 0, 0),
+        13 => egui::Color32::from_rgb(0, 0xFF, 0),
+        14 => egui::Color32::from_rgb(0, 0, 0xFF),
+        15 => egui::Color32::from_rgb(0xFF, 0xFF, 0),
+        16 => egui::Color32::from_rgb(0, 0xFF, 0xFF),
+        17 => egui::Color32::from_rgb(0xFF, 0xFB, 0xF0),
+        18 => egui::Color32::from_rgb(0xFF, 0xFF, 0xFF),
+        _ => return None,
+    })
+}
+
+/// Lays out an LTB string, interpreting its `{tag}` formatting. Unknown tags are kept as
+/// text so nothing the writer intended to show is lost.
 fn message_layout_job(response_number: Option<usize>, message: &str) -> egui::text::LayoutJob {
     let default_text_color = egui::Color32::BLACK;
     let mut remaining = message;
@@ -165,61 +304,40 @@ fn message_layout_job(response_number: Option<usize>, message: &str) -> egui::te
 
     while let Some(tag_start) = remaining.find('{') {
         let (before_tag, tag) = remaining.split_at(tag_start);
-        let tag_end = tag.find('}');
-        if tag_end.is_none() {
+        let Some(tag_end) = tag.find('}') else {
             break;
-        }
-        let tag_end = tag_end.unwrap();
+        };
         let (tag, after_tag) = tag.split_at(tag_end + 1);
 
+        if !before_tag.is_empty() {
+            job.append(before_tag, 0.0, current_text_format.clone());
+        }
+
         let tag_lower = tag.to_lowercase();
         match tag_lower.as_str() {
-            "{br}" => {
-                job.append(before_tag, 0.0, current_text_format.clone());
-                job.append("\n", 0.0, current_text_format.clone());
-            }
-            "{b}" => {
-                job.append(before_tag, 0.0, current_text_format.clone());
-                current_text_format.italics = true;
-            }
-            "{/b}" => {
-                job.append(before_tag, 0.0, current_text_format.clone());
-                current_text_format.italics = false;
-            }
-            "{/fc}" => {
-                job.append(before_tag, 0.0, current_text_format.clone());
-                current_text_format.color = default_text_color;
+            "{br}" => job.append("\n", 0.0, current_text_format.clone()),
+            // The default font has no bold face, italics is the closest emphasis we have
+            "{b}" | "{i}" => current_text_format.italics = true,
+            "{/b}" | "{/i}" => current_text_format.italics = false,
+            "{u}" => {
+                current_text_format.underline = egui::Stroke::new(1.0, current_text_format.color)
             }
-            tag if tag.starts_with("{fc=") => {
-                let len = tag.len();
-                let index_str = &tag[4..len - 1];
-                if let Ok(color_index) = index_str.parse::<i32>() {
-                    job.append(before_tag, 0.0, current_text_format.clone());
-                    current_text_format.color = match color_index {
-                        0 => egui::Color32::from_rgb(0, 0, 0),
-                        1 => egui::Color32::from_rgb(0x80, 0, 0),
-                        2 => egui::Color32::from_rgb(0, 0x80, 0),
-                        3 => egui::Color32::from_rgb(0, 0, 0x80),
-                        4 => egui::Color32::from_rgb(0x80, 0x80, 0),
-                        5 => egui::Color32::from_rgb(0x80, 0, 0x80),
-                        6 => egui::Color32::from_rgb(0, 0x80, 0x80),
-                        7 => egui::Color32::from_rgb(0x80, 0x80, 0x80),
-                        8 => egui::Color32::from_rgb(0xC0, 0xC0, 0xC0),
-                        9 => egui::Color32::from_rgb(0xC0, 0xDC, 0xC0),
-                        10 => egui::Color32::from_rgb(0xC0, 0xC0, 0xDC),
-                        11 => egui::Color32::from_rgb(0xA6, 0xCA, 0xF0),
-                        12 => egui::Color32::from_rgb(0xFF, 0, 0),
-                        13 => egui::Color32::from_rgb(0, 0xFF, 0),
-                        14 => egui::Color32::from_rgb(0, 0, 0xFF),
-                        15 => egui::Color32::from_rgb(0xFF, 0xFF, 0),
-                        16 => egui::Color32::from_rgb(0, 0xFF, 0xFF),
-                        17 => egui::Color32::from_rgb(0xFF, 0xFB, 0xF0),
-                        18 => egui::Color32::from_rgb(0xFF, 0xFF, 0xFF),
-                        _ => default_text_color,
-                    };
+            "{/u}" => current_text_format.underline = egui::Stroke::NONE,
+            "{/fc}" => current_text_format.color = default_text_color,
+            _ => {
+                if let Some(color) = tag_lower
+                    .strip_prefix("{fc=")
+                    .and_then(|index| index.trim_end_matches('}').parse::<i32>().ok())
+                    .map(|index| message_text_color(index).unwrap_or(default_text_color))
+                {
+                    current_text_format.color = color;
+                    if current_text_format.underline != egui::Stroke::NONE {
+                        current_text_format.underline.color = color;
+                    }
+                } else {
+                    job.append(tag, 0.0, current_text_format.clone());
                 }
             }
-            _ => {}
         }
 
         remaining = after_tag;
@@ -248,10 +366,11 @@ impl GeneratedDialog {
 
         let menu = &con_file.menus[menu_idx as usize];
         let mut any_added = false;
+        self.spoken = false;
         for message in menu.messages.iter() {
             if !message.condition_function.is_empty() {
-                match lua_vm.call_global_closure(
-                    user_context,
+                match user_context.call_global_closure(
+                    lua_vm,
                     &message.condition_function,
                     &[Lua4Value::UserData(event_object_handle.clone())],
                 ) {
@@ -316,6 +435,7 @@ impl GeneratedDialog {
                         .map(|message| parse_message(&message, user_context))
                     {
                         self.message = message_layout_job(None, message_text.as_str());
+                        self.message_reveal = 0.0;
                         self.responses.clear();
 
                         self.run_menu(
@@ -372,10 +492,9 @@ pub fn conversation_dialog_system(
     query_player_position: Query<&Position, With<PlayerCharacter>>,
     query_position: Query<&Position>,
     query_name: Query<&ClientEntityName>,
-    lua_game_constants: Res<LuaGameConstants>,
-    lua_game_functions: Res<LuaGameFunctions>,
-    lua_quest_functions: Res<LuaQuestFunctions>,
-    game_data: Res<GameData>,
+    mut lua_resources: ConversationDialogLuaResources,
+    mut conversation_dialog_portrait: ResMut<ConversationDialogPortrait>,
+    time: Res<Time>,
     vfs_resource: Res<VfsResource>,
     ui_resources: Res<UiResources>,
     dialog_assets: Res<Assets<Dialog>>,
@@ -410,9 +529,10 @@ pub fn conversation_dialog_system(
     let mut user_context = LuaVMContext {
         function_context: &mut lua_function_context,
         function_resources: &script_function_resources,
-        game_constants: &lua_game_constants,
-        game_functions: &lua_game_functions,
-        quest_functions: &lua_quest_functions,
+        game_constants: &lua_resources.game_constants,
+        game_functions: &lua_resources.game_functions,
+        quest_functions: &lua_resources.quest_functions,
+        inspector: &mut lua_resources.inspector,
     };
 
     for event in conversation_dialog_events.iter() {
@@ -423,6 +543,9 @@ pub fn conversation_dialog_system(
             ConversationDialogEvent::OpenEventDialog(con_file_path) => (None, con_file_path),
         };
         *current_dialog_state = None;
+        user_context
+            .inspector
+            .begin(con_file_path.path().to_string_lossy().to_string());
 
         if let Some(mut next_dialog_state) = vfs_resource
             .vfs
@@ -436,8 +559,8 @@ pub fn conversation_dialog_system(
                 &next_dialog_state.con_file.initial_messages[0].condition_function;
 
             if !check_open_function.is_empty() {
-                match next_dialog_state.lua_vm.call_global_closure(
-                    &mut user_context,
+                match user_context.call_global_closure(
+                    &mut next_dialog_state.lua_vm,
                     check_open_function,
                     &[Lua4Value::UserData(
                         next_dialog_state.event_object_handle.clone(),
@@ -461,7 +584,7 @@ pub fn conversation_dialog_system(
                                 &mut user_context,
                                 &next_dialog_state.con_file,
                                 &next_dialog_state.event_object_handle,
-                                &game_data,
+                                &script_function_resources.game_data,
                                 0,
                             ) {
                                 *current_dialog_state = Some(next_dialog_state);
@@ -480,6 +603,13 @@ pub fn conversation_dialog_system(
         }
     }
 
+    let portrait_npc_entity = current_dialog_state
+        .as_ref()
+        .and_then(|dialog_state| dialog_state.owner_entity);
+    if conversation_dialog_portrait.npc_entity != portrait_npc_entity {
+        conversation_dialog_portrait.npc_entity = portrait_npc_entity;
+    }
+
     if let Some(dialog_state) = current_dialog_state.as_mut() {
         let mut selected_response = None;
         let mut open = true;
@@ -497,12 +627,37 @@ pub fn conversation_dialog_system(
             }
         }
 
+        if !dialog_state.generated_dialog.spoken {
+            let generated_dialog = &mut dialog_state.generated_dialog;
+            let mut text = generated_dialog.message.text.clone();
+            for response in generated_dialog.responses.iter() {
+                text.push('\n');
+                text.push_str(&response.text.text);
+            }
+            text_to_speech::speak(TextToSpeechChannel::Dialog, &text);
+            generated_dialog.spoken = true;
+        }
+
+        let message_length = dialog_state.generated_dialog.message.text.chars().count();
+        let mut message_reveal_chars = dialog_state.generated_dialog.message_reveal as usize;
+        let revealing_message = message_reveal_chars < message_length;
+        if revealing_message {
+            dialog_state.generated_dialog.message_reveal +=
+                time.delta_seconds() * MESSAGE_REVEAL_CHARACTERS_PER_SECOND;
+            message_reveal_chars = dialog_state.generated_dialog.message_reveal as usize;
+        }
+
         let (message_galley, num_message_middle, num_response_middles) =
             egui_context.ctx_mut().fonts(|fonts| {
-                let message_galley =
-                    fonts.layout_job(dialog_state.generated_dialog.message.clone());
+                // Size the dialog for the whole message so it does not grow as it is revealed
+                let message_size = fonts
+                    .layout_job(dialog_state.generated_dialog.message.clone())
+                    .size();
+                let message_galley = fonts.layout_job(reveal_layout_job(
+                    &dialog_state.generated_dialog.message,
+                    message_reveal_chars,
+                ));
 
-                let message_size = message_galley.size();
                 let num_message_middle =
                     ((message_size.y + dialog_sprites.message_middle.height - 1.0)
                         / dialog_sprites.message_middle.height) as usize;
@@ -535,14 +690,19 @@ pub fn conversation_dialog_system(
             .map(|name| name.as_str())
             .unwrap_or("Event Dialog");
 
+        let portrait_texture_id = dialog_state
+            .owner_entity
+            .map(|_| egui_context.add_image(conversation_dialog_portrait.image.clone_weak()));
+
         let mut response_close_button = None;
+        let mut skip_message_reveal = false;
         let screen_size = egui_context
             .ctx_mut()
             .input(|input| input.screen_rect().size());
         let default_x = screen_size.x / 2.0 - dialog.width / 2.0;
         let default_y = screen_size.y / 2.0 - dialog_height / 2.0;
 
-        egui::Window::new("Conversation Dialog")
+        let window_response = egui::Window::new("Conversation Dialog")
             .frame(egui::Frame::none())
             .open(&mut open)
             .title_bar(false)
@@ -603,6 +763,22 @@ pub fn conversation_dialog_system(
                             ui.horizontal_top(|ui| ui.add(egui::Label::new(message_galley.clone())))
                         });
 
+                        // Responses are shown once the message has been revealed, until then
+                        // clicking the message skips the typewriter effect
+                        if revealing_message {
+                            if ui
+                                .interact(
+                                    message_rect,
+                                    ui.id().with("message_reveal"),
+                                    egui::Sense::click(),
+                                )
+                                .clicked()
+                            {
+                                skip_message_reveal = true;
+                            }
+                            return;
+                        }
+
                         let mut response_rect_min = response_rect_min + egui::vec2(15.0, 5.0);
                         for (index, answer) in
                             dialog_state.generated_dialog.responses.iter().enumerate()
@@ -646,7 +822,25 @@ pub fn conversation_dialog_system(
             return;
         }
 
-        if selected_response.is_none() {
+        if let (Some(portrait_texture_id), Some(window_response)) =
+            (portrait_texture_id, window_response.as_ref())
+        {
+            let portrait_size = CONVERSATION_DIALOG_PORTRAIT_SIZE as f32;
+            egui::Area::new("conversation_dialog_portrait")
+                .fixed_pos(
+                    window_response.response.rect.left_top() - egui::vec2(portrait_size + 4.0, 0.0),
+                )
+                .interactable(false)
+                .show(egui_context.ctx_mut(), |ui| {
+                    ui.image(
+                        portrait_texture_id,
+                        egui::vec2(portrait_size, portrait_size),
+                    );
+                });
+        }
+
+        // Do not use the response hotkeys when typing in a text box, e.g. the chatbox
+        if selected_response.is_none() && !egui_context.ctx_mut().wants_keyboard_input() {
             egui_context.ctx_mut().input(|input| {
                 for (index, &key) in [
                     egui::Key::Num1,
@@ -663,12 +857,24 @@ pub fn conversation_dialog_system(
                 .enumerate()
                 {
                     if input.key_pressed(key) {
-                        selected_response = Some(index);
+                        if revealing_message {
+                            skip_message_reveal = true;
+                        } else {
+                            selected_response = Some(index);
+                        }
                     }
                 }
+
+                if input.key_pressed(egui::Key::Space) || input.key_pressed(egui::Key::Enter) {
+                    skip_message_reveal = true;
+                }
             });
         }
 
+        if skip_message_reveal {
+            dialog_state.generated_dialog.message_reveal = message_length as f32;
+        }
+
         if let Some(selected_response) = selected_response {
             if let Some(selected_response) = dialog_state
                 .generated_dialog
@@ -677,8 +883,8 @@ pub fn conversation_dialog_system(
             {
                 let click_action_function = &selected_response.action_function;
                 if !click_action_function.is_empty() {
-                    if let Err(error) = dialog_state.lua_vm.call_global_closure(
-                        &mut user_context,
+                    if let Err(error) = user_context.call_global_closure(
+                        &mut dialog_state.lua_vm,
                         click_action_function,
                         &[Lua4Value::UserData(
                             dialog_state.event_object_handle.clone(),
@@ -697,7 +903,7 @@ pub fn conversation_dialog_system(
                     &mut user_context,
                     &dialog_state.con_file,
                     &dialog_state.event_object_handle,
-                    &game_data,
+                    &script_function_resources.game_data,
                     selected_response.menu_index,
                 ) {
                     *current_dialog_state = None;
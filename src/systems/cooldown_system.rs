@@ -1,11 +1,23 @@
-use bevy::prelude::{Query, Res, Time};
+use std::time::Instant;
 
-use crate::components::Cooldowns;
+use bevy::prelude::{EventReader, Query, Res, ResMut, Time, With};
+
+use rose_game_common::components::CharacterInfo;
+
+use crate::{
+    components::{Cooldowns, PlayerCharacter},
+    events::ZoneEvent,
+    resources::{Account, CharacterStoreKey, CooldownStore},
+};
 
 pub fn cooldown_system(mut query_cooldowns: Query<&mut Cooldowns>, time: Res<Time>) {
     let delta = time.delta();
 
     for mut cooldowns in query_cooldowns.iter_mut() {
+        // Counting down does not mark the cooldowns as changed, so cooldown_store_system
+        // only stores them again when a cooldown is started
+        let cooldowns = cooldowns.bypass_change_detection();
+
         if let Some((current, _)) = cooldowns.global.as_mut() {
             if delta < *current {
                 *current -= delta;
@@ -45,3 +57,27 @@ pub fn cooldown_system(mut query_cooldowns: Query<&mut Cooldowns>, time: Res<Tim
         }
     }
 }
+
+pub fn cooldown_store_system(
+    mut query_player: Query<(&CharacterInfo, &mut Cooldowns), With<PlayerCharacter>>,
+    mut zone_events: EventReader<ZoneEvent>,
+    mut cooldown_store: ResMut<CooldownStore>,
+    account: Option<Res<Account>>,
+) {
+    let Ok((character_info, mut cooldowns)) = query_player.get_single_mut() else {
+        return;
+    };
+    let now = Instant::now();
+    let store_key = CharacterStoreKey::new(account.as_deref(), &character_info.name);
+
+    // Restore cooldowns when the player is spawned, or after loading a zone as
+    // the cooldowns have not been updated during the loading time
+    let zone_loaded = zone_events.iter().count() > 0;
+    if cooldowns.is_added() || zone_loaded {
+        if let Some(restored) = cooldown_store.restore(&store_key, now) {
+            *cooldowns = restored;
+        }
+    } else if cooldowns.is_changed() {
+        cooldown_store.store(&store_key, &cooldowns, now);
+    }
+}
@@ -1,15 +1,16 @@
 use bevy::{
-    hierarchy::DespawnRecursiveExt,
     math::{Vec3Swizzles, Vec4},
-    prelude::{Commands, Entity, GlobalTransform, Query},
+    prelude::{Commands, Entity, GlobalTransform, Query, ResMut},
 };
 
 use crate::{
     animation::TransformAnimation, components::DamageDigits, render::DamageDigitRenderData,
+    resources::DamageDigitsSpawner,
 };
 
 pub fn damage_digit_render_system(
     mut commands: Commands,
+    mut damage_digits_spawner: ResMut<DamageDigitsSpawner>,
     mut query: Query<(
         Entity,
         &GlobalTransform,
@@ -24,20 +25,23 @@ pub fn damage_digit_render_system(
         damage_digit_render_data.clear();
 
         if animation.completed() {
-            // Animation completed, despawn
-            commands.entity(entity).despawn_recursive();
+            // Animation completed, return to the pool
+            damage_digits_spawner.despawn(&mut commands, entity);
             continue;
         }
 
+        // The animation sets the translation absolutely, so offset it to where the digits spawned
         let (scale, _, translation) = global_transform.to_scale_rotation_translation();
+        let translation = damage_digits.position + translation;
         if damage_digits.damage == 0 {
             // Miss, split over 4 digits
             for digit in 0..4 {
                 damage_digit_render_data.add(
                     translation,
                     -1.5 + digit as f32,
-                    0.4 * scale.xy(),
+                    0.4 * damage_digits.scale * scale.xy(),
                     Vec4::new(digit as f32 / 4.0, 0.0, (digit + 1) as f32 / 4.0, 1.0),
+                    damage_digits.color,
                 );
             }
         } else {
@@ -58,8 +62,9 @@ pub fn damage_digit_render_system(
                 damage_digit_render_data.add(
                     translation,
                     number_offset - digit_offset,
-                    0.4 * scale.xy(),
+                    0.4 * damage_digits.scale * scale.xy(),
                     Vec4::new(digit as f32 / 10.0, 0.0, (digit + 1) as f32 / 10.0, 1.0),
+                    damage_digits.color,
                 );
                 digit_offset += 1.0;
                 damage /= 10;
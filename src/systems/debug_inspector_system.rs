@@ -1,8 +1,6 @@
 use bevy::{
     input::Input,
-    prelude::{
-        App, Camera, Camera3d, GlobalTransform, KeyCode, Plugin, Query, Res, ResMut, Update, With,
-    },
+    prelude::{App, Camera, GlobalTransform, KeyCode, Plugin, Query, Res, ResMut, Update, With},
     window::{PrimaryWindow, Window},
 };
 use bevy_egui::EguiContexts;
@@ -13,7 +11,7 @@ use rose_game_common::{components::*, messages::ClientEntityId};
 use crate::{
     components::*,
     render::{ObjectMaterialBlend, ObjectMaterialGlow},
-    resources::DebugInspector,
+    resources::{DebugInspector, InputAction, InputBindings},
 };
 
 pub struct DebugInspectorPlugin;
@@ -31,10 +29,12 @@ impl Plugin for DebugInspectorPlugin {
 
         app.register_type::<AbilityValues>()
             .register_type::<AbilityValuesAdjust>()
+            .register_type::<Aggro>()
             .register_type::<BasicStats>()
             .register_type::<CharacterBlinkTimer>()
             .register_type::<CharacterGender>()
             .register_type::<CharacterInfo>()
+            .register_type::<CharacterTitle>()
             .register_type::<ClientEntity>()
             .register_type::<ClientEntityId>()
             .register_type::<ClientEntityName>()
@@ -56,10 +56,14 @@ impl Plugin for DebugInspectorPlugin {
             .register_type::<Effect>()
             .register_type::<EffectMesh>()
             .register_type::<EffectParticle>()
+            .register_type::<Encumbrance>()
             .register_type::<EventObject>()
             .register_type::<ExperiencePoints>()
             .register_type::<FacingDirection>()
             .register_type::<HealthPoints>()
+            .register_type::<ItemDropOwner>()
+            .register_type::<ItemGradeGlow>()
+            .register_type::<ItemGradeParticleEffect>()
             .register_type::<Level>()
             .register_type::<ManaPoints>()
             .register_type::<ModelHeight>()
@@ -68,6 +72,8 @@ impl Plugin for DebugInspectorPlugin {
             .register_type::<NextCommand>()
             .register_type::<NightTimeEffect>()
             .register_type::<Npc>()
+            .register_type::<NpcQuestMarker>()
+            .register_type::<NpcQuestMarkerType>()
             .register_type::<ObjectMaterialBlend>()
             .register_type::<ObjectMaterialGlow>()
             .register_type::<PassiveRecoveryTime>()
@@ -98,9 +104,10 @@ fn debug_inspector_picking_system(
     mut debug_inspector_state: ResMut<DebugInspector>,
     mut egui_ctx: EguiContexts,
     key_code_input: Res<Input<KeyCode>>,
+    input_bindings: Res<InputBindings>,
     rapier_context: Res<RapierContext>,
     query_window: Query<&Window, With<PrimaryWindow>>,
-    query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    query_camera: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
 ) {
     if !debug_inspector_state.enable_picking {
         // Picking disabled
@@ -118,7 +125,7 @@ fn debug_inspector_picking_system(
     }
     let cursor_position = cursor_position.unwrap();
 
-    if key_code_input.just_pressed(KeyCode::P) {
+    if input_bindings.just_pressed(InputAction::DebugInspectorPick, &key_code_input) {
         for (camera, camera_transform) in query_camera.iter() {
             if let Some(ray) = camera.viewport_to_world(camera_transform, cursor_position) {
                 if let Some((collider_entity, _distance)) = rapier_context.cast_ray(
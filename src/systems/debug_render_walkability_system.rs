@@ -0,0 +1,225 @@
+use bevy::{
+    math::{Quat, Vec3},
+    prelude::{Assets, Color, Gizmos, GlobalTransform, Query, Res, With},
+};
+use bevy_rapier3d::prelude::{Collider, CollisionGroups, Group, QueryFilter, RapierContext};
+
+use crate::{
+    components::{
+        MainCamera, PlayerCharacter, COLLISION_FILTER_COLLIDABLE, COLLISION_FILTER_MOVEABLE,
+        COLLISION_GROUP_CHARACTER, COLLISION_GROUP_ITEM_DROP, COLLISION_GROUP_NPC,
+        COLLISION_GROUP_PHYSICS_TOY, COLLISION_GROUP_PLAYER, COLLISION_GROUP_ZONE_TERRAIN,
+        COLLISION_GROUP_ZONE_WATER,
+    },
+    resources::{CurrentZone, DebugRenderConfig},
+    zone_loader::ZoneLoaderAsset,
+};
+
+const WALKABILITY_MIN_SPACING: f32 = 0.5;
+const WALKABILITY_MARKER_HEIGHT: f32 = 0.05;
+
+// Matches the collider used by collision_player_system to block movement
+const WALKABILITY_BLOCKER_RADIUS: f32 = 0.4;
+const WALKABILITY_BLOCKER_HEIGHT: f32 = 1.2;
+
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Walkability {
+    Walkable,
+    Steep,
+    Water,
+    Blocked,
+}
+
+impl Walkability {
+    fn color(self) -> Color {
+        match self {
+            Walkability::Walkable => Color::GREEN,
+            Walkability::Steep => Color::ORANGE,
+            Walkability::Water => Color::BLUE,
+            Walkability::Blocked => Color::RED,
+        }
+    }
+}
+
+struct WalkabilitySample {
+    position: Vec3,
+    walkability: Walkability,
+}
+
+fn cast_ray_down(
+    rapier_context: &RapierContext,
+    x: f32,
+    z: f32,
+    groups: CollisionGroups,
+) -> Option<f32> {
+    let ray_origin = Vec3::new(x, 100000.0, z);
+    rapier_context
+        .cast_ray(
+            ray_origin,
+            Vec3::NEG_Y,
+            100000000.0,
+            false,
+            QueryFilter::new().groups(groups),
+        )
+        .map(|(_, distance)| ray_origin.y - distance)
+}
+
+pub fn debug_render_walkability_system(
+    debug_render_config: Res<DebugRenderConfig>,
+    rapier_context: Res<RapierContext>,
+    current_zone: Option<Res<CurrentZone>>,
+    zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
+    query_player: Query<&GlobalTransform, With<PlayerCharacter>>,
+    query_camera: Query<&GlobalTransform, With<MainCamera>>,
+    mut gizmos: Gizmos,
+) {
+    let walkability_config = &debug_render_config.walkability;
+    if !walkability_config.enabled {
+        return;
+    }
+
+    let Some(current_zone_data) = current_zone
+        .as_ref()
+        .and_then(|current_zone| zone_loader_assets.get(&current_zone.handle))
+    else {
+        return;
+    };
+
+    let Some(center) = query_player
+        .get_single()
+        .or_else(|_| query_camera.get_single())
+        .ok()
+        .map(|global_transform| global_transform.translation())
+    else {
+        return;
+    };
+
+    let spacing = walkability_config.spacing.max(WALKABILITY_MIN_SPACING);
+    let num_samples = (walkability_config.radius / spacing).ceil() as i32 * 2 + 1;
+    let origin_x = (center.x / spacing).round() * spacing - (num_samples / 2) as f32 * spacing;
+    let origin_z = (center.z / spacing).round() * spacing - (num_samples / 2) as f32 * spacing;
+    let max_slope = walkability_config.max_slope_degrees.to_radians().tan();
+
+    let moveable_groups =
+        CollisionGroups::new(COLLISION_FILTER_MOVEABLE, !COLLISION_GROUP_PHYSICS_TOY);
+    let water_groups = CollisionGroups::new(Group::all(), COLLISION_GROUP_ZONE_WATER);
+    let blocker_filter = QueryFilter::new().groups(CollisionGroups::new(
+        COLLISION_FILTER_COLLIDABLE,
+        !COLLISION_GROUP_ZONE_TERRAIN
+            & !COLLISION_GROUP_PHYSICS_TOY
+            & !COLLISION_GROUP_PLAYER
+            & !COLLISION_GROUP_CHARACTER
+            & !COLLISION_GROUP_NPC
+            & !COLLISION_GROUP_ITEM_DROP,
+    ));
+    let blocker_collider = Collider::ball(WALKABILITY_BLOCKER_RADIUS);
+
+    // Sample the ground height the same way as the collision systems do
+    let mut samples = Vec::with_capacity((num_samples * num_samples) as usize);
+    for sample_z in 0..num_samples {
+        for sample_x in 0..num_samples {
+            let x = origin_x + sample_x as f32 * spacing;
+            let z = origin_z + sample_z as f32 * spacing;
+
+            let terrain_height =
+                current_zone_data.get_terrain_height(x * 100.0, -z * 100.0) / 100.0;
+            let ground_height = cast_ray_down(&rapier_context, x, z, moveable_groups)
+                .map_or(terrain_height, |collision_height| {
+                    collision_height.max(terrain_height)
+                });
+            let position = Vec3::new(x, ground_height, z);
+
+            let walkability = if rapier_context
+                .intersection_with_shape(
+                    position + Vec3::new(0.0, WALKABILITY_BLOCKER_HEIGHT, 0.0),
+                    Quat::IDENTITY,
+                    &blocker_collider,
+                    blocker_filter,
+                )
+                .is_some()
+            {
+                Walkability::Blocked
+            } else if cast_ray_down(&rapier_context, x, z, water_groups)
+                .map_or(false, |water_height| water_height > ground_height)
+            {
+                Walkability::Water
+            } else {
+                Walkability::Walkable
+            };
+
+            samples.push(WalkabilitySample {
+                position,
+                walkability,
+            });
+        }
+    }
+
+    // Mark samples which are too steep compared to their neighbours
+    let get_index = |sample_x: i32, sample_z: i32| (sample_z * num_samples + sample_x) as usize;
+    for sample_z in 0..num_samples {
+        for sample_x in 0..num_samples {
+            let index = get_index(sample_x, sample_z);
+            let height = samples[index].position.y;
+            let max_height_difference = [(1, 0), (-1, 0), (0, 1), (0, -1)]
+                .iter()
+                .filter_map(|(offset_x, offset_z)| {
+                    let neighbour_x = sample_x + offset_x;
+                    let neighbour_z = sample_z + offset_z;
+                    if (0..num_samples).contains(&neighbour_x)
+                        && (0..num_samples).contains(&neighbour_z)
+                    {
+                        Some(
+                            (samples[get_index(neighbour_x, neighbour_z)].position.y - height)
+                                .abs(),
+                        )
+                    } else {
+                        None
+                    }
+                })
+                .fold(0.0, f32::max);
+
+            if max_height_difference / spacing > max_slope {
+                let sample = &mut samples[index];
+                sample.walkability = sample.walkability.max(Walkability::Steep);
+            }
+        }
+    }
+
+    let marker_offset = Vec3::new(0.0, WALKABILITY_MARKER_HEIGHT, 0.0);
+    let marker_size = spacing * 0.25;
+    for sample_z in 0..num_samples {
+        for sample_x in 0..num_samples {
+            let sample = &samples[get_index(sample_x, sample_z)];
+            let position = sample.position + marker_offset;
+
+            if walkability_config.show_grid {
+                for (neighbour_x, neighbour_z) in
+                    [(sample_x + 1, sample_z), (sample_x, sample_z + 1)]
+                {
+                    if neighbour_x >= num_samples || neighbour_z >= num_samples {
+                        continue;
+                    }
+
+                    let neighbour = &samples[get_index(neighbour_x, neighbour_z)];
+                    gizmos.line(
+                        position,
+                        neighbour.position + marker_offset,
+                        sample.walkability.max(neighbour.walkability).color(),
+                    );
+                }
+            } else {
+                let color = sample.walkability.color();
+                gizmos.line(
+                    position - Vec3::new(marker_size, 0.0, 0.0),
+                    position + Vec3::new(marker_size, 0.0, 0.0),
+                    color,
+                );
+                gizmos.line(
+                    position - Vec3::new(0.0, 0.0, marker_size),
+                    position + Vec3::new(0.0, 0.0, marker_size),
+                    color,
+                );
+            }
+        }
+    }
+}
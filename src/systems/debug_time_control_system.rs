@@ -0,0 +1,31 @@
+use bevy::prelude::{ResMut, Time};
+
+use crate::resources::{DebugTimeControl, DEBUG_TIME_SCALE_MAX, DEBUG_TIME_SCALE_MIN};
+
+// Must run before TimeSystem so the current frame delta uses the new settings
+pub fn debug_time_control_system(
+    mut debug_time_control: ResMut<DebugTimeControl>,
+    mut time: ResMut<Time>,
+) {
+    let time_scale = debug_time_control
+        .time_scale
+        .clamp(DEBUG_TIME_SCALE_MIN, DEBUG_TIME_SCALE_MAX);
+    if time.relative_speed() != time_scale {
+        time.set_relative_speed(time_scale);
+    }
+
+    let paused = if debug_time_control.step_frames > 0 {
+        debug_time_control.step_frames -= 1;
+        false
+    } else {
+        debug_time_control.paused
+    };
+
+    if paused != time.is_paused() {
+        if paused {
+            time.pause();
+        } else {
+            time.unpause();
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use bevy::prelude::{EventReader, Query, ResMut, With};
+
+use crate::{
+    components::{ClientEntity, PlayerCharacter},
+    events::HitEvent,
+    resources::{DefendTarget, SelectedTarget},
+};
+
+pub fn defend_target_system(
+    mut hit_events: EventReader<HitEvent>,
+    mut defend_target: ResMut<DefendTarget>,
+    mut selected_target: ResMut<SelectedTarget>,
+    query_client_entity: Query<&ClientEntity>,
+    query_player: Query<&ClientEntity, With<PlayerCharacter>>,
+) {
+    let Some(defend_entity) = defend_target.entity else {
+        hit_events.clear();
+        return;
+    };
+
+    if !query_client_entity.contains(defend_entity) {
+        // The entity we were defending has left
+        defend_target.entity = None;
+        hit_events.clear();
+        return;
+    }
+
+    for hit_event in hit_events.iter() {
+        if hit_event.defender != defend_entity
+            || hit_event.attacker == defend_entity
+            || query_player.contains(hit_event.attacker)
+        {
+            continue;
+        }
+
+        if query_client_entity.contains(hit_event.attacker) {
+            selected_target.selected = Some(hit_event.attacker);
+        }
+    }
+}
@@ -1,16 +1,18 @@
 use bevy::{
     hierarchy::{Children, DespawnRecursiveExt},
-    prelude::{Commands, Entity, Query},
+    prelude::{Commands, Entity, Query, ResMut},
 };
 
 use crate::{
     animation::{MeshAnimation, TransformAnimation},
-    components::{Effect, EffectMesh, EffectParticle, ParticleSequence},
+    components::{Effect, EffectMesh, EffectParticle, ParticleSequence, PooledEffect},
+    resources::EntityPool,
 };
 
 pub fn effect_system(
     mut commands: Commands,
-    query_effects: Query<(Entity, &Children, &Effect)>,
+    mut effect_pool: ResMut<EntityPool<PooledEffect>>,
+    query_effects: Query<(Entity, &Children, &Effect, Option<&PooledEffect>)>,
     query_children: Query<&Children>,
     query_particle_sequence: Query<(
         &EffectParticle,
@@ -19,7 +21,7 @@ pub fn effect_system(
     )>,
     query_effect_mesh: Query<(&EffectMesh, Option<&MeshAnimation>)>,
 ) {
-    for (effect_entity, effect_children, effect) in query_effects.iter() {
+    for (effect_entity, effect_children, effect, pooled_effect) in query_effects.iter() {
         let mut children_finished = 0;
         let mut children_running = 0;
 
@@ -58,7 +60,12 @@ pub fn effect_system(
         }
 
         if children_finished > 0 && children_running == 0 {
-            commands.entity(effect_entity).despawn_recursive();
+            if pooled_effect.is_some() {
+                commands.entity(effect_entity).remove::<Effect>();
+                effect_pool.release(&mut commands, effect_entity);
+            } else {
+                commands.entity(effect_entity).despawn_recursive();
+            }
         }
     }
 }
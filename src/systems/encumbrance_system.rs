@@ -0,0 +1,114 @@
+use bevy::prelude::{Changed, Commands, Entity, EventWriter, Local, Or, Query, Res, With};
+
+use rose_data::ItemReference;
+use rose_game_common::components::{AbilityValues, Equipment, Inventory};
+
+use crate::{
+    components::{Encumbrance, PlayerCharacter, ENCUMBRANCE_WARNING_RATIO},
+    events::ChatboxEvent,
+    localize,
+    resources::GameData,
+};
+
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EncumbranceLevel {
+    #[default]
+    Normal,
+    Warning,
+    OverEncumbered,
+}
+
+fn get_item_weight(game_data: &GameData, item_reference: ItemReference, quantity: u32) -> u32 {
+    game_data
+        .items
+        .get_base_item(item_reference)
+        .map_or(0, |item_data| item_data.weight * quantity)
+}
+
+pub fn encumbrance_system(
+    mut commands: Commands,
+    mut last_encumbrance_level: Local<EncumbranceLevel>,
+    query_player: Query<
+        (
+            Entity,
+            &AbilityValues,
+            &Equipment,
+            &Inventory,
+            Option<&Encumbrance>,
+        ),
+        (
+            With<PlayerCharacter>,
+            Or<(
+                Changed<AbilityValues>,
+                Changed<Equipment>,
+                Changed<Inventory>,
+            )>,
+        ),
+    >,
+    mut chatbox_events: EventWriter<ChatboxEvent>,
+    game_data: Res<GameData>,
+) {
+    let Ok((entity, ability_values, equipment, inventory, encumbrance)) = query_player.get_single()
+    else {
+        return;
+    };
+
+    let mut weight = 0;
+
+    for page in [
+        &inventory.equipment,
+        &inventory.consumables,
+        &inventory.materials,
+        &inventory.vehicles,
+    ] {
+        for item in page.slots.iter().flatten() {
+            weight += get_item_weight(&game_data, item.get_item_reference(), item.get_quantity());
+        }
+    }
+
+    for item in equipment
+        .equipped_items
+        .values()
+        .chain(equipment.equipped_vehicle.values())
+        .flatten()
+    {
+        weight += get_item_weight(&game_data, item.item, 1);
+    }
+
+    for item in equipment.equipped_ammo.values().flatten() {
+        weight += get_item_weight(&game_data, item.item, item.quantity);
+    }
+
+    let new_encumbrance = Encumbrance {
+        weight,
+        max_weight: ability_values.get_max_weight().max(0) as u32,
+    };
+
+    let encumbrance_level = if new_encumbrance.is_over_encumbered() {
+        EncumbranceLevel::OverEncumbered
+    } else if new_encumbrance.get_ratio() >= ENCUMBRANCE_WARNING_RATIO {
+        EncumbranceLevel::Warning
+    } else {
+        EncumbranceLevel::Normal
+    };
+
+    if encumbrance_level > *last_encumbrance_level {
+        match encumbrance_level {
+            EncumbranceLevel::Warning => {
+                chatbox_events.send(ChatboxEvent::System(localize!("chat-encumbrance-warning")))
+            }
+            EncumbranceLevel::OverEncumbered => {
+                chatbox_events.send(ChatboxEvent::System(localize!("chat-encumbrance-over")))
+            }
+            EncumbranceLevel::Normal => {}
+        }
+    }
+    *last_encumbrance_level = encumbrance_level;
+
+    if encumbrance.map_or(true, |encumbrance| {
+        encumbrance.weight != new_encumbrance.weight
+            || encumbrance.max_weight != new_encumbrance.max_weight
+    }) {
+        commands.entity(entity).insert(new_encumbrance);
+    }
+}
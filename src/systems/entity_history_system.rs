@@ -0,0 +1,56 @@
+use bevy::prelude::{Entity, Local, Query, ResMut};
+
+use crate::{
+    components::{Command, NextCommand, Position},
+    resources::{EntityHistory, EntityHistoryEvent},
+};
+
+const ENTITY_HISTORY_POSITION_INTERVAL: f32 = 0.1;
+
+#[derive(Default)]
+pub struct EntityHistoryState {
+    entity: Option<Entity>,
+    command: Option<Command>,
+    next_command: Option<Option<Command>>,
+    last_position_time: f32,
+}
+
+pub fn entity_history_system(
+    mut state: Local<EntityHistoryState>,
+    mut entity_history: ResMut<EntityHistory>,
+    query: Query<(&Command, &NextCommand, &Position)>,
+) {
+    if state.entity != entity_history.entity {
+        *state = EntityHistoryState {
+            entity: entity_history.entity,
+            ..Default::default()
+        };
+    }
+
+    entity_history.remove_expired();
+
+    let Some((command, next_command, position)) = entity_history
+        .entity
+        .and_then(|entity| query.get(entity).ok())
+    else {
+        return;
+    };
+
+    if state.command.as_ref() != Some(command) {
+        state.command = Some(command.clone());
+        entity_history.push_event(EntityHistoryEvent::Command(command.clone()));
+    }
+
+    if state.next_command.as_ref() != Some(&**next_command) {
+        state.next_command = Some((**next_command).clone());
+        entity_history.push_event(EntityHistoryEvent::NextCommand((**next_command).clone()));
+    }
+
+    let time = entity_history.elapsed();
+    if time - state.last_position_time >= ENTITY_HISTORY_POSITION_INTERVAL
+        || entity_history.positions.is_empty()
+    {
+        state.last_position_time = time;
+        entity_history.push_position(position.position);
+    }
+}
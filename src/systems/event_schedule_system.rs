@@ -0,0 +1,19 @@
+use bevy::prelude::{Res, ResMut};
+
+use crate::resources::{EventSchedule, ServerConfiguration};
+
+pub fn event_schedule_system(
+    mut event_schedule: ResMut<EventSchedule>,
+    server_configuration: Res<ServerConfiguration>,
+) {
+    if event_schedule.url != server_configuration.event_schedule_url {
+        event_schedule.set_url(&server_configuration.event_schedule_url);
+    }
+
+    if event_schedule.needs_refresh() {
+        event_schedule.fetch();
+    }
+
+    event_schedule.receive();
+    event_schedule.update_reminders(chrono::Utc::now());
+}
@@ -0,0 +1,265 @@
+use bevy::{
+    ecs::query::WorldQuery,
+    math::Vec3,
+    prelude::{
+        AssetServer, Assets, Commands, ComputedVisibility, Entity, EventReader, GlobalTransform,
+        Handle, Image, Local, Query, Res, ResMut, Time, Transform, Visibility, With,
+    },
+};
+
+use rose_data::AnimationEventFlags;
+use rose_game_common::components::{MoveMode, Npc};
+
+use crate::{
+    animation::AnimationFrameEvent,
+    components::{FootstepEffect, ModelHeight},
+    render::EffectMeshMaterial,
+    resources::{
+        create_footstep_surface_materials, get_image_average_colour, CurrentZone,
+        FootstepEffectKind, FootstepEffects, GraphicsQuality, FOOTSTEP_EFFECT_FADE_LEVELS,
+        FOOTSTEP_EFFECT_POOL_SIZE,
+    },
+    zone_loader::ZoneLoaderAsset,
+};
+
+// Monsters shorter than this do not kick up dust
+const LARGE_MONSTER_MIN_MODEL_HEIGHT: f32 = 3.0;
+
+const CHARACTER_DUST_SIZE: f32 = 0.5;
+const CHARACTER_DECAL_SIZE: f32 = 0.3;
+const CHARACTER_FOOT_SPACING: f32 = 0.12;
+
+const DUST_LIFETIME: f32 = 0.8;
+const DECAL_LIFETIME: f32 = 6.0;
+const DUST_RISE_SPEED: f32 = 0.3;
+
+#[derive(WorldQuery)]
+pub struct FootstepEntity<'w> {
+    global_transform: &'w GlobalTransform,
+    move_mode: Option<&'w MoveMode>,
+    npc: Option<&'w Npc>,
+    model_height: Option<&'w ModelHeight>,
+}
+
+fn spawn_pooled_effect(
+    commands: &mut Commands,
+    footstep_effects: &mut FootstepEffects,
+    query_effects: &Query<Entity, With<FootstepEffect>>,
+    pool_size: usize,
+    effect: FootstepEffect,
+    transform: Transform,
+) {
+    let mesh = footstep_effects.meshes[effect.kind].clone_weak();
+    let material = footstep_effects.get_materials(effect.tile_index, effect.kind)
+        [FOOTSTEP_EFFECT_FADE_LEVELS - 1]
+        .clone();
+    let bundle = (
+        effect,
+        mesh,
+        material,
+        transform,
+        GlobalTransform::from(transform),
+        Visibility::Inherited,
+        ComputedVisibility::default(),
+    );
+
+    // Reuse the oldest effect once the pool is full, rather than spawning more
+    if footstep_effects.pool.len() < pool_size {
+        let entity = commands.spawn(bundle).id();
+        footstep_effects.pool.push(entity);
+        return;
+    }
+
+    let index = footstep_effects.next_pool_index % footstep_effects.pool.len();
+    footstep_effects.next_pool_index = index + 1;
+
+    let entity = footstep_effects.pool[index];
+    if query_effects.contains(entity) {
+        commands.entity(entity).insert(bundle);
+    } else {
+        // The pooled entity was despawned, such as by a zone change
+        footstep_effects.pool[index] = commands.spawn(bundle).id();
+    }
+}
+
+pub fn footstep_effect_system(
+    mut commands: Commands,
+    mut animation_frame_events: EventReader<AnimationFrameEvent>,
+    mut footstep_effects: ResMut<FootstepEffects>,
+    mut next_foot_is_left: Local<bool>,
+    query_event_entity: Query<FootstepEntity>,
+    query_effects: Query<Entity, With<FootstepEffect>>,
+    current_zone: Option<Res<CurrentZone>>,
+    zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
+    graphics_quality: Res<GraphicsQuality>,
+    asset_server: Res<AssetServer>,
+    mut images: ResMut<Assets<Image>>,
+    mut effect_mesh_materials: ResMut<Assets<EffectMeshMaterial>>,
+) {
+    let current_zone_data = current_zone
+        .as_ref()
+        .and_then(|current_zone| zone_loader_assets.get(&current_zone.handle));
+
+    // Tile colours are only valid for the zone they were created for
+    let current_zone_id = current_zone.as_ref().map(|current_zone| current_zone.id);
+    if footstep_effects.zone_id != current_zone_id {
+        footstep_effects.zone_id = current_zone_id;
+        footstep_effects.tile_materials.clear();
+    }
+
+    let particle_density = graphics_quality.effective_settings().particle_density;
+    let pool_size = ((FOOTSTEP_EFFECT_POOL_SIZE as f32 * particle_density).ceil() as usize).max(1);
+    if footstep_effects.pool.len() > pool_size {
+        for entity in footstep_effects.pool.drain(pool_size..) {
+            if query_effects.contains(entity) {
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+
+    for event in animation_frame_events.iter() {
+        if !event.flags.contains(AnimationEventFlags::SOUND_FOOTSTEP) {
+            continue;
+        }
+
+        let Ok(event_entity) = query_event_entity.get(event.entity) else {
+            continue;
+        };
+
+        let size_scale = if event_entity.npc.is_some() {
+            let model_height = event_entity
+                .model_height
+                .map_or(0.0, |model_height| model_height.height);
+            if model_height < LARGE_MONSTER_MIN_MODEL_HEIGHT {
+                continue;
+            }
+
+            model_height / 2.0
+        } else if matches!(event_entity.move_mode, Some(MoveMode::Run)) {
+            1.0
+        } else {
+            continue;
+        };
+
+        let translation = event_entity.global_transform.translation();
+        let rotation = event_entity.global_transform.compute_transform().rotation;
+
+        // Only leave prints and coloured dust on terrain, not when standing on objects
+        let tile_index = current_zone_data.and_then(|current_zone_data| {
+            let position = Vec3::new(translation.x * 100.0, -translation.z * 100.0, translation.y);
+            if current_zone_data.get_terrain_height(position.x, position.y) / 100.0
+                < (translation.y - 0.05)
+            {
+                None
+            } else {
+                Some((
+                    current_zone_data.get_tile_index(position.x, position.y),
+                    current_zone_data,
+                ))
+            }
+        });
+
+        let tile_index = tile_index.and_then(|(tile_index, current_zone_data)| {
+            if footstep_effects.tile_materials.contains_key(&tile_index) {
+                return Some(tile_index);
+            }
+
+            let path = current_zone_data.zon.tile_textures.get(tile_index)?;
+            let tile_image = asset_server.load(path.as_str());
+            let colour = get_image_average_colour(images.get(&tile_image)?)?;
+            let surface_materials =
+                create_footstep_surface_materials(&mut images, &mut effect_mesh_materials, colour);
+            footstep_effects
+                .tile_materials
+                .insert(tile_index, surface_materials);
+            Some(tile_index)
+        });
+
+        // Alternate between feet, offsetting to the side the model is facing
+        *next_foot_is_left = !*next_foot_is_left;
+        let side = if *next_foot_is_left { -1.0 } else { 1.0 };
+        let foot_position =
+            translation + rotation * Vec3::X * (side * CHARACTER_FOOT_SPACING * size_scale);
+
+        spawn_pooled_effect(
+            &mut commands,
+            &mut footstep_effects,
+            &query_effects,
+            pool_size,
+            FootstepEffect {
+                kind: FootstepEffectKind::Dust,
+                tile_index,
+                scale: CHARACTER_DUST_SIZE * size_scale,
+                age: 0.0,
+                lifetime: DUST_LIFETIME,
+                fade_level: FOOTSTEP_EFFECT_FADE_LEVELS - 1,
+            },
+            Transform::from_translation(foot_position)
+                .with_scale(Vec3::splat(CHARACTER_DUST_SIZE * size_scale)),
+        );
+
+        if tile_index.is_some() {
+            spawn_pooled_effect(
+                &mut commands,
+                &mut footstep_effects,
+                &query_effects,
+                pool_size,
+                FootstepEffect {
+                    kind: FootstepEffectKind::Decal,
+                    tile_index,
+                    scale: CHARACTER_DECAL_SIZE * size_scale,
+                    age: 0.0,
+                    lifetime: DECAL_LIFETIME,
+                    fade_level: FOOTSTEP_EFFECT_FADE_LEVELS - 1,
+                },
+                Transform::from_translation(foot_position)
+                    .with_rotation(rotation)
+                    .with_scale(Vec3::new(
+                        CHARACTER_DECAL_SIZE * size_scale * 0.6,
+                        1.0,
+                        CHARACTER_DECAL_SIZE * size_scale,
+                    )),
+            );
+        }
+    }
+}
+
+pub fn footstep_effect_update_system(
+    mut query_effects: Query<(
+        &mut FootstepEffect,
+        &mut Transform,
+        &mut Visibility,
+        &mut Handle<EffectMeshMaterial>,
+    )>,
+    footstep_effects: Res<FootstepEffects>,
+    time: Res<Time>,
+) {
+    let delta = time.delta_seconds();
+
+    for (mut effect, mut transform, mut visibility, mut material) in query_effects.iter_mut() {
+        if matches!(*visibility, Visibility::Hidden) {
+            continue;
+        }
+
+        effect.age += delta;
+        if effect.age >= effect.lifetime {
+            *visibility = Visibility::Hidden;
+            continue;
+        }
+
+        let progress = effect.age / effect.lifetime;
+        if matches!(effect.kind, FootstepEffectKind::Dust) {
+            // Dust puffs grow as they rise and drift apart
+            transform.translation.y += DUST_RISE_SPEED * delta;
+            transform.scale = Vec3::splat(effect.scale * (1.0 + progress));
+        }
+
+        let fade_level = ((1.0 - progress) * FOOTSTEP_EFFECT_FADE_LEVELS as f32).ceil() as usize;
+        let fade_level = fade_level.clamp(1, FOOTSTEP_EFFECT_FADE_LEVELS) - 1;
+        if fade_level != effect.fade_level {
+            effect.fade_level = fade_level;
+            *material =
+                footstep_effects.get_materials(effect.tile_index, effect.kind)[fade_level].clone();
+        }
+    }
+}
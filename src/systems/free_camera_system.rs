@@ -12,6 +12,8 @@ use bevy::{
 use bevy_egui::EguiContexts;
 use dolly::prelude::{CameraRig, LeftHanded, Position, Smooth, YawPitch};
 
+use crate::resources::{InputAction, InputBindings};
+
 #[derive(Component)]
 pub struct FreeCamera {
     pub rig: CameraRig<LeftHanded>,
@@ -48,6 +50,7 @@ pub fn free_camera_system(
     mut mouse_motion_events: EventReader<MouseMotion>,
     mut mouse_wheel_reader: EventReader<MouseWheel>,
     keyboard: Res<Input<KeyCode>>,
+    input_bindings: Res<InputBindings>,
     mouse_buttons: Res<Input<MouseButton>>,
     mut query_window: Query<&mut Window, With<PrimaryWindow>>,
     mut egui_ctx: EguiContexts,
@@ -101,17 +104,27 @@ pub fn free_camera_system(
     let mut move_vec = Vec3::ZERO;
     let mut speed_boost_multiplier = 1.0f32;
     if allow_keyboard_input {
-        for key in keyboard.get_pressed() {
-            match key {
-                KeyCode::W => move_vec.z -= 1.0,      // Forward
-                KeyCode::S => move_vec.z += 1.0,      // Backward
-                KeyCode::A => move_vec.x -= 1.0,      // Left
-                KeyCode::D => move_vec.x += 1.0,      // Right
-                KeyCode::Q => translate_vec.y -= 1.0, // Down
-                KeyCode::E => translate_vec.y += 1.0, // Up
-                KeyCode::ShiftLeft => speed_boost_multiplier = 4.0,
-                _ => {}
-            }
+        let pressed = |action| input_bindings.pressed(action, &keyboard);
+        if pressed(InputAction::FreeCameraForward) {
+            move_vec.z -= 1.0;
+        }
+        if pressed(InputAction::FreeCameraBackward) {
+            move_vec.z += 1.0;
+        }
+        if pressed(InputAction::FreeCameraLeft) {
+            move_vec.x -= 1.0;
+        }
+        if pressed(InputAction::FreeCameraRight) {
+            move_vec.x += 1.0;
+        }
+        if pressed(InputAction::FreeCameraDown) {
+            translate_vec.y -= 1.0;
+        }
+        if pressed(InputAction::FreeCameraUp) {
+            translate_vec.y += 1.0;
+        }
+        if pressed(InputAction::FreeCameraBoost) {
+            speed_boost_multiplier = 4.0;
         }
     }
 
@@ -135,7 +148,7 @@ pub fn free_camera_system(
 
         free_camera.rig.driver_mut::<Position>().translate(
             -(drag_vec.x * rot_x + (drag_vec.z * rot_z) - Vec3::new(0.0, drag_vec.y, 0.0))
-                * time.delta_seconds()
+                * time.raw_delta_seconds()
                 * speed_boost_multiplier
                 * drag_speed,
         );
@@ -144,7 +157,7 @@ pub fn free_camera_system(
     if move_vec.length_squared() > 0.0 || translate_vec.length_squared() > 0.0 {
         free_camera.rig.driver_mut::<Position>().translate(
             (camera_transform.rotation.mul_vec3(move_vec) + translate_vec)
-                * time.delta_seconds()
+                * time.raw_delta_seconds()
                 * speed_boost_multiplier
                 * move_speed,
         );
@@ -173,7 +186,8 @@ pub fn free_camera_system(
         control_state.is_dragging = false;
     }
 
-    let calculated_transform = free_camera.rig.update(time.delta_seconds());
+    // Uses real time so the camera still moves when debug time is paused or scaled
+    let calculated_transform = free_camera.rig.update(time.raw_delta_seconds());
     camera_transform.translation = calculated_transform.position;
     camera_transform.rotation = calculated_transform.rotation;
 }
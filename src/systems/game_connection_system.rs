@@ -4,8 +4,9 @@ use bevy::{
     math::{Quat, Vec3},
     prelude::{
         Commands, ComputedVisibility, DespawnRecursiveExt, Entity, EventWriter, GlobalTransform,
-        Mut, NextState, Res, ResMut, State, Transform, Visibility, World,
+        Mut, NextState, Query, Res, ResMut, State, Transform, Visibility, World,
     },
+    time::Time,
 };
 
 use rose_data::{
@@ -33,17 +34,24 @@ use rose_network_common::ConnectionError;
 use crate::{
     bundles::{ability_values_add_value_exclusive, ability_values_set_value_exclusive},
     components::{
-        Bank, Clan, ClanMember, ClanMembership, ClientEntity, ClientEntityName, ClientEntityType,
-        CollisionHeightOnly, CollisionPlayer, Command, CommandCastSkillTarget, Cooldowns, Dead,
-        FacingDirection, NextCommand, PartyInfo, PartyOwner, PassiveRecoveryTime, PendingDamage,
-        PendingDamageList, PendingSkillEffect, PendingSkillEffectList, PendingSkillTarget,
-        PendingSkillTargetList, PersonalStore, PlayerCharacter, Position, VisibleStatusEffects,
+        Bank, CastingState, ChatBubble, Clan, ClanMember, ClanMembership, ClientEntity,
+        ClientEntityName, ClientEntityType, CollisionHeightOnly, CollisionPlayer, Command,
+        CommandCastSkillTarget, Cooldowns, Dead, FacingDirection, ItemDropOwner,
+        NetworkedPositionHistory, NextCommand, PartyInfo, PartyOwner, PassiveRecoveryTime,
+        PendingDamage, PendingDamageList, PendingSkillEffect, PendingSkillEffectList,
+        PendingSkillTarget, PendingSkillTargetList, PersonalStore, PlayerCharacter, Position,
+        StatusEffectSources, VisibleStatusEffects, BANK_CHARACTER_PAGES, BANK_SLOTS_PER_PAGE,
     },
     events::{
-        BankEvent, ChatboxEvent, ClientEntityEvent, GameConnectionEvent, LoadZoneEvent,
-        MessageBoxEvent, PartyEvent, PersonalStoreEvent, QuestTriggerEvent, UseItemEvent,
+        AchievementEvent, BankEvent, ChatboxEvent, ClientEntityEvent, CraftEvent,
+        GameConnectionEvent, LoadZoneEvent, LogoutEvent, MessageBoxEvent, PartyEvent,
+        PersonalStoreEvent, QuestTriggerEvent, RepairEvent, UseItemEvent,
+    },
+    localize,
+    resources::{
+        AppState, ClientEntityList, EntityHistory, GameConnection, GameData,
+        PersonalStoreListRequests, SessionStatistics, WorldRates, WorldTime,
     },
-    resources::{AppState, ClientEntityList, GameConnection, GameData, WorldRates, WorldTime},
 };
 
 fn to_next_command(
@@ -137,13 +145,21 @@ pub fn game_connection_system(
     mut personal_store_events: EventWriter<PersonalStoreEvent>,
     mut quest_trigger_events: EventWriter<QuestTriggerEvent>,
     mut message_box_events: EventWriter<MessageBoxEvent>,
+    mut entity_history: ResMut<EntityHistory>,
+    mut query_networked_position: Query<(&mut Position, &MoveSpeed, &mut NetworkedPositionHistory)>,
+    time: Res<Time>,
 ) {
     let Some(game_connection) = game_connection else {
         return;
     };
 
     let result: Result<(), anyhow::Error> = loop {
-        match game_connection.server_message_rx.try_recv() {
+        let message = game_connection.server_message_rx.try_recv();
+        if let Ok(message) = message.as_ref() {
+            entity_history.record_server_message(&client_entity_list, message);
+        }
+
+        match message {
             Ok(ServerMessage::ConnectionRequestSuccess { .. }) =>{
             client_entity_list.clear();
             }
@@ -151,6 +167,8 @@ pub fn game_connection_system(
                 break Err(ConnectionError::ConnectionLost.into());
             },
             Ok(ServerMessage::CharacterData { data: character_data }) => {
+                commands.insert_resource(SessionStatistics::default());
+
                 let status_effects = StatusEffects::default();
                 let ability_values = game_data.ability_value_calculator.calculate(
                     &character_data.character_info,
@@ -209,6 +227,7 @@ pub fn game_connection_system(
                             GlobalTransform::default(),
                             Visibility::default(),
                             ComputedVisibility::default(),
+                            StatusEffectSources::default(),
                         )))
                         .id()
                 );
@@ -307,6 +326,7 @@ pub fn game_connection_system(
                     (
                         ClientEntity::new(message.entity_id, ClientEntityType::Character),
                         CollisionHeightOnly,
+                        NetworkedPositionHistory::default(),
                         FacingDirection::default(),
                         PendingDamageList::default(),
                         PendingSkillEffectList::default(),
@@ -320,6 +340,7 @@ pub fn game_connection_system(
                         Visibility::default(),
                         ComputedVisibility::default(),
                         VisibleStatusEffects::default(),
+                        StatusEffectSources::default(),
                     ),))
                     .id();
 
@@ -471,6 +492,7 @@ pub fn game_connection_system(
                     (
                         ClientEntity::new(entity_id, ClientEntityType::Monster),
                         CollisionHeightOnly,
+                        NetworkedPositionHistory::default(),
                         FacingDirection::default(),
                         PendingDamageList::default(),
                         PendingSkillEffectList::default(),
@@ -489,7 +511,7 @@ pub fn game_connection_system(
 
                 client_entity_list.add(entity_id, entity);
             }
-            Ok(ServerMessage::SpawnEntityItemDrop { entity_id, dropped_item, position, remaining_time: _, owner_entity_id: _ }) => {
+            Ok(ServerMessage::SpawnEntityItemDrop { entity_id, dropped_item, position, remaining_time: _, owner_entity_id }) => {
                 let name = match &dropped_item {
                     DroppedItem::Item(item) => game_data
                         .items
@@ -499,15 +521,16 @@ pub fn game_connection_system(
                             format!("[{:?} {}]", item.get_item_type(), item.get_item_number())
                         }),
                     DroppedItem::Money(money) => {
-                        format!("{} Zuly", money.0)
+                        localize!("item-drop-money", money = money.0)
                     }
                 };
 
-                // TODO: Use message.remaining_time, message.owner_entity_id ?
+                // TODO: Use message.remaining_time ?
                 let entity = commands
                     .spawn((
                         ClientEntityName::new(name),
                         ItemDrop::with_dropped_item(dropped_item),
+                        ItemDropOwner { owner_entity_id },
                         Position::new(position),
                         ClientEntity::new(entity_id, ClientEntityType::ItemDrop),
                         CollisionHeightOnly,
@@ -529,6 +552,17 @@ pub fn game_connection_system(
                     let target_entity = target_entity_id
                         .and_then(|id| client_entity_list.get(id));
 
+                    if let Ok((position, move_speed, mut position_history)) =
+                        query_networked_position.get_mut(entity)
+                    {
+                        position_history.push_move(
+                            time.elapsed_seconds_f64(),
+                            position.position,
+                            Vec3::new(x, y, z as f32),
+                            move_speed.speed,
+                        );
+                    }
+
                     commands.entity(entity).insert(NextCommand::with_move(
                         Vec3::new(x, y, z as f32),
                         target_entity,
@@ -538,14 +572,39 @@ pub fn game_connection_system(
             }
             Ok(ServerMessage::AdjustPosition { entity_id, position }) => {
                 if let Some(entity) = client_entity_list.get(entity_id) {
+                    if let Ok((current_position, move_speed, mut position_history)) =
+                        query_networked_position.get_mut(entity)
+                    {
+                        position_history.push_move(
+                            time.elapsed_seconds_f64(),
+                            current_position.position,
+                            position,
+                            move_speed.speed,
+                        );
+                    }
+
                     commands
                         .entity(entity)
                         .insert(NextCommand::with_move(position, None, None));
                 }
             }
-            Ok(ServerMessage::StopMoveEntity { entity_id, x: _, y: _, z: _ }) => {
-                // TODO: Lerp to XYZ ?
+            Ok(ServerMessage::StopMoveEntity { entity_id, x, y, z }) => {
                 if let Some(entity) = client_entity_list.get(entity_id) {
+                    // Remote entities stop where the server says, with the drawn model
+                    // blending there rather than snapping
+                    if let Ok((mut position, _, mut position_history)) =
+                        query_networked_position.get_mut(entity)
+                    {
+                        let stop_position = Vec3::new(x, y, z as f32);
+                        position_history.push_correction(
+                            time.elapsed_seconds_f64(),
+                            position.position,
+                            stop_position,
+                        );
+                        position.x = x;
+                        position.y = y;
+                    }
+
                     commands.entity(entity).insert(NextCommand::with_stop());
                 }
             }
@@ -595,13 +654,22 @@ pub fn game_connection_system(
                         }
 
                         if killed_by_player {
+                            let killed_npc_id = defender.get::<Npc>().map(|npc| npc.id);
+
                             if let Some(name) = defender.get::<ClientEntityName>() {
                                 let chat_message =
-                                    format!("You have succeeded in hunting {}", name.as_str());
+                                    localize!("chat-hunted-monster", name = name.as_str());
                                 world
                                     .resource_mut::<Events<ChatboxEvent>>()
                                     .send(ChatboxEvent::System(chat_message));
                             }
+
+                            if let Some(npc_id) = killed_npc_id {
+                                world
+                                    .resource_mut::<Events<AchievementEvent>>()
+                                    .send(AchievementEvent::MonsterKilled);
+                                world.resource_mut::<SessionStatistics>().add_kill(npc_id);
+                            }
                         }
                     });
                 }
@@ -646,6 +714,9 @@ pub fn game_connection_system(
                     commands.add(move |world: &mut World| {
                         if let Some(name) = world.entity(chat_entity).get::<ClientEntityName>() {
                             let name = name.to_string();
+                            world
+                                .entity_mut(chat_entity)
+                                .insert(ChatBubble::new(text.clone()));
                             world
                                 .resource_mut::<Events<ChatboxEvent>>()
                                 .send(ChatboxEvent::Say(name, text));
@@ -664,16 +735,19 @@ pub fn game_connection_system(
             }
             Ok(ServerMessage::UpdateAbilityValueAdd { ability_type, value }) => {
                 if let Some(player_entity) = client_entity_list.player_entity {
-                    chatbox_events.send(ChatboxEvent::System(format!(
-                        "Ability {:?} has {} by {}.",
-                        ability_type,
-                        if value < 0 {
-                            "decreased"
-                        } else {
-                            "increased"
-                        },
-                        value.abs(),
-                    )));
+                    chatbox_events.send(ChatboxEvent::System(if value < 0 {
+                        localize!(
+                            "chat-ability-decreased",
+                            ability = game_data.string_database.get_ability_type(ability_type),
+                            value = value.abs(),
+                        )
+                    } else {
+                        localize!(
+                            "chat-ability-increased",
+                            ability = game_data.string_database.get_ability_type(ability_type),
+                            value = value,
+                        )
+                    }));
 
                     commands.add(move |world: &mut World| {
                         let mut player = world.entity_mut(player_entity);
@@ -687,9 +761,10 @@ pub fn game_connection_system(
             }
             Ok(ServerMessage::UpdateAbilityValueSet { ability_type, value }) => {
                 if let Some(player_entity) = client_entity_list.player_entity {
-                    chatbox_events.send(ChatboxEvent::System(format!(
-                        "Ability {:?} has been changed to {}.",
-                        ability_type, value,
+                    chatbox_events.send(ChatboxEvent::System(localize!(
+                        "chat-ability-changed",
+                        ability = game_data.string_database.get_ability_type(ability_type),
+                        value = value,
                     )));
 
                     commands.add(move |world: &mut World| {
@@ -1076,10 +1151,12 @@ pub fn game_connection_system(
                             experience_points.xp = xp;
 
                             if xp > previous_xp {
+                                world.resource_mut::<SessionStatistics>().xp_earned +=
+                                    xp - previous_xp;
                                 world.resource_mut::<Events<ChatboxEvent>>().send(
-                                    ChatboxEvent::System(format!(
-                                        "You have earned {} experience points.",
-                                        xp - previous_xp
+                                    ChatboxEvent::System(localize!(
+                                        "chat-earned-xp",
+                                        xp = xp - previous_xp
                                     )),
                                 );
                             }
@@ -1092,31 +1169,49 @@ pub fn game_connection_system(
                     if let Some(item_data) =
                         game_data.items.get_base_item(item.get_item_reference())
                     {
-                        chatbox_events.send(ChatboxEvent::System(format!(
-                            "You have earned {}.",
-                            item_data.name
+                        chatbox_events.send(ChatboxEvent::System(localize!(
+                            "chat-earned-item",
+                            item = item_data.name
                         )));
                     }
 
                     commands.add(move |world: &mut World| {
+                        let item_reference = item.get_item_reference();
+                        let mut picked_up_quantity = item.get_quantity();
+
                         let mut player = world.entity_mut(player_entity);
                         if let Some(mut inventory) = player.get_mut::<Inventory>() {
                             if let Some(inventory_slot) = inventory.get_item_slot_mut(item_slot)
                             {
+                                // The server sends the whole stack, so only count the difference
+                                let previous_quantity = inventory_slot
+                                    .as_ref()
+                                    .filter(|slot_item| {
+                                        slot_item.get_item_reference() == item_reference
+                                    })
+                                    .map_or(0, |slot_item| slot_item.get_quantity());
+                                picked_up_quantity =
+                                    picked_up_quantity.saturating_sub(previous_quantity);
                                 *inventory_slot = Some(item);
                             }
                         }
+
+                        world
+                            .resource_mut::<SessionStatistics>()
+                            .add_item(item_reference, picked_up_quantity);
                     });
                 }
             }
             Ok(ServerMessage::PickupDropMoney { drop_entity_id: _, money }) => {
                 if let Some(player_entity) = client_entity_list.player_entity {
-                    chatbox_events.send(ChatboxEvent::System(format!(
-                        "You have earned {} Zuly.",
-                        money.0
+                    chatbox_events.send(ChatboxEvent::System(localize!(
+                        "chat-earned-money",
+                        money = money.0
                     )));
 
                     commands.add(move |world: &mut World| {
+                        world.resource_mut::<SessionStatistics>().money_earned += money.0;
+
                         let mut player = world.entity_mut(player_entity);
                         if let Some(mut inventory) = player.get_mut::<Inventory>() {
                             inventory.try_add_money(money).ok();
@@ -1126,14 +1221,14 @@ pub fn game_connection_system(
             }
             Ok(ServerMessage::PickupDropError { drop_entity_id: _, error }) => match error{
                 PickupItemDropError::InventoryFull => {
-                    chatbox_events.send(ChatboxEvent::System(
-                        "Cannot pickup item, inventory full.".to_string(),
-                    ));
+                    chatbox_events.send(ChatboxEvent::System(localize!(
+                        "chat-pickup-inventory-full"
+                    )));
                 }
                 PickupItemDropError::NoPermission => {
-                    chatbox_events.send(ChatboxEvent::System(
-                        "Cannot pickup item, it does not belong to you.".to_string(),
-                    ));
+                    chatbox_events.send(ChatboxEvent::System(localize!(
+                        "chat-pickup-no-permission"
+                    )));
                 }
                 PickupItemDropError::NotExist => {}
             },
@@ -1143,34 +1238,57 @@ pub fn game_connection_system(
                         if let Some(item_data) = item.as_ref().and_then(|item| {
                             game_data.items.get_base_item(item.get_item_reference())
                         }) {
-                            chatbox_events.send(ChatboxEvent::System(format!(
-                                "You have earned {}.",
-                                item_data.name
+                            chatbox_events.send(ChatboxEvent::System(localize!(
+                                "chat-earned-item",
+                                item = item_data.name
                             )));
                         }
                     }
 
                     commands.add(move |world: &mut World| {
+                        let mut rewarded_items = Vec::new();
+
                         let mut player = world.entity_mut(player_entity);
                         if let Some(mut inventory) = player.get_mut::<Inventory>() {
                             for (item_slot, item) in items.into_iter() {
                                 if let Some(inventory_slot) = inventory.get_item_slot_mut(item_slot)
                                 {
+                                    if let Some(item) = item.as_ref() {
+                                        let item_reference = item.get_item_reference();
+                                        let previous_quantity = inventory_slot
+                                            .as_ref()
+                                            .filter(|slot_item| {
+                                                slot_item.get_item_reference() == item_reference
+                                            })
+                                            .map_or(0, |slot_item| slot_item.get_quantity());
+                                        rewarded_items.push((
+                                            item_reference,
+                                            item.get_quantity().saturating_sub(previous_quantity),
+                                        ));
+                                    }
+
                                     *inventory_slot = item;
                                 }
                             }
                         }
+
+                        let mut session_statistics = world.resource_mut::<SessionStatistics>();
+                        for (item_reference, quantity) in rewarded_items {
+                            session_statistics.add_item(item_reference, quantity);
+                        }
                     });
                 }
             }
             Ok(ServerMessage::RewardMoney { money }) => {
                 if let Some(player_entity) = client_entity_list.player_entity {
-                    chatbox_events.send(ChatboxEvent::System(format!(
-                        "You have earned {} Zuly.",
-                        money.0
+                    chatbox_events.send(ChatboxEvent::System(localize!(
+                        "chat-earned-money",
+                        money = money.0
                     )));
 
                     commands.add(move |world: &mut World| {
+                        world.resource_mut::<SessionStatistics>().money_earned += money.0;
+
                         let mut player = world.entity_mut(player_entity);
                         if let Some(mut inventory) = player.get_mut::<Inventory>() {
                             inventory.try_add_money(money).ok();
@@ -1242,37 +1360,17 @@ pub fn game_connection_system(
                         .insert(updated_skill_points);
                 }
             }
-            Ok(ServerMessage::LearnSkillError { error }) => match error {
-                LearnSkillError::AlreadyLearnt => chatbox_events.send(ChatboxEvent::System(
-                    "Failed to learn skill, you already know it.".to_string(),
-                )),
-                LearnSkillError::JobRequirement => chatbox_events.send(ChatboxEvent::System(
-                    "Failed to learn skill, you do not satisfy the job requirement.".to_string(),
-                )),
-                LearnSkillError::SkillRequirement => {
-                    chatbox_events.send(ChatboxEvent::System(
-                        "Failed to learn skill, you do not satisfy the skill requirement."
-                            .to_string(),
-                    ))
-                }
-                LearnSkillError::AbilityRequirement => {
-                    chatbox_events.send(ChatboxEvent::System(
-                        "Failed to learn skill, you do not satisfy the ability requirement."
-                            .to_string(),
-                    ))
-                }
-                LearnSkillError::Full => chatbox_events.send(ChatboxEvent::System(
-                    "Failed to learn skill, you have too many skills.".to_string(),
-                )),
-                LearnSkillError::InvalidSkillId => chatbox_events.send(ChatboxEvent::System(
-                    "Failed to learn skill, invalid skill.".to_string(),
-                )),
-                LearnSkillError::SkillPointRequirement => {
-                    chatbox_events.send(ChatboxEvent::System(
-                        "Failed to learn skill, not enough skill points.".to_string(),
-                    ))
-                }
-            },
+            Ok(ServerMessage::LearnSkillError { error }) => {
+                chatbox_events.send(ChatboxEvent::System(localize!(match error {
+                    LearnSkillError::AlreadyLearnt => "chat-learn-skill-already-learnt",
+                    LearnSkillError::JobRequirement => "chat-learn-skill-job-requirement",
+                    LearnSkillError::SkillRequirement => "chat-learn-skill-skill-requirement",
+                    LearnSkillError::AbilityRequirement => "chat-learn-skill-ability-requirement",
+                    LearnSkillError::Full => "chat-learn-skill-full",
+                    LearnSkillError::InvalidSkillId => "chat-learn-skill-invalid-skill",
+                    LearnSkillError::SkillPointRequirement => "chat-learn-skill-skill-points",
+                })));
+            }
             Ok(ServerMessage::LevelUpSkillSuccess { skill_slot, skill_id, skill_points }) => {
                 if let Some(player_entity) = client_entity_list.player_entity {
                     commands.add(move |world: &mut World| {
@@ -1288,39 +1386,16 @@ pub fn game_connection_system(
                 }
             }
             Ok(ServerMessage::LevelUpSkillError { error, skill_points }) => {
-                match error {
-                    LevelUpSkillError::Failed => chatbox_events.send(ChatboxEvent::System(
-                        "Failed to level up skill.".to_string(),
-                    )),
-                    LevelUpSkillError::JobRequirement => {
-                        chatbox_events.send(ChatboxEvent::System(
-                            "Failed to level up skill, you do not satisfy the job requirement."
-                                .to_string(),
-                        ))
-                    }
-                    LevelUpSkillError::SkillRequirement => {
-                        chatbox_events.send(ChatboxEvent::System(
-                            "Failed to level up skill, you do not satisfy the skill requirement."
-                                .to_string(),
-                        ))
-                    }
+                chatbox_events.send(ChatboxEvent::System(localize!(match error {
+                    LevelUpSkillError::Failed => "chat-level-up-skill-failed",
+                    LevelUpSkillError::JobRequirement => "chat-level-up-skill-job-requirement",
+                    LevelUpSkillError::SkillRequirement => "chat-level-up-skill-skill-requirement",
                     LevelUpSkillError::AbilityRequirement => {
-                        chatbox_events.send(ChatboxEvent::System(
-                            "Failed to level up skill, you do not satisfy the ability requirement."
-                                .to_string(),
-                        ))
-                    }
-                    LevelUpSkillError::MoneyRequirement => {
-                        chatbox_events.send(ChatboxEvent::System(
-                            "Failed to level up skill, not enough money.".to_string(),
-                        ))
+                        "chat-level-up-skill-ability-requirement"
                     }
-                    LevelUpSkillError::SkillPointRequirement => {
-                        chatbox_events.send(ChatboxEvent::System(
-                            "Failed to level up skill, not enough skill points.".to_string(),
-                        ))
-                    }
-                }
+                    LevelUpSkillError::MoneyRequirement => "chat-level-up-skill-money",
+                    LevelUpSkillError::SkillPointRequirement => "chat-level-up-skill-skill-points",
+                })));
 
                 if let Some(player_entity) = client_entity_list.player_entity {
                     commands
@@ -1481,11 +1556,21 @@ pub fn game_connection_system(
                                 *command = Command::with_stop();
                             }
                         }
+                        character.remove::<CastingState>();
                     });
                 }
             }
-            Ok(ServerMessage::StartCastingSkill { entity_id: _ }) => {
-                // Nah bruv
+            Ok(ServerMessage::StartCastingSkill { entity_id }) => {
+                if let Some(entity) = client_entity_list.get(entity_id) {
+                    // We may have started casting before the server did, e.g. when it had to
+                    // move into range first, so time the cast from when the server starts it
+                    commands.add(move |world: &mut World| {
+                        let now = world.resource::<Time>().elapsed();
+                        if let Some(mut casting_state) = world.get_mut::<CastingState>(entity) {
+                            casting_state.start_time = now;
+                        }
+                    });
+                }
             }
             Ok(ServerMessage::FinishCastingSkill { entity_id, skill_id }) => {
                 if let Some(entity) = client_entity_list.get(entity_id) {
@@ -1585,9 +1670,9 @@ pub fn game_connection_system(
                 }
             }
             Ok(ServerMessage::NpcStoreTransactionError { error }) => {
-                chatbox_events.send(ChatboxEvent::System(format!(
-                    "Store transation failed with error {:?}",
-                    error
+                chatbox_events.send(ChatboxEvent::System(localize!(
+                    "chat-npc-store-transaction-failed",
+                    error = format!("{:?}", error)
                 )));
             }
             Ok(ServerMessage::PartyCreate { entity_id }) => {
@@ -1606,9 +1691,9 @@ pub fn game_connection_system(
                         if let Some(invited_entity_name) =
                             world.entity(invited_entity).get::<ClientEntityName>()
                         {
-                            let message = format!(
-                                "{} accepted your party invite.",
-                                invited_entity_name.as_str()
+                            let message = localize!(
+                                "chat-party-invite-accepted",
+                                name = invited_entity_name.as_str()
                             );
                             world
                                 .resource_mut::<Events<ChatboxEvent>>()
@@ -1636,7 +1721,7 @@ pub fn game_connection_system(
                             world.entity(player_entity).get::<ClientEntityName>()
                         {
                             let message =
-                                format!("{} has joined the party.", player_entity_name.as_str());
+                                localize!("chat-party-joined", name = player_entity_name.as_str());
                             world
                                 .resource_mut::<Events<ChatboxEvent>>()
                                 .send(ChatboxEvent::System(message));
@@ -1650,9 +1735,9 @@ pub fn game_connection_system(
                         if let Some(invited_entity_name) =
                             world.entity(invited_entity).get::<ClientEntityName>()
                         {
-                            let message = format!(
-                                "{} rejected your party invite.",
-                                invited_entity_name.as_str()
+                            let message = localize!(
+                                "chat-party-invite-rejected",
+                                name = invited_entity_name.as_str()
                             );
                             world
                                 .resource_mut::<Events<ChatboxEvent>>()
@@ -1676,9 +1761,9 @@ pub fn game_connection_system(
                                 if let Some(character_info) =
                                     world.entity(player_entity).get::<CharacterInfo>()
                                 {
-                                    let message = format!(
-                                        "{} is now leader of the party.",
-                                        &character_info.name
+                                    let message = localize!(
+                                        "chat-party-new-leader",
+                                        name = character_info.name.as_str()
                                     );
                                     world
                                         .resource_mut::<Events<ChatboxEvent>>()
@@ -1690,9 +1775,9 @@ pub fn game_connection_system(
                                 for member in party_info.members.iter() {
                                     if let PartyMemberInfo::Online(member_info_online) = member {
                                         if member_info_online.entity_id == entity_id {
-                                            let message = format!(
-                                                "{} is now leader of the party.",
-                                                &member_info_online.name
+                                            let message = localize!(
+                                                "chat-party-new-leader",
+                                                name = member_info_online.name.as_str()
                                             );
 
                                             party_info.owner = PartyOwner::Character(
@@ -1714,7 +1799,7 @@ pub fn game_connection_system(
             Ok(ServerMessage::PartyDelete) => {
                 if let Some(player_entity) = client_entity_list.player_entity {
                     commands.entity(player_entity).remove::<PartyInfo>();
-                    chatbox_events.send(ChatboxEvent::System("You have left the party.".into()));
+                    chatbox_events.send(ChatboxEvent::System(localize!("chat-party-left")));
                 }
             }
             Ok(ServerMessage::PartyMemberList {
@@ -1742,7 +1827,7 @@ pub fn game_connection_system(
 
                         let mut messages: ArrayVec<String, 10> = ArrayVec::new();
                         for member in members.iter() {
-                            messages.push(format!("{} has joined the party.", member.get_name()));
+                            messages.push(localize!("chat-party-joined", name = member.get_name()));
                         }
 
                         party_info.members.append(&mut members);
@@ -1776,9 +1861,9 @@ pub fn game_connection_system(
                                 .iter()
                                 .position(|x| x.get_character_id() == leaver_character_id)
                             {
-                                let message = format!(
-                                    "{} has left the party.",
-                                    party_info.members[index].get_name()
+                                let message = localize!(
+                                    "chat-party-member-left",
+                                    name = party_info.members[index].get_name()
                                 );
 
                                 party_info.members.remove(index);
@@ -1803,8 +1888,10 @@ pub fn game_connection_system(
                                 .find(|x| x.get_character_id() == character_id)
                             {
                                 if let PartyMemberInfo::Online(party_member_online) = party_member {
-                                    let message =
-                                        format!("{} has disconnected.", &party_member_online.name);
+                                    let message = localize!(
+                                        "chat-party-member-disconnected",
+                                        name = party_member_online.name.as_str()
+                                    );
 
                                     *party_member =
                                         PartyMemberInfo::Offline(PartyMemberInfoOffline {
@@ -1832,9 +1919,9 @@ pub fn game_connection_system(
                                 .iter()
                                 .position(|x| x.get_character_id() == character_id)
                             {
-                                let message = format!(
-                                    "{} has been kicked from the party.",
-                                    party_info.members[index].get_name()
+                                let message = localize!(
+                                    "chat-party-member-kicked",
+                                    name = party_info.members[index].get_name()
                                 );
                                 party_info.members.remove(index);
 
@@ -1894,10 +1981,10 @@ pub fn game_connection_system(
                     commands.add(move |world: &mut World| {
                         if let Some(member) = world.get_entity(member_entity) {
                             if let Some(member_entity_name) = member.get::<ClientEntityName>() {
-                                let chat_message = format!(
-                                    "{} has earned {}.",
-                                    member_entity_name.as_str(),
-                                    item_name
+                                let chat_message = localize!(
+                                    "chat-party-member-earned-item",
+                                    name = member_entity_name.as_str(),
+                                    item = item_name
                                 );
                                 world
                                     .resource_mut::<Events<ChatboxEvent>>()
@@ -1918,21 +2005,26 @@ pub fn game_connection_system(
 
                             let mut chatbox_events = world.resource_mut::<Events<ChatboxEvent>>();
                             chatbox_events
-                                .send(ChatboxEvent::System("Party rules have changed.".into()));
-                            chatbox_events.send(ChatboxEvent::System(format!(
-                                "Experience points sharing: {}.",
-                                match xp_sharing {
-                                    PartyXpSharing::EqualShare => "Equal Share",
-                                    PartyXpSharing::DistributedByLevel => "Distributed by Level",
-                                }
+                                .send(ChatboxEvent::System(localize!("chat-party-rules-changed")));
+                            chatbox_events.send(ChatboxEvent::System(localize!(
+                                "chat-party-xp-sharing",
+                                sharing = localize!(match xp_sharing {
+                                    PartyXpSharing::EqualShare => "party-xp-sharing-equal-share",
+                                    PartyXpSharing::DistributedByLevel => {
+                                        "party-xp-sharing-distributed-by-level"
+                                    }
+                                })
                             )));
-                            chatbox_events.send(ChatboxEvent::System(format!(
-                                "Item sharing: {}.",
-                                match item_sharing {
-                                    PartyItemSharing::EqualLootDistribution =>
-                                        "Equal Loot Distribution",
-                                    PartyItemSharing::AcquisitionOrder => "Acquisition Order",
-                                }
+                            chatbox_events.send(ChatboxEvent::System(localize!(
+                                "chat-party-item-sharing",
+                                sharing = localize!(match item_sharing {
+                                    PartyItemSharing::EqualLootDistribution => {
+                                        "party-item-sharing-equal-loot-distribution"
+                                    }
+                                    PartyItemSharing::AcquisitionOrder => {
+                                        "party-item-sharing-acquisition-order"
+                                    }
+                                })
                             )));
                         }
                     });
@@ -1972,9 +2064,23 @@ pub fn game_connection_system(
                 }
             }
             Ok(ServerMessage::PersonalStoreItemList { sell_items, buy_items  }) => {
-                personal_store_events.send(PersonalStoreEvent::SetItemList {
-                    sell_items,
-                    buy_items,
+                commands.add(move |world: &mut World| {
+                    let Some(entity) = world
+                        .resource_mut::<PersonalStoreListRequests>()
+                        .pending
+                        .pop_front()
+                    else {
+                        log::warn!("Received PersonalStoreItemList without a pending request");
+                        return;
+                    };
+
+                    let mut personal_store_events =
+                        world.resource_mut::<Events<PersonalStoreEvent>>();
+                    personal_store_events.send(PersonalStoreEvent::SetItemList {
+                        entity,
+                        sell_items,
+                        buy_items,
+                    });
                 });
             }
             Ok(ServerMessage::PersonalStoreTransaction {
@@ -2006,18 +2112,19 @@ pub fn game_connection_system(
 
                 match status {
                     PersonalStoreTransactionStatus::Cancelled => {
-                        chatbox_events
-                            .send(ChatboxEvent::System("Transaction failed.".to_string()));
+                        chatbox_events.send(ChatboxEvent::System(localize!(
+                            "chat-personal-store-transaction-cancelled"
+                        )));
                     }
                     PersonalStoreTransactionStatus::SoldOut => {
-                        chatbox_events.send(ChatboxEvent::System(
-                            "Transaction failed. Item has sold out.".to_string(),
-                        ));
+                        chatbox_events.send(ChatboxEvent::System(localize!(
+                            "chat-personal-store-transaction-sold-out"
+                        )));
                     }
                     PersonalStoreTransactionStatus::NoMoreNeed => {
-                        chatbox_events.send(ChatboxEvent::System(
-                            "Transaction failed. Item is no longer wanted.".to_string(),
-                        ));
+                        chatbox_events.send(ChatboxEvent::System(localize!(
+                            "chat-personal-store-transaction-no-more-need"
+                        )));
                     }
                     _ => {}
                 }
@@ -2055,30 +2162,16 @@ pub fn game_connection_system(
                                 if let Some(item_data) = transaction_item
                                     .and_then(|item| game_data.items.get_base_item(item))
                                 {
-                                    let message = if transaction_quantity > 1 {
-                                        format!(
-                                            "You have {} {}x {} for {} Zuly.",
-                                            if transaction_price < 0 {
-                                                "purchased"
-                                            } else {
-                                                "sold"
-                                            },
-                                            transaction_quantity,
-                                            item_data.name,
-                                            transaction_price.abs()
-                                        )
-                                    } else {
-                                        format!(
-                                            "You have {} {} for {} Zuly.",
-                                            if transaction_price < 0 {
-                                                "purchased"
-                                            } else {
-                                                "sold"
-                                            },
-                                            item_data.name,
-                                            transaction_price.abs()
-                                        )
-                                    };
+                                    let message = localize!(
+                                        if transaction_price < 0 {
+                                            "chat-personal-store-purchased"
+                                        } else {
+                                            "chat-personal-store-sold"
+                                        },
+                                        quantity = transaction_quantity,
+                                        item = item_data.name,
+                                        money = transaction_price.abs()
+                                    );
                                     let mut chatbox_events =
                                         world.resource_mut::<Events<ChatboxEvent>>();
                                     chatbox_events.send(ChatboxEvent::System(message));
@@ -2098,11 +2191,11 @@ pub fn game_connection_system(
             }
             Ok(ServerMessage::BankSetItems { items }) => {
                 if let Some(player_entity) = client_entity_list.player_entity {
-                    let mut slots = vec![None; 160];
+                    let mut slots = vec![None; BANK_CHARACTER_PAGES * BANK_SLOTS_PER_PAGE];
 
                     for (bank_slot_index, item) in items {
                         let bank_slot_index = bank_slot_index as usize;
-                        if bank_slot_index > slots.len() {
+                        if bank_slot_index >= slots.len() {
                             slots.resize(bank_slot_index + 1, None);
                         }
                         slots[bank_slot_index] = item;
@@ -2123,7 +2216,7 @@ pub fn game_connection_system(
                             for (bank_slot_index, item) in items {
                                 let bank_slot_index = bank_slot_index as usize;
 
-                                if bank_slot_index > bank.slots.len() {
+                                if bank_slot_index >= bank.slots.len() {
                                     bank.slots.resize(bank_slot_index + 1, None);
                                 }
 
@@ -2157,9 +2250,10 @@ pub fn game_connection_system(
                         }
 
                         if let Some(mut bank) = world.entity_mut(player_entity).get_mut::<Bank>() {
-                            if let Some(bank_slot) = bank.slots.get_mut(bank_slot) {
-                                *bank_slot = bank_item;
+                            if bank_slot >= bank.slots.len() {
+                                bank.slots.resize(bank_slot + 1, None);
                             }
+                            bank.slots[bank_slot] = bank_item;
                         }
                     });
                 }
@@ -2211,7 +2305,27 @@ pub fn game_connection_system(
                 if let Some(player_entity) = client_entity_list.player_entity {
                     commands.add(move |world: &mut World| {
                         let mut entity_mut = world.entity_mut(player_entity);
+                        let mut messages = Vec::new();
                         if let Some(mut clan) = entity_mut.get_mut::<Clan>() {
+                            if level.0 > clan.level.0 {
+                                messages.push(localize!(
+                                    "chat-clan-level-up",
+                                    level = level.0.to_string()
+                                ));
+                            }
+
+                            if points.0 != clan.points.0 {
+                                messages.push(localize!(
+                                    if points.0 > clan.points.0 {
+                                        "chat-clan-points-increased"
+                                    } else {
+                                        "chat-clan-points-decreased"
+                                    },
+                                    points = points.0,
+                                    change = points.0.abs_diff(clan.points.0)
+                                ));
+                            }
+
                             clan.unique_id = id;
                             clan.mark = mark;
                             clan.level = level;
@@ -2219,6 +2333,22 @@ pub fn game_connection_system(
                             clan.money = money;
                             clan.skills = skills;
                         }
+
+                        if !messages.is_empty() {
+                            let mut chatbox_events = world.resource_mut::<Events<ChatboxEvent>>();
+                            for message in messages.iter() {
+                                chatbox_events.send(ChatboxEvent::System(message.clone()));
+                            }
+
+                            let mut message_box_events =
+                                world.resource_mut::<Events<MessageBoxEvent>>();
+                            message_box_events.send(MessageBoxEvent::Show {
+                                message: messages.join("\n"),
+                                modal: false,
+                                ok: Some(Box::new(|_| {})),
+                                cancel: None,
+                            });
+                        }
                     });
                 }
             }
@@ -2296,23 +2426,58 @@ pub fn game_connection_system(
                     });
                 }
             }
-            Ok(ServerMessage::CraftInsertGem { .. }) => {
-                log::warn!("Received unimplemented ServerMessage::CraftInsertGem");
+            Ok(ServerMessage::CraftInsertGem { update_items }) => {
+                if let Some(player_entity) = client_entity_list.player_entity {
+                    commands.add(move |world: &mut World| {
+                        update_inventory_and_money(world, player_entity, update_items, None);
+
+                        let mut craft_events = world.resource_mut::<Events<CraftEvent>>();
+                        craft_events.send(CraftEvent::InsertGemSuccess);
+                    });
+                }
             }
-            Ok(ServerMessage::CraftInsertGemError { .. }) => {
-                log::warn!("Received unimplemented ServerMessage::CraftInsertGemError");
+            Ok(ServerMessage::CraftInsertGemError { error }) => {
+                commands.add(move |world: &mut World| {
+                    let mut craft_events = world.resource_mut::<Events<CraftEvent>>();
+                    craft_events.send(CraftEvent::InsertGemError { error });
+                });
             }
-            Ok(ServerMessage::RepairedItemUsingNpc { .. }) => {
-                log::warn!("Received unimplemented ServerMessage::RepairedItemUsingNpc");
+            Ok(ServerMessage::RepairedItemUsingNpc {
+                item_slot,
+                item,
+                updated_money,
+            }) => {
+                if let Some(player_entity) = client_entity_list.player_entity {
+                    commands.add(move |world: &mut World| {
+                        update_inventory_and_money(
+                            world,
+                            player_entity,
+                            vec![(item_slot, Some(item))],
+                            Some(updated_money),
+                        );
+
+                        let mut repair_events = world.resource_mut::<Events<RepairEvent>>();
+                        repair_events.send(RepairEvent::Repaired { item_slot });
+                    });
+                }
             }
             Ok(ServerMessage::LogoutSuccess) => {
-                log::warn!("Received unimplemented ServerMessage::LogoutSuccess");
+                commands.add(move |world: &mut World| {
+                    let mut logout_events = world.resource_mut::<Events<LogoutEvent>>();
+                    logout_events.send(LogoutEvent::Success);
+                });
             }
-            Ok(ServerMessage::LogoutFailed { .. }) => {
-                log::warn!("Received unimplemented ServerMessage::LogoutFailed");
+            Ok(ServerMessage::LogoutFailed { wait_duration }) => {
+                commands.add(move |world: &mut World| {
+                    let mut logout_events = world.resource_mut::<Events<LogoutEvent>>();
+                    logout_events.send(LogoutEvent::Failed { wait_duration });
+                });
             }
             Ok(ServerMessage::ReturnToCharacterSelect) => {
-                log::warn!("Received unimplemented ServerMessage::ReturnToCharacterSelect");
+                commands.add(move |world: &mut World| {
+                    let mut logout_events = world.resource_mut::<Events<LogoutEvent>>();
+                    logout_events.send(LogoutEvent::ReturnToCharacterSelect);
+                });
             }
             Ok(ServerMessage::LoginError { .. }) |
             Ok(ServerMessage::LoginSuccess { .. }) |
@@ -1,9 +1,11 @@
+use std::time::Duration;
+
 use bevy::{
     ecs::query::WorldQuery,
     input::Input,
     math::Vec3,
     prelude::{
-        Camera, Camera3d, Entity, EventWriter, GlobalTransform, MouseButton, Query, Res, ResMut,
+        Camera, Entity, EventWriter, GlobalTransform, Local, MouseButton, Query, Res, ResMut, Time,
         With,
     },
     window::{CursorGrabMode, PrimaryWindow, Window},
@@ -15,13 +17,18 @@ use rose_game_common::components::{ItemDrop, Team};
 
 use crate::{
     components::{
-        ClientEntity, ClientEntityType, ColliderParent, PlayerCharacter, Position, ZoneObject,
-        COLLISION_FILTER_CLICKABLE, COLLISION_GROUP_PHYSICS_TOY, COLLISION_GROUP_PLAYER,
+        ClientEntity, ClientEntityType, ColliderParent, MainCamera, PlayerCharacter, Position,
+        ZoneObject, COLLISION_FILTER_CLICKABLE, COLLISION_GROUP_PHYSICS_TOY,
+        COLLISION_GROUP_PLAYER,
     },
     events::{MoveDestinationEffectEvent, PlayerCommandEvent},
     resources::{SelectedTarget, UiCursorType, UiRequestedCursor},
 };
 
+// Right mouse button is also used to rotate the camera, so only a quick press and release
+// counts as a click which opens the context menu
+const RIGHT_CLICK_MAX_DURATION: Duration = Duration::from_millis(250);
+
 #[derive(WorldQuery)]
 pub struct PlayerQuery<'w> {
     entity: Entity,
@@ -32,7 +39,7 @@ pub struct PlayerQuery<'w> {
 pub fn game_mouse_input_system(
     mouse_button_input: Res<Input<MouseButton>>,
     query_window: Query<&Window, With<PrimaryWindow>>,
-    query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    query_camera: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
     rapier_context: Res<RapierContext>,
     mut egui_ctx: EguiContexts,
     query_collider_parent: Query<&ColliderParent>,
@@ -48,10 +55,20 @@ pub fn game_mouse_input_system(
     mut move_destination_effect_events: EventWriter<MoveDestinationEffectEvent>,
     mut selected_target: ResMut<SelectedTarget>,
     mut ui_requested_cursor: ResMut<UiRequestedCursor>,
+    mut right_pressed_time: Local<Option<Duration>>,
+    time: Res<Time>,
 ) {
     selected_target.hover = None;
     ui_requested_cursor.world_cursor = UiCursorType::Default;
 
+    if mouse_button_input.just_pressed(MouseButton::Right) {
+        *right_pressed_time = Some(time.elapsed());
+    }
+    let right_clicked = mouse_button_input.just_released(MouseButton::Right)
+        && right_pressed_time.take().map_or(false, |pressed_time| {
+            time.elapsed().saturating_sub(pressed_time) < RIGHT_CLICK_MAX_DURATION
+        });
+
     let Ok(window) = query_window.get_single() else {
         return;
     };
@@ -157,6 +174,15 @@ pub fn game_mouse_input_system(
                 } else if let Some(hit_team) = hit_team {
                     selected_target.hover = Some(hit_entity);
 
+                    if right_clicked
+                        && hit_client_entity.map_or(false, |hit_client_entity| {
+                            hit_client_entity.entity_type == ClientEntityType::Character
+                        })
+                    {
+                        selected_target.selected = Some(hit_entity);
+                        selected_target.context_menu = Some((hit_entity, cursor_position));
+                    }
+
                     if mouse_button_input.just_pressed(MouseButton::Left) {
                         if selected_target
                             .selected
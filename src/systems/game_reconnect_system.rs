@@ -0,0 +1,258 @@
+use bevy::prelude::{Commands, EventWriter, NextState, Query, Res, ResMut, State, Time, With};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_game_common::components::CharacterInfo;
+
+use crate::{
+    components::PlayerCharacter,
+    events::{CharacterSelectEvent, LoginEvent},
+    localize,
+    resources::{
+        Account, AppState, CharacterList, CharacterSelectState, GameConnection, GameReconnect,
+        GameReconnectState, LoginConnection, LoginState, Logout, ReplayPlayback,
+        ServerConfiguration, ServerList, WorldConnection, GAME_RECONNECT_MAX_ATTEMPTS,
+    },
+};
+
+/// Starts reconnecting when the game server connection is lost while in game.
+pub fn game_reconnect_start_system(
+    mut commands: Commands,
+    game_connection: Option<Res<GameConnection>>,
+    game_reconnect: Option<Res<GameReconnect>>,
+    replay_playback: Option<Res<ReplayPlayback>>,
+    logout: Option<Res<Logout>>,
+    account: Option<Res<Account>>,
+    server_configuration: Res<ServerConfiguration>,
+    query_player: Query<&CharacterInfo, With<PlayerCharacter>>,
+) {
+    if game_connection.is_some()
+        || game_reconnect.is_some()
+        || replay_playback.is_some()
+        || logout.is_some()
+    {
+        return;
+    }
+
+    let (Some(account), Ok(character_info)) = (account, query_player.get_single()) else {
+        return;
+    };
+
+    let (Some(server_id), Some(channel_id)) = (
+        account.server_id.or(server_configuration.preset_server_id),
+        account
+            .channel_id
+            .or(server_configuration.preset_channel_id),
+    ) else {
+        return;
+    };
+
+    log::info!("Lost connection to game server, starting reconnect");
+    let mut game_reconnect = GameReconnect::new(
+        account.username.clone(),
+        account.password.clone(),
+        server_id,
+        channel_id,
+        character_info.name.clone(),
+    );
+
+    // Auto login does not wait for the user, so neither does the first attempt
+    if server_configuration.auto_login {
+        game_reconnect.skip_countdown();
+    }
+
+    commands.insert_resource(game_reconnect);
+}
+
+fn return_to_login(
+    commands: &mut Commands,
+    app_state: AppState,
+    app_state_next: &mut NextState<AppState>,
+) {
+    commands.remove_resource::<LoginConnection>();
+    commands.remove_resource::<WorldConnection>();
+    commands.remove_resource::<GameConnection>();
+
+    // The server list is from the previous login, wait for a new one
+    commands.remove_resource::<ServerList>();
+
+    if app_state != AppState::GameLogin {
+        app_state_next.set(AppState::GameLogin);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn game_reconnect_system(
+    mut commands: Commands,
+    mut egui_context: EguiContexts,
+    app_state: Res<State<AppState>>,
+    mut app_state_next: ResMut<NextState<AppState>>,
+    game_reconnect: Option<ResMut<GameReconnect>>,
+    login_state: Option<Res<LoginState>>,
+    server_list: Option<Res<ServerList>>,
+    character_list: Option<Res<CharacterList>>,
+    character_select_state: Option<Res<CharacterSelectState>>,
+    game_connection: Option<Res<GameConnection>>,
+    mut login_events: EventWriter<LoginEvent>,
+    mut character_select_events: EventWriter<CharacterSelectEvent>,
+    time: Res<Time>,
+) {
+    let Some(mut game_reconnect) = game_reconnect else {
+        return;
+    };
+    let game_reconnect = &mut *game_reconnect;
+    let app_state = *app_state.get();
+    // The login screen returns to input when it has no login server connection
+    let login_input = app_state == AppState::GameLogin
+        && matches!(login_state.as_deref(), Some(LoginState::Input));
+
+    if game_reconnect.is_attempting()
+        && game_reconnect
+            .attempt_timeout
+            .tick(time.delta())
+            .just_finished()
+    {
+        log::warn!("Timed out reconnecting to game server");
+        game_reconnect.fail_attempt();
+        return_to_login(&mut commands, app_state, &mut app_state_next);
+    }
+
+    match game_reconnect.state {
+        GameReconnectState::Countdown(ref mut timer) => {
+            if timer.tick(time.delta()).finished() {
+                game_reconnect.start_attempt();
+                return_to_login(&mut commands, app_state, &mut app_state_next);
+            }
+        }
+        GameReconnectState::Login => {
+            if login_input {
+                login_events.send(LoginEvent::Login {
+                    username: game_reconnect.username.clone(),
+                    password: game_reconnect.password.clone(),
+                });
+                game_reconnect.state = GameReconnectState::WaitServerList;
+            }
+        }
+        GameReconnectState::WaitServerList => {
+            let logged_in = matches!(login_state.as_deref(), Some(LoginState::ServerSelect));
+            let has_channel = server_list.as_ref().map_or(false, |server_list| {
+                server_list.world_servers.iter().any(|world_server| {
+                    world_server.id == game_reconnect.server_id
+                        && world_server
+                            .game_servers
+                            .iter()
+                            .any(|game_server| game_server.id == game_reconnect.channel_id)
+                })
+            });
+
+            if logged_in && has_channel {
+                login_events.send(LoginEvent::SelectServer {
+                    server_id: game_reconnect.server_id,
+                    channel_id: game_reconnect.channel_id,
+                });
+                game_reconnect.state = GameReconnectState::WaitCharacterList;
+            } else if login_input {
+                log::warn!("Lost login server connection while reconnecting");
+                game_reconnect.fail_attempt();
+            }
+        }
+        GameReconnectState::WaitCharacterList => {
+            if app_state == AppState::GameCharacterSelect
+                && matches!(
+                    character_select_state.as_deref(),
+                    Some(CharacterSelectState::CharacterSelect(_))
+                )
+            {
+                let Some(character_list) = character_list.as_ref() else {
+                    return;
+                };
+
+                if let Some(index) = character_list
+                    .characters
+                    .iter()
+                    .position(|character| character.info.name == game_reconnect.character_name)
+                {
+                    character_select_events.send(CharacterSelectEvent::SelectCharacter(index));
+                    character_select_events.send(CharacterSelectEvent::PlaySelected);
+                    game_reconnect.state = GameReconnectState::JoiningGame;
+                } else {
+                    log::warn!(
+                        "Character {} not found while reconnecting",
+                        game_reconnect.character_name
+                    );
+                    game_reconnect.state = GameReconnectState::Failed;
+                }
+            } else if login_input {
+                log::warn!("Lost login server connection while reconnecting");
+                game_reconnect.fail_attempt();
+            }
+        }
+        GameReconnectState::JoiningGame => {
+            if app_state == AppState::Game && game_connection.is_some() {
+                log::info!("Reconnected to game server");
+                commands.remove_resource::<GameReconnect>();
+                return;
+            } else if app_state == AppState::GameLogin {
+                log::warn!("Lost world server connection while reconnecting");
+                game_reconnect.fail_attempt();
+            }
+        }
+        GameReconnectState::Failed => {}
+    }
+
+    let mut reconnect_now = false;
+    let mut cancel = false;
+
+    egui::Window::new(localize!("game-reconnect-title"))
+        .id(egui::Id::new("game_reconnect"))
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .order(egui::Order::Foreground)
+        .collapsible(false)
+        .resizable(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            match game_reconnect.state {
+                GameReconnectState::Countdown(ref timer) => {
+                    ui.label(localize!("game-reconnect-lost"));
+                    ui.label(localize!(
+                        "game-reconnect-countdown",
+                        seconds = timer.remaining_secs().ceil() as u32
+                    ));
+                }
+                GameReconnectState::Failed => {
+                    ui.label(localize!("game-reconnect-failed"));
+                }
+                _ => {
+                    ui.horizontal(|ui| {
+                        ui.spinner();
+                        ui.label(localize!("game-reconnect-connecting"));
+                    });
+                }
+            }
+
+            if game_reconnect.attempt > 0 {
+                ui.label(
+                    egui::RichText::new(localize!(
+                        "game-reconnect-attempt",
+                        attempt = game_reconnect.attempt,
+                        max = GAME_RECONNECT_MAX_ATTEMPTS
+                    ))
+                    .weak(),
+                );
+            }
+
+            ui.horizontal(|ui| {
+                if matches!(game_reconnect.state, GameReconnectState::Countdown(_)) {
+                    reconnect_now = ui.button(localize!("game-reconnect-now")).clicked();
+                }
+
+                cancel = ui.button(localize!("game-reconnect-cancel")).clicked();
+            });
+        });
+
+    if cancel {
+        commands.remove_resource::<GameReconnect>();
+        return_to_login(&mut commands, app_state, &mut app_state_next);
+    } else if reconnect_now {
+        game_reconnect.start_attempt();
+        return_to_login(&mut commands, app_state, &mut app_state_next);
+    }
+}
@@ -1,20 +1,21 @@
 use bevy::{
+    hierarchy::DespawnRecursiveExt,
     math::Vec3,
-    prelude::{Camera3d, Commands, Entity, EventReader, Query, Res, With},
+    prelude::{Commands, Entity, EventReader, Or, Query, Res, ResMut, With},
 };
 use rose_game_common::messages::client::ClientMessage;
 
 use crate::{
     animation::CameraAnimation,
-    components::PlayerCharacter,
+    components::{ClientEntity, MainCamera, PlayerCharacter},
     events::ZoneEvent,
-    resources::GameConnection,
+    resources::{ClientEntityList, GameConnection, Logout},
     systems::{FreeCamera, OrbitCamera},
 };
 
 pub fn game_state_enter_system(
     mut commands: Commands,
-    query_cameras: Query<Entity, With<Camera3d>>,
+    query_cameras: Query<Entity, With<MainCamera>>,
     query_player: Query<Entity, With<PlayerCharacter>>,
 ) {
     // Reset camera
@@ -32,6 +33,26 @@ pub fn game_state_enter_system(
     }
 }
 
+/// Despawns the entities from the game server, so a new session does not
+/// start with the entities of the previous one.
+pub fn game_state_exit_system(
+    mut commands: Commands,
+    mut client_entity_list: ResMut<ClientEntityList>,
+    query_client_entities: Query<Entity, Or<(With<ClientEntity>, With<PlayerCharacter>)>>,
+) {
+    for entity in query_client_entities.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    client_entity_list.clear();
+    client_entity_list.player_entity = None;
+    client_entity_list.player_entity_id = None;
+    client_entity_list.zone_id = None;
+
+    // Any logout has finished once we have left the game
+    commands.remove_resource::<Logout>();
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn game_zone_change_system(
     mut zone_events: EventReader<ZoneEvent>,
@@ -0,0 +1,86 @@
+use bevy::{
+    pbr::DirectionalLightShadowMap,
+    prelude::{Camera3d, DirectionalLight, Msaa, Projection, Query, Res, ResMut, Time, With},
+    render::renderer::RenderAdapterInfo,
+};
+
+use crate::resources::{
+    GraphicsBenchmark, GraphicsQuality, GraphicsQualityPreset, UserSettingsStore,
+};
+
+pub fn graphics_quality_system(
+    graphics_quality: Res<GraphicsQuality>,
+    mut msaa: ResMut<Msaa>,
+    mut shadow_map: ResMut<DirectionalLightShadowMap>,
+    mut query_lights: Query<&mut DirectionalLight>,
+    mut query_cameras: Query<&mut Projection, With<Camera3d>>,
+) {
+    // Only write values which differ to avoid triggering change detection every frame
+    let settings = &graphics_quality.effective_settings();
+    let target_msaa = Msaa::from(settings.anti_aliasing);
+    if *msaa != target_msaa {
+        *msaa = target_msaa;
+    }
+
+    let shadow_map_size = settings.shadow_quality.shadow_map_size();
+    if let Some(size) = shadow_map_size {
+        if shadow_map.size != size {
+            shadow_map.size = size;
+        }
+    }
+
+    for mut directional_light in query_lights.iter_mut() {
+        if directional_light.shadows_enabled != shadow_map_size.is_some() {
+            directional_light.shadows_enabled = shadow_map_size.is_some();
+        }
+    }
+
+    for mut projection in query_cameras.iter_mut() {
+        let Projection::Perspective(perspective_projection) = &*projection else {
+            continue;
+        };
+
+        if perspective_projection.far != settings.draw_distance {
+            if let Projection::Perspective(perspective_projection) = &mut *projection {
+                perspective_projection.far = settings.draw_distance;
+            }
+        }
+    }
+}
+
+pub fn graphics_quality_detect_system(
+    mut graphics_quality: ResMut<GraphicsQuality>,
+    user_settings_store: Res<UserSettingsStore>,
+    render_adapter_info: Option<Res<RenderAdapterInfo>>,
+) {
+    // Detection gives the same result each time, so it is fine to repeat it
+    // on every startup until the user has saved their graphics settings
+    if user_settings_store
+        .get_loaded::<GraphicsQualityPreset>("graphics", "quality_preset")
+        .is_some()
+    {
+        return;
+    }
+
+    let Some(render_adapter_info) = render_adapter_info else {
+        return;
+    };
+
+    let preset = GraphicsQualityPreset::detect(&render_adapter_info.name);
+    log::info!(
+        "Detected graphics quality preset {:?} for adapter {}",
+        preset,
+        render_adapter_info.name
+    );
+    graphics_quality.set_preset(preset);
+}
+
+pub fn graphics_benchmark_system(
+    mut graphics_benchmark: ResMut<GraphicsBenchmark>,
+    mut graphics_quality: ResMut<GraphicsQuality>,
+    time: Res<Time>,
+) {
+    if graphics_benchmark.is_running() {
+        graphics_benchmark.update(&mut graphics_quality, time.raw_delta());
+    }
+}
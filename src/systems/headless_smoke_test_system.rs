@@ -0,0 +1,65 @@
+use std::time::Duration;
+
+use bevy::{
+    app::AppExit,
+    prelude::{EventReader, EventWriter, Local, Res, Time},
+};
+
+use crate::{
+    events::ZoneEvent,
+    resources::{HeadlessSmokeTest, MissingAssets},
+};
+
+const HEADLESS_ZONE_LOAD_TIMEOUT: Duration = Duration::from_secs(300);
+
+#[derive(Default)]
+pub struct HeadlessSmokeTestState {
+    zone_loaded: bool,
+    num_frames: u32,
+}
+
+pub fn headless_smoke_test_system(
+    mut state: Local<HeadlessSmokeTestState>,
+    smoke_test: Res<HeadlessSmokeTest>,
+    missing_assets: Res<MissingAssets>,
+    time: Res<Time>,
+    mut zone_events: EventReader<ZoneEvent>,
+    mut app_exit_events: EventWriter<AppExit>,
+) {
+    for event in zone_events.iter() {
+        let ZoneEvent::Loaded(zone_id) = event;
+        log::info!("Smoke test loaded zone {}", zone_id.get());
+        state.zone_loaded = true;
+    }
+
+    let failure = if smoke_test.has_panicked() {
+        Some("a panic occurred".to_string())
+    } else if !state.zone_loaded && time.raw_elapsed() > HEADLESS_ZONE_LOAD_TIMEOUT {
+        Some(format!(
+            "zone did not load within {} seconds",
+            HEADLESS_ZONE_LOAD_TIMEOUT.as_secs()
+        ))
+    } else if !state.zone_loaded {
+        return;
+    } else if state.num_frames < smoke_test.num_frames {
+        state.num_frames += 1;
+        return;
+    } else if !missing_assets.is_empty() {
+        for path in missing_assets.paths() {
+            log::error!("Missing asset: {}", path.to_string_lossy());
+        }
+        Some("missing assets".to_string())
+    } else {
+        None
+    };
+
+    if let Some(failure) = failure {
+        log::error!("Smoke test failed: {}", failure);
+        smoke_test.set_exit_code(1);
+    } else {
+        log::info!("Smoke test passed after {} frames", state.num_frames);
+        smoke_test.set_exit_code(0);
+    }
+
+    app_exit_events.send(AppExit);
+}
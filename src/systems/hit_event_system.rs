@@ -1,6 +1,13 @@
+use std::time::Duration;
+
 use bevy::{
     ecs::query::WorldQuery,
-    prelude::{Commands, Entity, EventReader, EventWriter, GlobalTransform, Query, Res, ResMut},
+    math::{Quat, Vec3},
+    prelude::{
+        Commands, Entity, EventReader, EventWriter, GlobalTransform, Query, Res, ResMut, Time,
+        Transform,
+    },
+    render::mesh::skinning::SkinnedMesh,
 };
 
 use rose_game_common::{
@@ -10,11 +17,11 @@ use rose_game_common::{
 
 use crate::{
     components::{
-        ClientEntity, ClientEntityType, Dead, ModelHeight, NextCommand, PendingDamageList,
-        PendingSkillEffectList, PendingSkillTargetList,
+        CharacterModel, ClientEntity, ClientEntityType, Dead, DummyBoneOffset, ModelHeight,
+        NextCommand, PendingDamageList, PendingSkillEffectList, PendingSkillTargetList,
     },
     events::{HitEvent, SpawnEffectData, SpawnEffectEvent},
-    resources::{ClientEntityList, DamageDigitsSpawner, GameData},
+    resources::{ClientEntityList, DamageDigitType, DamageDigitsSpawner, GameData},
 };
 
 #[derive(WorldQuery)]
@@ -38,6 +45,9 @@ pub struct HitDefenderQuery<'w> {
     model_height: Option<&'w ModelHeight>,
     move_speed: &'w MoveSpeed,
     status_effects: &'w mut StatusEffects,
+    character_model: Option<&'w CharacterModel>,
+    skinned_mesh: Option<&'w SkinnedMesh>,
+    dummy_bone_offset: Option<&'w DummyBoneOffset>,
 }
 
 fn apply_damage(
@@ -45,8 +55,10 @@ fn apply_damage(
     defender: &mut HitDefenderQueryItem,
     damage: Damage,
     is_killed: bool,
-    damage_digits_spawner: &DamageDigitsSpawner,
+    damage_digits_spawner: &mut DamageDigitsSpawner,
     client_entity_list: &mut ClientEntityList,
+    query_global_transform: &Query<&GlobalTransform>,
+    now: Duration,
 ) {
     if defender.health_points.hp < damage.amount as i32 {
         defender.health_points.hp = 0;
@@ -54,16 +66,41 @@ fn apply_damage(
         defender.health_points.hp -= damage.amount as i32;
     }
 
+    let is_damage_player = client_entity_list
+        .player_entity
+        .map_or(false, |player_entity| defender.entity == player_entity);
+    let digit_type = if damage.amount == 0 {
+        DamageDigitType::Miss
+    } else if is_damage_player {
+        DamageDigitType::Taken
+    } else if damage.is_critical {
+        DamageDigitType::Critical
+    } else {
+        DamageDigitType::Dealt
+    };
+
+    // Only character skeletons have a known head dummy bone
+    let head_transform = defender
+        .character_model
+        .and(defender.skinned_mesh.zip(defender.dummy_bone_offset))
+        .and_then(|(skinned_mesh, dummy_bone_offset)| {
+            skinned_mesh
+                .joints
+                .get(dummy_bone_offset.index + DummyBoneOffset::CHARACTER_HEAD)
+        })
+        .and_then(|joint| query_global_transform.get(*joint).ok());
+
     damage_digits_spawner.spawn(
         commands,
-        defender.global_transform,
-        defender
-            .model_height
-            .map_or(1.8, |model_height| model_height.height),
+        now,
+        defender.entity,
+        DamageDigitsSpawner::get_anchored_spawn_position(
+            defender.global_transform,
+            defender.model_height,
+            head_transform,
+        ),
         damage.amount,
-        client_entity_list
-            .player_entity
-            .map_or(false, |player_entity| defender.entity == player_entity),
+        digit_type,
     );
 
     if is_killed {
@@ -82,11 +119,13 @@ fn apply_damage(
 pub fn hit_event_system(
     mut commands: Commands,
     mut query_defender: Query<HitDefenderQuery>,
+    query_global_transform: Query<&GlobalTransform>,
     mut hit_events: EventReader<HitEvent>,
     mut spawn_effect_events: EventWriter<SpawnEffectEvent>,
     mut client_entity_list: ResMut<ClientEntityList>,
-    damage_digits_spawner: Res<DamageDigitsSpawner>,
+    mut damage_digits_spawner: ResMut<DamageDigitsSpawner>,
     game_data: Res<GameData>,
+    time: Res<Time>,
 ) {
     for event in hit_events.iter() {
         let defender = query_defender.get_mut(event.defender).ok();
@@ -130,8 +169,10 @@ pub fn hit_event_system(
                     &mut defender,
                     damage,
                     is_killed,
-                    &damage_digits_spawner,
+                    &mut damage_digits_spawner,
                     &mut client_entity_list,
+                    &query_global_transform,
+                    time.elapsed(),
                 );
             }
         }
@@ -140,20 +181,28 @@ pub fn hit_event_system(
             .effect_id
             .and_then(|id| game_data.effect_database.get_effect(id))
         {
+            // Projectile hits are oriented to face out of the surface they struck
+            let spawn_hit_effect = |spawn_effect_data| match event.impact {
+                Some(impact) => SpawnEffectEvent::WithTransform(
+                    Transform::from_translation(impact.position)
+                        .with_rotation(Quat::from_rotation_arc(Vec3::Y, impact.normal)),
+                    spawn_effect_data,
+                ),
+                None => SpawnEffectEvent::AtEntity(defender.entity, spawn_effect_data),
+            };
+
             if damage.is_critical {
                 if let Some(effect_file_id) = effect_data.hit_effect_critical {
-                    spawn_effect_events.send(SpawnEffectEvent::AtEntity(
-                        defender.entity,
-                        SpawnEffectData::with_file_id(effect_file_id),
-                    ));
+                    spawn_effect_events.send(spawn_hit_effect(SpawnEffectData::with_file_id(
+                        effect_file_id,
+                    )));
                 }
             }
 
             if let Some(effect_file_id) = effect_data.hit_effect_normal {
-                spawn_effect_events.send(SpawnEffectEvent::AtEntity(
-                    defender.entity,
-                    SpawnEffectData::with_file_id(effect_file_id),
-                ));
+                spawn_effect_events.send(spawn_hit_effect(SpawnEffectData::with_file_id(
+                    effect_file_id,
+                )));
             }
         }
 
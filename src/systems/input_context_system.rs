@@ -0,0 +1,29 @@
+use bevy::prelude::{Query, ResMut, With};
+use enum_map::enum_map;
+
+use crate::{
+    components::{PlayerCharacter, Vehicle},
+    resources::{InputBindings, InputContext},
+    systems::FreeCamera,
+};
+
+/// Updates which binding contexts are active, so a key can be bound to
+/// different actions in contexts which are never active together.
+pub fn input_context_system(
+    mut input_bindings: ResMut<InputBindings>,
+    query_free_camera: Query<(), With<FreeCamera>>,
+    query_player_vehicle: Query<(), (With<PlayerCharacter>, With<Vehicle>)>,
+) {
+    let free_camera = !query_free_camera.is_empty();
+    let driving = !query_player_vehicle.is_empty();
+    let active_contexts = enum_map! {
+        InputContext::General | InputContext::Debug => true,
+        InputContext::Combat | InputContext::Ui => !free_camera,
+        InputContext::Vehicle => driving && !free_camera,
+        InputContext::FreeCamera => free_camera,
+    };
+
+    if input_bindings.active_contexts != active_contexts {
+        input_bindings.active_contexts = active_contexts;
+    }
+}
@@ -2,25 +2,32 @@ use bevy::{
     ecs::query::QueryEntityError,
     math::{Vec3, Vec3A},
     prelude::{
-        AssetServer, Assets, BuildChildren, Changed, Commands, Entity, GlobalTransform, Handle,
-        Mesh, Query, Res, ResMut, Transform, With, Without,
+        AssetServer, Assets, BuildChildren, Changed, Commands, ComputedVisibility,
+        DespawnRecursiveExt, Entity, GlobalTransform, Handle, Local, Mesh, Query, Ref, Res, ResMut,
+        Transform, Visibility, With, Without,
     },
     render::primitives::Aabb,
 };
 use bevy_rapier3d::prelude::{Collider, CollisionGroups};
 
-use rose_game_common::components::ItemDrop;
+use rose_data::Item;
+use rose_game_common::components::{DroppedItem, ItemDrop};
 
 use crate::{
     animation::TransformAnimation,
     components::{
-        ColliderEntity, ColliderParent, ItemDropModel, COLLISION_FILTER_CLICKABLE,
-        COLLISION_FILTER_INSPECTABLE, COLLISION_GROUP_ITEM_DROP, COLLISION_GROUP_PHYSICS_TOY,
+        ColliderEntity, ColliderParent, ItemDropEffectEntity, ItemDropModel,
+        COLLISION_FILTER_CLICKABLE, COLLISION_FILTER_INSPECTABLE, COLLISION_GROUP_ITEM_DROP,
+        COLLISION_GROUP_PHYSICS_TOY,
     },
     model_loader::ModelLoader,
     render::ObjectMaterial,
+    resources::{GameData, ItemDropEffects, ItemDropRarity, ItemGradeEffectSettings},
 };
 
+// Equipment of at least this grade is shown as rare even without a rare type
+const ITEM_DROP_RARE_MIN_GRADE: u8 = 4;
+
 pub fn item_drop_model_system(
     mut commands: Commands,
     mut query: Query<(Entity, &ItemDrop, Option<&mut ItemDropModel>), Changed<ItemDrop>>,
@@ -58,6 +65,98 @@ pub fn item_drop_model_system(
     }
 }
 
+fn get_item_drop_rarity(
+    game_data: &GameData,
+    dropped_item: &DroppedItem,
+) -> Option<ItemDropRarity> {
+    match dropped_item {
+        DroppedItem::Money(_) => Some(ItemDropRarity::Money),
+        DroppedItem::Item(Item::Equipment(equipment_item)) => {
+            let item_data = game_data.items.get_base_item(equipment_item.item)?;
+
+            // Matches the item name colours used by get_item_name_color
+            Some(match item_data.rare_type {
+                21 => ItemDropRarity::Unique,
+                1..=20 => ItemDropRarity::Rare,
+                _ if equipment_item.grade >= ITEM_DROP_RARE_MIN_GRADE => ItemDropRarity::Rare,
+                _ => ItemDropRarity::Equipment,
+            })
+        }
+        DroppedItem::Item(Item::Stackable(_)) => None,
+    }
+}
+
+pub fn item_drop_effect_system(
+    mut commands: Commands,
+    query: Query<(Entity, Ref<ItemDrop>, Option<&ItemDropEffectEntity>)>,
+    game_data: Res<GameData>,
+    item_drop_effects: Res<ItemDropEffects>,
+    item_grade_effect_settings: Res<ItemGradeEffectSettings>,
+    mut effects_enabled: Local<Option<bool>>,
+) {
+    let settings_changed = *effects_enabled != Some(item_grade_effect_settings.item_drop_effects);
+    *effects_enabled = Some(item_grade_effect_settings.item_drop_effects);
+
+    for (entity, item_drop, effect_entity) in query.iter() {
+        if !item_drop.is_changed() && !settings_changed {
+            continue;
+        }
+
+        if let Some(effect_entity) = effect_entity {
+            commands.entity(effect_entity.0).despawn_recursive();
+            commands.entity(entity).remove::<ItemDropEffectEntity>();
+        }
+
+        if !item_grade_effect_settings.item_drop_effects {
+            continue;
+        }
+
+        let Some(rarity) = item_drop
+            .item
+            .as_ref()
+            .and_then(|dropped_item| get_item_drop_rarity(&game_data, dropped_item))
+        else {
+            continue;
+        };
+        let rarity_effect = &item_drop_effects.rarity_effects[rarity];
+
+        let effect_entity = commands
+            .spawn((
+                Visibility::default(),
+                ComputedVisibility::default(),
+                Transform::default(),
+                GlobalTransform::default(),
+            ))
+            .with_children(|child_builder| {
+                child_builder.spawn((
+                    item_drop_effects.glow_mesh.clone_weak(),
+                    rarity_effect.glow_material.clone_weak(),
+                    Visibility::default(),
+                    ComputedVisibility::default(),
+                    Transform::default(),
+                    GlobalTransform::default(),
+                ));
+
+                if rarity_effect.beam_height > 0.0 {
+                    child_builder.spawn((
+                        item_drop_effects.beam_mesh.clone_weak(),
+                        rarity_effect.beam_material.clone_weak(),
+                        Visibility::default(),
+                        ComputedVisibility::default(),
+                        Transform::from_scale(Vec3::new(1.0, rarity_effect.beam_height, 1.0)),
+                        GlobalTransform::default(),
+                    ));
+                }
+            })
+            .set_parent(entity)
+            .id();
+
+        commands
+            .entity(entity)
+            .insert(ItemDropEffectEntity(effect_entity));
+    }
+}
+
 pub fn item_drop_model_add_collider_system(
     mut commands: Commands,
     query_models: Query<(Entity, &ItemDropModel), Without<ColliderEntity>>,
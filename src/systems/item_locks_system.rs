@@ -0,0 +1,45 @@
+use bevy::prelude::{Commands, DetectChanges, Entity, Query, Ref, Res, With};
+
+use rose_game_common::components::{CharacterInfo, Equipment, Inventory};
+
+use crate::{
+    components::{ItemLocks, PlayerCharacter},
+    resources::{Account, CharacterStoreKey},
+};
+
+/// Loads the item locks of the player character when it is spawned, and keeps
+/// each lock on its item as items are moved.
+pub fn item_locks_system(
+    mut commands: Commands,
+    mut query_player: Query<
+        (
+            Entity,
+            &CharacterInfo,
+            Ref<Equipment>,
+            Ref<Inventory>,
+            Option<&mut ItemLocks>,
+        ),
+        With<PlayerCharacter>,
+    >,
+    account: Option<Res<Account>>,
+) {
+    let Ok((player_entity, character_info, equipment, inventory, item_locks)) =
+        query_player.get_single_mut()
+    else {
+        return;
+    };
+
+    let store_key = CharacterStoreKey::new(account.as_deref(), &character_info.name);
+    match item_locks {
+        Some(mut item_locks) if item_locks.store_key.as_ref() == Some(&store_key) => {
+            if equipment.is_changed() || inventory.is_changed() {
+                item_locks.update_slots(&equipment, &inventory);
+            }
+        }
+        _ => {
+            let mut item_locks = ItemLocks::load(store_key);
+            item_locks.update_slots(&equipment, &inventory);
+            commands.entity(player_entity).insert(item_locks);
+        }
+    }
+}
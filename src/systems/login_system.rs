@@ -1,18 +1,19 @@
 use bevy::{
-    prelude::{
-        AssetServer, Camera3d, Commands, Entity, EventReader, EventWriter, Query, Res, ResMut, With,
-    },
+    prelude::{AssetServer, Commands, Entity, EventReader, EventWriter, Query, Res, ResMut, With},
     window::{CursorGrabMode, PrimaryWindow, Window},
 };
 use bevy_egui::{egui, EguiContexts};
 
-use rose_data::ZoneId;
 use rose_game_common::messages::client::ClientMessage;
 
 use crate::{
     animation::CameraAnimation,
+    components::MainCamera,
     events::{LoadZoneEvent, LoginEvent, NetworkEvent},
-    resources::{Account, LoginConnection, LoginState, ServerConfiguration, ServerList},
+    localize,
+    resources::{
+        Account, GraphicsQuality, LoginConnection, LoginState, ServerConfiguration, ServerList,
+    },
     systems::{FreeCamera, OrbitCamera},
 };
 
@@ -20,8 +21,10 @@ pub fn login_state_enter_system(
     mut commands: Commands,
     mut loaded_zone: EventWriter<LoadZoneEvent>,
     mut query_window: Query<&mut Window, With<PrimaryWindow>>,
-    query_cameras: Query<Entity, With<Camera3d>>,
+    query_cameras: Query<Entity, With<MainCamera>>,
     asset_server: Res<AssetServer>,
+    server_configuration: Res<ServerConfiguration>,
+    mut graphics_quality: ResMut<GraphicsQuality>,
 ) {
     // Ensure cursor is not locked
     if let Ok(mut window) = query_window.get_single_mut() {
@@ -31,25 +34,36 @@ pub fn login_state_enter_system(
 
     // Reset camera
     for entity in query_cameras.iter() {
-        commands
-            .entity(entity)
+        let mut entity_commands = commands.entity(entity);
+        entity_commands
             .remove::<FreeCamera>()
-            .remove::<OrbitCamera>()
-            .insert(CameraAnimation::repeat(
-                asset_server.load("3DDATA/TITLE/CAMERA01_INTRO01.ZMO"),
+            .remove::<OrbitCamera>();
+
+        if !server_configuration.should_skip_intro() {
+            entity_commands.insert(CameraAnimation::repeat(
+                asset_server.load(&server_configuration.login_camera),
                 None,
             ));
+        }
     }
 
     commands.remove_resource::<Account>();
     commands.insert_resource(LoginState::Input);
 
-    loaded_zone.send(LoadZoneEvent::new(ZoneId::new(4).unwrap()));
+    // The login zone is only used as the background for the intro
+    if !server_configuration.should_skip_intro() {
+        graphics_quality.reduced = true;
+        loaded_zone.send(LoadZoneEvent::new(server_configuration.login_zone));
+    }
 }
 
-pub fn login_state_exit_system(mut commands: Commands) {
+pub fn login_state_exit_system(
+    mut commands: Commands,
+    mut graphics_quality: ResMut<GraphicsQuality>,
+) {
     commands.remove_resource::<LoginConnection>();
     commands.remove_resource::<LoginState>();
+    graphics_quality.reduced = false;
 }
 
 pub fn login_system(
@@ -70,19 +84,19 @@ pub fn login_system(
 
     match *login_state {
         LoginState::WaitServerList => {
-            egui::Window::new("Connecting...")
+            egui::Window::new(localize!("connecting-title"))
                 .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
                 .collapsible(false)
                 .show(egui_context.ctx_mut(), |ui| {
-                    ui.label("Logging in");
+                    ui.label(localize!("connecting-login"));
                 });
         }
         LoginState::JoiningServer => {
-            egui::Window::new("Connecting...")
+            egui::Window::new(localize!("connecting-title"))
                 .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
                 .collapsible(false)
                 .show(egui_context.ctx_mut(), |ui| {
-                    ui.label("Connecting to channel");
+                    ui.label(localize!("connecting-channel"));
                 });
         }
         _ => {}
@@ -94,6 +108,7 @@ pub fn login_event_system(
     mut login_state: ResMut<LoginState>,
     mut login_events: EventReader<LoginEvent>,
     login_connection: Option<Res<LoginConnection>>,
+    mut account: Option<ResMut<Account>>,
     server_configuration: Res<ServerConfiguration>,
     mut network_events: EventWriter<NetworkEvent>,
 ) {
@@ -106,6 +121,12 @@ pub fn login_event_system(
                     commands.insert_resource(Account {
                         username: username.clone(),
                         password: password.clone(),
+                        login_server: format!(
+                            "{}:{}",
+                            server_configuration.ip, server_configuration.port
+                        ),
+                        server_id: None,
+                        channel_id: None,
                     });
 
                     network_events.send(NetworkEvent::ConnectLogin {
@@ -127,6 +148,11 @@ pub fn login_event_system(
                         })
                         .ok();
                 }
+
+                if let Some(account) = account.as_mut() {
+                    account.server_id = Some(server_id);
+                    account.channel_id = Some(channel_id);
+                }
                 *login_state = LoginState::JoiningServer;
             }
         }
@@ -0,0 +1,190 @@
+use bevy::{
+    app::AppExit,
+    math::Vec3Swizzles,
+    prelude::{Commands, EventReader, EventWriter, NextState, Query, Res, ResMut, Time, With},
+    time::{Timer, TimerMode},
+};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_game_common::messages::client::{ClientMessage, LogoutRequest};
+
+use crate::{
+    components::{PlayerCharacter, Position},
+    events::{ChatboxEvent, LogoutEvent},
+    localize,
+    resources::{AppState, GameConnection, Logout, LogoutState, LogoutTarget, WorldConnection},
+};
+
+/// Moving further than this from where the logout started cancels it.
+const LOGOUT_CANCEL_MOVE_DISTANCE: f32 = 100.0;
+
+fn finish_logout(
+    commands: &mut Commands,
+    target: LogoutTarget,
+    world_connection: Option<&WorldConnection>,
+    app_state_next: &mut NextState<AppState>,
+    app_exit_events: &mut EventWriter<AppExit>,
+) {
+    // Keep the logout resource until we have left the game, so the lost game
+    // server connection is not mistaken for a disconnect
+    commands.insert_resource(Logout {
+        target,
+        state: LogoutState::Complete,
+    });
+    commands.remove_resource::<GameConnection>();
+
+    match target {
+        LogoutTarget::CharacterSelect => {
+            // Receiving the character list will move us to character select
+            if let Some(world_connection) = world_connection {
+                world_connection
+                    .client_message_tx
+                    .send(ClientMessage::GetCharacterList)
+                    .ok();
+            } else {
+                app_state_next.set(AppState::GameLogin);
+            }
+        }
+        LogoutTarget::Exit => {
+            app_exit_events.send(AppExit);
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn logout_system(
+    mut commands: Commands,
+    mut egui_context: EguiContexts,
+    mut logout: Option<ResMut<Logout>>,
+    mut logout_events: EventReader<LogoutEvent>,
+    game_connection: Option<Res<GameConnection>>,
+    world_connection: Option<Res<WorldConnection>>,
+    query_player: Query<&Position, With<PlayerCharacter>>,
+    mut chatbox_events: EventWriter<ChatboxEvent>,
+    mut app_state_next: ResMut<NextState<AppState>>,
+    mut app_exit_events: EventWriter<AppExit>,
+    time: Res<Time>,
+) {
+    for event in logout_events.iter() {
+        match *event {
+            LogoutEvent::Start(target) => {
+                if logout.is_some() {
+                    continue;
+                }
+
+                if let Ok(position) = query_player.get_single() {
+                    commands.insert_resource(Logout::new(target, position.position));
+                }
+            }
+            LogoutEvent::Success => {
+                if let Some(logout) = logout.as_ref() {
+                    finish_logout(
+                        &mut commands,
+                        logout.target,
+                        world_connection.as_deref(),
+                        &mut app_state_next,
+                        &mut app_exit_events,
+                    );
+                }
+            }
+            LogoutEvent::Failed { wait_duration } => {
+                // The server decides how long we must wait, e.g. after combat
+                if let (Some(logout), Ok(position)) = (logout.as_mut(), query_player.get_single()) {
+                    logout.state = LogoutState::Countdown {
+                        timer: Timer::new(wait_duration, TimerMode::Once),
+                        start_position: position.position,
+                    };
+                    chatbox_events.send(ChatboxEvent::System(localize!(
+                        "logout-failed",
+                        seconds = wait_duration.as_secs()
+                    )));
+                }
+            }
+            LogoutEvent::ReturnToCharacterSelect => {
+                finish_logout(
+                    &mut commands,
+                    LogoutTarget::CharacterSelect,
+                    world_connection.as_deref(),
+                    &mut app_state_next,
+                    &mut app_exit_events,
+                );
+            }
+        }
+    }
+
+    let Some(logout) = logout.as_mut() else {
+        return;
+    };
+    let logout = &mut **logout;
+
+    if let LogoutState::Countdown {
+        ref mut timer,
+        start_position,
+    } = logout.state
+    {
+        let moved = query_player.get_single().map_or(false, |position| {
+            position.position.xy().distance(start_position.xy()) > LOGOUT_CANCEL_MOVE_DISTANCE
+        });
+
+        if moved {
+            commands.remove_resource::<Logout>();
+            chatbox_events.send(ChatboxEvent::System(localize!("logout-cancelled-moved")));
+            return;
+        }
+
+        if timer.tick(time.delta()).finished() {
+            if let Some(game_connection) = game_connection.as_ref() {
+                game_connection
+                    .client_message_tx
+                    .send(ClientMessage::LogoutRequest(match logout.target {
+                        LogoutTarget::CharacterSelect => LogoutRequest::ReturnToCharacterSelect,
+                        LogoutTarget::Exit => LogoutRequest::Logout,
+                    }))
+                    .ok();
+                logout.state = LogoutState::WaitServer;
+            } else {
+                finish_logout(
+                    &mut commands,
+                    logout.target,
+                    world_connection.as_deref(),
+                    &mut app_state_next,
+                    &mut app_exit_events,
+                );
+                return;
+            }
+        }
+    }
+
+    let mut cancel = false;
+
+    egui::Window::new(localize!("logout-title"))
+        .id(egui::Id::new("logout"))
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .collapsible(false)
+        .resizable(false)
+        .show(egui_context.ctx_mut(), |ui| match logout.state {
+            LogoutState::Countdown { ref timer, .. } => {
+                let seconds = timer.remaining_secs().ceil() as u32;
+                ui.label(match logout.target {
+                    LogoutTarget::CharacterSelect => {
+                        localize!("logout-countdown-character-select", seconds = seconds)
+                    }
+                    LogoutTarget::Exit => localize!("logout-countdown-exit", seconds = seconds),
+                });
+                ui.label(egui::RichText::new(localize!("logout-move-to-cancel")).weak());
+
+                cancel = ui.button(localize!("logout-cancel")).clicked();
+            }
+            LogoutState::WaitServer | LogoutState::Complete => {
+                ui.horizontal(|ui| {
+                    ui.spinner();
+                    ui.label(localize!("logout-waiting"));
+                });
+            }
+        });
+
+    if cancel {
+        commands.remove_resource::<Logout>();
+        chatbox_events.send(ChatboxEvent::System(localize!("logout-cancelled")));
+    }
+}
@@ -0,0 +1,22 @@
+use bevy::prelude::{Res, ResMut};
+
+use crate::resources::{LoginState, MessageOfTheDay, ServerConfiguration};
+
+pub fn message_of_the_day_system(
+    mut message_of_the_day: ResMut<MessageOfTheDay>,
+    server_configuration: Res<ServerConfiguration>,
+    login_state: Option<Res<LoginState>>,
+) {
+    if message_of_the_day.url != server_configuration.motd_url {
+        message_of_the_day.set_url(&server_configuration.motd_url);
+    }
+
+    // Wait until we have connected to the login server
+    if message_of_the_day.needs_fetch()
+        && matches!(login_state.as_deref(), Some(LoginState::ServerSelect))
+    {
+        message_of_the_day.fetch();
+    }
+
+    message_of_the_day.receive();
+}
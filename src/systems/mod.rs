@@ -1,8 +1,15 @@
 mod ability_values_system;
+mod accessibility_system;
+mod achievement_system;
+mod afk_system;
+mod aggro_system;
+mod ammo_system;
 mod animation_effect_system;
 mod animation_sound_system;
 mod auto_login_system;
+mod auto_loot_system;
 mod background_music_system;
+mod character_export_system;
 mod character_model_add_collider_system;
 mod character_model_blink_system;
 mod character_model_system;
@@ -11,6 +18,7 @@ mod clan_system;
 mod client_entity_event_system;
 mod collision_system;
 mod command_system;
+mod conversation_dialog_portrait_system;
 mod conversation_dialog_system;
 mod cooldown_system;
 mod damage_digit_render_system;
@@ -18,19 +26,34 @@ mod debug_inspector_system;
 mod debug_render_collider_system;
 mod debug_render_directional_light_system;
 mod debug_render_skeleton_system;
+mod debug_render_walkability_system;
+mod debug_time_control_system;
+mod defend_target_system;
 mod directional_light_system;
 mod effect_system;
+mod encumbrance_system;
+mod entity_history_system;
+mod event_schedule_system;
 mod facing_direction_system;
+mod footstep_effect_system;
 mod free_camera_system;
 mod game_connection_system;
 mod game_mouse_input_system;
+mod game_reconnect_system;
 mod game_system;
+mod graphics_quality_system;
+mod headless_smoke_test_system;
 mod hit_event_system;
+mod input_context_system;
 mod item_drop_model_system;
+mod item_locks_system;
 mod login_connection_system;
 mod login_system;
+mod logout_system;
+mod message_of_the_day_system;
 mod model_viewer_system;
 mod move_destination_effect_system;
+mod name_tag_combat_healthbar_system;
 mod name_tag_system;
 mod name_tag_update_color_system;
 mod name_tag_update_healthbar_system;
@@ -39,6 +62,7 @@ mod network_thread_system;
 mod npc_idle_sound_system;
 mod npc_model_add_collider_system;
 mod npc_model_system;
+mod npc_quest_marker_system;
 mod orbit_camera_system;
 mod particle_sequence_system;
 mod passive_recovery_system;
@@ -46,65 +70,102 @@ mod pending_damage_system;
 mod pending_skill_effect_system;
 mod personal_store_model_add_collider_system;
 mod personal_store_model_system;
+mod personal_store_signboard_system;
 mod player_command_system;
 mod projectile_system;
 mod quest_trigger_system;
+mod replay_system;
+mod skill_range_indicator_system;
 mod spawn_effect_system;
 mod spawn_projectile_system;
 mod status_effect_system;
 mod systemfunc_event_system;
 mod update_position_system;
 mod use_item_event_system;
+mod user_settings_system;
+mod vehicle_input_system;
 mod vehicle_model_system;
 mod vehicle_sound_system;
 mod visible_status_effects_system;
+mod water_reflection_system;
+mod window_settings_system;
 mod world_connection_system;
 mod world_time_system;
+mod zone_benchmark_system;
 mod zone_time_system;
+mod zone_validation_system;
 mod zone_viewer_system;
 
 pub use ability_values_system::ability_values_system;
+pub use accessibility_system::accessibility_bloom_system;
+pub use achievement_system::{achievement_save_system, achievement_system};
+pub use afk_system::{afk_auto_reply_system, afk_system};
+pub use aggro_system::aggro_system;
+pub use ammo_system::{ammo_system, get_weapon_ammo_index, LOW_AMMO_THRESHOLD};
 pub use animation_effect_system::animation_effect_system;
 pub use animation_sound_system::animation_sound_system;
 pub use auto_login_system::auto_login_system;
+pub use auto_loot_system::auto_loot_system;
 pub use background_music_system::background_music_system;
+pub use character_export_system::character_export_system;
 pub use character_model_add_collider_system::character_model_add_collider_system;
 pub use character_model_blink_system::character_model_blink_system;
 pub use character_model_system::character_model_update_system;
 pub use character_select_system::{
-    character_select_enter_system, character_select_event_system, character_select_exit_system,
-    character_select_input_system, character_select_models_system, character_select_system,
+    character_select_camera_system, character_select_enter_system, character_select_event_system,
+    character_select_exit_system, character_select_input_system, character_select_models_system,
+    character_select_system,
 };
-pub use clan_system::clan_system;
+pub use clan_system::{clan_skill_system, clan_system};
 pub use client_entity_event_system::client_entity_event_system;
 pub use collision_system::{
     collision_height_only_system, collision_player_system, collision_player_system_join_zoin,
 };
 pub use command_system::command_system;
+pub use conversation_dialog_portrait_system::conversation_dialog_portrait_system;
 pub use conversation_dialog_system::conversation_dialog_system;
-pub use cooldown_system::cooldown_system;
+pub use cooldown_system::{cooldown_store_system, cooldown_system};
 pub use damage_digit_render_system::damage_digit_render_system;
 pub use debug_inspector_system::DebugInspectorPlugin;
 pub use debug_render_collider_system::debug_render_collider_system;
 pub use debug_render_directional_light_system::debug_render_directional_light_system;
 pub use debug_render_skeleton_system::debug_render_skeleton_system;
+pub use debug_render_walkability_system::debug_render_walkability_system;
+pub use debug_time_control_system::debug_time_control_system;
+pub use defend_target_system::defend_target_system;
 pub use directional_light_system::directional_light_system;
 pub use effect_system::effect_system;
+pub use encumbrance_system::encumbrance_system;
+pub use entity_history_system::entity_history_system;
+pub use event_schedule_system::event_schedule_system;
 pub use facing_direction_system::facing_direction_system;
+pub use footstep_effect_system::{footstep_effect_system, footstep_effect_update_system};
 pub use free_camera_system::{free_camera_system, FreeCamera};
 pub use game_connection_system::game_connection_system;
 pub use game_mouse_input_system::game_mouse_input_system;
-pub use game_system::{game_state_enter_system, game_zone_change_system};
+pub use game_reconnect_system::{game_reconnect_start_system, game_reconnect_system};
+pub use game_system::{game_state_enter_system, game_state_exit_system, game_zone_change_system};
+pub use graphics_quality_system::{
+    graphics_benchmark_system, graphics_quality_detect_system, graphics_quality_system,
+};
+pub use headless_smoke_test_system::headless_smoke_test_system;
 pub use hit_event_system::hit_event_system;
-pub use item_drop_model_system::{item_drop_model_add_collider_system, item_drop_model_system};
+pub use input_context_system::input_context_system;
+pub use item_drop_model_system::{
+    item_drop_effect_system, item_drop_model_add_collider_system, item_drop_model_system,
+};
+pub use item_locks_system::item_locks_system;
 pub use login_connection_system::login_connection_system;
 pub use login_system::{
     login_event_system, login_state_enter_system, login_state_exit_system, login_system,
 };
+pub use logout_system::logout_system;
+pub use message_of_the_day_system::message_of_the_day_system;
 pub use model_viewer_system::{
     model_viewer_enter_system, model_viewer_exit_system, model_viewer_system,
 };
 pub use move_destination_effect_system::move_destination_effect_system;
+pub use name_tag_combat_healthbar_system::name_tag_combat_healthbar_system;
 pub use name_tag_system::name_tag_system;
 pub use name_tag_update_color_system::name_tag_update_color_system;
 pub use name_tag_update_healthbar_system::name_tag_update_healthbar_system;
@@ -113,6 +174,7 @@ pub use network_thread_system::network_thread_system;
 pub use npc_idle_sound_system::npc_idle_sound_system;
 pub use npc_model_add_collider_system::npc_model_add_collider_system;
 pub use npc_model_system::npc_model_update_system;
+pub use npc_quest_marker_system::npc_quest_marker_system;
 pub use orbit_camera_system::{orbit_camera_system, OrbitCamera};
 pub use particle_sequence_system::particle_sequence_system;
 pub use passive_recovery_system::passive_recovery_system;
@@ -120,19 +182,30 @@ pub use pending_damage_system::pending_damage_system;
 pub use pending_skill_effect_system::pending_skill_effect_system;
 pub use personal_store_model_add_collider_system::personal_store_model_add_collider_system;
 pub use personal_store_model_system::personal_store_model_system;
+pub use personal_store_signboard_system::personal_store_signboard_system;
 pub use player_command_system::player_command_system;
 pub use projectile_system::projectile_system;
 pub use quest_trigger_system::quest_trigger_system;
+pub use replay_system::{replay_playback_system, replay_record_input_system};
+pub use skill_range_indicator_system::skill_range_indicator_system;
 pub use spawn_effect_system::spawn_effect_system;
 pub use spawn_projectile_system::spawn_projectile_system;
 pub use status_effect_system::status_effect_system;
 pub use systemfunc_event_system::system_func_event_system;
-pub use update_position_system::update_position_system;
+pub use update_position_system::{update_networked_position_system, update_position_system};
 pub use use_item_event_system::use_item_event_system;
+pub use user_settings_system::{user_settings_load_system, user_settings_save_system};
+pub use vehicle_input_system::vehicle_input_system;
 pub use vehicle_model_system::vehicle_model_system;
 pub use vehicle_sound_system::vehicle_sound_system;
 pub use visible_status_effects_system::visible_status_effects_system;
+pub use water_reflection_system::water_reflection_system;
+pub use window_settings_system::{
+    window_focus_system, window_monitors_system, window_settings_system,
+};
 pub use world_connection_system::world_connection_system;
 pub use world_time_system::world_time_system;
+pub use zone_benchmark_system::zone_benchmark_system;
 pub use zone_time_system::zone_time_system;
+pub use zone_validation_system::zone_validation_system;
 pub use zone_viewer_system::zone_viewer_enter_system;
@@ -5,8 +5,8 @@ use bevy::{
     math::Vec3,
     pbr::AmbientLight,
     prelude::{
-        Camera3d, Color, Commands, ComputedVisibility, Entity, GlobalTransform, Query, Res, ResMut,
-        Resource, Transform, Visibility, With,
+        Color, Commands, ComputedVisibility, Entity, GlobalTransform, Query, Res, ResMut, Resource,
+        Time, Transform, Visibility, With,
     },
 };
 use bevy_egui::{egui, EguiContexts};
@@ -21,8 +21,10 @@ use rose_game_common::components::{CharacterGender, CharacterInfo, Equipment, Np
 
 use crate::{
     animation::{CameraAnimation, SkeletalAnimation},
-    components::{CharacterModel, ClientEntityName, ModelHeight, NameTagType, NpcModel},
-    resources::{DamageDigitsSpawner, GameData, NameTagSettings},
+    components::{
+        CharacterModel, ClientEntityName, MainCamera, ModelHeight, NameTagType, NpcModel,
+    },
+    resources::{DamageDigitType, DamageDigitsSpawner, GameData, NameTagSettings},
     systems::{FreeCamera, OrbitCamera},
     ui::UiStateDebugWindows,
 };
@@ -42,11 +44,13 @@ pub struct ModelViewerState {
     characters: Vec<Entity>,
     num_characters: usize,
     max_num_characters: usize,
+
+    previous_name_tag_show_all: EnumMap<NameTagType, bool>,
 }
 
 pub fn model_viewer_enter_system(
     mut commands: Commands,
-    query_cameras: Query<Entity, With<Camera3d>>,
+    query_cameras: Query<Entity, With<MainCamera>>,
     game_data: Res<GameData>,
     mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
     mut name_tag_settings: ResMut<NameTagSettings>,
@@ -90,6 +94,8 @@ pub fn model_viewer_enter_system(
         characters: Vec::new(),
         num_characters: 1,
         max_num_characters: 500,
+
+        previous_name_tag_show_all: name_tag_settings.show_all,
     });
 
     // Reset ambient light
@@ -100,6 +106,7 @@ pub fn model_viewer_enter_system(
 
     // Open relevant debug windows
     ui_state_debug_windows.debug_ui_open = true;
+    ui_state_debug_windows.spawn_palette_open = true;
     ui_state_debug_windows.debug_render_open = true;
     ui_state_debug_windows.npc_list_open = true;
     ui_state_debug_windows.item_list_open = true;
@@ -123,8 +130,8 @@ pub fn model_viewer_exit_system(
         commands.entity(*entity).despawn_recursive();
     }
 
-    // Restore default NameTagSettings
-    *name_tag_settings = NameTagSettings::default();
+    // Restore the user's NameTagSettings
+    name_tag_settings.show_all = model_viewer_state.previous_name_tag_show_all;
 }
 
 pub fn model_viewer_system(
@@ -134,9 +141,13 @@ pub fn model_viewer_system(
     query_npc_model: Query<(Entity, &NpcModel)>,
     game_data: Res<GameData>,
     mut egui_context: EguiContexts,
-    damage_digits_spawner: Res<DamageDigitsSpawner>,
-    query_damage_character_model: Query<(&GlobalTransform, &ModelHeight), With<CharacterModel>>,
-    query_damage_npc_model: Query<(&GlobalTransform, &ModelHeight), With<NpcModel>>,
+    mut damage_digits_spawner: ResMut<DamageDigitsSpawner>,
+    query_damage_character_model: Query<
+        (Entity, &GlobalTransform, &ModelHeight),
+        With<CharacterModel>,
+    >,
+    query_damage_npc_model: Query<(Entity, &GlobalTransform, &ModelHeight), With<NpcModel>>,
+    time: Res<Time>,
 ) {
     egui::Window::new("Model Viewer").show(egui_context.ctx_mut(), |ui| {
         let max_num_npcs = ui_state.max_num_npcs;
@@ -150,23 +161,25 @@ pub fn model_viewer_system(
         if ui.button("Spawn Damage").clicked() {
             let mut rng = rand::thread_rng();
 
-            for (global_transform, model_height) in query_damage_character_model.iter() {
+            for (entity, global_transform, model_height) in query_damage_character_model.iter() {
                 damage_digits_spawner.spawn(
                     &mut commands,
-                    global_transform,
-                    model_height.height,
+                    time.elapsed(),
+                    entity,
+                    DamageDigitsSpawner::get_spawn_position(global_transform, model_height.height),
                     rng.gen_range(0..2047),
-                    true,
+                    DamageDigitType::Taken,
                 );
             }
 
-            for (global_transform, model_height) in query_damage_npc_model.iter() {
+            for (entity, global_transform, model_height) in query_damage_npc_model.iter() {
                 damage_digits_spawner.spawn(
                     &mut commands,
-                    global_transform,
-                    model_height.height,
+                    time.elapsed(),
+                    entity,
+                    DamageDigitsSpawner::get_spawn_position(global_transform, model_height.height),
                     rng.gen_range(0..2047),
-                    false,
+                    DamageDigitType::Dealt,
                 );
             }
         }
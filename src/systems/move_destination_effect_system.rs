@@ -57,6 +57,7 @@ pub fn move_destination_effect_system(
                         effect_file_path,
                         true,
                         Some(effect_entity),
+                        1.0,
                     );
                 }
             }
@@ -0,0 +1,143 @@
+use std::time::Duration;
+
+use bevy::prelude::{
+    Children, Commands, Entity, EventReader, Or, Query, Res, Time, Visibility, With, Without,
+};
+
+use rose_game_common::components::CharacterInfo;
+
+use crate::{
+    components::{
+        ClientEntity, ClientEntityType, CombatHealthbar, Dead, NameTagEntity,
+        NameTagHealthbarBackground, NameTagHealthbarForeground, PartyInfo, PlayerCharacter,
+    },
+    events::HitEvent,
+    render::WorldUiRect,
+    resources::{NameTagSettings, SelectedTarget},
+};
+
+// How long the health bar stays visible after the last hit between the monster and the player
+// or their party, before fading out
+const COMBAT_HEALTHBAR_TIMEOUT: Duration = Duration::from_secs(8);
+const COMBAT_HEALTHBAR_FADE_DURATION: f32 = 1.0;
+
+pub fn name_tag_combat_healthbar_system(
+    mut commands: Commands,
+    mut hit_events: EventReader<HitEvent>,
+    query_player: Query<(Entity, Option<&PartyInfo>), With<PlayerCharacter>>,
+    query_characters: Query<(Entity, &CharacterInfo), Without<PlayerCharacter>>,
+    query_client_entity: Query<&ClientEntity>,
+    mut query_combat_healthbar: Query<(
+        Entity,
+        &mut CombatHealthbar,
+        Option<&NameTagEntity>,
+        Option<&Dead>,
+    )>,
+    query_children: Query<&Children>,
+    mut query_healthbar: Query<
+        (&mut Visibility, &mut WorldUiRect),
+        Or<(
+            With<NameTagHealthbarBackground>,
+            With<NameTagHealthbarForeground>,
+        )>,
+    >,
+    name_tag_settings: Res<NameTagSettings>,
+    selected_target: Res<SelectedTarget>,
+    time: Res<Time>,
+) {
+    let now = time.elapsed();
+
+    match query_player.get_single() {
+        Ok((player_entity, player_party)) if name_tag_settings.combat_healthbars => {
+            let mut protected_entities = vec![player_entity];
+            if let Some(player_party) = player_party {
+                for (entity, character_info) in query_characters.iter() {
+                    if player_party
+                        .members
+                        .iter()
+                        .any(|member| member.get_character_id() == character_info.unique_id)
+                    {
+                        protected_entities.push(entity);
+                    }
+                }
+            }
+
+            for event in hit_events.iter() {
+                let monster_entity = if protected_entities.contains(&event.attacker) {
+                    event.defender
+                } else if protected_entities.contains(&event.defender) {
+                    event.attacker
+                } else {
+                    continue;
+                };
+
+                if !query_client_entity
+                    .get(monster_entity)
+                    .map_or(false, |client_entity| {
+                        client_entity.entity_type == ClientEntityType::Monster
+                    })
+                {
+                    continue;
+                }
+
+                if let Ok((_, mut combat_healthbar, _, _)) =
+                    query_combat_healthbar.get_mut(monster_entity)
+                {
+                    combat_healthbar.last_combat_time = now;
+                } else {
+                    commands.entity(monster_entity).insert(CombatHealthbar {
+                        last_combat_time: now,
+                    });
+                }
+            }
+        }
+        _ => hit_events.clear(),
+    }
+
+    for (entity, combat_healthbar, name_tag_entity, dead) in query_combat_healthbar.iter() {
+        let alpha = if dead.is_some() || !name_tag_settings.combat_healthbars {
+            0.0
+        } else {
+            let fade_time = now
+                .saturating_sub(combat_healthbar.last_combat_time)
+                .saturating_sub(COMBAT_HEALTHBAR_TIMEOUT)
+                .as_secs_f32();
+            1.0 - (fade_time / COMBAT_HEALTHBAR_FADE_DURATION).min(1.0)
+        };
+
+        let expired = alpha <= 0.0;
+        if expired {
+            commands.entity(entity).remove::<CombatHealthbar>();
+        }
+
+        let Some(children) =
+            name_tag_entity.and_then(|name_tag_entity| query_children.get(name_tag_entity.0).ok())
+        else {
+            continue;
+        };
+
+        // The selected target always shows its health bar, name_tag_visibility_system
+        // hides it again when it is no longer selected
+        let (target_visibility, target_alpha) = if selected_target.selected == Some(entity) {
+            (Visibility::Inherited, 1.0)
+        } else if expired {
+            (Visibility::Hidden, 1.0)
+        } else {
+            (Visibility::Inherited, alpha)
+        };
+
+        for &child in children.iter() {
+            let Ok((mut visibility, mut rect)) = query_healthbar.get_mut(child) else {
+                continue;
+            };
+
+            if *visibility != target_visibility {
+                *visibility = target_visibility;
+            }
+
+            if rect.color.a() != target_alpha {
+                rect.color.set_a(target_alpha);
+            }
+        }
+    }
+}
@@ -5,8 +5,8 @@ use bevy::{
     ecs::query::WorldQuery,
     prelude::{
         Assets, BuildChildren, Changed, Color, Commands, ComputedVisibility, DespawnRecursiveExt,
-        Entity, EventReader, GlobalTransform, Handle, Image, Local, Query, Res, ResMut, Transform,
-        Vec2, Vec3, Visibility, With, Without,
+        Entity, EventReader, GlobalTransform, Handle, Image, Local, Or, Query, RemovedComponents,
+        Res, ResMut, Transform, Vec2, Vec3, Visibility, With, Without,
     },
     render::{
         render_resource::{Extent3d, TextureDimension, TextureFormat},
@@ -22,11 +22,12 @@ use rose_game_common::components::{Level, Npc, Team};
 
 use crate::{
     components::{
-        ClientEntityName, ModelHeight, NameTag, NameTagEntity, NameTagHealthbarBackground,
-        NameTagHealthbarForeground, NameTagName, NameTagTargetMark, NameTagType, PlayerCharacter,
+        CharacterTitle, ClientEntityName, ModelHeight, NameTag, NameTagEntity,
+        NameTagHealthbarBackground, NameTagHealthbarForeground, NameTagName, NameTagTargetMark,
+        NameTagType, PlayerCharacter,
     },
     events::LoadZoneEvent,
-    render::WorldUiRect,
+    render::{WorldUiGroup, WorldUiRect},
     resources::{GameData, NameTagSettings, UiResources, UiSpriteSheetType},
 };
 
@@ -65,6 +66,7 @@ pub struct PlayerQuery<'w> {
 pub struct NameTagObjectQuery<'w> {
     entity: Entity,
     name: &'w ClientEntityName,
+    title: Option<&'w CharacterTitle>,
     model_height: &'w ModelHeight,
     npc: Option<&'w Npc>,
     level: Option<&'w Level>,
@@ -110,19 +112,45 @@ fn create_pending_nametag(
     name_tag_type: NameTagType,
 ) -> NameTagPendingData {
     let layout_job = match name_tag_type {
-        NameTagType::Character => egui::epaint::text::LayoutJob::single_section(
-            object.name.name.clone(),
-            egui::TextFormat::simple(
-                egui::FontId::proportional(name_tag_settings.font_size[name_tag_type]),
-                if object.team.map_or(false, |team| {
-                    Some(team.id) != player.map(|player| player.team.id)
-                }) {
-                    egui::Color32::RED
-                } else {
-                    egui::Color32::WHITE
-                },
-            ),
-        ),
+        NameTagType::Character => {
+            let name_color = if object.team.map_or(false, |team| {
+                Some(team.id) != player.map(|player| player.team.id)
+            }) {
+                egui::Color32::RED
+            } else {
+                egui::Color32::WHITE
+            };
+
+            if let Some(title) = object.title {
+                let mut name = object.name.name.clone();
+                name.push('\n');
+
+                let mut layout_job = egui::epaint::text::LayoutJob::single_section(
+                    name,
+                    egui::TextFormat::simple(
+                        egui::FontId::proportional(name_tag_settings.font_size[name_tag_type]),
+                        name_color,
+                    ),
+                );
+                layout_job.append(
+                    &title.title,
+                    0.0,
+                    egui::TextFormat::simple(
+                        egui::FontId::proportional(name_tag_settings.font_size[name_tag_type]),
+                        egui::Color32::from_rgb(255, 206, 96),
+                    ),
+                );
+                layout_job
+            } else {
+                egui::epaint::text::LayoutJob::single_section(
+                    object.name.name.clone(),
+                    egui::TextFormat::simple(
+                        egui::FontId::proportional(name_tag_settings.font_size[name_tag_type]),
+                        name_color,
+                    ),
+                )
+            }
+        }
         NameTagType::Monster => egui::epaint::text::LayoutJob::single_section(
             object.name.name.clone(),
             egui::TextFormat::simple(
@@ -189,7 +217,7 @@ fn create_pending_nametag(
     }
 }
 
-fn create_nametag_data(
+pub fn create_nametag_data(
     window_entity: Entity,
     egui_context: &mut EguiContexts,
     egui_managed_textures: &bevy_egui::EguiManagedTextures,
@@ -386,7 +414,12 @@ pub fn name_tag_system(
     mut commands: Commands,
     mut name_tag_cache: Local<NameTagCache>,
     query_add: Query<NameTagObjectQuery, Without<NameTagEntity>>,
-    query_changed: Query<(Entity, Option<&NameTagEntity>), Changed<ClientEntityName>>,
+    query_changed: Query<
+        (Entity, Option<&NameTagEntity>),
+        Or<(Changed<ClientEntityName>, Changed<CharacterTitle>)>,
+    >,
+    mut removed_titles: RemovedComponents<CharacterTitle>,
+    query_name_tag_entity: Query<&NameTagEntity>,
     query_player: Query<PlayerQuery, With<PlayerCharacter>>,
     query_nametags: Query<(Entity, &NameTagEntity)>,
     query_window: Query<Entity, With<PrimaryWindow>>,
@@ -420,7 +453,13 @@ pub fn name_tag_system(
         return;
     }
 
-    for (entity, name_tag_entity) in query_changed.iter() {
+    let changed_entities = query_changed.iter().chain(
+        removed_titles
+            .iter()
+            .map(|entity| (entity, query_name_tag_entity.get(entity).ok())),
+    );
+
+    for (entity, name_tag_entity) in changed_entities {
         // Despawn previous name tag
         if let Some(name_tag_entity) = name_tag_entity {
             commands.entity(entity).remove::<NameTagEntity>();
@@ -449,8 +488,13 @@ pub fn name_tag_system(
             NameTagType::Character
         };
 
-        let name_tag_data = if let Some(name_tag_data) = name_tag_cache.cache.get(&object.name.name)
-        {
+        let cache_key = if let Some(title) = object.title {
+            format!("{}\n{}", object.name.name, title.title)
+        } else {
+            object.name.name.clone()
+        };
+
+        let name_tag_data = if let Some(name_tag_data) = name_tag_cache.cache.get(&cache_key) {
             name_tag_data
         } else if let Some(pending_name_tag_data) = name_tag_cache.pending.remove(&object.entity) {
             if let Some(name_tag_data) = create_nametag_data(
@@ -462,8 +506,8 @@ pub fn name_tag_system(
             ) {
                 name_tag_cache
                     .cache
-                    .insert(object.name.name.clone(), name_tag_data);
-                name_tag_cache.cache.get(&object.name.name).unwrap()
+                    .insert(cache_key.clone(), name_tag_data);
+                name_tag_cache.cache.get(&cache_key).unwrap()
             } else {
                 // Try again next frame
                 continue;
@@ -497,6 +541,7 @@ pub fn name_tag_system(
                 Transform::from_translation(Vec3::new(0.0, object.model_height.height, 0.0)),
                 GlobalTransform::default(),
                 NoFrustumCulling,
+                WorldUiGroup::default(),
             ))
             .id();
 
@@ -1,12 +1,12 @@
 use bevy::{
     ecs::query::WorldQuery,
-    prelude::{Changed, Children, Color, Or, Parent, Query, With},
+    prelude::{Changed, Children, Color, Entity, Or, Parent, Query, RemovedComponents, With},
 };
 
 use rose_game_common::components::{Level, Team};
 
 use crate::{
-    components::{NameTag, NameTagName, NameTagType, PlayerCharacter},
+    components::{Aggro, NameTag, NameTagName, NameTagType, PlayerCharacter},
     render::WorldUiRect,
     systems::name_tag_system::get_monster_name_tag_color,
 };
@@ -17,20 +17,39 @@ pub struct PlayerQuery<'w> {
     team: &'w Team,
 }
 
+const AGGRO_PLAYER_COLOR: Color = Color::rgb(1.0, 0.3, 1.0);
+const AGGRO_PARTY_COLOR: Color = Color::rgb(0.7, 0.5, 1.0);
+
 pub fn name_tag_update_color_system(
-    query_player: Query<PlayerQuery, (With<PlayerCharacter>, Or<(Changed<Level>, Changed<Team>)>)>,
+    query_player: Query<PlayerQuery, With<PlayerCharacter>>,
+    query_player_changed: Query<(), (With<PlayerCharacter>, Or<(Changed<Level>, Changed<Team>)>)>,
     query_nametags: Query<(&Parent, &NameTag, &Children)>,
     query_level: Query<&Level>,
     query_team: Query<&Team>,
+    query_aggro: Query<&Aggro>,
+    query_aggro_changed: Query<Entity, Changed<Aggro>>,
+    mut removed_aggro: RemovedComponents<Aggro>,
     mut query_name_rects: Query<&mut WorldUiRect, With<NameTagName>>,
 ) {
     let player = if let Ok(player) = query_player.get_single() {
         player
     } else {
+        removed_aggro.clear();
         return;
     };
 
+    let update_all = !query_player_changed.is_empty();
+    let mut update_entities: Vec<Entity> = query_aggro_changed.iter().collect();
+    update_entities.extend(removed_aggro.iter());
+    if !update_all && update_entities.is_empty() {
+        return;
+    }
+
     for (parent, nametag, children) in query_nametags.iter() {
+        if !update_all && !update_entities.contains(&parent.get()) {
+            continue;
+        }
+
         let color = match nametag.name_tag_type {
             NameTagType::Npc => continue,
             NameTagType::Character => {
@@ -43,20 +62,24 @@ pub fn name_tag_update_color_system(
                     Color::WHITE
                 }
             }
-            NameTagType::Monster => {
-                let color = get_monster_name_tag_color(
-                    Some(player.level),
-                    query_level.get(parent.get()).ok(),
-                    query_team.get(parent.get()).ok(),
-                )
-                .to_array();
+            NameTagType::Monster => match query_aggro.get(parent.get()) {
+                Ok(aggro) if aggro.is_targeting_player => AGGRO_PLAYER_COLOR,
+                Ok(_) => AGGRO_PARTY_COLOR,
+                Err(_) => {
+                    let color = get_monster_name_tag_color(
+                        Some(player.level),
+                        query_level.get(parent.get()).ok(),
+                        query_team.get(parent.get()).ok(),
+                    )
+                    .to_array();
 
-                Color::rgb_linear(
-                    color[0] as f32 / 255.0,
-                    color[1] as f32 / 255.0,
-                    color[2] as f32 / 255.0,
-                )
-            }
+                    Color::rgb_linear(
+                        color[0] as f32 / 255.0,
+                        color[1] as f32 / 255.0,
+                        color[2] as f32 / 255.0,
+                    )
+                }
+            },
         };
 
         for &child_entity in children.iter() {
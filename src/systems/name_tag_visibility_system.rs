@@ -1,17 +1,34 @@
 use bevy::{
     ecs::query::WorldQuery,
-    prelude::{Children, Entity, Local, Or, Query, Res, ResMut, Visibility, With},
+    math::Vec3Swizzles,
+    prelude::{
+        Camera, Children, ComputedVisibility, Entity, GlobalTransform, Local, Or, Query, Rect, Res,
+        ResMut, Time, Vec2, Visibility, With,
+    },
 };
+use bevy_rapier3d::prelude::{CollisionGroups, QueryFilter, RapierContext};
 use rose_game_common::components::Npc;
 
 use crate::{
     components::{
-        Dead, NameTag, NameTagEntity, NameTagHealthbarBackground, NameTagHealthbarForeground,
-        NameTagTargetMark,
+        Dead, MainCamera, NameTag, NameTagEntity, NameTagHealthbarBackground,
+        NameTagHealthbarForeground, NameTagName, NameTagTargetMark, COLLISION_FILTER_MOVEABLE,
+        COLLISION_GROUP_ZONE_OBJECT, COLLISION_GROUP_ZONE_TERRAIN,
     },
+    render::{WorldUiGroup, WorldUiRect},
     resources::{NameTagSettings, SelectedTarget},
 };
 
+// Name tags shrink between these distances from the camera
+const NAME_TAG_SCALE_NEAR_DISTANCE: f32 = 10.0;
+const NAME_TAG_SCALE_FAR_DISTANCE: f32 = 60.0;
+const NAME_TAG_MIN_SCALE: f32 = 0.6;
+
+// Name tags behind zone geometry are hidden, those overlapped by a closer name tag are faded
+const NAME_TAG_OCCLUDED_ALPHA: f32 = 0.0;
+const NAME_TAG_DECLUTTERED_ALPHA: f32 = 0.3;
+const NAME_TAG_FADE_SPEED: f32 = 4.0;
+
 #[derive(Default)]
 pub struct NameTagVisibility {
     pub hover: Option<Entity>,
@@ -40,7 +57,21 @@ pub fn name_tag_visibility_system(
         )>,
     >,
     query_npc_dead: Query<&Dead, With<Npc>>,
+    mut query_name_tag_group: Query<
+        (
+            Entity,
+            &GlobalTransform,
+            &ComputedVisibility,
+            &Children,
+            &mut WorldUiGroup,
+        ),
+        With<NameTag>,
+    >,
+    query_name_tag_name: Query<&WorldUiRect, With<NameTagName>>,
+    query_camera: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
     name_tag_settings: Res<NameTagSettings>,
+    rapier_context: Res<RapierContext>,
+    time: Res<Time>,
 ) {
     if selected_target
         .selected
@@ -134,4 +165,96 @@ pub fn name_tag_visibility_system(
             }
         }
     }
+
+    let Ok((camera, camera_transform)) = query_camera.get_single() else {
+        return;
+    };
+    let Some(viewport_size) = camera.physical_viewport_size() else {
+        return;
+    };
+    let camera_position = camera_transform.translation();
+
+    // Closest name tags take priority when decluttering
+    let mut name_tags: Vec<_> = query_name_tag_group
+        .iter_mut()
+        .filter(|(_, _, computed_visibility, _, _)| computed_visibility.is_visible_in_hierarchy())
+        .map(|(entity, global_transform, _, children, group)| {
+            let distance = global_transform.translation().distance(camera_position);
+            (entity, global_transform, children, group, distance)
+        })
+        .collect();
+    name_tags.sort_by(|a, b| a.4.total_cmp(&b.4));
+
+    let mut visible_screen_rects: Vec<Rect> = Vec::with_capacity(name_tags.len());
+    for (entity, global_transform, children, mut group, distance) in name_tags {
+        let scale = 1.0
+            - (1.0 - NAME_TAG_MIN_SCALE)
+                * ((distance - NAME_TAG_SCALE_NEAR_DISTANCE)
+                    / (NAME_TAG_SCALE_FAR_DISTANCE - NAME_TAG_SCALE_NEAR_DISTANCE))
+                    .clamp(0.0, 1.0);
+        let is_pinned = state.hover == Some(entity) || state.selected == Some(entity);
+
+        let position = global_transform.translation();
+        let is_occluded = !is_pinned
+            && (position - camera_position)
+                .try_normalize()
+                .and_then(|direction| {
+                    rapier_context.cast_ray(
+                        camera_position,
+                        direction,
+                        distance,
+                        false,
+                        QueryFilter::new().groups(CollisionGroups::new(
+                            COLLISION_FILTER_MOVEABLE,
+                            COLLISION_GROUP_ZONE_OBJECT | COLLISION_GROUP_ZONE_TERRAIN,
+                        )),
+                    )
+                })
+                .is_some();
+
+        let screen_rect = camera
+            .world_to_ndc(camera_transform, position)
+            .filter(|ndc| ndc.z >= 0.0 && ndc.z <= 1.0)
+            .and_then(|ndc| {
+                let screen_position = (ndc.xy() + Vec2::ONE) / 2.0 * viewport_size.as_vec2();
+                children
+                    .iter()
+                    .filter_map(|child| query_name_tag_name.get(*child).ok())
+                    .map(|rect| {
+                        let min = screen_position + rect.screen_offset * scale;
+                        Rect::from_corners(min, min + rect.screen_size * scale)
+                    })
+                    .reduce(|bounds, rect| bounds.union(rect))
+            });
+
+        let is_decluttered = !is_pinned
+            && screen_rect.map_or(false, |screen_rect| {
+                visible_screen_rects
+                    .iter()
+                    .any(|other| !other.intersect(screen_rect).is_empty())
+            });
+
+        let target_alpha = if is_pinned {
+            1.0
+        } else if is_occluded {
+            NAME_TAG_OCCLUDED_ALPHA
+        } else if is_decluttered {
+            NAME_TAG_DECLUTTERED_ALPHA
+        } else {
+            1.0
+        };
+
+        if !is_occluded && !is_decluttered {
+            if let Some(screen_rect) = screen_rect {
+                visible_screen_rects.push(screen_rect);
+            }
+        }
+
+        let max_change = NAME_TAG_FADE_SPEED * time.delta_seconds();
+        let alpha = group.alpha + (target_alpha - group.alpha).clamp(-max_change, max_change);
+        if group.alpha != alpha || group.scale != scale {
+            group.alpha = alpha;
+            group.scale = scale;
+        }
+    }
 }
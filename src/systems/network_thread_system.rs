@@ -1,23 +1,44 @@
-use bevy::prelude::{Commands, EventReader, Res};
+use bevy::prelude::{Commands, EventReader, Res, ResMut};
 
 use rose_game_common::{
     data::Password,
     messages::{client::ClientMessage, server::ServerMessage},
 };
+use rose_network_common::Packet;
 
 use crate::{
     events::NetworkEvent,
-    protocol::irose,
+    protocol::ProtocolClientArgs,
     resources::{
-        GameConnection, LoginConnection, NetworkThread, NetworkThreadMessage, WorldConnection,
+        GameConnection, LoginConnection, NetworkThread, NetworkThreadMessage, PacketInspector,
+        ProtocolRegistry, ReplayConnection, ReplayPlayback, ReplayRecorder, WorldConnection,
     },
 };
 
+fn create_replay_channel(
+    replay_playback: &mut Option<ResMut<ReplayPlayback>>,
+    connection: ReplayConnection,
+) -> Option<tokio::sync::mpsc::UnboundedReceiver<Packet>> {
+    let replay_playback = replay_playback.as_mut()?;
+    let (playback_tx, playback_rx) = tokio::sync::mpsc::unbounded_channel::<Packet>();
+    replay_playback.connections.insert(connection, playback_tx);
+    Some(playback_rx)
+}
+
 pub fn network_thread_system(
     mut commands: Commands,
     network_thread: Res<NetworkThread>,
+    protocol_registry: Res<ProtocolRegistry>,
     mut network_events: EventReader<NetworkEvent>,
+    packet_inspector: Res<PacketInspector>,
+    replay_recorder: Option<Res<ReplayRecorder>>,
+    mut replay_playback: Option<ResMut<ReplayPlayback>>,
 ) {
+    let Some(protocol) = protocol_registry.selected() else {
+        return;
+    };
+    let replay_recorder = replay_recorder.map(|replay_recorder| replay_recorder.clone());
+
     for event in network_events.iter() {
         match *event {
             NetworkEvent::ConnectLogin { ref ip, port } => {
@@ -29,13 +50,19 @@ pub fn network_thread_system(
 
                 network_thread
                     .control_tx
-                    .send(NetworkThreadMessage::RunProtocolClient(Box::new(
-                        irose::LoginClient::new(
+                    .send(NetworkThreadMessage::RunProtocolClient(
+                        protocol.create_login_client(ProtocolClientArgs {
                             server_address,
                             client_message_rx,
                             server_message_tx,
-                        ),
-                    )))
+                            replay_recorder: replay_recorder.clone(),
+                            replay_playback_rx: create_replay_channel(
+                                &mut replay_playback,
+                                ReplayConnection::Login,
+                            ),
+                            packet_inspector: packet_inspector.clone(),
+                        }),
+                    ))
                     .ok();
 
                 commands
@@ -56,14 +83,22 @@ pub fn network_thread_system(
 
                 network_thread
                     .control_tx
-                    .send(NetworkThreadMessage::RunProtocolClient(Box::new(
-                        irose::WorldClient::new(
-                            server_address,
+                    .send(NetworkThreadMessage::RunProtocolClient(
+                        protocol.create_world_client(
+                            ProtocolClientArgs {
+                                server_address,
+                                client_message_rx,
+                                server_message_tx,
+                                replay_recorder: replay_recorder.clone(),
+                                replay_playback_rx: create_replay_channel(
+                                    &mut replay_playback,
+                                    ReplayConnection::World,
+                                ),
+                                packet_inspector: packet_inspector.clone(),
+                            },
                             packet_codec_seed,
-                            client_message_rx,
-                            server_message_tx,
                         ),
-                    )))
+                    ))
                     .ok();
 
                 commands.insert_resource(WorldConnection::new(
@@ -88,14 +123,22 @@ pub fn network_thread_system(
 
                 network_thread
                     .control_tx
-                    .send(NetworkThreadMessage::RunProtocolClient(Box::new(
-                        irose::GameClient::new(
-                            server_address,
+                    .send(NetworkThreadMessage::RunProtocolClient(
+                        protocol.create_game_client(
+                            ProtocolClientArgs {
+                                server_address,
+                                client_message_rx,
+                                server_message_tx,
+                                replay_recorder: replay_recorder.clone(),
+                                replay_playback_rx: create_replay_channel(
+                                    &mut replay_playback,
+                                    ReplayConnection::Game,
+                                ),
+                                packet_inspector: packet_inspector.clone(),
+                            },
                             packet_codec_seed,
-                            client_message_rx,
-                            server_message_tx,
                         ),
-                    )))
+                    ))
                     .ok();
 
                 commands.insert_resource(GameConnection::new(
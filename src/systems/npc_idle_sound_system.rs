@@ -10,7 +10,7 @@ use crate::{
     animation::SkeletalAnimation,
     audio::{SoundRadius, SpatialSound},
     components::{Command, SoundCategory},
-    resources::{GameData, SoundCache, SoundSettings},
+    resources::{GameData, ReplayRng, SoundCache, SoundSettings},
 };
 
 #[derive(Component, Default)]
@@ -33,7 +33,7 @@ pub fn npc_idle_sound_system(
     sound_settings: Res<SoundSettings>,
     sound_cache: Res<SoundCache>,
 ) {
-    let mut rng = rand::thread_rng();
+    let mut rng = ReplayRng;
     let gain = sound_settings.gain(SoundCategory::NpcSounds);
 
     for (entity, npc, skeletal_animation, command, global_transform, idle_sound_state) in
@@ -0,0 +1,316 @@
+use std::{collections::HashMap, time::Duration};
+
+use bevy::{
+    prelude::{
+        Assets, BuildChildren, Color, Commands, ComputedVisibility, DespawnRecursiveExt, Entity,
+        GlobalTransform, Handle, Image, Local, Query, Res, ResMut, Transform, Vec2, Vec3,
+        Visibility,
+    },
+    render::{
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+        view::NoFrustumCulling,
+    },
+    time::Time,
+};
+
+use rose_data::{QuestDatabase, QuestTrigger, QuestTriggerHash};
+use rose_file_readers::{ConFile, QsdReward, VfsPathBuf};
+use rose_game_common::components::Npc;
+
+use crate::{
+    components::{ModelHeight, NpcQuestMarker, NpcQuestMarkerType},
+    render::WorldUiRect,
+    resources::{GameData, VfsResource},
+    scripting::{
+        lua4::Lua4Function, quest_check_conditions, ScriptFunctionContext, ScriptFunctionResources,
+    },
+};
+
+const MARKER_UPDATE_INTERVAL: Duration = Duration::from_secs(1);
+const MARKER_ORDER: u8 = 3;
+const MARKER_SCREEN_OFFSET_Y: f32 = 48.0;
+const MARKER_AVAILABLE_COLOR: Color = Color::rgb(1.0, 0.85, 0.1);
+const MARKER_COMPLETE_COLOR: Color = Color::rgb(0.3, 1.0, 0.3);
+
+const GLYPH_WIDTH: usize = 5;
+const GLYPH_HEIGHT: usize = 8;
+const GLYPH_SCALE: usize = 3;
+const GLYPH_CELL_WIDTH: usize = (GLYPH_WIDTH + 2) * GLYPH_SCALE;
+const GLYPH_CELL_HEIGHT: usize = (GLYPH_HEIGHT + 2) * GLYPH_SCALE;
+const MARKER_IMAGE_SIZE: usize = 64;
+
+const GLYPH_AVAILABLE: [&str; GLYPH_HEIGHT] = [
+    ".###.", ".###.", ".###.", ".###.", "..#..", ".....", ".###.", ".###.",
+];
+const GLYPH_COMPLETE: [&str; GLYPH_HEIGHT] = [
+    ".###.", "#...#", "....#", "...#.", "..#..", ".....", "..#..", "..#..",
+];
+
+#[derive(Default)]
+pub struct NpcQuestMarkerState {
+    conversation_triggers: HashMap<usize, Vec<(QuestTriggerHash, NpcQuestMarkerType)>>,
+    last_update_time: Option<Duration>,
+    marker_image: Option<Handle<Image>>,
+}
+
+fn get_glyph_pixel(glyph: &[&str; GLYPH_HEIGHT], x: isize, y: isize) -> bool {
+    let glyph_x = x.div_euclid(GLYPH_SCALE as isize) - 1;
+    let glyph_y = y.div_euclid(GLYPH_SCALE as isize) - 1;
+    if glyph_x < 0 || glyph_y < 0 {
+        return false;
+    }
+
+    glyph
+        .get(glyph_y as usize)
+        .and_then(|row| row.as_bytes().get(glyph_x as usize))
+        .map_or(false, |&pixel| pixel == b'#')
+}
+
+fn create_marker_image() -> Image {
+    let mut data = vec![0; MARKER_IMAGE_SIZE * MARKER_IMAGE_SIZE * 4];
+
+    for (cell_index, glyph) in [GLYPH_AVAILABLE, GLYPH_COMPLETE].iter().enumerate() {
+        for y in 0..GLYPH_CELL_HEIGHT {
+            for x in 0..GLYPH_CELL_WIDTH {
+                let (x, y) = (x as isize, y as isize);
+                let pixel = if get_glyph_pixel(glyph, x, y) {
+                    Some(255)
+                } else if (-2..=2).any(|offset_y| {
+                    (-2..=2).any(|offset_x| get_glyph_pixel(glyph, x + offset_x, y + offset_y))
+                }) {
+                    // Black outline so the marker is readable against bright backgrounds
+                    Some(0)
+                } else {
+                    None
+                };
+
+                if let Some(value) = pixel {
+                    let offset = (y as usize * MARKER_IMAGE_SIZE
+                        + cell_index * GLYPH_CELL_WIDTH
+                        + x as usize)
+                        * 4;
+                    data[offset..offset + 4].copy_from_slice(&[value, value, value, 255]);
+                }
+            }
+        }
+    }
+
+    Image::new(
+        Extent3d {
+            width: MARKER_IMAGE_SIZE as u32,
+            height: MARKER_IMAGE_SIZE as u32,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8Unorm,
+    )
+}
+
+fn create_marker_rect(image: &Handle<Image>, marker_type: NpcQuestMarkerType) -> WorldUiRect {
+    let (cell_index, color) = match marker_type {
+        NpcQuestMarkerType::Available => (0, MARKER_AVAILABLE_COLOR),
+        NpcQuestMarkerType::Complete => (1, MARKER_COMPLETE_COLOR),
+    };
+    let size = Vec2::new(GLYPH_CELL_WIDTH as f32, GLYPH_CELL_HEIGHT as f32);
+    let uv_min = Vec2::new((cell_index * GLYPH_CELL_WIDTH) as f32, 0.0) / MARKER_IMAGE_SIZE as f32;
+
+    WorldUiRect {
+        image: image.clone_weak(),
+        screen_offset: Vec2::new(-size.x / 2.0, MARKER_SCREEN_OFFSET_Y),
+        screen_size: size,
+        uv_min,
+        uv_max: uv_min + size / MARKER_IMAGE_SIZE as f32,
+        color,
+        order: MARKER_ORDER,
+    }
+}
+
+fn get_quest_trigger_marker_type(
+    quests: &QuestDatabase,
+    quest_trigger: &QuestTrigger,
+) -> Option<NpcQuestMarkerType> {
+    // Follow the chain of triggers run on success to find what the trigger does to the quest list
+    let mut quest_trigger = Some(quest_trigger);
+    let mut depth = 0;
+
+    while let Some(current_trigger) = quest_trigger.take() {
+        for reward in current_trigger.rewards.iter() {
+            match reward {
+                QsdReward::AddQuest { .. } => return Some(NpcQuestMarkerType::Available),
+                QsdReward::RemoveSelectedQuest | QsdReward::ChangeSelectedQuest { .. } => {
+                    return Some(NpcQuestMarkerType::Complete)
+                }
+                QsdReward::Trigger { name } => {
+                    quest_trigger = quests.get_trigger_by_name(name);
+                }
+                _ => {}
+            }
+        }
+
+        depth += 1;
+        if depth >= 8 {
+            break;
+        }
+    }
+
+    None
+}
+
+fn collect_constant_strings<'a>(function: &'a Lua4Function, strings: &mut Vec<&'a str>) {
+    strings.extend(
+        function
+            .constant_strings
+            .iter()
+            .map(|string| string.as_str()),
+    );
+
+    for child_function in function.constant_functions.iter() {
+        collect_constant_strings(child_function, strings);
+    }
+}
+
+fn load_conversation_triggers(
+    vfs_resource: &VfsResource,
+    game_data: &GameData,
+    conversation_index: usize,
+) -> Vec<(QuestTriggerHash, NpcQuestMarkerType)> {
+    let Some(con_file) = game_data
+        .npcs
+        .find_conversation(conversation_index)
+        .and_then(|conversation_data| {
+            vfs_resource
+                .vfs
+                .read_file::<ConFile, _>(&VfsPathBuf::new(&conversation_data.filename))
+                .ok()
+        })
+    else {
+        return Vec::new();
+    };
+
+    let Ok(lua_function) = Lua4Function::from_bytes(&con_file.script_binary) else {
+        return Vec::new();
+    };
+
+    // The conversation script checks quest triggers by name, so any string constant
+    // which names a quest trigger is a quest this NPC can give or complete
+    let mut strings = Vec::new();
+    collect_constant_strings(&lua_function, &mut strings);
+
+    let mut triggers = Vec::new();
+    for name in strings {
+        let Some(quest_trigger) = game_data.quests.get_trigger_by_name(name) else {
+            continue;
+        };
+
+        if let Some(marker_type) = get_quest_trigger_marker_type(&game_data.quests, quest_trigger) {
+            let trigger_hash: QuestTriggerHash = name.into();
+            if !triggers.contains(&(trigger_hash, marker_type)) {
+                triggers.push((trigger_hash, marker_type));
+            }
+        }
+    }
+
+    triggers
+}
+
+pub fn npc_quest_marker_system(
+    mut commands: Commands,
+    mut state: Local<NpcQuestMarkerState>,
+    query_npcs: Query<(Entity, &Npc, Option<&ModelHeight>, Option<&NpcQuestMarker>)>,
+    mut script_context: ScriptFunctionContext,
+    script_resources: ScriptFunctionResources,
+    vfs_resource: Res<VfsResource>,
+    mut images: ResMut<Assets<Image>>,
+    time: Res<Time>,
+) {
+    let now = time.elapsed();
+    if state.last_update_time.map_or(false, |last_update_time| {
+        now.saturating_sub(last_update_time) < MARKER_UPDATE_INTERVAL
+    }) {
+        return;
+    }
+    state.last_update_time = Some(now);
+
+    if script_context.query_quest.get_single().is_err() {
+        return;
+    }
+
+    let marker_image = state
+        .marker_image
+        .get_or_insert_with(|| images.add(create_marker_image()))
+        .clone();
+
+    for (entity, npc, model_height, current_marker) in query_npcs.iter() {
+        let marker_type = if npc.quest_index == 0 {
+            None
+        } else {
+            let triggers = state
+                .conversation_triggers
+                .entry(npc.quest_index as usize)
+                .or_insert_with(|| {
+                    load_conversation_triggers(
+                        &vfs_resource,
+                        &script_resources.game_data,
+                        npc.quest_index as usize,
+                    )
+                });
+
+            let mut check_triggers = |check_marker_type| {
+                triggers.iter().any(|&(trigger_hash, marker_type)| {
+                    marker_type == check_marker_type
+                        && matches!(
+                            quest_check_conditions(
+                                &script_resources,
+                                &mut script_context,
+                                trigger_hash
+                            ),
+                            Ok(true)
+                        )
+                })
+            };
+
+            if check_triggers(NpcQuestMarkerType::Complete) {
+                Some(NpcQuestMarkerType::Complete)
+            } else if check_triggers(NpcQuestMarkerType::Available) {
+                Some(NpcQuestMarkerType::Available)
+            } else {
+                None
+            }
+        };
+
+        if current_marker.map(|marker| marker.marker_type) == marker_type {
+            continue;
+        }
+
+        if let Some(current_marker) = current_marker {
+            commands
+                .entity(current_marker.rect_entity)
+                .despawn_recursive();
+            commands.entity(entity).remove::<NpcQuestMarker>();
+        }
+
+        let (Some(marker_type), Some(model_height)) = (marker_type, model_height) else {
+            continue;
+        };
+
+        let rect_entity = commands
+            .spawn((
+                create_marker_rect(&marker_image, marker_type),
+                Transform::from_translation(Vec3::new(0.0, model_height.height, 0.0)),
+                GlobalTransform::default(),
+                Visibility::default(),
+                ComputedVisibility::default(),
+                NoFrustumCulling,
+            ))
+            .id();
+
+        commands
+            .entity(entity)
+            .insert(NpcQuestMarker {
+                marker_type,
+                rect_entity,
+            })
+            .add_child(rect_entity);
+    }
+}
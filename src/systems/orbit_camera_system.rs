@@ -192,7 +192,7 @@ pub fn orbit_camera_system(
         ExpSmoothingParams {
             smoothness: 1.0,
             output_offset_scale: 1.0,
-            delta_time_seconds: time.delta_seconds(),
+            delta_time_seconds: time.raw_delta_seconds(),
         },
     );
 
@@ -203,8 +203,8 @@ pub fn orbit_camera_system(
         orbit_camera.rig.driver_mut::<Arm>().offset.z = arm_distance;
     }
 
-    // Update camera
-    let calculated_transform = orbit_camera.rig.update(time.delta_seconds());
+    // Update camera, uses real time so the camera still moves when debug time is paused or scaled
+    let calculated_transform = orbit_camera.rig.update(time.raw_delta_seconds());
     camera_transform.translation = calculated_transform.position;
     camera_transform.rotation = calculated_transform.rotation;
 }
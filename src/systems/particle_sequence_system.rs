@@ -11,8 +11,15 @@ use rose_file_readers::{PtlKeyframeData, PtlUpdateCoords};
 use crate::{
     components::{ActiveParticle, ParticleSequence},
     render::ParticleRenderData,
+    resources::{AccessibilitySettings, GraphicsQuality, ReplayRng},
 };
 
+// When reducing flashing, a particle takes at least a third of a second to fully change colour
+const REDUCED_FLASHING_MAX_COLOR_CHANGE_PER_SECOND: f32 = 3.0;
+
+// Matches the value used by decode_blend_factor for BlendFactor::One
+const PARTICLE_BLEND_FACTOR_ONE: u8 = 2;
+
 fn rng_gen_range<R: Rng>(rng: &mut R, range: &RangeInclusive<f32>) -> f32 {
     // This function is intentionally written this way to match the
     // original ROSE engine code to behave the same when fmin > fmax
@@ -283,14 +290,17 @@ fn apply_keyframes<R: Rng>(
 
 pub fn particle_sequence_system(
     time: Res<Time>,
+    graphics_quality: Res<GraphicsQuality>,
+    accessibility_settings: Res<AccessibilitySettings>,
     mut query: Query<(
         &GlobalTransform,
         &mut ParticleSequence,
         &mut ParticleRenderData,
     )>,
 ) {
-    let mut rng = rand::thread_rng();
+    let mut rng = ReplayRng;
     let delta_time = time.delta_seconds();
+    let particle_density = graphics_quality.effective_settings().particle_density;
 
     for (global_transform, mut particle_sequence, mut particle_render_data) in query.iter_mut() {
         if particle_sequence.start_delay > 0.0 {
@@ -326,7 +336,9 @@ pub fn particle_sequence_system(
             .particles
             .retain(|particle| particle.age < particle.life);
 
-        // Spawn any new particles
+        // Spawn any new particles, particle density limits how many can be alive at once
+        let max_alive_particles =
+            ((particle_sequence.num_particles as f32 * particle_density).ceil() as usize).max(1);
         if !particle_sequence.finished {
             particle_sequence.emit_counter +=
                 delta_time * rng_gen_range(&mut rng, &particle_sequence.emit_rate);
@@ -342,7 +354,7 @@ pub fn particle_sequence_system(
 
             // Spawn new particles
             while particle_sequence.emit_counter > 1.0
-                && particle_sequence.particles.len() < particle_sequence.num_particles as usize
+                && particle_sequence.particles.len() < max_alive_particles
             {
                 let mut position = Vec3::new(
                     rng_gen_range(&mut rng, &particle_sequence.emit_radius_x),
@@ -383,6 +395,8 @@ pub fn particle_sequence_system(
 
                 // Apply initial keyframes
                 apply_keyframes(&mut rng, &mut particle_sequence, particle_index);
+                let particle = &mut particle_sequence.particles[particle_index];
+                particle.render_color = particle.color;
 
                 particle_sequence.num_emitted += 1;
                 particle_sequence.emit_counter -= 1.0;
@@ -397,18 +411,37 @@ pub fn particle_sequence_system(
             }
             PtlUpdateCoords::Local => (*global_transform).into(),
         };
-        let texture_atlas_total =
-            particle_sequence.texture_atlas_cols * particle_sequence.texture_atlas_rows;
-        let texture_atlas_uv_w = 1.0 / particle_sequence.texture_atlas_cols as f32;
+        let texture_atlas_cols = particle_sequence.texture_atlas_cols;
+        let texture_atlas_total = texture_atlas_cols * particle_sequence.texture_atlas_rows;
+        let texture_atlas_uv_w = 1.0 / texture_atlas_cols as f32;
         let texture_atlas_uv_h = 1.0 / particle_sequence.texture_atlas_rows as f32;
 
+        // Additive particles brighten the scene so reduce their colour, otherwise fade them out
+        let intensity = particle_sequence.intensity;
+        let color_scale = if particle_render_data.dst_blend_factor == PARTICLE_BLEND_FACTOR_ONE {
+            Vec4::new(intensity, intensity, intensity, 1.0)
+        } else {
+            Vec4::new(1.0, 1.0, 1.0, intensity)
+        };
+        let max_color_change = REDUCED_FLASHING_MAX_COLOR_CHANGE_PER_SECOND * delta_time;
+
         particle_render_data.clear();
-        for particle in particle_sequence.particles.iter() {
+        for particle in particle_sequence.particles.iter_mut() {
+            if accessibility_settings.reduce_flashing {
+                let color_change = particle.color - particle.render_color;
+                particle.render_color += color_change.clamp(
+                    Vec4::splat(-max_color_change),
+                    Vec4::splat(max_color_change),
+                );
+            } else {
+                particle.render_color = particle.color;
+            }
+
             // TODO: Do we need to support negative texture index ?
             let texture_atlas_index =
                 particle.texture_atlas_index.abs() as u32 % texture_atlas_total;
-            let texture_atlas_x = texture_atlas_index % particle_sequence.texture_atlas_cols;
-            let texture_atlas_y = texture_atlas_index / particle_sequence.texture_atlas_cols;
+            let texture_atlas_x = texture_atlas_index % texture_atlas_cols;
+            let texture_atlas_y = texture_atlas_index / texture_atlas_cols;
             let texture_atlas_uv_x = texture_atlas_x as f32 * texture_atlas_uv_w;
             let texture_atlas_uv_y = texture_atlas_y as f32 * texture_atlas_uv_h;
 
@@ -422,7 +455,7 @@ pub fn particle_sequence_system(
                 ),
                 particle.rotation.to_radians(),
                 particle.size / 100.0,
-                particle.color,
+                particle.render_color * color_scale,
                 Vec4::new(
                     texture_atlas_uv_x,
                     texture_atlas_uv_y,
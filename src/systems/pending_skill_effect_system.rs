@@ -1,19 +1,26 @@
 use bevy::{
     ecs::query::WorldQuery,
-    prelude::{Entity, EventReader, EventWriter, Query, Res, Time},
+    prelude::{
+        Commands, Entity, EventReader, EventWriter, GlobalTransform, Query, Res, ResMut, Time, Vec3,
+    },
+    render::mesh::skinning::SkinnedMesh,
 };
 
 use rose_data::{AbilityType, AnimationEventFlags, SkillData, StatusEffectType};
 use rose_game_common::components::{
-    AbilityValues, HealthPoints, ManaPoints, MoveSpeed, StatusEffects,
+    AbilityValues, HealthPoints, ManaPoints, MoveSpeed, StatusEffects, Team,
 };
 
 use crate::{
     animation::AnimationFrameEvent,
     bundles::ability_values_get_value,
-    components::{PendingSkillEffectList, PendingSkillTargetList},
+    components::{
+        CharacterModel, DummyBoneOffset, ModelHeight, PendingSkillEffectList,
+        PendingSkillTargetList, StatusEffectSource, StatusEffectSources,
+    },
     events::HitEvent,
-    resources::GameData,
+    localize,
+    resources::{DamageDigitType, DamageDigitsSpawner, GameData, StatusEffectTextType},
 };
 
 // After 10 seconds, apply skill effects regardless
@@ -25,11 +32,18 @@ const MAX_SKILL_EFFECT_AGE: f32 = 10.0;
 pub struct SkillEffectTarget<'w> {
     entity: Entity,
     ability_values: &'w AbilityValues,
+    global_transform: &'w GlobalTransform,
+    model_height: Option<&'w ModelHeight>,
     health_points: &'w mut HealthPoints,
     mana_points: Option<&'w mut ManaPoints>,
     move_speed: &'w MoveSpeed,
     pending_skill_effect_list: &'w mut PendingSkillEffectList,
     status_effects: &'w mut StatusEffects,
+    status_effect_sources: Option<&'w mut StatusEffectSources>,
+    team: Option<&'w Team>,
+    character_model: Option<&'w CharacterModel>,
+    skinned_mesh: Option<&'w SkinnedMesh>,
+    dummy_bone_offset: Option<&'w DummyBoneOffset>,
 }
 
 fn apply_skill_effect(
@@ -39,7 +53,9 @@ fn apply_skill_effect(
     target: &mut SkillEffectTargetItem,
     caster_intelligence: i32,
     effect_success: [bool; 2],
-) {
+) -> u32 {
+    let mut healed_hp = 0;
+
     for (skill_effect_index, success) in effect_success.iter().enumerate() {
         if !success {
             continue;
@@ -98,6 +114,15 @@ fn apply_skill_effect(
                 time.last_update().unwrap() + skill_data.status_effect_duration,
                 adjust_value,
             );
+
+            if let Some(status_effect_sources) = target.status_effect_sources.as_mut() {
+                status_effect_sources.sources[status_effect_data.status_effect_type] =
+                    Some(StatusEffectSource {
+                        status_effect_id: status_effect_data.id,
+                        skill_id: skill_data.id,
+                        duration: skill_data.status_effect_duration,
+                    });
+            }
         }
 
         let add_ability = skill_data
@@ -107,6 +132,7 @@ fn apply_skill_effect(
         if let Some(add_ability) = add_ability {
             match add_ability.ability_type {
                 AbilityType::Health => {
+                    let previous_hp = target.health_points.hp;
                     target.health_points.hp = i32::min(
                         target.ability_values.get_max_health(),
                         target.health_points.hp
@@ -118,6 +144,7 @@ fn apply_skill_effect(
                                     target.health_points.hp,
                                 ),
                     );
+                    healed_hp += (target.health_points.hp - previous_hp).max(0) as u32;
                 }
                 AbilityType::Mana => {
                     if let Some(mana_points) = target.mana_points.as_mut() {
@@ -138,13 +165,56 @@ fn apply_skill_effect(
             }
         }
     }
+
+    healed_hp
+}
+
+fn spawn_status_effect_texts(
+    skill_data: &SkillData,
+    game_data: &GameData,
+    time: &Time,
+    damage_digits_spawner: &mut DamageDigitsSpawner,
+    position: Vec3,
+    effect_success: [bool; 2],
+    is_buff: bool,
+) {
+    for (status_effect_id, success) in skill_data.status_effects.iter().zip(effect_success) {
+        let Some(status_effect_data) = status_effect_id.as_ref().and_then(|status_effect_id| {
+            game_data
+                .status_effects
+                .get_status_effect(*status_effect_id)
+        }) else {
+            continue;
+        };
+
+        let (text, text_type) = if !success {
+            (
+                localize!("status-effect-resisted"),
+                StatusEffectTextType::Resisted,
+            )
+        } else {
+            (
+                localize!("status-effect-applied", name = status_effect_data.name),
+                if is_buff {
+                    StatusEffectTextType::Buff
+                } else {
+                    StatusEffectTextType::Debuff
+                },
+            )
+        };
+
+        damage_digits_spawner.spawn_status_effect_text(time.elapsed(), position, text, text_type);
+    }
 }
 
 pub fn pending_skill_effect_system(
-    mut query_caster: Query<(Entity, &mut PendingSkillTargetList)>,
+    mut commands: Commands,
+    mut query_caster: Query<(Entity, &mut PendingSkillTargetList, Option<&Team>)>,
     mut query_target: Query<SkillEffectTarget>,
+    query_global_transform: Query<&GlobalTransform>,
     mut animation_frame_events: EventReader<AnimationFrameEvent>,
     mut hit_events: EventWriter<HitEvent>,
+    mut damage_digits_spawner: ResMut<DamageDigitsSpawner>,
     game_data: Res<GameData>,
     time: Res<Time>,
 ) {
@@ -157,7 +227,7 @@ pub fn pending_skill_effect_system(
             continue;
         }
 
-        if let Ok((caster_entity, mut caster_pending_skill_target_list)) =
+        if let Ok((caster_entity, mut caster_pending_skill_target_list, caster_team)) =
             query_caster.get_mut(event.entity)
         {
             // Find all our skill targets
@@ -186,7 +256,7 @@ pub fn pending_skill_effect_system(
                                 pending_skill_effect.skill_id,
                             ));
 
-                            apply_skill_effect(
+                            let healed_hp = apply_skill_effect(
                                 skill_data,
                                 &game_data,
                                 &time,
@@ -194,6 +264,50 @@ pub fn pending_skill_effect_system(
                                 pending_skill_effect.caster_intelligence,
                                 pending_skill_effect.effect_success,
                             );
+
+                            // Only character skeletons have a known head dummy bone
+                            let head_transform = target
+                                .character_model
+                                .and(target.skinned_mesh.zip(target.dummy_bone_offset))
+                                .and_then(|(skinned_mesh, dummy_bone_offset)| {
+                                    skinned_mesh.joints.get(
+                                        dummy_bone_offset.index + DummyBoneOffset::CHARACTER_HEAD,
+                                    )
+                                })
+                                .and_then(|joint| query_global_transform.get(*joint).ok());
+                            let spawn_position = DamageDigitsSpawner::get_anchored_spawn_position(
+                                target.global_transform,
+                                target.model_height,
+                                head_transform,
+                            );
+
+                            if healed_hp > 0 {
+                                damage_digits_spawner.spawn(
+                                    &mut commands,
+                                    time.elapsed(),
+                                    target.entity,
+                                    spawn_position,
+                                    healed_hp,
+                                    DamageDigitType::Heal,
+                                );
+                            }
+
+                            // Skills cast by a member of the target's team are buffs
+                            let is_buff = match (caster_team, target.team) {
+                                (Some(caster_team), Some(target_team)) => {
+                                    caster_team.id == target_team.id
+                                }
+                                _ => true,
+                            };
+                            spawn_status_effect_texts(
+                                skill_data,
+                                &game_data,
+                                &time,
+                                &mut damage_digits_spawner,
+                                spawn_position,
+                                pending_skill_effect.effect_success,
+                                is_buff,
+                            );
                         }
                     }
                 }
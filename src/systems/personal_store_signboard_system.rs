@@ -0,0 +1,203 @@
+use arrayvec::ArrayVec;
+use bevy::{
+    prelude::{
+        Assets, BuildChildren, Changed, Color, Commands, ComputedVisibility, DespawnRecursiveExt,
+        Entity, GlobalTransform, Image, Local, Query, RemovedComponents, Res, ResMut, Transform,
+        Vec2, Vec3, Visibility, With,
+    },
+    render::{texture::DEFAULT_IMAGE_HANDLE, view::NoFrustumCulling},
+    utils::HashMap,
+    window::PrimaryWindow,
+};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    components::{ModelHeight, NameTagType, PersonalStore, PersonalStoreSignboard},
+    render::WorldUiRect,
+    resources::NameTagSettings,
+};
+
+use super::name_tag_system::{create_nametag_data, NameTagPendingData};
+
+const ORDER_SIGNBOARD_BACKGROUND: u8 = 0;
+const ORDER_SIGNBOARD_TEXT: u8 = 2;
+
+// Leave space for the owner's name tag beneath the signboard
+const SIGNBOARD_OFFSET_Y: f32 = 40.0;
+const SIGNBOARD_PADDING: f32 = 4.0;
+
+const SIGNBOARD_TEXT_COLOR: egui::Color32 = egui::Color32::from_rgb(255, 228, 122);
+const SIGNBOARD_BACKGROUND_COLOR: Color = Color::rgba(0.0, 0.0, 0.0, 0.6);
+
+fn create_pending_signboard(
+    egui_context: &mut EguiContexts,
+    name_tag_settings: &NameTagSettings,
+    title: &str,
+) -> NameTagPendingData {
+    let galley = egui_context.ctx_mut().fonts(|fonts| {
+        fonts.layout_no_wrap(
+            title.to_string(),
+            egui::FontId::proportional(name_tag_settings.font_size[NameTagType::Character]),
+            SIGNBOARD_TEXT_COLOR,
+        )
+    });
+
+    let [r, g, b, _] = SIGNBOARD_TEXT_COLOR.to_array().map(|c| c as f32 / 255.0);
+    let mut colors = ArrayVec::new();
+    colors.push(Color::rgb_linear(r, g, b));
+
+    NameTagPendingData {
+        galley,
+        colors,
+        name_tag_type: NameTagType::Character,
+    }
+}
+
+pub fn personal_store_signboard_system(
+    mut commands: Commands,
+    mut pending_signboards: Local<HashMap<Entity, NameTagPendingData>>,
+    query_personal_store: Query<(
+        Entity,
+        &PersonalStore,
+        Option<&PersonalStoreSignboard>,
+        Option<&ModelHeight>,
+    )>,
+    query_changed_model_height: Query<
+        (&ModelHeight, &PersonalStoreSignboard),
+        Changed<ModelHeight>,
+    >,
+    query_signboard: Query<&PersonalStoreSignboard>,
+    mut query_signboard_transform: Query<&mut Transform>,
+    mut removed_personal_stores: RemovedComponents<PersonalStore>,
+    query_window: Query<Entity, With<PrimaryWindow>>,
+    egui_managed_textures: Res<bevy_egui::EguiManagedTextures>,
+    mut egui_context: EguiContexts,
+    mut images: ResMut<Assets<Image>>,
+    name_tag_settings: Res<NameTagSettings>,
+) {
+    let Ok(window_entity) = query_window.get_single() else {
+        return;
+    };
+    let pixels_per_point = egui_context.ctx_mut().pixels_per_point();
+
+    // Remove the signboard when a store closes, if the entity was despawned then the
+    // signboard was despawned with it
+    for entity in removed_personal_stores.iter() {
+        pending_signboards.remove(&entity);
+
+        if let Ok(signboard) = query_signboard.get(entity) {
+            commands.entity(signboard.entity).despawn_recursive();
+            commands.entity(entity).remove::<PersonalStoreSignboard>();
+        }
+    }
+
+    for (entity, personal_store, signboard, model_height) in query_personal_store.iter() {
+        if let Some(signboard) = signboard {
+            if signboard.title == personal_store.title {
+                continue;
+            }
+
+            // Title has changed, replace the signboard
+            commands.entity(signboard.entity).despawn_recursive();
+            commands.entity(entity).remove::<PersonalStoreSignboard>();
+            pending_signboards.remove(&entity);
+            continue;
+        }
+
+        if personal_store.title.is_empty() {
+            continue;
+        }
+
+        // Create egui text and wait until next frame to read the font texture, as is done
+        // for name tags
+        let Some(pending_signboard) = pending_signboards.remove(&entity) else {
+            pending_signboards.insert(
+                entity,
+                create_pending_signboard(
+                    &mut egui_context,
+                    &name_tag_settings,
+                    &personal_store.title,
+                ),
+            );
+            continue;
+        };
+
+        let Some(signboard_data) = create_nametag_data(
+            window_entity,
+            &mut egui_context,
+            &egui_managed_textures,
+            &mut images,
+            pending_signboard,
+        ) else {
+            // Try again next frame
+            continue;
+        };
+
+        let signboard_entity = commands
+            .spawn((
+                Visibility::Inherited,
+                ComputedVisibility::default(),
+                Transform::from_translation(Vec3::new(
+                    0.0,
+                    model_height.map_or(0.0, |model_height| model_height.height),
+                    0.0,
+                )),
+                GlobalTransform::default(),
+                NoFrustumCulling,
+            ))
+            .set_parent(entity)
+            .id();
+
+        for text_rect in signboard_data.rects.iter() {
+            let offset = Vec2::new(0.0, SIGNBOARD_OFFSET_Y * pixels_per_point);
+            let padding = Vec2::splat(SIGNBOARD_PADDING * pixels_per_point);
+
+            commands
+                .spawn((
+                    WorldUiRect {
+                        image: DEFAULT_IMAGE_HANDLE.typed(),
+                        screen_offset: text_rect.screen_offset + offset - padding,
+                        screen_size: text_rect.screen_size + padding * 2.0,
+                        uv_min: Vec2::ZERO,
+                        uv_max: Vec2::ONE,
+                        color: SIGNBOARD_BACKGROUND_COLOR,
+                        order: ORDER_SIGNBOARD_BACKGROUND,
+                    },
+                    Transform::default(),
+                    GlobalTransform::default(),
+                    Visibility::default(),
+                    ComputedVisibility::default(),
+                    NoFrustumCulling,
+                ))
+                .set_parent(signboard_entity);
+
+            commands
+                .spawn((
+                    WorldUiRect {
+                        screen_offset: text_rect.screen_offset + offset,
+                        order: ORDER_SIGNBOARD_TEXT,
+                        ..text_rect.clone()
+                    },
+                    Transform::default(),
+                    GlobalTransform::default(),
+                    Visibility::default(),
+                    ComputedVisibility::default(),
+                    NoFrustumCulling,
+                ))
+                .set_parent(signboard_entity);
+        }
+
+        commands.entity(entity).insert(PersonalStoreSignboard {
+            title: personal_store.title.clone(),
+            entity: signboard_entity,
+            image: signboard_data.image,
+        });
+    }
+
+    // The model height changes once the store booth model has loaded
+    for (model_height, signboard) in query_changed_model_height.iter() {
+        if let Ok(mut transform) = query_signboard_transform.get_mut(signboard.entity) {
+            transform.translation.y = model_height.height;
+        }
+    }
+}
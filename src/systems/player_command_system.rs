@@ -3,7 +3,9 @@ use std::time::Duration;
 use bevy::{
     ecs::query::WorldQuery,
     math::Vec3Swizzles,
-    prelude::{Entity, EventReader, EventWriter, Query, Res, With},
+    prelude::{
+        Commands, Entity, EventReader, EventWriter, Events, Query, Res, ResMut, With, World,
+    },
 };
 
 use rose_data::{
@@ -11,17 +13,23 @@ use rose_data::{
     SkillTargetFilter, SkillType, VehiclePartIndex,
 };
 use rose_game_common::{
-    components::{CharacterInfo, Hotbar, HotbarSlot, Inventory, ItemDrop, SkillList, Team},
+    components::{
+        CharacterInfo, Hotbar, HotbarSlot, Inventory, ItemDrop, SkillList, StatusEffects, Team,
+    },
     messages::client::ClientMessage,
 };
 
 use crate::{
     components::{
-        Bank, Clan, ClientEntity, ClientEntityType, Command, ConsumableCooldownGroup, Cooldowns,
-        PartyInfo, PlayerCharacter, Position,
+        Bank, Clan, ClientEntity, ClientEntityType, Command, CommandAttack, CommandCastSkill,
+        CommandCastSkillTarget, ConsumableCooldownGroup, Cooldowns, ItemLocks, PartyInfo,
+        PlayerCharacter, Position,
+    },
+    events::{ChatboxEvent, MessageBoxEvent, PlayerCommandEvent, RepairEvent},
+    localize,
+    resources::{
+        AfkState, DefendTarget, GameConnection, GameData, QueuedSkill, SelectedTarget, SkillQueue,
     },
-    events::{ChatboxEvent, PlayerCommandEvent},
-    resources::{GameConnection, GameData, SelectedTarget},
 };
 
 #[derive(WorldQuery)]
@@ -32,11 +40,14 @@ pub struct PlayerQuery<'w> {
     entity: Entity,
 
     bank: Option<&'w Bank>,
+    command: &'w Command,
     cooldowns: &'w mut Cooldowns,
     hotbar: &'w mut Hotbar,
     inventory: &'w Inventory,
+    item_locks: Option<&'w mut ItemLocks>,
     position: &'w Position,
     skill_list: &'w SkillList,
+    status_effects: &'w StatusEffects,
     team: &'w Team,
     clan: Option<&'w Clan>,
     party_info: Option<&'w PartyInfo>,
@@ -49,6 +60,7 @@ pub struct SkillTargetQuery<'w> {
     character_info: Option<&'w CharacterInfo>,
     client_entity: &'w ClientEntity,
     command: &'w Command,
+    position: &'w Position,
     team: &'w Team,
 }
 
@@ -61,9 +73,14 @@ pub fn player_command_system(
     query_team: Query<(&ClientEntity, &Team)>,
     query_skill_target: Query<SkillTargetQuery>,
     mut chatbox_events: EventWriter<ChatboxEvent>,
+    mut message_box_events: EventWriter<MessageBoxEvent>,
+    mut repair_events: EventWriter<RepairEvent>,
     game_connection: Option<Res<GameConnection>>,
     game_data: Res<GameData>,
-    selected_target: Res<SelectedTarget>,
+    mut selected_target: ResMut<SelectedTarget>,
+    mut defend_target: ResMut<DefendTarget>,
+    mut afk_state: ResMut<AfkState>,
+    mut skill_queue: ResMut<SkillQueue>,
 ) {
     let query_player_result = query_player.get_single_mut();
     if query_player_result.is_err() {
@@ -71,9 +88,51 @@ pub fn player_command_system(
     }
     let mut player = query_player_result.unwrap();
 
-    for event in player_command_events.iter() {
-        let mut event = event.clone();
+    // Fire the queued skill once we have finished casting and are within range of the target
+    let mut queued_skill_event = None;
+    if let Some(queued_skill) = skill_queue.queued {
+        let is_casting = matches!(player.command, Command::CastSkill(_));
+        let target_position = queued_skill
+            .target_entity
+            .map(|target_entity| query_skill_target.get(target_entity).map(|x| x.position));
+
+        if matches!(target_position, Some(Err(_))) {
+            // Target no longer exists
+            skill_queue.queued = None;
+        } else if !is_casting && !player.cooldowns.has_global_cooldown() {
+            let cast_range = player
+                .skill_list
+                .get_skill(queued_skill.skill_slot)
+                .and_then(|skill_id| game_data.skills.get_skill(skill_id))
+                .map_or(0, |skill_data| skill_data.cast_range);
+            let in_range = match target_position {
+                Some(Ok(target_position)) => {
+                    cast_range == 0
+                        || player
+                            .position
+                            .position
+                            .xy()
+                            .distance(target_position.position.xy())
+                            <= cast_range as f32
+                }
+                _ => true,
+            };
+
+            if in_range {
+                skill_queue.queued = None;
+                queued_skill_event = Some((
+                    PlayerCommandEvent::UseSkill(queued_skill.skill_slot),
+                    Some(queued_skill),
+                ));
+            }
+        }
+    }
 
+    for (mut event, queued_skill) in queued_skill_event.into_iter().chain(
+        player_command_events
+            .iter()
+            .map(|event| (event.clone(), None)),
+    ) {
         if let PlayerCommandEvent::UseHotbar(page, index) = event {
             if let Some(hotbar_slot) = player
                 .hotbar
@@ -112,8 +171,33 @@ pub fn player_command_system(
                         }
                     };
 
-                    if has_skill_cooldown || player.cooldowns.has_global_cooldown() {
-                        chatbox_events.send(ChatboxEvent::System("Waiting...".to_string()));
+                    let skill_target_entity = if let Some(queued_skill) = queued_skill {
+                        queued_skill.target_entity
+                    } else {
+                        selected_target.selected
+                    };
+
+                    if has_skill_cooldown {
+                        chatbox_events.send(ChatboxEvent::System(localize!("chat-waiting")));
+                        continue;
+                    }
+
+                    if matches!(
+                        skill_data.skill_type,
+                        SkillType::BasicAction | SkillType::Emote
+                    ) {
+                        if player.cooldowns.has_global_cooldown() {
+                            chatbox_events.send(ChatboxEvent::System(localize!("chat-waiting")));
+                            continue;
+                        }
+                    } else if player.cooldowns.has_global_cooldown()
+                        || matches!(player.command, Command::CastSkill(_))
+                    {
+                        // Queue the skill to be cast when we are ready
+                        skill_queue.queued = Some(QueuedSkill {
+                            skill_slot,
+                            target_entity: skill_target_entity,
+                        });
                         continue;
                     }
 
@@ -296,9 +380,9 @@ pub fn player_command_system(
                         | SkillType::EnforceBullet
                         | SkillType::FireBullet
                         | SkillType::AreaTarget => {
-                            let target_entity_id = {
+                            let skill_target = {
                                 if let Ok(target) = query_skill_target
-                                    .get(selected_target.selected.unwrap_or(player.entity))
+                                    .get(skill_target_entity.unwrap_or(player.entity))
                                 {
                                     let target_is_alive = !target.command.is_die();
                                     let target_is_caster = target.entity == player.entity;
@@ -390,7 +474,11 @@ pub fn player_command_system(
                                     };
 
                                     if target_is_valid {
-                                        Some(target.client_entity.id)
+                                        Some((
+                                            target.entity,
+                                            target.client_entity.id,
+                                            target.position,
+                                        ))
                                     } else {
                                         None
                                     }
@@ -399,8 +487,38 @@ pub fn player_command_system(
                                 }
                             };
 
-                            if let Some(target_entity_id) = target_entity_id {
-                                if let Some(game_connection) = game_connection.as_ref() {
+                            if let Some((target_entity, target_entity_id, target_position)) =
+                                skill_target
+                            {
+                                let target_distance = player
+                                    .position
+                                    .position
+                                    .xy()
+                                    .distance(target_position.position.xy());
+
+                                if target_entity != player.entity
+                                    && skill_data.cast_range > 0
+                                    && target_distance > skill_data.cast_range as f32
+                                {
+                                    // Walk into range of the target, the skill will be cast
+                                    // once we are close enough
+                                    skill_queue.queued = Some(QueuedSkill {
+                                        skill_slot,
+                                        target_entity: Some(target_entity),
+                                    });
+
+                                    if let Some(game_connection) = game_connection.as_ref() {
+                                        game_connection
+                                            .client_message_tx
+                                            .send(ClientMessage::Move {
+                                                target_entity_id: Some(target_entity_id),
+                                                x: target_position.x,
+                                                y: target_position.y,
+                                                z: target_position.z as u16,
+                                            })
+                                            .ok();
+                                    }
+                                } else if let Some(game_connection) = game_connection.as_ref() {
                                     game_connection
                                         .client_message_tx
                                         .send(ClientMessage::CastSkillTargetEntity {
@@ -411,7 +529,7 @@ pub fn player_command_system(
                                 }
                             } else {
                                 chatbox_events
-                                    .send(ChatboxEvent::System("Invalid target".to_string()));
+                                    .send(ChatboxEvent::System(localize!("chat-invalid-target")));
                                 continue;
                             }
                         }
@@ -421,7 +539,10 @@ pub fn player_command_system(
                     }
                 }
             }
-            PlayerCommandEvent::UseItem(item_slot) => {
+            PlayerCommandEvent::UseItem(item_slot)
+            | PlayerCommandEvent::UseItemConfirmed(item_slot) => {
+                let confirmed = matches!(event, PlayerCommandEvent::UseItemConfirmed(_));
+
                 if let Some(item) = player.inventory.get_item(item_slot) {
                     if item.get_item_type() == ItemType::Consumable {
                         let consumable_item_data =
@@ -441,10 +562,12 @@ pub fn player_command_system(
                                 None => todo!(),
                             };
 
-                            // TODO: If item is a repair item, we need to handle this client side
+                            // Repair items are used on an item chosen in the repair dialog
                             if matches!(consumable_item_data.item_data.class, ItemClass::RepairTool)
                             {
-                                log::info!("TODO: Implement using ItemClass::RepairTool");
+                                repair_events.send(RepairEvent::OpenRepairUsingItem {
+                                    use_item_slot: item_slot,
+                                });
                                 continue;
                             }
 
@@ -466,10 +589,57 @@ pub fn player_command_system(
                                 })
                                 .is_some()
                             {
-                                chatbox_events.send(ChatboxEvent::System("Waiting...".to_string()));
+                                chatbox_events
+                                    .send(ChatboxEvent::System(localize!("chat-waiting")));
                                 continue;
                             }
 
+                            // Check if the item would replace a stronger active status effect
+                            if !confirmed {
+                                if let Some(active_status_effect_name) = consumable_item_data
+                                    .apply_status_effect
+                                    .and_then(|(status_effect_id, value)| {
+                                        let status_effect_data = game_data
+                                            .status_effects
+                                            .get_status_effect(status_effect_id)?;
+                                        let active_status_effect = player.status_effects.active
+                                            [status_effect_data.status_effect_type]
+                                            .as_ref()?;
+
+                                        if active_status_effect.value <= value {
+                                            return None;
+                                        }
+
+                                        Some(
+                                            game_data
+                                                .status_effects
+                                                .get_status_effect(active_status_effect.id)
+                                                .map_or(status_effect_data.name, |data| data.name),
+                                        )
+                                    })
+                                {
+                                    message_box_events.send(MessageBoxEvent::Show {
+                                        message: format!(
+                                            "Using {} will replace your stronger {} effect. Use anyway?",
+                                            consumable_item_data.item_data.name,
+                                            active_status_effect_name
+                                        ),
+                                        modal: false,
+                                        ok: Some(Box::new(move |commands: &mut Commands| {
+                                            commands.add(move |world: &mut World| {
+                                                world
+                                                    .resource_mut::<Events<PlayerCommandEvent>>()
+                                                    .send(PlayerCommandEvent::UseItemConfirmed(
+                                                        item_slot,
+                                                    ));
+                                            });
+                                        })),
+                                        cancel: Some(Box::new(|_| {})),
+                                    });
+                                    continue;
+                                }
+                            }
+
                             // Check if consumable requires a target
                             if matches!(consumable_item_data.item_data.class, ItemClass::MagicItem)
                             {
@@ -492,9 +662,9 @@ pub fn player_command_system(
                                             // TODO: Check target team
                                             use_item_target = Some(target_client_entity.id);
                                         } else {
-                                            chatbox_events.send(ChatboxEvent::System(
-                                                "Invalid target".to_string(),
-                                            ));
+                                            chatbox_events.send(ChatboxEvent::System(localize!(
+                                                "chat-invalid-target"
+                                            )));
                                             continue;
                                         }
                                     }
@@ -665,6 +835,16 @@ pub fn player_command_system(
             }
             PlayerCommandEvent::DropItem(item_slot) => {
                 if let Some(item) = player.inventory.get_item(item_slot) {
+                    if player
+                        .item_locks
+                        .as_ref()
+                        .map_or(false, |item_locks| item_locks.is_locked(item_slot, item))
+                    {
+                        chatbox_events
+                            .send(ChatboxEvent::System(localize!("chat-drop-locked-item")));
+                        continue;
+                    }
+
                     // TODO: if item.get_quantity() > 1, show number input dialog for quantity
                     if let Some(game_connection) = game_connection.as_ref() {
                         game_connection
@@ -677,6 +857,18 @@ pub fn player_command_system(
                     }
                 }
             }
+            PlayerCommandEvent::SetItemLocked(item_slot, locked) => {
+                if let (Some(item), Some(item_locks)) = (
+                    player.inventory.get_item(item_slot),
+                    player.item_locks.as_mut(),
+                ) {
+                    if locked {
+                        item_locks.lock(item_slot, item);
+                    } else {
+                        item_locks.unlock(item_slot);
+                    }
+                }
+            }
             PlayerCommandEvent::DropMoney(quantity) => {
                 if let Some(game_connection) = game_connection.as_ref() {
                     game_connection
@@ -686,6 +878,8 @@ pub fn player_command_system(
                 }
             }
             PlayerCommandEvent::Attack(entity) => {
+                skill_queue.queued = None;
+
                 if let Ok((target_client_entity, target_team)) = query_team.get(entity) {
                     if target_team.id != Team::DEFAULT_NPC_TEAM_ID
                         && target_team.id != player.team.id
@@ -701,7 +895,69 @@ pub fn player_command_system(
                     }
                 }
             }
+            PlayerCommandEvent::Assist(entity) => {
+                let Some(assist_entity) = entity.or(selected_target.selected) else {
+                    chatbox_events.send(ChatboxEvent::System(localize!("chat-assist-no-target")));
+                    continue;
+                };
+
+                let assist_target_entity =
+                    query_skill_target
+                        .get(assist_entity)
+                        .ok()
+                        .and_then(|assist| match assist.command {
+                            Command::Attack(CommandAttack { target }) => Some(*target),
+                            Command::CastSkill(CommandCastSkill {
+                                skill_target: Some(CommandCastSkillTarget::Entity(target)),
+                                ..
+                            }) => Some(*target),
+                            _ => None,
+                        });
+
+                if let Some(assist_target_entity) = assist_target_entity {
+                    selected_target.selected = Some(assist_target_entity);
+                } else {
+                    chatbox_events.send(ChatboxEvent::System(localize!(
+                        "chat-assist-target-has-no-target"
+                    )));
+                }
+            }
+            PlayerCommandEvent::Defend(entity) => {
+                if let Some(defend_entity) = entity.or(selected_target.selected) {
+                    defend_target.entity = Some(defend_entity);
+
+                    if let Ok(defend) = query_skill_target.get(defend_entity) {
+                        if let Some(character_info) = defend.character_info {
+                            chatbox_events.send(ChatboxEvent::System(localize!(
+                                "chat-defend-started",
+                                name = character_info.name.as_str()
+                            )));
+                        }
+                    }
+                } else {
+                    chatbox_events.send(ChatboxEvent::System(localize!("chat-defend-no-target")));
+                }
+            }
+            PlayerCommandEvent::StopDefend => {
+                if defend_target.entity.take().is_some() {
+                    chatbox_events.send(ChatboxEvent::System(localize!("chat-defend-stopped")));
+                }
+            }
+            PlayerCommandEvent::ToggleAfk(message) => {
+                if afk_state.is_afk && message.is_none() {
+                    afk_state.clear_afk();
+                    chatbox_events.send(ChatboxEvent::System(localize!("chat-afk-cleared")));
+                } else {
+                    afk_state.set_afk(true, message);
+                    chatbox_events.send(ChatboxEvent::System(localize!(
+                        "chat-afk-set",
+                        message = afk_state.get_message()
+                    )));
+                }
+            }
             PlayerCommandEvent::Move(position, target_entity) => {
+                skill_queue.queued = None;
+
                 let target_entity_id = target_entity
                     .and_then(|target_entity| query_client_entity.get(target_entity).ok())
                     .map(|target_client_entity| target_client_entity.id);
@@ -718,6 +974,24 @@ pub fn player_command_system(
                         .ok();
                 }
             }
+            PlayerCommandEvent::CancelCast => {
+                skill_queue.queued = None;
+
+                if matches!(player.command, Command::CastSkill(_)) {
+                    // There is no message to cancel a cast, stopping where we stand replaces it
+                    if let Some(game_connection) = game_connection.as_ref() {
+                        game_connection
+                            .client_message_tx
+                            .send(ClientMessage::Move {
+                                target_entity_id: None,
+                                x: player.position.x,
+                                y: player.position.y,
+                                z: player.position.z as u16,
+                            })
+                            .ok();
+                    }
+                }
+            }
             PlayerCommandEvent::SetHotbar(page, page_index, hotbar_slot) => {
                 if let Some(hotbar_page) = player.hotbar.pages.get_mut(page) {
                     if let Some(hotbar_page_slot) = hotbar_page.get_mut(page_index) {
@@ -770,6 +1044,7 @@ pub fn player_command_system(
                 }
             }
             PlayerCommandEvent::UseHotbar(_, _) => {} // Handled above
+            PlayerCommandEvent::ExportCharacter(_) => {} // Handled by character_export_system
         }
     }
 }
@@ -1,23 +1,54 @@
 use bevy::{
-    hierarchy::DespawnRecursiveExt,
-    math::{Quat, Vec3},
-    prelude::{Commands, Entity, EventWriter, GlobalTransform, Query, Res, Time, Transform},
+    math::{Quat, Vec3, Vec3Swizzles},
+    prelude::{
+        Commands, Entity, EventWriter, GlobalTransform, Query, Res, ResMut, Time, Transform,
+    },
     render::mesh::skinning::SkinnedMesh,
 };
+use bevy_rapier3d::prelude::{CollisionGroups, QueryFilter, RapierContext};
 
 use rose_data::EffectBulletMoveType;
 
 use crate::{
-    components::{DummyBoneOffset, Projectile, ProjectileParabola, ProjectileTarget},
-    events::HitEvent,
+    components::{
+        DummyBoneOffset, Projectile, ProjectileParabola, ProjectileTarget,
+        COLLISION_FILTER_MOVEABLE, COLLISION_GROUP_ZONE_OBJECT, COLLISION_GROUP_ZONE_TERRAIN,
+    },
+    events::{HitEvent, SpawnEffectData, SpawnEffectEvent},
+    resources::{EntityPool, GameData},
 };
 
+// Height of the arc relative to the horizontal distance travelled
+const PROJECTILE_PARABOLA_HEIGHT_SCALE: f32 = 0.2;
+const PROJECTILE_PARABOLA_MAX_HEIGHT: f32 = 4.0;
+
+// How far above and below a position target to search for the ground it landed on
+const PROJECTILE_GROUND_SEARCH_DISTANCE: f32 = 2.0;
+
+fn get_ground_impact(rapier_context: &RapierContext, position: Vec3) -> Option<(Vec3, Vec3)> {
+    let (_, intersection) = rapier_context.cast_ray_and_get_normal(
+        position + Vec3::Y * PROJECTILE_GROUND_SEARCH_DISTANCE,
+        Vec3::NEG_Y,
+        PROJECTILE_GROUND_SEARCH_DISTANCE * 2.0,
+        false,
+        QueryFilter::new().groups(CollisionGroups::new(
+            COLLISION_FILTER_MOVEABLE,
+            COLLISION_GROUP_ZONE_OBJECT | COLLISION_GROUP_ZONE_TERRAIN,
+        )),
+    )?;
+    Some((intersection.point, intersection.normal))
+}
+
 pub fn projectile_system(
     mut commands: Commands,
     mut hit_events: EventWriter<HitEvent>,
     mut query_bullets: Query<(Entity, &mut Projectile, &Transform)>,
     query_global_transform: Query<&GlobalTransform>,
     query_skeleton: Query<(&SkinnedMesh, &DummyBoneOffset)>,
+    mut spawn_effect_events: EventWriter<SpawnEffectEvent>,
+    mut projectile_pool: ResMut<EntityPool<Projectile>>,
+    game_data: Res<GameData>,
+    rapier_context: Res<RapierContext>,
     time: Res<Time>,
 ) {
     for (entity, mut projectile, transform) in query_bullets.iter_mut() {
@@ -40,8 +71,8 @@ pub fn projectile_system(
         };
 
         if target_translation.is_none() {
-            // Cannot find target, despawn projectile
-            commands.entity(entity).despawn_recursive();
+            // Cannot find target, return projectile to the pool
+            projectile_pool.release(&mut commands, entity);
             continue;
         };
         let mut target_translation = target_translation.unwrap();
@@ -61,68 +92,86 @@ pub fn projectile_system(
             EffectBulletMoveType::Parabola => {
                 let move_speed = projectile.move_speed;
                 let parabola = projectile.parabola.get_or_insert_with(|| {
-                    let distance = transform.translation.distance(target_translation);
-                    let travel_time = distance / move_speed;
-                    let velocity_y = travel_time * 98.0 / 2.0;
-
-                    let mut move_vec =
-                        move_speed * (target_translation - transform.translation).normalize();
-                    move_vec.y = velocity_y;
+                    let horizontal_distance =
+                        (target_translation.xz() - transform.translation.xz()).length();
 
                     ProjectileParabola {
-                        start_y: transform.translation.y,
-                        end_y: target_translation.y,
-                        velocity_y,
-                        move_vec,
+                        start: transform.translation,
+                        arc_height: (horizontal_distance * PROJECTILE_PARABOLA_HEIGHT_SCALE)
+                            .min(PROJECTILE_PARABOLA_MAX_HEIGHT),
                         current_time: 0.0,
-                        total_time: travel_time,
+                        total_time: transform.translation.distance(target_translation) / move_speed,
                     }
                 });
 
-                parabola.velocity_y -= 98.0 * time.delta_seconds();
-                parabola.move_vec.y = parabola.velocity_y;
                 parabola.current_time += time.delta_seconds();
+                let progress = (parabola.current_time / parabola.total_time).min(1.0);
+                let position = parabola.start.lerp(target_translation, progress)
+                    + Vec3::Y * (4.0 * parabola.arc_height * progress * (1.0 - progress));
 
-                let mut move_vec = parabola.move_vec * time.delta_seconds();
-                move_vec.y += ((parabola.end_y - parabola.start_y) / parabola.total_time)
-                    * time.delta_seconds();
-
-                (parabola.current_time >= parabola.total_time, move_vec)
+                (progress >= 1.0, position - transform.translation)
             }
             EffectBulletMoveType::Immediate => (true, Vec3::default()),
         };
 
         if complete {
             // Reached target, send hit event
-            if let ProjectileTarget::Entity {
-                entity: target_entity,
-            } = projectile.target
-            {
-                if let Some(skill_id) = projectile.skill_id {
-                    hit_events.send(
+            match projectile.target {
+                ProjectileTarget::Entity {
+                    entity: target_entity,
+                } => {
+                    // Characters are struck on the side facing the projectile
+                    let impact_normal = (transform.translation - target_translation)
+                        .xz()
+                        .try_normalize()
+                        .map_or(Vec3::Y, |normal| Vec3::new(normal.x, 0.0, normal.y));
+
+                    let hit_event = if let Some(skill_id) = projectile.skill_id {
                         HitEvent::with_skill_damage(projectile.source, target_entity, skill_id)
-                            .apply_damage(projectile.apply_damage),
-                    );
-                } else {
-                    hit_events.send(
+                    } else {
                         HitEvent::with_weapon(
                             projectile.source,
                             target_entity,
                             projectile.effect_id,
                         )
-                        .apply_damage(projectile.apply_damage),
+                    };
+                    hit_events.send(
+                        hit_event
+                            .apply_damage(projectile.apply_damage)
+                            .impact(target_translation, impact_normal),
                     );
                 }
+                ProjectileTarget::Position { position } => {
+                    if let Some(effect_file_id) = projectile
+                        .effect_id
+                        .and_then(|id| game_data.effect_database.get_effect(id))
+                        .and_then(|effect_data| effect_data.hit_effect_normal)
+                    {
+                        let (impact_position, impact_normal) =
+                            get_ground_impact(&rapier_context, position)
+                                .unwrap_or((position, Vec3::Y));
+
+                        spawn_effect_events.send(SpawnEffectEvent::WithTransform(
+                            Transform::from_translation(impact_position)
+                                .with_rotation(Quat::from_rotation_arc(Vec3::Y, impact_normal)),
+                            SpawnEffectData::with_file_id(effect_file_id),
+                        ));
+                    }
+                }
             }
 
-            commands.entity(entity).despawn_recursive();
+            projectile_pool.release(&mut commands, entity);
             continue;
         }
 
-        // Update transform
+        // Update transform, thrown weapons tumble end over end along their path
         let mut transform = *transform;
         transform.translation += move_vec;
-        transform.rotation = Quat::from_rotation_arc(Vec3::X, move_vec.normalize());
+        if let Some(direction) = move_vec.try_normalize() {
+            projectile.spin_angle += projectile.spin_speed * time.delta_seconds();
+            transform.rotation = Quat::from_rotation_arc(Vec3::X, direction)
+                * Quat::from_rotation_z(-projectile.spin_angle);
+        }
         commands.entity(entity).insert(transform);
     }
 }
@@ -0,0 +1,155 @@
+use bevy::{
+    app::AppExit,
+    input::{
+        keyboard::KeyboardInput,
+        mouse::{MouseButtonInput, MouseMotion, MouseWheel},
+    },
+    prelude::{Entity, EventReader, EventWriter, Events, Query, Res, ResMut, State, Time, With},
+    window::{CursorMoved, PrimaryWindow, ReceivedCharacter, Window},
+};
+
+use crate::resources::{
+    create_replay_packet, AppState, ReplayInputEvent, ReplayPlayback, ReplayRecordData,
+    ReplayRecorder,
+};
+
+pub fn replay_record_input_system(
+    replay_recorder: Res<ReplayRecorder>,
+    app_state: Res<State<AppState>>,
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut mouse_button_events: EventReader<MouseButtonInput>,
+    mut mouse_wheel_events: EventReader<MouseWheel>,
+    mut mouse_motion_events: EventReader<MouseMotion>,
+    mut cursor_moved_events: EventReader<CursorMoved>,
+    mut received_character_events: EventReader<ReceivedCharacter>,
+) {
+    // Do not record typing on the login screen so the password is not saved to the replay
+    let record_keyboard = *app_state.get() != AppState::GameLogin;
+
+    for event in keyboard_events.iter() {
+        if record_keyboard {
+            replay_recorder.record(ReplayRecordData::Input(ReplayInputEvent::Keyboard(
+                event.clone(),
+            )));
+        }
+    }
+
+    for event in received_character_events.iter() {
+        if record_keyboard {
+            replay_recorder.record(ReplayRecordData::Input(
+                ReplayInputEvent::ReceivedCharacter { char: event.char },
+            ));
+        }
+    }
+
+    for event in mouse_button_events.iter() {
+        replay_recorder.record(ReplayRecordData::Input(ReplayInputEvent::MouseButton(
+            event.clone(),
+        )));
+    }
+
+    for event in mouse_wheel_events.iter() {
+        replay_recorder.record(ReplayRecordData::Input(ReplayInputEvent::MouseWheel(
+            event.clone(),
+        )));
+    }
+
+    for event in mouse_motion_events.iter() {
+        replay_recorder.record(ReplayRecordData::Input(ReplayInputEvent::MouseMotion(
+            event.clone(),
+        )));
+    }
+
+    for event in cursor_moved_events.iter() {
+        replay_recorder.record(ReplayRecordData::Input(ReplayInputEvent::CursorMoved {
+            position: event.position.into(),
+        }));
+    }
+}
+
+pub fn replay_playback_system(
+    mut replay_playback: ResMut<ReplayPlayback>,
+    time: Res<Time>,
+    mut query_window: Query<(Entity, &mut Window), With<PrimaryWindow>>,
+    mut keyboard_events: ResMut<Events<KeyboardInput>>,
+    mut mouse_button_events: ResMut<Events<MouseButtonInput>>,
+    mut mouse_wheel_events: ResMut<Events<MouseWheel>>,
+    mut mouse_motion_events: ResMut<Events<MouseMotion>>,
+    mut cursor_moved_events: ResMut<Events<CursorMoved>>,
+    mut received_character_events: ResMut<Events<ReceivedCharacter>>,
+    mut app_exit_events: EventWriter<AppExit>,
+) {
+    // Ignore any real input so only the recorded input affects the game
+    keyboard_events.clear();
+    mouse_button_events.clear();
+    mouse_wheel_events.clear();
+    mouse_motion_events.clear();
+    cursor_moved_events.clear();
+    received_character_events.clear();
+
+    let Ok((window_entity, mut window)) = query_window.get_single_mut() else {
+        return;
+    };
+
+    let start_time = *replay_playback.start_time.get_or_insert(time.elapsed());
+    let replay_time_us = time.elapsed().saturating_sub(start_time).as_micros() as u64;
+
+    while let Some(record) = replay_playback.records.front() {
+        if record.time_us > replay_time_us {
+            break;
+        }
+
+        match &record.data {
+            ReplayRecordData::Seed { .. } => {}
+            ReplayRecordData::Input(input) => match input {
+                ReplayInputEvent::Keyboard(event) => keyboard_events.send(KeyboardInput {
+                    window: window_entity,
+                    ..event.clone()
+                }),
+                ReplayInputEvent::MouseButton(event) => {
+                    mouse_button_events.send(MouseButtonInput {
+                        window: window_entity,
+                        ..event.clone()
+                    })
+                }
+                ReplayInputEvent::MouseWheel(event) => mouse_wheel_events.send(MouseWheel {
+                    window: window_entity,
+                    ..event.clone()
+                }),
+                ReplayInputEvent::MouseMotion(event) => mouse_motion_events.send(event.clone()),
+                ReplayInputEvent::CursorMoved { position } => {
+                    window.set_cursor_position(Some((*position).into()));
+                    cursor_moved_events.send(CursorMoved {
+                        window: window_entity,
+                        position: (*position).into(),
+                    });
+                }
+                ReplayInputEvent::ReceivedCharacter { char } => {
+                    received_character_events.send(ReceivedCharacter {
+                        window: window_entity,
+                        char: *char,
+                    })
+                }
+            },
+            ReplayRecordData::ServerPacket {
+                connection,
+                command,
+                data,
+            } => {
+                // Wait until the client has created the connection before sending its packets
+                let Some(playback_tx) = replay_playback.connections.get(connection) else {
+                    break;
+                };
+
+                playback_tx.send(create_replay_packet(*command, data)).ok();
+            }
+        }
+
+        replay_playback.records.pop_front();
+    }
+
+    if replay_playback.is_finished() && replay_playback.headless {
+        log::info!("Replay playback finished");
+        app_exit_events.send(AppExit);
+    }
+}
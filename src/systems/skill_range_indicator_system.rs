@@ -0,0 +1,120 @@
+use std::f32::consts::TAU;
+
+use bevy::prelude::{Assets, Color, Gizmos, GlobalTransform, Query, Res, ResMut, Vec3, With};
+
+use rose_data::SkillType;
+
+use crate::{
+    components::PlayerCharacter,
+    resources::{CurrentZone, GameData, SelectedTarget, SkillRangeIndicator},
+    zone_loader::ZoneLoaderAsset,
+};
+
+const SKILL_RANGE_INDICATOR_HEIGHT: f32 = 0.1;
+const SKILL_RANGE_INDICATOR_MAX_TERRAIN_OFFSET: f32 = 0.5;
+const SKILL_RANGE_INDICATOR_MIN_SEGMENTS: usize = 32;
+const SKILL_RANGE_INDICATOR_MAX_SEGMENTS: usize = 128;
+
+const SKILL_CAST_RANGE_COLOR: Color = Color::rgb(0.3, 0.7, 1.0);
+const SKILL_AOE_RANGE_COLOR: Color = Color::rgb(1.0, 0.5, 0.1);
+
+fn draw_terrain_ring(
+    gizmos: &mut Gizmos,
+    zone_loader_asset: &ZoneLoaderAsset,
+    center: Vec3,
+    radius: f32,
+    color: Color,
+) {
+    let num_segments = ((radius * 4.0) as usize).clamp(
+        SKILL_RANGE_INDICATOR_MIN_SEGMENTS,
+        SKILL_RANGE_INDICATOR_MAX_SEGMENTS,
+    );
+
+    // Follow the terrain, unless the center is standing on top of something else
+    // such as a building, in which case draw a flat ring at the center height
+    let get_terrain_height =
+        |x: f32, z: f32| zone_loader_asset.get_terrain_height(x * 100.0, -z * 100.0) / 100.0;
+    let on_terrain = center.y - get_terrain_height(center.x, center.z)
+        < SKILL_RANGE_INDICATOR_MAX_TERRAIN_OFFSET;
+
+    gizmos.linestrip(
+        (0..=num_segments).map(|index| {
+            let angle = index as f32 / num_segments as f32 * TAU;
+            let x = center.x + radius * angle.cos();
+            let z = center.z + radius * angle.sin();
+            let y = if on_terrain {
+                get_terrain_height(x, z)
+            } else {
+                center.y
+            };
+            Vec3::new(x, y + SKILL_RANGE_INDICATOR_HEIGHT, z)
+        }),
+        color,
+    );
+}
+
+pub fn skill_range_indicator_system(
+    mut skill_range_indicator: ResMut<SkillRangeIndicator>,
+    game_data: Res<GameData>,
+    selected_target: Res<SelectedTarget>,
+    current_zone: Option<Res<CurrentZone>>,
+    zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
+    query_player: Query<&GlobalTransform, With<PlayerCharacter>>,
+    query_target: Query<&GlobalTransform>,
+    mut gizmos: Gizmos,
+) {
+    // The UI sets the hovered skill every frame that it is hovered
+    let Some(skill_data) = skill_range_indicator
+        .hovered_skill
+        .take()
+        .and_then(|skill_id| game_data.skills.get_skill(skill_id))
+    else {
+        return;
+    };
+
+    let Some(zone_loader_asset) = current_zone
+        .as_ref()
+        .and_then(|current_zone| zone_loader_assets.get(&current_zone.handle))
+    else {
+        return;
+    };
+
+    let Ok(player_transform) = query_player.get_single() else {
+        return;
+    };
+    let player_position = player_transform.translation();
+
+    if skill_data.cast_range > 0 {
+        draw_terrain_ring(
+            &mut gizmos,
+            zone_loader_asset,
+            player_position,
+            skill_data.cast_range as f32 / 100.0,
+            SKILL_CAST_RANGE_COLOR,
+        );
+    }
+
+    if skill_data.scope > 0 {
+        let aoe_center = match skill_data.skill_type {
+            SkillType::SelfBound
+            | SkillType::SelfBoundDuration
+            | SkillType::SelfStateDuration
+            | SkillType::SelfDamage => Some(player_position),
+            _ => selected_target
+                .selected
+                .or(selected_target.hover)
+                .and_then(|target_entity| query_target.get(target_entity).ok())
+                .map(|target_transform| target_transform.translation()),
+        };
+
+        if let Some(aoe_center) = aoe_center {
+            draw_terrain_ring(
+                &mut gizmos,
+                zone_loader_asset,
+                aoe_center,
+                skill_data.scope as f32 / 100.0,
+                SKILL_AOE_RANGE_COLOR,
+            );
+        }
+    }
+}
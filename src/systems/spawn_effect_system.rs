@@ -1,18 +1,19 @@
 use bevy::{
-    hierarchy::BuildChildren,
+    hierarchy::{BuildChildren, DespawnRecursiveExt},
     prelude::{
-        AssetServer, Assets, Commands, EventReader, GlobalTransform, Query, Res, ResMut, Transform,
+        AssetServer, Assets, Commands, ComputedVisibility, Entity, EventReader, GlobalTransform,
+        Query, Res, ResMut, Transform, Visibility,
     },
     render::mesh::skinning::SkinnedMesh,
 };
 use rose_file_readers::VfsPath;
 
 use crate::{
-    components::DummyBoneOffset,
+    components::{DummyBoneOffset, PooledEffect},
     effect_loader::spawn_effect,
     events::{SpawnEffect, SpawnEffectData, SpawnEffectEvent},
     render::{EffectMeshMaterial, ParticleMaterial},
-    resources::GameData,
+    resources::{AccessibilitySettings, EntityPool, GameData},
     VfsResource,
 };
 
@@ -29,16 +30,56 @@ fn get_effect_file_path<'a>(
     }
 }
 
+/// Spawns the root entity of an effect. Only free standing effects which are
+/// despawned by effect_system use a pooled entity, the root of any other effect
+/// can be reached from elsewhere so its id could outlive the effect it was for.
+fn spawn_effect_root(
+    commands: &mut Commands,
+    effect_pool: &mut EntityPool<PooledEffect>,
+    transform: Transform,
+    pooled: bool,
+) -> Entity {
+    let bundle = (
+        transform,
+        GlobalTransform::default(),
+        Visibility::default(),
+        ComputedVisibility::default(),
+    );
+
+    if pooled {
+        effect_pool
+            .spawn(commands, (PooledEffect::default(), bundle))
+            .id()
+    } else {
+        commands.spawn(bundle).id()
+    }
+}
+
+fn despawn_effect_root(
+    commands: &mut Commands,
+    effect_pool: &mut EntityPool<PooledEffect>,
+    effect_entity: Entity,
+    pooled: bool,
+) {
+    if pooled {
+        effect_pool.release(commands, effect_entity);
+    } else {
+        commands.entity(effect_entity).despawn_recursive();
+    }
+}
+
 pub fn spawn_effect_system(
     mut commands: Commands,
     mut events: EventReader<SpawnEffectEvent>,
     query_transform: Query<&GlobalTransform>,
     query_skeleton: Query<(&SkinnedMesh, &DummyBoneOffset)>,
     game_data: Res<GameData>,
+    accessibility_settings: Res<AccessibilitySettings>,
     asset_server: Res<AssetServer>,
     vfs_resource: Res<VfsResource>,
     mut effect_mesh_materials: ResMut<Assets<EffectMeshMaterial>>,
     mut particle_materials: ResMut<Assets<ParticleMaterial>>,
+    mut effect_pool: ResMut<EntityPool<PooledEffect>>,
 ) {
     for event in events.iter() {
         match event {
@@ -54,6 +95,7 @@ pub fn spawn_effect_system(
                         effect_file_path,
                         spawn_effect_data.manual_despawn,
                         Some(*effect_entity),
+                        accessibility_settings.effect_intensity,
                     );
                 }
             }
@@ -61,7 +103,15 @@ pub fn spawn_effect_system(
                 if let Some(effect_file_path) = get_effect_file_path(spawn_effect_data, &game_data)
                 {
                     if let Ok(at_global_transform) = query_transform.get(*at_entity) {
-                        if let Some(effect_entity) = spawn_effect(
+                        let pooled = !spawn_effect_data.manual_despawn;
+                        let effect_entity = spawn_effect_root(
+                            &mut commands,
+                            &mut effect_pool,
+                            Transform::from_translation(at_global_transform.translation()),
+                            pooled,
+                        );
+
+                        if spawn_effect(
                             &vfs_resource.vfs,
                             &mut commands,
                             &asset_server,
@@ -69,13 +119,17 @@ pub fn spawn_effect_system(
                             &mut effect_mesh_materials,
                             effect_file_path,
                             spawn_effect_data.manual_despawn,
-                            None,
-                        ) {
-                            commands
-                                .entity(effect_entity)
-                                .insert(Transform::from_translation(
-                                    at_global_transform.translation(),
-                                ));
+                            Some(effect_entity),
+                            accessibility_settings.effect_intensity,
+                        )
+                        .is_none()
+                        {
+                            despawn_effect_root(
+                                &mut commands,
+                                &mut effect_pool,
+                                effect_entity,
+                                pooled,
+                            );
                         }
                     }
                 }
@@ -96,7 +150,15 @@ pub fn spawn_effect_system(
 
                 if let Some(effect_file_path) = get_effect_file_path(spawn_effect_data, &game_data)
                 {
-                    if let Some(effect_entity) = spawn_effect(
+                    // Not pooled as the effect is a child of the entity it is on
+                    let effect_entity = spawn_effect_root(
+                        &mut commands,
+                        &mut effect_pool,
+                        Transform::default(),
+                        false,
+                    );
+
+                    if spawn_effect(
                         &vfs_resource.vfs,
                         &mut commands,
                         &asset_server,
@@ -104,16 +166,25 @@ pub fn spawn_effect_system(
                         &mut effect_mesh_materials,
                         effect_file_path,
                         spawn_effect_data.manual_despawn,
-                        None,
-                    ) {
+                        Some(effect_entity),
+                        accessibility_settings.effect_intensity,
+                    )
+                    .is_some()
+                    {
                         commands.entity(link_entity).add_child(effect_entity);
+                    } else {
+                        despawn_effect_root(&mut commands, &mut effect_pool, effect_entity, false);
                     }
                 }
             }
             SpawnEffectEvent::WithTransform(transform, spawn_effect_data) => {
                 if let Some(effect_file_path) = get_effect_file_path(spawn_effect_data, &game_data)
                 {
-                    if let Some(effect_entity) = spawn_effect(
+                    let pooled = !spawn_effect_data.manual_despawn;
+                    let effect_entity =
+                        spawn_effect_root(&mut commands, &mut effect_pool, *transform, pooled);
+
+                    if spawn_effect(
                         &vfs_resource.vfs,
                         &mut commands,
                         &asset_server,
@@ -121,9 +192,12 @@ pub fn spawn_effect_system(
                         &mut effect_mesh_materials,
                         effect_file_path,
                         spawn_effect_data.manual_despawn,
-                        None,
-                    ) {
-                        commands.entity(effect_entity).insert(*transform);
+                        Some(effect_entity),
+                        accessibility_settings.effect_intensity,
+                    )
+                    .is_none()
+                    {
+                        despawn_effect_root(&mut commands, &mut effect_pool, effect_entity, pooled);
                     }
                 }
             }
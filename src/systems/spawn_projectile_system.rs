@@ -1,17 +1,27 @@
 use bevy::{
+    math::Vec3,
     prelude::{
-        Commands, ComputedVisibility, EventReader, EventWriter, GlobalTransform, Query, Res,
-        Transform, Visibility,
+        AssetServer, BuildChildren, Color, Commands, ComputedVisibility, EventReader, EventWriter,
+        GlobalTransform, Query, Res, ResMut, Transform, Visibility,
     },
     render::mesh::skinning::SkinnedMesh,
 };
 
+use rose_data::EffectBulletMoveType;
+
 use crate::{
     components::{CharacterModel, CharacterModelPart, DummyBoneOffset, Projectile},
     events::{SpawnEffectData, SpawnEffectEvent, SpawnProjectileEvent},
-    resources::GameData,
+    render::TrailEffect,
+    resources::{EntityPool, GameData},
 };
 
+// Thrown weapons follow a parabola and tumble end over end, in radians per second
+const PROJECTILE_TUMBLE_SPEED: f32 = 4.0 * std::f32::consts::TAU;
+
+const PROJECTILE_TRACER_DURATION: f32 = 0.15;
+const PROJECTILE_TRACER_HALF_WIDTH: f32 = 0.03;
+
 pub fn spawn_projectile_system(
     mut commands: Commands,
     mut events: EventReader<SpawnProjectileEvent>,
@@ -19,6 +29,8 @@ pub fn spawn_projectile_system(
     query_character: Query<&CharacterModel>,
     query_skeleton: Query<(&SkinnedMesh, &DummyBoneOffset)>,
     mut spawn_effect_events: EventWriter<SpawnEffectEvent>,
+    mut projectile_pool: ResMut<EntityPool<Projectile>>,
+    asset_server: Res<AssetServer>,
     game_data: Res<GameData>,
 ) {
     for event in events.iter() {
@@ -53,25 +65,55 @@ pub fn spawn_projectile_system(
         }
         let source_global_transform = source_global_transform.unwrap();
 
-        let projectile_entity = commands
-            .spawn((
-                Projectile {
-                    source: event.source,
-                    effect_id: Some(event.effect_id),
-                    skill_id: event.source_skill_id,
-                    move_type: event.move_type,
-                    move_speed: event.move_speed,
-                    apply_damage: event.apply_damage,
-                    parabola: None,
-                    target: event.target,
-                },
-                Transform::from_translation(source_global_transform.translation()),
-                GlobalTransform::default(),
-                Visibility::default(),
-                ComputedVisibility::default(),
-            ))
+        let projectile_entity = projectile_pool
+            .spawn(
+                &mut commands,
+                (
+                    Projectile {
+                        source: event.source,
+                        effect_id: Some(event.effect_id),
+                        skill_id: event.source_skill_id,
+                        move_type: event.move_type,
+                        move_speed: event.move_speed,
+                        apply_damage: event.apply_damage,
+                        parabola: None,
+                        spin_speed: if matches!(event.move_type, EffectBulletMoveType::Parabola) {
+                            PROJECTILE_TUMBLE_SPEED
+                        } else {
+                            0.0
+                        },
+                        spin_angle: 0.0,
+                        target: event.target,
+                    },
+                    Transform::from_translation(source_global_transform.translation()),
+                    GlobalTransform::default(),
+                    Visibility::default(),
+                    ComputedVisibility::default(),
+                ),
+            )
             .id();
 
+        if matches!(event.move_type, EffectBulletMoveType::Linear) {
+            // Arrows and bullets leave a short tracer behind them
+            let tracer_entity = commands
+                .spawn((
+                    TrailEffect {
+                        colour: Color::rgba(1.0, 0.95, 0.8, 0.6),
+                        duration: PROJECTILE_TRACER_DURATION,
+                        start_offset: Vec3::new(0.0, -PROJECTILE_TRACER_HALF_WIDTH, 0.0),
+                        end_offset: Vec3::new(0.0, PROJECTILE_TRACER_HALF_WIDTH, 0.0),
+                        trail_texture: asset_server.load("3DDATA/EFFECT/TRAIL.DDS"),
+                        distance_per_point: 10.0 / 100.0,
+                    },
+                    Transform::default(),
+                    GlobalTransform::default(),
+                    Visibility::default(),
+                    ComputedVisibility::default(),
+                ))
+                .id();
+            commands.entity(projectile_entity).add_child(tracer_entity);
+        }
+
         if let Some(projectile_effect_file_id) = game_data
             .effect_database
             .get_effect(event.effect_id)
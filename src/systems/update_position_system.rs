@@ -1,11 +1,13 @@
 use bevy::{
     math::Vec3Swizzles,
-    prelude::{Query, Res, Time},
+    prelude::{Query, Res, Time, Transform, Vec3},
 };
 
 use rose_game_common::components::MoveSpeed;
 
-use crate::components::{Command, CommandMove, FacingDirection, Position};
+use crate::components::{
+    Command, CommandMove, FacingDirection, NetworkedPositionHistory, Position,
+};
 
 pub fn update_position_system(
     mut query: Query<(&Command, &MoveSpeed, &mut FacingDirection, &mut Position)>,
@@ -36,3 +38,29 @@ pub fn update_position_system(
         }
     }
 }
+
+/// Draws remote entities along the positions reported by the server, so moves
+/// and corrections from the server do not cause them to snap.
+///
+/// This must run after the collision systems have updated the entity height.
+pub fn update_networked_position_system(
+    mut query: Query<(&Position, &mut NetworkedPositionHistory, &mut Transform)>,
+    time: Res<Time>,
+) {
+    let current_time = time.elapsed_seconds_f64();
+
+    for (position, mut position_history, mut transform) in query.iter_mut() {
+        let drawn_position = position_history
+            .sample(current_time)
+            .unwrap_or_else(|| position.xy());
+
+        let translation = Vec3::new(
+            drawn_position.x / 100.0,
+            transform.translation.y,
+            -drawn_position.y / 100.0,
+        );
+        if transform.translation != translation {
+            transform.translation = translation;
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use bevy::{
+    app::AppExit,
+    prelude::{EventReader, ResMut},
+};
+
+use crate::resources::{
+    AccessibilitySettings, AfkSettings, AutoLootSettings, ConfigPersistence, CooldownAlertSettings,
+    DamageDigitsSpawner, GraphicsQuality, InputBindings, ItemGradeEffectSettings, LevelUpSettings,
+    NameTagSettings, SoundSettings, UserSettings, UserSettingsStore, WindowSettings,
+};
+
+#[allow(clippy::too_many_arguments)]
+pub fn user_settings_load_system(
+    mut user_settings_store: ResMut<UserSettingsStore>,
+    mut sound_settings: ResMut<SoundSettings>,
+    mut auto_loot_settings: ResMut<AutoLootSettings>,
+    mut afk_settings: ResMut<AfkSettings>,
+    mut level_up_settings: ResMut<LevelUpSettings>,
+    mut cooldown_alert_settings: ResMut<CooldownAlertSettings>,
+    mut item_grade_effect_settings: ResMut<ItemGradeEffectSettings>,
+    mut graphics_quality: ResMut<GraphicsQuality>,
+    mut window_settings: ResMut<WindowSettings>,
+    mut name_tag_settings: ResMut<NameTagSettings>,
+    mut damage_digits_spawner: ResMut<DamageDigitsSpawner>,
+    mut accessibility_settings: ResMut<AccessibilitySettings>,
+    mut input_bindings: ResMut<InputBindings>,
+) {
+    let user_settings = user_settings_store.load(UserSettings::new(
+        &sound_settings,
+        &auto_loot_settings,
+        &afk_settings,
+        &level_up_settings,
+        &cooldown_alert_settings,
+        &item_grade_effect_settings,
+        &graphics_quality,
+        &window_settings,
+        &name_tag_settings,
+        &damage_digits_spawner,
+        &accessibility_settings,
+        &input_bindings,
+    ));
+
+    user_settings.apply(
+        &mut sound_settings,
+        &mut auto_loot_settings,
+        &mut afk_settings,
+        &mut level_up_settings,
+        &mut cooldown_alert_settings,
+        &mut item_grade_effect_settings,
+        &mut graphics_quality,
+        &mut window_settings,
+        &mut name_tag_settings,
+        &mut damage_digits_spawner,
+        &mut accessibility_settings,
+        &mut input_bindings,
+    );
+}
+
+pub fn user_settings_save_system(
+    mut user_settings_store: ResMut<UserSettingsStore>,
+    mut config_persistence: ResMut<ConfigPersistence>,
+    mut app_exit_events: EventReader<AppExit>,
+) {
+    if let Some(user_settings) = user_settings_store.latest() {
+        config_persistence.update(user_settings);
+    }
+
+    // Save immediately when exiting, otherwise wait for the settings to stop changing
+    let exiting = app_exit_events.iter().count() > 0;
+    user_settings_store.save_pending(exiting);
+    config_persistence.save_pending(exiting);
+}
@@ -0,0 +1,30 @@
+use bevy::{
+    input::Input,
+    prelude::{KeyCode, Res},
+};
+use bevy_egui::EguiContexts;
+
+use rose_game_common::messages::client::ClientMessage;
+
+use crate::resources::{GameConnection, InputAction, InputBindings};
+
+pub fn vehicle_input_system(
+    mut egui_context: EguiContexts,
+    input_bindings: Res<InputBindings>,
+    keyboard_input: Res<Input<KeyCode>>,
+    game_connection: Option<Res<GameConnection>>,
+) {
+    if egui_context.ctx_mut().wants_keyboard_input()
+        || !input_bindings.just_pressed(InputAction::VehicleGetOff, &keyboard_input)
+    {
+        return;
+    }
+
+    // The vehicle context is only active while driving, so this always gets off
+    if let Some(game_connection) = game_connection.as_ref() {
+        game_connection
+            .client_message_tx
+            .send(ClientMessage::DriveToggle)
+            .ok();
+    }
+}
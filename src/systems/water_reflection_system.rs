@@ -0,0 +1,187 @@
+use bevy::{
+    core_pipeline::{
+        core_3d::{self, Camera3d},
+        tonemapping::{DebandDither, Tonemapping},
+    },
+    math::{Mat3, Quat, Vec2, Vec3, Vec4},
+    prelude::{
+        Assets, Camera, Commands, Entity, GlobalTransform, Handle, Image, Projection, Query, Res,
+        ResMut, Transform, With, Without,
+    },
+    render::{
+        camera::{CameraProjection, CameraRenderGraph, RenderTarget},
+        primitives::{Aabb, Frustum},
+        render_resource::Extent3d,
+        view::{ColorGrading, VisibleEntities},
+    },
+};
+
+use crate::{
+    components::{MainCamera, WaterReflectionCamera},
+    render::{WaterMaterial, WaterReflectionProjection},
+    resources::{RenderConfiguration, WaterReflection},
+};
+
+// Water further than this from the camera is not reflected
+const WATER_REFLECTION_MAX_DISTANCE: f32 = 1000.0;
+
+// The reflection is rendered at a fraction of the main camera resolution
+const WATER_REFLECTION_RESOLUTION_DIVISOR: u32 = 2;
+
+// The clip plane is slightly below the water so the shore line is reflected without a gap
+const WATER_REFLECTION_CLIP_OFFSET: f32 = 0.1;
+
+fn mirror_y(vector: Vec3) -> Vec3 {
+    Vec3::new(vector.x, -vector.y, vector.z)
+}
+
+#[allow(clippy::type_complexity)]
+pub fn water_reflection_system(
+    mut commands: Commands,
+    mut water_reflection: ResMut<WaterReflection>,
+    mut images: ResMut<Assets<Image>>,
+    mut water_materials: ResMut<Assets<WaterMaterial>>,
+    render_configuration: Res<RenderConfiguration>,
+    query_camera: Query<(&Camera, &Transform, &Projection), With<MainCamera>>,
+    mut query_reflection_camera: Query<
+        (
+            Entity,
+            &mut Camera,
+            &mut Transform,
+            &mut WaterReflectionProjection,
+        ),
+        (With<WaterReflectionCamera>, Without<MainCamera>),
+    >,
+    query_water: Query<(&Handle<WaterMaterial>, Option<&Aabb>)>,
+) {
+    let Some((camera, camera_transform, camera_perspective, target_size)) = query_camera
+        .get_single()
+        .ok()
+        .filter(|_| render_configuration.water_reflections)
+        .and_then(|(camera, transform, projection)| {
+            let Projection::Perspective(perspective) = projection else {
+                return None;
+            };
+            Some((
+                camera,
+                transform,
+                perspective,
+                camera.physical_target_size()?,
+            ))
+        })
+    else {
+        for (entity, _, _, _) in query_reflection_camera.iter() {
+            commands.entity(entity).despawn();
+        }
+        water_reflection.view_proj = None;
+        return;
+    };
+
+    // Match the aspect ratio of the main camera
+    let reflection_size = Extent3d {
+        width: (target_size.x / WATER_REFLECTION_RESOLUTION_DIVISOR).max(1),
+        height: (target_size.y / WATER_REFLECTION_RESOLUTION_DIVISOR).max(1),
+        depth_or_array_layers: 1,
+    };
+    let mut resized = false;
+    if let Some(image) = images.get_mut(&water_reflection.image) {
+        if image.texture_descriptor.size != reflection_size {
+            image.resize(reflection_size);
+            resized = true;
+        }
+    }
+
+    // The water material bind group must be recreated to use the resized image
+    for (water_material_handle, _) in query_water.iter() {
+        let outdated = water_materials
+            .get(water_material_handle)
+            .map_or(false, |water_material| {
+                resized || water_material.reflection_image.as_ref() != Some(&water_reflection.image)
+            });
+        if outdated {
+            if let Some(water_material) = water_materials.get_mut(water_material_handle) {
+                water_material.reflection_image = Some(water_reflection.image.clone());
+            }
+        }
+    }
+
+    // Reflect the water plane nearest to the camera, if the camera is above it
+    let eye = camera_transform.translation;
+    let water_height = query_water
+        .iter()
+        .filter_map(|(_, aabb)| {
+            let aabb = aabb?;
+            let center = Vec3::from(aabb.center);
+            let offset = ((eye - center).abs() - Vec3::from(aabb.half_extents)).max(Vec3::ZERO);
+            let distance = Vec2::new(offset.x, offset.z).length();
+
+            if center.y < eye.y && distance < WATER_REFLECTION_MAX_DISTANCE {
+                Some((distance, center.y))
+            } else {
+                None
+            }
+        })
+        .min_by(|(a, _), (b, _)| a.total_cmp(b))
+        .map(|(_, water_height)| water_height);
+
+    // Mirroring flips the handedness, so also flip the x axis to keep a valid rotation,
+    // the reflection is sampled by projecting the water position so this has no effect
+    let reflection = water_height.map(|water_height| {
+        let rotation = Mat3::from_cols(
+            -mirror_y(camera_transform.right()),
+            mirror_y(camera_transform.up()),
+            mirror_y(camera_transform.back()),
+        );
+        let transform =
+            Transform::from_translation(Vec3::new(eye.x, 2.0 * water_height - eye.y, eye.z))
+                .with_rotation(Quat::from_mat3(&rotation));
+
+        // Clip everything below the water plane, which would otherwise be drawn in front
+        // of the reflected scene. The plane is transformed to view space by the inverse
+        // transpose of the view matrix, which is the transpose of the camera transform.
+        let clip_plane = transform.compute_matrix().transpose()
+            * Vec4::new(0.0, 1.0, 0.0, WATER_REFLECTION_CLIP_OFFSET - water_height);
+        let projection = WaterReflectionProjection {
+            perspective: camera_perspective.clone(),
+            clip_plane,
+        };
+
+        (transform, projection)
+    });
+
+    water_reflection.view_proj = reflection.as_ref().map(|(transform, projection)| {
+        projection.get_projection_matrix() * transform.compute_matrix().inverse()
+    });
+
+    if let Ok((_, mut reflection_camera, mut transform, mut projection)) =
+        query_reflection_camera.get_single_mut()
+    {
+        reflection_camera.is_active = camera.is_active && reflection.is_some();
+        if let Some((reflection_transform, reflection_projection)) = reflection {
+            *transform = reflection_transform;
+            *projection = reflection_projection;
+        }
+    } else if let Some((reflection_transform, reflection_projection)) = reflection {
+        // The reflection camera does not have the water render layer, so it does not
+        // render the water which samples the reflection image
+        commands.spawn((
+            WaterReflectionCamera,
+            Camera {
+                // Render before the main and conversation dialog portrait cameras
+                order: -2,
+                target: RenderTarget::Image(water_reflection.image.clone()),
+                ..Default::default()
+            },
+            CameraRenderGraph::new(core_3d::graph::NAME),
+            Camera3d::default(),
+            reflection_projection,
+            Frustum::default(),
+            VisibleEntities::default(),
+            reflection_transform,
+            GlobalTransform::default(),
+            Tonemapping::default(),
+            DebandDither::Enabled,
+            ColorGrading::default(),
+        ));
+    }
+}
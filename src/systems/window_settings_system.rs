@@ -0,0 +1,193 @@
+use bevy::{
+    prelude::{Entity, EventReader, Local, NonSend, Query, Res, ResMut, With},
+    window::{PrimaryWindow, Window, WindowFocused, WindowMode},
+    winit::WinitWindows,
+};
+use winit::{monitor::VideoMode, window::Fullscreen};
+
+use crate::resources::{
+    AvailableMonitors, MonitorInfo, MonitorVideoMode, WindowModeSetting, WindowSettings,
+};
+
+fn get_monitor_video_mode(video_mode: &VideoMode) -> MonitorVideoMode {
+    let size = video_mode.size();
+    MonitorVideoMode {
+        width: size.width,
+        height: size.height,
+        refresh_rate: (video_mode.refresh_rate_millihertz() + 500) / 1000,
+    }
+}
+
+// Bevy always chooses the highest refresh rate for sized fullscreen, so once
+// it has entered fullscreen switch to the video mode with the requested one
+fn update_exclusive_refresh_rate(
+    window_settings: &WindowSettings,
+    unavailable_video_mode: &mut Option<MonitorVideoMode>,
+    winit_window: &winit::window::Window,
+) {
+    if window_settings.mode != WindowModeSetting::Exclusive {
+        return;
+    }
+
+    let Some(requested) = window_settings
+        .exclusive_video_mode
+        .filter(|video_mode| Some(*video_mode) != *unavailable_video_mode)
+    else {
+        return;
+    };
+
+    let Some(Fullscreen::Exclusive(current)) = winit_window.fullscreen() else {
+        return;
+    };
+
+    let current_size = current.size();
+    if current_size.width != requested.width || current_size.height != requested.height {
+        return;
+    }
+
+    // A refresh rate of 0 uses the highest available
+    let video_mode = current
+        .monitor()
+        .video_modes()
+        .filter(|video_mode| {
+            let monitor_video_mode = get_monitor_video_mode(video_mode);
+            monitor_video_mode.width == requested.width
+                && monitor_video_mode.height == requested.height
+                && (requested.refresh_rate == 0
+                    || monitor_video_mode.refresh_rate == requested.refresh_rate)
+        })
+        .max_by_key(|video_mode| video_mode.refresh_rate_millihertz());
+
+    match video_mode {
+        Some(video_mode) => {
+            if video_mode.refresh_rate_millihertz() != current.refresh_rate_millihertz() {
+                winit_window.set_fullscreen(Some(Fullscreen::Exclusive(video_mode)));
+            }
+        }
+        None => {
+            log::warn!(
+                "Monitor does not support {}x{} at {}Hz",
+                requested.width,
+                requested.height,
+                requested.refresh_rate
+            );
+            *unavailable_video_mode = Some(requested);
+        }
+    }
+}
+
+pub fn window_settings_system(
+    window_settings: Res<WindowSettings>,
+    mut pending_mode: Local<Option<WindowMode>>,
+    mut unavailable_video_mode: Local<Option<MonitorVideoMode>>,
+    mut query_window: Query<(Entity, &mut Window), With<PrimaryWindow>>,
+    winit_windows: Option<NonSend<WinitWindows>>,
+) {
+    let Ok((entity, mut window)) = query_window.get_single_mut() else {
+        return;
+    };
+
+    if let Some(mode) = pending_mode.take() {
+        window.mode = mode;
+        return;
+    }
+
+    if let Some(winit_window) = winit_windows
+        .as_ref()
+        .and_then(|winit_windows| winit_windows.get_window(entity))
+    {
+        update_exclusive_refresh_rate(&window_settings, &mut unavailable_video_mode, winit_window);
+    }
+
+    if !window_settings.is_changed() {
+        return;
+    }
+
+    let present_mode = window_settings.present_mode();
+    if window.present_mode != present_mode {
+        window.present_mode = present_mode;
+    }
+
+    let mode = window_settings.window_mode();
+    let position = window_settings.window_position();
+    let (width, height) = window_settings.window_resolution();
+    let resolution_changed = mode == WindowMode::SizedFullscreen
+        && (window.resolution.width() != width || window.resolution.height() != height);
+    if window.mode == mode && window.position == position && !resolution_changed {
+        return;
+    }
+
+    // Only reset the size of the window when leaving fullscreen, so changing
+    // other settings does not undo the user resizing the window
+    if mode == WindowMode::SizedFullscreen
+        || (mode == WindowMode::Windowed && (window.mode != mode || window.position != position))
+    {
+        window.resolution.set(width, height);
+    }
+
+    if window.position != position && mode != WindowMode::Windowed {
+        // Fullscreen uses the window's current monitor, so leave fullscreen and
+        // move to the new monitor before entering fullscreen again next frame
+        window.position = position;
+        window.mode = WindowMode::Windowed;
+        *pending_mode = Some(mode);
+    } else {
+        window.position = position;
+        window.mode = mode;
+    }
+}
+
+pub fn window_monitors_system(
+    mut available_monitors: ResMut<AvailableMonitors>,
+    winit_windows: Option<NonSend<WinitWindows>>,
+    query_window: Query<Entity, With<PrimaryWindow>>,
+) {
+    if !available_monitors.monitors.is_empty() {
+        return;
+    }
+
+    let Some(winit_window) = winit_windows.as_ref().and_then(|winit_windows| {
+        query_window
+            .get_single()
+            .ok()
+            .and_then(|entity| winit_windows.get_window(entity))
+    }) else {
+        return;
+    };
+
+    available_monitors.monitors = winit_window
+        .available_monitors()
+        .enumerate()
+        .map(|(index, monitor)| {
+            let mut video_modes: Vec<MonitorVideoMode> = monitor
+                .video_modes()
+                .map(|video_mode| get_monitor_video_mode(&video_mode))
+                .collect();
+            video_modes.sort_by(|a, b| b.cmp(a));
+            video_modes.dedup();
+
+            MonitorInfo {
+                name: monitor
+                    .name()
+                    .unwrap_or_else(|| format!("Monitor {}", index + 1)),
+                video_modes,
+            }
+        })
+        .collect();
+}
+
+pub fn window_focus_system(
+    window_settings: Res<WindowSettings>,
+    mut window_focused_events: EventReader<WindowFocused>,
+    mut query_window: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    for event in window_focused_events.iter() {
+        if event.focused || !window_settings.should_minimize_on_focus_loss() {
+            continue;
+        }
+
+        if let Ok(mut window) = query_window.get_mut(event.window) {
+            window.set_minimized(true);
+        }
+    }
+}
@@ -0,0 +1,327 @@
+use std::{f32::consts::TAU, path::PathBuf, time::Duration};
+
+use bevy::{
+    app::AppExit,
+    ecs::entity::Entities,
+    math::Vec3,
+    prelude::{
+        AssetServer, Assets, Commands, Entity, EventReader, EventWriter, Image, Local, Mesh, Query,
+        Res, ResMut, Time, Transform, With,
+    },
+    render::mesh::Indices,
+};
+use enum_map::EnumMap;
+use serde::Serialize;
+
+use rose_data::ZoneId;
+
+use crate::{
+    animation::CameraAnimation,
+    components::MainCamera,
+    events::ZoneEvent,
+    render::{GpuTimingCategory, RenderStatistics},
+    resources::{
+        get_data_dir, GameData, GraphicsQuality, GraphicsQualityPreset, GraphicsQualitySettings,
+        WindowSettings, ZoneBenchmark,
+    },
+    systems::FreeCamera,
+    ui::UiStateDebugWindows,
+};
+
+const BENCHMARK_ZONE_LOAD_TIMEOUT: Duration = Duration::from_secs(300);
+
+// Give streamed in assets and shader compilation time to settle before measuring
+const BENCHMARK_WARM_UP_DURATION: Duration = Duration::from_secs(3);
+
+const BENCHMARK_ORBIT_CENTER: Vec3 = Vec3::new(5120.0, 0.0, -5120.0);
+const BENCHMARK_ORBIT_RADIUS: f32 = 200.0;
+const BENCHMARK_ORBIT_HEIGHT: f32 = 80.0;
+
+#[derive(Serialize)]
+struct BenchmarkGpuTimings {
+    terrain_ms: f64,
+    objects_ms: f64,
+    particles_ms: f64,
+    ui_ms: f64,
+    other_ms: f64,
+}
+
+#[derive(Serialize)]
+struct BenchmarkMemoryUsage {
+    image_bytes: usize,
+    mesh_bytes: usize,
+    num_entities: u32,
+}
+
+#[derive(Serialize)]
+struct BenchmarkReport {
+    zone_id: u16,
+    zone_name: String,
+    time: String,
+    camera_path: String,
+    zone_load_seconds: f64,
+    duration_seconds: f64,
+    num_frames: usize,
+    average_fps: f64,
+    one_percent_low_fps: f64,
+    average_frame_time_ms: f64,
+    max_frame_time_ms: f64,
+    average_draw_calls: f64,
+    average_triangles: f64,
+    graphics_preset: GraphicsQualityPreset,
+    graphics_settings: GraphicsQualitySettings,
+    /// None when the GPU does not support timestamp queries
+    gpu_timings: Option<BenchmarkGpuTimings>,
+    memory: BenchmarkMemoryUsage,
+}
+
+#[derive(Default)]
+enum BenchmarkPhase {
+    #[default]
+    LoadingZone,
+    WarmingUp {
+        until: Duration,
+    },
+    Running {
+        start: Duration,
+    },
+    Finished,
+}
+
+#[derive(Default)]
+pub struct ZoneBenchmarkState {
+    phase: BenchmarkPhase,
+    zone_id: Option<ZoneId>,
+    zone_load_time: Duration,
+    frame_times: Vec<Duration>,
+    draw_calls: u64,
+    triangles: u64,
+    gpu_timings_ms: EnumMap<GpuTimingCategory, f64>,
+    num_gpu_timings: u32,
+}
+
+fn get_mesh_size(mesh: &Mesh) -> usize {
+    let index_size = mesh.indices().map_or(0, |indices| match indices {
+        Indices::U16(indices) => indices.len() * 2,
+        Indices::U32(indices) => indices.len() * 4,
+    });
+    mesh.count_vertices() * mesh.get_vertex_size() as usize + index_size
+}
+
+fn write_report(report: &BenchmarkReport, output_path: Option<PathBuf>) -> anyhow::Result<PathBuf> {
+    let path = if let Some(output_path) = output_path {
+        output_path
+    } else {
+        get_data_dir()?.join("benchmarks").join(format!(
+            "zone-{}-{}.json",
+            report.zone_id,
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ))
+    };
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, serde_json::to_string_pretty(report)?)?;
+    Ok(path)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn zone_benchmark_system(
+    mut commands: Commands,
+    mut state: Local<ZoneBenchmarkState>,
+    mut query_cameras: Query<(Entity, &mut Transform, Option<&CameraAnimation>), With<MainCamera>>,
+    mut window_settings: ResMut<WindowSettings>,
+    mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
+    benchmark: Res<ZoneBenchmark>,
+    graphics_quality: Res<GraphicsQuality>,
+    asset_server: Res<AssetServer>,
+    game_data: Res<GameData>,
+    render_statistics: Option<Res<RenderStatistics>>,
+    images: Res<Assets<Image>>,
+    meshes: Res<Assets<Mesh>>,
+    entities: &Entities,
+    time: Res<Time>,
+    mut zone_events: EventReader<ZoneEvent>,
+    mut app_exit_events: EventWriter<AppExit>,
+) {
+    let now = time.raw_elapsed();
+
+    for event in zone_events.iter() {
+        let ZoneEvent::Loaded(zone_id) = event;
+        if matches!(state.phase, BenchmarkPhase::LoadingZone) {
+            log::info!("Benchmark loaded zone {}", zone_id.get());
+            state.zone_id = Some(*zone_id);
+            state.zone_load_time = now;
+            state.phase = BenchmarkPhase::WarmingUp {
+                until: now + BENCHMARK_WARM_UP_DURATION,
+            };
+        }
+    }
+
+    let start = match state.phase {
+        BenchmarkPhase::LoadingZone => {
+            if now > BENCHMARK_ZONE_LOAD_TIMEOUT {
+                log::error!(
+                    "Benchmark failed: zone did not load within {} seconds",
+                    BENCHMARK_ZONE_LOAD_TIMEOUT.as_secs()
+                );
+                state.phase = BenchmarkPhase::Finished;
+                benchmark.set_exit_code(1);
+                app_exit_events.send(AppExit);
+            }
+            return;
+        }
+        BenchmarkPhase::WarmingUp { until } => {
+            if now < until {
+                return;
+            }
+
+            // Measure only the zone, without v-sync or the zone viewer debug windows
+            window_settings.vsync = false;
+            ui_state_debug_windows.debug_ui_open = false;
+
+            for (entity, _, _) in query_cameras.iter() {
+                let mut camera_commands = commands.entity(entity);
+                camera_commands.remove::<FreeCamera>();
+
+                if let Some(camera_motion) = benchmark.camera_motion.as_ref() {
+                    camera_commands.insert(CameraAnimation::once(
+                        asset_server.load(camera_motion.as_str()),
+                    ));
+                }
+            }
+
+            state.phase = BenchmarkPhase::Running { start: now };
+            return;
+        }
+        BenchmarkPhase::Running { start } => start,
+        BenchmarkPhase::Finished => return,
+    };
+    let elapsed = now.saturating_sub(start);
+
+    let completed = if benchmark.camera_motion.is_some() {
+        // Wait for the camera animation to have been added before checking if it completed
+        elapsed > Duration::ZERO
+            && query_cameras.iter().all(|(_, _, camera_animation)| {
+                camera_animation.map_or(true, |camera_animation| camera_animation.completed())
+            })
+    } else {
+        let progress = elapsed.as_secs_f32() / benchmark.duration.as_secs_f32().max(0.001);
+        let angle = progress.min(1.0) * TAU;
+        let eye = BENCHMARK_ORBIT_CENTER
+            + Vec3::new(
+                angle.cos() * BENCHMARK_ORBIT_RADIUS,
+                BENCHMARK_ORBIT_HEIGHT,
+                angle.sin() * BENCHMARK_ORBIT_RADIUS,
+            );
+        for (_, mut transform, _) in query_cameras.iter_mut() {
+            *transform =
+                Transform::from_translation(eye).looking_at(BENCHMARK_ORBIT_CENTER, Vec3::Y);
+        }
+
+        progress >= 1.0
+    };
+
+    if elapsed > Duration::ZERO {
+        state.frame_times.push(time.raw_delta());
+
+        if let Some(render_statistics) = render_statistics.as_ref() {
+            let frame = render_statistics.get();
+            for pass in frame.passes.values() {
+                state.draw_calls += pass.draw_calls as u64;
+                state.triangles += pass.triangles;
+            }
+
+            if let Some(gpu_timings_ms) = frame.gpu_timings_ms() {
+                for (category, time_ms) in gpu_timings_ms {
+                    state.gpu_timings_ms[category] += time_ms;
+                }
+                state.num_gpu_timings += 1;
+            }
+        }
+    }
+
+    if !completed {
+        return;
+    }
+
+    state.phase = BenchmarkPhase::Finished;
+    app_exit_events.send(AppExit);
+
+    let num_frames = state.frame_times.len();
+    if num_frames == 0 {
+        log::error!("Benchmark failed: no frames were recorded, check the camera path");
+        benchmark.set_exit_code(1);
+        return;
+    }
+
+    let mut frame_times: Vec<f64> = state
+        .frame_times
+        .iter()
+        .map(|frame_time| frame_time.as_secs_f64())
+        .collect();
+    frame_times.sort_by(|a, b| b.total_cmp(a));
+    let total_time: f64 = frame_times.iter().sum();
+    let num_low_frames = (num_frames / 100).max(1);
+    let low_frame_time = frame_times[..num_low_frames].iter().sum::<f64>() / num_low_frames as f64;
+
+    let zone_id = state.zone_id.map_or(0, |zone_id| zone_id.get());
+    let gpu_timings = (state.num_gpu_timings > 0).then(|| {
+        let average = |category| state.gpu_timings_ms[category] / state.num_gpu_timings as f64;
+        BenchmarkGpuTimings {
+            terrain_ms: average(GpuTimingCategory::Terrain),
+            objects_ms: average(GpuTimingCategory::Objects),
+            particles_ms: average(GpuTimingCategory::Particles),
+            ui_ms: average(GpuTimingCategory::Ui),
+            other_ms: average(GpuTimingCategory::Other),
+        }
+    });
+
+    let report = BenchmarkReport {
+        zone_id,
+        zone_name: state
+            .zone_id
+            .and_then(|zone_id| game_data.zone_list.get_zone(zone_id))
+            .map_or_else(String::new, |zone_data| zone_data.name.to_string()),
+        time: chrono::Local::now().to_rfc3339(),
+        camera_path: benchmark
+            .camera_motion
+            .clone()
+            .unwrap_or_else(|| "orbit".to_string()),
+        zone_load_seconds: state.zone_load_time.as_secs_f64(),
+        duration_seconds: total_time,
+        num_frames,
+        average_fps: num_frames as f64 / total_time,
+        one_percent_low_fps: 1.0 / low_frame_time,
+        average_frame_time_ms: total_time * 1000.0 / num_frames as f64,
+        max_frame_time_ms: frame_times[0] * 1000.0,
+        average_draw_calls: state.draw_calls as f64 / num_frames as f64,
+        average_triangles: state.triangles as f64 / num_frames as f64,
+        graphics_preset: graphics_quality.preset,
+        graphics_settings: graphics_quality.effective_settings(),
+        gpu_timings,
+        memory: BenchmarkMemoryUsage {
+            image_bytes: images.iter().map(|(_, image)| image.data.len()).sum(),
+            mesh_bytes: meshes.iter().map(|(_, mesh)| get_mesh_size(mesh)).sum(),
+            num_entities: entities.len(),
+        },
+    };
+
+    match write_report(&report, benchmark.output_path.clone()) {
+        Ok(path) => {
+            log::info!(
+                "Benchmark of zone {} averaged {:.1} fps, 1% low {:.1} fps, report written to {}",
+                zone_id,
+                report.average_fps,
+                report.one_percent_low_fps,
+                path.to_string_lossy()
+            );
+            benchmark.set_exit_code(0);
+        }
+        Err(error) => {
+            log::error!("Benchmark failed to write report with error: {}", error);
+            benchmark.set_exit_code(1);
+        }
+    }
+}
@@ -0,0 +1,403 @@
+use std::{
+    collections::BTreeSet,
+    path::{Path, PathBuf},
+};
+
+use bevy::{
+    app::AppExit,
+    asset::LoadState,
+    prelude::{AssetServer, Assets, EventReader, EventWriter, Handle, Local, Res},
+};
+use serde::Serialize;
+
+use rose_data::ZoneId;
+use rose_file_readers::{StbFile, VirtualFilesystem, ZscFile};
+
+use crate::{
+    events::ValidateZoneEvent,
+    model_loader::ModelLoader,
+    resources::{get_data_dir, GameData, ZoneValidationResult},
+    zone_loader::ZoneLoaderAsset,
+    VfsResource,
+};
+
+const WARP_STB_PATH: &str = "3DDATA/STB/WARP.STB";
+
+#[derive(Serialize)]
+struct ZoneValidationIssue {
+    block: Option<[usize; 2]>,
+    message: String,
+}
+
+#[derive(Default, Serialize)]
+struct ZoneValidationReport {
+    zone_id: u16,
+    zone_name: String,
+    time: String,
+    missing_textures: Vec<ZoneValidationIssue>,
+    missing_zsc_parts: Vec<ZoneValidationIssue>,
+    npc_issues: Vec<ZoneValidationIssue>,
+    warp_issues: Vec<ZoneValidationIssue>,
+    event_object_issues: Vec<ZoneValidationIssue>,
+}
+
+impl ZoneValidationReport {
+    fn num_issues(&self) -> usize {
+        self.missing_textures.len()
+            + self.missing_zsc_parts.len()
+            + self.npc_issues.len()
+            + self.warp_issues.len()
+            + self.event_object_issues.len()
+    }
+}
+
+pub struct PendingZoneValidation {
+    zone_id: ZoneId,
+    handle: Handle<ZoneLoaderAsset>,
+    output_path: Option<PathBuf>,
+}
+
+fn push_issue(issues: &mut Vec<ZoneValidationIssue>, block: Option<[usize; 2]>, message: String) {
+    issues.push(ZoneValidationIssue { block, message });
+}
+
+fn vfs_file_exists(vfs: &VirtualFilesystem, path: &Path) -> bool {
+    vfs.open_file(&*path.to_string_lossy()).is_ok()
+}
+
+fn validate_zsc(
+    report: &mut ZoneValidationReport,
+    vfs: &VirtualFilesystem,
+    zsc_name: &str,
+    zsc: &ZscFile,
+) {
+    for (material_id, material) in zsc.materials.iter().enumerate() {
+        if !vfs_file_exists(vfs, material.path.path()) {
+            push_issue(
+                &mut report.missing_textures,
+                None,
+                format!(
+                    "{} material {} texture {}",
+                    zsc_name,
+                    material_id,
+                    material.path.path().to_string_lossy()
+                ),
+            );
+        }
+    }
+
+    for (mesh_id, mesh) in zsc.meshes.iter().enumerate() {
+        if !vfs_file_exists(vfs, mesh.path()) {
+            push_issue(
+                &mut report.missing_zsc_parts,
+                None,
+                format!(
+                    "{} mesh {} file {}",
+                    zsc_name,
+                    mesh_id,
+                    mesh.path().to_string_lossy()
+                ),
+            );
+        }
+    }
+
+    for (object_id, object) in zsc.objects.iter().enumerate() {
+        for (part_index, part) in object.parts.iter().enumerate() {
+            if part.mesh_id as usize >= zsc.meshes.len() {
+                push_issue(
+                    &mut report.missing_zsc_parts,
+                    None,
+                    format!(
+                        "{} object {} part {} references mesh {} which does not exist",
+                        zsc_name, object_id, part_index, part.mesh_id
+                    ),
+                );
+            }
+
+            if part.material_id as usize >= zsc.materials.len() {
+                push_issue(
+                    &mut report.missing_zsc_parts,
+                    None,
+                    format!(
+                        "{} object {} part {} references material {} which does not exist",
+                        zsc_name, object_id, part_index, part.material_id
+                    ),
+                );
+            }
+        }
+    }
+}
+
+fn validate_zone(
+    zone_data: &ZoneLoaderAsset,
+    game_data: &GameData,
+    model_loader: &ModelLoader,
+    vfs: &VirtualFilesystem,
+) -> ZoneValidationReport {
+    let mut report = ZoneValidationReport {
+        zone_id: zone_data.zone_id.get(),
+        zone_name: game_data
+            .zone_list
+            .get_zone(zone_data.zone_id)
+            .map_or_else(String::new, |zone_list_entry| {
+                zone_list_entry.name.to_string()
+            }),
+        time: chrono::Local::now().to_rfc3339(),
+        ..Default::default()
+    };
+
+    for path in zone_data.zon.tile_textures.iter() {
+        if path == "end" {
+            break;
+        }
+
+        if !vfs_file_exists(vfs, Path::new(path)) {
+            push_issue(
+                &mut report.missing_textures,
+                None,
+                format!("tile texture {}", path),
+            );
+        }
+    }
+
+    validate_zsc(&mut report, vfs, "ZSC_CNST", &zone_data.zsc_cnst);
+    validate_zsc(&mut report, vfs, "ZSC_DECO", &zone_data.zsc_deco);
+
+    let warp_stb = vfs.read_file::<StbFile, _>(WARP_STB_PATH);
+    if let Err(error) = warp_stb.as_ref() {
+        push_issue(
+            &mut report.warp_issues,
+            None,
+            format!("failed to read {}: {}", WARP_STB_PATH, error),
+        );
+    }
+
+    for block_data in zone_data.blocks.iter().flatten() {
+        let block = Some([block_data.block_x, block_data.block_y]);
+        let lightmap_path = zone_data.zone_path.join(format!(
+            "{}_{}/LIGHTMAP/",
+            block_data.block_x, block_data.block_y
+        ));
+
+        for lit in [block_data.lit_cnst.as_ref(), block_data.lit_deco.as_ref()]
+            .into_iter()
+            .flatten()
+        {
+            let lightmap_files: BTreeSet<&str> = lit
+                .objects
+                .iter()
+                .flat_map(|lit_object| lit_object.parts.iter())
+                .map(|lit_part| lit_part.filename.as_str())
+                .collect();
+
+            for filename in lightmap_files {
+                if !vfs_file_exists(vfs, &lightmap_path.join(filename)) {
+                    push_issue(
+                        &mut report.missing_textures,
+                        block,
+                        format!(
+                            "lightmap {}",
+                            lightmap_path.join(filename).to_string_lossy()
+                        ),
+                    );
+                }
+            }
+        }
+
+        let Some(ifo) = block_data.ifo.as_ref() else {
+            continue;
+        };
+
+        for (zsc_name, zsc, objects) in [
+            ("ZSC_CNST", &zone_data.zsc_cnst, &ifo.cnst_objects),
+            ("ZSC_DECO", &zone_data.zsc_deco, &ifo.deco_objects),
+        ] {
+            for (ifo_object_id, object_instance) in objects.iter().enumerate() {
+                if object_instance.object_id as usize >= zsc.objects.len() {
+                    push_issue(
+                        &mut report.missing_zsc_parts,
+                        block,
+                        format!(
+                            "object {} references {} object {} which does not exist",
+                            ifo_object_id, zsc_name, object_instance.object_id
+                        ),
+                    );
+                }
+            }
+        }
+
+        for (ifo_object_id, event_object) in ifo.event_objects.iter().enumerate() {
+            if event_object.object.object_id as usize >= game_data.zsc_event_object.objects.len() {
+                push_issue(
+                    &mut report.event_object_issues,
+                    block,
+                    format!(
+                        "event object {} references EVENT_OBJECT.ZSC object {} which does not exist",
+                        ifo_object_id, event_object.object.object_id
+                    ),
+                );
+            }
+        }
+
+        let Ok(warp_stb) = warp_stb.as_ref() else {
+            continue;
+        };
+
+        for (ifo_object_id, warp_object) in ifo.warps.iter().enumerate() {
+            let warp_id = warp_object.warp_id as usize;
+            if warp_id >= warp_stb.rows() {
+                push_issue(
+                    &mut report.warp_issues,
+                    block,
+                    format!(
+                        "warp {} references warp gate {} which does not exist",
+                        ifo_object_id, warp_id
+                    ),
+                );
+                continue;
+            }
+
+            let target_zone = warp_stb.get_int(warp_id, 1);
+            if u16::try_from(target_zone)
+                .ok()
+                .and_then(ZoneId::new)
+                .and_then(|zone_id| game_data.zone_list.get_zone(zone_id))
+                .is_none()
+            {
+                push_issue(
+                    &mut report.warp_issues,
+                    block,
+                    format!(
+                        "warp gate {} destination zone {} does not exist",
+                        warp_id, target_zone
+                    ),
+                );
+            }
+
+            if warp_stb.get(warp_id, 2).is_empty() {
+                push_issue(
+                    &mut report.warp_issues,
+                    block,
+                    format!("warp gate {} has no destination event", warp_id),
+                );
+            }
+        }
+    }
+
+    let mut npc_ids = Vec::new();
+    for npc in zone_data.npcs.iter() {
+        if !npc_ids.contains(&npc.npc_id) {
+            npc_ids.push(npc.npc_id);
+        }
+    }
+
+    for npc_id in npc_ids {
+        if game_data.npcs.get_npc(npc_id).is_none() {
+            push_issue(
+                &mut report.npc_issues,
+                None,
+                format!("npc {} does not exist in LIST_NPC.STB", npc_id.get()),
+            );
+            continue;
+        }
+
+        for issue in model_loader.get_npc_model_issues(npc_id) {
+            push_issue(
+                &mut report.npc_issues,
+                None,
+                format!("npc {} {}", npc_id.get(), issue),
+            );
+        }
+    }
+
+    report
+}
+
+fn write_report(
+    report: &ZoneValidationReport,
+    output_path: Option<PathBuf>,
+) -> anyhow::Result<PathBuf> {
+    let path = if let Some(output_path) = output_path {
+        output_path
+    } else {
+        get_data_dir()?.join("zone_validation").join(format!(
+            "zone-{}-{}.json",
+            report.zone_id,
+            chrono::Local::now().format("%Y%m%d-%H%M%S")
+        ))
+    };
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, serde_json::to_string_pretty(report)?)?;
+    Ok(path)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn zone_validation_system(
+    mut pending_validations: Local<Vec<PendingZoneValidation>>,
+    mut validate_zone_events: EventReader<ValidateZoneEvent>,
+    asset_server: Res<AssetServer>,
+    zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
+    game_data: Res<GameData>,
+    model_loader: Res<ModelLoader>,
+    vfs_resource: Res<VfsResource>,
+    zone_validation_result: Option<Res<ZoneValidationResult>>,
+    mut app_exit_events: EventWriter<AppExit>,
+) {
+    for event in validate_zone_events.iter() {
+        log::info!("Validating zone {}", event.id.get());
+        pending_validations.push(PendingZoneValidation {
+            zone_id: event.id,
+            handle: asset_server.load(format!("{}.zone_loader", event.id.get())),
+            output_path: event.output_path.clone(),
+        });
+    }
+
+    pending_validations.retain_mut(|pending_validation| {
+        let result = if let Some(zone_data) = zone_loader_assets.get(&pending_validation.handle) {
+            let report = validate_zone(zone_data, &game_data, &model_loader, &vfs_resource.vfs);
+            write_report(&report, pending_validation.output_path.take())
+                .map(|path| (path, report.num_issues()))
+        } else if matches!(
+            asset_server.get_load_state(&pending_validation.handle),
+            LoadState::Failed
+        ) {
+            Err(anyhow::anyhow!("Failed to load zone"))
+        } else {
+            return true;
+        };
+
+        let exit_code = match result {
+            Ok((path, num_issues)) => {
+                log::info!(
+                    "Zone {} validation found {} issues, report written to {}",
+                    pending_validation.zone_id.get(),
+                    num_issues,
+                    path.to_string_lossy()
+                );
+                if num_issues == 0 {
+                    0
+                } else {
+                    1
+                }
+            }
+            Err(error) => {
+                log::error!(
+                    "Zone {} validation failed with error: {}",
+                    pending_validation.zone_id.get(),
+                    error
+                );
+                1
+            }
+        };
+
+        if let Some(zone_validation_result) = zone_validation_result.as_ref() {
+            zone_validation_result.set_exit_code(exit_code);
+            app_exit_events.send(AppExit);
+        }
+
+        false
+    });
+}
@@ -1,17 +1,18 @@
 use bevy::{
     math::Vec3,
-    prelude::{Camera3d, Commands, Entity, Query, ResMut, With},
+    prelude::{Commands, Entity, Query, ResMut, With},
 };
 
 use crate::{
     animation::CameraAnimation,
+    components::MainCamera,
     systems::{FreeCamera, OrbitCamera},
     ui::UiStateDebugWindows,
 };
 
 pub fn zone_viewer_enter_system(
     mut commands: Commands,
-    query_cameras: Query<Entity, With<Camera3d>>,
+    query_cameras: Query<Entity, With<MainCamera>>,
     mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
 ) {
     // Reset camera
@@ -30,5 +31,6 @@ pub fn zone_viewer_enter_system(
     // Open relevant debug windows
     ui_state_debug_windows.camera_info_open = true;
     ui_state_debug_windows.debug_ui_open = true;
+    ui_state_debug_windows.spawn_palette_open = true;
     ui_state_debug_windows.zone_list_open = true;
 }
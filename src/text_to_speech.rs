@@ -0,0 +1,150 @@
+use std::sync::Mutex;
+
+use bevy::prelude::{App, EventReader, NonSendMut, Plugin, Res, Update};
+use enum_map::Enum;
+use lazy_static::lazy_static;
+
+use crate::{events::ChatboxEvent, localize, resources::AccessibilitySettings};
+
+// Drop any further text when the pending queue is this long, e.g. if the system is not running
+const TEXT_TO_SPEECH_MAX_PENDING: usize = 100;
+
+lazy_static! {
+    static ref TEXT_TO_SPEECH_PENDING: Mutex<Vec<(TextToSpeechChannel, String)>> =
+        Mutex::new(Vec::new());
+}
+
+#[derive(Enum, Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TextToSpeechChannel {
+    Say,
+    Shout,
+    Whisper,
+    Announce,
+    System,
+    Quest,
+    Dialog,
+    MessageBox,
+}
+
+impl TextToSpeechChannel {
+    /// Dialogs and message boxes replace whatever is currently being spoken,
+    /// chat messages wait for the previous message to finish.
+    fn interrupt(self) -> bool {
+        matches!(
+            self,
+            TextToSpeechChannel::Dialog | TextToSpeechChannel::MessageBox
+        )
+    }
+}
+
+/// Queues text to be sent to the platform text to speech or screen reader,
+/// if it is enabled for the channel in AccessibilitySettings.
+pub fn speak(channel: TextToSpeechChannel, text: &str) {
+    if text.trim().is_empty() {
+        return;
+    }
+
+    if let Ok(mut pending) = TEXT_TO_SPEECH_PENDING.lock() {
+        if pending.len() < TEXT_TO_SPEECH_MAX_PENDING {
+            pending.push((channel, text.to_string()));
+        }
+    }
+}
+
+#[derive(Default)]
+struct TextToSpeech {
+    #[cfg(feature = "text-to-speech")]
+    tts: Option<tts::Tts>,
+    failed: bool,
+}
+
+impl TextToSpeech {
+    #[cfg(feature = "text-to-speech")]
+    fn speak(&mut self, text: &str, interrupt: bool) {
+        if self.failed {
+            return;
+        }
+
+        // Only connect to the platform speech service once it is first used
+        if self.tts.is_none() {
+            match tts::Tts::default() {
+                Ok(tts) => self.tts = Some(tts),
+                Err(error) => {
+                    log::error!("Failed to initialise text to speech with error: {}", error);
+                    self.failed = true;
+                    return;
+                }
+            }
+        }
+
+        if let Some(tts) = self.tts.as_mut() {
+            if let Err(error) = tts.speak(text, interrupt) {
+                log::warn!("Failed to speak text with error: {}", error);
+            }
+        }
+    }
+
+    #[cfg(not(feature = "text-to-speech"))]
+    fn speak(&mut self, _text: &str, _interrupt: bool) {
+        if !self.failed {
+            log::warn!("Text to speech is not available without the text-to-speech feature");
+            self.failed = true;
+        }
+    }
+}
+
+fn text_to_speech_system(
+    accessibility_settings: Res<AccessibilitySettings>,
+    mut chatbox_events: EventReader<ChatboxEvent>,
+    mut text_to_speech: NonSendMut<TextToSpeech>,
+) {
+    for event in chatbox_events.iter() {
+        let (channel, text) = match event {
+            ChatboxEvent::Say(name, text) => (
+                TextToSpeechChannel::Say,
+                localize!("tts-say", name = name.as_str(), text = text.as_str()),
+            ),
+            ChatboxEvent::Shout(name, text) => (
+                TextToSpeechChannel::Shout,
+                localize!("tts-shout", name = name.as_str(), text = text.as_str()),
+            ),
+            ChatboxEvent::Whisper(name, text) => (
+                TextToSpeechChannel::Whisper,
+                localize!("tts-whisper", name = name.as_str(), text = text.as_str()),
+            ),
+            ChatboxEvent::Announce(Some(name), text) => (
+                TextToSpeechChannel::Announce,
+                localize!("tts-announce", name = name.as_str(), text = text.as_str()),
+            ),
+            ChatboxEvent::Announce(None, text) => (TextToSpeechChannel::Announce, text.clone()),
+            ChatboxEvent::System(text) => (TextToSpeechChannel::System, text.clone()),
+            ChatboxEvent::Quest(text) => (TextToSpeechChannel::Quest, text.clone()),
+        };
+        speak(channel, &text);
+    }
+
+    let pending = TEXT_TO_SPEECH_PENDING
+        .lock()
+        .map(|mut pending| std::mem::take(&mut *pending))
+        .unwrap_or_default();
+    if !accessibility_settings.text_to_speech_enabled {
+        return;
+    }
+
+    for (channel, text) in pending {
+        if accessibility_settings.text_to_speech_channels[channel] {
+            text_to_speech.speak(&text, channel.interrupt());
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct TextToSpeechPlugin;
+
+impl Plugin for TextToSpeechPlugin {
+    fn build(&self, app: &mut App) {
+        // The platform speech APIs are not thread safe, so only use them from the main thread
+        app.insert_non_send_resource(TextToSpeech::default())
+            .add_systems(Update, text_to_speech_system);
+    }
+}
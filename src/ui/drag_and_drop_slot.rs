@@ -28,6 +28,8 @@ pub struct DragAndDropSlot<'a> {
     sprite: Option<UiSprite>,
     socket_sprite: Option<UiSprite>,
     broken: bool,
+    locked: bool,
+    disabled: bool,
     cooldown_percent: Option<f32>,
     quantity: Option<usize>,
     quantity_margin: f32,
@@ -56,6 +58,8 @@ impl<'a> DragAndDropSlot<'a> {
             sprite,
             socket_sprite,
             broken,
+            locked: false,
+            disabled: false,
             cooldown_percent,
             quantity,
             quantity_margin: 2.0,
@@ -156,6 +160,8 @@ impl<'a> DragAndDropSlot<'a> {
             sprite,
             socket_sprite,
             broken,
+            locked: false,
+            disabled: cooldown_percent.is_some(),
             cooldown_percent,
             quantity,
             quantity_margin: 2.0,
@@ -201,6 +207,8 @@ impl<'a> DragAndDropSlot<'a> {
             sprite,
             socket_sprite: None,
             broken: false,
+            locked: false,
+            disabled: false,
             cooldown_percent,
             quantity: None,
             quantity_margin: 2.0,
@@ -209,9 +217,17 @@ impl<'a> DragAndDropSlot<'a> {
             dropped_item: Some(dropped_item),
         }
     }
+
+    pub fn with_locked(mut self, locked: bool) -> Self {
+        self.locked = locked;
+        self
+    }
 }
 
-fn generate_cooldown_mesh(cooldown: f32, content_rect: egui::Rect) -> egui::epaint::Mesh {
+pub(crate) fn generate_cooldown_mesh(
+    cooldown: f32,
+    content_rect: egui::Rect,
+) -> egui::epaint::Mesh {
     use egui::epaint::*;
 
     let segment_size = Vec2::new(content_rect.width() / 2.0, content_rect.height() / 2.0);
@@ -301,6 +317,31 @@ fn generate_cooldown_mesh(cooldown: f32, content_rect: egui::Rect) -> egui::epai
     mesh
 }
 
+fn draw_padlock(painter: &egui::Painter, content_rect: egui::Rect) {
+    use egui::epaint::*;
+
+    let body_rect = Rect::from_min_size(
+        Pos2::new(content_rect.min.x + 2.0, content_rect.max.y - 9.0),
+        Vec2::new(10.0, 7.0),
+    );
+    let shackle_stroke = Stroke::new(1.5, Color32::from_rgb(220, 220, 220));
+    painter.circle_stroke(
+        Pos2::new(body_rect.center().x, body_rect.min.y),
+        3.0,
+        shackle_stroke,
+    );
+    painter.rect_filled(
+        body_rect,
+        Rounding::same(1.0),
+        Color32::from_rgb(220, 180, 50),
+    );
+    painter.rect_stroke(
+        body_rect,
+        Rounding::same(1.0),
+        Stroke::new(1.0, Color32::from_rgb(60, 40, 0)),
+    );
+}
+
 impl<'w> DragAndDropSlot<'w> {
     pub fn draw(&self, ui: &mut egui::Ui, accepts_dragged_item: bool) -> (bool, egui::Response) {
         let (rect, response) = ui.allocate_exact_size(
@@ -341,10 +382,13 @@ impl<'w> DragAndDropSlot<'w> {
                 mesh.add_rect_with_uv(
                     content_rect,
                     sprite.uv,
-                    if !self.broken {
-                        egui::Color32::WHITE
-                    } else {
+                    if self.broken {
                         egui::Color32::LIGHT_RED
+                    } else if self.disabled {
+                        // Consumables whose cooldown group is active are greyed out
+                        egui::Color32::from_gray(110)
+                    } else {
+                        egui::Color32::WHITE
                     },
                 );
                 ui.painter().add(Shape::mesh(mesh));
@@ -362,6 +406,10 @@ impl<'w> DragAndDropSlot<'w> {
                     ui.painter().add(Shape::mesh(mesh));
                 }
 
+                if self.locked {
+                    draw_padlock(ui.painter(), content_rect);
+                }
+
                 if let Some(cooldown_percent) = self.cooldown_percent {
                     ui.painter().add(Shape::mesh(generate_cooldown_mesh(
                         cooldown_percent,
@@ -1,13 +1,23 @@
 mod dialog_loader;
 mod drag_and_drop_slot;
+mod money_input;
 mod tooltips;
+mod ui_achievements_system;
+mod ui_announcement_system;
 mod ui_bank_system;
+mod ui_calendar_system;
+mod ui_cast_bar_system;
 mod ui_character_create_system;
 mod ui_character_info_system;
 mod ui_character_select_name_tag_system;
 mod ui_character_select_system;
+mod ui_chat_bubble_system;
 mod ui_chatbox_system;
 mod ui_clan_system;
+mod ui_clock_system;
+mod ui_cooldown_alert_system;
+mod ui_craft_system;
+mod ui_crash_report_system;
 mod ui_create_clan;
 mod ui_debug_camera_info_system;
 mod ui_debug_client_entity_list_system;
@@ -16,73 +26,273 @@ mod ui_debug_diagnostics_system;
 mod ui_debug_dialog_list;
 mod ui_debug_effect_list;
 mod ui_debug_entity_inspector_system;
+mod ui_debug_frame_profiler_system;
 mod ui_debug_item_list_system;
+mod ui_debug_log_viewer_system;
+mod ui_debug_lua_vm_system;
+mod ui_debug_memory_usage_system;
 mod ui_debug_npc_list_system;
+mod ui_debug_packet_inspector_system;
 mod ui_debug_physics;
+mod ui_debug_render_statistics_system;
 mod ui_debug_render_system;
 mod ui_debug_skill_list_system;
+mod ui_debug_spawn_palette_system;
+mod ui_debug_time_control_system;
 mod ui_debug_window_system;
 mod ui_debug_zone_lighting_system;
 mod ui_debug_zone_list_system;
 mod ui_debug_zone_time_system;
 mod ui_drag_and_drop_system;
+mod ui_friends_system;
 mod ui_game_menu_system;
 mod ui_hotbar_system;
+mod ui_hud_layout_system;
+mod ui_inspect_character_system;
 mod ui_inventory_system;
 mod ui_item_drop_name_system;
+mod ui_level_up_system;
 mod ui_login_system;
 mod ui_message_box_system;
 mod ui_minimap_system;
+mod ui_money_input_dialog_system;
 mod ui_npc_store_system;
 mod ui_number_input_dialog_system;
 mod ui_party_option_system;
 mod ui_party_system;
+mod ui_personal_store_scanner_system;
 mod ui_personal_store_system;
 mod ui_player_info_system;
 mod ui_quest_list_system;
+mod ui_repair_system;
 mod ui_respawn_system;
+mod ui_screen_transition_system;
+mod ui_screenshot_harness_system;
 mod ui_selected_target_system;
 mod ui_server_select_system;
+mod ui_session_statistics_system;
 mod ui_settings_system;
+mod ui_setup_wizard_system;
 mod ui_skill_list_system;
 mod ui_skill_tree_system;
 mod ui_sound_event_system;
+mod ui_status_effect_text_system;
 mod ui_status_effects_system;
+mod ui_waypoints_system;
+mod ui_window_manager_system;
 mod ui_window_sound_system;
 pub mod widgets;
 
 #[derive(Default, Resource)]
 pub struct UiStateWindows {
+    pub achievements_open: bool,
+    pub calendar_open: bool,
     pub character_info_open: bool,
     pub clan_open: bool,
+    pub friends_open: bool,
     pub inventory_open: bool,
     pub skill_list_open: bool,
     pub skill_tree_open: bool,
     pub quest_list_open: bool,
+    pub session_statistics_open: bool,
     pub settings_open: bool,
+    pub waypoints_open: bool,
     pub menu_open: bool,
     pub party_open: bool,
     pub party_options_open: bool,
+    pub personal_store_scanner_open: bool,
 
     // Below are only opened via in game events rather than directly
     pub bank_open: bool,
+    pub craft_open: bool,
+    pub repair_open: bool,
     pub create_clan_open: bool,
+    pub npc_store_open: bool,
+    pub personal_store_open: bool,
+    pub message_box_open: bool,
+    pub inspect_character: Option<Entity>,
 
     // Test ui
     pub selected_target_ui_open: bool,
+
+    /// Open windows in the order they were opened, the last is the topmost which is closed first
+    /// when pressing escape.
+    pub window_stack: Vec<UiWindow>,
+}
+
+/// Windows which can be toggled by hotkey or closed with escape. The party frame is
+/// always open while in a party so is not included.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum UiWindow {
+    Achievements,
+    Calendar,
+    CharacterInfo,
+    Clan,
+    Friends,
+    Inventory,
+    SkillList,
+    SkillTree,
+    QuestList,
+    SessionStatistics,
+    Settings,
+    Waypoints,
+    Menu,
+    PartyOptions,
+    PersonalStoreScanner,
+    Bank,
+    Craft,
+    Repair,
+    CreateClan,
+    NpcStore,
+    PersonalStore,
+    MessageBox,
+    InspectCharacter,
+}
+
+impl UiWindow {
+    pub const ALL: [UiWindow; 23] = [
+        UiWindow::Achievements,
+        UiWindow::Calendar,
+        UiWindow::CharacterInfo,
+        UiWindow::Clan,
+        UiWindow::Friends,
+        UiWindow::Inventory,
+        UiWindow::SkillList,
+        UiWindow::SkillTree,
+        UiWindow::QuestList,
+        UiWindow::SessionStatistics,
+        UiWindow::Settings,
+        UiWindow::Waypoints,
+        UiWindow::Menu,
+        UiWindow::PartyOptions,
+        UiWindow::PersonalStoreScanner,
+        UiWindow::Bank,
+        UiWindow::Craft,
+        UiWindow::Repair,
+        UiWindow::CreateClan,
+        UiWindow::NpcStore,
+        UiWindow::PersonalStore,
+        UiWindow::MessageBox,
+        UiWindow::InspectCharacter,
+    ];
+}
+
+impl UiStateWindows {
+    pub fn is_open(&self, window: UiWindow) -> bool {
+        match window {
+            UiWindow::Achievements => self.achievements_open,
+            UiWindow::Calendar => self.calendar_open,
+            UiWindow::CharacterInfo => self.character_info_open,
+            UiWindow::Clan => self.clan_open,
+            UiWindow::Friends => self.friends_open,
+            UiWindow::Inventory => self.inventory_open,
+            UiWindow::SkillList => self.skill_list_open,
+            UiWindow::SkillTree => self.skill_tree_open,
+            UiWindow::QuestList => self.quest_list_open,
+            UiWindow::SessionStatistics => self.session_statistics_open,
+            UiWindow::Settings => self.settings_open,
+            UiWindow::Waypoints => self.waypoints_open,
+            UiWindow::Menu => self.menu_open,
+            UiWindow::PartyOptions => self.party_options_open,
+            UiWindow::PersonalStoreScanner => self.personal_store_scanner_open,
+            UiWindow::Bank => self.bank_open,
+            UiWindow::Craft => self.craft_open,
+            UiWindow::Repair => self.repair_open,
+            UiWindow::CreateClan => self.create_clan_open,
+            UiWindow::NpcStore => self.npc_store_open,
+            UiWindow::PersonalStore => self.personal_store_open,
+            UiWindow::MessageBox => self.message_box_open,
+            UiWindow::InspectCharacter => self.inspect_character.is_some(),
+        }
+    }
+
+    pub fn set_open(&mut self, window: UiWindow, open: bool) {
+        match window {
+            UiWindow::Achievements => self.achievements_open = open,
+            UiWindow::Calendar => self.calendar_open = open,
+            UiWindow::CharacterInfo => self.character_info_open = open,
+            UiWindow::Clan => self.clan_open = open,
+            UiWindow::Friends => self.friends_open = open,
+            UiWindow::Inventory => self.inventory_open = open,
+            UiWindow::SkillList => self.skill_list_open = open,
+            UiWindow::SkillTree => self.skill_tree_open = open,
+            UiWindow::QuestList => self.quest_list_open = open,
+            UiWindow::SessionStatistics => self.session_statistics_open = open,
+            UiWindow::Settings => self.settings_open = open,
+            UiWindow::Waypoints => self.waypoints_open = open,
+            UiWindow::Menu => self.menu_open = open,
+            UiWindow::PartyOptions => self.party_options_open = open,
+            UiWindow::PersonalStoreScanner => self.personal_store_scanner_open = open,
+            UiWindow::Bank => self.bank_open = open,
+            UiWindow::Craft => self.craft_open = open,
+            UiWindow::Repair => self.repair_open = open,
+            UiWindow::CreateClan => self.create_clan_open = open,
+            UiWindow::NpcStore => self.npc_store_open = open,
+            UiWindow::PersonalStore => self.personal_store_open = open,
+            UiWindow::MessageBox => self.message_box_open = open,
+            UiWindow::InspectCharacter => {
+                if !open {
+                    self.inspect_character = None;
+                }
+            }
+        }
+    }
+
+    pub fn toggle(&mut self, window: UiWindow) {
+        self.set_open(window, !self.is_open(window));
+    }
+
+    /// Closes the topmost open window, returns false if there were no open windows.
+    pub fn close_topmost(&mut self) -> bool {
+        self.update_window_stack();
+
+        if let Some(window) = self.window_stack.pop() {
+            self.set_open(window, false);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Windows are opened and closed by directly changing their state, so this keeps the
+    /// window stack in sync with those changes.
+    pub fn update_window_stack(&mut self) {
+        let mut window_stack = std::mem::take(&mut self.window_stack);
+        window_stack.retain(|window| self.is_open(*window));
+
+        for window in UiWindow::ALL {
+            if self.is_open(window) && !window_stack.contains(&window) {
+                window_stack.push(window);
+            }
+        }
+
+        self.window_stack = window_stack;
+    }
 }
 
-use bevy::prelude::Resource;
+use bevy::prelude::{Entity, Resource};
 pub use dialog_loader::{load_dialog_sprites_system, DialogInstance, DialogLoader};
 pub use drag_and_drop_slot::{DragAndDropId, DragAndDropSlot};
-pub use tooltips::{get_item_name_color, ui_add_item_tooltip, ui_add_skill_tooltip};
+pub use money_input::{format_money, parse_money, validate_money, MoneyInput, MoneyInputError};
+pub use tooltips::{
+    get_item_name_color, ui_add_item_sell_price, ui_add_item_tooltip, ui_add_skill_tooltip,
+};
+pub use ui_achievements_system::ui_achievements_system;
+pub use ui_announcement_system::ui_announcement_system;
 pub use ui_bank_system::ui_bank_system;
+pub use ui_calendar_system::{format_countdown, ui_calendar_system};
+pub use ui_cast_bar_system::ui_cast_bar_system;
 pub use ui_character_create_system::ui_character_create_system;
 pub use ui_character_info_system::ui_character_info_system;
 pub use ui_character_select_name_tag_system::ui_character_select_name_tag_system;
 pub use ui_character_select_system::ui_character_select_system;
+pub use ui_chat_bubble_system::ui_chat_bubble_system;
 pub use ui_chatbox_system::ui_chatbox_system;
 pub use ui_clan_system::ui_clan_system;
+pub use ui_clock_system::ui_clock_system;
+pub use ui_cooldown_alert_system::ui_cooldown_alert_system;
+pub use ui_craft_system::ui_craft_system;
+pub use ui_crash_report_system::ui_crash_report_system;
 pub use ui_create_clan::ui_create_clan_system;
 pub use ui_debug_camera_info_system::ui_debug_camera_info_system;
 pub use ui_debug_client_entity_list_system::ui_debug_client_entity_list_system;
@@ -91,37 +301,62 @@ pub use ui_debug_diagnostics_system::ui_debug_diagnostics_system;
 pub use ui_debug_dialog_list::ui_debug_dialog_list_system;
 pub use ui_debug_effect_list::ui_debug_effect_list_system;
 pub use ui_debug_entity_inspector_system::ui_debug_entity_inspector_system;
+pub use ui_debug_frame_profiler_system::ui_debug_frame_profiler_system;
 pub use ui_debug_item_list_system::ui_debug_item_list_system;
+pub use ui_debug_log_viewer_system::ui_debug_log_viewer_system;
+pub use ui_debug_lua_vm_system::ui_debug_lua_vm_system;
+pub use ui_debug_memory_usage_system::ui_debug_memory_usage_system;
 pub use ui_debug_npc_list_system::ui_debug_npc_list_system;
+pub use ui_debug_packet_inspector_system::ui_debug_packet_inspector_system;
 pub use ui_debug_physics::ui_debug_physics_system;
+pub use ui_debug_render_statistics_system::ui_debug_render_statistics_system;
 pub use ui_debug_render_system::ui_debug_render_system;
 pub use ui_debug_skill_list_system::ui_debug_skill_list_system;
-pub use ui_debug_window_system::{ui_debug_menu_system, UiStateDebugWindows};
+pub use ui_debug_spawn_palette_system::ui_debug_spawn_palette_system;
+pub use ui_debug_time_control_system::ui_debug_time_control_system;
+pub use ui_debug_window_system::{
+    show_detachable_debug_window, ui_debug_detached_windows_system, ui_debug_menu_system,
+    DetachableDebugWindow, UiStateDebugWindows,
+};
 pub use ui_debug_zone_lighting_system::ui_debug_zone_lighting_system;
 pub use ui_debug_zone_list_system::ui_debug_zone_list_system;
 pub use ui_debug_zone_time_system::ui_debug_zone_time_system;
 pub use ui_drag_and_drop_system::{ui_drag_and_drop_system, UiStateDragAndDrop};
+pub use ui_friends_system::ui_friends_system;
 pub use ui_game_menu_system::ui_game_menu_system;
 pub use ui_hotbar_system::ui_hotbar_system;
+pub use ui_hud_layout_system::ui_hud_layout_system;
+pub use ui_inspect_character_system::ui_inspect_character_system;
 pub use ui_inventory_system::ui_inventory_system;
 pub use ui_item_drop_name_system::ui_item_drop_name_system;
+pub use ui_level_up_system::ui_level_up_system;
 pub use ui_login_system::ui_login_system;
 pub use ui_message_box_system::ui_message_box_system;
 pub use ui_minimap_system::ui_minimap_system;
+pub use ui_money_input_dialog_system::ui_money_input_dialog_system;
 pub use ui_npc_store_system::ui_npc_store_system;
 pub use ui_number_input_dialog_system::ui_number_input_dialog_system;
 pub use ui_party_option_system::ui_party_option_system;
 pub use ui_party_system::ui_party_system;
+pub use ui_personal_store_scanner_system::ui_personal_store_scanner_system;
 pub use ui_personal_store_system::ui_personal_store_system;
 pub use ui_player_info_system::ui_player_info_system;
 pub use ui_quest_list_system::ui_quest_list_system;
+pub use ui_repair_system::ui_repair_system;
 pub use ui_respawn_system::ui_respawn_system;
+pub use ui_screen_transition_system::ui_screen_transition_system;
+pub use ui_screenshot_harness_system::ui_screenshot_harness_system;
 pub use ui_selected_target_system::ui_selected_target_system;
 pub use ui_server_select_system::ui_server_select_system;
-pub use ui_settings_system::ui_settings_system;
+pub use ui_session_statistics_system::ui_session_statistics_system;
+pub use ui_settings_system::{ui_settings_system, UiStateSettings};
+pub use ui_setup_wizard_system::ui_setup_wizard_system;
 pub use ui_skill_list_system::ui_skill_list_system;
 pub use ui_skill_tree_system::ui_skill_tree_system;
 pub use ui_sound_event_system::{ui_sound_event_system, UiSoundEvent};
-pub use ui_status_effects_system::ui_status_effects_system;
+pub use ui_status_effect_text_system::ui_status_effect_text_system;
+pub use ui_status_effects_system::{ui_add_status_effect_icon, ui_status_effects_system};
+pub use ui_waypoints_system::ui_waypoints_system;
+pub use ui_window_manager_system::ui_window_manager_system;
 pub use ui_window_sound_system::ui_window_sound_system;
 pub use widgets::DataBindings;
@@ -0,0 +1,241 @@
+use bevy_egui::egui;
+
+use rose_game_common::components::Money;
+
+use crate::localize;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum MoneyInputError {
+    Empty,
+    Invalid,
+    Overflow,
+    InsufficientBalance,
+}
+
+impl MoneyInputError {
+    pub fn message(&self) -> String {
+        match self {
+            MoneyInputError::Empty => localize!("money-input-empty"),
+            MoneyInputError::Invalid => localize!("money-input-invalid"),
+            MoneyInputError::Overflow => localize!("money-input-overflow"),
+            MoneyInputError::InsufficientBalance => localize!("money-input-insufficient"),
+        }
+    }
+}
+
+/// Formats money with thousands separators, e.g. 1234567 as "1,234,567".
+pub fn format_money(money: Money) -> String {
+    let digits = money.0.unsigned_abs().to_string();
+    let mut result = String::with_capacity(digits.len() + digits.len() / 3 + 1);
+    if money.0 < 0 {
+        result.push('-');
+    }
+
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            result.push(',');
+        }
+        result.push(c);
+    }
+
+    result
+}
+
+/// Parses money typed by the user, which may contain thousands separators and
+/// a k or m suffix, e.g. "1,500", "1.5k" or "2m".
+pub fn parse_money(text: &str) -> Result<Money, MoneyInputError> {
+    let text: String = text
+        .chars()
+        .filter(|c| *c != ',' && !c.is_whitespace())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    if text.is_empty() {
+        return Err(MoneyInputError::Empty);
+    }
+
+    let (number, multiplier) = if let Some(number) = text.strip_suffix('k') {
+        (number, 1_000)
+    } else if let Some(number) = text.strip_suffix('m') {
+        (number, 1_000_000)
+    } else {
+        (text.as_str(), 1)
+    };
+
+    let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+    if (whole.is_empty() && fraction.is_empty())
+        || !whole.chars().all(|c| c.is_ascii_digit())
+        || !fraction.chars().all(|c| c.is_ascii_digit())
+    {
+        return Err(MoneyInputError::Invalid);
+    }
+
+    let parse_digits = |digits: &str| -> Result<i64, MoneyInputError> {
+        digits.chars().try_fold(0i64, |value, c| {
+            value
+                .checked_mul(10)
+                .and_then(|value| value.checked_add(c as i64 - '0' as i64))
+                .ok_or(MoneyInputError::Overflow)
+        })
+    };
+
+    let mut value = parse_digits(whole)?
+        .checked_mul(multiplier)
+        .ok_or(MoneyInputError::Overflow)?;
+
+    if !fraction.is_empty() {
+        // Only allow fractions which are a whole amount of money, e.g. 1.5k but not 1.5
+        let fraction_digits =
+            u32::try_from(fraction.len()).map_err(|_| MoneyInputError::Invalid)?;
+        let divisor = 10i64
+            .checked_pow(fraction_digits)
+            .ok_or(MoneyInputError::Invalid)?;
+        let fraction_value = parse_digits(fraction)?
+            .checked_mul(multiplier)
+            .ok_or(MoneyInputError::Overflow)?;
+        if fraction_value % divisor != 0 {
+            return Err(MoneyInputError::Invalid);
+        }
+
+        value = value
+            .checked_add(fraction_value / divisor)
+            .ok_or(MoneyInputError::Overflow)?;
+    }
+
+    Ok(Money(value))
+}
+
+/// Parses the money and checks it is not more than the balance, if there is one.
+pub fn validate_money(text: &str, balance: Option<Money>) -> Result<Money, MoneyInputError> {
+    let money = parse_money(text)?;
+
+    if balance.map_or(false, |balance| money.0 > balance.0) {
+        return Err(MoneyInputError::InsufficientBalance);
+    }
+
+    Ok(money)
+}
+
+/// A text input for an amount of money which shows the parsed amount, or why it
+/// is not valid, and optionally the current balance.
+pub struct MoneyInput<'a> {
+    text: &'a mut String,
+    balance: Option<Money>,
+}
+
+impl<'a> MoneyInput<'a> {
+    pub fn new(text: &'a mut String) -> Self {
+        Self {
+            text,
+            balance: None,
+        }
+    }
+
+    pub fn balance(mut self, balance: Money) -> Self {
+        self.balance = Some(balance);
+        self
+    }
+}
+
+impl<'a> egui::Widget for MoneyInput<'a> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        ui.vertical(|ui| {
+            let response = ui.add(
+                egui::TextEdit::singleline(self.text)
+                    .hint_text("0")
+                    .char_limit(24),
+            );
+
+            match validate_money(self.text, self.balance) {
+                Ok(money) => {
+                    ui.label(localize!("money-input-amount", money = format_money(money)));
+                }
+                Err(MoneyInputError::Empty) => {
+                    ui.label(localize!("money-input-hint"));
+                }
+                Err(error) => {
+                    ui.colored_label(egui::Color32::RED, error.message());
+                }
+            }
+
+            if let Some(balance) = self.balance {
+                ui.label(localize!(
+                    "money-input-balance",
+                    money = format_money(balance)
+                ));
+            }
+
+            response
+        })
+        .inner
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(text: &str) -> Result<i64, MoneyInputError> {
+        parse_money(text).map(|money| money.0)
+    }
+
+    #[test]
+    fn format_money_separators() {
+        assert_eq!(format_money(Money(0)), "0");
+        assert_eq!(format_money(Money(999)), "999");
+        assert_eq!(format_money(Money(1000)), "1,000");
+        assert_eq!(format_money(Money(1234567)), "1,234,567");
+        assert_eq!(format_money(Money(-1234567)), "-1,234,567");
+        assert_eq!(format_money(Money(i64::MIN)), "-9,223,372,036,854,775,808");
+    }
+
+    #[test]
+    fn parse_money_separators() {
+        assert_eq!(parse("1500"), Ok(1500));
+        assert_eq!(parse("1,500"), Ok(1500));
+        assert_eq!(parse(" 1 234 567 "), Ok(1234567));
+    }
+
+    #[test]
+    fn parse_money_suffixes() {
+        assert_eq!(parse("2k"), Ok(2000));
+        assert_eq!(parse("1.5k"), Ok(1500));
+        assert_eq!(parse("1.5K"), Ok(1500));
+        assert_eq!(parse("2m"), Ok(2000000));
+        assert_eq!(parse("0.25m"), Ok(250000));
+        assert_eq!(parse(".5k"), Ok(500));
+    }
+
+    #[test]
+    fn parse_money_invalid() {
+        assert_eq!(parse(""), Err(MoneyInputError::Empty));
+        assert_eq!(parse(" , "), Err(MoneyInputError::Empty));
+        assert_eq!(parse("1.5"), Err(MoneyInputError::Invalid));
+        assert_eq!(parse("1.0005k"), Err(MoneyInputError::Invalid));
+        assert_eq!(parse("."), Err(MoneyInputError::Invalid));
+        assert_eq!(parse("k"), Err(MoneyInputError::Invalid));
+        assert_eq!(parse("-5"), Err(MoneyInputError::Invalid));
+        assert_eq!(parse("12a"), Err(MoneyInputError::Invalid));
+        assert_eq!(parse("1kk"), Err(MoneyInputError::Invalid));
+    }
+
+    #[test]
+    fn parse_money_overflow() {
+        assert_eq!(parse("9223372036854775807"), Ok(i64::MAX));
+        assert_eq!(parse("9223372036854775808"), Err(MoneyInputError::Overflow));
+        assert_eq!(parse("9223372036854776k"), Err(MoneyInputError::Overflow));
+        assert_eq!(parse("9300000000000m"), Err(MoneyInputError::Overflow));
+    }
+
+    #[test]
+    fn validate_money_balance() {
+        assert_eq!(
+            validate_money("1k", Some(Money(1000))).map(|money| money.0),
+            Ok(1000)
+        );
+        assert_eq!(
+            validate_money("1.001k", Some(Money(1000))).map(|money| money.0),
+            Err(MoneyInputError::InsufficientBalance)
+        );
+        assert_eq!(validate_money("1m", None).map(|money| money.0), Ok(1000000));
+    }
+}
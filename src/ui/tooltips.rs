@@ -10,10 +10,16 @@ use rose_data::{
 };
 use rose_game_common::components::{
     AbilityValues, CharacterInfo, Equipment, ExperiencePoints, HealthPoints, Inventory, Level,
-    ManaPoints, MoveSpeed, SkillList, SkillPoints, Stamina, StatPoints, Team, UnionMembership,
+    ManaPoints, Money, MoveSpeed, SkillList, SkillPoints, Stamina, StatPoints, Team,
+    UnionMembership,
 };
 
-use crate::{bundles::ability_values_get_value, resources::GameData};
+use crate::{
+    bundles::ability_values_get_value,
+    localize,
+    resources::{GameData, WorldRates},
+    ui::format_money,
+};
 
 const TOOLTIP_MAX_WIDTH: f32 = 300.0;
 
@@ -133,6 +139,52 @@ fn add_item_defence(
     ));
 }
 
+fn add_equipment_item_grade_bonus(
+    ui: &mut egui::Ui,
+    game_data: &GameData,
+    equipment_item: &EquipmentItem,
+    grade_data: Option<&ItemGradeData>,
+) {
+    let Some(grade_data) = grade_data else {
+        return;
+    };
+    if equipment_item.grade == 0 {
+        return;
+    }
+
+    let bonuses: &[(AbilityType, i32)] = match equipment_item.item.item_type {
+        ItemType::Weapon => &[
+            (AbilityType::Attack, grade_data.attack as i32),
+            (AbilityType::Hit, grade_data.hit as i32),
+        ],
+        _ => &[
+            (AbilityType::Defence, grade_data.defence as i32),
+            (AbilityType::Resistance, grade_data.resistance as i32),
+            (AbilityType::Avoid, grade_data.avoid as i32),
+        ],
+    };
+
+    let mut bonuses_text = String::new();
+    for &(ability_type, value) in bonuses.iter().filter(|(_, value)| *value != 0) {
+        write!(
+            &mut bonuses_text,
+            " {} +{}",
+            game_data.string_database.get_ability_type(ability_type),
+            value
+        )
+        .ok();
+    }
+
+    ui.colored_label(
+        egui::Color32::from_rgb(255, 200, 100),
+        localize!(
+            "item-tooltip-grade-bonus",
+            grade = equipment_item.grade,
+            bonuses = bonuses_text
+        ),
+    );
+}
+
 fn add_item_add_ability(ui: &mut egui::Ui, game_data: &GameData, item_data: &BaseItemData) {
     for &(ability_type, value) in item_data.add_ability.iter() {
         ui.colored_label(
@@ -157,6 +209,9 @@ fn add_equipment_item_add_appraisal(
     equipment_item: &EquipmentItem,
 ) {
     if equipment_item.gem == 0 {
+        if equipment_item.has_socket {
+            ui.colored_label(egui::Color32::GRAY, localize!("item-tooltip-empty-socket"));
+        }
         return;
     }
 
@@ -388,6 +443,7 @@ pub fn ui_add_item_tooltip(
                         weapon_item_data.attack_range / 100
                     ));
 
+                    add_equipment_item_grade_bonus(ui, game_data, equipment_item, grade_data);
                     add_item_add_ability(ui, game_data, item_data);
                     add_equipment_item_add_appraisal(ui, game_data, equipment_item);
                     add_item_equip_requirement(ui, game_data, player, item_data);
@@ -425,6 +481,7 @@ pub fn ui_add_item_tooltip(
                         add_item_defence(ui, game_data, item_data, grade_data);
                     }
 
+                    add_equipment_item_grade_bonus(ui, game_data, equipment_item, grade_data);
                     add_item_add_ability(ui, game_data, item_data);
                     add_equipment_item_add_appraisal(ui, game_data, equipment_item);
                     add_item_equip_requirement(ui, game_data, player, item_data);
@@ -488,6 +545,7 @@ pub fn ui_add_item_tooltip(
                         }
                     }
 
+                    add_equipment_item_grade_bonus(ui, game_data, equipment_item, grade_data);
                     add_item_add_ability(ui, game_data, item_data);
                     add_equipment_item_add_appraisal(ui, game_data, equipment_item);
                     add_item_equip_requirement(ui, game_data, player, item_data);
@@ -616,6 +674,42 @@ pub fn ui_add_item_tooltip(
     }
 }
 
+/// Shows what an NPC store would pay for the item, using the player's sell rate
+/// and the current world rates.
+pub fn ui_add_item_sell_price(
+    ui: &mut egui::Ui,
+    game_data: &GameData,
+    player: Option<&PlayerTooltipQueryItem>,
+    world_rates: Option<&WorldRates>,
+    item: &Item,
+) {
+    let (Some(player), Some(world_rates)) = (player, world_rates) else {
+        return;
+    };
+
+    let Some(sell_price) = game_data
+        .ability_value_calculator
+        .calculate_npc_store_item_sell_price(
+            &game_data.items,
+            item,
+            player.ability_values.get_npc_store_sell_rate(),
+            world_rates.world_price_rate,
+            world_rates.item_price_rate,
+            world_rates.town_price_rate,
+        )
+    else {
+        return;
+    };
+
+    ui.colored_label(
+        egui::Color32::YELLOW,
+        localize!(
+            "item-tooltip-sell-price",
+            money = format_money(Money(sell_price as i64 * item.get_quantity() as i64))
+        ),
+    );
+}
+
 fn add_skill_name(ui: &mut egui::Ui, game_data: &GameData, skill_data: &SkillData) {
     let text = if skill_data.name.is_empty() {
         format!("??? [Skill ID: {}]", skill_data.id.get())
@@ -0,0 +1,80 @@
+use bevy::prelude::ResMut;
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    resources::{Achievements, ACHIEVEMENTS},
+    ui::UiStateWindows,
+};
+
+pub fn ui_achievements_system(
+    mut egui_context: EguiContexts,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    mut achievements: ResMut<Achievements>,
+) {
+    if !ui_state_windows.achievements_open {
+        return;
+    }
+
+    let achievements = &mut *achievements;
+    let mut selected_title = achievements
+        .get_selected_title()
+        .map(|title| title.to_string());
+
+    egui::Window::new("Achievements")
+        .open(&mut ui_state_windows.achievements_open)
+        .resizable(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Title:");
+                egui::ComboBox::from_id_source("achievement_title")
+                    .selected_text(selected_title.as_deref().unwrap_or("None"))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut selected_title, None, "None");
+                        for title in achievements.unlocked_titles() {
+                            ui.selectable_value(
+                                &mut selected_title,
+                                Some(title.to_string()),
+                                title,
+                            );
+                        }
+                    });
+            });
+
+            ui.separator();
+
+            egui::Grid::new("achievements_grid")
+                .num_columns(2)
+                .striped(true)
+                .show(ui, |ui| {
+                    for achievement in ACHIEVEMENTS.iter() {
+                        let progress = achievements
+                            .progress
+                            .get(achievement.kind)
+                            .min(achievement.required);
+
+                        ui.vertical(|ui| {
+                            if achievements.is_unlocked(achievement) {
+                                ui.label(
+                                    egui::RichText::new(achievement.name)
+                                        .color(egui::Color32::from_rgb(255, 206, 96)),
+                                );
+                            } else {
+                                ui.label(achievement.name);
+                            }
+                            ui.label(egui::RichText::new(achievement.description).small().weak());
+                        });
+                        ui.add(
+                            egui::ProgressBar::new(progress as f32 / achievement.required as f32)
+                                .desired_width(150.0)
+                                .text(format!("{} / {}", progress, achievement.required)),
+                        );
+                        ui.end_row();
+                    }
+                });
+        });
+
+    if selected_title.as_deref() != achievements.get_selected_title() {
+        achievements.progress.selected_title = selected_title;
+        achievements.changed = true;
+    }
+}
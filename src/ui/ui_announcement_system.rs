@@ -0,0 +1,169 @@
+use std::{collections::VecDeque, time::Duration};
+
+use bevy::{
+    prelude::{EventReader, Local, Res},
+    time::Time,
+};
+use bevy_egui::{egui, EguiContexts};
+use regex::Regex;
+
+use crate::events::ChatboxEvent;
+
+const ANNOUNCEMENT_DISPLAY_DURATION: Duration = Duration::from_secs(10);
+const ANNOUNCEMENT_COLOR: egui::Color32 = egui::Color32::from_rgb(255, 188, 172);
+const ANNOUNCEMENT_WARNING_COLOR: egui::Color32 = egui::Color32::from_rgb(255, 100, 100);
+
+struct Announcement {
+    name: Option<String>,
+    text: String,
+    is_warning: bool,
+    countdown: Option<Duration>,
+    countdown_end: Option<Duration>,
+    expire_time: Option<Duration>,
+}
+
+pub struct UiStateAnnouncements {
+    queue: VecDeque<Announcement>,
+    countdown_regex: Regex,
+}
+
+impl Default for UiStateAnnouncements {
+    fn default() -> Self {
+        Self {
+            queue: VecDeque::new(),
+            countdown_regex: Regex::new(r"(?i)(\d+)\s*(seconds?|secs?|minutes?|mins?)\b").unwrap(),
+        }
+    }
+}
+
+impl UiStateAnnouncements {
+    fn parse_countdown(&self, text: &str) -> Option<Duration> {
+        let captures = self.countdown_regex.captures(text)?;
+        let value = captures.get(1)?.as_str().parse::<u64>().ok()?;
+        let unit = captures.get(2)?.as_str().to_lowercase();
+
+        if unit.starts_with("min") {
+            Some(Duration::from_secs(value * 60))
+        } else {
+            Some(Duration::from_secs(value))
+        }
+    }
+}
+
+fn format_countdown(remaining: Duration) -> String {
+    let seconds = remaining.as_secs();
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+pub fn ui_announcement_system(
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiStateAnnouncements>,
+    mut chatbox_events: EventReader<ChatboxEvent>,
+    time: Res<Time>,
+) {
+    let now = time.elapsed();
+
+    for event in chatbox_events.iter() {
+        let ChatboxEvent::Announce(name, text) = event else {
+            continue;
+        };
+
+        let lowercase_text = text.to_lowercase();
+        let is_warning = ["shutdown", "restart", "maintenance"]
+            .iter()
+            .any(|keyword| lowercase_text.contains(keyword));
+        let countdown = ui_state.parse_countdown(text);
+
+        ui_state.queue.push_back(Announcement {
+            name: name.clone(),
+            text: text.clone(),
+            is_warning,
+            countdown,
+            countdown_end: None,
+            expire_time: None,
+        });
+    }
+
+    // Remove the current announcement once it has expired
+    if ui_state
+        .queue
+        .front()
+        .and_then(|announcement| announcement.expire_time)
+        .map_or(false, |expire_time| now >= expire_time)
+    {
+        ui_state.queue.pop_front();
+    }
+
+    let num_queued = ui_state.queue.len().saturating_sub(1);
+    let Some(announcement) = ui_state.queue.front_mut() else {
+        return;
+    };
+
+    if announcement.expire_time.is_none() {
+        // Start the timers when the announcement is first displayed
+        announcement.countdown_end = announcement.countdown.map(|countdown| now + countdown);
+        announcement.expire_time = Some(
+            announcement
+                .countdown_end
+                .unwrap_or(now + ANNOUNCEMENT_DISPLAY_DURATION),
+        );
+    }
+
+    let color = if announcement.is_warning {
+        ANNOUNCEMENT_WARNING_COLOR
+    } else {
+        ANNOUNCEMENT_COLOR
+    };
+    let mut dismissed = false;
+
+    egui::Area::new("announcement_banner")
+        .anchor(egui::Align2::CENTER_TOP, [0.0, 80.0])
+        .show(egui_context.ctx_mut(), |ui| {
+            egui::Frame::none()
+                .fill(egui::Color32::from_black_alpha(180))
+                .stroke(egui::Stroke::new(1.0, color))
+                .rounding(4.0)
+                .inner_margin(egui::Margin::symmetric(16.0, 8.0))
+                .show(ui, |ui| {
+                    ui.horizontal(|ui| {
+                        ui.vertical(|ui| {
+                            if let Some(name) = announcement.name.as_ref() {
+                                ui.label(egui::RichText::new(name).color(egui::Color32::WHITE));
+                            }
+
+                            ui.label(
+                                egui::RichText::new(&announcement.text)
+                                    .color(color)
+                                    .size(18.0),
+                            );
+
+                            if let Some(countdown_end) = announcement.countdown_end {
+                                ui.label(
+                                    egui::RichText::new(format_countdown(
+                                        countdown_end.saturating_sub(now),
+                                    ))
+                                    .color(egui::Color32::WHITE)
+                                    .size(16.0),
+                                );
+                            }
+
+                            if num_queued > 0 {
+                                ui.label(
+                                    egui::RichText::new(format!("+{} more", num_queued))
+                                        .color(egui::Color32::GRAY)
+                                        .small(),
+                                );
+                            }
+                        });
+
+                        if ui.small_button("X").clicked() {
+                            dismissed = true;
+                        }
+                    });
+                });
+        });
+
+    if dismissed {
+        ui_state.queue.pop_front();
+    }
+}
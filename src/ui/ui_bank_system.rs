@@ -11,7 +11,7 @@ use rose_game_common::{
 };
 
 use crate::{
-    components::{Bank, PlayerCharacter, Position},
+    components::{Bank, PlayerCharacter, Position, BANK_CHARACTER_PAGES, BANK_SLOTS_PER_PAGE},
     events::{BankEvent, PlayerCommandEvent},
     resources::{ClientEntityList, GameConnection, GameData, UiResources},
     ui::{
@@ -29,12 +29,12 @@ const IID_BTN_TAB2: i32 = 32;
 const IID_BTN_TAB3: i32 = 33;
 const IID_BTN_TAB4: i32 = 34;
 
-const BANK_SLOTS_PER_PAGE: usize = 40;
 const BANK_SLOTS_PER_ROW: usize = 8;
 
 pub struct UiStateBank {
     bank_entity: Option<Entity>,
     current_page: i32,
+    shared_page: Option<usize>,
 }
 
 impl Default for UiStateBank {
@@ -42,6 +42,7 @@ impl Default for UiStateBank {
         Self {
             bank_entity: None,
             current_page: IID_BTN_TAB1,
+            shared_page: None,
         }
     }
 }
@@ -184,9 +185,20 @@ pub fn ui_bank_system(
         }
     }
 
+    let shared_page_count = player.bank.get_shared_page_count();
+    if ui_state
+        .shared_page
+        .map_or(false, |shared_page| shared_page >= shared_page_count)
+    {
+        ui_state.shared_page = None;
+        ui_state.current_page = IID_BTN_TAB1;
+    }
+    let shared_page = ui_state.shared_page;
+    let previous_page = ui_state.current_page;
+
     let mut response_close_button = None;
 
-    egui::Window::new("Bank")
+    let window_response = egui::Window::new("Bank")
         .frame(egui::Frame::none())
         .title_bar(false)
         .resizable(false)
@@ -232,12 +244,16 @@ pub fn ui_bank_system(
                         ),
                     );
 
-                    let tab_index = match bindings.get_radio(IID_RADIOBOX) {
-                        Some(&mut IID_BTN_TAB1) => 0,
-                        Some(&mut IID_BTN_TAB2) => 1,
-                        Some(&mut IID_BTN_TAB3) => 2,
-                        Some(&mut IID_BTN_TAB4) => 3,
-                        _ => 0,
+                    let tab_index = if let Some(shared_page) = shared_page {
+                        BANK_CHARACTER_PAGES + shared_page
+                    } else {
+                        match bindings.get_radio(IID_RADIOBOX) {
+                            Some(&mut IID_BTN_TAB1) => 0,
+                            Some(&mut IID_BTN_TAB2) => 1,
+                            Some(&mut IID_BTN_TAB3) => 2,
+                            Some(&mut IID_BTN_TAB4) => 3,
+                            _ => 0,
+                        }
                     };
 
                     for slot in 0..BANK_SLOTS_PER_PAGE {
@@ -265,6 +281,36 @@ pub fn ui_bank_system(
             );
         });
 
+    if ui_state.current_page != previous_page {
+        // Selecting a character bank tab leaves the shared storage pages
+        ui_state.shared_page = None;
+    }
+
+    if let Some(window_response) = window_response.filter(|_| shared_page_count > 0) {
+        egui::Area::new("bank_shared_pages")
+            .fixed_pos(window_response.response.rect.left_bottom() + egui::vec2(10.0, 5.0))
+            .show(egui_context.ctx_mut(), |ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Shared Storage:");
+
+                    for page in 0..shared_page_count {
+                        if ui
+                            .selectable_label(
+                                ui_state.shared_page == Some(page),
+                                format!("{}", page + 1),
+                            )
+                            .clicked()
+                        {
+                            ui_state.shared_page = Some(page);
+
+                            // Deselect the character bank tabs so any of them can be selected again
+                            ui_state.current_page = 0;
+                        }
+                    }
+                });
+            });
+    }
+
     if response_close_button.map_or(false, |r| r.clicked()) {
         ui_state_windows.bank_open = false;
     }
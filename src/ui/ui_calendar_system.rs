@@ -0,0 +1,198 @@
+use bevy::prelude::{Local, Res, ResMut, Time};
+use bevy_egui::{egui, EguiContexts};
+use chrono::{Local as LocalTime, Utc};
+
+use crate::{localize, resources::EventSchedule, ui::UiStateWindows};
+
+const EVENT_REMINDER_TOAST_DURATION: f32 = 8.0;
+const EVENT_REMINDER_TOAST_FADE_DURATION: f32 = 1.0;
+
+#[derive(Default)]
+pub struct UiStateCalendar {
+    reminder_toasts: Vec<(String, f32)>,
+}
+
+pub fn format_countdown(duration: chrono::Duration) -> String {
+    let seconds = duration.num_seconds().max(0);
+    let (days, hours, minutes) = (seconds / 86400, (seconds / 3600) % 24, (seconds / 60) % 60);
+
+    if days > 0 {
+        format!("{}d {}h", days, hours)
+    } else if hours > 0 {
+        format!("{}h {:02}m", hours, minutes)
+    } else {
+        format!("{}m {:02}s", minutes, seconds % 60)
+    }
+}
+
+pub fn ui_calendar_system(
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiStateCalendar>,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    mut event_schedule: ResMut<EventSchedule>,
+    time: Res<Time>,
+) {
+    let ctx = egui_context.ctx_mut();
+
+    for name in event_schedule.pending_reminders.drain(..) {
+        ui_state
+            .reminder_toasts
+            .push((name, EVENT_REMINDER_TOAST_DURATION));
+    }
+
+    ui_state.reminder_toasts.retain_mut(|(_, remaining)| {
+        *remaining -= time.delta_seconds();
+        *remaining > 0.0
+    });
+
+    for (index, (name, remaining)) in ui_state.reminder_toasts.iter().enumerate() {
+        let alpha = (remaining / EVENT_REMINDER_TOAST_FADE_DURATION).min(1.0);
+
+        egui::Area::new(egui::Id::new("event_reminder_toast").with(index))
+            .order(egui::Order::Foreground)
+            .interactable(false)
+            .anchor(egui::Align2::CENTER_TOP, [0.0, 80.0 + index as f32 * 40.0])
+            .show(ctx, |ui| {
+                egui::Frame::none()
+                    .fill(egui::Color32::from_black_alpha((200.0 * alpha) as u8))
+                    .rounding(egui::Rounding::same(4.0))
+                    .inner_margin(egui::Margin::symmetric(12.0, 6.0))
+                    .show(ui, |ui| {
+                        ui.label(
+                            egui::RichText::new(localize!(
+                                "calendar-reminder",
+                                name = name.clone()
+                            ))
+                            .strong()
+                            .color(egui::Color32::from_white_alpha((255.0 * alpha) as u8)),
+                        );
+                    });
+            });
+    }
+
+    if !ui_state_windows.calendar_open {
+        return;
+    }
+
+    let now = Utc::now();
+    let mut refresh = false;
+
+    egui::Window::new(localize!("calendar-title"))
+        .id(egui::Id::new("calendar"))
+        .open(&mut ui_state_windows.calendar_open)
+        .default_width(320.0)
+        .show(ctx, |ui| {
+            if event_schedule.url.is_empty() {
+                ui.label(localize!("calendar-no-schedule"));
+                return;
+            }
+
+            ui.horizontal(|ui| {
+                refresh = ui
+                    .add_enabled(
+                        !event_schedule.is_fetching(),
+                        egui::Button::new(localize!("calendar-refresh")),
+                    )
+                    .clicked();
+
+                if event_schedule.is_fetching() {
+                    ui.spinner();
+                }
+            });
+
+            if let Some(error) = event_schedule.error.as_ref() {
+                ui.colored_label(
+                    egui::Color32::RED,
+                    localize!("calendar-fetch-failed", error = error.clone()),
+                );
+            }
+
+            ui.separator();
+
+            egui::ScrollArea::vertical()
+                .max_height(400.0)
+                .show(ui, |ui| {
+                    let mut current_date = None;
+                    let mut any_events = false;
+
+                    for event in event_schedule.events.iter_mut() {
+                        if event.has_ended(now) {
+                            continue;
+                        }
+                        any_events = true;
+
+                        let local_start = event.start.with_timezone(&LocalTime);
+                        let date = local_start.date_naive();
+                        if current_date != Some(date) {
+                            current_date = Some(date);
+                            ui.add_space(4.0);
+                            ui.heading(local_start.format("%A %-d %B").to_string());
+                        }
+
+                        ui.group(|ui| {
+                            ui.set_width(ui.available_width());
+                            ui.horizontal(|ui| {
+                                ui.label(egui::RichText::new(&event.name).strong());
+
+                                let time_range = if let Some(end) = event.end {
+                                    format!(
+                                        "{} - {}",
+                                        local_start.format("%H:%M"),
+                                        end.with_timezone(&LocalTime).format("%H:%M")
+                                    )
+                                } else {
+                                    local_start.format("%H:%M").to_string()
+                                };
+                                ui.with_layout(
+                                    egui::Layout::right_to_left(egui::Align::Center),
+                                    |ui| {
+                                        ui.label(time_range);
+                                    },
+                                );
+                            });
+
+                            if !event.description.is_empty() {
+                                ui.label(egui::RichText::new(&event.description).weak());
+                            }
+
+                            if event.is_active(now) {
+                                ui.colored_label(
+                                    egui::Color32::LIGHT_GREEN,
+                                    localize!(
+                                        "calendar-event-active",
+                                        countdown = format_countdown(
+                                            event
+                                                .end
+                                                .map_or(chrono::Duration::zero(), |end| end - now)
+                                        )
+                                    ),
+                                );
+                            } else {
+                                ui.horizontal(|ui| {
+                                    ui.label(localize!(
+                                        "calendar-event-starts-in",
+                                        countdown = format_countdown(event.start - now)
+                                    ));
+
+                                    ui.add_enabled(
+                                        !event.reminded,
+                                        egui::Checkbox::new(
+                                            &mut event.remind,
+                                            localize!("calendar-remind-me"),
+                                        ),
+                                    );
+                                });
+                            }
+                        });
+                    }
+
+                    if !any_events {
+                        ui.label(localize!("calendar-no-events"));
+                    }
+                });
+        });
+
+    if refresh {
+        event_schedule.fetch();
+    }
+}
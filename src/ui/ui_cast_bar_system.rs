@@ -0,0 +1,125 @@
+use bevy::prelude::{
+    Assets, Camera, ComputedVisibility, GlobalTransform, Query, Res, Time, Vec3, With,
+};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_game_common::components::Team;
+
+use crate::{
+    animation::ZmoAsset,
+    components::{CastingState, MainCamera, ModelHeight, PlayerCharacter},
+    localize,
+    resources::{GameData, HudElement, HudLayout, SelectedTarget},
+};
+
+const PLAYER_CAST_BAR_WIDTH: f32 = 200.0;
+const TARGET_CAST_BAR_WIDTH: f32 = 100.0;
+
+// Place the target's cast bar above its name tag
+const TARGET_CAST_BAR_OFFSET_Y: f32 = 40.0;
+
+pub fn ui_cast_bar_system(
+    mut egui_context: EguiContexts,
+    query_player: Query<(Option<&CastingState>, &Team), With<PlayerCharacter>>,
+    query_target: Query<(
+        &CastingState,
+        &Team,
+        &GlobalTransform,
+        Option<&ModelHeight>,
+        &ComputedVisibility,
+    )>,
+    query_camera: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    selected_target: Res<SelectedTarget>,
+    hud_layout: Res<HudLayout>,
+    zmo_assets: Res<Assets<ZmoAsset>>,
+    game_data: Res<GameData>,
+    time: Res<Time>,
+) {
+    let Ok((player_casting_state, player_team)) = query_player.get_single() else {
+        return;
+    };
+    let ctx = egui_context.ctx_mut();
+    let now = time.elapsed();
+
+    if let Some(casting_state) = player_casting_state {
+        // Without a casting motion we cannot tell how long the cast takes, so show it as full
+        let progress = casting_state.get_progress(now, &zmo_assets).unwrap_or(1.0);
+        let skill_name = game_data
+            .skills
+            .get_skill(casting_state.skill_id)
+            .map_or("", |skill_data| skill_data.name);
+
+        let area = egui::Area::new("player_cast_bar")
+            .order(egui::Order::Foreground)
+            .interactable(false);
+        let area = if let Some(hotbar_rect) = hud_layout.rects[HudElement::Hotbar] {
+            area.pivot(egui::Align2::CENTER_BOTTOM)
+                .fixed_pos(egui::pos2(hotbar_rect.center().x, hotbar_rect.top() - 8.0))
+        } else {
+            area.anchor(egui::Align2::CENTER_BOTTOM, [0.0, -80.0])
+        };
+
+        area.show(ctx, |ui| {
+            egui::Frame::none()
+                .fill(egui::Color32::from_black_alpha(160))
+                .rounding(egui::Rounding::same(4.0))
+                .inner_margin(egui::Margin::same(4.0))
+                .show(ui, |ui| {
+                    ui.add(
+                        egui::ProgressBar::new(progress)
+                            .desired_width(PLAYER_CAST_BAR_WIDTH)
+                            .text(skill_name),
+                    );
+                    ui.label(
+                        egui::RichText::new(localize!("cast-bar-cancel-hint"))
+                            .small()
+                            .weak(),
+                    );
+                });
+        });
+    }
+
+    let Some((casting_state, team, global_transform, model_height, visibility)) = selected_target
+        .selected
+        .and_then(|entity| query_target.get(entity).ok())
+    else {
+        return;
+    };
+
+    // Only enemies' casts are worth warning about
+    if team.id == player_team.id || !visibility.is_visible() {
+        return;
+    }
+
+    let model_height = model_height.map_or(1.8, |model_height| model_height.height);
+    let Some(screen_pos) = query_camera
+        .get_single()
+        .ok()
+        .and_then(|(camera, camera_transform)| {
+            camera.world_to_viewport(
+                camera_transform,
+                global_transform.translation() + Vec3::new(0.0, model_height, 0.0),
+            )
+        })
+    else {
+        return;
+    };
+    let screen_size = ctx.input(|input| input.screen_rect().size());
+    let progress = casting_state.get_progress(now, &zmo_assets).unwrap_or(1.0);
+
+    egui::Area::new("target_cast_bar")
+        .order(egui::Order::Background)
+        .interactable(false)
+        .pivot(egui::Align2::CENTER_BOTTOM)
+        .fixed_pos(egui::pos2(
+            screen_pos.x,
+            screen_size.y - screen_pos.y - TARGET_CAST_BAR_OFFSET_Y,
+        ))
+        .show(ctx, |ui| {
+            ui.add(
+                egui::ProgressBar::new(progress)
+                    .desired_width(TARGET_CAST_BAR_WIDTH)
+                    .fill(egui::Color32::from_rgb(200, 80, 40)),
+            );
+        });
+}
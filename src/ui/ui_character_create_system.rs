@@ -1,7 +1,6 @@
 use bevy::prelude::{
-    AssetServer, Assets, Camera3d, Commands, ComputedVisibility, DespawnRecursiveExt, Entity,
-    EventWriter, GlobalTransform, Local, Quat, Query, Res, ResMut, Transform, Vec3, Visibility,
-    With,
+    AssetServer, Assets, Commands, ComputedVisibility, DespawnRecursiveExt, Entity, EventWriter,
+    GlobalTransform, Local, Quat, Query, Res, ResMut, Transform, Vec3, Visibility, With,
 };
 use bevy_egui::{egui, EguiContexts};
 use rose_data::ZoneId;
@@ -12,6 +11,7 @@ use rose_game_common::{
 
 use crate::{
     animation::CameraAnimation,
+    components::MainCamera,
     resources::{CharacterSelectState, UiResources, WorldConnection},
     ui::{
         widgets::{DataBindings, Dialog, DrawText},
@@ -86,7 +86,7 @@ pub fn ui_character_create_system(
     mut ui_sound_events: EventWriter<UiSoundEvent>,
     mut character_select_state: ResMut<CharacterSelectState>,
     mut egui_context: EguiContexts,
-    query_camera: Query<Entity, With<Camera3d>>,
+    query_camera: Query<Entity, With<MainCamera>>,
     mut query_create_character_info: Query<&mut CharacterInfo>,
     asset_server: Res<AssetServer>,
     dialog_assets: Res<Assets<Dialog>>,
@@ -6,8 +6,8 @@ use bevy_egui::{egui, EguiContexts};
 
 use rose_game_common::{
     components::{
-        AbilityValues, BasicStatType, BasicStats, CharacterInfo, ExperiencePoints, Level,
-        MoveSpeed, Stamina, StatPoints, MAX_STAMINA,
+        AbilityValues, BasicStatType, BasicStats, CharacterInfo, Equipment, ExperiencePoints,
+        Level, MoveSpeed, SkillList, Stamina, StatPoints, StatusEffects, MAX_STAMINA,
     },
     messages::client::ClientMessage,
 };
@@ -43,12 +43,14 @@ const IID_TAB_UNION: i32 = 41;
 
 pub struct UiStateCharacterInfo {
     current_tab: i32,
+    pending_stat_increases: Vec<BasicStatType>,
 }
 
 impl Default for UiStateCharacterInfo {
     fn default() -> Self {
         Self {
             current_tab: IID_TAB_BASICINFO,
+            pending_stat_increases: Vec::new(),
         }
     }
 }
@@ -58,11 +60,37 @@ pub struct PlayerQuery<'w> {
     ability_values: &'w AbilityValues,
     basic_stats: &'w BasicStats,
     character_info: &'w CharacterInfo,
+    equipment: &'w Equipment,
     experience_points: &'w ExperiencePoints,
     level: &'w Level,
     move_speed: &'w MoveSpeed,
+    skill_list: &'w SkillList,
     stamina: &'w Stamina,
     stat_points: &'w StatPoints,
+    status_effects: &'w StatusEffects,
+}
+
+fn increase_basic_stat(basic_stats: &mut BasicStats, basic_stat_type: BasicStatType) {
+    match basic_stat_type {
+        BasicStatType::Strength => basic_stats.strength += 1,
+        BasicStatType::Dexterity => basic_stats.dexterity += 1,
+        BasicStatType::Intelligence => basic_stats.intelligence += 1,
+        BasicStatType::Concentration => basic_stats.concentration += 1,
+        BasicStatType::Charm => basic_stats.charm += 1,
+        BasicStatType::Sense => basic_stats.sense += 1,
+    }
+}
+
+fn preview_label<T: PartialEq + std::fmt::Display>(
+    current: T,
+    preview: Option<T>,
+) -> egui::WidgetText {
+    match preview {
+        Some(preview) if preview != current => egui::RichText::new(format!("{}", preview))
+            .color(egui::Color32::from_rgb(100, 200, 100))
+            .into(),
+        _ => format!("{}", current).into(),
+    }
 }
 
 pub fn ui_character_info_system(
@@ -89,7 +117,42 @@ pub fn ui_character_info_system(
     };
 
     let ui_state = &mut *ui_state;
+
+    // Apply the pending stat increases to a copy of the player's stats, dropping
+    // any which can no longer be afforded
+    let mut preview_basic_stats = player.basic_stats.clone();
+    let mut preview_stat_points = player.stat_points.points;
+    ui_state.pending_stat_increases.retain(|basic_stat_type| {
+        match game_data
+            .ability_value_calculator
+            .calculate_basic_stat_increase_cost(&preview_basic_stats, *basic_stat_type)
+        {
+            Some(cost) if cost <= preview_stat_points => {
+                preview_stat_points -= cost;
+                increase_basic_stat(&mut preview_basic_stats, *basic_stat_type);
+                true
+            }
+            _ => false,
+        }
+    });
+
+    let preview_ability_values = if ui_state.pending_stat_increases.is_empty() {
+        None
+    } else {
+        Some(game_data.ability_value_calculator.calculate(
+            player.character_info,
+            player.level,
+            player.equipment,
+            &preview_basic_stats,
+            player.skill_list,
+            player.status_effects,
+        ))
+    };
+    let preview = preview_ability_values.as_ref();
+
     let mut response_close_button = None;
+    let mut response_apply_button = None;
+    let mut response_reset_button = None;
     let mut response_raise_str_button = None;
     let mut response_raise_dex_button = None;
     let mut response_raise_int_button = None;
@@ -153,60 +216,108 @@ pub fn ui_character_info_system(
                     Some(&mut IID_TAB_ABILITY) => {
                         ui.add_label_at(
                             egui::pos2(58.0, 67.0),
-                            &format!("{}", player.ability_values.get_strength()),
+                            preview_label(
+                                player.ability_values.get_strength(),
+                                preview.map(|ability_values| ability_values.get_strength()),
+                            ),
                         );
                         ui.add_label_at(
                             egui::pos2(58.0, 88.0),
-                            &format!("{}", player.ability_values.get_dexterity()),
+                            preview_label(
+                                player.ability_values.get_dexterity(),
+                                preview.map(|ability_values| ability_values.get_dexterity()),
+                            ),
                         );
                         ui.add_label_at(
                             egui::pos2(58.0, 109.0),
-                            &format!("{}", player.ability_values.get_intelligence()),
+                            preview_label(
+                                player.ability_values.get_intelligence(),
+                                preview.map(|ability_values| ability_values.get_intelligence()),
+                            ),
                         );
                         ui.add_label_at(
                             egui::pos2(58.0, 130.0),
-                            &format!("{}", player.ability_values.get_concentration()),
+                            preview_label(
+                                player.ability_values.get_concentration(),
+                                preview.map(|ability_values| ability_values.get_concentration()),
+                            ),
                         );
                         ui.add_label_at(
                             egui::pos2(58.0, 151.0),
-                            &format!("{}", player.ability_values.get_charm()),
+                            preview_label(
+                                player.ability_values.get_charm(),
+                                preview.map(|ability_values| ability_values.get_charm()),
+                            ),
                         );
                         ui.add_label_at(
                             egui::pos2(58.0, 172.0),
-                            &format!("{}", player.ability_values.get_sense()),
+                            preview_label(
+                                player.ability_values.get_sense(),
+                                preview.map(|ability_values| ability_values.get_sense()),
+                            ),
                         );
                         ui.add_label_at(
                             egui::pos2(69.0, 211.0),
-                            &format!("{}", player.stat_points.points),
+                            preview_label(player.stat_points.points, Some(preview_stat_points)),
                         );
 
+                        if !ui_state.pending_stat_increases.is_empty() {
+                            response_apply_button = Some(
+                                ui.add_at(egui::pos2(20.0, 232.0), egui::Button::new("Apply")),
+                            );
+                            response_reset_button = Some(
+                                ui.add_at(egui::pos2(70.0, 232.0), egui::Button::new("Reset")),
+                            );
+                        }
+
                         ui.add_label_at(
                             egui::pos2(171.0, 67.0),
-                            &format!("{}", player.ability_values.get_attack_power()),
+                            preview_label(
+                                player.ability_values.get_attack_power(),
+                                preview.map(|ability_values| ability_values.get_attack_power()),
+                            ),
                         );
                         ui.add_label_at(
                             egui::pos2(171.0, 88.0),
-                            &format!("{}", player.ability_values.get_defence()),
+                            preview_label(
+                                player.ability_values.get_defence(),
+                                preview.map(|ability_values| ability_values.get_defence()),
+                            ),
                         );
                         ui.add_label_at(
                             egui::pos2(171.0, 109.0),
-                            &format!("{}", player.ability_values.get_resistance()),
+                            preview_label(
+                                player.ability_values.get_resistance(),
+                                preview.map(|ability_values| ability_values.get_resistance()),
+                            ),
                         );
                         ui.add_label_at(
                             egui::pos2(171.0, 130.0),
-                            &format!("{}", player.ability_values.get_hit()),
+                            preview_label(
+                                player.ability_values.get_hit(),
+                                preview.map(|ability_values| ability_values.get_hit()),
+                            ),
                         );
                         ui.add_label_at(
                             egui::pos2(171.0, 151.0),
-                            &format!("{}", player.ability_values.get_critical()),
+                            preview_label(
+                                player.ability_values.get_critical(),
+                                preview.map(|ability_values| ability_values.get_critical()),
+                            ),
                         );
                         ui.add_label_at(
                             egui::pos2(171.0, 172.0),
-                            &format!("{}", player.ability_values.get_avoid()),
+                            preview_label(
+                                player.ability_values.get_avoid(),
+                                preview.map(|ability_values| ability_values.get_avoid()),
+                            ),
                         );
                         ui.add_label_at(
                             egui::pos2(171.0, 193.0),
-                            &format!("{}", player.ability_values.get_attack_speed()),
+                            preview_label(
+                                player.ability_values.get_attack_speed(),
+                                preview.map(|ability_values| ability_values.get_attack_speed()),
+                            ),
                         );
                         ui.add_label_at(
                             egui::pos2(171.0, 214.0),
@@ -223,29 +334,51 @@ pub fn ui_character_info_system(
         ui_state_windows.character_info_open = false;
     }
 
-    let stat_button_response = |basic_stat_type: BasicStatType,
-                                response: Option<egui::Response>| {
-        if let Some(response) = response {
-            if let Some(cost) = game_data
-                .ability_value_calculator
-                .calculate_basic_stat_increase_cost(player.basic_stats, basic_stat_type)
-            {
-                if response
-                    .on_hover_text(format!("Required Points: {}", cost))
-                    .clicked()
-                    && cost <= player.stat_points.points
+    if response_reset_button.map_or(false, |r| r.clicked()) {
+        ui_state.pending_stat_increases.clear();
+    }
+
+    if response_apply_button.map_or(false, |r| r.clicked()) {
+        if let Some(game_connection) = game_connection.as_ref() {
+            // The server processes these in order, so the cost of each increase
+            // matches the cost used for the preview
+            for basic_stat_type in ui_state.pending_stat_increases.drain(..) {
+                game_connection
+                    .client_message_tx
+                    .send(ClientMessage::IncreaseBasicStat { basic_stat_type })
+                    .ok();
+            }
+        }
+    }
+
+    let mut stat_button_response =
+        |basic_stat_type: BasicStatType, response: Option<egui::Response>| {
+            if let Some(response) = response {
+                if let Some(cost) = game_data
+                    .ability_value_calculator
+                    .calculate_basic_stat_increase_cost(&preview_basic_stats, basic_stat_type)
                 {
-                    if let Some(game_connection) = game_connection.as_ref() {
-                        game_connection
-                            .client_message_tx
-                            .send(ClientMessage::IncreaseBasicStat { basic_stat_type })
-                            .ok();
+                    let response = response.on_hover_text(format!(
+                        "Required Points: {}\nRight click to remove a pending point",
+                        cost
+                    ));
+
+                    if response.clicked() && cost <= preview_stat_points {
+                        preview_stat_points -= cost;
+                        increase_basic_stat(&mut preview_basic_stats, basic_stat_type);
+                        ui_state.pending_stat_increases.push(basic_stat_type);
+                    } else if response.secondary_clicked() {
+                        if let Some(index) = ui_state
+                            .pending_stat_increases
+                            .iter()
+                            .rposition(|pending| *pending == basic_stat_type)
+                        {
+                            ui_state.pending_stat_increases.remove(index);
+                        }
                     }
                 }
             }
-        }
-    };
-
+        };
     stat_button_response(BasicStatType::Strength, response_raise_str_button);
     stat_button_response(BasicStatType::Dexterity, response_raise_dex_button);
     stat_button_response(BasicStatType::Intelligence, response_raise_int_button);
@@ -1,17 +1,87 @@
-use bevy::prelude::{Camera, Camera3d, GlobalTransform, Query, Res, Vec3, With};
+use std::time::Duration;
+
+use bevy::prelude::{Camera, GlobalTransform, Query, Res, Vec3, With};
 use bevy_egui::{egui, EguiContexts};
 
-use crate::resources::{CharacterList, CharacterSelectState, GameData};
+use crate::{
+    components::MainCamera,
+    localize,
+    resources::{CharacterList, CharacterSelectState, GameData},
+};
+
+fn format_delete_countdown(duration: Duration) -> String {
+    let seconds = duration.as_secs() % 60;
+    let minutes = (duration.as_secs() / 60) % 60;
+    let hours = duration.as_secs() / 3600;
+
+    if hours > 0 {
+        format!("{}h {:02}m {:02}s", hours, minutes, seconds)
+    } else {
+        format!("{:02}m {:02}s", minutes, seconds)
+    }
+}
 
 pub fn ui_character_select_name_tag_system(
     mut egui_context: EguiContexts,
-    query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    query_camera: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
     character_list: Option<Res<CharacterList>>,
     character_select_state: Res<CharacterSelectState>,
     game_data: Res<GameData>,
 ) {
     for (camera, camera_transform) in query_camera.iter() {
-        if let CharacterSelectState::CharacterSelect(Some(index)) = *character_select_state {
+        let selected_index = if let CharacterSelectState::CharacterSelect(selected_index) =
+            *character_select_state
+        {
+            selected_index
+        } else {
+            continue;
+        };
+
+        // Show the deletion countdown above every other character pending deletion
+        for (index, character) in character_list
+            .iter()
+            .flat_map(|character_list| character_list.characters.iter().enumerate())
+        {
+            let Some(delete_time) = character.delete_time.as_ref() else {
+                continue;
+            };
+
+            if Some(index) == selected_index {
+                continue;
+            }
+
+            let Some(slot_transform) = game_data.character_select_positions.get(index) else {
+                continue;
+            };
+
+            if let Some(screen_pos) = camera.world_to_viewport(
+                camera_transform,
+                slot_transform.translation + Vec3::new(0.0, 3.0, 0.0),
+            ) {
+                let ctx = egui_context.ctx_mut();
+                let screen_size = ctx.input(|input| input.screen_rect().size());
+
+                egui::Area::new(egui::Id::new("character_delete_countdown").with(index))
+                    .interactable(false)
+                    .fixed_pos(egui::Pos2::new(
+                        screen_pos.x - 30.0,
+                        screen_size.y - screen_pos.y,
+                    ))
+                    .show(ctx, |ui| {
+                        ui.label(
+                            egui::RichText::new(localize!(
+                                "character-delete-countdown",
+                                countdown =
+                                    format_delete_countdown(delete_time.get_time_until_delete())
+                            ))
+                            .color(egui::Color32::RED)
+                            .background_color(egui::Color32::from_black_alpha(160)),
+                        );
+                    });
+            }
+        }
+
+        if let Some(index) = selected_index {
             if let Some(selected_character) = character_list
                 .as_ref()
                 .and_then(|character_list| character_list.characters.get(index))
@@ -52,10 +122,12 @@ pub fn ui_character_select_name_tag_system(
                             ));
 
                             if let Some(delete_time) = selected_character.delete_time.as_ref() {
-                                let duration = delete_time.get_time_until_delete();
-                                let seconds = duration.as_secs() % 60;
-                                let minutes = (duration.as_secs() / 60) % 60;
-                                ui.label(format!("Deleted in {:02}m {:02}s", minutes, seconds));
+                                ui.label(localize!(
+                                    "character-delete-countdown",
+                                    countdown = format_delete_countdown(
+                                        delete_time.get_time_until_delete()
+                                    )
+                                ));
                             }
                         },
                     );
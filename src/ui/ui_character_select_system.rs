@@ -1,11 +1,13 @@
 use bevy::prelude::{
-    AssetServer, Assets, Camera3d, Commands, Entity, EventWriter, Local, Query, Res, ResMut, With,
+    AssetServer, Assets, Commands, Entity, EventWriter, Local, Query, Res, ResMut, With,
 };
 use bevy_egui::{egui, EguiContexts};
 
 use crate::{
     animation::CameraAnimation,
+    components::MainCamera,
     events::CharacterSelectEvent,
+    localize,
     resources::{CharacterList, CharacterSelectState, GameData, UiResources},
     ui::{
         widgets::{DataBindings, Dialog, Widget},
@@ -15,12 +17,18 @@ use crate::{
 
 pub struct UiCharacterSelectState {
     dialog_instance: DialogInstance,
+
+    /// Character slot waiting for the user to confirm deletion by typing its name.
+    delete_confirm_index: Option<usize>,
+    delete_confirm_name: String,
 }
 
 impl Default for UiCharacterSelectState {
     fn default() -> Self {
         Self {
             dialog_instance: DialogInstance::new("DLGSELAVATAR.XML"),
+            delete_confirm_index: None,
+            delete_confirm_name: String::new(),
         }
     }
 }
@@ -35,7 +43,7 @@ pub fn ui_character_select_system(
     mut ui_state: Local<UiCharacterSelectState>,
     mut character_select_state: ResMut<CharacterSelectState>,
     mut egui_context: EguiContexts,
-    query_camera: Query<Entity, With<Camera3d>>,
+    query_camera: Query<Entity, With<MainCamera>>,
     character_list: Option<Res<CharacterList>>,
     asset_server: Res<AssetServer>,
     dialog_assets: Res<Assets<Dialog>>,
@@ -124,8 +132,94 @@ pub fn ui_character_select_system(
         *character_select_state = CharacterSelectState::CharacterCreate;
     }
 
+    let selected_index =
+        if let CharacterSelectState::CharacterSelect(selected_index) = *character_select_state {
+            selected_index
+        } else {
+            None
+        };
+    let selected_character = selected_index.and_then(|index| {
+        character_list
+            .as_ref()
+            .and_then(|character_list| character_list.characters.get(index))
+    });
+
     if response_delete_button.map_or(false, |r| r.clicked()) {
-        character_select_events.send(CharacterSelectEvent::DeleteSelected);
+        if let Some(selected_character) = selected_character {
+            if selected_character.delete_time.is_some() {
+                character_select_events.send(CharacterSelectEvent::CancelDeleteSelected);
+            } else {
+                ui_state.delete_confirm_index = selected_index;
+                ui_state.delete_confirm_name.clear();
+            }
+        }
+    }
+
+    if let Some(selected_character) = selected_character {
+        if selected_character.delete_time.is_some() {
+            egui::Area::new(egui::Id::new("character_select_undo_delete"))
+                .anchor(egui::Align2::CENTER_BOTTOM, [0.0, -24.0 - 40.0 - 48.0])
+                .show(egui_context.ctx_mut(), |ui| {
+                    if ui.button(localize!("character-delete-undo")).clicked() {
+                        character_select_events.send(CharacterSelectEvent::CancelDeleteSelected);
+                    }
+                });
+        }
+    }
+
+    if let Some(delete_index) = ui_state.delete_confirm_index {
+        let delete_character = character_list
+            .as_ref()
+            .and_then(|character_list| character_list.characters.get(delete_index))
+            .filter(|character| character.delete_time.is_none());
+
+        if let Some(delete_character) = delete_character {
+            let mut confirmed = false;
+            let mut cancelled = false;
+
+            egui::Window::new(localize!("character-delete-title"))
+                .id(egui::Id::new("character_delete_confirm"))
+                .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+                .collapsible(false)
+                .resizable(false)
+                .show(egui_context.ctx_mut(), |ui| {
+                    ui.label(localize!(
+                        "character-delete-warning",
+                        name = delete_character.info.name.clone()
+                    ));
+                    ui.label(localize!("character-delete-type-name"));
+
+                    let response = ui.text_edit_singleline(&mut ui_state.delete_confirm_name);
+                    let name_matches = ui_state.delete_confirm_name == delete_character.info.name;
+
+                    ui.horizontal(|ui| {
+                        confirmed = ui
+                            .add_enabled(
+                                name_matches,
+                                egui::Button::new(localize!("character-delete-confirm")),
+                            )
+                            .clicked()
+                            || (name_matches
+                                && response.lost_focus()
+                                && ui.input(|input| input.key_pressed(egui::Key::Enter)));
+                        cancelled = ui.button(localize!("character-delete-cancel")).clicked();
+                    });
+                });
+
+            if confirmed {
+                // Selection may have changed while the dialog was open
+                character_select_events.send(CharacterSelectEvent::SelectCharacter(delete_index));
+                character_select_events.send(CharacterSelectEvent::DeleteSelected);
+            }
+
+            if confirmed || cancelled {
+                ui_state.delete_confirm_index = None;
+                ui_state.delete_confirm_name.clear();
+            }
+        } else {
+            ui_state.delete_confirm_index = None;
+            ui_state.delete_confirm_name.clear();
+        }
     }
 
     if response_ok_button.map_or(false, |r| r.clicked()) {
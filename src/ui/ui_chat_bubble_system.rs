@@ -0,0 +1,85 @@
+use bevy::prelude::{
+    Camera, Commands, ComputedVisibility, Entity, GlobalTransform, Query, Res, Time, Vec3, With,
+};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    components::{ChatBubble, MainCamera, ModelHeight},
+    resources::NameTagSettings,
+};
+
+const CHAT_BUBBLE_MAX_WIDTH: f32 = 200.0;
+
+// Place the bubble above the name tag
+const CHAT_BUBBLE_OFFSET_Y: f32 = 60.0;
+
+pub fn ui_chat_bubble_system(
+    mut commands: Commands,
+    mut egui_context: EguiContexts,
+    mut query_chat_bubbles: Query<(
+        Entity,
+        &mut ChatBubble,
+        &GlobalTransform,
+        &ModelHeight,
+        &ComputedVisibility,
+    )>,
+    query_camera: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    name_tag_settings: Res<NameTagSettings>,
+    time: Res<Time>,
+) {
+    let camera = query_camera.get_single().ok();
+    let ctx = egui_context.ctx_mut();
+    let screen_size = ctx.input(|input| input.screen_rect().size());
+
+    for (entity, mut chat_bubble, global_transform, model_height, visibility) in
+        query_chat_bubbles.iter_mut()
+    {
+        if chat_bubble.timer.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<ChatBubble>();
+            continue;
+        }
+
+        if !name_tag_settings.chat_bubbles || !visibility.is_visible() {
+            continue;
+        }
+
+        let Some(screen_pos) = camera.and_then(|(camera, camera_transform)| {
+            camera.world_to_viewport(
+                camera_transform,
+                global_transform.translation() + Vec3::new(0.0, model_height.height, 0.0),
+            )
+        }) else {
+            continue;
+        };
+
+        // Fade out during the last second
+        let remaining = chat_bubble.timer.remaining_secs();
+        let alpha = (remaining.min(1.0) * 255.0) as u8;
+
+        egui::Area::new(egui::Id::new("chat_bubble").with(entity))
+            .order(egui::Order::Background)
+            .interactable(false)
+            .pivot(egui::Align2::CENTER_BOTTOM)
+            .fixed_pos(egui::pos2(
+                screen_pos.x,
+                screen_size.y - screen_pos.y - CHAT_BUBBLE_OFFSET_Y,
+            ))
+            .show(ctx, |ui| {
+                egui::Frame::none()
+                    .fill(egui::Color32::from_rgba_unmultiplied(255, 255, 255, alpha))
+                    .stroke(egui::Stroke::new(
+                        1.0,
+                        egui::Color32::from_rgba_unmultiplied(80, 80, 80, alpha),
+                    ))
+                    .rounding(egui::Rounding::same(6.0))
+                    .inner_margin(egui::Margin::symmetric(8.0, 4.0))
+                    .show(ui, |ui| {
+                        ui.set_max_width(CHAT_BUBBLE_MAX_WIDTH);
+                        ui.label(
+                            egui::RichText::new(&chat_bubble.text)
+                                .color(egui::Color32::from_rgba_unmultiplied(0, 0, 0, alpha)),
+                        );
+                    });
+            });
+    }
+}
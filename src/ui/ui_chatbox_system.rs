@@ -1,11 +1,11 @@
-use bevy::prelude::{Assets, EventReader, EventWriter, Local, Res};
+use bevy::prelude::{Assets, EventReader, EventWriter, Local, Res, ResMut};
 use bevy_egui::{egui, EguiContexts};
 
 use rose_game_common::messages::client::ClientMessage;
 
 use crate::{
-    events::ChatboxEvent,
-    resources::{GameConnection, UiResources},
+    events::{CharacterExportFormat, ChatboxEvent, ChatboxInputEvent, PlayerCommandEvent},
+    resources::{GameConnection, HudElement, HudLayout, UiResources},
     ui::{
         widgets::{DataBindings, Dialog},
         UiSoundEvent,
@@ -62,6 +62,7 @@ pub struct UiStateChatbox {
     textbox_layout_job: egui::text::LayoutJob,
     cleanup_layout_text_counter: usize,
     selected_channel: i32,
+    focus_textbox: bool,
 }
 
 impl Default for UiStateChatbox {
@@ -71,18 +72,49 @@ impl Default for UiStateChatbox {
             textbox_layout_job: Default::default(),
             cleanup_layout_text_counter: 0,
             selected_channel: IID_BTN_ALL,
+            focus_textbox: false,
         }
     }
 }
 
+fn parse_player_command(text: &str) -> Option<PlayerCommandEvent> {
+    let mut args = text.strip_prefix('/')?.split_whitespace();
+
+    match args.next()? {
+        "assist" => Some(PlayerCommandEvent::Assist(None)),
+        "defend" => match args.next() {
+            Some("off") => Some(PlayerCommandEvent::StopDefend),
+            _ => Some(PlayerCommandEvent::Defend(None)),
+        },
+        "afk" => {
+            let message = args.collect::<Vec<_>>().join(" ");
+            Some(PlayerCommandEvent::ToggleAfk(
+                (!message.is_empty()).then_some(message),
+            ))
+        }
+        "export" => match args.next() {
+            Some("html") => Some(PlayerCommandEvent::ExportCharacter(
+                CharacterExportFormat::Html,
+            )),
+            _ => Some(PlayerCommandEvent::ExportCharacter(
+                CharacterExportFormat::Json,
+            )),
+        },
+        _ => None, // Unknown commands are sent to the server
+    }
+}
+
 pub fn ui_chatbox_system(
     mut egui_context: EguiContexts,
     mut ui_state_chatbox: Local<UiStateChatbox>,
     mut chatbox_events: EventReader<ChatboxEvent>,
+    mut chatbox_input_events: EventReader<ChatboxInputEvent>,
     game_connection: Option<Res<GameConnection>>,
     ui_resources: Res<UiResources>,
     mut ui_sound_events: EventWriter<UiSoundEvent>,
     dialog_assets: Res<Assets<Dialog>>,
+    mut player_command_events: EventWriter<PlayerCommandEvent>,
+    mut hud_layout: ResMut<HudLayout>,
 ) {
     let ui_state_chatbox = &mut *ui_state_chatbox;
     let dialog = if let Some(dialog) = dialog_assets.get(&ui_resources.dialog_chatbox) {
@@ -91,6 +123,15 @@ pub fn ui_chatbox_system(
         return;
     };
 
+    for event in chatbox_input_events.iter() {
+        match event {
+            ChatboxInputEvent::Whisper(name) => {
+                ui_state_chatbox.textbox_text = format!("@{} ", name);
+                ui_state_chatbox.focus_textbox = true;
+            }
+        }
+    }
+
     let local_time = chrono::Local::now();
     let timestamp = local_time.format("%H:%M:%S");
 
@@ -198,6 +239,11 @@ pub fn ui_chatbox_system(
         }
     }
 
+    if !hud_layout.is_visible(HudElement::Chatbox) {
+        hud_layout.rects[HudElement::Chatbox] = None;
+        return;
+    }
+
     let mut chatbox_style = (*egui_context.ctx_mut().style()).clone();
     chatbox_style.visuals.widgets.noninteractive.bg_fill = egui::Color32::from_rgba_unmultiplied(
         chatbox_style.visuals.widgets.noninteractive.bg_fill.r(),
@@ -219,8 +265,11 @@ pub fn ui_chatbox_system(
     let mut response_clan_button = None;
     let mut response_allied_button = None;
 
-    egui::Window::new("Chat Box")
-        .anchor(egui::Align2::LEFT_BOTTOM, [0.0, 0.0])
+    let response = egui::Window::new("Chat Box")
+        .anchor(
+            egui::Align2::LEFT_BOTTOM,
+            hud_layout.get_offset(HudElement::Chatbox),
+        )
         .frame(egui::Frame::none().fill(frame_fill))
         .title_bar(false)
         .resizable(false)
@@ -287,14 +336,23 @@ pub fn ui_chatbox_system(
                 },
             );
         });
+    hud_layout.rects[HudElement::Chatbox] = response.map(|response| response.response.rect);
 
     if let Some(response) = response_editbox {
+        if ui_state_chatbox.focus_textbox {
+            ui_state_chatbox.focus_textbox = false;
+            response.request_focus();
+        }
+
         if response
             .ctx
             .input(|input| input.key_pressed(egui::Key::Enter))
         {
             if response.lost_focus() {
-                if !ui_state_chatbox.textbox_text.is_empty() {
+                if let Some(player_command) = parse_player_command(&ui_state_chatbox.textbox_text) {
+                    player_command_events.send(player_command);
+                    ui_state_chatbox.textbox_text.clear();
+                } else if !ui_state_chatbox.textbox_text.is_empty() {
                     // TODO: Parse text line to decide whether its chat, shout, etc
                     if let Some(game_connection) = game_connection.as_ref() {
                         game_connection
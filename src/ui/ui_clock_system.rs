@@ -0,0 +1,124 @@
+use bevy::prelude::{Res, ResMut};
+use bevy_egui::{egui, EguiContexts};
+use chrono::Local as LocalTime;
+use rose_data::WORLD_TICK_DURATION;
+
+use crate::{
+    localize,
+    resources::{CurrentZone, GameData, HudElement, HudLayout, WorldTime, ZoneTime, ZoneTimeState},
+    ui::{format_countdown, UiStateWindows},
+};
+
+const CLOCK_MINUTES_PER_DAY: u64 = 24 * 60;
+
+fn format_zone_clock(time: u32, day_cycle: u32) -> String {
+    let minutes = time as u64 * CLOCK_MINUTES_PER_DAY / day_cycle.max(1) as u64;
+    format!("{:02}:{:02}", minutes / 60, minutes % 60)
+}
+
+fn get_zone_time_state_name(state: ZoneTimeState) -> String {
+    match state {
+        ZoneTimeState::Morning => localize!("zone-time-morning"),
+        ZoneTimeState::Day => localize!("zone-time-day"),
+        ZoneTimeState::Evening => localize!("zone-time-evening"),
+        ZoneTimeState::Night => localize!("zone-time-night"),
+    }
+}
+
+pub fn ui_clock_system(
+    mut egui_context: EguiContexts,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    current_zone: Option<Res<CurrentZone>>,
+    game_data: Res<GameData>,
+    hud_layout: Res<HudLayout>,
+    world_time: Res<WorldTime>,
+    zone_time: Res<ZoneTime>,
+) {
+    let Some(zone_data) = current_zone
+        .as_ref()
+        .and_then(|current_zone| game_data.zone_list.get_zone(current_zone.id))
+    else {
+        return;
+    };
+    let day_cycle = zone_data.day_cycle.max(1);
+
+    // Morning and day count as day time, evening and night as night time
+    let (is_night, next_transition_time) = match zone_time.state {
+        ZoneTimeState::Morning | ZoneTimeState::Day => (false, zone_data.evening_time),
+        ZoneTimeState::Evening | ZoneTimeState::Night => (true, zone_data.morning_time),
+    };
+    let ticks_until_transition =
+        match (next_transition_time + day_cycle - zone_time.time % day_cycle) % day_cycle {
+            0 => day_cycle,
+            ticks => ticks,
+        };
+    let time_until_transition = (WORLD_TICK_DURATION * ticks_until_transition)
+        .saturating_sub(world_time.time_since_last_tick);
+    let countdown = format_countdown(
+        chrono::Duration::from_std(time_until_transition)
+            .unwrap_or_else(|_| chrono::Duration::zero()),
+    );
+
+    let world_day = world_time.ticks.get_world_time() / day_cycle + 1;
+    let zone_clock = format_zone_clock(zone_time.time, day_cycle);
+    let real_clock = LocalTime::now().format("%H:%M").to_string();
+
+    // Sit below the minimap, or in its place when the minimap is hidden
+    let position = hud_layout.rects[HudElement::Minimap].map_or_else(
+        || egui::pos2(egui_context.ctx_mut().screen_rect().right() - 160.0, 0.0),
+        |rect| rect.left_bottom() + egui::vec2(0.0, 2.0),
+    );
+
+    let response = egui::Area::new("clock")
+        .fixed_pos(position)
+        .show(egui_context.ctx_mut(), |ui| {
+            egui::Frame::none()
+                .fill(egui::Color32::from_black_alpha(160))
+                .rounding(egui::Rounding::same(3.0))
+                .inner_margin(egui::Margin::symmetric(6.0, 3.0))
+                .show(ui, |ui| {
+                    ui.set_min_width(148.0);
+                    ui.horizontal(|ui| {
+                        ui.label(egui::RichText::new(&real_clock).strong());
+                        ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                            ui.label(localize!(
+                                "clock-world-time",
+                                day = world_day,
+                                time = zone_clock.as_str()
+                            ));
+                        });
+                    });
+                    ui.label(
+                        egui::RichText::new(if is_night {
+                            localize!(
+                                "clock-day-in",
+                                state = get_zone_time_state_name(zone_time.state),
+                                countdown = countdown.as_str()
+                            )
+                        } else {
+                            localize!(
+                                "clock-night-in",
+                                state = get_zone_time_state_name(zone_time.state),
+                                countdown = countdown.as_str()
+                            )
+                        })
+                        .small(),
+                    );
+                })
+        })
+        .response;
+
+    let response = response.interact(egui::Sense::click()).on_hover_ui(|ui| {
+        ui.label(localize!("clock-real-time", time = real_clock.as_str()));
+        ui.label(localize!(
+            "clock-world-time",
+            day = world_day,
+            time = zone_clock.as_str()
+        ));
+        ui.label(localize!("clock-open-calendar"));
+    });
+
+    if response.clicked() {
+        ui_state_windows.calendar_open = !ui_state_windows.calendar_open;
+    }
+}
@@ -0,0 +1,176 @@
+use std::collections::HashSet;
+
+use bevy::prelude::{EventWriter, Local, Query, Res, ResMut, Time, With};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_data::{SkillCooldown, SkillId, SoundId};
+use rose_game_common::components::{CharacterInfo, StatusEffects};
+
+use crate::{
+    components::{Cooldowns, PlayerCharacter},
+    localize,
+    resources::{Account, CharacterStoreKey, CooldownAlertSettings, CooldownAlerts, GameData},
+    ui::UiSoundEvent,
+};
+
+const COOLDOWN_ALERT_TOAST_DURATION: f32 = 3.0;
+const COOLDOWN_ALERT_TOAST_FADE_DURATION: f32 = 0.5;
+const COOLDOWN_ALERT_PULSE_DURATION: f32 = 1.0;
+
+#[derive(Default)]
+pub struct UiStateCooldownAlerts {
+    skills_on_cooldown: HashSet<u16>,
+    warned_status_effects: HashSet<u16>,
+    toasts: Vec<(String, f32)>,
+}
+
+pub fn ui_cooldown_alert_system(
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiStateCooldownAlerts>,
+    mut cooldown_alerts: ResMut<CooldownAlerts>,
+    cooldown_alert_settings: Res<CooldownAlertSettings>,
+    mut ui_sound_events: EventWriter<UiSoundEvent>,
+    query_player: Query<(&CharacterInfo, &Cooldowns, &StatusEffects), With<PlayerCharacter>>,
+    account: Option<Res<Account>>,
+    game_data: Res<GameData>,
+    time: Res<Time>,
+) {
+    let Ok((character_info, cooldowns, status_effects)) = query_player.get_single() else {
+        return;
+    };
+
+    let store_key = CharacterStoreKey::new(account.as_deref(), &character_info.name);
+    if cooldown_alerts.store_key.as_ref() != Some(&store_key) {
+        *cooldown_alerts = CooldownAlerts::load(store_key);
+        ui_state.skills_on_cooldown.clear();
+        ui_state.warned_status_effects.clear();
+    }
+
+    let cooldown_alerts = &mut *cooldown_alerts;
+    let mut play_sound = false;
+
+    ui_state
+        .skills_on_cooldown
+        .retain(|skill_id| cooldown_alerts.skills.contains(skill_id));
+
+    for &skill_id in cooldown_alerts.skills.iter() {
+        let Some(skill_data) =
+            SkillId::new(skill_id).and_then(|skill_id| game_data.skills.get_skill(skill_id))
+        else {
+            continue;
+        };
+
+        // Only the skill's own cooldown counts, the global cooldown is too short to alert
+        let on_cooldown = match &skill_data.cooldown {
+            SkillCooldown::Skill { .. } => cooldowns.has_skill_cooldown(skill_data.id),
+            SkillCooldown::Group { group, .. } => cooldowns.has_skill_group_cooldown(group.get()),
+        };
+
+        if on_cooldown {
+            ui_state.skills_on_cooldown.insert(skill_id);
+        } else if ui_state.skills_on_cooldown.remove(&skill_id) {
+            if cooldown_alert_settings.hotbar_pulse {
+                cooldown_alerts
+                    .ready_pulses
+                    .insert(skill_id, COOLDOWN_ALERT_PULSE_DURATION);
+            }
+
+            if cooldown_alert_settings.toast {
+                ui_state.toasts.push((
+                    localize!("cooldown-alert-ready", name = skill_data.name),
+                    COOLDOWN_ALERT_TOAST_DURATION,
+                ));
+            }
+
+            play_sound = true;
+        }
+    }
+
+    let now = time.last_update().unwrap();
+    let mut expiring_status_effects = HashSet::new();
+    for (status_effect_type, active_status_effect) in status_effects.active.iter() {
+        let Some(active_status_effect) = active_status_effect else {
+            continue;
+        };
+        let status_effect_id = active_status_effect.id.get();
+        if !cooldown_alerts.status_effects.contains(&status_effect_id) {
+            continue;
+        }
+
+        let Some(expire_time) = status_effects.expire_times[status_effect_type] else {
+            continue;
+        };
+        let remaining_time = expire_time.saturating_duration_since(now);
+        if remaining_time > cooldown_alert_settings.status_effect_warning {
+            continue;
+        }
+
+        expiring_status_effects.insert(status_effect_id);
+        if ui_state.warned_status_effects.contains(&status_effect_id) {
+            continue;
+        }
+
+        if cooldown_alert_settings.toast {
+            if let Some(status_effect_data) = game_data
+                .status_effects
+                .get_status_effect(active_status_effect.id)
+            {
+                ui_state.toasts.push((
+                    localize!(
+                        "cooldown-alert-expiring",
+                        name = status_effect_data.name,
+                        seconds = remaining_time.as_secs()
+                    ),
+                    COOLDOWN_ALERT_TOAST_DURATION,
+                ));
+            }
+        }
+
+        play_sound = true;
+    }
+
+    // Forget effects which have expired or been renewed, so they can warn again
+    ui_state.warned_status_effects = expiring_status_effects;
+
+    if play_sound && cooldown_alert_settings.sound {
+        if let Some(sound_id) = SoundId::new(cooldown_alert_settings.sound_id) {
+            ui_sound_events.send(UiSoundEvent::new(sound_id));
+        }
+    }
+
+    cooldown_alerts.ready_pulses.retain(|_, remaining| {
+        *remaining -= time.delta_seconds();
+        *remaining > 0.0
+    });
+
+    ui_state.toasts.retain_mut(|(_, remaining)| {
+        *remaining -= time.delta_seconds();
+        *remaining > 0.0
+    });
+
+    let ctx = egui_context.ctx_mut();
+    for (index, (text, remaining)) in ui_state.toasts.iter().enumerate() {
+        let alpha = (remaining / COOLDOWN_ALERT_TOAST_FADE_DURATION).min(1.0);
+
+        // Above the hotbar, away from the event reminders at the top of the screen
+        egui::Area::new(egui::Id::new("cooldown_alert_toast").with(index))
+            .order(egui::Order::Foreground)
+            .interactable(false)
+            .anchor(
+                egui::Align2::CENTER_BOTTOM,
+                [0.0, -120.0 - index as f32 * 32.0],
+            )
+            .show(ctx, |ui| {
+                egui::Frame::none()
+                    .fill(egui::Color32::from_black_alpha((160.0 * alpha) as u8))
+                    .rounding(egui::Rounding::same(4.0))
+                    .inner_margin(egui::Margin::symmetric(10.0, 4.0))
+                    .show(ui, |ui| {
+                        ui.label(
+                            egui::RichText::new(text.as_str())
+                                .color(egui::Color32::from_white_alpha((255.0 * alpha) as u8)),
+                        );
+                    });
+            });
+    }
+}
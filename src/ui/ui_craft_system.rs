@@ -0,0 +1,289 @@
+use bevy::{
+    ecs::query::WorldQuery,
+    prelude::{EventReader, Local, Query, Res, ResMut, With},
+};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_data::{EquipmentIndex, Item, ItemType};
+use rose_game_common::{
+    components::{Equipment, Inventory, InventoryPageType, ItemSlot},
+    messages::{client::ClientMessage, server::CraftInsertGemError},
+};
+
+use crate::{
+    components::PlayerCharacter,
+    events::CraftEvent,
+    localize,
+    resources::{GameConnection, GameData, UiResources},
+    ui::{
+        tooltips::{PlayerTooltipQuery, PlayerTooltipQueryItem},
+        ui_add_item_tooltip, DragAndDropId, DragAndDropSlot, UiStateDragAndDrop, UiStateWindows,
+    },
+};
+
+const CRAFT_SOCKET_ITEMS_PER_ROW: usize = 5;
+
+#[derive(Default)]
+pub struct UiStateCraft {
+    gem_item_slot: Option<ItemSlot>,
+    equipment_index: Option<EquipmentIndex>,
+    pending_insert_gem: bool,
+    result: Option<Result<String, String>>,
+}
+
+#[derive(WorldQuery)]
+pub struct PlayerQuery<'w> {
+    equipment: &'w Equipment,
+    inventory: &'w Inventory,
+}
+
+fn gem_slot_drag_accepts(drag_source: &DragAndDropId) -> bool {
+    matches!(
+        drag_source,
+        DragAndDropId::Inventory(ItemSlot::Inventory(InventoryPageType::Materials, _))
+    )
+}
+
+fn get_gem_item<'a>(inventory: &'a Inventory, item_slot: Option<ItemSlot>) -> Option<&'a Item> {
+    item_slot
+        .and_then(|item_slot| inventory.get_item(item_slot))
+        .filter(|item| item.get_item_type() == ItemType::Gem)
+}
+
+fn ui_add_craft_item_slot(
+    ui: &mut egui::Ui,
+    ui_state_dnd: &mut UiStateDragAndDrop,
+    item: Option<&Item>,
+    accepts: fn(&DragAndDropId) -> bool,
+    selected: bool,
+    player_tooltip_data: Option<&PlayerTooltipQueryItem>,
+    game_data: &GameData,
+    ui_resources: &UiResources,
+) -> (egui::Response, Option<DragAndDropId>) {
+    let mut dropped_item = None;
+    let response = egui::Widget::ui(
+        DragAndDropSlot::with_item(
+            DragAndDropId::NotDraggable,
+            item,
+            None,
+            game_data,
+            ui_resources,
+            accepts,
+            &mut ui_state_dnd.dragged_item,
+            &mut dropped_item,
+            [40.0, 40.0],
+        ),
+        ui,
+    );
+
+    if selected {
+        ui.painter().rect_stroke(
+            response.rect.expand(1.0),
+            egui::Rounding::none(),
+            egui::Stroke::new(2.0, egui::Color32::YELLOW),
+        );
+    }
+
+    let response = if let Some(item) = item {
+        response.on_hover_ui(|ui| {
+            ui_add_item_tooltip(ui, game_data, player_tooltip_data, item);
+        })
+    } else {
+        response
+    };
+
+    (response, dropped_item)
+}
+
+pub fn ui_craft_system(
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiStateCraft>,
+    mut ui_state_dnd: ResMut<UiStateDragAndDrop>,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    mut craft_events: EventReader<CraftEvent>,
+    game_connection: Option<Res<GameConnection>>,
+    game_data: Res<GameData>,
+    ui_resources: Res<UiResources>,
+    query_player: Query<PlayerQuery, With<PlayerCharacter>>,
+    query_player_tooltip: Query<PlayerTooltipQuery, With<PlayerCharacter>>,
+) {
+    let ui_state = &mut *ui_state;
+
+    for event in craft_events.iter() {
+        match event {
+            CraftEvent::OpenInsertGem { gem_item_slot } => {
+                ui_state.gem_item_slot = Some(*gem_item_slot);
+                ui_state.result = None;
+                ui_state_windows.craft_open = true;
+            }
+            CraftEvent::InsertGemSuccess => {
+                ui_state.pending_insert_gem = false;
+                ui_state.gem_item_slot = None;
+                ui_state.equipment_index = None;
+                ui_state.result = Some(Ok(localize!("craft-insert-gem-success")));
+            }
+            CraftEvent::InsertGemError { error } => {
+                ui_state.pending_insert_gem = false;
+                ui_state.result = Some(Err(match error {
+                    CraftInsertGemError::NoSocket => localize!("craft-insert-gem-no-socket"),
+                    CraftInsertGemError::SocketFull => localize!("craft-insert-gem-socket-full"),
+                }));
+            }
+        }
+    }
+
+    if !ui_state_windows.craft_open {
+        return;
+    }
+
+    let Ok(player) = query_player.get_single() else {
+        return;
+    };
+    let player_tooltip_data = query_player_tooltip.get_single().ok();
+
+    // Forget the gem if it has been moved out of its inventory slot
+    if get_gem_item(player.inventory, ui_state.gem_item_slot).is_none() {
+        ui_state.gem_item_slot = None;
+    }
+
+    let socket_items: Vec<(EquipmentIndex, Item)> = player
+        .equipment
+        .equipped_items
+        .iter()
+        .filter_map(|(equipment_index, equipment_item)| {
+            equipment_item
+                .as_ref()
+                .filter(|equipment_item| equipment_item.has_socket)
+                .map(|equipment_item| (equipment_index, Item::Equipment(equipment_item.clone())))
+        })
+        .collect();
+
+    if ui_state.equipment_index.map_or(false, |equipment_index| {
+        !socket_items
+            .iter()
+            .any(|(socket_index, _)| *socket_index == equipment_index)
+    }) {
+        ui_state.equipment_index = None;
+    }
+
+    // Gem item numbers start after 300, lower values are an empty socket
+    let socket_full = ui_state
+        .equipment_index
+        .and_then(|equipment_index| player.equipment.get_equipment_item(equipment_index))
+        .map_or(false, |equipment_item| equipment_item.gem > 300);
+    let mut insert_gem = false;
+
+    egui::Window::new(localize!("craft-insert-gem-title"))
+        .id(egui::Id::new("craft_insert_gem"))
+        .open(&mut ui_state_windows.craft_open)
+        .resizable(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            ui.horizontal(|ui| {
+                let (_, dropped_item) = ui_add_craft_item_slot(
+                    ui,
+                    &mut ui_state_dnd,
+                    get_gem_item(player.inventory, ui_state.gem_item_slot),
+                    gem_slot_drag_accepts,
+                    false,
+                    player_tooltip_data.as_ref(),
+                    &game_data,
+                    &ui_resources,
+                );
+
+                if let Some(DragAndDropId::Inventory(item_slot)) = dropped_item {
+                    if get_gem_item(player.inventory, Some(item_slot)).is_some() {
+                        ui_state.gem_item_slot = Some(item_slot);
+                        ui_state.result = None;
+                    }
+                }
+
+                ui.label(localize!("craft-insert-gem-drop-gem"));
+            });
+
+            ui.separator();
+            ui.label(localize!("craft-insert-gem-select-item"));
+
+            if socket_items.is_empty() {
+                ui.label(egui::RichText::new(localize!("craft-insert-gem-no-items")).weak());
+            }
+
+            egui::Grid::new("craft_socket_items")
+                .spacing([4.0, 4.0])
+                .show(ui, |ui| {
+                    for (index, (equipment_index, item)) in socket_items.iter().enumerate() {
+                        let (response, _) = ui_add_craft_item_slot(
+                            ui,
+                            &mut ui_state_dnd,
+                            Some(item),
+                            |_| false,
+                            ui_state.equipment_index == Some(*equipment_index),
+                            player_tooltip_data.as_ref(),
+                            &game_data,
+                            &ui_resources,
+                        );
+
+                        if response.clicked() {
+                            ui_state.equipment_index = Some(*equipment_index);
+                            ui_state.result = None;
+                        }
+
+                        if (index + 1) % CRAFT_SOCKET_ITEMS_PER_ROW == 0 {
+                            ui.end_row();
+                        }
+                    }
+                });
+
+            ui.separator();
+
+            if socket_full {
+                ui.colored_label(
+                    egui::Color32::RED,
+                    localize!("craft-insert-gem-socket-full"),
+                );
+            }
+
+            match ui_state.result.as_ref() {
+                Some(Ok(message)) => {
+                    ui.colored_label(egui::Color32::LIGHT_GREEN, message);
+                }
+                Some(Err(message)) => {
+                    ui.colored_label(egui::Color32::RED, message);
+                }
+                None => {}
+            }
+
+            ui.horizontal(|ui| {
+                insert_gem = ui
+                    .add_enabled(
+                        !ui_state.pending_insert_gem
+                            && !socket_full
+                            && ui_state.gem_item_slot.is_some()
+                            && ui_state.equipment_index.is_some(),
+                        egui::Button::new(localize!("craft-insert-gem-button")),
+                    )
+                    .clicked();
+
+                if ui_state.pending_insert_gem {
+                    ui.spinner();
+                }
+            });
+        });
+
+    if insert_gem {
+        if let (Some(game_connection), Some(gem_item_slot), Some(equipment_index)) = (
+            game_connection.as_ref(),
+            ui_state.gem_item_slot,
+            ui_state.equipment_index,
+        ) {
+            game_connection
+                .client_message_tx
+                .send(ClientMessage::CraftInsertGem {
+                    equipment_index,
+                    item_slot: gem_item_slot,
+                })
+                .ok();
+            ui_state.pending_insert_gem = true;
+            ui_state.result = None;
+        }
+    }
+}
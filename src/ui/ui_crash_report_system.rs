@@ -0,0 +1,44 @@
+use bevy::prelude::{Commands, Res};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::crash_report::{open_crash_report_dir, PendingCrashReport};
+
+pub fn ui_crash_report_system(
+    mut commands: Commands,
+    mut egui_context: EguiContexts,
+    pending_crash_report: Option<Res<PendingCrashReport>>,
+) {
+    let Some(pending_crash_report) = pending_crash_report else {
+        return;
+    };
+
+    let mut close = false;
+    egui::Window::new("Crash Report")
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .collapsible(false)
+        .resizable(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            ui.label("rose-offline-client crashed the last time it was run.");
+            ui.label(format!(
+                "A crash report was saved to {}",
+                pending_crash_report.path.to_string_lossy()
+            ));
+
+            ui.horizontal(|ui| {
+                if ui.button("Open Folder").clicked() {
+                    if let Err(error) = open_crash_report_dir() {
+                        log::error!("Failed to open crash report folder with error: {}", error);
+                    }
+                    close = true;
+                }
+
+                if ui.button("Close").clicked() {
+                    close = true;
+                }
+            });
+        });
+
+    if close {
+        commands.remove_resource::<PendingCrashReport>();
+    }
+}
@@ -1,66 +1,77 @@
-use bevy::prelude::{Local, Query, Res, ResMut, Time, With};
+use bevy::prelude::{Entity, Local, Query, Res, ResMut, With};
 use bevy_egui::{egui, EguiContexts};
-use std::collections::VecDeque;
 use std::fmt::Write;
 
-use rose_game_common::components::MoveMode;
+use rose_game_common::components::{MoveMode, MoveSpeed};
 
 use crate::{
     components::{
         Command, CommandCastSkillState, CommandCastSkillTarget, CommandSit, PlayerCharacter,
     },
+    resources::{
+        EntityHistory, EntityHistoryEvent, SelectedTarget, ENTITY_HISTORY_MAX_DURATION,
+        ENTITY_HISTORY_MIN_DURATION,
+    },
     ui::UiStateDebugWindows,
 };
 
-#[derive(Default)]
+// Position changes faster than this multiple of the move speed are highlighted as a jump
+const POSITION_JUMP_SPEED_SCALE: f32 = 1.5;
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+enum CommandViewerTarget {
+    #[default]
+    Player,
+    SelectedTarget,
+}
+
 pub struct UiStateDebugCommandViewer {
-    pub current_command: Option<Command>,
-    pub current_duration: f32,
-    pub command_history: VecDeque<(Command, f32)>,
+    target: CommandViewerTarget,
+    show_commands: bool,
+    show_server_messages: bool,
+    show_positions: bool,
 }
 
-pub fn format_command(ui: &mut egui::Ui, command: &Command) {
-    match command {
-        Command::Stop => {
-            ui.label("Stop");
-        }
-        Command::Move(command_move) => {
-            ui.label(format!(
-                "{} to ({}, {})",
-                match command_move.move_mode {
-                    Some(MoveMode::Run) => "Run",
-                    Some(MoveMode::Walk) => "Walk",
-                    Some(MoveMode::Drive) => "Drive",
-                    None => "Move",
-                },
-                command_move.destination.x,
-                command_move.destination.y
-            ));
-        }
-        Command::Attack(command_attack) => {
-            ui.label(format!("Attack {}", command_attack.target.index()));
-        }
-        Command::Die => {
-            ui.label("Die");
-        }
-        Command::PickupItem(pickup_entity) => {
-            ui.label(format!("Pickup {}", pickup_entity.index()));
-        }
-        Command::Emote(command_emote) => {
-            ui.label(format!("Emote {}", command_emote.motion_id.get()));
-        }
-        Command::PersonalStore => {
-            ui.label("Personal Store");
-        }
-        Command::Sit(CommandSit::Sit) => {
-            ui.label("Sit");
-        }
-        Command::Sit(CommandSit::Sitting) => {
-            ui.label("Sit (Sitting)");
-        }
-        Command::Sit(CommandSit::Standing) => {
-            ui.label("Sit (Standing)");
+impl Default for UiStateDebugCommandViewer {
+    fn default() -> Self {
+        Self {
+            target: CommandViewerTarget::default(),
+            show_commands: true,
+            show_server_messages: true,
+            show_positions: false,
         }
+    }
+}
+
+struct TimelineRow {
+    time: f32,
+    kind: &'static str,
+    description: String,
+    highlight: bool,
+}
+
+fn format_command(command: &Command) -> String {
+    match command {
+        Command::Stop => "Stop".to_string(),
+        Command::Move(command_move) => format!(
+            "{} to ({}, {})",
+            match command_move.move_mode {
+                Some(MoveMode::Run) => "Run",
+                Some(MoveMode::Walk) => "Walk",
+                Some(MoveMode::Drive) => "Drive",
+                None => "Move",
+            },
+            command_move.destination.x,
+            command_move.destination.y
+        ),
+        Command::Attack(command_attack) => format!("Attack {}", command_attack.target.index()),
+        Command::Die => "Die".to_string(),
+        Command::PickupItem(pickup_entity) => format!("Pickup {}", pickup_entity.index()),
+        Command::Emote(command_emote) => format!("Emote {}", command_emote.motion_id.get()),
+        Command::PersonalStore => "Personal Store".to_string(),
+        Command::Sit(CommandSit::Sit) => "Sit".to_string(),
+        Command::Sit(CommandSit::Sitting) => "Sit (Sitting)".to_string(),
+        Command::Sit(CommandSit::Standing) => "Sit (Standing)".to_string(),
         Command::CastSkill(command_cast_skill) => {
             let mut label = String::with_capacity(128);
             write!(label, "Cast skill {}", command_cast_skill.skill_id.get()).ok();
@@ -100,7 +111,7 @@ pub fn format_command(ui: &mut egui::Ui, command: &Command) {
                 }
             }
 
-            ui.label(label);
+            label
         }
     }
 }
@@ -108,62 +119,186 @@ pub fn format_command(ui: &mut egui::Ui, command: &Command) {
 pub fn ui_debug_command_viewer_system(
     mut egui_context: EguiContexts,
     mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
-    mut ui_state_debug_command_viewer: Local<UiStateDebugCommandViewer>,
-    query_player: Query<&Command, With<PlayerCharacter>>,
-    time: Res<Time>,
+    mut ui_state: Local<UiStateDebugCommandViewer>,
+    mut entity_history: ResMut<EntityHistory>,
+    selected_target: Res<SelectedTarget>,
+    query_player: Query<Entity, With<PlayerCharacter>>,
+    query_move_speed: Query<&MoveSpeed>,
 ) {
-    if !ui_state_debug_windows.debug_ui_open {
+    // Only record history while the window is visible
+    if !ui_state_debug_windows.debug_ui_open || !ui_state_debug_windows.command_viewer_open {
+        entity_history.set_entity(None);
         return;
     }
 
-    let ctx = egui_context.ctx_mut();
+    let ui_state = &mut *ui_state;
+    let entity = match ui_state.target {
+        CommandViewerTarget::Player => query_player.get_single().ok(),
+        CommandViewerTarget::SelectedTarget => selected_target.selected,
+    };
+    entity_history.set_entity(entity);
+
+    let now = entity_history.elapsed();
+    let jump_speed = entity
+        .and_then(|entity| query_move_speed.get(entity).ok())
+        .map(|move_speed| move_speed.speed * POSITION_JUMP_SPEED_SCALE);
+
+    let mut rows = Vec::new();
+    if ui_state.show_commands || ui_state.show_server_messages {
+        for (index, (time, event)) in entity_history.events.iter().enumerate() {
+            match event {
+                EntityHistoryEvent::Command(command) if ui_state.show_commands => {
+                    // A command lasts until the next command event, or until now
+                    let end_time = entity_history
+                        .events
+                        .iter()
+                        .skip(index + 1)
+                        .find(|(_, event)| matches!(event, EntityHistoryEvent::Command(_)))
+                        .map_or(now, |(time, _)| *time);
+
+                    rows.push(TimelineRow {
+                        time: *time,
+                        kind: "Command",
+                        description: format!(
+                            "{} ({:.3}s)",
+                            format_command(command),
+                            end_time - time
+                        ),
+                        highlight: false,
+                    });
+                }
+                EntityHistoryEvent::NextCommand(next_command) if ui_state.show_commands => {
+                    rows.push(TimelineRow {
+                        time: *time,
+                        kind: "Next",
+                        description: next_command
+                            .as_ref()
+                            .map_or_else(|| "None".to_string(), format_command),
+                        highlight: false,
+                    });
+                }
+                EntityHistoryEvent::ServerMessage(message) if ui_state.show_server_messages => {
+                    rows.push(TimelineRow {
+                        time: *time,
+                        kind: "Server",
+                        description: message.clone(),
+                        highlight: false,
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if ui_state.show_positions {
+        for position in entity_history.positions.iter() {
+            rows.push(TimelineRow {
+                time: position.time,
+                kind: "Position",
+                description: format!(
+                    "({:.0}, {:.0}, {:.0}) {:.0}/s",
+                    position.position.x, position.position.y, position.position.z, position.speed
+                ),
+                highlight: jump_speed.map_or(false, |jump_speed| position.speed > jump_speed),
+            });
+        }
+    }
+    rows.sort_by(|a, b| a.time.total_cmp(&b.time));
+
     egui::Window::new("Command Viewer")
-        .resizable(false)
+        .resizable(true)
+        .default_width(500.0)
         .open(&mut ui_state_debug_windows.command_viewer_open)
-        .show(ctx, |ui| {
-            if let Ok(command) = query_player.get_single() {
-                if ui_state_debug_command_viewer.current_command.as_ref() == Some(command) {
-                    ui_state_debug_command_viewer.current_duration += time.delta_seconds();
-                } else {
-                    if let Some(last_command) = ui_state_debug_command_viewer.current_command.take()
-                    {
-                        if ui_state_debug_command_viewer.command_history.len() > 20 {
-                            ui_state_debug_command_viewer.command_history.pop_front();
+        .show(egui_context.ctx_mut(), |ui| {
+            egui::Grid::new("command_viewer_controls_grid")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label("Entity:");
+                    ui.horizontal(|ui| {
+                        ui.selectable_value(
+                            &mut ui_state.target,
+                            CommandViewerTarget::Player,
+                            "Player",
+                        );
+                        ui.selectable_value(
+                            &mut ui_state.target,
+                            CommandViewerTarget::SelectedTarget,
+                            "Selected Target",
+                        );
+
+                        if let Some(entity) = entity {
+                            ui.label(format!("{}", entity.index()));
+                        } else {
+                            ui.label("None");
                         }
+                    });
+                    ui.end_row();
 
-                        let duration = ui_state_debug_command_viewer.current_duration;
-                        ui_state_debug_command_viewer
-                            .command_history
-                            .push_back((last_command, duration));
-                    }
+                    ui.label("History:");
+                    ui.add(
+                        egui::Slider::new(
+                            &mut entity_history.duration,
+                            ENTITY_HISTORY_MIN_DURATION..=ENTITY_HISTORY_MAX_DURATION,
+                        )
+                        .suffix("s"),
+                    );
+                    ui.end_row();
 
-                    ui_state_debug_command_viewer.current_command = Some(command.clone());
-                    ui_state_debug_command_viewer.current_duration = 0.0;
-                }
+                    ui.label("Show:");
+                    ui.horizontal(|ui| {
+                        ui.checkbox(&mut ui_state.show_commands, "Commands");
+                        ui.checkbox(&mut ui_state.show_server_messages, "Server Messages");
+                        ui.checkbox(&mut ui_state.show_positions, "Positions");
 
-                egui::Grid::new("command_view_grid")
-                    .num_columns(2)
-                    .striped(true)
-                    .show(ui, |ui| {
-                        for (previous_command, duration) in
-                            ui_state_debug_command_viewer.command_history.iter()
-                        {
-                            format_command(ui, previous_command);
-                            ui.label(format!("{: >2.3}s", duration));
-                            ui.end_row();
+                        if ui.button("Clear").clicked() {
+                            entity_history.clear();
                         }
+                    });
+                    ui.end_row();
+                });
 
-                        if let Some(current_command) =
-                            ui_state_debug_command_viewer.current_command.as_ref()
-                        {
-                            format_command(ui, current_command);
-                            ui.label(format!(
-                                "{: >2.3}s",
-                                ui_state_debug_command_viewer.current_duration
-                            ));
-                            ui.end_row();
-                        }
+            ui.separator();
+
+            egui_extras::TableBuilder::new(ui)
+                .striped(true)
+                .stick_to_bottom(true)
+                .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                .column(egui_extras::Column::initial(60.0).at_least(60.0))
+                .column(egui_extras::Column::initial(60.0).at_least(60.0))
+                .column(egui_extras::Column::remainder().at_least(200.0).clip(true))
+                .header(20.0, |mut header| {
+                    header.col(|ui| {
+                        ui.heading("Time");
                     });
-            }
+                    header.col(|ui| {
+                        ui.heading("Type");
+                    });
+                    header.col(|ui| {
+                        ui.heading("Description");
+                    });
+                })
+                .body(|body| {
+                    body.rows(20.0, rows.len(), |row_index, mut row| {
+                        let Some(timeline_row) = rows.get(row_index) else {
+                            return;
+                        };
+
+                        row.col(|ui| {
+                            ui.label(format!("{:.3}s", timeline_row.time - now));
+                        });
+                        row.col(|ui| {
+                            ui.label(timeline_row.kind);
+                        });
+                        row.col(|ui| {
+                            let text = if timeline_row.highlight {
+                                egui::RichText::new(&timeline_row.description)
+                                    .color(egui::Color32::RED)
+                            } else {
+                                egui::RichText::new(&timeline_row.description)
+                            };
+                            ui.label(text).on_hover_text(&timeline_row.description);
+                        });
+                    });
+                });
         });
 }
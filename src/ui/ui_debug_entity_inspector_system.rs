@@ -1,10 +1,63 @@
 use bevy::{
-    prelude::{Camera3d, DirectionalLight, Entity, Mut, With, World},
+    prelude::{AppTypeRegistry, DirectionalLight, Entity, Mut, With, World},
+    reflect::ReflectFromPtr,
     window::PrimaryWindow,
 };
 use bevy_egui::EguiContext;
 
-use crate::{components::PlayerCharacter, resources::DebugInspector, ui::UiStateDebugWindows};
+use crate::{
+    components::{MainCamera, PlayerCharacter},
+    resources::{DebugInspector, DebugInspectorSnapshot},
+    ui::{show_detachable_debug_window, DetachableDebugWindow, UiStateDebugWindows},
+};
+
+fn snapshot_entity(world: &World, entity: Entity) -> Option<DebugInspectorSnapshot> {
+    let type_registry = world.resource::<AppTypeRegistry>().read();
+    let entity_ref = world.get_entity(entity)?;
+    let components = entity_ref
+        .archetype()
+        .components()
+        .filter_map(|component_id| {
+            let type_id = world.components().get_info(component_id)?.type_id()?;
+            let reflect_from_ptr = type_registry.get_type_data::<ReflectFromPtr>(type_id)?;
+            let component = entity_ref.get_by_id(component_id)?;
+
+            // SAFETY: reflect_from_ptr was registered for the type of this component
+            let value = unsafe { reflect_from_ptr.as_reflect_ptr(component) };
+            Some((component_id, value.clone_value()))
+        })
+        .collect();
+
+    Some(DebugInspectorSnapshot { entity, components })
+}
+
+fn revert_entity(world: &mut World, snapshot: &DebugInspectorSnapshot) {
+    let type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = type_registry.read();
+
+    for (component_id, value) in snapshot.components.iter() {
+        let Some(type_id) = world
+            .components()
+            .get_info(*component_id)
+            .and_then(|component_info| component_info.type_id())
+        else {
+            continue;
+        };
+        let Some(reflect_from_ptr) = type_registry.get_type_data::<ReflectFromPtr>(type_id) else {
+            continue;
+        };
+        let Some(mut entity_mut) = world.get_entity_mut(snapshot.entity) else {
+            return;
+        };
+        let Some(component) = entity_mut.get_mut_by_id(*component_id) else {
+            // Component has been removed since the snapshot
+            continue;
+        };
+
+        // SAFETY: reflect_from_ptr was registered for the type of this component
+        unsafe { reflect_from_ptr.as_reflect_ptr_mut(component.into_inner()) }.apply(&**value);
+    }
+}
 
 pub fn ui_debug_entity_inspector_system(world: &mut World) {
     let mut egui_context = world
@@ -19,18 +72,29 @@ pub fn ui_debug_entity_inspector_system(world: &mut World) {
                     return;
                 }
 
-                egui::Window::new("Entity Inspector")
-                    .open(&mut ui_state_debug_windows.object_inspector_open)
-                    .resizable(true)
-                    .vscroll(true)
-                    .show(egui_context.get_mut(), |ui| {
+                let ui_state_debug_windows = &mut *ui_state_debug_windows;
+                let mut detached_egui_context = ui_state_debug_windows.detached_windows
+                    [DetachableDebugWindow::EntityInspector]
+                    .and_then(|window| world.get::<EguiContext>(window).cloned());
+
+                show_detachable_debug_window(
+                    egui_context.get_mut(),
+                    detached_egui_context
+                        .as_mut()
+                        .map(|detached_egui_context| &*detached_egui_context.get_mut()),
+                    egui::Window::new("Entity Inspector")
+                        .open(&mut ui_state_debug_windows.object_inspector_open)
+                        .resizable(true)
+                        .vscroll(true),
+                    &mut ui_state_debug_windows.detached[DetachableDebugWindow::EntityInspector],
+                    |ui| {
                         ui.style_mut().wrap = Some(false);
 
                         ui.horizontal(|ui| {
                             if ui.button("Camera").clicked() {
                                 debug_inspector_state.entity = Some(
                                     world
-                                        .query_filtered::<Entity, With<Camera3d>>()
+                                        .query_filtered::<Entity, With<MainCamera>>()
                                         .single(world),
                                 );
                             }
@@ -60,9 +124,41 @@ pub fn ui_debug_entity_inspector_system(world: &mut World) {
                         ui.separator();
 
                         if let Some(entity) = debug_inspector_state.entity {
+                            // Take a snapshot of newly selected entities so edits can be reverted
+                            if debug_inspector_state
+                                .snapshot
+                                .as_ref()
+                                .map_or(true, |snapshot| snapshot.entity != entity)
+                            {
+                                debug_inspector_state.snapshot = snapshot_entity(world, entity);
+                            }
+
+                            ui.horizontal(|ui| {
+                                if ui
+                                    .button("Revert")
+                                    .on_hover_text("Restore the values from the last snapshot")
+                                    .clicked()
+                                {
+                                    if let Some(snapshot) = debug_inspector_state.snapshot.as_ref()
+                                    {
+                                        revert_entity(world, snapshot);
+                                    }
+                                }
+
+                                if ui
+                                    .button("Snapshot")
+                                    .on_hover_text("Save the current values to revert to")
+                                    .clicked()
+                                {
+                                    debug_inspector_state.snapshot = snapshot_entity(world, entity);
+                                }
+                            });
+                            ui.separator();
+
                             bevy_inspector_egui::bevy_inspector::ui_for_entity(world, entity, ui);
                         }
-                    });
+                    },
+                );
             });
         },
     );
@@ -0,0 +1,300 @@
+use std::{cmp::Ordering, collections::BTreeMap, time::Duration};
+
+use bevy::prelude::{Local, ResMut};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    frame_profiler::{FrameProfiler, FrameProfilerSpanKind},
+    localize,
+    ui::{show_detachable_debug_window, DetachableDebugWindow, UiStateDebugWindows},
+};
+
+const FLAME_ROW_HEIGHT: f32 = 14.0;
+const FLAME_SCHEDULE_COLOR: egui::Color32 = egui::Color32::from_rgb(70, 110, 170);
+const FLAME_SYSTEM_COLOR: egui::Color32 = egui::Color32::from_rgb(200, 130, 50);
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+enum FrameProfilerSortColumn {
+    Name,
+    Kind,
+    #[default]
+    Time,
+    Average,
+    Calls,
+}
+
+#[derive(Default)]
+pub struct UiStateDebugFrameProfiler {
+    sort_column: FrameProfilerSortColumn,
+    sort_ascending: bool,
+    show_schedules: bool,
+    last_export: Option<String>,
+}
+
+fn sort_header(
+    ui: &mut egui::Ui,
+    ui_state: &mut UiStateDebugFrameProfiler,
+    column: FrameProfilerSortColumn,
+    label: &str,
+) {
+    let selected = ui_state.sort_column == column;
+    let text = if !selected {
+        label.to_string()
+    } else if ui_state.sort_ascending {
+        format!("{} ^", label)
+    } else {
+        format!("{} v", label)
+    };
+
+    if ui.selectable_label(selected, text).clicked() {
+        if selected {
+            ui_state.sort_ascending = !ui_state.sort_ascending;
+        } else {
+            ui_state.sort_column = column;
+            ui_state.sort_ascending = matches!(
+                column,
+                FrameProfilerSortColumn::Name | FrameProfilerSortColumn::Kind
+            );
+        }
+    }
+}
+
+fn draw_flame_graph(ui: &mut egui::Ui, frame_profiler: &FrameProfiler) {
+    let frame_duration = frame_profiler
+        .frame_end
+        .saturating_sub(frame_profiler.frame_start)
+        .as_secs_f32()
+        .max(0.000001);
+
+    // Group spans by thread, the depth of each span is how many spans on the
+    // same thread are still running when it starts
+    let mut threads: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for (index, span) in frame_profiler.frame_spans.iter().enumerate() {
+        threads.entry(span.thread_index).or_default().push(index);
+    }
+
+    for (thread_index, mut span_indices) in threads {
+        span_indices.sort_by_key(|index| {
+            let span = &frame_profiler.frame_spans[*index];
+            (span.start, std::cmp::Reverse(span.duration))
+        });
+
+        let mut stack: Vec<Duration> = Vec::new();
+        let mut rows = Vec::with_capacity(span_indices.len());
+        for index in span_indices {
+            let span = &frame_profiler.frame_spans[index];
+            while stack.last().map_or(false, |end| *end <= span.start) {
+                stack.pop();
+            }
+            rows.push((index, stack.len()));
+            stack.push(span.start + span.duration);
+        }
+        let max_depth = rows.iter().map(|(_, depth)| *depth).max().unwrap_or(0);
+
+        ui.label(format!("Thread {}", thread_index));
+        let (rect, response) = ui.allocate_exact_size(
+            egui::vec2(
+                ui.available_width(),
+                (max_depth + 1) as f32 * FLAME_ROW_HEIGHT,
+            ),
+            egui::Sense::hover(),
+        );
+        let painter = ui.painter_at(rect);
+        painter.rect_filled(rect, 0.0, egui::Color32::from_black_alpha(100));
+
+        let mut hovered = None;
+        for (index, depth) in rows {
+            let span = &frame_profiler.frame_spans[index];
+            let start = span.start.saturating_sub(frame_profiler.frame_start);
+            let x_min = rect.left() + rect.width() * start.as_secs_f32() / frame_duration;
+            let width = (rect.width() * span.duration.as_secs_f32() / frame_duration).max(1.0);
+            let bar_rect = egui::Rect::from_min_size(
+                egui::pos2(x_min, rect.top() + depth as f32 * FLAME_ROW_HEIGHT),
+                egui::vec2(width, FLAME_ROW_HEIGHT - 1.0),
+            );
+
+            let color = match span.kind {
+                FrameProfilerSpanKind::Schedule => FLAME_SCHEDULE_COLOR,
+                FrameProfilerSpanKind::System => FLAME_SYSTEM_COLOR,
+            };
+            painter.rect_filled(bar_rect, 1.0, color);
+
+            if width > 40.0 {
+                painter.with_clip_rect(bar_rect.intersect(rect)).text(
+                    bar_rect.left_center() + egui::vec2(2.0, 0.0),
+                    egui::Align2::LEFT_CENTER,
+                    &span.name,
+                    egui::FontId::monospace(10.0),
+                    egui::Color32::WHITE,
+                );
+            }
+
+            if response
+                .hover_pos()
+                .map_or(false, |pos| bar_rect.contains(pos))
+            {
+                hovered = Some(span);
+            }
+        }
+
+        if let Some(span) = hovered {
+            response.on_hover_text(format!(
+                "{}\n{:.3} ms",
+                span.name,
+                span.duration.as_secs_f64() * 1000.0
+            ));
+        }
+    }
+}
+
+pub fn ui_debug_frame_profiler_system(
+    mut egui_context: EguiContexts,
+    mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
+    mut ui_state: Local<UiStateDebugFrameProfiler>,
+    mut frame_profiler: ResMut<FrameProfiler>,
+) {
+    if !ui_state_debug_windows.debug_ui_open {
+        return;
+    }
+
+    let ui_state = &mut *ui_state;
+    let ui_state_debug_windows = &mut *ui_state_debug_windows;
+    let detached_ctx = ui_state_debug_windows.detached_windows
+        [DetachableDebugWindow::FrameProfiler]
+        .and_then(|window| egui_context.try_ctx_for_window_mut(window).cloned());
+
+    show_detachable_debug_window(
+        egui_context.ctx_mut(),
+        detached_ctx.as_ref(),
+        egui::Window::new("Frame Profiler")
+            .open(&mut ui_state_debug_windows.frame_profiler_open)
+            .default_width(600.0)
+            .resizable(true),
+        &mut ui_state_debug_windows.detached[DetachableDebugWindow::FrameProfiler],
+        |ui| {
+            if !FrameProfiler::is_enabled() {
+                ui.label("Per-system timings require building with --features profiler");
+                return;
+            }
+
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut frame_profiler.frozen, "Freeze Frame");
+                ui.checkbox(&mut ui_state.show_schedules, "Show Schedules");
+
+                if ui.button("Export Trace").clicked() {
+                    ui_state.last_export = Some(match frame_profiler.export_chrome_trace() {
+                        Ok(path) => localize!(
+                            "frame-profiler-export-succeeded",
+                            path = path.display().to_string()
+                        ),
+                        Err(error) => {
+                            localize!("frame-profiler-export-failed", error = error.to_string())
+                        }
+                    });
+                }
+            });
+
+            if let Some(last_export) = ui_state.last_export.as_ref() {
+                ui.label(last_export);
+            }
+
+            ui.label(format!(
+                "Frame: {:.3} ms, {} spans",
+                frame_profiler
+                    .frame_end
+                    .saturating_sub(frame_profiler.frame_start)
+                    .as_secs_f64()
+                    * 1000.0,
+                frame_profiler.frame_spans.len()
+            ));
+
+            egui::CollapsingHeader::new("Flame Graph")
+                .default_open(true)
+                .show(ui, |ui| {
+                    egui::ScrollArea::vertical()
+                        .id_source("frame_profiler_flame_graph")
+                        .max_height(250.0)
+                        .show(ui, |ui| {
+                            draw_flame_graph(ui, &frame_profiler);
+                        });
+                });
+
+            let mut timings: Vec<_> = frame_profiler
+                .timings
+                .iter()
+                .filter(|timing| {
+                    ui_state.show_schedules || timing.kind == FrameProfilerSpanKind::System
+                })
+                .collect();
+            timings.sort_by(|a, b| {
+                let ordering = match ui_state.sort_column {
+                    FrameProfilerSortColumn::Name => a.name.cmp(&b.name),
+                    FrameProfilerSortColumn::Kind => a.kind.cmp(&b.kind),
+                    FrameProfilerSortColumn::Time => a.total.cmp(&b.total),
+                    FrameProfilerSortColumn::Average => a
+                        .average_ms
+                        .partial_cmp(&b.average_ms)
+                        .unwrap_or(Ordering::Equal),
+                    FrameProfilerSortColumn::Calls => a.calls.cmp(&b.calls),
+                };
+
+                if ui_state.sort_ascending {
+                    ordering
+                } else {
+                    ordering.reverse()
+                }
+            });
+
+            ui.separator();
+
+            egui_extras::TableBuilder::new(ui)
+                .striped(true)
+                .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                .column(egui_extras::Column::remainder().at_least(200.0).clip(true))
+                .column(egui_extras::Column::initial(70.0).at_least(70.0))
+                .column(egui_extras::Column::initial(70.0).at_least(70.0))
+                .column(egui_extras::Column::initial(70.0).at_least(70.0))
+                .column(egui_extras::Column::initial(50.0).at_least(50.0))
+                .header(20.0, |mut header| {
+                    header.col(|ui| {
+                        sort_header(ui, ui_state, FrameProfilerSortColumn::Name, "Name");
+                    });
+                    header.col(|ui| {
+                        sort_header(ui, ui_state, FrameProfilerSortColumn::Kind, "Kind");
+                    });
+                    header.col(|ui| {
+                        sort_header(ui, ui_state, FrameProfilerSortColumn::Time, "Time ms");
+                    });
+                    header.col(|ui| {
+                        sort_header(ui, ui_state, FrameProfilerSortColumn::Average, "Avg ms");
+                    });
+                    header.col(|ui| {
+                        sort_header(ui, ui_state, FrameProfilerSortColumn::Calls, "Calls");
+                    });
+                })
+                .body(|body| {
+                    body.rows(20.0, timings.len(), |row_index, mut row| {
+                        let Some(timing) = timings.get(row_index) else {
+                            return;
+                        };
+
+                        row.col(|ui| {
+                            ui.label(&timing.name);
+                        });
+                        row.col(|ui| {
+                            ui.label(format!("{:?}", timing.kind));
+                        });
+                        row.col(|ui| {
+                            ui.label(format!("{:.3}", timing.total.as_secs_f64() * 1000.0));
+                        });
+                        row.col(|ui| {
+                            ui.label(format!("{:.3}", timing.average_ms));
+                        });
+                        row.col(|ui| {
+                            ui.label(format!("{}", timing.calls));
+                        });
+                    });
+                });
+        },
+    );
+}
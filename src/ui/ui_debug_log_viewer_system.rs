@@ -0,0 +1,129 @@
+use bevy::{
+    log::Level,
+    prelude::{Local, ResMut},
+};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    log_viewer::{LogViewer, LogViewerLine},
+    ui::UiStateDebugWindows,
+};
+
+const LOG_VIEWER_LEVELS: [Level; 5] = [
+    Level::ERROR,
+    Level::WARN,
+    Level::INFO,
+    Level::DEBUG,
+    Level::TRACE,
+];
+
+pub struct UiStateDebugLogViewer {
+    max_level: Level,
+    filter_text: String,
+}
+
+impl Default for UiStateDebugLogViewer {
+    fn default() -> Self {
+        Self {
+            max_level: Level::TRACE,
+            filter_text: String::new(),
+        }
+    }
+}
+
+impl UiStateDebugLogViewer {
+    fn is_visible(&self, line: &LogViewerLine) -> bool {
+        line.level <= self.max_level
+            && (self.filter_text.is_empty()
+                || line
+                    .text
+                    .to_lowercase()
+                    .contains(&self.filter_text.to_lowercase()))
+    }
+}
+
+fn get_level_color(level: Level) -> Option<egui::Color32> {
+    match level {
+        Level::ERROR => Some(egui::Color32::RED),
+        Level::WARN => Some(egui::Color32::YELLOW),
+        _ => None,
+    }
+}
+
+pub fn ui_debug_log_viewer_system(
+    mut egui_context: EguiContexts,
+    mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
+    mut ui_state: Local<UiStateDebugLogViewer>,
+    mut log_viewer: ResMut<LogViewer>,
+) {
+    if !ui_state_debug_windows.debug_ui_open {
+        return;
+    }
+
+    let ui_state = &mut *ui_state;
+    let mut clear_lines = false;
+    egui::Window::new("Log Viewer")
+        .open(&mut ui_state_debug_windows.log_viewer_open)
+        .default_width(800.0)
+        .resizable(true)
+        .show(egui_context.ctx_mut(), |ui| {
+            let visible_lines: Vec<&LogViewerLine> = log_viewer
+                .lines
+                .iter()
+                .filter(|line| ui_state.is_visible(line))
+                .collect();
+
+            ui.horizontal(|ui| {
+                ui.label("Level:");
+                egui::ComboBox::from_id_source("log_viewer_level")
+                    .selected_text(ui_state.max_level.as_str())
+                    .show_ui(ui, |ui| {
+                        for level in LOG_VIEWER_LEVELS {
+                            ui.selectable_value(&mut ui_state.max_level, level, level.as_str());
+                        }
+                    });
+
+                ui.label("Filter:");
+                ui.text_edit_singleline(&mut ui_state.filter_text);
+
+                if ui
+                    .button("Copy")
+                    .on_hover_text("Copy the visible log lines to the clipboard")
+                    .clicked()
+                {
+                    let text = visible_lines
+                        .iter()
+                        .map(|line| line.text.as_str())
+                        .collect::<Vec<_>>()
+                        .join("\n");
+                    ui.output_mut(|output| output.copied_text = text);
+                }
+
+                if ui.button("Clear").clicked() {
+                    clear_lines = true;
+                }
+
+                ui.label(format!("{} lines", visible_lines.len()));
+            });
+
+            ui.separator();
+
+            let row_height = ui.text_style_height(&egui::TextStyle::Monospace);
+            egui::ScrollArea::both()
+                .auto_shrink([false, false])
+                .stick_to_bottom(true)
+                .show_rows(ui, row_height, visible_lines.len(), |ui, row_range| {
+                    for line in &visible_lines[row_range] {
+                        let mut text = egui::RichText::new(&line.text).monospace();
+                        if let Some(color) = get_level_color(line.level) {
+                            text = text.color(color);
+                        }
+                        ui.add(egui::Label::new(text).wrap(false));
+                    }
+                });
+        });
+
+    if clear_lines {
+        log_viewer.lines.clear();
+    }
+}
@@ -0,0 +1,231 @@
+use bevy::prelude::{Local, Res, ResMut};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    scripting::{
+        LuaGameConstants, LuaGameFunctions, LuaQuestFunctions, LuaVMInspector,
+        LuaVMInspectorCallKind,
+    },
+    ui::UiStateDebugWindows,
+};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+enum LuaVMInspectorTab {
+    #[default]
+    Globals,
+    Functions,
+    Calls,
+}
+
+pub struct UiStateDebugLuaVM {
+    tab: LuaVMInspectorTab,
+    filter_name: String,
+    show_builtin_globals: bool,
+    show_rust_calls: bool,
+}
+
+impl Default for UiStateDebugLuaVM {
+    fn default() -> Self {
+        Self {
+            tab: LuaVMInspectorTab::default(),
+            filter_name: String::new(),
+            show_builtin_globals: false,
+            show_rust_calls: true,
+        }
+    }
+}
+
+fn matches_filter(filter_name: &str, name: &str) -> bool {
+    filter_name.is_empty() || name.to_lowercase().contains(&filter_name.to_lowercase())
+}
+
+pub fn ui_debug_lua_vm_system(
+    mut egui_context: EguiContexts,
+    mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
+    mut ui_state: Local<UiStateDebugLuaVM>,
+    mut lua_vm_inspector: ResMut<LuaVMInspector>,
+    lua_game_constants: Res<LuaGameConstants>,
+    lua_game_functions: Res<LuaGameFunctions>,
+    lua_quest_functions: Res<LuaQuestFunctions>,
+) {
+    if !ui_state_debug_windows.debug_ui_open {
+        return;
+    }
+
+    let ui_state = &mut *ui_state;
+    let is_builtin_global = |name: &str| {
+        lua_game_constants.constants.contains_key(name)
+            || lua_game_functions.closures.contains_key(name)
+            || lua_quest_functions.closures.contains_key(name)
+    };
+
+    egui::Window::new("Lua VM")
+        .open(&mut ui_state_debug_windows.lua_vm_open)
+        .default_width(600.0)
+        .resizable(true)
+        .show(egui_context.ctx_mut(), |ui| {
+            ui.label(format!(
+                "Script: {}",
+                lua_vm_inspector.source.as_deref().unwrap_or("None")
+            ));
+
+            ui.horizontal(|ui| {
+                for tab in [
+                    LuaVMInspectorTab::Globals,
+                    LuaVMInspectorTab::Functions,
+                    LuaVMInspectorTab::Calls,
+                ] {
+                    ui.selectable_value(&mut ui_state.tab, tab, format!("{:?}", tab));
+                }
+            });
+
+            ui.horizontal(|ui| {
+                ui.label("Filter:");
+                ui.text_edit_singleline(&mut ui_state.filter_name);
+            });
+
+            ui.separator();
+
+            match ui_state.tab {
+                LuaVMInspectorTab::Globals => {
+                    ui.checkbox(
+                        &mut ui_state.show_builtin_globals,
+                        "Show constants and client functions",
+                    );
+
+                    egui::ScrollArea::vertical()
+                        .id_source("lua_vm_globals")
+                        .auto_shrink([false, false])
+                        .show(ui, |ui| {
+                            egui::Grid::new("lua_vm_globals_grid")
+                                .num_columns(2)
+                                .striped(true)
+                                .show(ui, |ui| {
+                                    for (name, value) in lua_vm_inspector.globals.iter() {
+                                        if !matches_filter(&ui_state.filter_name, name)
+                                            || (!ui_state.show_builtin_globals
+                                                && is_builtin_global(name))
+                                        {
+                                            continue;
+                                        }
+
+                                        ui.monospace(name);
+                                        ui.monospace(value);
+                                        ui.end_row();
+                                    }
+                                });
+                        });
+                }
+                LuaVMInspectorTab::Functions => {
+                    let mut functions: Vec<(&str, &str)> = lua_game_functions
+                        .closures
+                        .keys()
+                        .map(|name| (name.as_str(), "Game"))
+                        .chain(
+                            lua_quest_functions
+                                .closures
+                                .keys()
+                                .map(|name| (name.as_str(), "Quest")),
+                        )
+                        .filter(|(name, _)| matches_filter(&ui_state.filter_name, name))
+                        .collect();
+                    functions.sort();
+
+                    egui::ScrollArea::vertical()
+                        .id_source("lua_vm_functions")
+                        .auto_shrink([false, false])
+                        .show(ui, |ui| {
+                            egui::Grid::new("lua_vm_functions_grid")
+                                .num_columns(2)
+                                .striped(true)
+                                .show(ui, |ui| {
+                                    for (name, source) in functions {
+                                        ui.monospace(name);
+                                        ui.label(source);
+                                        ui.end_row();
+                                    }
+                                });
+                        });
+                }
+                LuaVMInspectorTab::Calls => {
+                    ui.horizontal(|ui| {
+                        ui.checkbox(&mut ui_state.show_rust_calls, "Show client function calls");
+
+                        if ui.button("Clear").clicked() {
+                            lua_vm_inspector.calls.clear();
+                        }
+                    });
+
+                    let visible_calls: Vec<usize> = lua_vm_inspector
+                        .calls
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, call)| {
+                            (ui_state.show_rust_calls || call.kind == LuaVMInspectorCallKind::Lua)
+                                && matches_filter(&ui_state.filter_name, &call.name)
+                        })
+                        .map(|(index, _)| index)
+                        .collect();
+
+                    egui_extras::TableBuilder::new(ui)
+                        .striped(true)
+                        .stick_to_bottom(true)
+                        .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                        .column(egui_extras::Column::initial(70.0).at_least(70.0))
+                        .column(egui_extras::Column::initial(40.0).at_least(40.0))
+                        .column(
+                            egui_extras::Column::initial(250.0)
+                                .at_least(100.0)
+                                .clip(true),
+                        )
+                        .column(egui_extras::Column::remainder().at_least(100.0).clip(true))
+                        .header(20.0, |mut header| {
+                            header.col(|ui| {
+                                ui.heading("Time");
+                            });
+                            header.col(|ui| {
+                                ui.heading("Kind");
+                            });
+                            header.col(|ui| {
+                                ui.heading("Call");
+                            });
+                            header.col(|ui| {
+                                ui.heading("Result");
+                            });
+                        })
+                        .body(|body| {
+                            body.rows(20.0, visible_calls.len(), |row_index, mut row| {
+                                let Some(call) = visible_calls
+                                    .get(row_index)
+                                    .and_then(|index| lua_vm_inspector.calls.get(*index))
+                                else {
+                                    return;
+                                };
+
+                                row.col(|ui| {
+                                    ui.label(call.time.format("%H:%M:%S").to_string());
+                                });
+                                row.col(|ui| {
+                                    ui.label(format!("{:?}", call.kind));
+                                });
+                                row.col(|ui| {
+                                    ui.monospace(format!(
+                                        "{}({})",
+                                        call.name,
+                                        call.parameters.join(", ")
+                                    ));
+                                });
+                                row.col(|ui| match &call.result {
+                                    Ok(values) => {
+                                        ui.monospace(values.join(", "));
+                                    }
+                                    Err(error) => {
+                                        ui.colored_label(egui::Color32::RED, error);
+                                    }
+                                });
+                            });
+                        });
+                }
+            }
+        });
+}
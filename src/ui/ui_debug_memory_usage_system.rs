@@ -0,0 +1,274 @@
+use std::time::Duration;
+
+use bevy::{
+    asset::HandleId,
+    ecs::{archetype::Archetypes, component::Components, entity::Entities},
+    prelude::{AssetServer, Assets, Image, Local, Mesh, Res, ResMut},
+    render::mesh::Indices,
+    time::Time,
+};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    animation::ZmoAsset, audio::AudioSource, resources::SoundCache, ui::UiStateDebugWindows,
+};
+
+const MEMORY_USAGE_UPDATE_INTERVAL: Duration = Duration::from_secs(1);
+const MEMORY_USAGE_NUM_TOP_ASSETS: usize = 10;
+
+#[derive(Default)]
+struct AssetCategoryUsage {
+    count: usize,
+    total_bytes: usize,
+    top_assets: Vec<(String, usize)>,
+}
+
+impl AssetCategoryUsage {
+    fn from_sizes(
+        asset_server: &AssetServer,
+        sizes: impl Iterator<Item = (HandleId, usize)>,
+    ) -> Self {
+        let mut sizes: Vec<(HandleId, usize)> = sizes.collect();
+        sizes.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+        Self {
+            count: sizes.len(),
+            total_bytes: sizes.iter().map(|(_, size)| size).sum(),
+            top_assets: sizes
+                .iter()
+                .take(MEMORY_USAGE_NUM_TOP_ASSETS)
+                .map(|(handle_id, size)| {
+                    let name = asset_server
+                        .get_handle_path(*handle_id)
+                        .map(|asset_path| asset_path.path().to_string_lossy().into_owned())
+                        .unwrap_or_else(|| format!("{:?}", handle_id));
+                    (name, *size)
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct UiStateDebugMemoryUsage {
+    last_update_time: Option<Duration>,
+    images: AssetCategoryUsage,
+    meshes: AssetCategoryUsage,
+    zmo_assets: AssetCategoryUsage,
+    audio_sources: AssetCategoryUsage,
+    num_cached_sounds: usize,
+    num_entities: u32,
+    component_counts: Vec<(String, usize)>,
+}
+
+fn get_zmo_asset_size(zmo_asset: &ZmoAsset) -> usize {
+    zmo_asset.frame_events.len() * std::mem::size_of::<u16>()
+        + zmo_asset
+            .bones
+            .iter()
+            .map(|bone| {
+                bone.translation.len() * std::mem::size_of::<bevy::math::Vec3>()
+                    + bone.rotation.len() * std::mem::size_of::<bevy::math::Quat>()
+                    + bone.scale.len() * std::mem::size_of::<f32>()
+            })
+            .sum::<usize>()
+}
+
+fn get_audio_source_size(audio_source: &AudioSource) -> usize {
+    audio_source.bytes.len()
+        + audio_source.decoded.as_ref().map_or(0, |decoded| {
+            decoded.samples.len() * std::mem::size_of::<f32>()
+        })
+}
+
+fn format_bytes(bytes: usize) -> String {
+    if bytes >= 1024 * 1024 {
+        format!("{:.2} MB", bytes as f64 / (1024.0 * 1024.0))
+    } else if bytes >= 1024 {
+        format!("{:.2} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{} B", bytes)
+    }
+}
+
+fn ui_add_asset_category(ui: &mut egui::Ui, name: &str, usage: &AssetCategoryUsage) {
+    egui::CollapsingHeader::new(format!(
+        "{}: {} assets, {}",
+        name,
+        usage.count,
+        format_bytes(usage.total_bytes)
+    ))
+    .id_source(name)
+    .show(ui, |ui| {
+        egui::Grid::new(format!("memory_usage_{}_grid", name))
+            .num_columns(2)
+            .striped(true)
+            .show(ui, |ui| {
+                for (asset_name, size) in usage.top_assets.iter() {
+                    ui.label(asset_name);
+                    ui.label(format_bytes(*size));
+                    ui.end_row();
+                }
+            });
+    });
+}
+
+pub fn ui_debug_memory_usage_system(
+    mut egui_context: EguiContexts,
+    mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
+    mut ui_state: Local<UiStateDebugMemoryUsage>,
+    asset_server: Res<AssetServer>,
+    images: Res<Assets<Image>>,
+    meshes: Res<Assets<Mesh>>,
+    zmo_assets: Res<Assets<ZmoAsset>>,
+    audio_sources: Res<Assets<AudioSource>>,
+    sound_cache: Option<Res<SoundCache>>,
+    archetypes: &Archetypes,
+    components: &Components,
+    entities: &Entities,
+    time: Res<Time>,
+) {
+    if !ui_state_debug_windows.debug_ui_open || !ui_state_debug_windows.memory_usage_open {
+        return;
+    }
+
+    let ui_state = &mut *ui_state;
+    let now = time.elapsed();
+    if ui_state.last_update_time.map_or(true, |last_update_time| {
+        now.saturating_sub(last_update_time) >= MEMORY_USAGE_UPDATE_INTERVAL
+    }) {
+        ui_state.last_update_time = Some(now);
+
+        ui_state.images = AssetCategoryUsage::from_sizes(
+            &asset_server,
+            images
+                .iter()
+                .map(|(handle_id, image)| (handle_id, image.data.len())),
+        );
+        ui_state.meshes = AssetCategoryUsage::from_sizes(
+            &asset_server,
+            meshes.iter().map(|(handle_id, mesh)| {
+                let index_size = mesh.indices().map_or(0, |indices| match indices {
+                    Indices::U16(indices) => indices.len() * 2,
+                    Indices::U32(indices) => indices.len() * 4,
+                });
+                (
+                    handle_id,
+                    mesh.count_vertices() * mesh.get_vertex_size() as usize + index_size,
+                )
+            }),
+        );
+        ui_state.zmo_assets = AssetCategoryUsage::from_sizes(
+            &asset_server,
+            zmo_assets
+                .iter()
+                .map(|(handle_id, zmo_asset)| (handle_id, get_zmo_asset_size(zmo_asset))),
+        );
+        ui_state.audio_sources = AssetCategoryUsage::from_sizes(
+            &asset_server,
+            audio_sources
+                .iter()
+                .map(|(handle_id, audio_source)| (handle_id, get_audio_source_size(audio_source))),
+        );
+        ui_state.num_cached_sounds = sound_cache.as_ref().map_or(0, |sound_cache| {
+            sound_cache
+                .cached_sounds
+                .read()
+                .unwrap()
+                .iter()
+                .filter(|handle| handle.is_some())
+                .count()
+        });
+
+        let mut component_counts = vec![0; components.len()];
+        for archetype in archetypes.iter() {
+            for component_id in archetype.components() {
+                if let Some(count) = component_counts.get_mut(component_id.index()) {
+                    *count += archetype.len();
+                }
+            }
+        }
+
+        ui_state.num_entities = entities.len();
+        ui_state.component_counts = components
+            .iter()
+            .filter_map(|component_info| {
+                let count = component_counts[component_info.id().index()];
+                if count > 0 {
+                    Some((component_info.name().to_string(), count))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        ui_state.component_counts.sort_by(|(_, a), (_, b)| b.cmp(a));
+    }
+
+    let mut drop_unused_assets = false;
+
+    egui::Window::new("Memory Usage")
+        .open(&mut ui_state_debug_windows.memory_usage_open)
+        .default_width(400.0)
+        .show(egui_context.ctx_mut(), |ui| {
+            ui.label(format!(
+                "Total Assets: {}",
+                format_bytes(
+                    ui_state.images.total_bytes
+                        + ui_state.meshes.total_bytes
+                        + ui_state.zmo_assets.total_bytes
+                        + ui_state.audio_sources.total_bytes
+                )
+            ));
+
+            ui_add_asset_category(ui, "Textures", &ui_state.images);
+            ui_add_asset_category(ui, "Meshes", &ui_state.meshes);
+            ui_add_asset_category(ui, "ZMO Animations", &ui_state.zmo_assets);
+            ui_add_asset_category(ui, "Sounds", &ui_state.audio_sources);
+
+            ui.horizontal(|ui| {
+                ui.label(format!(
+                    "Sound Cache: {} sounds",
+                    ui_state.num_cached_sounds
+                ));
+
+                if ui
+                    .button("Drop Unused Assets")
+                    .on_hover_text(
+                        "Clears the sound cache so sounds which are not playing are unloaded",
+                    )
+                    .clicked()
+                {
+                    drop_unused_assets = true;
+                }
+            });
+
+            ui.separator();
+
+            egui::CollapsingHeader::new(format!("Entities: {}", ui_state.num_entities))
+                .id_source("memory_usage_entities")
+                .show(ui, |ui| {
+                    egui::ScrollArea::vertical()
+                        .max_height(300.0)
+                        .show(ui, |ui| {
+                            egui::Grid::new("memory_usage_components_grid")
+                                .num_columns(2)
+                                .striped(true)
+                                .show(ui, |ui| {
+                                    for (name, count) in ui_state.component_counts.iter() {
+                                        ui.label(name);
+                                        ui.label(format!("{}", count));
+                                        ui.end_row();
+                                    }
+                                });
+                        });
+                });
+        });
+
+    if drop_unused_assets {
+        if let Some(sound_cache) = sound_cache.as_ref() {
+            sound_cache.clear();
+        }
+        asset_server.free_unused_assets();
+        ui_state.last_update_time = None;
+    }
+}
@@ -0,0 +1,258 @@
+use std::collections::VecDeque;
+
+use bevy::prelude::{Local, Res, ResMut};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    resources::{
+        PacketInspector, PacketInspectorDirection, PacketInspectorEntry, ReplayConnection,
+    },
+    ui::{show_detachable_debug_window, DetachableDebugWindow, UiStateDebugWindows},
+};
+
+const PACKET_INSPECTOR_MAX_ENTRIES: usize = 5000;
+
+pub struct UiStateDebugPacketInspector {
+    entries: VecDeque<PacketInspectorEntry>,
+    selected_index: Option<usize>,
+    paused: bool,
+    filter_name: String,
+    show_incoming: bool,
+    show_outgoing: bool,
+    show_login: bool,
+    show_world: bool,
+    show_game: bool,
+}
+
+impl Default for UiStateDebugPacketInspector {
+    fn default() -> Self {
+        Self {
+            entries: VecDeque::new(),
+            selected_index: None,
+            paused: false,
+            filter_name: String::new(),
+            show_incoming: true,
+            show_outgoing: true,
+            show_login: true,
+            show_world: true,
+            show_game: true,
+        }
+    }
+}
+
+impl UiStateDebugPacketInspector {
+    fn push_entry(&mut self, entry: PacketInspectorEntry) {
+        if self.entries.len() >= PACKET_INSPECTOR_MAX_ENTRIES {
+            self.entries.pop_front();
+            self.selected_index = self.selected_index.and_then(|index| index.checked_sub(1));
+        }
+        self.entries.push_back(entry);
+    }
+
+    fn is_visible(&self, entry: &PacketInspectorEntry) -> bool {
+        let show_direction = match entry.direction {
+            PacketInspectorDirection::Incoming => self.show_incoming,
+            PacketInspectorDirection::Outgoing => self.show_outgoing,
+        };
+        let show_connection = match entry.connection {
+            ReplayConnection::Login => self.show_login,
+            ReplayConnection::World => self.show_world,
+            ReplayConnection::Game => self.show_game,
+        };
+
+        show_direction
+            && show_connection
+            && (self.filter_name.is_empty()
+                || entry
+                    .name()
+                    .to_lowercase()
+                    .contains(&self.filter_name.to_lowercase()))
+    }
+}
+
+fn format_commands(commands: &[u16]) -> String {
+    commands
+        .iter()
+        .map(|command| format!("0x{:03X}", command))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub fn ui_debug_packet_inspector_system(
+    mut egui_context: EguiContexts,
+    mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
+    mut ui_state: Local<UiStateDebugPacketInspector>,
+    packet_inspector: Res<PacketInspector>,
+) {
+    // Only capture packets while the window is visible
+    let window_open =
+        ui_state_debug_windows.debug_ui_open && ui_state_debug_windows.packet_inspector_open;
+    if packet_inspector.is_enabled() != window_open {
+        packet_inspector.set_enabled(window_open);
+    }
+
+    if !window_open {
+        return;
+    }
+
+    let ui_state = &mut *ui_state;
+    if !ui_state.paused {
+        while let Some(entry) = packet_inspector.pop_entry() {
+            ui_state.push_entry(entry);
+        }
+    }
+
+    let ui_state_debug_windows = &mut *ui_state_debug_windows;
+    let detached_ctx = ui_state_debug_windows.detached_windows
+        [DetachableDebugWindow::PacketInspector]
+        .and_then(|window| egui_context.try_ctx_for_window_mut(window).cloned());
+
+    show_detachable_debug_window(
+        egui_context.ctx_mut(),
+        detached_ctx.as_ref(),
+        egui::Window::new("Packet Inspector")
+            .open(&mut ui_state_debug_windows.packet_inspector_open)
+            .default_width(700.0)
+            .resizable(true),
+        &mut ui_state_debug_windows.detached[DetachableDebugWindow::PacketInspector],
+        |ui| {
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut ui_state.paused, "Pause");
+
+                if ui
+                    .add_enabled(ui_state.paused, egui::Button::new("Step"))
+                    .clicked()
+                {
+                    if let Some(entry) = packet_inspector.pop_entry() {
+                        ui_state.push_entry(entry);
+                    }
+                }
+
+                if ui.button("Clear").clicked() {
+                    ui_state.entries.clear();
+                    ui_state.selected_index = None;
+                }
+
+                if ui_state.paused {
+                    ui.label(format!(
+                        "{} pending packets",
+                        packet_inspector.num_pending_entries()
+                    ));
+                }
+            });
+
+            ui.horizontal(|ui| {
+                ui.label("Filter:");
+                ui.text_edit_singleline(&mut ui_state.filter_name);
+            });
+
+            ui.horizontal(|ui| {
+                ui.checkbox(&mut ui_state.show_incoming, "Incoming");
+                ui.checkbox(&mut ui_state.show_outgoing, "Outgoing");
+                ui.separator();
+                ui.checkbox(&mut ui_state.show_login, "Login");
+                ui.checkbox(&mut ui_state.show_world, "World");
+                ui.checkbox(&mut ui_state.show_game, "Game");
+            });
+
+            ui.separator();
+
+            let visible_entries: Vec<usize> = ui_state
+                .entries
+                .iter()
+                .enumerate()
+                .filter(|(_, entry)| ui_state.is_visible(entry))
+                .map(|(index, _)| index)
+                .collect();
+
+            egui::TopBottomPanel::bottom("packet_inspector_fields")
+                .resizable(true)
+                .default_height(200.0)
+                .show_inside(ui, |ui| {
+                    let Some(entry) = ui_state
+                        .selected_index
+                        .and_then(|index| ui_state.entries.get(index))
+                    else {
+                        ui.label("Select a packet to view its fields");
+                        return;
+                    };
+
+                    egui::ScrollArea::vertical()
+                        .id_source("packet_inspector_fields_scroll")
+                        .auto_shrink([false, false])
+                        .show(ui, |ui| {
+                            for fields in entry.fields.iter() {
+                                ui.monospace(fields);
+                            }
+                        });
+                });
+
+            egui_extras::TableBuilder::new(ui)
+                .striped(true)
+                .stick_to_bottom(!ui_state.paused)
+                .cell_layout(egui::Layout::left_to_right(egui::Align::Center))
+                .column(egui_extras::Column::initial(70.0).at_least(70.0))
+                .column(egui_extras::Column::initial(50.0).at_least(50.0))
+                .column(egui_extras::Column::initial(30.0).at_least(30.0))
+                .column(egui_extras::Column::initial(80.0).at_least(50.0))
+                .column(egui_extras::Column::initial(50.0).at_least(50.0))
+                .column(egui_extras::Column::remainder().at_least(200.0).clip(true))
+                .header(20.0, |mut header| {
+                    header.col(|ui| {
+                        ui.heading("Time");
+                    });
+                    header.col(|ui| {
+                        ui.heading("Server");
+                    });
+                    header.col(|ui| {
+                        ui.heading("Dir");
+                    });
+                    header.col(|ui| {
+                        ui.heading("Command");
+                    });
+                    header.col(|ui| {
+                        ui.heading("Size");
+                    });
+                    header.col(|ui| {
+                        ui.heading("Name");
+                    });
+                })
+                .body(|body| {
+                    body.rows(20.0, visible_entries.len(), |row_index, mut row| {
+                        let Some(entry_index) = visible_entries.get(row_index).copied() else {
+                            return;
+                        };
+                        let entry = &ui_state.entries[entry_index];
+                        let selected = ui_state.selected_index == Some(entry_index);
+                        let mut clicked = false;
+
+                        row.col(|ui| {
+                            ui.label(format!("{:.3}", entry.time.as_secs_f64()));
+                        });
+                        row.col(|ui| {
+                            ui.label(format!("{:?}", entry.connection));
+                        });
+                        row.col(|ui| {
+                            ui.label(match entry.direction {
+                                PacketInspectorDirection::Incoming => "<-",
+                                PacketInspectorDirection::Outgoing => "->",
+                            });
+                        });
+                        row.col(|ui| {
+                            ui.label(format_commands(&entry.commands));
+                        });
+                        row.col(|ui| {
+                            ui.label(format!("{}", entry.size));
+                        });
+                        row.col(|ui| {
+                            clicked = ui.selectable_label(selected, entry.name()).clicked();
+                        });
+
+                        if clicked {
+                            ui_state.selected_index = Some(entry_index);
+                        }
+                    });
+                });
+        },
+    );
+}
@@ -4,8 +4,8 @@ use bevy::{
     math::Vec3,
     pbr::{AlphaMode, StandardMaterial},
     prelude::{
-        shape, Assets, Camera, Camera3d, Color, Commands, ComputedVisibility, GlobalTransform,
-        Handle, KeyCode, Local, Mesh, Query, Res, ResMut, Time, Transform, Visibility, With,
+        shape, Assets, Camera, Color, Commands, ComputedVisibility, GlobalTransform, Handle,
+        KeyCode, Local, Mesh, Query, Res, ResMut, Time, Transform, Visibility, With,
     },
     window::{PrimaryWindow, Window},
 };
@@ -19,7 +19,10 @@ use rose_data::NpcId;
 use rose_game_common::components::Npc;
 
 use crate::{
-    components::{ColliderEntity, COLLISION_FILTER_CLICKABLE, COLLISION_GROUP_PHYSICS_TOY},
+    components::{
+        ColliderEntity, MainCamera, COLLISION_FILTER_CLICKABLE, COLLISION_GROUP_PHYSICS_TOY,
+    },
+    resources::{DebugRenderConfig, InputAction, InputBindings},
     ui::UiStateDebugWindows,
 };
 
@@ -96,13 +99,15 @@ pub fn ui_debug_physics_system(
     mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
     mut ui_state_debug_physics: Local<UiDebugPhysicsState>,
     mut rapier_configuration: ResMut<RapierConfiguration>,
+    mut debug_render_config: ResMut<DebugRenderConfig>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     key_code_input: Res<Input<KeyCode>>,
+    input_bindings: Res<InputBindings>,
     rapier_context: Res<RapierContext>,
     time: Res<Time>,
     query_primary_window: Query<&Window, With<PrimaryWindow>>,
-    query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    query_camera: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
 ) {
     if !ui_state_debug_windows.debug_ui_open {
         return;
@@ -122,6 +127,43 @@ pub fn ui_debug_physics_system(
 
             ui.separator();
 
+            let walkability = &mut debug_render_config.walkability;
+            egui::Grid::new("debug_physics_walkability")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label("Show walkability:");
+                    ui.checkbox(&mut walkability.enabled, "Enabled");
+                    ui.end_row();
+
+                    ui.label("Show as grid:");
+                    ui.checkbox(&mut walkability.show_grid, "Enabled");
+                    ui.end_row();
+
+                    ui.label("Radius:");
+                    ui.add(
+                        egui::Slider::new(&mut walkability.radius, 5.0..=100.0).show_value(true),
+                    );
+                    ui.end_row();
+
+                    ui.label("Spacing:");
+                    ui.add(egui::Slider::new(&mut walkability.spacing, 0.5..=5.0).show_value(true));
+                    ui.end_row();
+
+                    ui.label("Max slope:");
+                    ui.add(
+                        egui::Slider::new(&mut walkability.max_slope_degrees, 0.0..=90.0)
+                            .suffix("°")
+                            .show_value(true),
+                    );
+                    ui.end_row();
+                })
+                .response
+                .on_hover_text(
+                    "Green is walkable, orange is too steep, blue is water and red is blocked",
+                );
+
+            ui.separator();
+
             egui::Grid::new("debug_physics_spawn")
                 .num_columns(2)
                 .show(ui, |ui| {
@@ -160,7 +202,7 @@ pub fn ui_debug_physics_system(
         });
 
     if ui_state_debug_physics.spawn_balls
-        && key_code_input.pressed(KeyCode::B)
+        && input_bindings.pressed(InputAction::DebugPhysicsSpawnBalls, &key_code_input)
         && !egui_context.ctx_mut().wants_keyboard_input()
         && !egui_context.ctx_mut().wants_pointer_input()
     {
@@ -274,7 +316,7 @@ pub fn ui_debug_physics_system(
         }
     }
 
-    if !key_code_input.pressed(KeyCode::B) {
+    if !input_bindings.pressed(InputAction::DebugPhysicsSpawnBalls, &key_code_input) {
         ui_state_debug_physics.spawn_timer = ui_state_debug_physics.spawn_interval;
     }
 }
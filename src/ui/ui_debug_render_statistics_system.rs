@@ -0,0 +1,107 @@
+use bevy::prelude::{Query, Res, ResMut, With};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    components::MainCamera,
+    render::{GpuTimingCategory, RenderStatistics},
+    ui::UiStateDebugWindows,
+};
+
+pub fn ui_debug_render_statistics_system(
+    mut egui_context: EguiContexts,
+    mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
+    render_statistics: Res<RenderStatistics>,
+    query_main_camera: Query<(), With<MainCamera>>,
+) {
+    if !ui_state_debug_windows.debug_ui_open {
+        return;
+    }
+
+    let frame = render_statistics.get();
+
+    egui::Window::new("Render Statistics")
+        .open(&mut ui_state_debug_windows.render_statistics_open)
+        .resizable(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            egui::Grid::new("render_statistics_grid")
+                .num_columns(3)
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.label("Pass");
+                    ui.label("Draw Calls");
+                    ui.label("Triangles");
+                    ui.end_row();
+
+                    let mut total_draw_calls = 0;
+                    let mut total_triangles = 0;
+                    for (pass, statistics) in frame.passes.iter() {
+                        total_draw_calls += statistics.draw_calls;
+                        total_triangles += statistics.triangles;
+
+                        ui.label(format!("{:?}", pass));
+                        ui.label(format!("{}", statistics.draw_calls));
+                        ui.label(format!("{}", statistics.triangles));
+                        ui.end_row();
+                    }
+
+                    ui.label("Total");
+                    ui.label(format!("{}", total_draw_calls));
+                    ui.label(format!("{}", total_triangles));
+                    ui.end_row();
+                });
+
+            ui.separator();
+
+            if !frame.gpu_timestamps_supported {
+                ui.label("GPU timings require --gpu-timestamps and a GPU which supports timestamp queries");
+                return;
+            }
+
+            let Some(gpu_timings_ms) = frame.gpu_timings_ms() else {
+                ui.label("Waiting for GPU timings...");
+                return;
+            };
+
+            let mut cameras: Vec<_> = frame.gpu_camera_timings_ms.iter().collect();
+            cameras.sort_by_key(|(entity, _)| (!query_main_camera.contains(**entity), **entity));
+
+            egui::Grid::new("render_statistics_gpu_grid")
+                .num_columns(cameras.len() + 3)
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.label("GPU Time");
+                    for (entity, _) in cameras.iter() {
+                        if query_main_camera.contains(**entity) {
+                            ui.label("Main Camera");
+                        } else {
+                            ui.label(format!("Camera {}", entity.index()));
+                        }
+                    }
+                    ui.label("Egui");
+                    ui.label("Total");
+                    ui.end_row();
+
+                    for (category, time_ms) in gpu_timings_ms.iter() {
+                        ui.label(format!("{:?}", category));
+                        for (_, camera_timings_ms) in cameras.iter() {
+                            ui.label(format!("{:.3} ms", camera_timings_ms[category]));
+                        }
+                        if category == GpuTimingCategory::Ui {
+                            ui.label(format!("{:.3} ms", frame.gpu_egui_timing_ms.unwrap_or(0.0)));
+                        } else {
+                            ui.label("");
+                        }
+                        ui.label(format!("{:.3} ms", time_ms));
+                        ui.end_row();
+                    }
+
+                    ui.label("Total");
+                    for (_, camera_timings_ms) in cameras.iter() {
+                        ui.label(format!("{:.3} ms", camera_timings_ms.values().sum::<f64>()));
+                    }
+                    ui.label(format!("{:.3} ms", frame.gpu_egui_timing_ms.unwrap_or(0.0)));
+                    ui.label(format!("{:.3} ms", gpu_timings_ms.values().sum::<f64>()));
+                    ui.end_row();
+                });
+        });
+}
@@ -0,0 +1,333 @@
+use bevy::{
+    input::Input,
+    math::{Quat, Vec3},
+    prelude::{
+        Camera, Commands, ComputedVisibility, DespawnRecursiveExt, Entity, EventWriter,
+        GlobalTransform, Local, MouseButton, Query, Res, ResMut, Transform, Visibility, With,
+        Without,
+    },
+    window::{PrimaryWindow, Window},
+};
+use bevy_egui::{egui, EguiContexts};
+use bevy_rapier3d::prelude::{CollisionGroups, QueryFilter, RapierContext};
+use regex::Regex;
+
+use rose_data::{EffectFileId, NpcId, NpcMotionAction};
+use rose_game_common::components::Npc;
+
+use crate::{
+    animation::SkeletalAnimation,
+    components::{
+        ClientEntityName, MainCamera, NpcModel, COLLISION_FILTER_MOVEABLE,
+        COLLISION_GROUP_ZONE_OBJECT, COLLISION_GROUP_ZONE_TERRAIN,
+    },
+    events::{SpawnEffectData, SpawnEffectEvent},
+    resources::GameData,
+    ui::UiStateDebugWindows,
+};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+enum SpawnPaletteMode {
+    #[default]
+    Npc,
+    Effect,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum SpawnPaletteItem {
+    Npc(NpcId),
+    Effect(EffectFileId),
+}
+
+#[derive(Default)]
+pub struct UiStateDebugSpawnPalette {
+    mode: SpawnPaletteMode,
+    filter_name: String,
+    filter_changed: bool,
+    filtered_items: Vec<(SpawnPaletteItem, String)>,
+    selected_item: Option<SpawnPaletteItem>,
+    place_on_click: bool,
+    rotation_degrees: f32,
+    spawned_entities: Vec<(Entity, String)>,
+}
+
+fn get_cursor_world_position(
+    window: &Window,
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    rapier_context: &RapierContext,
+) -> Option<Vec3> {
+    let cursor_position = window.cursor_position()?;
+    let ray = camera.viewport_to_world(camera_transform, cursor_position)?;
+
+    if let Some((_, distance)) = rapier_context.cast_ray(
+        ray.origin,
+        ray.direction,
+        10000000.0,
+        false,
+        QueryFilter::new().groups(CollisionGroups::new(
+            COLLISION_FILTER_MOVEABLE,
+            COLLISION_GROUP_ZONE_OBJECT | COLLISION_GROUP_ZONE_TERRAIN,
+        )),
+    ) {
+        return Some(ray.get_point(distance));
+    }
+
+    // The model viewer has no zone, so place on the ground plane instead
+    ray.intersect_plane(Vec3::ZERO, Vec3::Y)
+        .map(|distance| ray.get_point(distance))
+}
+
+pub fn ui_debug_spawn_palette_system(
+    mut commands: Commands,
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiStateDebugSpawnPalette>,
+    mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
+    game_data: Res<GameData>,
+    mouse_button_input: Res<Input<MouseButton>>,
+    rapier_context: Res<RapierContext>,
+    query_window: Query<&Window, With<PrimaryWindow>>,
+    query_camera: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    query_npc_model: Query<&NpcModel, Without<SkeletalAnimation>>,
+    query_exists: Query<Entity>,
+    mut spawn_effect_events: EventWriter<SpawnEffectEvent>,
+) {
+    let ui_state = &mut *ui_state;
+
+    // Forget any entities which have been despawned elsewhere, e.g. by a zone change
+    ui_state
+        .spawned_entities
+        .retain(|(entity, _)| query_exists.get(*entity).is_ok());
+
+    // Play the idle animation once the NPC model has loaded
+    for (entity, _) in ui_state.spawned_entities.iter() {
+        if let Ok(npc_model) = query_npc_model.get(*entity) {
+            commands.entity(*entity).insert(SkeletalAnimation::repeat(
+                npc_model.action_motions[NpcMotionAction::Stop].clone(),
+                None,
+            ));
+        }
+    }
+
+    if !ui_state_debug_windows.debug_ui_open {
+        return;
+    }
+
+    egui::Window::new("Spawn Palette")
+        .resizable(true)
+        .default_height(400.0)
+        .open(&mut ui_state_debug_windows.spawn_palette_open)
+        .show(egui_context.ctx_mut(), |ui| {
+            egui::Grid::new("spawn_palette_controls_grid")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label("Type:");
+                    ui.horizontal(|ui| {
+                        for mode in [SpawnPaletteMode::Npc, SpawnPaletteMode::Effect] {
+                            if ui
+                                .selectable_value(&mut ui_state.mode, mode, format!("{:?}", mode))
+                                .changed()
+                            {
+                                ui_state.filter_changed = true;
+                                ui_state.selected_item = None;
+                            }
+                        }
+                    });
+                    ui.end_row();
+
+                    ui.label("Filter:");
+                    if ui.text_edit_singleline(&mut ui_state.filter_name).changed() {
+                        ui_state.filter_changed = true;
+                    }
+                    ui.end_row();
+
+                    ui.label("Rotation:");
+                    ui.add(
+                        egui::Slider::new(&mut ui_state.rotation_degrees, 0.0..=360.0).suffix("°"),
+                    );
+                    ui.end_row();
+
+                    ui.label("Place:");
+                    ui.add_enabled(
+                        ui_state.selected_item.is_some(),
+                        egui::Checkbox::new(&mut ui_state.place_on_click, "Left click in world"),
+                    );
+                    ui.end_row();
+                });
+
+            if ui_state.filter_name.is_empty() && ui_state.filtered_items.is_empty() {
+                ui_state.filter_changed = true;
+            }
+
+            if ui_state.filter_changed {
+                ui_state.filter_changed = false;
+
+                let filter_name_re = if !ui_state.filter_name.is_empty() {
+                    Some(
+                        Regex::new(&format!("(?i){}", regex::escape(&ui_state.filter_name)))
+                            .unwrap(),
+                    )
+                } else {
+                    None
+                };
+
+                ui_state.filtered_items = match ui_state.mode {
+                    SpawnPaletteMode::Npc => game_data
+                        .npcs
+                        .iter()
+                        .map(|npc_data| {
+                            (
+                                SpawnPaletteItem::Npc(npc_data.id),
+                                format!("{} {}", npc_data.id.get(), npc_data.name),
+                            )
+                        })
+                        .collect(),
+                    SpawnPaletteMode::Effect => game_data
+                        .effect_database
+                        .iter_files()
+                        .map(|(effect_file_id, effect_file_path)| {
+                            (
+                                SpawnPaletteItem::Effect(effect_file_id),
+                                format!(
+                                    "{} {}",
+                                    effect_file_id.get(),
+                                    effect_file_path.path().to_string_lossy()
+                                ),
+                            )
+                        })
+                        .collect(),
+                };
+
+                if let Some(filter_name_re) = filter_name_re {
+                    ui_state
+                        .filtered_items
+                        .retain(|(_, name)| filter_name_re.is_match(name));
+                }
+            }
+
+            egui::ScrollArea::vertical()
+                .id_source("spawn_palette_items")
+                .max_height(250.0)
+                .show_rows(ui, 18.0, ui_state.filtered_items.len(), |ui, row_range| {
+                    for (item, name) in ui_state.filtered_items[row_range].iter() {
+                        if ui
+                            .selectable_label(ui_state.selected_item == Some(*item), name)
+                            .clicked()
+                        {
+                            ui_state.selected_item = Some(*item);
+                        }
+                    }
+                });
+
+            ui.separator();
+
+            ui.horizontal(|ui| {
+                ui.label(format!("Spawned: {}", ui_state.spawned_entities.len()));
+
+                if ui.button("Delete All").clicked() {
+                    for (entity, _) in ui_state.spawned_entities.drain(..) {
+                        commands.entity(entity).despawn_recursive();
+                    }
+                }
+            });
+
+            let mut delete_index = None;
+            egui::ScrollArea::vertical()
+                .id_source("spawn_palette_spawned")
+                .max_height(150.0)
+                .show(ui, |ui| {
+                    for (index, (_, name)) in ui_state.spawned_entities.iter().enumerate() {
+                        ui.horizontal(|ui| {
+                            if ui.button("Delete").clicked() {
+                                delete_index = Some(index);
+                            }
+                            ui.label(name);
+                        });
+                    }
+                });
+
+            if let Some(index) = delete_index {
+                let (entity, _) = ui_state.spawned_entities.remove(index);
+                commands.entity(entity).despawn_recursive();
+            }
+        });
+
+    if !ui_state_debug_windows.spawn_palette_open
+        || !ui_state.place_on_click
+        || !mouse_button_input.just_pressed(MouseButton::Left)
+        || egui_context.ctx_mut().wants_pointer_input()
+    {
+        return;
+    }
+
+    let spawn_position = if let (Ok(window), Ok((camera, camera_transform))) =
+        (query_window.get_single(), query_camera.get_single())
+    {
+        get_cursor_world_position(window, camera, camera_transform, &rapier_context)
+    } else {
+        None
+    };
+
+    let (Some(spawn_position), Some(selected_item)) = (spawn_position, ui_state.selected_item)
+    else {
+        return;
+    };
+
+    let transform = Transform::from_translation(spawn_position).with_rotation(
+        Quat::from_rotation_y(ui_state.rotation_degrees.to_radians()),
+    );
+
+    match selected_item {
+        SpawnPaletteItem::Npc(npc_id) => {
+            let Some(npc_data) = game_data.npcs.get_npc(npc_id) else {
+                return;
+            };
+
+            let entity = commands
+                .spawn((
+                    ClientEntityName {
+                        name: npc_data.name.to_string(),
+                    },
+                    Npc::new(npc_id, 0),
+                    Visibility::default(),
+                    ComputedVisibility::default(),
+                    GlobalTransform::default(),
+                    transform,
+                ))
+                .id();
+
+            ui_state
+                .spawned_entities
+                .push((entity, format!("NPC {} {}", npc_id.get(), npc_data.name)));
+        }
+        SpawnPaletteItem::Effect(effect_file_id) => {
+            let Some(effect_file_path) = game_data.effect_database.get_effect_file(effect_file_id)
+            else {
+                return;
+            };
+
+            let entity = commands
+                .spawn((
+                    transform,
+                    GlobalTransform::default(),
+                    Visibility::default(),
+                    ComputedVisibility::default(),
+                ))
+                .id();
+
+            spawn_effect_events.send(SpawnEffectEvent::InEntity(
+                entity,
+                SpawnEffectData::with_path(effect_file_path.clone()),
+            ));
+
+            ui_state.spawned_entities.push((
+                entity,
+                format!(
+                    "Effect {} {}",
+                    effect_file_id.get(),
+                    effect_file_path.path().to_string_lossy()
+                ),
+            ));
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use bevy::prelude::{Res, ResMut, Time};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    resources::{DebugTimeControl, DEBUG_TIME_SCALE_MAX, DEBUG_TIME_SCALE_MIN},
+    ui::UiStateDebugWindows,
+};
+
+pub fn ui_debug_time_control_system(
+    mut egui_context: EguiContexts,
+    mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
+    mut debug_time_control: ResMut<DebugTimeControl>,
+    time: Res<Time>,
+) {
+    if !ui_state_debug_windows.debug_ui_open {
+        return;
+    }
+
+    egui::Window::new("Time Control")
+        .open(&mut ui_state_debug_windows.time_control_open)
+        .resizable(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            egui::Grid::new("time_control_grid")
+                .num_columns(2)
+                .show(ui, |ui| {
+                    ui.label("Time Scale:");
+                    ui.add(
+                        egui::Slider::new(
+                            &mut debug_time_control.time_scale,
+                            DEBUG_TIME_SCALE_MIN..=DEBUG_TIME_SCALE_MAX,
+                        )
+                        .logarithmic(true)
+                        .suffix("x"),
+                    );
+                    ui.end_row();
+
+                    ui.label("Presets:");
+                    ui.horizontal(|ui| {
+                        for time_scale in [0.1, 0.25, 0.5, 1.0, 2.0, 5.0] {
+                            if ui.button(format!("{}x", time_scale)).clicked() {
+                                debug_time_control.time_scale = time_scale;
+                            }
+                        }
+                    });
+                    ui.end_row();
+
+                    ui.label("Paused:");
+                    ui.checkbox(&mut debug_time_control.paused, "Enabled");
+                    ui.end_row();
+
+                    ui.label("Step:");
+                    ui.horizontal(|ui| {
+                        if ui.button("1 Frame").clicked() {
+                            debug_time_control.step(1);
+                        }
+
+                        if ui.button("10 Frames").clicked() {
+                            debug_time_control.step(10);
+                        }
+                    });
+                    ui.end_row();
+
+                    ui.label("Frame Delta:");
+                    ui.label(format!(
+                        "{:.2} ms ({:.2} ms real)",
+                        time.delta_seconds_f64() * 1000.0,
+                        time.raw_delta_seconds_f64() * 1000.0
+                    ));
+                    ui.end_row();
+
+                    ui.label("Elapsed:");
+                    ui.label(format!(
+                        "{:.3} s ({:.3} s real)",
+                        time.elapsed_seconds_f64(),
+                        time.raw_elapsed_seconds_f64()
+                    ));
+                    ui.end_row();
+                });
+
+            if ui.button("Reset").clicked() {
+                debug_time_control.reset();
+            }
+        });
+}
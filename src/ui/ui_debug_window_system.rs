@@ -2,16 +2,22 @@ use bevy::{
     input::Input,
     math::{EulerRot, Vec3},
     prelude::{
-        Camera3d, Commands, Entity, KeyCode, Local, NextState, Query, Res, ResMut, Resource,
+        Commands, Entity, EventWriter, KeyCode, Local, NextState, Query, Res, ResMut, Resource,
         Transform, With,
     },
+    window::{Window, WindowResolution},
 };
 use bevy_egui::{egui, EguiContexts};
+use enum_map::{Enum, EnumMap};
 use rose_game_common::messages::client::ClientMessage;
 
 use crate::{
-    components::PlayerCharacter,
-    resources::{AppState, DebugInspector, GameConnection, WorldConnection},
+    components::{MainCamera, PlayerCharacter},
+    events::ValidateZoneEvent,
+    resources::{
+        AppState, CurrentZone, DebugInspector, GameConnection, InputAction, InputBindings,
+        WorldConnection,
+    },
     systems::{FreeCamera, OrbitCamera},
 };
 
@@ -37,14 +43,128 @@ pub struct UiStateDebugWindows {
     pub debug_render_open: bool,
     pub dialog_list_open: bool,
     pub effect_list_open: bool,
+    pub frame_profiler_open: bool,
     pub item_list_open: bool,
+    pub log_viewer_open: bool,
+    pub lua_vm_open: bool,
+    pub memory_usage_open: bool,
     pub npc_list_open: bool,
     pub object_inspector_open: bool,
+    pub packet_inspector_open: bool,
     pub physics_open: bool,
+    pub render_statistics_open: bool,
     pub skill_list_open: bool,
+    pub spawn_palette_open: bool,
+    pub time_control_open: bool,
     pub zone_list_open: bool,
     pub zone_lighting_open: bool,
     pub zone_time_open: bool,
+
+    /// Debug tools to show in their own OS window rather than over the game
+    pub detached: EnumMap<DetachableDebugWindow, bool>,
+    pub detached_windows: EnumMap<DetachableDebugWindow, Option<Entity>>,
+}
+
+/// Debug tools which can be moved to their own OS window, for example onto another monitor.
+#[derive(Copy, Clone, Debug, Enum)]
+pub enum DetachableDebugWindow {
+    EntityInspector,
+    FrameProfiler,
+    PacketInspector,
+}
+
+impl DetachableDebugWindow {
+    const ALL: [DetachableDebugWindow; 3] = [
+        DetachableDebugWindow::EntityInspector,
+        DetachableDebugWindow::FrameProfiler,
+        DetachableDebugWindow::PacketInspector,
+    ];
+
+    fn title(self) -> &'static str {
+        match self {
+            DetachableDebugWindow::EntityInspector => "Entity Inspector",
+            DetachableDebugWindow::FrameProfiler => "Frame Profiler",
+            DetachableDebugWindow::PacketInspector => "Packet Inspector",
+        }
+    }
+}
+
+impl UiStateDebugWindows {
+    fn detachable_window_open_mut(&mut self, window: DetachableDebugWindow) -> &mut bool {
+        match window {
+            DetachableDebugWindow::EntityInspector => &mut self.object_inspector_open,
+            DetachableDebugWindow::FrameProfiler => &mut self.frame_profiler_open,
+            DetachableDebugWindow::PacketInspector => &mut self.packet_inspector_open,
+        }
+    }
+}
+
+/// Shows a debug tool as a window over the game, or filling its own OS window
+/// when detached_ctx is the context of its detached window.
+pub fn show_detachable_debug_window(
+    ctx: &egui::Context,
+    detached_ctx: Option<&egui::Context>,
+    window: egui::Window,
+    detached: &mut bool,
+    add_contents: impl FnOnce(&mut egui::Ui),
+) {
+    let add_contents = |ui: &mut egui::Ui| {
+        if ui
+            .small_button(if *detached { "Attach" } else { "Detach" })
+            .on_hover_text("Move between the game window and a separate window")
+            .clicked()
+        {
+            *detached = !*detached;
+        }
+
+        add_contents(ui);
+    };
+
+    if let Some(detached_ctx) = detached_ctx {
+        egui::CentralPanel::default().show(detached_ctx, |ui| {
+            egui::ScrollArea::vertical()
+                .auto_shrink([false; 2])
+                .show(ui, add_contents);
+        });
+    } else {
+        window.show(ctx, add_contents);
+    }
+}
+
+/// Opens and closes the OS windows of detached debug tools, closing a detached
+/// window closes its tool.
+pub fn ui_debug_detached_windows_system(
+    mut commands: Commands,
+    mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
+    query_windows: Query<(), With<Window>>,
+) {
+    let ui_state_debug_windows = &mut *ui_state_debug_windows;
+
+    for kind in DetachableDebugWindow::ALL {
+        let open = ui_state_debug_windows.debug_ui_open
+            && *ui_state_debug_windows.detachable_window_open_mut(kind);
+
+        if let Some(window) = ui_state_debug_windows.detached_windows[kind] {
+            if !query_windows.contains(window) {
+                ui_state_debug_windows.detached_windows[kind] = None;
+                ui_state_debug_windows.detached[kind] = false;
+                *ui_state_debug_windows.detachable_window_open_mut(kind) = false;
+            } else if !open || !ui_state_debug_windows.detached[kind] {
+                commands.entity(window).despawn();
+                ui_state_debug_windows.detached_windows[kind] = None;
+            }
+        } else if open && ui_state_debug_windows.detached[kind] {
+            ui_state_debug_windows.detached_windows[kind] = Some(
+                commands
+                    .spawn(Window {
+                        title: format!("rose-offline-client - {}", kind.title()),
+                        resolution: WindowResolution::new(800.0, 600.0),
+                        ..Default::default()
+                    })
+                    .id(),
+            );
+        }
+    }
 }
 
 #[derive(Default)]
@@ -58,15 +178,18 @@ pub fn ui_debug_menu_system(
     mut egui_context: EguiContexts,
     mut ui_state_debug_windows: ResMut<UiStateDebugWindows>,
     mut ui_state_debug_menu: Local<UiStateDebugMenu>,
-    query_cameras: Query<(Entity, &Transform), With<Camera3d>>,
+    query_cameras: Query<(Entity, &Transform), With<MainCamera>>,
     query_player: Query<Entity, With<PlayerCharacter>>,
     game_connection: Option<Res<GameConnection>>,
     world_connection: Option<Res<WorldConnection>>,
     keyboard: Res<Input<KeyCode>>,
+    input_bindings: Res<InputBindings>,
     mut debug_inspector: ResMut<DebugInspector>,
     mut app_state_next: ResMut<NextState<AppState>>,
+    current_zone: Option<Res<CurrentZone>>,
+    mut validate_zone_events: EventWriter<ValidateZoneEvent>,
 ) {
-    if keyboard.pressed(KeyCode::ControlLeft) && keyboard.just_pressed(KeyCode::D) {
+    if input_bindings.just_pressed(InputAction::ToggleDebugWindow, &keyboard) {
         ui_state_debug_windows.debug_ui_open = !ui_state_debug_windows.debug_ui_open;
     }
 
@@ -114,6 +237,19 @@ pub fn ui_debug_menu_system(
                     }
                 });
 
+                ui.separator();
+
+                ui.add_enabled_ui(current_zone.is_some(), |ui| {
+                    if ui.button("Validate Zone").clicked() {
+                        if let Some(current_zone) = current_zone.as_ref() {
+                            validate_zone_events.send(ValidateZoneEvent {
+                                id: current_zone.id,
+                                output_path: None,
+                            });
+                        }
+                    }
+                });
+
                 ui.set_enabled(true);
             });
 
@@ -191,9 +327,35 @@ pub fn ui_debug_menu_system(
                 );
                 ui.checkbox(&mut ui_state_debug_windows.dialog_list_open, "Dialog List");
                 ui.checkbox(&mut ui_state_debug_windows.effect_list_open, "Effect List");
+                ui.checkbox(
+                    &mut ui_state_debug_windows.frame_profiler_open,
+                    "Frame Profiler",
+                );
                 ui.checkbox(&mut ui_state_debug_windows.item_list_open, "Item List");
+                ui.checkbox(&mut ui_state_debug_windows.log_viewer_open, "Log Viewer");
+                ui.checkbox(&mut ui_state_debug_windows.lua_vm_open, "Lua VM");
+                ui.checkbox(
+                    &mut ui_state_debug_windows.memory_usage_open,
+                    "Memory Usage",
+                );
                 ui.checkbox(&mut ui_state_debug_windows.npc_list_open, "NPC List");
+                ui.checkbox(
+                    &mut ui_state_debug_windows.packet_inspector_open,
+                    "Packet Inspector",
+                );
+                ui.checkbox(
+                    &mut ui_state_debug_windows.render_statistics_open,
+                    "Render Statistics",
+                );
                 ui.checkbox(&mut ui_state_debug_windows.skill_list_open, "Skill List");
+                ui.checkbox(
+                    &mut ui_state_debug_windows.spawn_palette_open,
+                    "Spawn Palette",
+                );
+                ui.checkbox(
+                    &mut ui_state_debug_windows.time_control_open,
+                    "Time Control",
+                );
                 ui.checkbox(&mut ui_state_debug_windows.zone_list_open, "Zone List");
                 ui.checkbox(
                     &mut ui_state_debug_windows.zone_lighting_open,
@@ -0,0 +1,125 @@
+use bevy::prelude::{EventWriter, Local, Query, Res, ResMut, With, Without};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_game_common::components::CharacterInfo;
+
+use crate::{
+    components::PlayerCharacter,
+    events::ChatboxInputEvent,
+    localize,
+    resources::{Account, CharacterStoreKey, FriendList},
+    ui::UiStateWindows,
+};
+
+#[derive(Default)]
+pub struct UiStateFriends {
+    new_friend_name: String,
+}
+
+pub fn ui_friends_system(
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiStateFriends>,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    mut friend_list: ResMut<FriendList>,
+    mut chatbox_input_events: EventWriter<ChatboxInputEvent>,
+    query_player: Query<&CharacterInfo, With<PlayerCharacter>>,
+    query_characters: Query<&CharacterInfo, Without<PlayerCharacter>>,
+    account: Option<Res<Account>>,
+) {
+    let Ok(player_character_info) = query_player.get_single() else {
+        return;
+    };
+
+    let store_key = CharacterStoreKey::new(account.as_deref(), &player_character_info.name);
+    if friend_list.store_key.as_ref() != Some(&store_key) {
+        *friend_list = FriendList::load(store_key);
+    }
+
+    if !ui_state_windows.friends_open {
+        return;
+    }
+
+    let mut add_friend = false;
+    let mut whisper_friend = None;
+    let mut remove_friend = None;
+
+    egui::Window::new(localize!("friends-title"))
+        .id(egui::Id::new("friends"))
+        .open(&mut ui_state_windows.friends_open)
+        .resizable(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            ui.horizontal(|ui| {
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut ui_state.new_friend_name)
+                        .hint_text(localize!("friends-name-hint"))
+                        .desired_width(150.0),
+                );
+                let enter_pressed =
+                    response.lost_focus() && ui.input(|input| input.key_pressed(egui::Key::Enter));
+
+                if ui.button(localize!("friends-add")).clicked() || enter_pressed {
+                    add_friend = true;
+                }
+            });
+
+            ui.separator();
+
+            if friend_list.friends.is_empty() {
+                ui.label(localize!("friends-empty"));
+                return;
+            }
+
+            egui::ScrollArea::vertical()
+                .max_height(300.0)
+                .show(ui, |ui| {
+                    egui::Grid::new("friends_grid")
+                        .num_columns(4)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for friend in friend_list.friends.iter() {
+                                // Without a messenger server we only know about characters
+                                // which are close enough to be visible
+                                let nearby = query_characters.iter().any(|character_info| {
+                                    character_info.name.eq_ignore_ascii_case(&friend.name)
+                                });
+
+                                ui.label(&friend.name);
+                                if nearby {
+                                    ui.colored_label(
+                                        egui::Color32::LIGHT_GREEN,
+                                        localize!("friends-nearby"),
+                                    );
+                                } else {
+                                    ui.label(
+                                        egui::RichText::new(localize!("friends-not-nearby")).weak(),
+                                    );
+                                }
+
+                                if ui.button(localize!("friends-whisper")).clicked() {
+                                    whisper_friend = Some(friend.name.clone());
+                                }
+
+                                if ui.button(localize!("friends-remove")).clicked() {
+                                    remove_friend = Some(friend.name.clone());
+                                }
+                                ui.end_row();
+                            }
+                        });
+                });
+        });
+
+    if add_friend {
+        let name = ui_state.new_friend_name.trim();
+        if !name.eq_ignore_ascii_case(&player_character_info.name) && friend_list.add(name) {
+            ui_state.new_friend_name.clear();
+        }
+    }
+
+    if let Some(name) = whisper_friend {
+        chatbox_input_events.send(ChatboxInputEvent::Whisper(name));
+    }
+
+    if let Some(name) = remove_friend {
+        friend_list.remove(&name);
+    }
+}
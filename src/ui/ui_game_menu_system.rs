@@ -2,10 +2,12 @@ use bevy::prelude::{Assets, EventWriter, Local, Res, ResMut};
 use bevy_egui::{egui, EguiContexts};
 
 use crate::{
-    resources::UiResources,
+    events::LogoutEvent,
+    localize,
+    resources::{LogoutTarget, UiResources},
     ui::{
         widgets::{DataBindings, Dialog},
-        UiSoundEvent, UiStateWindows,
+        UiSoundEvent, UiStateWindows, UiWindow,
     },
 };
 
@@ -24,6 +26,7 @@ const IID_BTN_EXIT: i32 = 19;
 pub struct UiGameMenuState {
     pub was_open: bool,
     pub mouse_up_after_open: bool,
+    pub exit_dialog_open: bool,
 }
 
 pub fn ui_game_menu_system(
@@ -32,6 +35,7 @@ pub fn ui_game_menu_system(
     mut ui_state: Local<UiGameMenuState>,
     ui_resources: Res<UiResources>,
     mut ui_sound_events: EventWriter<UiSoundEvent>,
+    mut logout_events: EventWriter<LogoutEvent>,
     dialog_assets: Res<Assets<Dialog>>,
 ) {
     let dialog = if let Some(dialog) = dialog_assets.get(&ui_resources.dialog_game_menu) {
@@ -102,37 +106,37 @@ pub fn ui_game_menu_system(
     }
 
     if response_button_character_info.map_or(false, |r| r.clicked()) {
-        ui_state_windows.character_info_open = !ui_state_windows.character_info_open;
+        ui_state_windows.toggle(UiWindow::CharacterInfo);
         ui_state_windows.menu_open = false;
     }
 
     if response_button_inventory.map_or(false, |r| r.clicked()) {
-        ui_state_windows.inventory_open = !ui_state_windows.inventory_open;
+        ui_state_windows.toggle(UiWindow::Inventory);
         ui_state_windows.menu_open = false;
     }
 
     if response_button_skill_list.map_or(false, |r| r.clicked()) {
-        ui_state_windows.skill_list_open = !ui_state_windows.skill_list_open;
+        ui_state_windows.toggle(UiWindow::SkillList);
         ui_state_windows.menu_open = false;
     }
 
     if response_button_quest_list.map_or(false, |r| r.clicked()) {
-        ui_state_windows.quest_list_open = !ui_state_windows.quest_list_open;
+        ui_state_windows.toggle(UiWindow::QuestList);
         ui_state_windows.menu_open = false;
     }
 
     if response_button_options.map_or(false, |r| r.clicked()) {
-        ui_state_windows.settings_open = !ui_state_windows.settings_open;
+        ui_state_windows.toggle(UiWindow::Settings);
         ui_state_windows.menu_open = false;
     }
 
     if response_button_community.map_or(false, |r| r.clicked()) {
-        // TODO: Community dialog
+        ui_state_windows.toggle(UiWindow::Friends);
         ui_state_windows.menu_open = false;
     }
 
     if response_button_clan.map_or(false, |r| r.clicked()) {
-        ui_state_windows.clan_open = !ui_state_windows.clan_open;
+        ui_state_windows.toggle(UiWindow::Clan);
         ui_state_windows.menu_open = false;
     }
 
@@ -142,42 +146,44 @@ pub fn ui_game_menu_system(
     }
 
     if response_button_info.map_or(false, |r| r.clicked()) {
-        // TODO: Info dialog
+        ui_state_windows.toggle(UiWindow::Achievements);
         ui_state_windows.menu_open = false;
     }
 
     if response_button_exit.map_or(false, |r| r.clicked()) {
-        // TODO: Exit dialog
+        ui_state.exit_dialog_open = true;
         ui_state_windows.menu_open = false;
     }
 
-    if !egui_context.ctx_mut().wants_keyboard_input() {
-        egui_context.ctx_mut().input_mut(|input| {
-            if input.consume_key(egui::Modifiers::ALT, egui::Key::A) {
-                ui_state_windows.character_info_open = !ui_state_windows.character_info_open;
-            }
-
-            if input.consume_key(egui::Modifiers::ALT, egui::Key::I)
-                || input.consume_key(egui::Modifiers::ALT, egui::Key::V)
-            {
-                ui_state_windows.inventory_open = !ui_state_windows.inventory_open;
-            }
-
-            if input.consume_key(egui::Modifiers::ALT, egui::Key::N) {
-                ui_state_windows.clan_open = !ui_state_windows.clan_open;
-            }
-
-            if input.consume_key(egui::Modifiers::ALT, egui::Key::S) {
-                ui_state_windows.skill_list_open = !ui_state_windows.skill_list_open;
-            }
-
-            if input.consume_key(egui::Modifiers::ALT, egui::Key::Q) {
-                ui_state_windows.quest_list_open = !ui_state_windows.quest_list_open;
-            }
+    if ui_state.exit_dialog_open {
+        let mut logout_target = None;
+        let mut cancel = false;
+
+        egui::Window::new(localize!("exit-game-title"))
+            .id(egui::Id::new("exit_game"))
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .collapsible(false)
+            .resizable(false)
+            .show(egui_context.ctx_mut(), |ui| {
+                ui.vertical_centered_justified(|ui| {
+                    if ui.button(localize!("exit-game-character-select")).clicked() {
+                        logout_target = Some(LogoutTarget::CharacterSelect);
+                    }
+
+                    if ui.button(localize!("exit-game-exit")).clicked() {
+                        logout_target = Some(LogoutTarget::Exit);
+                    }
+
+                    cancel = ui.button(localize!("exit-game-cancel")).clicked();
+                });
+            });
+
+        if let Some(logout_target) = logout_target {
+            logout_events.send(LogoutEvent::Start(logout_target));
+        }
 
-            if input.consume_key(egui::Modifiers::ALT, egui::Key::O) {
-                ui_state_windows.settings_open = !ui_state_windows.settings_open;
-            }
-        });
+        if cancel || logout_target.is_some() {
+            ui_state.exit_dialog_open = false;
+        }
     }
 }
@@ -6,13 +6,18 @@ use bevy::{
 use bevy_egui::{egui, EguiContexts};
 
 use rose_game_common::components::{
-    Equipment, Hotbar, HotbarSlot, Inventory, SkillList, HOTBAR_NUM_PAGES, HOTBAR_PAGE_SIZE,
+    Equipment, Hotbar, HotbarSlot, Inventory, SkillList, SkillSlot, HOTBAR_NUM_PAGES,
+    HOTBAR_PAGE_SIZE,
 };
 
 use crate::{
     components::{Cooldowns, PlayerCharacter},
     events::PlayerCommandEvent,
-    resources::{GameData, UiResources},
+    localize,
+    resources::{
+        CooldownAlerts, GameData, HudElement, HudLayout, InputAction, InputBindings, SkillQueue,
+        SkillRangeIndicator, UiResources,
+    },
     ui::{
         tooltips::{PlayerTooltipQuery, PlayerTooltipQueryItem, SkillTooltipType},
         ui_add_item_tooltip, ui_add_skill_tooltip,
@@ -31,6 +36,8 @@ const IID_BTN_VERTICAL_PREV: i32 = 13;
 const IID_BTN_VERTICAL_NEXT: i32 = 14;
 const IID_NUMBER: i32 = 20;
 
+const COOLDOWN_ALERT_PULSE_COLOR: egui::Color32 = egui::Color32::from_rgb(120, 220, 255);
+
 pub struct UiStateHotBar {
     dialog_instance: DialogInstance,
     current_page: usize,
@@ -73,8 +80,11 @@ fn ui_add_hotbar_slot(
     game_data: &GameData,
     ui_resources: &UiResources,
     ui_state_dnd: &mut UiStateDragAndDrop,
+    queued_skill_slot: Option<SkillSlot>,
     use_slot: bool,
     player_command_events: &mut EventWriter<PlayerCommandEvent>,
+    skill_range_indicator: &mut SkillRangeIndicator,
+    cooldown_alerts: &mut CooldownAlerts,
 ) {
     let hotbar_slot = player.hotbar.pages[hotbar_index.0][hotbar_index.1].as_ref();
     let mut dropped_item = None;
@@ -123,13 +133,50 @@ fn ui_add_hotbar_slot(
         ),
     };
 
-    let response = ui
+    let mut response = ui
         .allocate_ui_at_rect(
             egui::Rect::from_min_size(pos, egui::vec2(40.0, 40.0)),
             |ui| egui::Widget::ui(drag_and_drop_slot, ui),
         )
         .inner;
 
+    if let Some(HotbarSlot::Skill(skill_slot)) = hotbar_slot {
+        if queued_skill_slot == Some(*skill_slot) {
+            // Highlight the skill which is queued to be cast next
+            ui.painter().rect_stroke(
+                response.rect.shrink(1.0),
+                egui::Rounding::none(),
+                egui::Stroke::new(2.0, egui::Color32::from_rgb(255, 200, 0)),
+            );
+        }
+
+        if let Some(skill_id) = player.skill_list.get_skill(*skill_slot) {
+            let skill_id = skill_id.get();
+
+            // Pulse the slot of a watched skill which has just come off cooldown
+            if let Some(remaining) = cooldown_alerts.ready_pulses.get(&skill_id) {
+                let alpha = (remaining * std::f32::consts::PI * 2.0).sin().abs();
+                ui.painter().rect_stroke(
+                    response.rect.expand(1.0),
+                    egui::Rounding::none(),
+                    egui::Stroke::new(3.0, COOLDOWN_ALERT_PULSE_COLOR.gamma_multiply(alpha)),
+                );
+                ui.ctx().request_repaint();
+            }
+
+            response = response.context_menu(|ui| {
+                let mut enabled = cooldown_alerts.skills.contains(&skill_id);
+                if ui
+                    .checkbox(&mut enabled, localize!("hotbar-alert-when-ready"))
+                    .changed()
+                {
+                    cooldown_alerts.set_skill(skill_id, enabled);
+                    ui.close_menu();
+                }
+            });
+        }
+    }
+
     if use_slot || response.double_clicked() {
         player_command_events.send(PlayerCommandEvent::UseHotbar(
             hotbar_index.0,
@@ -137,6 +184,12 @@ fn ui_add_hotbar_slot(
         ));
     }
 
+    if response.hovered() {
+        if let Some(HotbarSlot::Skill(skill_slot)) = hotbar_slot {
+            skill_range_indicator.hovered_skill = player.skill_list.get_skill(*skill_slot);
+        }
+    }
+
     if hotbar_slot.is_some() {
         response.on_hover_ui(|ui| match hotbar_slot {
             Some(HotbarSlot::Inventory(item_slot)) => {
@@ -205,9 +258,14 @@ pub fn ui_hotbar_system(
     query_player_tooltip: Query<PlayerTooltipQuery, With<PlayerCharacter>>,
     mut player_command_events: EventWriter<PlayerCommandEvent>,
     keyboard_input: Res<Input<KeyCode>>,
+    input_bindings: Res<InputBindings>,
     game_data: Res<GameData>,
     ui_resources: Res<UiResources>,
     dialog_assets: Res<Assets<Dialog>>,
+    skill_queue: Res<SkillQueue>,
+    mut skill_range_indicator: ResMut<SkillRangeIndicator>,
+    mut hud_layout: ResMut<HudLayout>,
+    mut cooldown_alerts: ResMut<CooldownAlerts>,
 ) {
     let ui_state_hot_bar = &mut *ui_state_hot_bar;
     let dialog = if let Some(dialog) = ui_state_hot_bar
@@ -225,31 +283,31 @@ pub fn ui_hotbar_system(
         return;
     };
     let player_tooltip_data = query_player_tooltip.get_single().ok();
+    let queued_skill_slot = skill_queue
+        .queued
+        .as_ref()
+        .map(|queued_skill| queued_skill.skill_slot);
 
     let use_hotbar_index = if !egui_context.ctx_mut().wants_keyboard_input() {
-        if keyboard_input.just_pressed(KeyCode::F1) {
-            Some(0)
-        } else if keyboard_input.just_pressed(KeyCode::F2) {
-            Some(1)
-        } else if keyboard_input.just_pressed(KeyCode::F3) {
-            Some(2)
-        } else if keyboard_input.just_pressed(KeyCode::F4) {
-            Some(3)
-        } else if keyboard_input.just_pressed(KeyCode::F5) {
-            Some(4)
-        } else if keyboard_input.just_pressed(KeyCode::F6) {
-            Some(5)
-        } else if keyboard_input.just_pressed(KeyCode::F7) {
-            Some(6)
-        } else if keyboard_input.just_pressed(KeyCode::F8) {
-            Some(7)
-        } else {
-            None
-        }
+        InputAction::HOTBAR
+            .iter()
+            .position(|&action| input_bindings.just_pressed(action, &keyboard_input))
     } else {
         None
     };
 
+    if !hud_layout.is_visible(HudElement::Hotbar) {
+        // The hotkeys still work while the hotbar is hidden
+        if let Some(index) = use_hotbar_index {
+            player_command_events.send(PlayerCommandEvent::UseHotbar(
+                ui_state_hot_bar.current_page,
+                index,
+            ));
+        }
+        hud_layout.rects[HudElement::Hotbar] = None;
+        return;
+    }
+
     let mut response_rotate_button = None;
     let mut response_hprev_button = None;
     let mut response_hnext_button = None;
@@ -265,13 +323,13 @@ pub fn ui_hotbar_system(
         screen_size.y - dialog.height,
     );
 
-    egui::Window::new("Hot Bar")
+    let response = egui::Window::new("Hot Bar")
         .frame(egui::Frame::none())
         .title_bar(false)
         .resizable(false)
         .default_width(dialog.width)
         .default_height(dialog.height)
-        .default_pos(default_position)
+        .fixed_pos(default_position + hud_layout.get_offset(HudElement::Hotbar))
         .show(egui_context.ctx_mut(), |ui| {
             dialog.draw(
                 ui,
@@ -313,13 +371,17 @@ pub fn ui_hotbar_system(
                             &game_data,
                             &ui_resources,
                             &mut ui_state_dnd,
+                            queued_skill_slot,
                             use_hotbar_index.map_or(false, |use_index| use_index == i),
                             &mut player_command_events,
+                            &mut skill_range_indicator,
+                            &mut cooldown_alerts,
                         );
                     }
                 },
             );
         });
+    hud_layout.rects[HudElement::Hotbar] = response.map(|response| response.response.rect);
 
     let previous_page = ui_state_hot_bar.current_page;
 
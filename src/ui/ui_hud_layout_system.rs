@@ -0,0 +1,131 @@
+use bevy::prelude::{Query, Res, ResMut, With};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_game_common::components::CharacterInfo;
+
+use crate::{
+    components::PlayerCharacter,
+    localize,
+    resources::{Account, CharacterStoreKey, HudElement, HudLayout},
+};
+
+fn get_hud_element_name(element: HudElement) -> String {
+    match element {
+        HudElement::Chatbox => localize!("hud-element-chatbox"),
+        HudElement::Minimap => localize!("hud-element-minimap"),
+        HudElement::Hotbar => localize!("hud-element-hotbar"),
+        HudElement::Party => localize!("hud-element-party"),
+    }
+}
+
+pub fn ui_hud_layout_system(
+    mut egui_context: EguiContexts,
+    mut hud_layout: ResMut<HudLayout>,
+    query_player: Query<&CharacterInfo, With<PlayerCharacter>>,
+    account: Option<Res<Account>>,
+) {
+    let Ok(character_info) = query_player.get_single() else {
+        return;
+    };
+
+    let store_key = CharacterStoreKey::new(account.as_deref(), &character_info.name);
+    if hud_layout.store_key.as_ref() != Some(&store_key) {
+        *hud_layout = HudLayout::load(store_key);
+    }
+
+    if hud_layout.edit_mode {
+        let hud_layout = &mut *hud_layout;
+        let ctx = egui_context.ctx_mut();
+
+        // Draw a handle over each element which was drawn this frame, dragging it
+        // moves the element next frame
+        for (element, rect) in hud_layout.rects.iter() {
+            let Some(rect) = *rect else {
+                continue;
+            };
+            let element_layout = &mut hud_layout.elements[element];
+
+            let (response, toggle_hidden) = egui::Area::new(format!("hud_layout_{:?}", element))
+                .order(egui::Order::Foreground)
+                .fixed_pos(rect.min)
+                .show(ctx, |ui| {
+                    let (handle_rect, response) =
+                        ui.allocate_exact_size(rect.size(), egui::Sense::drag());
+
+                    let fill = if element_layout.hidden {
+                        egui::Color32::from_rgba_unmultiplied(160, 40, 40, 120)
+                    } else {
+                        egui::Color32::from_rgba_unmultiplied(40, 80, 160, 120)
+                    };
+                    ui.painter().rect(
+                        handle_rect,
+                        egui::Rounding::same(2.0),
+                        fill,
+                        egui::Stroke::new(1.0, egui::Color32::WHITE),
+                    );
+                    ui.painter().text(
+                        handle_rect.center(),
+                        egui::Align2::CENTER_CENTER,
+                        get_hud_element_name(element),
+                        egui::FontId::proportional(16.0),
+                        egui::Color32::WHITE,
+                    );
+
+                    let button_rect = egui::Rect::from_min_size(
+                        handle_rect.min + egui::vec2(4.0, 4.0),
+                        egui::vec2(60.0, 20.0),
+                    );
+                    let toggle_hidden = ui
+                        .put(
+                            button_rect,
+                            egui::Button::new(if element_layout.hidden {
+                                localize!("hud-edit-show")
+                            } else {
+                                localize!("hud-edit-hide")
+                            }),
+                        )
+                        .clicked();
+
+                    (response, toggle_hidden)
+                })
+                .inner;
+
+            if response.dragged() {
+                let delta = response.drag_delta();
+                element_layout.offset[0] += delta.x;
+                element_layout.offset[1] += delta.y;
+            }
+
+            if response.drag_released() {
+                hud_layout.changed = true;
+            }
+
+            if toggle_hidden {
+                element_layout.hidden = !element_layout.hidden;
+                hud_layout.changed = true;
+            }
+        }
+
+        egui::Window::new(localize!("hud-edit-title"))
+            .anchor(egui::Align2::CENTER_TOP, [0.0, 40.0])
+            .collapsible(false)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.label(localize!("hud-edit-hint"));
+                ui.horizontal(|ui| {
+                    if ui.button(localize!("hud-edit-reset")).clicked() {
+                        hud_layout.reset();
+                    }
+
+                    if ui.button(localize!("hud-edit-done")).clicked() {
+                        hud_layout.edit_mode = false;
+                    }
+                });
+            });
+    }
+
+    if hud_layout.changed {
+        hud_layout.save();
+        hud_layout.changed = false;
+    }
+}
@@ -0,0 +1,205 @@
+use bevy::{
+    ecs::query::WorldQuery,
+    prelude::{Entity, EventWriter, Local, Query, Res, ResMut, With, Without},
+};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_data::{EquipmentIndex, Item};
+use rose_game_common::components::{CharacterInfo, Equipment, Level};
+
+use crate::{
+    components::PlayerCharacter,
+    events::ChatboxInputEvent,
+    localize,
+    resources::{FriendList, GameData, SelectedTarget, UiResources},
+    ui::{
+        tooltips::{PlayerTooltipQuery, PlayerTooltipQueryItem},
+        ui_add_item_tooltip, DragAndDropId, DragAndDropSlot, UiStateDragAndDrop, UiStateWindows,
+    },
+};
+
+// Same layout as the equipment tab of the inventory
+const INSPECT_EQUIPMENT_SLOTS: [(EquipmentIndex, egui::Pos2); 11] = [
+    (EquipmentIndex::Face, egui::pos2(0.0, 0.0)),
+    (EquipmentIndex::Head, egui::pos2(50.0, 0.0)),
+    (EquipmentIndex::Back, egui::pos2(100.0, 0.0)),
+    (EquipmentIndex::Weapon, egui::pos2(0.0, 46.0)),
+    (EquipmentIndex::Body, egui::pos2(50.0, 46.0)),
+    (EquipmentIndex::SubWeapon, egui::pos2(100.0, 46.0)),
+    (EquipmentIndex::Hands, egui::pos2(0.0, 92.0)),
+    (EquipmentIndex::Feet, egui::pos2(50.0, 92.0)),
+    (EquipmentIndex::Ring, egui::pos2(0.0, 138.0)),
+    (EquipmentIndex::Necklace, egui::pos2(50.0, 138.0)),
+    (EquipmentIndex::Earring, egui::pos2(100.0, 138.0)),
+];
+const INSPECT_EQUIPMENT_SIZE: egui::Vec2 = egui::vec2(140.0, 178.0);
+
+#[derive(Default)]
+pub struct UiInspectCharacterState {
+    context_menu: Option<(Entity, egui::Pos2)>,
+}
+
+#[derive(WorldQuery)]
+pub struct InspectCharacterQuery<'w> {
+    character_info: &'w CharacterInfo,
+    equipment: &'w Equipment,
+    level: &'w Level,
+}
+
+fn ui_add_inspect_equipment_slot(
+    ui: &mut egui::Ui,
+    ui_state_dnd: &mut UiStateDragAndDrop,
+    pos: egui::Pos2,
+    item: Option<Item>,
+    player_tooltip_data: Option<&PlayerTooltipQueryItem>,
+    game_data: &GameData,
+    ui_resources: &UiResources,
+) {
+    let mut dropped_item = None;
+    let response = ui
+        .allocate_ui_at_rect(
+            egui::Rect::from_min_size(ui.min_rect().min + pos.to_vec2(), egui::vec2(40.0, 40.0)),
+            |ui| {
+                egui::Widget::ui(
+                    DragAndDropSlot::with_item(
+                        DragAndDropId::NotDraggable,
+                        item.as_ref(),
+                        None,
+                        game_data,
+                        ui_resources,
+                        |_| false,
+                        &mut ui_state_dnd.dragged_item,
+                        &mut dropped_item,
+                        [40.0, 40.0],
+                    ),
+                    ui,
+                )
+            },
+        )
+        .inner;
+
+    if let Some(item) = item.as_ref() {
+        response.on_hover_ui(|ui| {
+            ui_add_item_tooltip(ui, game_data, player_tooltip_data, item);
+        });
+    }
+}
+
+pub fn ui_inspect_character_system(
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiInspectCharacterState>,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    mut ui_state_dnd: ResMut<UiStateDragAndDrop>,
+    mut selected_target: ResMut<SelectedTarget>,
+    mut friend_list: ResMut<FriendList>,
+    mut chatbox_input_events: EventWriter<ChatboxInputEvent>,
+    query_character: Query<InspectCharacterQuery, Without<PlayerCharacter>>,
+    query_player_tooltip: Query<PlayerTooltipQuery, With<PlayerCharacter>>,
+    game_data: Res<GameData>,
+    ui_resources: Res<UiResources>,
+) {
+    let mut opened_context_menu = false;
+    if let Some((entity, cursor_position)) = selected_target.context_menu.take() {
+        ui_state.context_menu = Some((entity, egui::pos2(cursor_position.x, cursor_position.y)));
+        opened_context_menu = true;
+    }
+
+    if let Some((entity, position)) = ui_state.context_menu {
+        if let Ok(character) = query_character.get(entity) {
+            let name = &character.character_info.name;
+            let mut inspect = false;
+            let mut whisper = false;
+            let mut add_friend = false;
+
+            let response = egui::Area::new("character_context_menu")
+                .order(egui::Order::Foreground)
+                .fixed_pos(position)
+                .show(egui_context.ctx_mut(), |ui| {
+                    egui::Frame::menu(ui.style()).show(ui, |ui| {
+                        ui.label(name);
+                        ui.separator();
+                        inspect = ui.button(localize!("character-menu-inspect")).clicked();
+                        whisper = ui.button(localize!("character-menu-whisper")).clicked();
+                        add_friend = ui
+                            .add_enabled(
+                                !friend_list.contains(name),
+                                egui::Button::new(localize!("character-menu-add-friend")),
+                            )
+                            .clicked();
+                    });
+                });
+
+            if inspect {
+                ui_state_windows.inspect_character = Some(entity);
+            }
+
+            if whisper {
+                chatbox_input_events.send(ChatboxInputEvent::Whisper(name.clone()));
+            }
+
+            if add_friend {
+                friend_list.add(name);
+            }
+
+            if inspect || whisper || add_friend {
+                ui_state.context_menu = None;
+            } else if !opened_context_menu && response.response.clicked_elsewhere() {
+                ui_state.context_menu = None;
+            }
+        } else {
+            ui_state.context_menu = None;
+        }
+    }
+
+    let Some(entity) = ui_state_windows.inspect_character else {
+        return;
+    };
+    let Ok(character) = query_character.get(entity) else {
+        // Character has left our view range
+        ui_state_windows.inspect_character = None;
+        return;
+    };
+    let player_tooltip_data = query_player_tooltip.get_single().ok();
+
+    // The visible equipment is already synced for the character model, so there is no need
+    // to request it from the server
+    let mut open = true;
+    egui::Window::new(localize!(
+        "inspect-character-title",
+        name = character.character_info.name.as_str()
+    ))
+    .id(egui::Id::new("inspect_character"))
+    .open(&mut open)
+    .resizable(false)
+    .show(egui_context.ctx_mut(), |ui| {
+        ui.label(localize!(
+            "inspect-character-level-job",
+            level = character.level.level,
+            job = game_data
+                .string_database
+                .get_job_name(character.character_info.job)
+        ));
+        ui.separator();
+
+        let (rect, _) = ui.allocate_exact_size(INSPECT_EQUIPMENT_SIZE, egui::Sense::hover());
+        ui.allocate_ui_at_rect(rect, |ui| {
+            for &(equipment_index, pos) in INSPECT_EQUIPMENT_SLOTS.iter() {
+                ui_add_inspect_equipment_slot(
+                    ui,
+                    &mut ui_state_dnd,
+                    pos,
+                    character.equipment.equipped_items[equipment_index]
+                        .as_ref()
+                        .map(|item| Item::Equipment(item.clone())),
+                    player_tooltip_data.as_ref(),
+                    &game_data,
+                    &ui_resources,
+                );
+            }
+        });
+    });
+
+    if !open {
+        ui_state_windows.inspect_character = None;
+    }
+}
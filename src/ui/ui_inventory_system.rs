@@ -5,18 +5,20 @@ use bevy::{
 use bevy_egui::{egui, EguiContexts};
 use enum_map::{enum_map, EnumMap};
 
-use rose_data::{AmmoIndex, EquipmentIndex, Item, VehiclePartIndex};
+use rose_data::{AmmoIndex, EquipmentIndex, Item, ItemType, VehiclePartIndex};
 use rose_game_common::components::{
     Equipment, Inventory, InventoryPageType, ItemSlot, INVENTORY_PAGE_SIZE,
 };
 
 use crate::{
-    components::{Cooldowns, PlayerCharacter},
-    events::{NumberInputDialogEvent, PlayerCommandEvent},
-    resources::{GameData, UiResources},
+    components::{Cooldowns, Encumbrance, ItemLocks, PlayerCharacter, ENCUMBRANCE_WARNING_RATIO},
+    events::{CraftEvent, MoneyInputDialogEvent, PlayerCommandEvent},
+    localize,
+    resources::{GameData, UiResources, WorldRates},
     ui::{
+        format_money,
         tooltips::{PlayerTooltipQuery, PlayerTooltipQueryItem},
-        ui_add_item_tooltip,
+        ui_add_item_sell_price, ui_add_item_tooltip,
         widgets::{DataBindings, Dialog, Widget},
         DialogInstance, DragAndDropId, DragAndDropSlot, UiSoundEvent, UiStateDragAndDrop,
         UiStateWindows,
@@ -221,10 +223,12 @@ fn ui_add_inventory_slot(
     player: &PlayerQueryItem,
     player_tooltip_data: Option<&PlayerTooltipQueryItem>,
     game_data: &GameData,
+    world_rates: Option<&WorldRates>,
     ui_resources: &UiResources,
     item_slot_map: &mut EnumMap<InventoryPageType, Vec<ItemSlot>>,
     ui_state_dnd: &mut UiStateDragAndDrop,
     player_command_events: &mut EventWriter<PlayerCommandEvent>,
+    craft_events: &mut EventWriter<CraftEvent>,
 ) {
     let drag_accepts = match inventory_slot {
         ItemSlot::Inventory(page_type, _) => match page_type {
@@ -238,6 +242,11 @@ fn ui_add_inventory_slot(
         ItemSlot::Vehicle(_) => drag_accepts_vehicles,
     };
     let item = (player.equipment, player.inventory).get_item(inventory_slot);
+    let locked = item.as_ref().map_or(false, |item| {
+        player.item_locks.map_or(false, |item_locks| {
+            item_locks.is_locked(inventory_slot, item)
+        })
+    });
 
     let mut dropped_item = None;
     let response = ui
@@ -255,7 +264,8 @@ fn ui_add_inventory_slot(
                         &mut ui_state_dnd.dragged_item,
                         &mut dropped_item,
                         [40.0, 40.0],
-                    ),
+                    )
+                    .with_locked(locked),
                     ui,
                 )
             },
@@ -271,6 +281,8 @@ fn ui_add_inventory_slot(
     let mut use_inventory_slot = None;
     let mut drop_inventory_slot = None;
     let mut swap_inventory_slots = None;
+    let mut lock_inventory_slot = None;
+    let mut insert_gem_inventory_slot = None;
 
     if response.double_clicked() {
         match inventory_slot {
@@ -324,6 +336,12 @@ fn ui_add_inventory_slot(
                 equip_ammo_inventory_slot = Some(inventory_slot);
             }
 
+            if item.get_item_type() == ItemType::Gem
+                && ui.button(localize!("craft-insert-gem-menu")).clicked()
+            {
+                insert_gem_inventory_slot = Some(inventory_slot);
+            }
+
             if let ItemSlot::Equipment(equipment_index) = inventory_slot {
                 if ui.button("Unequip").clicked() {
                     unequip_equipment_index = Some(equipment_index);
@@ -338,13 +356,20 @@ fn ui_add_inventory_slot(
                 use_inventory_slot = Some(inventory_slot);
             }
 
-            if matches!(inventory_slot, ItemSlot::Inventory(_, _)) && ui.button("Drop").clicked() {
-                drop_inventory_slot = Some(inventory_slot);
+            if matches!(inventory_slot, ItemSlot::Inventory(_, _)) {
+                if !locked && ui.button("Drop").clicked() {
+                    drop_inventory_slot = Some(inventory_slot);
+                }
+
+                if ui.button(if locked { "Unlock" } else { "Lock" }).clicked() {
+                    lock_inventory_slot = Some((inventory_slot, !locked));
+                }
             }
         });
 
         response.on_hover_ui(|ui| {
             ui_add_item_tooltip(ui, game_data, player_tooltip_data, &item);
+            ui_add_item_sell_price(ui, game_data, player_tooltip_data, world_rates, &item);
         });
     }
 
@@ -429,6 +454,14 @@ fn ui_add_inventory_slot(
         player_command_events.send(PlayerCommandEvent::DropItem(drop_inventory_slot));
     }
 
+    if let Some((item_slot, locked)) = lock_inventory_slot {
+        player_command_events.send(PlayerCommandEvent::SetItemLocked(item_slot, locked));
+    }
+
+    if let Some(gem_item_slot) = insert_gem_inventory_slot {
+        craft_events.send(CraftEvent::OpenInsertGem { gem_item_slot });
+    }
+
     if let Some((ItemSlot::Inventory(page_a, slot_a), ItemSlot::Inventory(page_b, slot_b))) =
         swap_inventory_slots
     {
@@ -453,6 +486,34 @@ pub struct PlayerQuery<'w> {
     equipment: &'w Equipment,
     inventory: &'w Inventory,
     cooldowns: &'w Cooldowns,
+    item_locks: Option<&'w ItemLocks>,
+    encumbrance: Option<&'w Encumbrance>,
+}
+
+fn ui_add_weight_bar(ui: &mut egui::Ui, encumbrance: &Encumbrance) {
+    let ratio = encumbrance.get_ratio();
+    let fill_color = if encumbrance.is_over_encumbered() {
+        egui::Color32::from_rgb(200, 50, 50)
+    } else if ratio >= ENCUMBRANCE_WARNING_RATIO {
+        egui::Color32::from_rgb(220, 180, 40)
+    } else {
+        egui::Color32::from_rgb(60, 160, 60)
+    };
+
+    ui.add(
+        egui::ProgressBar::new(ratio.min(1.0))
+            .desired_width(90.0)
+            .fill(fill_color)
+            .text(format!(
+                "{} / {}",
+                encumbrance.weight, encumbrance.max_weight
+            )),
+    )
+    .on_hover_text(if encumbrance.is_over_encumbered() {
+        localize!("inventory-weight-over-encumbered")
+    } else {
+        localize!("inventory-weight")
+    });
 }
 
 pub fn ui_inventory_system(
@@ -466,8 +527,10 @@ pub fn ui_inventory_system(
     dialog_assets: Res<Assets<Dialog>>,
     game_data: Res<GameData>,
     ui_resources: Res<UiResources>,
+    world_rates: Option<Res<WorldRates>>,
     mut player_command_events: EventWriter<PlayerCommandEvent>,
-    mut number_input_dialog_events: EventWriter<NumberInputDialogEvent>,
+    mut money_input_dialog_events: EventWriter<MoneyInputDialogEvent>,
+    mut craft_events: EventWriter<CraftEvent>,
 ) {
     let ui_state_inventory = &mut *ui_state_inventory;
     let dialog = if let Some(dialog) = ui_state_inventory
@@ -546,10 +609,12 @@ pub fn ui_inventory_system(
                                         &player,
                                         player_tooltip_data.as_ref(),
                                         &game_data,
+                                        world_rates.as_deref(),
                                         &ui_resources,
                                         &mut ui_state_inventory.item_slot_map,
                                         &mut ui_state_dnd,
                                         &mut player_command_events,
+                                        &mut craft_events,
                                     );
                                 }
                             }
@@ -577,10 +642,12 @@ pub fn ui_inventory_system(
                                         &player,
                                         player_tooltip_data.as_ref(),
                                         &game_data,
+                                        world_rates.as_deref(),
                                         &ui_resources,
                                         &mut ui_state_inventory.item_slot_map,
                                         &mut ui_state_dnd,
                                         &mut player_command_events,
+                                        &mut craft_events,
                                     );
                                 }
                             }
@@ -611,10 +678,12 @@ pub fn ui_inventory_system(
                                 &player,
                                 player_tooltip_data.as_ref(),
                                 &game_data,
+                                world_rates.as_deref(),
                                 &ui_resources,
                                 &mut ui_state_inventory.item_slot_map,
                                 &mut ui_state_dnd,
                                 &mut player_command_events,
+                                &mut craft_events,
                             );
                         }
 
@@ -628,11 +697,21 @@ pub fn ui_inventory_system(
                         )),
                         |ui| {
                             ui.horizontal_top(|ui| {
-                                ui.add(egui::Label::new(format!("{}", player.inventory.money.0)))
+                                ui.add(egui::Label::new(format_money(player.inventory.money)))
                             })
                             .inner
                         },
                     );
+
+                    if let Some(encumbrance) = player.encumbrance {
+                        ui.allocate_ui_at_rect(
+                            ui.min_rect().translate(egui::vec2(
+                                125.0,
+                                dialog.height - 27.0 - if is_minimised { 200.0 } else { 0.0 },
+                            )),
+                            |ui| ui_add_weight_bar(ui, encumbrance),
+                        );
+                    }
                 },
             );
         });
@@ -658,15 +737,16 @@ pub fn ui_inventory_system(
     }
 
     if response_drop_money_button.map_or(false, |r| r.clicked()) && player.inventory.money.0 > 0 {
-        number_input_dialog_events.send(NumberInputDialogEvent::Show {
-            max_value: Some(player.inventory.money.0 as usize),
+        money_input_dialog_events.send(MoneyInputDialogEvent::Show {
+            title: localize!("money-input-drop-title"),
+            balance: player.inventory.money,
             modal: false,
-            ok: Some(Box::new(move |commands, amount| {
+            ok: Some(Box::new(move |commands, money| {
                 commands.add(move |world: &mut World| {
                     if let Some(mut player_command_events) =
                         world.get_resource_mut::<Events<PlayerCommandEvent>>()
                     {
-                        player_command_events.send(PlayerCommandEvent::DropMoney(amount));
+                        player_command_events.send(PlayerCommandEvent::DropMoney(money.0 as usize));
                     }
                 });
             })),
@@ -1,12 +1,12 @@
 use std::sync::Arc;
 
-use bevy::prelude::{Camera, Camera3d, GlobalTransform, Local, Query, Res, Vec2, With};
+use bevy::prelude::{Camera, GlobalTransform, Local, Query, Res, Vec2, With};
 use bevy_egui::{egui, EguiContexts};
 
 use rose_data::Item;
 use rose_game_common::components::{DroppedItem, ItemDrop};
 
-use crate::{resources::GameData, ui::get_item_name_color};
+use crate::{components::MainCamera, resources::GameData, ui::get_item_name_color};
 
 pub struct ItemDropName {
     screen_z: f32,
@@ -17,7 +17,7 @@ pub struct ItemDropName {
 
 pub fn ui_item_drop_name_system(
     mut egui_context: EguiContexts,
-    query_camera: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    query_camera: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
     query_item_drop: Query<(&ItemDrop, &GlobalTransform)>,
     game_data: Res<GameData>,
     mut visible_names: Local<Vec<ItemDropName>>,
@@ -0,0 +1,186 @@
+use bevy::prelude::{Camera, GlobalTransform, Query, Res, ResMut, Time, Vec2, Vec3, With};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    components::{MainCamera, ModelHeight},
+    localize,
+    resources::{AccessibilitySettings, LevelUpPresentation, LevelUpSettings},
+};
+
+const LEVEL_UP_COLOUR: [u8; 3] = [255, 215, 80];
+
+const LEVEL_UP_FLASH_DURATION: f32 = 1.0;
+const LEVEL_UP_FLASH_MAX_ALPHA: f32 = 0.5;
+// Width of the vignette relative to the smallest screen dimension
+const LEVEL_UP_FLASH_WIDTH: f32 = 0.2;
+
+const LEVEL_UP_BANNER_DURATION: f32 = 3.0;
+const LEVEL_UP_BANNER_POP_DURATION: f32 = 0.3;
+const LEVEL_UP_BANNER_FADE_DURATION: f32 = 0.75;
+const LEVEL_UP_BANNER_RISE_HEIGHT: f32 = 0.5;
+const LEVEL_UP_BANNER_FONT_SIZE: f32 = 32.0;
+const LEVEL_UP_BANNER_LEVEL_FONT_SIZE: f32 = 18.0;
+
+fn paint_vignette(painter: &egui::Painter, screen_rect: egui::Rect, colour: egui::Color32) {
+    let width = screen_rect.width().min(screen_rect.height()) * LEVEL_UP_FLASH_WIDTH;
+    let inner_rect = screen_rect.shrink(width);
+
+    // Each edge is a quad fading from the screen border to transparent
+    let mut mesh = egui::Mesh::default();
+    for rect in [screen_rect, inner_rect] {
+        let vertex_colour = if rect == screen_rect {
+            colour
+        } else {
+            egui::Color32::TRANSPARENT
+        };
+        mesh.colored_vertex(rect.left_top(), vertex_colour);
+        mesh.colored_vertex(rect.right_top(), vertex_colour);
+        mesh.colored_vertex(rect.right_bottom(), vertex_colour);
+        mesh.colored_vertex(rect.left_bottom(), vertex_colour);
+    }
+
+    for outer in 0..4 {
+        let next = (outer + 1) % 4;
+        mesh.add_triangle(outer, next, 4 + outer);
+        mesh.add_triangle(next, 4 + next, 4 + outer);
+    }
+
+    painter.add(egui::Shape::mesh(mesh));
+}
+
+fn paint_banner_text(
+    ctx: &egui::Context,
+    painter: &egui::Painter,
+    text: String,
+    font_size: f32,
+    bottom_centre: egui::Pos2,
+    alpha: f32,
+) -> f32 {
+    let [r, g, b] = LEVEL_UP_COLOUR;
+    let colour = egui::Color32::from_rgba_unmultiplied(r, g, b, (alpha * 255.0) as u8);
+    let shadow_colour = egui::Color32::from_black_alpha((alpha * 200.0) as u8);
+
+    let galley = ctx
+        .fonts(|fonts| fonts.layout_no_wrap(text, egui::FontId::proportional(font_size), colour));
+    let height = galley.rect.height();
+    let pos = egui::pos2(
+        bottom_centre.x - galley.rect.width() / 2.0,
+        bottom_centre.y - height,
+    );
+
+    painter.add(egui::epaint::TextShape {
+        pos: pos + egui::vec2(2.0, 2.0),
+        galley: galley.clone(),
+        underline: egui::Stroke::NONE,
+        override_text_color: Some(shadow_colour),
+        angle: 0.0,
+    });
+    painter.add(egui::epaint::TextShape {
+        pos,
+        galley,
+        underline: egui::Stroke::NONE,
+        override_text_color: Some(colour),
+        angle: 0.0,
+    });
+
+    height
+}
+
+pub fn ui_level_up_system(
+    mut egui_context: EguiContexts,
+    query_camera: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    query_entity: Query<(&GlobalTransform, Option<&ModelHeight>)>,
+    accessibility_settings: Res<AccessibilitySettings>,
+    level_up_settings: Res<LevelUpSettings>,
+    mut level_up_presentation: ResMut<LevelUpPresentation>,
+    time: Res<Time>,
+) {
+    let Some(entity) = level_up_presentation.entity else {
+        return;
+    };
+
+    let age = time
+        .elapsed()
+        .saturating_sub(level_up_presentation.start_time)
+        .as_secs_f32();
+    if age >= LEVEL_UP_FLASH_DURATION.max(LEVEL_UP_BANNER_DURATION) {
+        level_up_presentation.entity = None;
+        return;
+    }
+
+    let ctx = egui_context.ctx_mut();
+    let screen_rect = ctx.input(|input| input.screen_rect());
+    let painter = ctx.layer_painter(egui::LayerId::new(
+        egui::Order::Background,
+        egui::Id::new("level_up"),
+    ));
+
+    if level_up_settings.screen_flash
+        && !accessibility_settings.reduce_flashing
+        && age < LEVEL_UP_FLASH_DURATION
+    {
+        let alpha = LEVEL_UP_FLASH_MAX_ALPHA * (1.0 - age / LEVEL_UP_FLASH_DURATION);
+        let [r, g, b] = LEVEL_UP_COLOUR;
+        paint_vignette(
+            &painter,
+            screen_rect,
+            egui::Color32::from_rgba_unmultiplied(r, g, b, (alpha * 255.0) as u8),
+        );
+    }
+
+    if !level_up_settings.banner || age >= LEVEL_UP_BANNER_DURATION {
+        return;
+    }
+
+    let Ok((camera, camera_transform)) = query_camera.get_single() else {
+        return;
+    };
+    let Ok((global_transform, model_height)) = query_entity.get(entity) else {
+        return;
+    };
+
+    let height = model_height.map_or(2.0, |model_height| model_height.height)
+        + LEVEL_UP_BANNER_RISE_HEIGHT * (1.0 + age / LEVEL_UP_BANNER_DURATION);
+    let Some(ndc_space_coords) = camera.world_to_ndc(
+        camera_transform,
+        global_transform.translation() + Vec3::Y * height,
+    ) else {
+        return;
+    };
+    if ndc_space_coords.z < 0.0 || ndc_space_coords.z > 1.0 {
+        // Outside near / far plane
+        return;
+    }
+
+    let screen_size = screen_rect.size();
+    let screen_pos =
+        (ndc_space_coords.truncate() + Vec2::ONE) / 2.0 * Vec2::new(screen_size.x, screen_size.y);
+    let bottom_centre = egui::pos2(screen_pos.x, screen_size.y - screen_pos.y);
+
+    // Pop in larger than normal then settle, fading out at the end
+    let pop = (age / LEVEL_UP_BANNER_POP_DURATION).min(1.0);
+    let scale = 1.0 + 0.5 * (1.0 - pop) * (1.0 - pop);
+    let alpha = pop.min((LEVEL_UP_BANNER_DURATION - age) / LEVEL_UP_BANNER_FADE_DURATION);
+
+    let level_height = if let Some(level) = level_up_presentation.level {
+        paint_banner_text(
+            ctx,
+            &painter,
+            localize!("level-up-banner-level", level = level),
+            LEVEL_UP_BANNER_LEVEL_FONT_SIZE * scale,
+            bottom_centre,
+            alpha,
+        )
+    } else {
+        0.0
+    };
+
+    paint_banner_text(
+        ctx,
+        &painter,
+        localize!("level-up-banner"),
+        LEVEL_UP_BANNER_FONT_SIZE * scale,
+        bottom_centre - egui::vec2(0.0, level_height),
+        alpha,
+    );
+}
@@ -1,12 +1,13 @@
 use bevy::{
     app::AppExit,
-    prelude::{Assets, EventWriter, Local, Res},
+    prelude::{Assets, EventWriter, Local, Res, ResMut},
 };
 use bevy_egui::{egui, EguiContexts};
 
 use crate::{
     events::LoginEvent,
-    resources::{LoginState, ServerConfiguration, UiResources},
+    localize,
+    resources::{LoginProfiles, LoginState, ServerConfiguration, UiResources},
     ui::{
         widgets::{DataBindings, Dialog},
         UiSoundEvent,
@@ -34,7 +35,8 @@ pub fn ui_login_system(
     mut egui_context: EguiContexts,
     dialog_assets: Res<Assets<Dialog>>,
     login_state: Res<LoginState>,
-    server_configuration: Res<ServerConfiguration>,
+    mut server_configuration: ResMut<ServerConfiguration>,
+    mut login_profiles: ResMut<LoginProfiles>,
     ui_resources: Res<UiResources>,
     mut exit_events: EventWriter<AppExit>,
     mut login_events: EventWriter<LoginEvent>,
@@ -62,6 +64,34 @@ pub fn ui_login_system(
         .input(|input| input.screen_rect().size());
     let position = egui::pos2(screen_size.x - dialog.width - 100.0, 100.0);
 
+    if login_profiles.profiles.len() > 1 {
+        let mut selected = login_profiles.selected;
+        egui::Window::new("Login Profile")
+            .title_bar(false)
+            .resizable(false)
+            .fixed_pos(position + egui::vec2(0.0, dialog.height + 10.0))
+            .show(egui_context.ctx_mut(), |ui| {
+                ui.horizontal(|ui| {
+                    ui.label(localize!("login-profile"));
+                    egui::ComboBox::from_id_source("login_profile")
+                        .selected_text(&login_profiles.selected().name)
+                        .show_ui(ui, |ui| {
+                            for (index, profile) in login_profiles.profiles.iter().enumerate() {
+                                ui.selectable_value(&mut selected, index, &profile.name);
+                            }
+                        });
+                });
+            });
+
+        if selected != login_profiles.selected {
+            login_profiles.selected = selected;
+            server_configuration.select_profile(login_profiles.selected());
+            ui_state.username.clear();
+            ui_state.password.clear();
+            ui_state.initial_focus_set = false;
+        }
+    }
+
     if !ui_state.initial_focus_set {
         if let Some(username) = server_configuration.preset_username.as_ref() {
             ui_state.username = username.clone();
@@ -70,6 +100,8 @@ pub fn ui_login_system(
         if let Some(password) = server_configuration.preset_password.as_ref() {
             ui_state.password = password.clone();
         }
+
+        ui_state.remember_details = login_profiles.selected().config.remember_password;
     }
 
     egui::Window::new("Login")
@@ -123,6 +155,15 @@ pub fn ui_login_system(
                 r.request_focus();
             }
         } else {
+            if ui_state.remember_details || login_profiles.selected().config.remember_password {
+                login_profiles.selected_mut().remember_password(
+                    &ui_state.username,
+                    &ui_state.password,
+                    ui_state.remember_details,
+                );
+                server_configuration.select_profile(login_profiles.selected());
+            }
+
             login_events.send(LoginEvent::Login {
                 username: ui_state.username.clone(),
                 password: ui_state.password.clone(),
@@ -5,9 +5,10 @@ use bevy_inspector_egui::egui::text::LayoutJob;
 use crate::{
     events::MessageBoxEvent,
     resources::UiResources,
+    text_to_speech::{self, TextToSpeechChannel},
     ui::{
         widgets::{Dialog, DrawWidget, Widget},
-        DataBindings, DialogInstance, UiSoundEvent,
+        DataBindings, DialogInstance, UiSoundEvent, UiStateWindows,
     },
 };
 
@@ -40,6 +41,7 @@ pub fn ui_message_box_system(
     mut ui_sound_events: EventWriter<UiSoundEvent>,
     mut egui_context: EguiContexts,
     mut message_box_events: ResMut<Events<MessageBoxEvent>>,
+    mut ui_state_windows: ResMut<UiStateWindows>,
     dialog_assets: Res<Assets<Dialog>>,
     ui_resources: Res<UiResources>,
 ) {
@@ -69,6 +71,26 @@ pub fn ui_message_box_system(
             59.0
         };
 
+    // Escape from the window manager closes the most recent message box, as cancel when it
+    // has a cancel button
+    if !ui_state_windows.message_box_open {
+        if let Some(active_message_box) = ui_state.active.pop() {
+            if let Some(cancel) = active_message_box.cancel {
+                cancel(&mut commands);
+            } else if let Some(ok) = active_message_box.ok {
+                ok(&mut commands);
+            }
+
+            if let Some((in_use, _)) = ui_state
+                .window_ids
+                .iter_mut()
+                .find(|(_, id)| *id == active_message_box.id)
+            {
+                *in_use = false;
+            }
+        }
+    }
+
     for event in message_box_events.drain() {
         let MessageBoxEvent::Show {
             message,
@@ -84,6 +106,7 @@ pub fn ui_message_box_system(
         };
         job.wrap.max_width = dialog.width - 16.0;
         job.append(&message, 0.0, current_text_format.clone());
+        text_to_speech::speak(TextToSpeechChannel::MessageBox, &message);
 
         let id = if let Some((in_use, id)) =
             ui_state.window_ids.iter_mut().find(|(in_use, _)| !in_use)
@@ -306,4 +329,6 @@ pub fn ui_message_box_system(
 
         i += 1;
     }
+
+    ui_state_windows.message_box_open = !ui_state.active.is_empty();
 }
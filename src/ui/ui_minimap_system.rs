@@ -1,20 +1,24 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use bevy::{
     math::{Vec2, Vec3Swizzles},
     prelude::{
-        AssetServer, Assets, Camera3d, EventWriter, Handle, Image, Local, Query, Res, Transform,
+        AssetServer, Assets, EventWriter, Handle, Image, Local, Query, Res, ResMut, Transform,
         Vec3, With, Without,
     },
 };
 use bevy_egui::{egui, EguiContexts};
 
 use rose_data::ZoneId;
-use rose_game_common::components::{CharacterInfo, Team};
+use rose_game_common::components::{CharacterInfo, Npc, Team};
 
 use crate::{
-    components::{PartyInfo, PlayerCharacter, Position},
-    resources::{CurrentZone, GameData, UiResources, UiSpriteSheetType},
+    components::{
+        MainCamera, NpcQuestMarker, NpcQuestMarkerType, PartyInfo, PlayerCharacter, Position,
+    },
+    resources::{
+        CurrentZone, GameData, HudElement, HudLayout, UiResources, UiSpriteSheetType, Waypoints,
+    },
     ui::{
         widgets::{DataBindings, Dialog, Widget},
         UiSoundEvent,
@@ -89,14 +93,17 @@ pub fn ui_minimap_system(
     mut ui_sound_events: EventWriter<UiSoundEvent>,
     query_player: Query<(&Position, &Team, Option<&PartyInfo>), With<PlayerCharacter>>,
     query_characters: Query<(&CharacterInfo, &Position, &Team), Without<PlayerCharacter>>,
+    query_npc_quest_markers: Query<(&Npc, &NpcQuestMarker)>,
     asset_server: Res<AssetServer>,
-    query_camera: Query<&Transform, With<Camera3d>>,
+    query_camera: Query<&Transform, With<MainCamera>>,
     images: Res<Assets<Image>>,
     current_zone: Option<Res<CurrentZone>>,
     zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
     game_data: Res<GameData>,
     ui_resources: Res<UiResources>,
     dialog_assets: Res<Assets<Dialog>>,
+    waypoints: Res<Waypoints>,
+    mut hud_layout: ResMut<HudLayout>,
 ) {
     let ui_state = &mut *ui_state;
     let dialog = if let Some(dialog) = dialog_assets.get(&ui_resources.dialog_minimap) {
@@ -231,8 +238,16 @@ pub fn ui_minimap_system(
         Vec2::new(minimap_player_x, minimap_player_y)
     };
 
-    egui::Window::new("Minimap")
-        .anchor(egui::Align2::RIGHT_TOP, [0.0, 0.0])
+    if !hud_layout.is_visible(HudElement::Minimap) {
+        hud_layout.rects[HudElement::Minimap] = None;
+        return;
+    }
+
+    let response = egui::Window::new("Minimap")
+        .anchor(
+            egui::Align2::RIGHT_TOP,
+            hud_layout.get_offset(HudElement::Minimap),
+        )
         .frame(egui::Frame::none())
         .title_bar(false)
         .resizable(false)
@@ -381,6 +396,11 @@ pub fn ui_minimap_system(
                     }
                 }
 
+                let npc_quest_markers: HashMap<_, _> = query_npc_quest_markers
+                    .iter()
+                    .map(|(npc, npc_quest_marker)| (npc.id, npc_quest_marker.marker_type))
+                    .collect();
+
                 // Draw NPC markers
                 for &ZoneNpc {
                     npc_id,
@@ -406,6 +426,24 @@ pub fn ui_minimap_system(
                     if minimap_rect.contains_rect(icon_rect) {
                         icon_image.draw(ui, icon_rect.min);
 
+                        if let Some(marker_type) = npc_quest_markers.get(&npc_id) {
+                            let (text, color) = match marker_type {
+                                NpcQuestMarkerType::Available => {
+                                    ("!", egui::Color32::from_rgb(255, 217, 25))
+                                }
+                                NpcQuestMarkerType::Complete => {
+                                    ("?", egui::Color32::from_rgb(76, 255, 76))
+                                }
+                            };
+                            ui.painter().text(
+                                icon_rect.center_top(),
+                                egui::Align2::CENTER_BOTTOM,
+                                text,
+                                egui::FontId::proportional(14.0),
+                                color,
+                            );
+                        }
+
                         let response = ui.allocate_rect(
                             egui::Rect::from_min_size(
                                 icon_rect.min + egui::vec2(6.0, 6.0),
@@ -417,6 +455,28 @@ pub fn ui_minimap_system(
                     }
                 }
 
+                // Draw personal waypoint markers
+                for waypoint in waypoints.iter_zone(current_zone.id) {
+                    let waypoint_minimap_position =
+                        map_absolute_position(ui_state, waypoint.get_position());
+                    let waypoint_rect = egui::Rect::from_center_size(
+                        waypoint_minimap_position.to_array().into(),
+                        egui::vec2(8.0, 8.0),
+                    );
+
+                    if minimap_rect.contains_rect(waypoint_rect) {
+                        ui.painter().circle(
+                            waypoint_rect.center(),
+                            3.5,
+                            egui::Color32::from_rgb(80, 200, 255),
+                            egui::Stroke::new(1.0, egui::Color32::BLACK),
+                        );
+
+                        let response = ui.allocate_rect(waypoint_rect, egui::Sense::hover());
+                        response.on_hover_text(&waypoint.name);
+                    }
+                }
+
                 // Draw player position arrow texture on a rotated rectangle to face camera position
                 if let Some(minimap_player_pos) = minimap_player_pos {
                     let minimap_player_sprite = ui_resources.get_minimap_player_sprite().unwrap();
@@ -530,6 +590,7 @@ pub fn ui_minimap_system(
                 });
             }
         });
+    hud_layout.rects[HudElement::Minimap] = response.map(|response| response.response.rect);
 
     if response_expand_button.map_or(false, |r| r.clicked()) {
         ui_state.is_expanded = true;
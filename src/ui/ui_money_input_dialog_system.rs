@@ -0,0 +1,140 @@
+use bevy::prelude::{Commands, Events, Local, ResMut};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_game_common::components::Money;
+
+use crate::{
+    events::MoneyInputDialogEvent,
+    localize,
+    ui::{validate_money, MoneyInput},
+};
+
+pub struct ActiveMoneyInputDialog {
+    title: String,
+    text: String,
+    balance: Money,
+    modal: bool,
+    request_focus: bool,
+    ok: Option<Box<dyn FnOnce(&mut Commands, Money) + Send + Sync>>,
+    cancel: Option<Box<dyn FnOnce(&mut Commands) + Send + Sync>>,
+}
+
+#[derive(Default)]
+pub struct UiStateMoneyInputDialog {
+    active: Option<ActiveMoneyInputDialog>,
+}
+
+pub fn ui_money_input_dialog_system(
+    mut commands: Commands,
+    mut ui_state: Local<UiStateMoneyInputDialog>,
+    mut egui_context: EguiContexts,
+    mut money_input_dialog_events: ResMut<Events<MoneyInputDialogEvent>>,
+) {
+    for event in money_input_dialog_events.drain() {
+        let MoneyInputDialogEvent::Show {
+            title,
+            balance,
+            modal,
+            ok,
+            cancel,
+        } = event;
+
+        // Cancel any currently open dialog
+        if let Some(mut active) = ui_state.active.take() {
+            if let Some(cancel) = active.cancel.take() {
+                cancel(&mut commands);
+            }
+        }
+
+        ui_state.active = Some(ActiveMoneyInputDialog {
+            title,
+            text: String::with_capacity(32),
+            balance,
+            modal,
+            request_focus: true,
+            ok,
+            cancel,
+        });
+    }
+
+    let Some(active_dialog) = ui_state.active.as_mut() else {
+        return;
+    };
+
+    if active_dialog.modal {
+        egui::Area::new("modal_money_input")
+            .interactable(true)
+            .fixed_pos(egui::Pos2::ZERO)
+            .show(egui_context.ctx_mut(), |ui| {
+                let interceptor_rect = ui.ctx().input(|input| input.screen_rect());
+
+                ui.allocate_response(interceptor_rect.size(), egui::Sense::click_and_drag());
+                ui.painter().rect_filled(
+                    interceptor_rect,
+                    0.0,
+                    egui::Color32::from_rgba_unmultiplied(0, 0, 0, 144),
+                );
+            });
+    }
+
+    let money = validate_money(&active_dialog.text, Some(active_dialog.balance));
+    let mut ok_clicked = false;
+    let mut cancel_clicked = false;
+
+    egui::Window::new(active_dialog.title.as_str())
+        .id(egui::Id::new("money_input_dialog"))
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .order(egui::Order::Foreground)
+        .collapsible(false)
+        .resizable(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            let response =
+                ui.add(MoneyInput::new(&mut active_dialog.text).balance(active_dialog.balance));
+            if active_dialog.request_focus {
+                response.request_focus();
+                active_dialog.request_focus = false;
+            }
+
+            let enter_pressed =
+                response.lost_focus() && ui.input(|input| input.key_pressed(egui::Key::Enter));
+
+            ui.horizontal(|ui| {
+                ok_clicked = ui
+                    .add_enabled(
+                        money.is_ok(),
+                        egui::Button::new(localize!("money-input-ok")),
+                    )
+                    .clicked()
+                    || (enter_pressed && money.is_ok());
+
+                if ui.button(localize!("money-input-max")).clicked() {
+                    active_dialog.text = active_dialog.balance.0.to_string();
+                }
+
+                cancel_clicked = ui.button(localize!("money-input-cancel")).clicked();
+            });
+        });
+
+    if ok_clicked {
+        let active = ui_state.active.take().unwrap();
+
+        match money {
+            Ok(money) if money.0 > 0 => {
+                if let Some(ok) = active.ok {
+                    ok(&mut commands, money);
+                }
+            }
+            _ => {
+                if let Some(cancel) = active.cancel {
+                    cancel(&mut commands);
+                }
+            }
+        }
+    } else if cancel_clicked {
+        let active = ui_state.active.take().unwrap();
+
+        if let Some(cancel) = active.cancel {
+            cancel(&mut commands);
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use bevy::{
-    ecs::query::WorldQuery,
+    ecs::{query::WorldQuery, system::SystemParam},
     math::Vec3Swizzles,
     prelude::{
         Assets, Entity, EventReader, EventWriter, Events, Local, Query, Res, ResMut, With, World,
@@ -17,8 +17,9 @@ use rose_game_common::{
 };
 
 use crate::{
-    components::{PlayerCharacter, Position},
+    components::{ItemLocks, PlayerCharacter, Position},
     events::{MessageBoxEvent, NpcStoreEvent, NumberInputDialogEvent},
+    localize,
     resources::{
         ClientEntityList, GameConnection, GameData, UiResources, UiSpriteSheetType, WorldRates,
     },
@@ -27,7 +28,7 @@ use crate::{
         ui_add_item_tooltip,
         ui_drag_and_drop_system::UiStateDragAndDrop,
         widgets::{DataBindings, Dialog, DrawText},
-        DragAndDropId, DragAndDropSlot, UiSoundEvent,
+        DragAndDropId, DragAndDropSlot, UiSoundEvent, UiStateWindows,
     },
 };
 
@@ -314,6 +315,7 @@ fn ui_add_sell_item_slot(
     game_data: &GameData,
     ui_resources: &UiResources,
     world_rates: Option<&Res<WorldRates>>,
+    message_box_events: &mut EventWriter<MessageBoxEvent>,
 ) -> i64 {
     let pending_sell_item = &mut sell_list[sell_slot_index];
     let item = player.and_then(|player| {
@@ -321,6 +323,11 @@ fn ui_add_sell_item_slot(
             .as_ref()
             .and_then(|pending_sell_item| player.inventory.get_item(pending_sell_item.item_slot))
     });
+    let locked = player
+        .zip(pending_sell_item.as_ref())
+        .map_or(false, |(player, pending_sell_item)| {
+            is_item_locked(player, pending_sell_item.item_slot)
+        });
 
     let item_price = if let Some(item) = item {
         game_data
@@ -355,7 +362,8 @@ fn ui_add_sell_item_slot(
                         &mut ui_state_dnd.dragged_item,
                         &mut dropped_item,
                         [40.0, 40.0],
-                    ),
+                    )
+                    .with_locked(locked),
                     ui,
                 )
             },
@@ -375,19 +383,39 @@ fn ui_add_sell_item_slot(
     }
 
     if let Some(DragAndDropId::Inventory(item_slot)) = dropped_item {
-        *pending_sell_item = Some(PendingSellItem {
-            item_slot,
-            quantity: 1,
-        });
+        if player.map_or(false, |player| is_item_locked(player, item_slot)) {
+            message_box_events.send(MessageBoxEvent::Show {
+                message: localize!("npc-store-sell-locked-item"),
+                modal: false,
+                ok: Some(Box::new(|_| {})),
+                cancel: None,
+            });
+        } else {
+            *pending_sell_item = Some(PendingSellItem {
+                item_slot,
+                quantity: 1,
+            });
+        }
     }
 
     item_price
 }
 
+fn is_item_locked(player: &NpcStorePlayerWorldQueryItem, item_slot: ItemSlot) -> bool {
+    player
+        .inventory
+        .get_item(item_slot)
+        .zip(player.item_locks)
+        .map_or(false, |(item, item_locks)| {
+            item_locks.is_locked(item_slot, item)
+        })
+}
+
 #[derive(WorldQuery)]
 pub struct NpcStorePlayerWorldQuery<'w> {
     ability_values: &'w AbilityValues,
     inventory: &'w Inventory,
+    item_locks: Option<&'w ItemLocks>,
     position: &'w Position,
     player_character: &'w PlayerCharacter,
 }
@@ -398,10 +426,20 @@ pub struct NpcStoreNpcWorldQuery<'w> {
     position: &'w Position,
 }
 
+#[derive(SystemParam)]
+pub struct NpcStoreDialogEvents<'w, 's> {
+    number_input_dialog_events: EventWriter<'w, NumberInputDialogEvent>,
+    message_box_events: EventWriter<'w, MessageBoxEvent>,
+
+    #[system_param(ignore)]
+    phantom: std::marker::PhantomData<&'s ()>,
+}
+
 pub fn ui_npc_store_system(
     mut egui_context: EguiContexts,
     mut ui_state: Local<UiNpcStoreState>,
     mut ui_state_dnd: ResMut<UiStateDragAndDrop>,
+    mut ui_state_windows: ResMut<UiStateWindows>,
     mut ui_sound_events: EventWriter<UiSoundEvent>,
     mut npc_store_events: EventReader<NpcStoreEvent>,
     query_player: Query<NpcStorePlayerWorldQuery>,
@@ -413,8 +451,7 @@ pub fn ui_npc_store_system(
     dialog_assets: Res<Assets<Dialog>>,
     ui_resources: Res<UiResources>,
     world_rates: Option<Res<WorldRates>>,
-    mut number_input_dialog_events: EventWriter<NumberInputDialogEvent>,
-    mut message_box_events: EventWriter<MessageBoxEvent>,
+    mut dialog_events: NpcStoreDialogEvents,
 ) {
     let ui_state = &mut *ui_state;
     let store_dialog = if let Some(dialog) = dialog_assets.get(&ui_resources.dialog_npc_store) {
@@ -447,6 +484,7 @@ pub fn ui_npc_store_system(
                             }
 
                             ui_state.owner_entity = Some((owner_entity, client_entity_id));
+                            ui_state_windows.npc_store_open = true;
                         }
                     }
                 }
@@ -480,6 +518,11 @@ pub fn ui_npc_store_system(
         }
     }
 
+    // The store can be closed by escape from the window manager
+    if !ui_state_windows.npc_store_open {
+        ui_state.owner_entity = None;
+    }
+
     let player = query_player.get_single().ok();
     let player_tooltip_data = query_player_tooltip.get_single().ok();
     let npc = ui_state
@@ -490,12 +533,14 @@ pub fn ui_npc_store_system(
     if let (Some(player), Some(npc)) = (player.as_ref(), npc.as_ref()) {
         if player.position.position.xy().distance(npc.position.xy()) > 600.0 {
             ui_state.owner_entity = None;
+            ui_state_windows.npc_store_open = false;
             return;
         }
     }
 
     let npc_data = npc.and_then(|npc| game_data.npcs.get_npc(npc.npc.id));
     if npc_data.is_none() {
+        ui_state_windows.npc_store_open = false;
         return;
     }
     let npc_data = npc_data.unwrap();
@@ -581,7 +626,7 @@ pub fn ui_npc_store_system(
                                     &game_data,
                                     &ui_resources,
                                     world_rates.as_ref(),
-                                    &mut number_input_dialog_events,
+                                    &mut dialog_events.number_input_dialog_events,
                                 );
                             }
                         }
@@ -650,6 +695,7 @@ pub fn ui_npc_store_system(
                             &game_data,
                             &ui_resources,
                             world_rates.as_ref(),
+                            &mut dialog_events.message_box_events,
                         );
                     }
                     ui.add_label_at(egui::pos2(39.0, 272.0), format!("{}", sell_item_value));
@@ -676,6 +722,13 @@ pub fn ui_npc_store_system(
             }
 
             for pending_sell_item in ui_state.sell_list.iter_mut().filter_map(|x| x.take()) {
+                // The item may have been locked after it was added to the sell list
+                if player.as_ref().map_or(false, |player| {
+                    is_item_locked(player, pending_sell_item.item_slot)
+                }) {
+                    continue;
+                }
+
                 sell_items.push((pending_sell_item.item_slot, pending_sell_item.quantity));
             }
 
@@ -690,12 +743,14 @@ pub fn ui_npc_store_system(
                     .ok();
             }
         } else {
-            message_box_events.send(MessageBoxEvent::Show {
-                message: "You do not have enough Zuly for this transaction.".to_string(),
-                modal: true,
-                ok: Some(Box::new(|_| {})),
-                cancel: None,
-            });
+            dialog_events
+                .message_box_events
+                .send(MessageBoxEvent::Show {
+                    message: localize!("npc-store-not-enough-money"),
+                    modal: true,
+                    ok: Some(Box::new(|_| {})),
+                    cancel: None,
+                });
         }
     }
 
@@ -703,5 +758,6 @@ pub fn ui_npc_store_system(
         || response_cancel.map_or(false, |x| x.clicked())
     {
         ui_state.owner_entity = None;
+        ui_state_windows.npc_store_open = false;
     }
 }
@@ -1,21 +1,27 @@
 use bevy::{
-    ecs::query::WorldQuery,
-    prelude::{Assets, Entity, EventReader, EventWriter, Local, Query, Res, ResMut, With},
+    ecs::{query::WorldQuery, system::SystemParam},
+    prelude::{Assets, Entity, EventReader, EventWriter, Local, Query, Res, ResMut, Time, With},
 };
 use bevy_egui::{egui, EguiContexts};
 
 use rose_game_common::{
-    components::{AbilityValues, CharacterInfo, HealthPoints, Level},
+    components::{AbilityValues, CharacterInfo, HealthPoints, Level, StatusEffects},
     messages::{
         client::ClientMessage, server::PartyMemberInfo, ClientEntityId, PartyRejectInviteReason,
     },
 };
 
 use crate::{
-    components::{ClientEntity, ClientEntityName, PartyInfo, PartyOwner, PlayerCharacter},
-    events::PartyEvent,
-    resources::{ClientEntityList, GameConnection, SelectedTarget, UiResources},
+    components::{
+        ClientEntity, ClientEntityName, PartyInfo, PartyOwner, PlayerCharacter, StatusEffectSources,
+    },
+    events::{PartyEvent, PlayerCommandEvent},
+    resources::{
+        ClientEntityList, DefendTarget, GameConnection, GameData, HudElement, HudLayout,
+        SelectedTarget, UiResources,
+    },
     ui::{
+        ui_add_status_effect_icon,
         widgets::{Dialog, Gauge},
         UiSoundEvent,
     },
@@ -33,6 +39,8 @@ const IID_BTN_OPTION: i32 = 14;
 const IID_PARTY_XP_GAUGE: i32 = 1001;
 const IID_PARTY_MEMBER_HP_GAUGE: i32 = 1002;
 
+const PARTY_MEMBER_STATUS_EFFECT_ICON_SCALE: f32 = 0.7;
+
 #[derive(WorldQuery)]
 pub struct PlayerQuery<'w> {
     _player_character: With<PlayerCharacter>,
@@ -50,6 +58,15 @@ pub struct PartyMemberQuery<'w> {
     ability_values: &'w AbilityValues,
     health_points: &'w HealthPoints,
     level: &'w Level,
+    status_effects: &'w StatusEffects,
+    status_effect_sources: Option<&'w StatusEffectSources>,
+}
+
+#[derive(SystemParam)]
+pub struct PartyMembers<'w, 's> {
+    query: Query<'w, 's, PartyMemberQuery<'static>>,
+    game_data: Res<'w, GameData>,
+    time: Res<'w, Time>,
 }
 
 pub struct PendingPartyInvite {
@@ -94,13 +111,37 @@ impl Default for UiStatePartySystem {
     }
 }
 
+fn add_party_member_status_effects(
+    ui: &mut egui::Ui,
+    party_members: &PartyMembers,
+    ui_resources: &UiResources,
+    party_member: &PartyMemberQueryItem,
+) {
+    let now = party_members.time.last_update().unwrap();
+
+    for (status_effect_type, active_status_effect) in party_member.status_effects.active.iter() {
+        if active_status_effect.is_some() {
+            ui_add_status_effect_icon(
+                ui,
+                &party_members.game_data,
+                ui_resources,
+                party_member.status_effects,
+                party_member.status_effect_sources,
+                status_effect_type,
+                now,
+                PARTY_MEMBER_STATUS_EFFECT_ICON_SCALE,
+            );
+        }
+    }
+}
+
 pub fn ui_party_system(
     mut ui_state: Local<UiStatePartySystem>,
     mut ui_state_windows: ResMut<UiStateWindows>,
     mut ui_sound_events: EventWriter<UiSoundEvent>,
     mut egui_context: EguiContexts,
     query_player: Query<PlayerQuery>,
-    query_party_member: Query<PartyMemberQuery>,
+    party_members: PartyMembers,
     query_invite: Query<(&ClientEntity, &ClientEntityName)>,
     mut party_events: EventReader<PartyEvent>,
     game_connection: Option<Res<GameConnection>>,
@@ -108,6 +149,9 @@ pub fn ui_party_system(
     ui_resources: Res<UiResources>,
     dialog_assets: Res<Assets<Dialog>>,
     mut selected_target: ResMut<SelectedTarget>,
+    defend_target: Res<DefendTarget>,
+    mut player_command_events: EventWriter<PlayerCommandEvent>,
+    mut hud_layout: ResMut<HudLayout>,
 ) {
     let player = if let Ok(player) = query_player.get_single() {
         player
@@ -246,12 +290,19 @@ pub fn ui_party_system(
     let mut response_option_button = None;
 
     ui_state_windows.party_open = player.party_info.is_some();
+    hud_layout.rects[HudElement::Party] = None;
 
-    if let Some(party_info) = player.party_info {
+    if let Some(party_info) = player
+        .party_info
+        .filter(|_| hud_layout.is_visible(HudElement::Party))
+    {
         let player_is_owner = matches!(party_info.owner, PartyOwner::Player);
 
-        egui::Window::new("Party2")
-            .anchor(egui::Align2::RIGHT_CENTER, [0.0, 0.0])
+        let response = egui::Window::new("Party2")
+            .anchor(
+                egui::Align2::RIGHT_CENTER,
+                hud_layout.get_offset(HudElement::Party),
+            )
             .frame(egui::Frame::none())
             .title_bar(false)
             .resizable(false)
@@ -300,7 +351,7 @@ pub fn ui_party_system(
                                             if let Some(party_member) = client_entity_list
                                                 .get(member_info.entity_id)
                                                 .and_then(|entity| {
-                                                    query_party_member.get(entity).ok()
+                                                    party_members.query.get(entity).ok()
                                                 })
                                             {
                                                 let hp_percent = party_member.health_points.hp
@@ -322,6 +373,24 @@ pub fn ui_party_system(
                                                         ..Default::default()
                                                     },
                                                 );
+
+                                                ui.allocate_ui_at_rect(
+                                                    egui::Rect::from_min_size(
+                                                        rect.min + egui::vec2(3.0, 3.0),
+                                                        egui::vec2(214.0, 20.0),
+                                                    ),
+                                                    |ui| {
+                                                        ui.spacing_mut().item_spacing.x = 1.0;
+                                                        ui.horizontal(|ui| {
+                                                            add_party_member_status_effects(
+                                                                ui,
+                                                                &party_members,
+                                                                &ui_resources,
+                                                                &party_member,
+                                                            );
+                                                        });
+                                                    },
+                                                );
                                             }
 
                                             (true, &member_info.name)
@@ -347,21 +416,47 @@ pub fn ui_party_system(
                                     );
                                 }
 
+                                let member_entity = member
+                                    .get_client_entity_id()
+                                    .and_then(|entity_id| client_entity_list.get(entity_id));
+
                                 if response.clicked() {
-                                    if let Some(entity) = member
-                                        .get_client_entity_id()
-                                        .and_then(|entity_id| client_entity_list.get(entity_id))
-                                    {
+                                    if let Some(entity) = member_entity {
                                         selected_target.selected = Some(entity);
                                     }
 
                                     ui_state.selected_party_member_index = Some(index);
                                 }
+
+                                if let Some(member_entity) = member_entity {
+                                    response.context_menu(|ui| {
+                                        if ui.button("Assist").clicked() {
+                                            player_command_events.send(PlayerCommandEvent::Assist(
+                                                Some(member_entity),
+                                            ));
+                                            ui.close_menu();
+                                        }
+
+                                        if defend_target.entity == Some(member_entity) {
+                                            if ui.button("Stop Defending").clicked() {
+                                                player_command_events
+                                                    .send(PlayerCommandEvent::StopDefend);
+                                                ui.close_menu();
+                                            }
+                                        } else if ui.button("Defend").clicked() {
+                                            player_command_events.send(PlayerCommandEvent::Defend(
+                                                Some(member_entity),
+                                            ));
+                                            ui.close_menu();
+                                        }
+                                    });
+                                }
                             }
                         });
                     },
                 );
             });
+        hud_layout.rects[HudElement::Party] = response.map(|response| response.response.rect);
 
         if player_is_owner {
             if let Some(selected_party_member) = ui_state
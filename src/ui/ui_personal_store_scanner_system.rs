@@ -0,0 +1,286 @@
+use std::{collections::HashMap, time::Duration};
+
+use bevy::{
+    math::Vec3Swizzles,
+    prelude::{Entity, EventReader, EventWriter, Local, Query, Res, ResMut, With},
+    time::Time,
+};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_data::{Item, ItemType};
+use rose_game_common::{
+    components::{CharacterInfo, Money},
+    messages::client::ClientMessage,
+};
+
+use crate::{
+    components::{ClientEntity, PersonalStore, PlayerCharacter, Position},
+    events::{PersonalStoreEvent, PlayerCommandEvent},
+    resources::{GameConnection, GameData, PersonalStoreListRequests},
+    ui::{format_money, get_item_name_color, parse_money, MoneyInput, UiStateWindows},
+};
+
+const SCAN_REQUEST_INTERVAL: Duration = Duration::from_millis(250);
+
+const FILTER_ITEM_TYPES: [ItemType; 14] = [
+    ItemType::Face,
+    ItemType::Head,
+    ItemType::Body,
+    ItemType::Hands,
+    ItemType::Feet,
+    ItemType::Back,
+    ItemType::Weapon,
+    ItemType::SubWeapon,
+    ItemType::Jewellery,
+    ItemType::Consumable,
+    ItemType::Gem,
+    ItemType::Material,
+    ItemType::Quest,
+    ItemType::Vehicle,
+];
+
+#[derive(Default)]
+pub struct UiStatePersonalStoreScanner {
+    store_items: HashMap<Entity, Vec<Option<(Item, Money)>>>,
+    last_request_time: Option<Duration>,
+    filter_name: String,
+    filter_item_type: Option<ItemType>,
+    filter_max_price: String,
+}
+
+pub fn ui_personal_store_scanner_system(
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiStatePersonalStoreScanner>,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    mut personal_store_events: EventReader<PersonalStoreEvent>,
+    mut player_command_events: EventWriter<PlayerCommandEvent>,
+    mut personal_store_list_requests: ResMut<PersonalStoreListRequests>,
+    query_personal_store: Query<(Entity, &ClientEntity, &PersonalStore, &Position)>,
+    query_character_info: Query<&CharacterInfo>,
+    query_player: Query<&Position, With<PlayerCharacter>>,
+    game_connection: Option<Res<GameConnection>>,
+    game_data: Res<GameData>,
+    time: Res<Time>,
+) {
+    let ui_state = &mut *ui_state;
+
+    // Item lists are cached for every store, including those opened directly
+    for event in personal_store_events.iter() {
+        match event {
+            PersonalStoreEvent::SetItemList {
+                entity, sell_items, ..
+            } => {
+                let mut store_items = vec![None; 30];
+                for (slot_index, item, price) in sell_items.iter() {
+                    if let Some(store_slot) = store_items.get_mut(*slot_index as usize) {
+                        *store_slot = Some((item.clone(), *price));
+                    }
+                }
+                ui_state.store_items.insert(*entity, store_items);
+            }
+            PersonalStoreEvent::UpdateSellList { entity, item_list } => {
+                if let Some(store_items) = ui_state.store_items.get_mut(entity) {
+                    for (slot_index, item) in item_list.iter() {
+                        if let Some(store_slot) = store_items.get_mut(*slot_index) {
+                            if let Some(item) = item {
+                                if let Some((store_slot_item, _)) = store_slot.as_mut() {
+                                    *store_slot_item = item.clone();
+                                }
+                            } else {
+                                *store_slot = None;
+                            }
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Forget stores which have closed or are no longer visible
+    ui_state
+        .store_items
+        .retain(|entity, _| query_personal_store.contains(*entity));
+
+    if !ui_state_windows.personal_store_scanner_open {
+        return;
+    }
+
+    let Ok(player_position) = query_player.get_single() else {
+        return;
+    };
+
+    // Lazily request the item list of the nearest store which has not been scanned,
+    // one at a time so the replies can be matched to their store
+    let now = time.elapsed();
+    if personal_store_list_requests.pending.is_empty()
+        && ui_state.last_request_time.map_or(true, |last| {
+            now.saturating_sub(last) >= SCAN_REQUEST_INTERVAL
+        })
+    {
+        let nearest_unscanned = query_personal_store
+            .iter()
+            .filter(|(entity, ..)| !ui_state.store_items.contains_key(entity))
+            .min_by(|(_, _, _, a), (_, _, _, b)| {
+                let distance_a = a.position.xy().distance(player_position.position.xy());
+                let distance_b = b.position.xy().distance(player_position.position.xy());
+                distance_a.total_cmp(&distance_b)
+            });
+
+        if let (Some((entity, client_entity, ..)), Some(game_connection)) =
+            (nearest_unscanned, game_connection.as_ref())
+        {
+            personal_store_list_requests.pending.push_back(entity);
+            game_connection
+                .client_message_tx
+                .send(ClientMessage::PersonalStoreListItems {
+                    store_entity_id: client_entity.id,
+                })
+                .ok();
+            ui_state.last_request_time = Some(now);
+        }
+    }
+
+    let filter_name = ui_state.filter_name.to_lowercase();
+    let filter_max_price = parse_money(&ui_state.filter_max_price).ok();
+    let mut listings = Vec::new();
+    for (store_entity, store_items) in ui_state.store_items.iter() {
+        let Ok((_, _, personal_store, _)) = query_personal_store.get(*store_entity) else {
+            continue;
+        };
+
+        for (item, price) in store_items.iter().flatten() {
+            let Some(item_data) = game_data.items.get_base_item(item.get_item_reference()) else {
+                continue;
+            };
+
+            if ui_state
+                .filter_item_type
+                .map_or(false, |item_type| item.get_item_type() != item_type)
+            {
+                continue;
+            }
+
+            if !filter_name.is_empty() && !item_data.name.to_lowercase().contains(&filter_name) {
+                continue;
+            }
+
+            if filter_max_price.map_or(false, |max_price| price.0 > max_price.0) {
+                continue;
+            }
+
+            listings.push((
+                *store_entity,
+                personal_store,
+                item.clone(),
+                item_data,
+                *price,
+            ));
+        }
+    }
+    listings.sort_by_key(|(_, _, _, _, price)| price.0);
+
+    let num_stores = query_personal_store.iter().count();
+    let num_scanned = ui_state.store_items.len();
+    let mut walk_to_store = None;
+    let mut rescan = false;
+
+    egui::Window::new("Nearby Stores")
+        .open(&mut ui_state_windows.personal_store_scanner_open)
+        .resizable(false)
+        .default_width(450.0)
+        .show(egui_context.ctx_mut(), |ui| {
+            ui.horizontal(|ui| {
+                ui.add(
+                    egui::TextEdit::singleline(&mut ui_state.filter_name)
+                        .hint_text("Item name")
+                        .desired_width(150.0),
+                );
+
+                egui::ComboBox::from_id_source("personal_store_scanner_item_type")
+                    .selected_text(
+                        ui_state
+                            .filter_item_type
+                            .map_or("All".to_string(), |item_type| format!("{:?}", item_type)),
+                    )
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(&mut ui_state.filter_item_type, None, "All");
+                        for item_type in FILTER_ITEM_TYPES {
+                            ui.selectable_value(
+                                &mut ui_state.filter_item_type,
+                                Some(item_type),
+                                format!("{:?}", item_type),
+                            );
+                        }
+                    });
+
+                if ui.button("Rescan").clicked() {
+                    rescan = true;
+                }
+            });
+
+            ui.horizontal(|ui| {
+                ui.label("Max price");
+                ui.add(MoneyInput::new(&mut ui_state.filter_max_price));
+            });
+
+            ui.label(format!("Scanned {} of {} stores", num_scanned, num_stores));
+            ui.separator();
+
+            if listings.is_empty() {
+                ui.label("No items found.");
+                return;
+            }
+
+            egui::ScrollArea::vertical()
+                .max_height(350.0)
+                .show(ui, |ui| {
+                    egui::Grid::new("personal_store_scanner_grid")
+                        .num_columns(5)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            ui.label("Item");
+                            ui.label("Qty");
+                            ui.label("Price");
+                            ui.label("Store");
+                            ui.label("");
+                            ui.end_row();
+
+                            for (store_entity, personal_store, item, item_data, price) in
+                                listings.iter()
+                            {
+                                ui.colored_label(
+                                    get_item_name_color(item.get_item_type(), item_data),
+                                    item_data.name,
+                                );
+                                ui.label(format!("{}", item.get_quantity()));
+                                ui.label(format_money(*price));
+
+                                let owner_name = query_character_info
+                                    .get(*store_entity)
+                                    .map_or("???", |character_info| &character_info.name);
+                                ui.label(format!("{} ({})", personal_store.title, owner_name));
+
+                                if ui.button("Go").clicked() {
+                                    walk_to_store = Some(*store_entity);
+                                }
+                                ui.end_row();
+                            }
+                        });
+                });
+        });
+
+    if rescan {
+        ui_state.store_items.clear();
+    }
+
+    if let Some(store_entity) = walk_to_store {
+        if let Ok((_, _, _, store_position)) = query_personal_store.get(store_entity) {
+            // Moving to the store character will open the store on arrival
+            player_command_events.send(PlayerCommandEvent::Move(
+                store_position.clone(),
+                Some(store_entity),
+            ));
+        }
+    }
+}
@@ -11,12 +11,13 @@ use rose_game_common::{components::Money, messages::client::ClientMessage};
 use crate::{
     components::{ClientEntity, PersonalStore, PlayerCharacter, Position},
     events::{MessageBoxEvent, PersonalStoreEvent},
-    resources::{GameConnection, GameData, UiResources},
+    resources::{GameConnection, GameData, PersonalStoreListRequests, UiResources},
     ui::{
+        format_money,
         tooltips::{PlayerTooltipQuery, PlayerTooltipQueryItem},
         ui_add_item_tooltip,
         widgets::{DataBindings, Dialog},
-        DragAndDropId, DragAndDropSlot, UiSoundEvent, UiStateDragAndDrop,
+        DragAndDropId, DragAndDropSlot, UiSoundEvent, UiStateDragAndDrop, UiStateWindows,
     },
 };
 
@@ -91,7 +92,7 @@ fn ui_add_store_item_slot(
             message: format!(
                 "Are you sure you want to buy {} for {} Zuly?",
                 item_data.as_ref().map(|x| x.name).unwrap_or(""),
-                price.0
+                format_money(*price)
             ),
             modal: false,
             ok: Some(Box::new(move |commands| {
@@ -112,9 +113,15 @@ fn ui_add_store_item_slot(
         ui_add_item_tooltip(ui, game_data, player_tooltip_data, item);
 
         if is_sell_item {
-            ui.colored_label(egui::Color32::YELLOW, format!("Price: {}", price.0));
+            ui.colored_label(
+                egui::Color32::YELLOW,
+                format!("Price: {}", format_money(*price)),
+            );
         } else {
-            ui.colored_label(egui::Color32::GREEN, format!("Price: {}", price.0));
+            ui.colored_label(
+                egui::Color32::GREEN,
+                format!("Price: {}", format_money(*price)),
+            );
         }
     });
 }
@@ -123,6 +130,7 @@ pub fn ui_personal_store_system(
     mut egui_context: EguiContexts,
     mut ui_state: Local<UiPersonalStoreState>,
     mut ui_state_dnd: ResMut<UiStateDragAndDrop>,
+    mut ui_state_windows: ResMut<UiStateWindows>,
     mut ui_sound_events: EventWriter<UiSoundEvent>,
     mut personal_store_events: EventReader<PersonalStoreEvent>,
     query_personal_store: Query<(&ClientEntity, &PersonalStore, &Position), With<PersonalStore>>,
@@ -133,6 +141,7 @@ pub fn ui_personal_store_system(
     game_connection: Option<Res<GameConnection>>,
     game_data: Res<GameData>,
     mut message_box_events: EventWriter<MessageBoxEvent>,
+    mut personal_store_list_requests: ResMut<PersonalStoreListRequests>,
 ) {
     let ui_state = &mut *ui_state;
 
@@ -145,6 +154,7 @@ pub fn ui_personal_store_system(
                 // Open new store and request item list
                 if let Ok((client_entity, _, _)) = query_personal_store.get(entity) {
                     if let Some(game_connection) = game_connection.as_ref() {
+                        personal_store_list_requests.pending.push_back(entity);
                         game_connection
                             .client_message_tx
                             .send(ClientMessage::PersonalStoreListItems {
@@ -154,12 +164,18 @@ pub fn ui_personal_store_system(
                     }
 
                     ui_state.store_owner = Some(entity);
+                    ui_state_windows.personal_store_open = true;
                 }
             }
             PersonalStoreEvent::SetItemList {
+                entity,
                 sell_items,
                 buy_items,
             } => {
+                if ui_state.store_owner != Some(*entity) {
+                    continue;
+                }
+
                 ui_state.store_buy_items.fill(None);
                 ui_state.store_sell_items.fill(None);
 
@@ -224,6 +240,11 @@ pub fn ui_personal_store_system(
         }
     }
 
+    // The store can be closed by escape from the window manager
+    if !ui_state_windows.personal_store_open {
+        *ui_state = Default::default();
+    }
+
     let personal_store_entity = if let Some(entity) = ui_state.store_owner {
         entity
     } else {
@@ -235,6 +256,7 @@ pub fn ui_personal_store_system(
             personal_store
         } else {
             *ui_state = Default::default();
+            ui_state_windows.personal_store_open = false;
             return;
         };
 
@@ -247,6 +269,7 @@ pub fn ui_personal_store_system(
             > 1100.0
         {
             *ui_state = Default::default();
+            ui_state_windows.personal_store_open = false;
             return;
         }
     }
@@ -347,5 +370,6 @@ pub fn ui_personal_store_system(
 
     if response_close_button.map_or(false, |x| x.clicked()) {
         *ui_state = Default::default();
+        ui_state_windows.personal_store_open = false;
     }
 }
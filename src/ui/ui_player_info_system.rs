@@ -5,12 +5,16 @@ use bevy::{
 use bevy_egui::{egui, EguiContexts};
 use rose_data::{AmmoIndex, EquipmentIndex, Item, ItemClass};
 use rose_game_common::components::{
-    AbilityValues, CharacterInfo, Equipment, ExperiencePoints, HealthPoints, Level, ManaPoints,
+    AbilityValues, CharacterInfo, Equipment, ExperiencePoints, HealthPoints, Inventory,
+    InventoryPageType, ItemSlot, Level, ManaPoints, INVENTORY_PAGE_SIZE,
 };
 
 use crate::{
     components::PlayerCharacter,
+    events::PlayerCommandEvent,
+    localize,
     resources::{GameData, SelectedTarget, UiResources},
+    systems::{get_weapon_ammo_index, LOW_AMMO_THRESHOLD},
     ui::{
         tooltips::{PlayerTooltipQuery, PlayerTooltipQueryItem},
         ui_add_item_tooltip,
@@ -38,6 +42,44 @@ pub struct PlayerQuery<'w> {
     mana_points: &'w ManaPoints,
     experience_points: &'w ExperiencePoints,
     equipment: &'w Equipment,
+    inventory: &'w Inventory,
+}
+
+fn find_restock_ammo_slot(
+    player: &PlayerQueryItem,
+    game_data: &GameData,
+    ammo_index: AmmoIndex,
+) -> Option<ItemSlot> {
+    let ammo_class = match ammo_index {
+        AmmoIndex::Arrow => ItemClass::Arrow,
+        AmmoIndex::Bullet => ItemClass::Bullet,
+        AmmoIndex::Throw => ItemClass::Shell,
+    };
+    let equipped_ammo = player.equipment.get_ammo_item(ammo_index);
+    let mut restock_slot = None;
+
+    for index in 0..INVENTORY_PAGE_SIZE {
+        let item_slot = ItemSlot::Inventory(InventoryPageType::Materials, index);
+        let Some(item) = player.inventory.get_item(item_slot) else {
+            continue;
+        };
+
+        if equipped_ammo.map_or(false, |ammo| ammo.item == item.get_item_reference()) {
+            // Prefer to restock with the same type of ammo
+            return Some(item_slot);
+        }
+
+        if restock_slot.is_none()
+            && game_data
+                .items
+                .get_base_item(item.get_item_reference())
+                .map_or(false, |item_data| item_data.class == ammo_class)
+        {
+            restock_slot = Some(item_slot);
+        }
+    }
+
+    restock_slot
 }
 
 fn add_equipped_weapon_slot(
@@ -113,6 +155,7 @@ pub fn ui_player_info_system(
     ui_resources: Res<UiResources>,
     dialog_assets: Res<Assets<Dialog>>,
     mut selected_target: ResMut<SelectedTarget>,
+    mut player_command_events: EventWriter<PlayerCommandEvent>,
 ) {
     let dialog = if let Some(dialog) = dialog_assets.get(&ui_resources.dialog_player_info) {
         dialog
@@ -214,4 +257,60 @@ pub fn ui_player_info_system(
     if response_menu_button.map_or(false, |r| r.clicked()) {
         ui_state_windows.menu_open = !ui_state_windows.menu_open;
     }
+
+    // Warn when low on ammo and offer to restock from the inventory
+    if let Some(ammo_index) = get_weapon_ammo_index(player.equipment, &game_data) {
+        let ammo_quantity = player
+            .equipment
+            .get_ammo_item(ammo_index)
+            .map_or(0, |ammo| ammo.quantity);
+
+        if ammo_quantity <= LOW_AMMO_THRESHOLD {
+            let restock_slot = find_restock_ammo_slot(&player, &game_data, ammo_index);
+
+            egui::Area::new("player_info_ammo_warning")
+                .anchor(egui::Align2::LEFT_TOP, [10.0, dialog.height + 5.0])
+                .show(egui_context.ctx_mut(), |ui| {
+                    ui.horizontal(|ui| {
+                        if ammo_quantity == 0 {
+                            ui.colored_label(
+                                egui::Color32::RED,
+                                localize!("player-info-out-of-ammo"),
+                            );
+                        } else {
+                            ui.colored_label(
+                                egui::Color32::from_rgb(255, 166, 107),
+                                localize!("player-info-low-ammo", quantity = ammo_quantity),
+                            );
+                        }
+
+                        if let Some(restock_slot) = restock_slot {
+                            let restock_item = player.inventory.get_item(restock_slot);
+                            let restock_name = restock_item
+                                .and_then(|item| {
+                                    game_data.items.get_base_item(item.get_item_reference())
+                                })
+                                .map_or_else(
+                                    || localize!("player-info-ammo"),
+                                    |item_data| item_data.name.to_string(),
+                                );
+                            let restock_quantity =
+                                restock_item.map_or(0, |item| item.get_quantity());
+
+                            if ui
+                                .button(localize!(
+                                    "player-info-equip-ammo",
+                                    name = restock_name,
+                                    quantity = restock_quantity,
+                                ))
+                                .clicked()
+                            {
+                                player_command_events
+                                    .send(PlayerCommandEvent::EquipAmmo(restock_slot));
+                            }
+                        }
+                    });
+                });
+        }
+    }
 }
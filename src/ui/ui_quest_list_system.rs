@@ -1,17 +1,20 @@
 use bevy::prelude::{Assets, EventWriter, Local, Query, Res, ResMut, With};
 use bevy_egui::{egui, EguiContexts};
 
-use rose_data::Item;
-use rose_game_common::components::QuestState;
+use rose_data::{Item, ItemType};
+use rose_game_common::components::{
+    Inventory, InventoryPageType, ItemSlot, QuestState, INVENTORY_PAGE_SIZE,
+};
 
 use crate::{
     components::PlayerCharacter,
+    events::PlayerCommandEvent,
     resources::{GameData, UiResources},
     ui::{
         tooltips::{PlayerTooltipQuery, PlayerTooltipQueryItem},
         ui_add_item_tooltip,
         widgets::{DataBindings, Dialog, DrawText, Widget},
-        DragAndDropId, DragAndDropSlot, UiSoundEvent, UiStateWindows,
+        DragAndDropId, DragAndDropSlot, UiSoundEvent, UiStateDragAndDrop, UiStateWindows,
     },
 };
 
@@ -29,15 +32,37 @@ const IID_LIST_QUESTINFO: i32 = 30;
 // const IID_PANE_QUESTLIST: i32 = 100;
 const IID_PANE_QUESTINFO: i32 = 200;
 
+fn find_quest_item_inventory_slot(inventory: &Inventory, item: &Item) -> Option<ItemSlot> {
+    if item.get_item_type() != ItemType::Consumable {
+        return None;
+    }
+
+    (0..INVENTORY_PAGE_SIZE)
+        .map(|index| ItemSlot::Inventory(InventoryPageType::Consumables, index))
+        .find(|item_slot| {
+            inventory
+                .get_item(*item_slot)
+                .map_or(false, |inventory_item| {
+                    inventory_item.get_item_reference() == item.get_item_reference()
+                })
+        })
+}
+
 fn ui_add_quest_item_slot(
     ui: &mut egui::Ui,
     pos: egui::Pos2,
     player_tooltip_data: Option<&PlayerTooltipQueryItem>,
     item: Option<&Item>,
+    inventory: &Inventory,
     game_data: &GameData,
     ui_resources: &UiResources,
+    ui_state_dnd: &mut UiStateDragAndDrop,
+    player_command_events: &mut EventWriter<PlayerCommandEvent>,
 ) {
-    let mut dragged_item = None;
+    // Usable quest items are used from the matching inventory stack, so they
+    // can be dragged to the hotbar the same as an inventory item
+    let use_item_slot = item.and_then(|item| find_quest_item_inventory_slot(inventory, item));
+
     let mut dropped_item = None;
     let response = ui
         .allocate_ui_at_rect(
@@ -45,13 +70,13 @@ fn ui_add_quest_item_slot(
             |ui| {
                 egui::Widget::ui(
                     DragAndDropSlot::with_item(
-                        DragAndDropId::NotDraggable,
+                        use_item_slot.map_or(DragAndDropId::NotDraggable, DragAndDropId::Inventory),
                         item,
                         None,
                         game_data,
                         ui_resources,
                         |_| false,
-                        &mut dragged_item,
+                        &mut ui_state_dnd.dragged_item,
                         &mut dropped_item,
                         [40.0, 40.0],
                     ),
@@ -61,6 +86,19 @@ fn ui_add_quest_item_slot(
         )
         .inner;
 
+    if let Some(item_slot) = use_item_slot {
+        let use_clicked = ui
+            .put(
+                egui::Rect::from_min_size(pos + egui::vec2(0.0, 42.0), egui::vec2(40.0, 16.0)),
+                egui::Button::new("Use").small(),
+            )
+            .clicked();
+
+        if use_clicked || response.double_clicked() {
+            player_command_events.send(PlayerCommandEvent::UseItem(item_slot));
+        }
+    }
+
     if let Some(item) = item {
         response.on_hover_ui(|ui| {
             ui_add_item_tooltip(ui, game_data, player_tooltip_data, item);
@@ -90,8 +128,10 @@ pub fn ui_quest_list_system(
     mut ui_state: Local<UiQuestListState>,
     mut egui_context: EguiContexts,
     mut ui_state_windows: ResMut<UiStateWindows>,
+    mut ui_state_dnd: ResMut<UiStateDragAndDrop>,
     mut ui_sound_events: EventWriter<UiSoundEvent>,
-    query_player: Query<&QuestState, With<PlayerCharacter>>,
+    mut player_command_events: EventWriter<PlayerCommandEvent>,
+    query_player: Query<(&QuestState, &Inventory), With<PlayerCharacter>>,
     query_player_tooltip: Query<PlayerTooltipQuery, With<PlayerCharacter>>,
     game_data: Res<GameData>,
     ui_resources: Res<UiResources>,
@@ -106,7 +146,7 @@ pub fn ui_quest_list_system(
     } else {
         return;
     };
-    let player_quest_state = if let Ok(player) = query_player.get_single() {
+    let (player_quest_state, player_inventory) = if let Ok(player) = query_player.get_single() {
         player
     } else {
         return;
@@ -259,8 +299,11 @@ pub fn ui_quest_list_system(
                                 rect_info.min + QUEST_ITEM_SLOT_POS[i],
                                 player_tooltip_data.as_ref(),
                                 item.as_ref(),
+                                player_inventory,
                                 &game_data,
                                 &ui_resources,
+                                &mut ui_state_dnd,
+                                &mut player_command_events,
                             );
                         }
                     }
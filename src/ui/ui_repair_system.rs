@@ -0,0 +1,333 @@
+use bevy::{
+    ecs::query::WorldQuery,
+    math::Vec3Swizzles,
+    prelude::{Entity, EventReader, Local, Query, Res, ResMut, With},
+};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_data::{EquipmentItem, Item, ItemType};
+use rose_game_common::{
+    components::{Equipment, Inventory, InventoryPageType, ItemSlot, Money},
+    messages::{client::ClientMessage, ClientEntityId},
+};
+
+use crate::{
+    components::{PlayerCharacter, Position},
+    events::RepairEvent,
+    localize,
+    resources::{ClientEntityList, GameConnection, GameData, UiResources},
+    ui::{
+        format_money,
+        tooltips::{PlayerTooltipQuery, PlayerTooltipQueryItem},
+        ui_add_item_tooltip, DragAndDropId, DragAndDropSlot, UiStateDragAndDrop, UiStateWindows,
+    },
+};
+
+const REPAIR_ITEM_MAX_LIFE: u16 = 1000;
+const REPAIR_NPC_MAX_DISTANCE: f32 = 1000.0;
+
+enum RepairSource {
+    Npc {
+        entity: Entity,
+        client_entity_id: ClientEntityId,
+    },
+    Item {
+        use_item_slot: ItemSlot,
+    },
+}
+
+#[derive(Default)]
+pub struct UiStateRepair {
+    source: Option<RepairSource>,
+    pending_item_slot: Option<ItemSlot>,
+    result: Option<String>,
+}
+
+#[derive(WorldQuery)]
+pub struct PlayerQuery<'w> {
+    equipment: &'w Equipment,
+    inventory: &'w Inventory,
+    position: &'w Position,
+}
+
+/// An estimate of the Zuly an NPC charges to repair an item, the final price is calculated by
+/// the server. More expensive and more durable items cost more, scaled by the lost life.
+fn get_repair_price(game_data: &GameData, equipment_item: &EquipmentItem) -> i64 {
+    let base_price = game_data
+        .items
+        .get_base_item(equipment_item.item)
+        .map_or(0, |item_data| item_data.base_price as i64);
+    let lost_life = REPAIR_ITEM_MAX_LIFE.saturating_sub(equipment_item.life) as i64;
+
+    base_price * (equipment_item.durability as i64 + 10) * lost_life / 400000
+}
+
+fn get_damaged_items(player: &PlayerQueryItem) -> Vec<(ItemSlot, EquipmentItem)> {
+    let equipped_items =
+        player
+            .equipment
+            .equipped_items
+            .iter()
+            .filter_map(|(equipment_index, equipment_item)| {
+                equipment_item
+                    .as_ref()
+                    .map(|equipment_item| (ItemSlot::Equipment(equipment_index), equipment_item))
+            });
+    let inventory_items = player
+        .inventory
+        .equipment
+        .slots
+        .iter()
+        .enumerate()
+        .filter_map(|(index, item)| match item {
+            Some(Item::Equipment(equipment_item)) => Some((
+                ItemSlot::Inventory(InventoryPageType::Equipment, index),
+                equipment_item,
+            )),
+            _ => None,
+        });
+
+    equipped_items
+        .chain(inventory_items)
+        .filter(|(_, equipment_item)| equipment_item.life < REPAIR_ITEM_MAX_LIFE)
+        .map(|(item_slot, equipment_item)| (item_slot, equipment_item.clone()))
+        .collect()
+}
+
+fn ui_add_repair_item_slot(
+    ui: &mut egui::Ui,
+    ui_state_dnd: &mut UiStateDragAndDrop,
+    item: &Item,
+    player_tooltip_data: Option<&PlayerTooltipQueryItem>,
+    game_data: &GameData,
+    ui_resources: &UiResources,
+) {
+    let mut dropped_item = None;
+    egui::Widget::ui(
+        DragAndDropSlot::with_item(
+            DragAndDropId::NotDraggable,
+            Some(item),
+            None,
+            game_data,
+            ui_resources,
+            |_| false,
+            &mut ui_state_dnd.dragged_item,
+            &mut dropped_item,
+            [40.0, 40.0],
+        ),
+        ui,
+    )
+    .on_hover_ui(|ui| {
+        ui_add_item_tooltip(ui, game_data, player_tooltip_data, item);
+    });
+}
+
+pub fn ui_repair_system(
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiStateRepair>,
+    mut ui_state_dnd: ResMut<UiStateDragAndDrop>,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    mut repair_events: EventReader<RepairEvent>,
+    client_entity_list: Res<ClientEntityList>,
+    game_connection: Option<Res<GameConnection>>,
+    game_data: Res<GameData>,
+    ui_resources: Res<UiResources>,
+    query_player: Query<PlayerQuery, With<PlayerCharacter>>,
+    query_player_tooltip: Query<PlayerTooltipQuery, With<PlayerCharacter>>,
+    query_position: Query<&Position>,
+) {
+    let ui_state = &mut *ui_state;
+
+    for event in repair_events.iter() {
+        match *event {
+            RepairEvent::OpenRepairFromClientEntity { client_entity_id } => {
+                if let Some(entity) = client_entity_list.get(client_entity_id) {
+                    ui_state.source = Some(RepairSource::Npc {
+                        entity,
+                        client_entity_id,
+                    });
+                    ui_state.pending_item_slot = None;
+                    ui_state.result = None;
+                    ui_state_windows.repair_open = true;
+                }
+            }
+            RepairEvent::OpenRepairUsingItem { use_item_slot } => {
+                ui_state.source = Some(RepairSource::Item { use_item_slot });
+                ui_state.pending_item_slot = None;
+                ui_state.result = None;
+                ui_state_windows.repair_open = true;
+            }
+            RepairEvent::Repaired { item_slot } => {
+                if ui_state.pending_item_slot == Some(item_slot) {
+                    ui_state.pending_item_slot = None;
+                }
+                ui_state.result = Some(localize!("repair-success"));
+            }
+        }
+    }
+
+    if !ui_state_windows.repair_open {
+        ui_state.source = None;
+        return;
+    }
+
+    let (Ok(player), Some(source)) = (query_player.get_single(), ui_state.source.as_ref()) else {
+        ui_state_windows.repair_open = false;
+        return;
+    };
+    let player_tooltip_data = query_player_tooltip.get_single().ok();
+
+    match *source {
+        RepairSource::Npc { entity, .. } => {
+            // If player has moved away from the npc, close the dialog
+            if query_position.get(entity).map_or(true, |npc_position| {
+                player
+                    .position
+                    .position
+                    .xy()
+                    .distance(npc_position.position.xy())
+                    > REPAIR_NPC_MAX_DISTANCE
+            }) {
+                ui_state_windows.repair_open = false;
+                return;
+            }
+        }
+        RepairSource::Item { use_item_slot } => {
+            // The repair item has been used up or moved
+            if player
+                .inventory
+                .get_item(use_item_slot)
+                .map_or(true, |item| item.get_item_type() != ItemType::Consumable)
+            {
+                ui_state_windows.repair_open = false;
+                return;
+            }
+        }
+    }
+
+    let damaged_items = get_damaged_items(&player);
+    let money = player.inventory.money.0;
+    let mut repair_item_slot = None;
+
+    egui::Window::new(localize!("repair-title"))
+        .id(egui::Id::new("repair"))
+        .open(&mut ui_state_windows.repair_open)
+        .resizable(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            if let RepairSource::Item { .. } = source {
+                ui.label(localize!("repair-using-item"));
+                ui.separator();
+            }
+
+            if damaged_items.is_empty() {
+                ui.label(egui::RichText::new(localize!("repair-no-items")).weak());
+            }
+
+            egui::ScrollArea::vertical()
+                .max_height(400.0)
+                .show(ui, |ui| {
+                    egui::Grid::new("repair_items")
+                        .spacing([8.0, 4.0])
+                        .show(ui, |ui| {
+                            for (item_slot, equipment_item) in damaged_items.iter() {
+                                let item = Item::Equipment(equipment_item.clone());
+                                ui_add_repair_item_slot(
+                                    ui,
+                                    &mut ui_state_dnd,
+                                    &item,
+                                    player_tooltip_data.as_ref(),
+                                    &game_data,
+                                    &ui_resources,
+                                );
+
+                                let name = game_data
+                                    .items
+                                    .get_base_item(equipment_item.item)
+                                    .map_or("???", |item_data| item_data.name);
+                                ui.vertical(|ui| {
+                                    ui.label(name);
+
+                                    let life = localize!(
+                                        "repair-item-life",
+                                        life = (equipment_item.life + 9) / 10
+                                    );
+                                    if equipment_item.life == 0 {
+                                        ui.colored_label(egui::Color32::RED, life);
+                                    } else {
+                                        ui.label(life);
+                                    }
+                                });
+
+                                let price = if let RepairSource::Npc { .. } = source {
+                                    let price = get_repair_price(&game_data, equipment_item);
+                                    ui.colored_label(
+                                        egui::Color32::YELLOW,
+                                        localize!(
+                                            "repair-item-price",
+                                            money = format_money(Money(price))
+                                        ),
+                                    );
+                                    price
+                                } else {
+                                    0
+                                };
+
+                                ui.horizontal(|ui| {
+                                    if ui
+                                        .add_enabled(
+                                            ui_state.pending_item_slot.is_none() && price <= money,
+                                            egui::Button::new(localize!("repair-button")),
+                                        )
+                                        .clicked()
+                                    {
+                                        repair_item_slot = Some(*item_slot);
+                                    }
+
+                                    if ui_state.pending_item_slot == Some(*item_slot) {
+                                        ui.spinner();
+                                    }
+                                });
+                                ui.end_row();
+                            }
+                        });
+                });
+
+            if let Some(result) = ui_state.result.as_ref() {
+                ui.separator();
+                ui.colored_label(egui::Color32::LIGHT_GREEN, result);
+            }
+        });
+
+    let (Some(item_slot), Some(game_connection)) = (repair_item_slot, game_connection.as_ref())
+    else {
+        return;
+    };
+
+    match *source {
+        RepairSource::Npc {
+            client_entity_id, ..
+        } => {
+            game_connection
+                .client_message_tx
+                .send(ClientMessage::RepairItemUsingNpc {
+                    npc_entity_id: client_entity_id,
+                    item_slot,
+                })
+                .ok();
+            ui_state.pending_item_slot = Some(item_slot);
+            ui_state.result = None;
+        }
+        RepairSource::Item { use_item_slot } => {
+            game_connection
+                .client_message_tx
+                .send(ClientMessage::RepairItemUsingItem {
+                    use_item_slot,
+                    item_slot,
+                })
+                .ok();
+
+            // Each repair item is consumed by a single repair
+            ui_state_windows.repair_open = false;
+        }
+    }
+}
@@ -1,45 +1,121 @@
-use bevy::prelude::{Assets, EventWriter, Query, Res, With};
+use bevy::{
+    math::{Vec2, Vec3Swizzles},
+    prelude::{AssetServer, Assets, EventWriter, Handle, Image, Local, Query, Res, Vec3, With},
+};
 use bevy_egui::{egui, EguiContexts};
-use rose_game_common::messages::client::ClientMessage;
+
+use rose_data::ZoneId;
+use rose_game_common::{components::CharacterInfo, messages::client::ClientMessage};
 
 use crate::{
-    components::{Dead, PlayerCharacter},
-    resources::{GameConnection, UiResources},
+    components::{Dead, PlayerCharacter, Position},
+    events::ScreenTransitionEvent,
+    localize,
+    resources::{CurrentZone, GameConnection, GameData, UiResources},
     ui::{
-        widgets::{DataBindings, Dialog},
+        widgets::{DataBindings, Dialog, Widget},
         UiSoundEvent,
     },
+    zone_loader::ZoneLoaderAsset,
 };
 
 const IID_BTN_SAVE_POSITION: i32 = 3;
 const IID_BTN_REVIVE_POSITION: i32 = 4;
 
+const MAP_BLOCK_PIXELS: f32 = 64.0;
+const MAP_OUTLINE_PIXELS: f32 = MAP_BLOCK_PIXELS;
+const MAP_MAX_SIZE: f32 = 320.0;
+
+#[derive(Default)]
+pub struct UiStateRespawn {
+    zone_id: Option<ZoneId>,
+    minimap_image: Handle<Image>,
+    minimap_texture: egui::TextureId,
+    set_save_zone: bool,
+}
+
 pub fn ui_respawn_system(
-    query_player_dead: Query<&Dead, With<PlayerCharacter>>,
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiStateRespawn>,
+    query_player_dead: Query<(&CharacterInfo, &Position), (With<Dead>, With<PlayerCharacter>)>,
     dialog_assets: Res<Assets<Dialog>>,
     ui_resources: Res<UiResources>,
     mut ui_sound_events: EventWriter<UiSoundEvent>,
-    mut egui_context: EguiContexts,
+    asset_server: Res<AssetServer>,
+    images: Res<Assets<Image>>,
+    current_zone: Option<Res<CurrentZone>>,
+    zone_loader_assets: Res<Assets<ZoneLoaderAsset>>,
     game_connection: Option<Res<GameConnection>>,
+    game_data: Res<GameData>,
+    mut screen_transition_events: EventWriter<ScreenTransitionEvent>,
 ) {
-    if query_player_dead.is_empty() {
+    let ui_state = &mut *ui_state;
+    let Ok((character_info, player_position)) = query_player_dead.get_single() else {
+        ui_state.set_save_zone = false;
         return;
-    }
+    };
 
-    let dialog = if let Some(dialog) = dialog_assets.get(&ui_resources.dialog_respawn) {
-        dialog
-    } else {
+    let Some(dialog) = dialog_assets.get(&ui_resources.dialog_respawn) else {
         return;
     };
 
+    let Some(current_zone) = current_zone else {
+        return;
+    };
+    let zone_data = game_data.zone_list.get_zone(current_zone.id);
+
+    if ui_state.zone_id != Some(current_zone.id) {
+        ui_state.zone_id = Some(current_zone.id);
+        ui_state.minimap_image = Default::default();
+        ui_state.minimap_texture = Default::default();
+
+        if let Some(minimap_path) = zone_data.and_then(|zone_data| zone_data.minimap_path.as_ref())
+        {
+            ui_state.minimap_image = asset_server.load(minimap_path.path());
+            ui_state.minimap_texture = egui_context.add_image(ui_state.minimap_image.clone_weak());
+        }
+    }
+
+    // The server always revives at the revive point closest to where the player died
+    let revive_positions = zone_data.map_or(&[][..], |zone_data| &zone_data.revive_positions[..]);
+    let nearest_revive_position = revive_positions.iter().min_by(|a, b| {
+        a.xy()
+            .distance_squared(player_position.xy())
+            .total_cmp(&b.xy().distance_squared(player_position.xy()))
+    });
+
     let screen_size = egui_context
         .ctx_mut()
         .input(|input| input.screen_rect().size());
     let default_x = screen_size.x / 2.0 - dialog.width / 2.0;
     let default_y = screen_size.y / 2.0 - dialog.height / 2.0;
 
+    let minimap_image_size = images
+        .get(&ui_state.minimap_image)
+        .map(|minimap_image| minimap_image.size());
+    let zone_loader_asset = zone_loader_assets.get(&current_zone.handle);
+    let revive_map = if let (Some(image_size), Some(zone_data), Some(zone_loader_asset)) =
+        (minimap_image_size, zone_data, zone_loader_asset)
+    {
+        let world_block_size =
+            16.0 * zone_loader_asset.zon.grid_per_patch * zone_loader_asset.zon.grid_size;
+        Some(ReviveMap {
+            minimap_texture: ui_state.minimap_texture,
+            image_size,
+            world_block_size,
+            min_world_pos: Vec2::new(
+                zone_data.minimap_start_x as f32 * world_block_size,
+                (64.0 - zone_data.minimap_start_y as f32 + 1.0) * world_block_size,
+            ),
+        })
+    } else {
+        None
+    };
+
     let mut response_save_position = None;
     let mut response_revive_position = None;
+    let mut clicked_revive_position = false;
+    let mut clicked_save_position = false;
 
     egui::Window::new("Respawn")
         .frame(egui::Frame::none())
@@ -59,21 +135,86 @@ pub fn ui_respawn_system(
                     ],
                     ..Default::default()
                 },
-                |_, _| {},
+                |ui, bindings| {
+                    let panel_rect = egui::Rect::from_min_size(
+                        ui.min_rect().min + egui::vec2(0.0, dialog.height),
+                        egui::vec2(MAP_MAX_SIZE, MAP_MAX_SIZE),
+                    );
+                    let map_response = ui
+                        .allocate_ui_at_rect(panel_rect, |ui| {
+                            egui::Frame::none()
+                                .fill(egui::Color32::from_black_alpha(200))
+                                .inner_margin(4.0)
+                                .show(ui, |ui| {
+                                    ui.label(
+                                        egui::RichText::new(localize!("respawn-map-title"))
+                                            .color(egui::Color32::WHITE),
+                                    );
+                                    ui.add_space(4.0);
+
+                                    let map_response = revive_map.as_ref().map(|revive_map| {
+                                        ui_add_revive_map(
+                                            ui,
+                                            revive_map,
+                                            revive_positions,
+                                            nearest_revive_position,
+                                            character_info,
+                                            player_position,
+                                            current_zone.id,
+                                        )
+                                    });
+
+                                    ui.add_space(4.0);
+                                    ui.checkbox(
+                                        &mut ui_state.set_save_zone,
+                                        egui::RichText::new(localize!("respawn-set-save-zone"))
+                                            .color(egui::Color32::WHITE),
+                                    );
+                                    map_response.unwrap_or_default()
+                                })
+                                .inner
+                        })
+                        .inner;
+
+                    if map_response.revive_position {
+                        clicked_revive_position = true;
+                        emit_button_click_sound(dialog, bindings, IID_BTN_REVIVE_POSITION);
+                    }
+
+                    if map_response.save_position {
+                        clicked_save_position = true;
+                        emit_button_click_sound(dialog, bindings, IID_BTN_SAVE_POSITION);
+                    }
+                },
             )
         });
 
-    if response_save_position.map_or(false, |x| x.clicked()) {
-        if let Some(game_connection) = game_connection.as_ref() {
+    let revive_save_position =
+        clicked_save_position || response_save_position.map_or(false, |x| x.clicked());
+    let revive_current_position =
+        clicked_revive_position || response_revive_position.map_or(false, |x| x.clicked());
+
+    if !revive_save_position && !revive_current_position {
+        return;
+    }
+
+    if let Some(game_connection) = game_connection.as_ref() {
+        // Hide the teleport to the respawn point, the zone load keeps the screen black
+        screen_transition_events.send(ScreenTransitionEvent::FadeOut);
+
+        if revive_save_position {
             game_connection
                 .client_message_tx
                 .send(ClientMessage::ReviveSaveZone)
                 .ok();
-        }
-    }
+        } else {
+            if ui_state.set_save_zone {
+                game_connection
+                    .client_message_tx
+                    .send(ClientMessage::SetReviveSaveZone)
+                    .ok();
+            }
 
-    if response_revive_position.map_or(false, |x| x.clicked()) {
-        if let Some(game_connection) = game_connection.as_ref() {
             game_connection
                 .client_message_tx
                 .send(ClientMessage::ReviveCurrentZone)
@@ -81,3 +222,129 @@ pub fn ui_respawn_system(
         }
     }
 }
+
+fn emit_button_click_sound(dialog: &Dialog, bindings: &mut DataBindings, id: i32) {
+    if let Some(Widget::Button(button)) = dialog.get_widget(id) {
+        if let Some(click_sound_id) = button.click_sound_id {
+            bindings.emit_sound(click_sound_id);
+        }
+    }
+}
+
+struct ReviveMap {
+    minimap_texture: egui::TextureId,
+    image_size: Vec2,
+    world_block_size: f32,
+    min_world_pos: Vec2,
+}
+
+#[derive(Default)]
+struct ReviveMapResponse {
+    revive_position: bool,
+    save_position: bool,
+}
+
+fn ui_add_revive_map(
+    ui: &mut egui::Ui,
+    revive_map: &ReviveMap,
+    revive_positions: &[Vec3],
+    nearest_revive_position: Option<&Vec3>,
+    character_info: &CharacterInfo,
+    player_position: &Position,
+    current_zone_id: ZoneId,
+) -> ReviveMapResponse {
+    let mut map_response = ReviveMapResponse::default();
+    let image_size = revive_map.image_size;
+    let scale = (MAP_MAX_SIZE / image_size.x).min(MAP_MAX_SIZE / image_size.y);
+    let (map_rect, _) = ui.allocate_exact_size(
+        egui::vec2(image_size.x * scale, image_size.y * scale),
+        egui::Sense::hover(),
+    );
+
+    let mut mesh = egui::epaint::Mesh::with_texture(revive_map.minimap_texture);
+    mesh.add_rect_with_uv(
+        map_rect,
+        egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(1.0, 1.0)),
+        egui::Color32::WHITE,
+    );
+    ui.painter().add(egui::epaint::Shape::mesh(mesh));
+
+    let distance_per_pixel = revive_map.world_block_size / MAP_BLOCK_PIXELS;
+    let min_world_pos = revive_map.min_world_pos;
+    let map_position = |position: Vec3| -> egui::Pos2 {
+        map_rect.min
+            + egui::vec2(
+                MAP_OUTLINE_PIXELS + (position.x - min_world_pos.x).max(0.0) / distance_per_pixel,
+                MAP_OUTLINE_PIXELS + (min_world_pos.y - position.y).max(0.0) / distance_per_pixel,
+            ) * scale
+    };
+
+    for revive_position in revive_positions.iter() {
+        let is_nearest = Some(revive_position) == nearest_revive_position;
+        let center = map_position(*revive_position);
+        ui.painter().circle(
+            center,
+            5.0,
+            if is_nearest {
+                egui::Color32::GREEN
+            } else {
+                egui::Color32::GRAY
+            },
+            egui::Stroke::new(1.0, egui::Color32::BLACK),
+        );
+
+        let response = ui.interact(
+            egui::Rect::from_center_size(center, egui::vec2(12.0, 12.0)),
+            ui.id()
+                .with(("revive_position", center.x as i32, center.y as i32)),
+            if is_nearest {
+                egui::Sense::click()
+            } else {
+                egui::Sense::hover()
+            },
+        );
+
+        if is_nearest {
+            if response
+                .on_hover_text(localize!("respawn-nearest-revive-point"))
+                .clicked()
+            {
+                map_response.revive_position = true;
+            }
+        } else {
+            response.on_hover_text(localize!("respawn-other-revive-point"));
+        }
+    }
+
+    // Draw saved revive location when it is in this zone
+    if character_info.revive_zone_id == current_zone_id {
+        let center = map_position(character_info.revive_position);
+        ui.painter().circle(
+            center,
+            5.0,
+            egui::Color32::GOLD,
+            egui::Stroke::new(1.0, egui::Color32::BLACK),
+        );
+
+        let response = ui.interact(
+            egui::Rect::from_center_size(center, egui::vec2(12.0, 12.0)),
+            ui.id().with("save_position"),
+            egui::Sense::click(),
+        );
+        if response
+            .on_hover_text(localize!("respawn-saved-location"))
+            .clicked()
+        {
+            map_response.save_position = true;
+        }
+    }
+
+    // Draw where the player died
+    ui.painter().circle_filled(
+        map_position(player_position.position),
+        3.0,
+        egui::Color32::RED,
+    );
+
+    map_response
+}
@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use bevy::{
+    prelude::{EventReader, Local, Res},
+    time::{Time, Timer, TimerMode},
+};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::events::{LoadZoneEvent, ScreenTransitionEvent, ZoneEvent};
+
+const FADE_OUT_DURATION: f32 = 0.3;
+const FADE_IN_DURATION: f32 = 0.5;
+
+// Fade back in if whatever the screen was hidden for never happens, so a lost
+// message can not leave the screen black
+const FADE_OUT_HOLD_TIMEOUT: Duration = Duration::from_secs(5);
+const ZONE_LOAD_HOLD_TIMEOUT: Duration = Duration::from_secs(30);
+
+#[derive(Default)]
+pub struct UiStateScreenTransition {
+    alpha: f32,
+    fading_out: bool,
+    waiting_for_zone: bool,
+    hold_timeout: Option<Timer>,
+}
+
+pub fn ui_screen_transition_system(
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiStateScreenTransition>,
+    mut load_zone_events: EventReader<LoadZoneEvent>,
+    mut zone_events: EventReader<ZoneEvent>,
+    mut screen_transition_events: EventReader<ScreenTransitionEvent>,
+    time: Res<Time>,
+) {
+    let ui_state = &mut *ui_state;
+
+    for event in screen_transition_events.iter() {
+        match event {
+            ScreenTransitionEvent::FadeOut => {
+                ui_state.fading_out = true;
+                if !ui_state.waiting_for_zone {
+                    ui_state.hold_timeout =
+                        Some(Timer::new(FADE_OUT_HOLD_TIMEOUT, TimerMode::Once));
+                }
+            }
+            ScreenTransitionEvent::FadeIn => {
+                ui_state.fading_out = false;
+                ui_state.waiting_for_zone = false;
+                ui_state.hold_timeout = None;
+            }
+            ScreenTransitionEvent::CutIn => {
+                ui_state.alpha = 1.0;
+                ui_state.fading_out = false;
+                ui_state.waiting_for_zone = false;
+                ui_state.hold_timeout = None;
+            }
+        }
+    }
+
+    if load_zone_events.iter().count() > 0 {
+        ui_state.fading_out = true;
+        ui_state.waiting_for_zone = true;
+        ui_state.hold_timeout = Some(Timer::new(ZONE_LOAD_HOLD_TIMEOUT, TimerMode::Once));
+    }
+
+    if zone_events.iter().count() > 0 && ui_state.waiting_for_zone {
+        ui_state.waiting_for_zone = false;
+        ui_state.hold_timeout = None;
+    }
+
+    if let Some(hold_timeout) = ui_state.hold_timeout.as_mut() {
+        if hold_timeout.tick(time.delta()).finished() {
+            ui_state.waiting_for_zone = false;
+            ui_state.hold_timeout = None;
+        }
+    }
+
+    let delta = time.delta_seconds();
+    if ui_state.fading_out {
+        ui_state.alpha = (ui_state.alpha + delta / FADE_OUT_DURATION).min(1.0);
+
+        // Always finish fading out, so a zone which loads instantly does not flicker
+        if ui_state.alpha >= 1.0 && ui_state.hold_timeout.is_none() {
+            ui_state.fading_out = false;
+        }
+    } else {
+        ui_state.alpha = (ui_state.alpha - delta / FADE_IN_DURATION).max(0.0);
+    }
+
+    if ui_state.alpha <= 0.0 {
+        return;
+    }
+
+    // Drawn behind all windows so dialogs such as respawn remain usable
+    let ctx = egui_context.ctx_mut();
+    let screen_rect = ctx.input(|input| input.screen_rect());
+    ctx.layer_painter(egui::LayerId::new(
+        egui::Order::Background,
+        egui::Id::new("screen_transition"),
+    ))
+    .rect_filled(
+        screen_rect,
+        0.0,
+        egui::Color32::from_black_alpha((ui_state.alpha * 255.0) as u8),
+    );
+}
@@ -0,0 +1,409 @@
+use bevy::{
+    app::AppExit,
+    asset::LoadState,
+    core_pipeline::core_2d::Camera2dBundle,
+    prelude::{
+        AssetServer, Assets, Camera, Commands, EventWriter, Handle, Image, Query, Res, ResMut,
+        UVec2,
+    },
+    render::{
+        camera::{CameraRenderGraph, RenderTarget},
+        render_resource::{Extent3d, TextureDimension, TextureFormat, TextureUsages},
+        texture::BevyDefault,
+    },
+    utils::HashMap,
+};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    create_ui_font_definitions,
+    render::{UiScreenshotCapture, UiScreenshotMesh, UiScreenshotRequest, UI_SCREENSHOT_GRAPH},
+    resources::{UiResources, UiScreenshotHarness},
+    ui::widgets::{DataBindings, Dialog, Widget},
+};
+
+// Number of frames to draw each dialog before taking the screenshot
+const SCREENSHOT_SETTLE_FRAMES: u32 = 5;
+
+// Number of frames to wait for a screenshot to be rendered and read back
+const SCREENSHOT_TIMEOUT_FRAMES: u32 = 120;
+
+const SCREENSHOT_LABEL_HEIGHT: f32 = 20.0;
+
+#[derive(Default)]
+struct DialogWidgetIds {
+    checkbox: Vec<i32>,
+    editbox: Vec<i32>,
+    gauge: Vec<i32>,
+    listbox: Vec<i32>,
+}
+
+fn collect_widget_ids(widgets: &[Widget], ids: &mut DialogWidgetIds) {
+    for widget in widgets {
+        match widget {
+            Widget::Checkbox(checkbox) => ids.checkbox.push(checkbox.id),
+            Widget::Editbox(editbox) => ids.editbox.push(editbox.id),
+            Widget::Gauge(gauge) => ids.gauge.push(gauge.id),
+            Widget::Listbox(listbox) => ids.listbox.push(listbox.id),
+            Widget::Pane(pane) => collect_widget_ids(&pane.widgets, ids),
+            Widget::TabbedPane(tabbed_pane) => {
+                for tab in tabbed_pane.tabs.iter() {
+                    collect_widget_ids(&tab.widgets, ids);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn collect_user_textures(
+    widgets: &[Widget],
+    textures: &mut HashMap<egui::TextureId, Handle<Image>>,
+) {
+    for widget in widgets {
+        match widget {
+            Widget::Skill(skill) => {
+                if let Some(ui_texture) = skill.ui_texture.as_ref() {
+                    textures.insert(ui_texture.texture_id, ui_texture.handle.clone());
+                }
+                collect_user_textures(&skill.widgets, textures);
+            }
+            Widget::Pane(pane) => collect_user_textures(&pane.widgets, textures),
+            Widget::TabbedPane(tabbed_pane) => {
+                for tab in tabbed_pane.tabs.iter() {
+                    collect_user_textures(&tab.widgets, textures);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn draw_dialog_with_sample_bindings(ui: &mut egui::Ui, dialog: &Dialog) {
+    let mut ids = DialogWidgetIds::default();
+    collect_widget_ids(&dialog.widgets, &mut ids);
+
+    let mut checked = vec![true; ids.checkbox.len()];
+    let mut text_buffers = vec!["Sample Text".to_string(); ids.editbox.len()];
+    let mut list_indices = vec![0; ids.listbox.len()];
+    let gauge_value = 0.65;
+    let get_list_item = |index: i32| (index < 5).then(|| format!("List Item {}", index + 1));
+
+    dialog.draw(
+        ui,
+        DataBindings {
+            checked: &mut ids
+                .checkbox
+                .iter()
+                .copied()
+                .zip(checked.iter_mut())
+                .collect::<Vec<_>>(),
+            text: &mut ids
+                .editbox
+                .iter()
+                .copied()
+                .zip(text_buffers.iter_mut())
+                .collect::<Vec<_>>(),
+            gauge: &mut ids
+                .gauge
+                .iter()
+                .map(|id| (*id, &gauge_value, "65 / 100"))
+                .collect::<Vec<_>>(),
+            listbox: &mut ids
+                .listbox
+                .iter()
+                .copied()
+                .zip(list_indices.iter_mut())
+                .map(|(id, index)| {
+                    (
+                        id,
+                        (index, &get_list_item as &dyn Fn(i32) -> Option<String>),
+                    )
+                })
+                .collect::<Vec<_>>(),
+            ..Default::default()
+        },
+        |_, _| {},
+    );
+}
+
+/// Updates the images of the textures managed by the harness egui context, such as the
+/// font atlas, so they can be drawn by the screenshot camera.
+fn update_managed_textures(
+    harness: &mut UiScreenshotHarness,
+    images: &mut Assets<Image>,
+    textures_delta: egui::TexturesDelta,
+) {
+    for (texture_id, delta) in textures_delta.set {
+        let egui::TextureId::Managed(id) = texture_id else {
+            continue;
+        };
+
+        let [width, height] = delta.image.size();
+        let pixels: Vec<u8> = match &delta.image {
+            egui::ImageData::Color(image) => image
+                .pixels
+                .iter()
+                .flat_map(|color| color.to_array())
+                .collect(),
+            egui::ImageData::Font(image) => image
+                .srgba_pixels(None)
+                .flat_map(|color| color.to_array())
+                .collect(),
+        };
+
+        if let Some([x, y]) = delta.pos {
+            let Some(image) = harness
+                .managed_textures
+                .get(&id)
+                .and_then(|handle| images.get_mut(handle))
+            else {
+                continue;
+            };
+
+            let image_width = image.texture_descriptor.size.width as usize;
+            for (row, row_pixels) in pixels.chunks(width * 4).enumerate() {
+                let start = ((y + row) * image_width + x) * 4;
+                image.data[start..start + row_pixels.len()].copy_from_slice(row_pixels);
+            }
+        } else {
+            let image = Image::new(
+                Extent3d {
+                    width: width as u32,
+                    height: height as u32,
+                    depth_or_array_layers: 1,
+                },
+                TextureDimension::D2,
+                pixels,
+                TextureFormat::Rgba8UnormSrgb,
+            );
+            harness.managed_textures.insert(id, images.add(image));
+        }
+    }
+
+    for texture_id in textures_delta.free {
+        if let egui::TextureId::Managed(id) = texture_id {
+            harness.managed_textures.remove(&id);
+        }
+    }
+}
+
+fn create_render_target(size: UVec2) -> Image {
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: size.x,
+            height: size.y,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 0],
+        TextureFormat::bevy_default(),
+    );
+    image.texture_descriptor.usage = TextureUsages::TEXTURE_BINDING
+        | TextureUsages::COPY_DST
+        | TextureUsages::COPY_SRC
+        | TextureUsages::RENDER_ATTACHMENT;
+    image
+}
+
+fn save_screenshot(
+    harness: &mut UiScreenshotHarness,
+    filename: String,
+    size: UVec2,
+    pixels: &[u8],
+) {
+    let path = harness.screenshot_path(&filename);
+    match image::save_buffer(&path, pixels, size.x, size.y, image::ColorType::Rgba8) {
+        Ok(_) => harness.num_written += 1,
+        Err(error) => {
+            log::error!(
+                "Failed to save UI screenshot {} with error: {}",
+                path.to_string_lossy(),
+                error
+            );
+            harness.failed.push(filename);
+        }
+    }
+}
+
+pub fn ui_screenshot_harness_system(
+    mut commands: Commands,
+    mut egui_context: EguiContexts,
+    mut harness: ResMut<UiScreenshotHarness>,
+    mut images: ResMut<Assets<Image>>,
+    mut query_camera: Query<&mut Camera>,
+    ui_screenshot_capture: Res<UiScreenshotCapture>,
+    asset_server: Res<AssetServer>,
+    dialog_assets: Res<Assets<Dialog>>,
+    ui_resources: Option<Res<UiResources>>,
+    mut app_exit_events: EventWriter<AppExit>,
+) {
+    let Some(ui_resources) = ui_resources.filter(|ui_resources| ui_resources.loaded_all_textures)
+    else {
+        return;
+    };
+
+    if harness.dialog_filenames.is_empty() && harness.current_index == 0 {
+        if let Err(error) = std::fs::create_dir_all(&harness.output_dir) {
+            log::error!(
+                "Failed to create UI screenshot directory {} with error: {}",
+                harness.output_dir.to_string_lossy(),
+                error
+            );
+            app_exit_events.send(AppExit);
+            return;
+        }
+
+        let mut dialog_filenames: Vec<String> = ui_resources.dialog_files.keys().cloned().collect();
+        dialog_filenames.sort();
+        harness.dialog_filenames = dialog_filenames;
+
+        // Draw with the same fonts and style as the game UI
+        harness.egui_context.set_fonts(create_ui_font_definitions());
+        harness
+            .egui_context
+            .set_style(egui_context.ctx_mut().style());
+    }
+
+    let Some(filename) = harness
+        .current_dialog()
+        .map(|filename| filename.to_string())
+    else {
+        log::info!(
+            "Wrote {} UI screenshots to {}",
+            harness.num_written,
+            harness.output_dir.to_string_lossy()
+        );
+        for filename in harness.failed.iter() {
+            log::error!("Failed to capture UI screenshot of {}", filename);
+        }
+        app_exit_events.send(AppExit);
+        return;
+    };
+
+    let handle = &ui_resources.dialog_files[&filename];
+    let Some(dialog) = dialog_assets.get(handle).filter(|dialog| dialog.loaded) else {
+        if matches!(asset_server.get_load_state(handle), LoadState::Failed) {
+            harness.failed.push(filename);
+            harness.next_dialog();
+        }
+        return;
+    };
+
+    let size = UVec2::new(
+        (dialog.width.ceil() as u32).max(1),
+        (dialog.height + SCREENSHOT_LABEL_HEIGHT).ceil() as u32,
+    );
+
+    harness.current_frame += 1;
+    if harness.capture_requested {
+        if let Some(pixels) = ui_screenshot_capture.take_pixels() {
+            save_screenshot(&mut harness, filename, size, &pixels);
+            harness.next_dialog();
+        } else if harness.current_frame >= SCREENSHOT_SETTLE_FRAMES + SCREENSHOT_TIMEOUT_FRAMES {
+            log::error!("Timed out rendering UI screenshot of {}", filename);
+            harness.failed.push(filename);
+            harness.next_dialog();
+        }
+        return;
+    }
+
+    let raw_input = egui::RawInput {
+        screen_rect: Some(egui::Rect::from_min_size(
+            egui::Pos2::ZERO,
+            egui::vec2(size.x as f32, size.y as f32),
+        )),
+        pixels_per_point: Some(1.0),
+        ..Default::default()
+    };
+    let ctx = harness.egui_context.clone();
+    let full_output = ctx.run(raw_input, |ctx| {
+        egui::Area::new("ui_screenshot_harness_label")
+            .fixed_pos([0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(
+                    egui::RichText::new(format!(
+                        "{} ({}x{})",
+                        filename, dialog.width, dialog.height
+                    ))
+                    .color(egui::Color32::WHITE)
+                    .background_color(egui::Color32::BLACK),
+                );
+            });
+
+        egui::Window::new("ui_screenshot_harness_dialog")
+            .fixed_pos([0.0, SCREENSHOT_LABEL_HEIGHT])
+            .frame(egui::Frame::none())
+            .title_bar(false)
+            .resizable(false)
+            .default_width(dialog.width)
+            .default_height(dialog.height)
+            .show(ctx, |ui| draw_dialog_with_sample_bindings(ui, dialog));
+    });
+    update_managed_textures(&mut harness, &mut images, full_output.textures_delta);
+
+    if harness.current_frame < SCREENSHOT_SETTLE_FRAMES {
+        return;
+    }
+
+    let mut user_textures = HashMap::default();
+    for sprite_sheet in ui_resources.sprite_sheets.values().flatten() {
+        for ui_texture in sprite_sheet.loaded_textures.iter() {
+            user_textures.insert(ui_texture.texture_id, ui_texture.handle.clone());
+        }
+    }
+    collect_user_textures(&dialog.widgets, &mut user_textures);
+
+    let meshes = ctx
+        .tessellate(full_output.shapes)
+        .into_iter()
+        .filter_map(|clipped_primitive| {
+            let egui::epaint::Primitive::Mesh(mesh) = clipped_primitive.primitive else {
+                return None;
+            };
+            let image = match mesh.texture_id {
+                egui::TextureId::Managed(id) => harness.managed_textures.get(&id),
+                egui::TextureId::User(_) => user_textures.get(&mesh.texture_id),
+            }?;
+
+            Some(UiScreenshotMesh {
+                image: image.clone(),
+                clip_rect: clipped_primitive.clip_rect,
+                mesh,
+            })
+        })
+        .collect();
+
+    let target = images.add(create_render_target(size));
+    let camera_entity = match harness
+        .camera
+        .filter(|camera_entity| query_camera.contains(*camera_entity))
+    {
+        Some(camera_entity) => {
+            if let Ok(mut camera) = query_camera.get_mut(camera_entity) {
+                camera.target = RenderTarget::Image(target.clone());
+            }
+            camera_entity
+        }
+        None => commands
+            .spawn(Camera2dBundle {
+                camera: Camera {
+                    order: -1,
+                    target: RenderTarget::Image(target.clone()),
+                    ..Default::default()
+                },
+                camera_render_graph: CameraRenderGraph::new(UI_SCREENSHOT_GRAPH),
+                ..Default::default()
+            })
+            .id(),
+    };
+    harness.camera = Some(camera_entity);
+
+    ui_screenshot_capture.request(UiScreenshotRequest {
+        camera: camera_entity,
+        target,
+        size,
+        meshes,
+    });
+    harness.capture_requested = true;
+}
@@ -3,19 +3,97 @@ use bevy_egui::{egui, EguiContexts};
 
 use crate::{
     events::LoginEvent,
-    resources::{LoginConnection, LoginState, ServerList, UiResources},
+    localize,
+    resources::{LoginConnection, LoginState, MessageOfTheDay, ServerList, UiResources},
     ui::{
         widgets::{DataBindings, Dialog},
         UiSoundEvent,
     },
 };
 
+const MESSAGE_OF_THE_DAY_WIDTH: f32 = 360.0;
+
 #[derive(Default)]
 pub struct UiStateServerSelect {
     selected_world_server_index: i32,
     selected_game_server_index: i32,
 }
 
+/// Adds text where any http or https urls are shown as links which open in the browser.
+fn ui_add_text_with_links(ui: &mut egui::Ui, text: &str) {
+    for line in text.lines() {
+        ui.horizontal_wrapped(|ui| {
+            for word in line.split_whitespace() {
+                if word.starts_with("https://") || word.starts_with("http://") {
+                    ui.hyperlink(word);
+                } else {
+                    ui.label(word);
+                }
+            }
+        });
+    }
+}
+
+fn ui_add_message_of_the_day(ui: &mut egui::Ui, message_of_the_day: &MessageOfTheDay) {
+    let Some(content) = message_of_the_day.content.as_ref() else {
+        if message_of_the_day.is_fetching() {
+            ui.horizontal(|ui| {
+                ui.spinner();
+                ui.label(localize!("motd-loading"));
+            });
+        } else if let Some(error) = message_of_the_day.error.as_ref() {
+            ui.colored_label(
+                egui::Color32::RED,
+                localize!("motd-fetch-failed", error = error.clone()),
+            );
+        }
+        return;
+    };
+
+    egui::ScrollArea::vertical()
+        .max_height(400.0)
+        .show(ui, |ui| {
+            ui_add_text_with_links(ui, &content.message);
+
+            if !content.links.is_empty() {
+                ui.add_space(4.0);
+                ui.horizontal_wrapped(|ui| {
+                    for link in content.links.iter() {
+                        ui.hyperlink_to(&link.text, &link.url);
+                    }
+                });
+            }
+
+            if !content.patch_notes.is_empty() {
+                ui.separator();
+                ui.heading(localize!("motd-patch-notes"));
+
+                // Only the latest patch notes are expanded
+                for (index, patch_notes) in content.patch_notes.iter().enumerate() {
+                    let title = if patch_notes.date.is_empty() {
+                        patch_notes.title.clone()
+                    } else {
+                        format!("{} ({})", patch_notes.title, patch_notes.date)
+                    };
+
+                    egui::CollapsingHeader::new(title)
+                        .id_source(("patch_notes", index))
+                        .default_open(index == 0)
+                        .show(ui, |ui| {
+                            for note in patch_notes.notes.iter() {
+                                ui.horizontal(|ui| {
+                                    ui.label("•");
+                                    ui.vertical(|ui| {
+                                        ui_add_text_with_links(ui, note);
+                                    });
+                                });
+                            }
+                        });
+                }
+            }
+        });
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn ui_server_select_system(
     mut commands: Commands,
@@ -26,6 +104,7 @@ pub fn ui_server_select_system(
     dialog_assets: Res<Assets<Dialog>>,
     server_list: Option<Res<ServerList>>,
     ui_resources: Res<UiResources>,
+    message_of_the_day: Res<MessageOfTheDay>,
     mut login_events: EventWriter<LoginEvent>,
 ) {
     if !matches!(*login_state, LoginState::ServerSelect) {
@@ -110,6 +189,18 @@ pub fn ui_server_select_system(
             );
         });
 
+    if !message_of_the_day.url.is_empty() {
+        egui::Window::new(localize!("motd-title"))
+            .id(egui::Id::new("message_of_the_day"))
+            .collapsible(false)
+            .resizable(false)
+            .default_width(MESSAGE_OF_THE_DAY_WIDTH)
+            .fixed_pos(position - egui::vec2(MESSAGE_OF_THE_DAY_WIDTH + 40.0, 0.0))
+            .show(egui_context.ctx_mut(), |ui| {
+                ui_add_message_of_the_day(ui, &message_of_the_day);
+            });
+    }
+
     if response_ok_button.map_or(false, |r| r.clicked()) {
         try_select_server = true;
     }
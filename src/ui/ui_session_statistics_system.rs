@@ -0,0 +1,235 @@
+use std::{fmt::Write, path::PathBuf};
+
+use bevy::prelude::{Local, Res, ResMut};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    localize,
+    resources::{get_data_dir, GameData, SessionStatistics},
+    ui::UiStateWindows,
+};
+
+#[derive(Default)]
+pub struct UiStateSessionStatistics {
+    export_result: Option<String>,
+}
+
+fn csv_escape(value: &str) -> String {
+    if value.contains(|c| matches!(c, ',' | '"' | '\n')) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn export_csv(
+    session_statistics: &SessionStatistics,
+    game_data: &GameData,
+) -> anyhow::Result<PathBuf> {
+    let path = get_data_dir()?.join("statistics").join(format!(
+        "session-{}.csv",
+        session_statistics.start_time.format("%Y%m%d-%H%M%S")
+    ));
+
+    let mut csv = String::new();
+    writeln!(csv, "category,name,value,per_hour")?;
+    writeln!(
+        csv,
+        "session,start,{},",
+        session_statistics.start_time.to_rfc3339()
+    )?;
+    writeln!(
+        csv,
+        "session,hours,{:.2},",
+        session_statistics.get_elapsed_hours()
+    )?;
+    writeln!(
+        csv,
+        "session,xp,{},{:.0}",
+        session_statistics.xp_earned,
+        session_statistics.get_per_hour(session_statistics.xp_earned as f64)
+    )?;
+    writeln!(
+        csv,
+        "session,zuly,{},{:.0}",
+        session_statistics.money_earned,
+        session_statistics.get_per_hour(session_statistics.money_earned as f64)
+    )?;
+
+    for &(npc_id, count) in session_statistics.kills.iter() {
+        let name = game_data
+            .npcs
+            .get_npc(npc_id)
+            .map_or("???", |npc_data| npc_data.name);
+        writeln!(
+            csv,
+            "kill,{},{},{:.1}",
+            csv_escape(name),
+            count,
+            session_statistics.get_per_hour(count as f64)
+        )?;
+    }
+
+    for &(item_reference, count) in session_statistics.items_looted.iter() {
+        let name = game_data
+            .items
+            .get_base_item(item_reference)
+            .map_or("???", |item_data| item_data.name);
+        writeln!(
+            csv,
+            "item,{},{},{:.1}",
+            csv_escape(name),
+            count,
+            session_statistics.get_per_hour(count as f64)
+        )?;
+    }
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(&path, csv)?;
+    Ok(path)
+}
+
+pub fn ui_session_statistics_system(
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiStateSessionStatistics>,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    mut session_statistics: ResMut<SessionStatistics>,
+    game_data: Res<GameData>,
+) {
+    if !ui_state_windows.session_statistics_open {
+        return;
+    }
+
+    let mut reset = false;
+    let mut export = false;
+
+    egui::Window::new(localize!("session-statistics-title"))
+        .id(egui::Id::new("session_statistics"))
+        .open(&mut ui_state_windows.session_statistics_open)
+        .resizable(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            let elapsed_minutes = (session_statistics.get_elapsed_hours() * 60.0) as u64;
+
+            egui::Grid::new("session_statistics_summary")
+                .num_columns(3)
+                .show(ui, |ui| {
+                    ui.label(localize!("session-statistics-time"));
+                    ui.label(localize!(
+                        "session-statistics-duration",
+                        hours = elapsed_minutes / 60,
+                        minutes = format!("{:02}", elapsed_minutes % 60),
+                    ));
+                    ui.label("");
+                    ui.end_row();
+
+                    ui.label(localize!("session-statistics-xp"));
+                    ui.label(format!("{}", session_statistics.xp_earned));
+                    ui.label(localize!(
+                        "session-statistics-per-hour",
+                        value = format!(
+                            "{:.0}",
+                            session_statistics.get_per_hour(session_statistics.xp_earned as f64)
+                        ),
+                    ));
+                    ui.end_row();
+
+                    ui.label(localize!("session-statistics-money"));
+                    ui.label(format!("{}", session_statistics.money_earned));
+                    ui.label(localize!(
+                        "session-statistics-per-hour",
+                        value = format!(
+                            "{:.0}",
+                            session_statistics.get_per_hour(session_statistics.money_earned as f64)
+                        ),
+                    ));
+                    ui.end_row();
+
+                    let total_kills = session_statistics.get_total_kills();
+                    ui.label(localize!("session-statistics-kills"));
+                    ui.label(format!("{}", total_kills));
+                    ui.label(localize!(
+                        "session-statistics-per-hour",
+                        value =
+                            format!("{:.0}", session_statistics.get_per_hour(total_kills as f64)),
+                    ));
+                    ui.end_row();
+                });
+
+            egui::CollapsingHeader::new(localize!("session-statistics-kills-by-monster"))
+                .id_source("session_statistics_kills_by_monster")
+                .default_open(true)
+                .show(ui, |ui| {
+                    egui::Grid::new("session_statistics_kills")
+                        .num_columns(2)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for &(npc_id, count) in session_statistics.kills.iter() {
+                                ui.label(
+                                    game_data
+                                        .npcs
+                                        .get_npc(npc_id)
+                                        .map_or("???", |npc_data| npc_data.name),
+                                );
+                                ui.label(format!("{}", count));
+                                ui.end_row();
+                            }
+                        });
+                });
+
+            egui::CollapsingHeader::new(localize!("session-statistics-items-looted"))
+                .id_source("session_statistics_items_looted")
+                .default_open(true)
+                .show(ui, |ui| {
+                    egui::Grid::new("session_statistics_items")
+                        .num_columns(2)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for &(item_reference, count) in session_statistics.items_looted.iter() {
+                                ui.label(
+                                    game_data
+                                        .items
+                                        .get_base_item(item_reference)
+                                        .map_or("???", |item_data| item_data.name),
+                                );
+                                ui.label(format!("{}", count));
+                                ui.end_row();
+                            }
+                        });
+                });
+
+            ui.separator();
+            ui.horizontal(|ui| {
+                if ui.button(localize!("session-statistics-reset")).clicked() {
+                    reset = true;
+                }
+
+                if ui.button(localize!("session-statistics-export")).clicked() {
+                    export = true;
+                }
+            });
+
+            if let Some(export_result) = ui_state.export_result.as_ref() {
+                ui.label(export_result);
+            }
+        });
+
+    if export {
+        ui_state.export_result = Some(match export_csv(&session_statistics, &game_data) {
+            Ok(path) => localize!(
+                "session-statistics-export-succeeded",
+                path = path.to_string_lossy().into_owned()
+            ),
+            Err(error) => localize!(
+                "session-statistics-export-failed",
+                error = error.to_string()
+            ),
+        });
+    }
+
+    if reset {
+        *session_statistics = SessionStatistics::default();
+        ui_state.export_result = None;
+    }
+}
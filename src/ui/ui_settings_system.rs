@@ -1,89 +1,1226 @@
-use bevy::prelude::{Local, Query, ResMut};
+use std::time::Duration;
+
+use bevy::{
+    ecs::system::SystemParam,
+    input::Input,
+    prelude::{EventWriter, KeyCode, Query, Res, ResMut, Resource},
+};
 use bevy_egui::{egui, EguiContexts};
 
+use rose_data::SoundId;
+
 use crate::{
-    audio::SoundGain, components::SoundCategory, resources::SoundSettings, ui::UiStateWindows,
+    audio::{AvailableAudioDevices, SoundGain},
+    components::{NameTagType, SoundCategory},
+    localize,
+    resources::{
+        AccessibilitySettings, AfkSettings, AntiAliasing, AutoLootSettings, AvailableMonitors,
+        CooldownAlertSettings, DamageDigitType, DamageDigitsSpawner, GraphicsBenchmark,
+        GraphicsQuality, GraphicsQualityPreset, HudLayout, InputAction, InputBindings,
+        InputContext, ItemGradeEffectSettings, KeyBinding, LevelUpSettings, MinimizeOnFocusLoss,
+        MonitorVideoMode, NameTagSettings, ShadowQuality, SoundSettings, StatusEffectTextType,
+        TextureQuality, UserSettings, UserSettingsStore, WindowModeSetting, WindowSettings,
+        INPUT_BINDINGS_PER_ACTION,
+    },
+    text_to_speech::TextToSpeechChannel,
+    ui::{UiSoundEvent, UiStateWindows},
 };
 
 #[derive(Copy, Clone, PartialEq, Debug)]
 enum SettingsPage {
     Sound,
+    Gameplay,
+    Graphics,
+    CombatText,
+    Accessibility,
+    Controls,
 }
 
+#[derive(Resource)]
 pub struct UiStateSettings {
     page: SettingsPage,
+    audio_devices_requested: bool,
+    controls_context: InputContext,
+    rebinding: Option<(InputAction, usize)>,
+    cheat_sheet_result: Option<String>,
 }
 
 impl Default for UiStateSettings {
     fn default() -> Self {
         Self {
             page: SettingsPage::Sound,
+            audio_devices_requested: false,
+            controls_context: InputContext::General,
+            rebinding: None,
+            cheat_sheet_result: None,
         }
     }
 }
 
+impl UiStateSettings {
+    /// Whether a key is being captured to rebind an action.
+    pub fn is_rebinding(&self) -> bool {
+        self.rebinding.is_some()
+    }
+}
+
+fn graphics_quality_preset_name(preset: GraphicsQualityPreset) -> String {
+    match preset {
+        GraphicsQualityPreset::Low => localize!("settings-quality-low"),
+        GraphicsQualityPreset::Medium => localize!("settings-quality-medium"),
+        GraphicsQualityPreset::High => localize!("settings-quality-high"),
+        GraphicsQualityPreset::Ultra => localize!("settings-quality-ultra"),
+        GraphicsQualityPreset::Custom => localize!("settings-quality-custom"),
+    }
+}
+
+fn shadow_quality_name(shadow_quality: ShadowQuality) -> String {
+    match shadow_quality {
+        ShadowQuality::Off => localize!("settings-off"),
+        ShadowQuality::Low => localize!("settings-quality-low"),
+        ShadowQuality::Medium => localize!("settings-quality-medium"),
+        ShadowQuality::High => localize!("settings-quality-high"),
+    }
+}
+
+fn texture_quality_name(texture_quality: TextureQuality) -> String {
+    match texture_quality {
+        TextureQuality::Low => localize!("settings-quality-low"),
+        TextureQuality::Medium => localize!("settings-quality-medium"),
+        TextureQuality::High => localize!("settings-quality-high"),
+        TextureQuality::Ultra => localize!("settings-quality-ultra"),
+    }
+}
+
+fn anti_aliasing_name(anti_aliasing: AntiAliasing) -> String {
+    match anti_aliasing {
+        AntiAliasing::Off => localize!("settings-off"),
+        AntiAliasing::Msaa2 => localize!("settings-msaa-2x"),
+        AntiAliasing::Msaa4 => localize!("settings-msaa-4x"),
+    }
+}
+
+fn window_mode_name(mode: WindowModeSetting) -> String {
+    match mode {
+        WindowModeSetting::Windowed => localize!("settings-window-mode-windowed"),
+        WindowModeSetting::Borderless => localize!("settings-window-mode-borderless"),
+        WindowModeSetting::Exclusive => localize!("settings-window-mode-exclusive"),
+    }
+}
+
+fn minimize_on_focus_loss_name(minimize_on_focus_loss: MinimizeOnFocusLoss) -> String {
+    match minimize_on_focus_loss {
+        MinimizeOnFocusLoss::Never => localize!("settings-minimize-never"),
+        MinimizeOnFocusLoss::ExclusiveFullscreen => localize!("settings-window-mode-exclusive"),
+        MinimizeOnFocusLoss::AnyFullscreen => localize!("settings-minimize-any-fullscreen"),
+    }
+}
+
+fn video_mode_name(video_mode: Option<MonitorVideoMode>) -> String {
+    match video_mode {
+        Some(video_mode) if video_mode.refresh_rate != 0 => format!(
+            "{}x{} @ {}Hz",
+            video_mode.width, video_mode.height, video_mode.refresh_rate
+        ),
+        Some(video_mode) => format!("{}x{}", video_mode.width, video_mode.height),
+        None => localize!("settings-resolution-native"),
+    }
+}
+
+fn ui_window_settings(
+    ui: &mut egui::Ui,
+    window_settings: &mut WindowSettings,
+    available_monitors: &AvailableMonitors,
+) {
+    let mut settings = window_settings.clone();
+
+    egui::Grid::new("graphics_settings_window")
+        .num_columns(2)
+        .show(ui, |ui| {
+            ui.label(localize!("settings-window-mode"));
+            egui::ComboBox::from_id_source("graphics_settings_window_mode")
+                .selected_text(window_mode_name(settings.mode))
+                .show_ui(ui, |ui| {
+                    for value in [
+                        WindowModeSetting::Windowed,
+                        WindowModeSetting::Borderless,
+                        WindowModeSetting::Exclusive,
+                    ] {
+                        ui.selectable_value(&mut settings.mode, value, window_mode_name(value));
+                    }
+                });
+            ui.end_row();
+
+            ui.label(localize!("settings-monitor"));
+            let monitor_name = |monitor: Option<usize>| match monitor {
+                Some(index) => available_monitors.monitors.get(index).map_or_else(
+                    || localize!("settings-monitor-index", index = index + 1),
+                    |monitor| monitor.name.clone(),
+                ),
+                None => localize!("settings-monitor-automatic"),
+            };
+            egui::ComboBox::from_id_source("graphics_settings_monitor")
+                .selected_text(monitor_name(settings.monitor))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut settings.monitor, None, monitor_name(None));
+                    for index in 0..available_monitors.monitors.len() {
+                        ui.selectable_value(
+                            &mut settings.monitor,
+                            Some(index),
+                            monitor_name(Some(index)),
+                        );
+                    }
+                });
+            ui.end_row();
+
+            if settings.mode == WindowModeSetting::Exclusive {
+                ui.label(localize!("settings-resolution"));
+                let video_modes = available_monitors
+                    .monitors
+                    .get(settings.monitor.unwrap_or(0))
+                    .map_or(&[][..], |monitor| &monitor.video_modes);
+                egui::ComboBox::from_id_source("graphics_settings_video_mode")
+                    .selected_text(video_mode_name(settings.exclusive_video_mode))
+                    .show_ui(ui, |ui| {
+                        ui.selectable_value(
+                            &mut settings.exclusive_video_mode,
+                            None,
+                            video_mode_name(None),
+                        );
+                        for video_mode in video_modes.iter() {
+                            ui.selectable_value(
+                                &mut settings.exclusive_video_mode,
+                                Some(*video_mode),
+                                video_mode_name(Some(*video_mode)),
+                            );
+                        }
+                    });
+                ui.end_row();
+            }
+
+            ui.label(localize!("settings-minimize-on-focus-loss"));
+            egui::ComboBox::from_id_source("graphics_settings_minimize_on_focus_loss")
+                .selected_text(minimize_on_focus_loss_name(settings.minimize_on_focus_loss))
+                .show_ui(ui, |ui| {
+                    for value in [
+                        MinimizeOnFocusLoss::Never,
+                        MinimizeOnFocusLoss::ExclusiveFullscreen,
+                        MinimizeOnFocusLoss::AnyFullscreen,
+                    ] {
+                        ui.selectable_value(
+                            &mut settings.minimize_on_focus_loss,
+                            value,
+                            minimize_on_focus_loss_name(value),
+                        );
+                    }
+                });
+            ui.end_row();
+
+            ui.label(localize!("settings-vsync"));
+            ui.checkbox(&mut settings.vsync, localize!("settings-enabled"));
+            ui.end_row();
+        });
+
+    if settings != *window_settings {
+        *window_settings = settings;
+    }
+}
+
+fn ui_graphics_quality(
+    ui: &mut egui::Ui,
+    graphics_quality: &mut GraphicsQuality,
+    graphics_benchmark: &mut GraphicsBenchmark,
+) {
+    ui.add_enabled_ui(!graphics_benchmark.is_running(), |ui| {
+        egui::Grid::new("graphics_settings_quality")
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label(localize!("settings-quality-preset"));
+                let mut preset = graphics_quality.preset;
+                egui::ComboBox::from_id_source("graphics_settings_quality_preset")
+                    .selected_text(graphics_quality_preset_name(preset))
+                    .show_ui(ui, |ui| {
+                        for value in GraphicsQualityPreset::PRESETS {
+                            ui.selectable_value(
+                                &mut preset,
+                                value,
+                                graphics_quality_preset_name(value),
+                            );
+                        }
+                    });
+                if preset != graphics_quality.preset {
+                    graphics_quality.set_preset(preset);
+                }
+                ui.end_row();
+
+                let mut settings = graphics_quality.settings.clone();
+
+                ui.label(localize!("settings-shadows"));
+                egui::ComboBox::from_id_source("graphics_settings_shadow_quality")
+                    .selected_text(shadow_quality_name(settings.shadow_quality))
+                    .show_ui(ui, |ui| {
+                        for value in [
+                            ShadowQuality::Off,
+                            ShadowQuality::Low,
+                            ShadowQuality::Medium,
+                            ShadowQuality::High,
+                        ] {
+                            ui.selectable_value(
+                                &mut settings.shadow_quality,
+                                value,
+                                shadow_quality_name(value),
+                            );
+                        }
+                    });
+                ui.end_row();
+
+                ui.label(localize!("settings-draw-distance"));
+                ui.add(
+                    egui::Slider::new(&mut settings.draw_distance, 100.0..=3000.0).show_value(true),
+                );
+                ui.end_row();
+
+                ui.label(localize!("settings-particle-density"));
+                ui.add(
+                    egui::Slider::new(&mut settings.particle_density, 0.1..=1.0).show_value(true),
+                );
+                ui.end_row();
+
+                ui.label(localize!("settings-texture-quality"));
+                ui.horizontal(|ui| {
+                    egui::ComboBox::from_id_source("graphics_settings_texture_quality")
+                        .selected_text(texture_quality_name(settings.texture_quality))
+                        .show_ui(ui, |ui| {
+                            for value in [
+                                TextureQuality::Low,
+                                TextureQuality::Medium,
+                                TextureQuality::High,
+                                TextureQuality::Ultra,
+                            ] {
+                                ui.selectable_value(
+                                    &mut settings.texture_quality,
+                                    value,
+                                    texture_quality_name(value),
+                                );
+                            }
+                        });
+                    ui.label(localize!("settings-applies-after-restart"));
+                });
+                ui.end_row();
+
+                ui.label(localize!("settings-anti-aliasing"));
+                egui::ComboBox::from_id_source("graphics_settings_anti_aliasing")
+                    .selected_text(anti_aliasing_name(settings.anti_aliasing))
+                    .show_ui(ui, |ui| {
+                        for value in [AntiAliasing::Off, AntiAliasing::Msaa2, AntiAliasing::Msaa4] {
+                            ui.selectable_value(
+                                &mut settings.anti_aliasing,
+                                value,
+                                anti_aliasing_name(value),
+                            );
+                        }
+                    });
+                ui.end_row();
+
+                if settings != graphics_quality.settings {
+                    graphics_quality.preset = GraphicsQualityPreset::from_settings(&settings);
+                    graphics_quality.settings = settings;
+                }
+            });
+    });
+
+    ui.horizontal(|ui| {
+        if ui
+            .add_enabled(
+                !graphics_benchmark.is_running(),
+                egui::Button::new(localize!("settings-benchmark-presets")),
+            )
+            .clicked()
+        {
+            graphics_benchmark.start(graphics_quality);
+        }
+
+        if let Some(preset) = graphics_benchmark.current_preset() {
+            ui.label(localize!(
+                "settings-benchmark-measuring",
+                preset = graphics_quality_preset_name(preset)
+            ));
+        }
+    });
+
+    if !graphics_benchmark.results.is_empty() {
+        egui::Grid::new("graphics_settings_benchmark_results")
+            .num_columns(3)
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label(localize!("settings-benchmark-preset"));
+                ui.label(localize!("settings-benchmark-frame-time"));
+                ui.label(localize!("settings-benchmark-fps"));
+                ui.end_row();
+
+                for (preset, frame_time) in graphics_benchmark.results.iter() {
+                    let frame_time_ms = frame_time.as_secs_f64() * 1000.0;
+                    ui.label(graphics_quality_preset_name(*preset));
+                    ui.label(format!("{:.2} ms", frame_time_ms));
+                    ui.label(format!("{:.1}", 1000.0 / frame_time_ms.max(0.001)));
+                    ui.end_row();
+                }
+            });
+    }
+}
+
+const CONTROLS_CONTEXTS: [InputContext; 6] = [
+    InputContext::General,
+    InputContext::Combat,
+    InputContext::Vehicle,
+    InputContext::FreeCamera,
+    InputContext::Ui,
+    InputContext::Debug,
+];
+
+fn ui_controls_settings(
+    ui: &mut egui::Ui,
+    ui_state_settings: &mut UiStateSettings,
+    input_bindings: &mut InputBindings,
+    keyboard_input: &Input<KeyCode>,
+) {
+    ui.horizontal(|ui| {
+        for context in CONTROLS_CONTEXTS {
+            ui.selectable_value(
+                &mut ui_state_settings.controls_context,
+                context,
+                context.name(),
+            );
+        }
+    });
+    ui.separator();
+
+    let mut captured = false;
+    if let Some((action, index)) = ui_state_settings.rebinding {
+        if keyboard_input.just_pressed(KeyCode::Escape) {
+            ui_state_settings.rebinding = None;
+        } else if let Some(binding) = KeyBinding::from_input(keyboard_input) {
+            input_bindings.bindings[action][index] = Some(binding);
+            ui_state_settings.rebinding = None;
+            captured = true;
+        }
+    }
+
+    let context = ui_state_settings.controls_context;
+    let mut clear_binding = None;
+    let mut resolve_action = None;
+
+    egui::Grid::new("controls_settings")
+        .num_columns(2 + INPUT_BINDINGS_PER_ACTION)
+        .striped(true)
+        .show(ui, |ui| {
+            for (action, bindings) in input_bindings
+                .bindings
+                .iter()
+                .filter(|(action, _)| action.context() == context)
+            {
+                ui.label(action.name());
+
+                for (index, binding) in bindings.iter().enumerate() {
+                    let waiting = ui_state_settings.rebinding == Some((action, index));
+                    let text = if waiting {
+                        localize!("settings-controls-press-key")
+                    } else {
+                        binding.map_or_else(|| "-".to_string(), |binding| binding.to_string())
+                    };
+
+                    let response = ui
+                        .add(egui::Button::new(text).min_size(egui::vec2(100.0, 0.0)))
+                        .on_hover_text(localize!("settings-controls-binding-tooltip"));
+
+                    if waiting {
+                        // While focused the key is not also handled as a game hotkey
+                        response.request_focus();
+                    }
+
+                    if response.clicked() && !captured {
+                        ui_state_settings.rebinding = (!waiting).then_some((action, index));
+                    } else if response.secondary_clicked() {
+                        ui_state_settings.rebinding = None;
+                        clear_binding = Some((action, index));
+                    }
+                }
+
+                let conflicts: Vec<String> = bindings
+                    .iter()
+                    .flatten()
+                    .flat_map(|binding| input_bindings.find_conflicts(action, binding))
+                    .map(|conflict| conflict.name())
+                    .collect();
+                if conflicts.is_empty() {
+                    ui.label("");
+                } else {
+                    ui.horizontal(|ui| {
+                        ui.colored_label(
+                            egui::Color32::RED,
+                            localize!("settings-controls-conflict", actions = conflicts.join(", ")),
+                        );
+
+                        if ui
+                            .small_button(localize!("settings-controls-resolve"))
+                            .on_hover_text(localize!("settings-controls-resolve-tooltip"))
+                            .clicked()
+                        {
+                            resolve_action = Some(action);
+                        }
+                    });
+                }
+                ui.end_row();
+            }
+        });
+
+    if let Some((action, index)) = clear_binding {
+        input_bindings.bindings[action][index] = None;
+    }
+
+    if let Some(action) = resolve_action {
+        for binding in input_bindings.bindings[action].into_iter().flatten() {
+            input_bindings.unbind_conflicts(action, &binding);
+        }
+    }
+
+    ui.separator();
+    ui.horizontal(|ui| {
+        if ui.button(localize!("settings-controls-reset")).clicked() {
+            input_bindings.bindings = InputBindings::default_bindings();
+            ui_state_settings.rebinding = None;
+        }
+
+        if ui
+            .button(localize!("settings-controls-export-cheat-sheet"))
+            .clicked()
+        {
+            ui_state_settings.cheat_sheet_result =
+                Some(match input_bindings.export_cheat_sheet() {
+                    Ok(path) => localize!(
+                        "settings-controls-cheat-sheet-exported",
+                        path = path.to_string_lossy().to_string()
+                    ),
+                    Err(error) => localize!(
+                        "settings-controls-cheat-sheet-failed",
+                        error = error.to_string()
+                    ),
+                });
+        }
+    });
+
+    if let Some(result) = ui_state_settings.cheat_sheet_result.as_ref() {
+        ui.label(result);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+#[derive(SystemParam)]
+pub struct GameplaySettingsResources<'w, 's> {
+    pub auto_loot_settings: ResMut<'w, AutoLootSettings>,
+    pub afk_settings: ResMut<'w, AfkSettings>,
+    pub level_up_settings: ResMut<'w, LevelUpSettings>,
+    pub cooldown_alert_settings: ResMut<'w, CooldownAlertSettings>,
+    pub hud_layout: ResMut<'w, HudLayout>,
+    pub input_bindings: ResMut<'w, InputBindings>,
+    pub keyboard_input: Res<'w, Input<KeyCode>>,
+    pub ui_sound_events: EventWriter<'w, UiSoundEvent>,
+
+    #[system_param(ignore)]
+    pub phantom: std::marker::PhantomData<&'s ()>,
+}
+
 pub fn ui_settings_system(
     mut egui_context: EguiContexts,
     mut ui_state_windows: ResMut<UiStateWindows>,
-    mut ui_state_settings: Local<UiStateSettings>,
+    mut ui_state_settings: ResMut<UiStateSettings>,
     mut sound_settings: ResMut<SoundSettings>,
+    gameplay_settings: GameplaySettingsResources,
+    mut item_grade_effect_settings: ResMut<ItemGradeEffectSettings>,
+    mut graphics_quality: ResMut<GraphicsQuality>,
+    mut graphics_benchmark: ResMut<GraphicsBenchmark>,
+    mut window_settings: ResMut<WindowSettings>,
+    available_monitors: Res<AvailableMonitors>,
+    mut name_tag_settings: ResMut<NameTagSettings>,
+    mut damage_digits_spawner: Option<ResMut<DamageDigitsSpawner>>,
+    mut accessibility_settings: ResMut<AccessibilitySettings>,
+    mut user_settings_store: ResMut<UserSettingsStore>,
     mut query_sounds: Query<(&SoundCategory, &mut SoundGain)>,
+    mut available_audio_devices: ResMut<AvailableAudioDevices>,
 ) {
-    egui::Window::new("Settings")
+    if !ui_state_windows.settings_open {
+        // Stop capturing a key for rebinding when the window is closed
+        if ui_state_settings.is_rebinding() {
+            ui_state_settings.rebinding = None;
+        }
+        return;
+    }
+
+    let GameplaySettingsResources {
+        mut auto_loot_settings,
+        mut afk_settings,
+        mut level_up_settings,
+        mut cooldown_alert_settings,
+        mut hud_layout,
+        mut input_bindings,
+        keyboard_input,
+        mut ui_sound_events,
+        ..
+    } = gameplay_settings;
+
+    let mut edit_hud_layout = false;
+    egui::Window::new(localize!("settings-title"))
         .open(&mut ui_state_windows.settings_open)
         .resizable(false)
         .show(egui_context.ctx_mut(), |ui| {
             ui.horizontal(|ui| {
-                ui.selectable_value(&mut ui_state_settings.page, SettingsPage::Sound, "Sound");
+                ui.selectable_value(
+                    &mut ui_state_settings.page,
+                    SettingsPage::Sound,
+                    localize!("settings-page-sound"),
+                );
+                ui.selectable_value(
+                    &mut ui_state_settings.page,
+                    SettingsPage::Gameplay,
+                    localize!("settings-page-gameplay"),
+                );
+                ui.selectable_value(
+                    &mut ui_state_settings.page,
+                    SettingsPage::Graphics,
+                    localize!("settings-page-graphics"),
+                );
+                ui.selectable_value(
+                    &mut ui_state_settings.page,
+                    SettingsPage::CombatText,
+                    localize!("settings-page-combat-text"),
+                );
+                ui.selectable_value(
+                    &mut ui_state_settings.page,
+                    SettingsPage::Accessibility,
+                    localize!("settings-page-accessibility"),
+                );
+                ui.selectable_value(
+                    &mut ui_state_settings.page,
+                    SettingsPage::Controls,
+                    localize!("settings-page-controls"),
+                );
             });
 
-            egui::Grid::new("sound_settings_gain")
-                .num_columns(2)
-                .show(ui, |ui| {
-                    let mut gain_changed = false;
+            if ui_state_settings.page != SettingsPage::Controls && ui_state_settings.is_rebinding()
+            {
+                ui_state_settings.rebinding = None;
+            }
 
-                    ui.label("Sound:");
-                    gain_changed |= ui
-                        .checkbox(&mut sound_settings.enabled, "Enabled")
-                        .changed();
-                    ui.end_row();
+            match ui_state_settings.page {
+                SettingsPage::Sound => {
+                    egui::Grid::new("sound_settings_gain")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            let mut gain_changed = false;
+
+                            ui.label(localize!("settings-sound"));
+                            gain_changed |= ui
+                                .checkbox(
+                                    &mut sound_settings.enabled,
+                                    localize!("settings-enabled"),
+                                )
+                                .changed();
+                            ui.end_row();
+
+                            ui.label(localize!("settings-output-device"));
+                            if !ui_state_settings.audio_devices_requested {
+                                ui_state_settings.audio_devices_requested = true;
+                                available_audio_devices.refresh = true;
+                            }
+                            ui.horizontal(|ui| {
+                                let device_name = |device: Option<&String>| {
+                                    device.map_or_else(
+                                        || localize!("settings-output-device-default"),
+                                        |device| device.clone(),
+                                    )
+                                };
+                                egui::ComboBox::from_id_source("sound_settings_output_device")
+                                    .selected_text(device_name(
+                                        sound_settings.output_device.as_ref(),
+                                    ))
+                                    .show_ui(ui, |ui| {
+                                        ui.selectable_value(
+                                            &mut sound_settings.output_device,
+                                            None,
+                                            device_name(None),
+                                        );
+                                        for device in available_audio_devices.output_devices.iter()
+                                        {
+                                            ui.selectable_value(
+                                                &mut sound_settings.output_device,
+                                                Some(device.clone()),
+                                                device_name(Some(device)),
+                                            );
+                                        }
+                                    });
+
+                                if ui
+                                    .button(localize!("settings-output-device-refresh"))
+                                    .clicked()
+                                {
+                                    available_audio_devices.refresh = true;
+                                }
+                            });
+                            ui.end_row();
 
-                    ui.label("Global Volume:");
-                    gain_changed |= ui
-                        .add(
-                            egui::Slider::new(&mut sound_settings.global_gain, 0.0..=1.0)
+                            ui.label(localize!("settings-global-volume"));
+                            gain_changed |= ui
+                                .add(
+                                    egui::Slider::new(&mut sound_settings.global_gain, 0.0..=1.0)
+                                        .show_value(true),
+                                )
+                                .changed();
+                            ui.end_row();
+
+                            let mut add_category_slider = |text: String, category| {
+                                ui.label(text);
+                                gain_changed |= ui
+                                    .add(
+                                        egui::Slider::new(
+                                            &mut sound_settings.gains[category],
+                                            0.0..=1.0,
+                                        )
+                                        .show_value(true),
+                                    )
+                                    .changed();
+                                ui.end_row();
+                            };
+
+                            add_category_slider(
+                                localize!("settings-background-music"),
+                                SoundCategory::BackgroundMusic,
+                            );
+                            add_category_slider(
+                                localize!("settings-player-footsteps"),
+                                SoundCategory::PlayerFootstep,
+                            );
+                            add_category_slider(
+                                localize!("settings-other-footsteps"),
+                                SoundCategory::OtherFootstep,
+                            );
+                            add_category_slider(
+                                localize!("settings-player-combat"),
+                                SoundCategory::PlayerCombat,
+                            );
+                            add_category_slider(
+                                localize!("settings-other-combat"),
+                                SoundCategory::OtherCombat,
+                            );
+                            add_category_slider(
+                                localize!("settings-npc-sounds"),
+                                SoundCategory::NpcSounds,
+                            );
+
+                            if gain_changed {
+                                for (category, mut gain) in query_sounds.iter_mut() {
+                                    let target_gain = sound_settings.gain(*category);
+
+                                    if target_gain != *gain {
+                                        *gain = target_gain;
+                                    }
+                                }
+                            }
+                        });
+                }
+                SettingsPage::Gameplay => {
+                    egui::Grid::new("gameplay_settings_auto_loot")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label(localize!("settings-auto-loot"));
+                            ui.checkbox(
+                                &mut auto_loot_settings.enabled,
+                                localize!("settings-enabled"),
+                            );
+                            ui.end_row();
+
+                            ui.label(localize!("settings-loot-radius"));
+                            ui.add(
+                                egui::Slider::new(&mut auto_loot_settings.radius, 100.0..=2000.0)
+                                    .show_value(true),
+                            );
+                            ui.end_row();
+
+                            ui.label(localize!("settings-loot-min-item-quality"));
+                            ui.add(
+                                egui::Slider::new(
+                                    &mut auto_loot_settings.min_item_quality,
+                                    0..=100,
+                                )
                                 .show_value(true),
-                        )
-                        .changed();
-                    ui.end_row();
+                            );
+                            ui.end_row();
+
+                            ui.label(localize!("settings-loot-pickup"));
+                            ui.vertical(|ui| {
+                                ui.checkbox(
+                                    &mut auto_loot_settings.pickup_money,
+                                    localize!("settings-loot-money"),
+                                );
+                                ui.checkbox(
+                                    &mut auto_loot_settings.pickup_equipment,
+                                    localize!("settings-loot-equipment"),
+                                );
+                                ui.checkbox(
+                                    &mut auto_loot_settings.pickup_consumables,
+                                    localize!("settings-loot-consumables"),
+                                );
+                                ui.checkbox(
+                                    &mut auto_loot_settings.pickup_materials,
+                                    localize!("settings-loot-materials"),
+                                );
+                            });
+                            ui.end_row();
+                        });
+
+                    ui.label(localize!("settings-loot-hint"));
+                    ui.separator();
+
+                    egui::Grid::new("gameplay_settings_afk")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label(localize!("settings-auto-away"));
+                            ui.checkbox(
+                                &mut afk_settings.auto_away_enabled,
+                                localize!("settings-enabled"),
+                            );
+                            ui.end_row();
+
+                            ui.label(localize!("settings-auto-away-timeout"));
+                            let mut timeout_minutes = afk_settings.auto_away_timeout.as_secs() / 60;
+                            if ui
+                                .add(
+                                    egui::Slider::new(&mut timeout_minutes, 1..=60)
+                                        .show_value(true),
+                                )
+                                .changed()
+                            {
+                                afk_settings.auto_away_timeout =
+                                    Duration::from_secs(timeout_minutes * 60);
+                            }
+                            ui.end_row();
+
+                            ui.label(localize!("settings-while-away"));
+                            ui.checkbox(
+                                &mut afk_settings.auto_reply_enabled,
+                                localize!("settings-auto-reply"),
+                            );
+                            ui.end_row();
 
-                    let mut add_category_slider = |text: &str, category| {
-                        ui.label(text);
-                        gain_changed |= ui
-                            .add(
-                                egui::Slider::new(&mut sound_settings.gains[category], 0.0..=1.0)
+                            ui.label(localize!("settings-while-unfocused"));
+                            ui.vertical(|ui| {
+                                ui.checkbox(
+                                    &mut afk_settings.low_power_when_unfocused,
+                                    localize!("settings-low-power-unfocused"),
+                                );
+                                ui.checkbox(
+                                    &mut afk_settings.mute_when_unfocused,
+                                    localize!("settings-mute-unfocused"),
+                                );
+                            });
+                            ui.end_row();
+                        });
+
+                    ui.label(localize!("settings-afk-hint"));
+                    ui.separator();
+
+                    egui::Grid::new("gameplay_settings_level_up")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label(localize!("settings-level-up"));
+                            ui.vertical(|ui| {
+                                ui.checkbox(
+                                    &mut level_up_settings.effect,
+                                    localize!("settings-level-up-effect"),
+                                );
+                                ui.checkbox(
+                                    &mut level_up_settings.fanfare,
+                                    localize!("settings-level-up-fanfare"),
+                                );
+                                ui.checkbox(
+                                    &mut level_up_settings.screen_flash,
+                                    localize!("settings-level-up-screen-flash"),
+                                );
+                                ui.checkbox(
+                                    &mut level_up_settings.banner,
+                                    localize!("settings-level-up-banner"),
+                                );
+                            });
+                            ui.end_row();
+                        });
+                    ui.separator();
+
+                    egui::Grid::new("gameplay_settings_cooldown_alerts")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label(localize!("settings-cooldown-alerts"));
+                            ui.vertical(|ui| {
+                                ui.checkbox(
+                                    &mut cooldown_alert_settings.hotbar_pulse,
+                                    localize!("settings-cooldown-alerts-hotbar-pulse"),
+                                );
+                                ui.checkbox(
+                                    &mut cooldown_alert_settings.toast,
+                                    localize!("settings-cooldown-alerts-toast"),
+                                );
+                                ui.horizontal(|ui| {
+                                    ui.checkbox(
+                                        &mut cooldown_alert_settings.sound,
+                                        localize!("settings-cooldown-alerts-sound"),
+                                    );
+                                    ui.add_enabled(
+                                        cooldown_alert_settings.sound,
+                                        egui::DragValue::new(&mut cooldown_alert_settings.sound_id)
+                                            .clamp_range(1..=u16::MAX),
+                                    )
+                                    .on_hover_text(localize!("settings-cooldown-alerts-sound-id"));
+
+                                    if ui
+                                        .add_enabled(
+                                            cooldown_alert_settings.sound,
+                                            egui::Button::new(localize!(
+                                                "settings-cooldown-alerts-test"
+                                            )),
+                                        )
+                                        .clicked()
+                                    {
+                                        if let Some(sound_id) =
+                                            SoundId::new(cooldown_alert_settings.sound_id)
+                                        {
+                                            ui_sound_events.send(UiSoundEvent::new(sound_id));
+                                        }
+                                    }
+                                });
+                            });
+                            ui.end_row();
+
+                            ui.label(localize!("settings-cooldown-alerts-warning"));
+                            let mut warning_secs =
+                                cooldown_alert_settings.status_effect_warning.as_secs();
+                            if ui
+                                .add(egui::Slider::new(&mut warning_secs, 1..=60).show_value(true))
+                                .changed()
+                            {
+                                cooldown_alert_settings.status_effect_warning =
+                                    Duration::from_secs(warning_secs);
+                            }
+                            ui.end_row();
+                        });
+                    ui.label(
+                        egui::RichText::new(localize!("settings-cooldown-alerts-hint")).weak(),
+                    );
+                    ui.separator();
+
+                    egui::Grid::new("gameplay_settings_hud_layout")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label(localize!("settings-hud-layout"));
+                            edit_hud_layout = ui.button(localize!("settings-hud-edit")).clicked();
+                            ui.end_row();
+                        });
+                }
+                SettingsPage::Graphics => {
+                    // TODO: Render scale, Bevy 0.11 cameras cannot render at a different
+                    // resolution to the window so it needs an offscreen target and upscale pass
+                    ui_window_settings(ui, &mut window_settings, &available_monitors);
+                    ui.separator();
+
+                    ui_graphics_quality(ui, &mut graphics_quality, &mut graphics_benchmark);
+                    ui.separator();
+
+                    egui::Grid::new("graphics_settings_item_grade")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label(localize!("settings-item-grade-effects"));
+                            let mut intensity = item_grade_effect_settings.intensity;
+                            if ui
+                                .add(egui::Slider::new(&mut intensity, 0.0..=2.0).show_value(true))
+                                .changed()
+                            {
+                                item_grade_effect_settings.intensity = intensity;
+                            }
+                            ui.end_row();
+
+                            ui.label(localize!("settings-item-drop-effects"));
+                            let mut item_drop_effects =
+                                item_grade_effect_settings.item_drop_effects;
+                            if ui
+                                .checkbox(&mut item_drop_effects, localize!("settings-enabled"))
+                                .changed()
+                            {
+                                item_grade_effect_settings.item_drop_effects = item_drop_effects;
+                            }
+                            ui.end_row();
+
+                            // Also on the accessibility page, both change the same setting
+                            ui.label(localize!("settings-bloom-intensity"));
+                            ui.add(
+                                egui::Slider::new(
+                                    &mut accessibility_settings.bloom_intensity,
+                                    0.0..=1.0,
+                                )
+                                .show_value(true),
+                            );
+                            ui.end_row();
+                        });
+
+                    ui.label(localize!("settings-item-grade-effects-hint"));
+                    ui.separator();
+
+                    egui::Grid::new("graphics_settings_name_tags")
+                        .num_columns(3)
+                        .show(ui, |ui| {
+                            for (name_tag_type, label) in [
+                                (NameTagType::Character, "settings-name-tags-character"),
+                                (NameTagType::Npc, "settings-name-tags-npc"),
+                                (NameTagType::Monster, "settings-name-tags-monster"),
+                            ] {
+                                ui.label(localize!(label));
+                                ui.checkbox(
+                                    &mut name_tag_settings.show_all[name_tag_type],
+                                    localize!("settings-name-tags-always-show"),
+                                );
+                                ui.add(
+                                    egui::Slider::new(
+                                        &mut name_tag_settings.font_size[name_tag_type],
+                                        8.0..=32.0,
+                                    )
+                                    .text(localize!("settings-name-tags-font-size"))
                                     .show_value(true),
-                            )
-                            .changed();
-                        ui.end_row();
+                                );
+                                ui.end_row();
+                            }
+                        });
+
+                    egui::Grid::new("graphics_settings_combat_healthbars")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label(localize!("settings-name-tags-combat-healthbars"));
+                            ui.checkbox(
+                                &mut name_tag_settings.combat_healthbars,
+                                localize!("settings-enabled"),
+                            );
+                            ui.end_row();
+
+                            ui.label(localize!("settings-name-tags-chat-bubbles"));
+                            ui.checkbox(
+                                &mut name_tag_settings.chat_bubbles,
+                                localize!("settings-enabled"),
+                            );
+                            ui.end_row();
+                        });
+                }
+                SettingsPage::CombatText => {
+                    let Some(damage_digits_spawner) = damage_digits_spawner.as_mut() else {
+                        return;
                     };
 
-                    add_category_slider("Background Music:", SoundCategory::BackgroundMusic);
-                    add_category_slider("Player Footsteps:", SoundCategory::PlayerFootstep);
-                    add_category_slider("Other Footsteps:", SoundCategory::OtherFootstep);
-                    add_category_slider("Player Combat:", SoundCategory::PlayerCombat);
-                    add_category_slider("Other Combat:", SoundCategory::OtherCombat);
-                    add_category_slider("NPC Sounds:", SoundCategory::NpcSounds);
+                    egui::Grid::new("combat_text_settings_styles")
+                        .num_columns(3)
+                        .show(ui, |ui| {
+                            for (digit_type, label) in [
+                                (DamageDigitType::Dealt, "settings-combat-text-dealt"),
+                                (DamageDigitType::Critical, "settings-combat-text-critical"),
+                                (DamageDigitType::Taken, "settings-combat-text-taken"),
+                                (DamageDigitType::Miss, "settings-combat-text-miss"),
+                                (DamageDigitType::Heal, "settings-combat-text-heal"),
+                            ] {
+                                let style = &mut damage_digits_spawner.styles[digit_type];
+                                ui.label(localize!(label));
+                                ui.color_edit_button_rgb(&mut style.color);
+                                ui.add(
+                                    egui::Slider::new(&mut style.scale, 0.5..=3.0)
+                                        .text(localize!("settings-combat-text-scale"))
+                                        .show_value(true),
+                                );
+                                ui.end_row();
+                            }
+                        });
 
-                    if gain_changed {
-                        for (category, mut gain) in query_sounds.iter_mut() {
-                            let target_gain = sound_settings.gain(*category);
+                    ui.separator();
 
-                            if target_gain != *gain {
-                                *gain = target_gain;
+                    egui::Grid::new("combat_text_settings_merge")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label(localize!("settings-combat-text-merge"));
+                            ui.checkbox(
+                                &mut damage_digits_spawner.merge_small_hits,
+                                localize!("settings-enabled"),
+                            );
+                            ui.end_row();
+
+                            ui.label(localize!("settings-combat-text-merge-max-damage"));
+                            ui.add(
+                                egui::Slider::new(
+                                    &mut damage_digits_spawner.merge_max_damage,
+                                    1..=1000,
+                                )
+                                .show_value(true),
+                            );
+                            ui.end_row();
+
+                            ui.label(localize!("settings-combat-text-merge-window"));
+                            let mut merge_window_ms =
+                                damage_digits_spawner.merge_window.as_millis() as u64;
+                            if ui
+                                .add(
+                                    egui::Slider::new(&mut merge_window_ms, 100..=2000)
+                                        .show_value(true),
+                                )
+                                .changed()
+                            {
+                                damage_digits_spawner.merge_window =
+                                    Duration::from_millis(merge_window_ms);
                             }
-                        }
-                    }
-                });
+                            ui.end_row();
+                        });
+
+                    ui.separator();
+
+                    egui::Grid::new("combat_text_settings_status_effects")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label(localize!("settings-combat-text-status-effects"));
+                            ui.checkbox(
+                                &mut damage_digits_spawner.status_effect_text_enabled,
+                                localize!("settings-enabled"),
+                            );
+                            ui.end_row();
+
+                            for (text_type, label) in [
+                                (StatusEffectTextType::Buff, "settings-combat-text-buff"),
+                                (StatusEffectTextType::Debuff, "settings-combat-text-debuff"),
+                                (
+                                    StatusEffectTextType::Resisted,
+                                    "settings-combat-text-resisted",
+                                ),
+                            ] {
+                                ui.label(localize!(label));
+                                ui.color_edit_button_rgb(
+                                    &mut damage_digits_spawner.status_effect_text_colors[text_type],
+                                );
+                                ui.end_row();
+                            }
+                        });
+                }
+                SettingsPage::Controls => {
+                    ui_controls_settings(
+                        ui,
+                        &mut ui_state_settings,
+                        &mut input_bindings,
+                        &keyboard_input,
+                    );
+                }
+                SettingsPage::Accessibility => {
+                    egui::Grid::new("accessibility_settings")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label(localize!("settings-effect-intensity"));
+                            ui.add(
+                                egui::Slider::new(
+                                    &mut accessibility_settings.effect_intensity,
+                                    0.0..=1.0,
+                                )
+                                .show_value(true),
+                            );
+                            ui.end_row();
+
+                            ui.label(localize!("settings-bloom-intensity"));
+                            ui.add(
+                                egui::Slider::new(
+                                    &mut accessibility_settings.bloom_intensity,
+                                    0.0..=1.0,
+                                )
+                                .show_value(true),
+                            );
+                            ui.end_row();
+
+                            ui.label(localize!("settings-reduce-flashing"));
+                            ui.checkbox(
+                                &mut accessibility_settings.reduce_flashing,
+                                localize!("settings-enabled"),
+                            );
+                            ui.end_row();
+                        });
+
+                    ui.label(localize!("settings-effect-intensity-hint"));
+                    ui.separator();
+
+                    egui::Grid::new("accessibility_settings_text_to_speech")
+                        .num_columns(2)
+                        .show(ui, |ui| {
+                            ui.label(localize!("settings-text-to-speech"));
+                            ui.checkbox(
+                                &mut accessibility_settings.text_to_speech_enabled,
+                                localize!("settings-enabled"),
+                            );
+                            ui.end_row();
+
+                            ui.label(localize!("settings-text-to-speech-read"));
+                            ui.add_enabled_ui(
+                                accessibility_settings.text_to_speech_enabled,
+                                |ui| {
+                                    ui.vertical(|ui| {
+                                        for (channel, label) in [
+                                            (TextToSpeechChannel::Say, "settings-tts-say"),
+                                            (TextToSpeechChannel::Shout, "settings-tts-shout"),
+                                            (TextToSpeechChannel::Whisper, "settings-tts-whisper"),
+                                            (
+                                                TextToSpeechChannel::Announce,
+                                                "settings-tts-announce",
+                                            ),
+                                            (TextToSpeechChannel::System, "settings-tts-system"),
+                                            (TextToSpeechChannel::Quest, "settings-tts-quest"),
+                                            (TextToSpeechChannel::Dialog, "settings-tts-dialog"),
+                                            (
+                                                TextToSpeechChannel::MessageBox,
+                                                "settings-tts-message-box",
+                                            ),
+                                        ] {
+                                            ui.checkbox(
+                                                &mut accessibility_settings.text_to_speech_channels
+                                                    [channel],
+                                                localize!(label),
+                                            );
+                                        }
+                                    });
+                                },
+                            );
+                            ui.end_row();
+                        });
+                }
+            }
         });
+
+    if edit_hud_layout {
+        // Close settings so the whole HUD can be seen while editing
+        hud_layout.edit_mode = true;
+        ui_state_windows.settings_open = false;
+    }
+
+    // Do not save the temporary presets used while benchmarking
+    if graphics_benchmark.is_running() {
+        return;
+    }
+
+    if let Some(damage_digits_spawner) = damage_digits_spawner.as_ref() {
+        user_settings_store.update(UserSettings::new(
+            &sound_settings,
+            &auto_loot_settings,
+            &afk_settings,
+            &level_up_settings,
+            &cooldown_alert_settings,
+            &item_grade_effect_settings,
+            &graphics_quality,
+            &window_settings,
+            &name_tag_settings,
+            damage_digits_spawner,
+            &accessibility_settings,
+            &input_bindings,
+        ));
+    }
 }
@@ -0,0 +1,81 @@
+use bevy::{
+    app::AppExit,
+    prelude::{EventWriter, ResMut},
+};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::resources::{SetupDataType, SetupWizard};
+
+pub fn ui_setup_wizard_system(
+    mut egui_context: EguiContexts,
+    mut setup_wizard: ResMut<SetupWizard>,
+    mut app_exit_events: EventWriter<AppExit>,
+) {
+    let setup_wizard = &mut *setup_wizard;
+
+    egui::CentralPanel::default().show(egui_context.ctx_mut(), |ui| {
+        ui.heading("rose-offline-client setup");
+        ui.label("Select the location of your ROSE Online game data, this is usually the data.idx in your installed client directory.");
+        ui.separator();
+
+        egui::Grid::new("setup_wizard_data")
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label("Data Type:");
+                egui::ComboBox::from_id_source("setup_wizard_data_type")
+                    .selected_text(setup_wizard.data_type.name())
+                    .show_ui(ui, |ui| {
+                        for data_type in SetupDataType::ALL {
+                            ui.selectable_value(
+                                &mut setup_wizard.data_type,
+                                data_type,
+                                data_type.name(),
+                            );
+                        }
+                    });
+                ui.end_row();
+
+                ui.label("Location:");
+                ui.horizontal(|ui| {
+                    ui.text_edit_singleline(&mut setup_wizard.path);
+
+                    if ui.button("Browse...").clicked() {
+                        let path = if setup_wizard.data_type.is_directory() {
+                            rfd::FileDialog::new().pick_folder()
+                        } else {
+                            rfd::FileDialog::new()
+                                .add_filter("Data index", &["idx"])
+                                .add_filter("All files", &["*"])
+                                .pick_file()
+                        };
+
+                        if let Some(path) = path {
+                            setup_wizard.path = path.to_string_lossy().into();
+                            setup_wizard.error = None;
+                        }
+                    }
+                });
+                ui.end_row();
+            });
+
+        if let Some(error) = setup_wizard.error.as_ref() {
+            ui.colored_label(egui::Color32::RED, error);
+        }
+
+        ui.label(format!(
+            "The location will be saved to {}",
+            setup_wizard.config_path.to_string_lossy()
+        ));
+
+        ui.separator();
+        ui.horizontal(|ui| {
+            if ui.button("Continue").clicked() && setup_wizard.complete() {
+                app_exit_events.send(AppExit);
+            }
+
+            if ui.button("Exit").clicked() {
+                app_exit_events.send(AppExit);
+            }
+        });
+    });
+}
@@ -12,7 +12,7 @@ use crate::{
     bundles::ability_values_get_value,
     components::{Cooldowns, PlayerCharacter},
     events::PlayerCommandEvent,
-    resources::{GameData, UiResources},
+    resources::{GameData, SkillRangeIndicator, UiResources},
     ui::{
         tooltips::{PlayerTooltipQuery, PlayerTooltipQueryItem, SkillTooltipType},
         ui_add_skill_tooltip,
@@ -69,6 +69,7 @@ fn ui_add_skill_list_slot(
     ui_resources: &UiResources,
     ui_state_dnd: &mut UiStateDragAndDrop,
     player_command_events: &mut EventWriter<PlayerCommandEvent>,
+    skill_range_indicator: &mut SkillRangeIndicator,
 ) {
     let skill = player.skill_list.get_skill(skill_slot);
     let mut dropped_item = None;
@@ -99,6 +100,10 @@ fn ui_add_skill_list_slot(
     }
 
     if let Some(skill_id) = skill {
+        if response.hovered() {
+            skill_range_indicator.hovered_skill = Some(skill_id);
+        }
+
         response.on_hover_ui(|ui| {
             let extra = ui.input(|input| input.pointer.secondary_down());
             ui_add_skill_tooltip(
@@ -136,6 +141,7 @@ pub fn ui_skill_list_system(
     game_data: Res<GameData>,
     ui_resources: Res<UiResources>,
     dialog_assets: Res<Assets<Dialog>>,
+    mut skill_range_indicator: ResMut<SkillRangeIndicator>,
 ) {
     let ui_state_skill_list = &mut *ui_state_skill_list;
     let dialog = if let Some(dialog) = dialog_assets.get(&ui_resources.dialog_skill_list) {
@@ -342,6 +348,7 @@ pub fn ui_skill_list_system(
                             &ui_resources,
                             &mut ui_state_dnd,
                             &mut player_command_events,
+                            &mut skill_range_indicator,
                         );
                     }
 
@@ -0,0 +1,82 @@
+use bevy::prelude::{Camera, GlobalTransform, Query, Res, ResMut, Time, Vec2, Vec3, With};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{components::MainCamera, resources::DamageDigitsSpawner};
+
+const STATUS_EFFECT_TEXT_DURATION: f32 = 1.5;
+const STATUS_EFFECT_TEXT_FADE_DURATION: f32 = 0.5;
+const STATUS_EFFECT_TEXT_RISE_HEIGHT: f32 = 1.0;
+const STATUS_EFFECT_TEXT_FONT_SIZE: f32 = 16.0;
+
+pub fn ui_status_effect_text_system(
+    mut egui_context: EguiContexts,
+    query_camera: Query<(&Camera, &GlobalTransform), With<MainCamera>>,
+    mut damage_digits_spawner: ResMut<DamageDigitsSpawner>,
+    time: Res<Time>,
+) {
+    let now = time.elapsed();
+    damage_digits_spawner
+        .status_effect_texts
+        .retain(|status_effect_text| {
+            now.saturating_sub(status_effect_text.spawn_time)
+                .as_secs_f32()
+                < STATUS_EFFECT_TEXT_DURATION
+        });
+    if damage_digits_spawner.status_effect_texts.is_empty() {
+        return;
+    }
+
+    let Ok((camera, camera_transform)) = query_camera.get_single() else {
+        return;
+    };
+
+    let ctx = egui_context.ctx_mut();
+    let screen_size = ctx.input(|input| input.screen_rect().size());
+    let painter = ctx.layer_painter(egui::LayerId::new(
+        egui::Order::Background,
+        egui::Id::new("status_effect_texts"),
+    ));
+
+    for status_effect_text in damage_digits_spawner.status_effect_texts.iter() {
+        let age = now
+            .saturating_sub(status_effect_text.spawn_time)
+            .as_secs_f32();
+        let position = status_effect_text.position
+            + Vec3::Y * STATUS_EFFECT_TEXT_RISE_HEIGHT * age / STATUS_EFFECT_TEXT_DURATION;
+        let Some(ndc_space_coords) = camera.world_to_ndc(camera_transform, position) else {
+            continue;
+        };
+        if ndc_space_coords.z < 0.0 || ndc_space_coords.z > 1.0 {
+            // Outside near / far plane
+            continue;
+        }
+
+        let screen_pos = (ndc_space_coords.truncate() + Vec2::ONE) / 2.0
+            * Vec2::new(screen_size.x, screen_size.y);
+
+        let alpha = ((STATUS_EFFECT_TEXT_DURATION - age) / STATUS_EFFECT_TEXT_FADE_DURATION)
+            .clamp(0.0, 1.0);
+        let [r, g, b] =
+            damage_digits_spawner.status_effect_text_colors[status_effect_text.text_type];
+        let colour = egui::Rgba::from_rgba_unmultiplied(r, g, b, alpha).into();
+
+        let galley = ctx.fonts(|fonts| {
+            fonts.layout_no_wrap(
+                status_effect_text.text.clone(),
+                egui::FontId::proportional(STATUS_EFFECT_TEXT_FONT_SIZE),
+                colour,
+            )
+        });
+        let pos = egui::pos2(
+            screen_pos.x - galley.rect.width() / 2.0,
+            screen_size.y - screen_pos.y - galley.rect.height(),
+        );
+        painter.add(egui::epaint::TextShape {
+            pos,
+            galley,
+            underline: egui::Stroke::NONE,
+            override_text_color: Some(colour),
+            angle: 0.0,
+        });
+    }
+}
@@ -1,23 +1,131 @@
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use bevy::{
     ecs::query::WorldQuery,
-    prelude::{Entity, Query, Res, With},
+    prelude::{Entity, Query, Res, ResMut, With},
     time::Time,
 };
 use bevy_egui::{egui, EguiContexts};
 
+use rose_data::StatusEffectType;
 use rose_game_common::components::StatusEffects;
 
 use crate::{
-    components::PlayerCharacter,
-    resources::{GameData, UiResources, UiSpriteSheetType},
+    components::{Clan, PlayerCharacter, StatusEffectSources},
+    localize,
+    resources::{CooldownAlerts, GameData, UiResources, UiSpriteSheetType},
+    ui::drag_and_drop_slot::generate_cooldown_mesh,
 };
 
+// Icons smaller than this are too small to fit the remaining time text
+const MIN_REMAINING_TIME_TEXT_ICON_SIZE: f32 = 24.0;
+
 #[derive(WorldQuery)]
 pub struct PlayerQuery<'w> {
     entity: Entity,
     status_effects: &'w StatusEffects,
+    status_effect_sources: Option<&'w StatusEffectSources>,
+    clan: Option<&'w Clan>,
+}
+
+fn format_remaining_time_short(remaining_time: Duration) -> String {
+    let seconds = remaining_time.as_secs();
+    if seconds >= 60 {
+        format!("{}m", seconds / 60)
+    } else {
+        format!("{}", seconds)
+    }
+}
+
+fn format_remaining_time(remaining_time: Duration) -> String {
+    let seconds = remaining_time.as_secs();
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+/// Draws the icon of an active status effect scaled by `scale`, with a sweep showing how
+/// much of its duration has passed and a tooltip with its description and time remaining.
+pub fn ui_add_status_effect_icon(
+    ui: &mut egui::Ui,
+    game_data: &GameData,
+    ui_resources: &UiResources,
+    status_effects: &StatusEffects,
+    status_effect_sources: Option<&StatusEffectSources>,
+    status_effect_type: StatusEffectType,
+    now: Instant,
+    scale: f32,
+) -> Option<egui::Response> {
+    let active_status_effect = status_effects.active[status_effect_type].as_ref()?;
+    let status_effect_data = game_data
+        .status_effects
+        .get_status_effect(active_status_effect.id)?;
+    let sprite = ui_resources.get_sprite_by_index(
+        UiSpriteSheetType::StateIcon,
+        status_effect_data.icon_id as usize,
+    )?;
+
+    let remaining_time = status_effects.expire_times[status_effect_type]
+        .map(|expire_time| expire_time.saturating_duration_since(now));
+    let source = status_effect_sources.and_then(|status_effect_sources| {
+        status_effect_sources.get(status_effect_type, active_status_effect.id)
+    });
+
+    let (rect, response) = ui.allocate_exact_size(
+        egui::vec2(sprite.width, sprite.height) * scale,
+        egui::Sense::click(),
+    );
+    sprite.draw_stretched(ui, rect);
+
+    if let (Some(remaining_time), Some(source)) = (remaining_time, source) {
+        if !source.duration.is_zero() {
+            let elapsed = 1.0 - remaining_time.as_secs_f32() / source.duration.as_secs_f32();
+            ui.painter()
+                .add(egui::epaint::Shape::mesh(generate_cooldown_mesh(
+                    elapsed.clamp(0.0, 1.0),
+                    rect,
+                )));
+        }
+    }
+
+    if let Some(remaining_time) = remaining_time {
+        if rect.width() >= MIN_REMAINING_TIME_TEXT_ICON_SIZE {
+            let text = format_remaining_time_short(remaining_time);
+            let font_id = egui::FontId::proportional(11.0);
+            let pos = rect.right_bottom() - egui::vec2(1.0, 0.0);
+            ui.painter().text(
+                pos + egui::vec2(1.0, 1.0),
+                egui::Align2::RIGHT_BOTTOM,
+                &text,
+                font_id.clone(),
+                egui::Color32::BLACK,
+            );
+            ui.painter().text(
+                pos,
+                egui::Align2::RIGHT_BOTTOM,
+                &text,
+                font_id,
+                egui::Color32::WHITE,
+            );
+        }
+    }
+
+    Some(response.on_hover_ui(|ui| {
+        ui.label(egui::RichText::new(status_effect_data.name).strong());
+
+        if let Some(skill_data) =
+            source.and_then(|source| game_data.skills.get_skill(source.skill_id))
+        {
+            if !skill_data.description.is_empty() {
+                ui.label(skill_data.description);
+            }
+        }
+
+        if let Some(remaining_time) = remaining_time {
+            ui.label(localize!(
+                "status-effect-time-remaining",
+                time = format_remaining_time(remaining_time)
+            ));
+        }
+    }))
 }
 
 pub fn ui_status_effects_system(
@@ -25,6 +133,7 @@ pub fn ui_status_effects_system(
     query_player: Query<PlayerQuery, With<PlayerCharacter>>,
     game_data: Res<GameData>,
     ui_resources: Res<UiResources>,
+    mut cooldown_alerts: ResMut<CooldownAlerts>,
     time: Res<Time>,
 ) {
     let player = if let Ok(player) = query_player.get_single() {
@@ -32,6 +141,7 @@ pub fn ui_status_effects_system(
     } else {
         return;
     };
+    let now = time.last_update().unwrap();
 
     egui::Window::new("Player Status Effects}")
         .anchor(egui::Align2::LEFT_TOP, [250.0, 40.0])
@@ -43,46 +153,58 @@ pub fn ui_status_effects_system(
                 for (status_effect_type, active_status_effect) in
                     player.status_effects.active.iter()
                 {
-                    if let Some(active_status_effect) = active_status_effect {
-                        if let Some(status_effect_data) = game_data
-                            .status_effects
-                            .get_status_effect(active_status_effect.id)
+                    let Some(active_status_effect) = active_status_effect else {
+                        continue;
+                    };
+                    let Some(response) = ui_add_status_effect_icon(
+                        ui,
+                        &game_data,
+                        &ui_resources,
+                        player.status_effects,
+                        player.status_effect_sources,
+                        status_effect_type,
+                        now,
+                        1.0,
+                    ) else {
+                        continue;
+                    };
+
+                    let status_effect_id = active_status_effect.id.get();
+                    response.context_menu(|ui| {
+                        let mut enabled =
+                            cooldown_alerts.status_effects.contains(&status_effect_id);
+                        if ui
+                            .checkbox(&mut enabled, localize!("status-effect-warn-expiry"))
+                            .changed()
                         {
-                            let remaining_time = if let Some(expire_time) =
-                                player.status_effects.expire_times[status_effect_type]
-                            {
-                                let now = time.last_update().unwrap();
-                                if now >= expire_time {
-                                    Some(Duration::ZERO)
-                                } else {
-                                    Some(expire_time - now)
-                                }
-                            } else {
-                                None
-                            };
-
-                            if let Some(sprite) = ui_resources.get_sprite_by_index(
-                                UiSpriteSheetType::StateIcon,
-                                status_effect_data.icon_id as usize,
-                            ) {
-                                let (rect, response) = ui.allocate_exact_size(
-                                    egui::vec2(sprite.width, sprite.height),
-                                    egui::Sense::hover(),
-                                );
-                                sprite.draw(ui, rect.min);
-
-                                if response.hovered() {
-                                    if let Some(remaining_time) = remaining_time {
-                                        response.on_hover_text(format!(
-                                            "{}\n\nTime Remaining: {} seconds",
-                                            status_effect_data.name,
-                                            remaining_time.as_secs()
-                                        ));
-                                    } else {
-                                        response.on_hover_text(status_effect_data.name);
-                                    }
-                                }
-                            }
+                            cooldown_alerts.set_status_effect(status_effect_id, enabled);
+                            ui.close_menu();
+                        }
+                    });
+                }
+
+                // Passive clan skills are always active while in a clan
+                for skill_data in player
+                    .clan
+                    .iter()
+                    .flat_map(|clan| clan.skills.iter())
+                    .filter_map(|skill_id| game_data.skills.get_skill(*skill_id))
+                {
+                    if let Some(sprite) = ui_resources.get_sprite_by_index(
+                        UiSpriteSheetType::Skill,
+                        skill_data.icon_number as usize,
+                    ) {
+                        let (rect, response) = ui.allocate_exact_size(
+                            egui::vec2(sprite.width, sprite.height),
+                            egui::Sense::hover(),
+                        );
+                        sprite.draw(ui, rect.min);
+
+                        if response.hovered() {
+                            response.on_hover_text(format!(
+                                "{} (Clan Skill)\n\n{}",
+                                skill_data.name, skill_data.description
+                            ));
                         }
                     }
                 }
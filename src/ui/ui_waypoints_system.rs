@@ -0,0 +1,146 @@
+use bevy::prelude::{EventWriter, Local, Query, Res, ResMut, Vec3, With};
+use bevy_egui::{egui, EguiContexts};
+
+use rose_game_common::components::CharacterInfo;
+
+use crate::{
+    components::{PlayerCharacter, Position},
+    events::PlayerCommandEvent,
+    resources::{Account, CharacterStoreKey, CurrentZone, GameData, Waypoint, Waypoints},
+    ui::UiStateWindows,
+};
+
+#[derive(Default)]
+pub struct UiStateWaypoints {
+    new_waypoint_name: String,
+}
+
+pub fn ui_waypoints_system(
+    mut egui_context: EguiContexts,
+    mut ui_state: Local<UiStateWaypoints>,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    mut waypoints: ResMut<Waypoints>,
+    query_player: Query<(&CharacterInfo, &Position), With<PlayerCharacter>>,
+    current_zone: Option<Res<CurrentZone>>,
+    mut player_command_events: EventWriter<PlayerCommandEvent>,
+    game_data: Res<GameData>,
+    account: Option<Res<Account>>,
+) {
+    let Ok((character_info, player_position)) = query_player.get_single() else {
+        return;
+    };
+
+    let store_key = CharacterStoreKey::new(account.as_deref(), &character_info.name);
+    if waypoints.store_key.as_ref() != Some(&store_key) {
+        *waypoints = Waypoints::load(store_key);
+    }
+
+    if !ui_state_windows.waypoints_open {
+        return;
+    }
+
+    let mut add_waypoint = false;
+    let mut move_to_waypoint = None;
+    let mut remove_waypoint = None;
+
+    egui::Window::new("Waypoints")
+        .open(&mut ui_state_windows.waypoints_open)
+        .resizable(false)
+        .show(egui_context.ctx_mut(), |ui| {
+            ui.horizontal(|ui| {
+                let response = ui.add(
+                    egui::TextEdit::singleline(&mut ui_state.new_waypoint_name)
+                        .hint_text("Waypoint name")
+                        .desired_width(150.0),
+                );
+                let enter_pressed =
+                    response.lost_focus() && ui.input(|input| input.key_pressed(egui::Key::Enter));
+
+                if ui
+                    .add_enabled(current_zone.is_some(), egui::Button::new("Save location"))
+                    .clicked()
+                    || enter_pressed
+                {
+                    add_waypoint = true;
+                }
+            });
+
+            ui.separator();
+
+            if waypoints.waypoints.is_empty() {
+                ui.label("You have not saved any waypoints.");
+                return;
+            }
+
+            egui::ScrollArea::vertical()
+                .max_height(300.0)
+                .show(ui, |ui| {
+                    egui::Grid::new("waypoints_grid")
+                        .num_columns(5)
+                        .striped(true)
+                        .show(ui, |ui| {
+                            for (index, waypoint) in waypoints.waypoints.iter().enumerate() {
+                                let zone_name = waypoint
+                                    .get_zone_id()
+                                    .and_then(|zone_id| game_data.zone_list.get_zone(zone_id))
+                                    .map_or("???", |zone_data| zone_data.name);
+                                let in_current_zone = current_zone
+                                    .as_ref()
+                                    .map_or(false, |zone| waypoint.zone_id == zone.id.get());
+
+                                ui.label(&waypoint.name);
+                                ui.label(zone_name);
+                                ui.label(format!(
+                                    "{:0>4}, {:0>4}",
+                                    (waypoint.x / 100.0) as i32,
+                                    (waypoint.y / 100.0) as i32
+                                ));
+
+                                if ui
+                                    .add_enabled(in_current_zone, egui::Button::new("Go"))
+                                    .on_disabled_hover_text("Waypoint is in a different zone")
+                                    .clicked()
+                                {
+                                    move_to_waypoint = Some(index);
+                                }
+
+                                if ui.button("Delete").clicked() {
+                                    remove_waypoint = Some(index);
+                                }
+                                ui.end_row();
+                            }
+                        });
+                });
+        });
+
+    if add_waypoint {
+        if let Some(current_zone) = current_zone.as_ref() {
+            let name = ui_state.new_waypoint_name.trim();
+            let name = if name.is_empty() {
+                format!("Waypoint {}", waypoints.waypoints.len() + 1)
+            } else {
+                name.to_string()
+            };
+
+            waypoints.waypoints.push(Waypoint::new(
+                name,
+                current_zone.id,
+                player_position.position,
+            ));
+            waypoints.save();
+            ui_state.new_waypoint_name.clear();
+        }
+    }
+
+    if let Some(waypoint) = move_to_waypoint.and_then(|index| waypoints.waypoints.get(index)) {
+        player_command_events.send(PlayerCommandEvent::Move(
+            Position::new(Vec3::new(waypoint.x, waypoint.y, waypoint.z)),
+            None,
+        ));
+    }
+
+    if let Some(index) = remove_waypoint {
+        waypoints.waypoints.remove(index);
+        waypoints.save();
+    }
+}
@@ -0,0 +1,66 @@
+use bevy::{
+    input::Input,
+    prelude::{EventWriter, KeyCode, Query, Res, ResMut, With},
+};
+use bevy_egui::{egui, EguiContexts};
+
+use crate::{
+    components::{CastingState, PlayerCharacter},
+    events::PlayerCommandEvent,
+    resources::{InputAction, InputBindings},
+    ui::{UiStateSettings, UiStateWindows, UiWindow},
+};
+
+const UI_WINDOW_HOTKEYS: [(InputAction, UiWindow); 11] = [
+    (InputAction::ToggleCharacterInfo, UiWindow::CharacterInfo),
+    (InputAction::ToggleInventory, UiWindow::Inventory),
+    (InputAction::ToggleClan, UiWindow::Clan),
+    (InputAction::ToggleFriends, UiWindow::Friends),
+    (InputAction::ToggleCalendar, UiWindow::Calendar),
+    (InputAction::ToggleSkillList, UiWindow::SkillList),
+    (InputAction::ToggleQuestList, UiWindow::QuestList),
+    (InputAction::ToggleSettings, UiWindow::Settings),
+    (
+        InputAction::ToggleSessionStatistics,
+        UiWindow::SessionStatistics,
+    ),
+    (InputAction::ToggleWaypoints, UiWindow::Waypoints),
+    (
+        InputAction::TogglePersonalStoreScanner,
+        UiWindow::PersonalStoreScanner,
+    ),
+];
+
+pub fn ui_window_manager_system(
+    mut egui_context: EguiContexts,
+    mut ui_state_windows: ResMut<UiStateWindows>,
+    input_bindings: Res<InputBindings>,
+    keyboard_input: Res<Input<KeyCode>>,
+    query_player_casting: Query<(), (With<PlayerCharacter>, With<CastingState>)>,
+    mut player_command_events: EventWriter<PlayerCommandEvent>,
+    ui_state_settings: Res<UiStateSettings>,
+) {
+    ui_state_windows.update_window_stack();
+
+    // The pressed key is being captured to rebind an action, and Escape cancels it
+    if egui_context.ctx_mut().wants_keyboard_input() || ui_state_settings.is_rebinding() {
+        return;
+    }
+
+    for (action, window) in UI_WINDOW_HOTKEYS {
+        if input_bindings.just_pressed(action, &keyboard_input) {
+            ui_state_windows.toggle(window);
+        }
+    }
+
+    egui_context.ctx_mut().input_mut(|input| {
+        if input.consume_key(egui::Modifiers::NONE, egui::Key::Escape) {
+            if !query_player_casting.is_empty() {
+                // Cancelling a cast takes priority over closing windows
+                player_command_events.send(PlayerCommandEvent::CancelCast);
+            } else if !ui_state_windows.close_topmost() {
+                ui_state_windows.set_open(UiWindow::Menu, true);
+            }
+        }
+    });
+}
@@ -6,13 +6,19 @@ use std::{
 
 use rose_file_readers::{VfsFile, VirtualFilesystem};
 
+use crate::resources::MissingAssets;
+
 pub struct VfsAssetIo {
     vfs: Arc<VirtualFilesystem>,
+    missing_assets: MissingAssets,
 }
 
 impl VfsAssetIo {
-    pub fn new(vfs: Arc<VirtualFilesystem>) -> Self {
-        Self { vfs }
+    pub fn new(vfs: Arc<VirtualFilesystem>, missing_assets: MissingAssets) -> Self {
+        Self {
+            vfs,
+            missing_assets,
+        }
     }
 }
 
@@ -35,6 +41,7 @@ impl AssetIo for VfsAssetIo {
                     VfsFile::View(view) => Ok(view.into()),
                 }
             } else {
+                self.missing_assets.add(Path::new(path));
                 Err(AssetIoError::NotFound(path.into()))
             }
         })
@@ -19,7 +19,7 @@ use bevy::{
     reflect::{TypePath, TypeUuid},
     render::{
         mesh::{Indices, PrimitiveTopology},
-        view::NoFrustumCulling,
+        view::{NoFrustumCulling, RenderLayers},
     },
     tasks::IoTaskPool,
 };
@@ -32,12 +32,15 @@ use thiserror::Error;
 use rose_data::{NpcId, SkyboxData, WarpGateId, ZoneId, ZoneList};
 use rose_file_readers::{
     HimFile, IfoEffectObject, IfoFile, IfoObject, IfoSoundObject, LitFile, LitObject, RoseFile,
-    RoseFileReader, StbFile, TilFile, ZonFile, ZonTileRotation, ZscCollisionFlags, ZscEffectType,
-    ZscFile,
+    RoseFileReader, StbFile, TilFile, ZmoFile, ZonFile, ZonTileRotation, ZscCollisionFlags,
+    ZscEffectType, ZscFile,
 };
 
 use crate::{
-    animation::{MeshAnimation, TransformAnimation, ZmoTextureAssetLoader},
+    animation::{
+        is_vertex_animation, MeshAnimation, MorphAnimation, TransformAnimation,
+        ZmoTextureAssetLoader,
+    },
     audio::{SoundRadius, SpatialSound},
     components::{
         ColliderParent, EventObject, NightTimeEffect, WarpObject, Zone, ZoneObject,
@@ -52,7 +55,7 @@ use crate::{
     render::{
         EffectMeshAnimationRenderState, EffectMeshMaterial, ObjectMaterial, ParticleMaterial,
         SkyMaterial, TerrainMaterial, WaterMaterial, MESH_ATTRIBUTE_UV_1,
-        TERRAIN_MATERIAL_MAX_TEXTURES, TERRAIN_MESH_ATTRIBUTE_TILE_INFO,
+        TERRAIN_MATERIAL_MAX_TEXTURES, TERRAIN_MESH_ATTRIBUTE_TILE_INFO, WATER_RENDER_LAYER,
     },
     resources::{CurrentZone, DebugInspector, GameData, SpecularTexture},
     VfsResource,
@@ -566,6 +569,7 @@ pub fn spawn_zone(
 
         water_materials.add(WaterMaterial {
             textures: water_material_textures,
+            reflection_image: None,
         })
     };
 
@@ -1074,6 +1078,7 @@ fn spawn_water(
             ComputedVisibility::default(),
             NotShadowCaster,
             NotShadowReceiver,
+            RenderLayers::layer(WATER_RENDER_LAYER),
             RigidBody::Fixed,
             Collider::trimesh(collider_verts, collider_indices),
             CollisionGroups::new(COLLISION_GROUP_ZONE_WATER, COLLISION_FILTER_INSPECTABLE),
@@ -1292,11 +1297,21 @@ fn spawn_object(
                 CollisionGroups::new(collision_group, collision_filter),
             ));
 
-            let active_motion = object_part.animation_path.as_ref().map(|animation_path| {
-                TransformAnimation::repeat(asset_server.load(animation_path.path()), None)
-            });
-            if let Some(active_motion) = active_motion {
-                part_commands.insert(active_motion);
+            if let Some(animation_path) = object_part.animation_path.as_ref() {
+                let motion = asset_server.load(animation_path.path());
+
+                if vfs_resource
+                    .vfs
+                    .read_file::<ZmoFile, _>(animation_path)
+                    .map_or(false, |zmo| is_vertex_animation(&zmo))
+                {
+                    part_commands.insert((
+                        MorphAnimation::repeat(motion, None),
+                        NoFrustumCulling, // AABB culling is broken for mesh animations
+                    ));
+                } else {
+                    part_commands.insert(TransformAnimation::repeat(motion, None));
+                }
             }
 
             part_entities.push(part_commands.id());
@@ -1334,6 +1349,7 @@ fn spawn_object(
                 effect_path.into(),
                 false,
                 None,
+                1.0,
             ) {
                 if let Some(parent_part_entity) = object_effect
                     .parent
@@ -1415,6 +1431,7 @@ fn spawn_animated_object(
         animation_texture: Some(
             asset_server.load(ZmoTextureAssetLoader::convert_path_texture(motion_path)),
         ),
+        intensity: 1.0,
     });
 
     commands
@@ -1492,6 +1509,7 @@ fn spawn_effect_object(
         (&effect_object.effect_path).into(),
         false,
         Some(effect_object_entity),
+        1.0,
     );
 
     effect_object_entity